@@ -0,0 +1,491 @@
+//! # Arbitration
+//!
+//! Companion contract to the bounty escrow that decides disputes with a
+//! panel of staked arbiters instead of a single trusted arbiter.
+//!
+//! ## Flow
+//! 1. Arbiters join the panel by staking `min_stake` of the stake token.
+//! 2. An escrow configured with `set_arbitration_contract` files a case via
+//!    `open_case` whenever a dispute is opened on it. Only escrows the admin
+//!    registered with `set_escrow` may file cases.
+//! 3. Panel members (other than the disputing parties) vote the share of the
+//!    frozen funds, in basis points, that should go to the contributor.
+//! 4. Once `quorum` votes are in and either the voting period has elapsed or
+//!    the whole panel has voted, anyone can `finalize` the case. The median
+//!    vote is the outcome and is passed to the escrow's `resolve_dispute`.
+//!
+//! Arbiters cannot withdraw their stake while they have votes on undecided
+//! cases. The admin can slash misbehaving arbiters; an arbiter whose stake
+//! drops below `min_stake` is removed from the panel.
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token,
+    Address, Env, Vec,
+};
+
+/// Maximum contributor share, in basis points.
+pub const BASIS_POINTS: u32 = 10_000;
+
+/// Subset of the escrow interface the arbitration contract calls into.
+#[contractclient(name = "EscrowClient")]
+pub trait EscrowContract {
+    fn resolve_dispute(env: Env, bounty_id: u64, contributor_share_bps: u32);
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when min_stake or quorum is zero
+    InvalidConfig = 3,
+    AlreadyMember = 4,
+    NotMember = 5,
+    /// Returned when a case is filed by an escrow not registered with `set_escrow`
+    UnknownEscrow = 6,
+    CaseExists = 7,
+    CaseNotFound = 8,
+    CaseClosed = 9,
+    AlreadyVoted = 10,
+    /// Returned when the depositor or contributor of a case tries to vote on it
+    PartyCannotVote = 11,
+    /// Returned when the contributor share exceeds 10_000 basis points
+    InvalidShare = 12,
+    QuorumNotReached = 13,
+    /// Returned when finalizing before the voting period elapsed and the
+    /// whole panel has voted
+    VotingOpen = 14,
+    /// Returned when leaving the panel with votes on undecided cases
+    HasOpenVotes = 15,
+    InvalidAmount = 16,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitrationConfig {
+    /// Token arbiters stake to join the panel.
+    pub stake_token: Address,
+    pub min_stake: i128,
+    /// Votes required before a case can be finalized.
+    pub quorum: u32,
+    /// Seconds a case stays open for votes unless the whole panel has voted.
+    pub voting_period: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CaseStatus {
+    Open,
+    Decided,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Case {
+    pub escrow: Address,
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub status: CaseStatus,
+    pub opened_at: u64,
+    pub voters: Vec<Address>,
+    /// Contributor share voted by each voter, in the order of `voters`.
+    pub votes: Vec<u32>,
+    /// Median vote passed to the escrow. Zero until decided.
+    pub outcome_bps: u32,
+    pub decided_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CaseOpened {
+    pub escrow: Address,
+    pub bounty_id: u64,
+    pub voting_ends_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCast {
+    pub escrow: Address,
+    pub bounty_id: u64,
+    pub arbiter: Address,
+    pub contributor_share_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CaseDecided {
+    pub escrow: Address,
+    pub bounty_id: u64,
+    pub outcome_bps: u32,
+    pub votes: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PanelChanged {
+    pub arbiter: Address,
+    /// Stake held after the change; zero when the arbiter left the panel.
+    pub stake: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Config,
+    /// Vec<Address> of current panel members
+    Panel,
+    /// arbiter Address -> i128 staked amount
+    Stake(Address),
+    /// arbiter Address -> u32 votes on undecided cases
+    OpenVotes(Address),
+    /// escrow Address -> bool
+    Escrow(Address),
+    /// (escrow, bounty_id) -> Case
+    Case(Address, u64),
+}
+
+#[contract]
+pub struct ArbitrationContract;
+
+#[contractimpl]
+impl ArbitrationContract {
+    /// Initialize with an admin and the panel configuration.
+    pub fn init(env: Env, admin: Address, config: ArbitrationConfig) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        if config.min_stake <= 0 || config.quorum == 0 {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Config, &config);
+        Ok(())
+    }
+
+    /// Allow or revoke `escrow` to file cases (admin only).
+    pub fn set_escrow(env: Env, escrow: Address, allowed: bool) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        let key = DataKey::Escrow(escrow);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Join the panel, transferring `min_stake` of the stake token from
+    /// `arbiter` to this contract.
+    pub fn join_panel(env: Env, arbiter: Address) -> Result<(), Error> {
+        let config = Self::get_config(env.clone())?;
+        arbiter.require_auth();
+        let mut panel = Self::get_panel(env.clone());
+        if panel.contains(&arbiter) {
+            return Err(Error::AlreadyMember);
+        }
+
+        panel.push_back(arbiter.clone());
+        env.storage().instance().set(&DataKey::Panel, &panel);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Stake(arbiter.clone()), &config.min_stake);
+
+        token::Client::new(&env, &config.stake_token).transfer(
+            &arbiter,
+            &env.current_contract_address(),
+            &config.min_stake,
+        );
+        Self::emit_panel_changed(&env, arbiter, config.min_stake);
+        Ok(())
+    }
+
+    /// Leave the panel and withdraw the stake. Not allowed while the arbiter
+    /// has votes on undecided cases.
+    pub fn leave_panel(env: Env, arbiter: Address) -> Result<(), Error> {
+        let config = Self::get_config(env.clone())?;
+        arbiter.require_auth();
+        if Self::open_votes(&env, &arbiter) > 0 {
+            return Err(Error::HasOpenVotes);
+        }
+        let stake = Self::remove_member(&env, &arbiter)?;
+
+        token::Client::new(&env, &config.stake_token).transfer(
+            &env.current_contract_address(),
+            &arbiter,
+            &stake,
+        );
+        Self::emit_panel_changed(&env, arbiter, 0);
+        Ok(())
+    }
+
+    /// Confiscate `amount` of `arbiter`'s stake to the admin (admin only).
+    /// If the remaining stake falls below `min_stake` the arbiter is removed
+    /// from the panel and the remainder returned.
+    pub fn slash(env: Env, arbiter: Address, amount: i128) -> Result<(), Error> {
+        let admin = Self::require_admin(&env)?;
+        let config = Self::get_config(env.clone())?;
+        let stake = Self::get_stake(env.clone(), arbiter.clone());
+        if stake == 0 {
+            return Err(Error::NotMember);
+        }
+        if amount <= 0 || amount > stake {
+            return Err(Error::InvalidAmount);
+        }
+
+        let remaining = stake - amount;
+        let client = token::Client::new(&env, &config.stake_token);
+        if remaining < config.min_stake {
+            Self::remove_member(&env, &arbiter)?;
+            if remaining > 0 {
+                client.transfer(&env.current_contract_address(), &arbiter, &remaining);
+            }
+            Self::emit_panel_changed(&env, arbiter, 0);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Stake(arbiter.clone()), &remaining);
+            Self::emit_panel_changed(&env, arbiter, remaining);
+        }
+        client.transfer(&env.current_contract_address(), &admin, &amount);
+        Ok(())
+    }
+
+    /// File a dispute on `escrow` as a new case. Called by the escrow from
+    /// `open_dispute`; requires the escrow's authorization.
+    pub fn open_case(
+        env: Env,
+        escrow: Address,
+        bounty_id: u64,
+        depositor: Address,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let config = Self::get_config(env.clone())?;
+        escrow.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Escrow(escrow.clone()))
+        {
+            return Err(Error::UnknownEscrow);
+        }
+        let key = DataKey::Case(escrow.clone(), bounty_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::CaseExists);
+        }
+
+        let now = env.ledger().timestamp();
+        let case = Case {
+            escrow: escrow.clone(),
+            bounty_id,
+            depositor,
+            contributor,
+            status: CaseStatus::Open,
+            opened_at: now,
+            voters: Vec::new(&env),
+            votes: Vec::new(&env),
+            outcome_bps: 0,
+            decided_at: 0,
+        };
+        env.storage().persistent().set(&key, &case);
+
+        env.events().publish(
+            (symbol_short!("case_new"), bounty_id),
+            CaseOpened {
+                escrow,
+                bounty_id,
+                voting_ends_at: now.saturating_add(config.voting_period),
+            },
+        );
+        Ok(())
+    }
+
+    /// Vote the share of the frozen funds, in basis points, that should go
+    /// to the contributor. One vote per panel member per case.
+    pub fn vote(
+        env: Env,
+        arbiter: Address,
+        escrow: Address,
+        bounty_id: u64,
+        contributor_share_bps: u32,
+    ) -> Result<(), Error> {
+        arbiter.require_auth();
+        if !Self::get_panel(env.clone()).contains(&arbiter) {
+            return Err(Error::NotMember);
+        }
+        if contributor_share_bps > BASIS_POINTS {
+            return Err(Error::InvalidShare);
+        }
+        let key = DataKey::Case(escrow.clone(), bounty_id);
+        let mut case: Case = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::CaseNotFound)?;
+        if case.status != CaseStatus::Open {
+            return Err(Error::CaseClosed);
+        }
+        if arbiter == case.depositor || arbiter == case.contributor {
+            return Err(Error::PartyCannotVote);
+        }
+        if case.voters.contains(&arbiter) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        case.voters.push_back(arbiter.clone());
+        case.votes.push_back(contributor_share_bps);
+        env.storage().persistent().set(&key, &case);
+        env.storage().persistent().set(
+            &DataKey::OpenVotes(arbiter.clone()),
+            &(Self::open_votes(&env, &arbiter) + 1),
+        );
+
+        env.events().publish(
+            (symbol_short!("case_vote"), bounty_id),
+            VoteCast {
+                escrow,
+                bounty_id,
+                arbiter,
+                contributor_share_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Decide a case and resolve the escrow dispute with the median vote.
+    /// Callable by anyone once quorum is reached and either the voting period
+    /// has elapsed or every panel member has voted. Returns the outcome.
+    pub fn finalize(env: Env, escrow: Address, bounty_id: u64) -> Result<u32, Error> {
+        let config = Self::get_config(env.clone())?;
+        let key = DataKey::Case(escrow.clone(), bounty_id);
+        let mut case: Case = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::CaseNotFound)?;
+        if case.status != CaseStatus::Open {
+            return Err(Error::CaseClosed);
+        }
+        let votes = case.votes.len();
+        if votes < config.quorum {
+            return Err(Error::QuorumNotReached);
+        }
+        let now = env.ledger().timestamp();
+        let voting_ended = now >= case.opened_at.saturating_add(config.voting_period);
+        if !voting_ended && votes < Self::get_panel(env.clone()).len() {
+            return Err(Error::VotingOpen);
+        }
+
+        // EFFECTS: record the decision and free the voters' stakes
+        case.status = CaseStatus::Decided;
+        case.outcome_bps = Self::median(&env, &case.votes);
+        case.decided_at = now;
+        env.storage().persistent().set(&key, &case);
+        for voter in case.voters.iter() {
+            let open = Self::open_votes(&env, &voter);
+            env.storage()
+                .persistent()
+                .set(&DataKey::OpenVotes(voter), &open.saturating_sub(1));
+        }
+
+        // INTERACTION: release the frozen funds
+        EscrowClient::new(&env, &escrow).resolve_dispute(&bounty_id, &case.outcome_bps);
+
+        env.events().publish(
+            (symbol_short!("case_done"), bounty_id),
+            CaseDecided {
+                escrow,
+                bounty_id,
+                outcome_bps: case.outcome_bps,
+                votes,
+            },
+        );
+        Ok(case.outcome_bps)
+    }
+
+    /// View: the case filed by `escrow` for `bounty_id`.
+    pub fn get_case(env: Env, escrow: Address, bounty_id: u64) -> Result<Case, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Case(escrow, bounty_id))
+            .ok_or(Error::CaseNotFound)
+    }
+
+    /// View: current panel members in joining order.
+    pub fn get_panel(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Panel)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// View: stake held for `arbiter`. Zero for non-members.
+    pub fn get_stake(env: Env, arbiter: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stake(arbiter))
+            .unwrap_or(0)
+    }
+
+    /// View: the panel configuration.
+    pub fn get_config(env: Env) -> Result<ArbitrationConfig, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn require_admin(env: &Env) -> Result<Address, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(admin)
+    }
+
+    fn open_votes(env: &Env, arbiter: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OpenVotes(arbiter.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Remove `arbiter` from the panel, returning the stake it held.
+    fn remove_member(env: &Env, arbiter: &Address) -> Result<i128, Error> {
+        let mut panel = Self::get_panel(env.clone());
+        let index = panel.first_index_of(arbiter).ok_or(Error::NotMember)?;
+        panel.remove(index);
+        env.storage().instance().set(&DataKey::Panel, &panel);
+
+        let key = DataKey::Stake(arbiter.clone());
+        let stake: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().remove(&key);
+        Ok(stake)
+    }
+
+    /// Lower median of `votes`, which must not be empty.
+    fn median(env: &Env, votes: &Vec<u32>) -> u32 {
+        let mut sorted: Vec<u32> = Vec::new(env);
+        for vote in votes.iter() {
+            let mut i = 0;
+            while i < sorted.len() && sorted.get(i).unwrap() <= vote {
+                i += 1;
+            }
+            sorted.insert(i, vote);
+        }
+        sorted.get((sorted.len() - 1) / 2).unwrap()
+    }
+
+    fn emit_panel_changed(env: &Env, arbiter: Address, stake: i128) {
+        env.events()
+            .publish((symbol_short!("panel"),), PanelChanged { arbiter, stake });
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,241 @@
+#![cfg(test)]
+
+use crate::{ArbitrationConfig, ArbitrationContract, ArbitrationContractClient, CaseStatus, Error};
+use bounty_escrow::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env, Vec,
+};
+
+const VOTING_PERIOD: u64 = 3_600;
+
+struct Setup<'a> {
+    env: Env,
+    admin: Address,
+    depositor: Address,
+    contributor: Address,
+    arbiters: Vec<Address>,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+    arbitration: ArbitrationContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_address);
+        let token_admin = token::StellarAssetClient::new(&env, &token_address);
+        token_admin.mint(&depositor, &10_000);
+
+        let escrow_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &escrow_id);
+        escrow.init(&admin, &token_address);
+
+        let arbitration_id = env.register_contract(None, ArbitrationContract);
+        let arbitration = ArbitrationContractClient::new(&env, &arbitration_id);
+        arbitration.init(
+            &admin,
+            &ArbitrationConfig {
+                stake_token: token_address.clone(),
+                min_stake: 500,
+                quorum: 2,
+                voting_period: VOTING_PERIOD,
+            },
+        );
+        arbitration.set_escrow(&escrow_id, &true);
+        escrow.set_arbitration_contract(&Some(arbitration_id));
+
+        let mut arbiters = Vec::new(&env);
+        for _ in 0..3 {
+            let arbiter = Address::generate(&env);
+            token_admin.mint(&arbiter, &1_000);
+            arbitration.join_panel(&arbiter);
+            arbiters.push_back(arbiter);
+        }
+
+        let deadline = env.ledger().timestamp() + 10_000;
+        escrow.lock_funds(&depositor, &1, &1_000, &deadline);
+        // Only the escrow's assignee can be party to a dispute.
+        escrow.assign_contributor(&1, &contributor, &1_000);
+        escrow.accept_assignment(&1);
+
+        Self {
+            env,
+            admin,
+            depositor,
+            contributor,
+            arbiters,
+            token,
+            escrow,
+            arbitration,
+        }
+    }
+
+    fn arbiter(&self, i: u32) -> Address {
+        self.arbiters.get(i).unwrap()
+    }
+
+    fn open_dispute(&self) {
+        let reason = BytesN::from_array(&self.env, &[7u8; 32]);
+        self.escrow
+            .open_dispute(&self.depositor, &1, &self.contributor, &reason);
+    }
+
+    fn vote(&self, i: u32, share_bps: u32) {
+        self.arbitration
+            .vote(&self.arbiter(i), &self.escrow.address, &1, &share_bps);
+    }
+}
+
+#[test]
+fn test_panel_decides_escrow_dispute() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.get_dispute_arbiter(),
+        Some(s.arbitration.address.clone())
+    );
+    s.open_dispute();
+
+    let case = s.arbitration.get_case(&s.escrow.address, &1);
+    assert_eq!(case.status, CaseStatus::Open);
+    assert_eq!(case.contributor, s.contributor);
+
+    s.vote(0, 7_000);
+    s.vote(1, 2_000);
+    s.vote(2, 6_000);
+
+    // The whole panel voted, so the case can be decided early.
+    assert_eq!(s.arbitration.finalize(&s.escrow.address, &1), 6_000);
+    let case = s.arbitration.get_case(&s.escrow.address, &1);
+    assert_eq!(case.status, CaseStatus::Decided);
+    assert_eq!(case.outcome_bps, 6_000);
+
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.contributor), 600);
+    assert_eq!(s.token.balance(&s.depositor), 9_400);
+    assert_eq!(
+        s.arbitration.try_finalize(&s.escrow.address, &1),
+        Err(Ok(Error::CaseClosed))
+    );
+}
+
+#[test]
+fn test_finalize_requires_quorum_and_voting_period() {
+    let s = Setup::new();
+    s.open_dispute();
+
+    s.vote(0, 10_000);
+    assert_eq!(
+        s.arbitration.try_finalize(&s.escrow.address, &1),
+        Err(Ok(Error::QuorumNotReached))
+    );
+    s.vote(1, 0);
+    assert_eq!(
+        s.arbitration.try_finalize(&s.escrow.address, &1),
+        Err(Ok(Error::VotingOpen))
+    );
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + VOTING_PERIOD);
+    // Lower median of [0, 10_000].
+    assert_eq!(s.arbitration.finalize(&s.escrow.address, &1), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_vote_validation() {
+    let s = Setup::new();
+    let outsider = Address::generate(&s.env);
+    assert_eq!(
+        s.arbitration
+            .try_vote(&s.arbiter(0), &s.escrow.address, &1, &5_000),
+        Err(Ok(Error::CaseNotFound))
+    );
+    s.open_dispute();
+
+    assert_eq!(
+        s.arbitration
+            .try_vote(&outsider, &s.escrow.address, &1, &5_000),
+        Err(Ok(Error::NotMember))
+    );
+    assert_eq!(
+        s.arbitration
+            .try_vote(&s.arbiter(0), &s.escrow.address, &1, &10_001),
+        Err(Ok(Error::InvalidShare))
+    );
+    s.vote(0, 5_000);
+    assert_eq!(
+        s.arbitration
+            .try_vote(&s.arbiter(0), &s.escrow.address, &1, &5_000),
+        Err(Ok(Error::AlreadyVoted))
+    );
+
+    // A disputing party that sits on the panel cannot vote on its own case.
+    s.token.transfer(&s.depositor, &s.contributor, &500);
+    s.arbitration.join_panel(&s.contributor);
+    assert_eq!(
+        s.arbitration
+            .try_vote(&s.contributor, &s.escrow.address, &1, &10_000),
+        Err(Ok(Error::PartyCannotVote))
+    );
+}
+
+#[test]
+fn test_stake_locked_until_case_decided() {
+    let s = Setup::new();
+    assert_eq!(s.token.balance(&s.arbitration.address), 1_500);
+    assert_eq!(s.arbitration.get_stake(&s.arbiter(0)), 500);
+    s.open_dispute();
+    s.vote(0, 5_000);
+
+    assert_eq!(
+        s.arbitration.try_leave_panel(&s.arbiter(0)),
+        Err(Ok(Error::HasOpenVotes))
+    );
+    s.vote(1, 5_000);
+    s.vote(2, 5_000);
+    s.arbitration.finalize(&s.escrow.address, &1);
+
+    s.arbitration.leave_panel(&s.arbiter(0));
+    assert_eq!(s.token.balance(&s.arbiter(0)), 1_000);
+    assert_eq!(s.arbitration.get_stake(&s.arbiter(0)), 0);
+    assert_eq!(s.arbitration.get_panel().len(), 2);
+}
+
+#[test]
+fn test_slash_removes_underfunded_arbiter() {
+    let s = Setup::new();
+    s.arbitration.slash(&s.arbiter(0), &200);
+
+    assert_eq!(s.token.balance(&s.admin), 200);
+    assert_eq!(s.token.balance(&s.arbiter(0)), 800);
+    assert!(!s.arbitration.get_panel().contains(s.arbiter(0)));
+    assert_eq!(
+        s.arbitration.try_slash(&s.arbiter(0), &100),
+        Err(Ok(Error::NotMember))
+    );
+}
+
+#[test]
+fn test_unregistered_escrow_cannot_open_disputes() {
+    let s = Setup::new();
+    s.arbitration.set_escrow(&s.escrow.address, &false);
+
+    let reason = BytesN::from_array(&s.env, &[7u8; 32]);
+    let res = s
+        .escrow
+        .try_open_dispute(&s.depositor, &1, &s.contributor, &reason);
+    assert!(res.is_err());
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
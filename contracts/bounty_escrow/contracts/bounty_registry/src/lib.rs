@@ -0,0 +1,224 @@
+//! # Bounty Registry
+//!
+//! Companion contract to the bounty escrow. It stores the definition of each
+//! bounty (repository, issue, reward and status) so front-ends and other
+//! contracts can discover bounties without indexing escrow events.
+//!
+//! `create_bounty` registers a bounty and locks its reward in the configured
+//! escrow contract in the same invocation: if the escrow rejects the lock
+//! (insufficient balance, duplicate id, paused, ...) the whole call fails and
+//! no registry entry is written.
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, Address,
+    Env, String, Vec,
+};
+
+/// Subset of the escrow interface the registry calls into.
+#[contractclient(name = "EscrowClient")]
+pub trait EscrowContract {
+    fn lock_funds(env: Env, depositor: Address, bounty_id: u64, amount: i128, deadline: u64);
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    BountyExists = 3,
+    BountyNotFound = 4,
+    /// Returned when the reward is zero or negative
+    InvalidReward = 5,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BountyStatus {
+    /// Reward locked in escrow, work in progress.
+    Open,
+    /// Reward paid out to the contributor.
+    Completed,
+    /// Bounty withdrawn and reward refunded.
+    Cancelled,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyDefinition {
+    pub bounty_id: u64,
+    /// Repository slug, e.g. `owner/name`.
+    pub repo: String,
+    pub issue_id: u64,
+    pub reward: i128,
+    pub status: BountyStatus,
+    pub creator: Address,
+    pub created_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyCreated {
+    pub bounty_id: u64,
+    pub repo: String,
+    pub issue_id: u64,
+    pub reward: i128,
+    pub creator: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyStatusChanged {
+    pub bounty_id: u64,
+    pub status: BountyStatus,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// Address of the bounty escrow contract
+    Escrow,
+    /// bounty_id -> BountyDefinition
+    Bounty(u64),
+    /// Vec<u64> of all registered bounty_ids
+    BountyIndex,
+}
+
+#[contract]
+pub struct BountyRegistryContract;
+
+#[contractimpl]
+impl BountyRegistryContract {
+    /// Initialize with an admin and the escrow contract rewards are locked in.
+    pub fn init(env: Env, admin: Address, escrow: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Escrow, &escrow);
+        Ok(())
+    }
+
+    /// Register a bounty and lock `reward` from `creator` in the escrow under
+    /// the same `bounty_id`. Requires the creator's authorization, which also
+    /// covers the escrow lock and token transfer.
+    pub fn create_bounty(
+        env: Env,
+        creator: Address,
+        bounty_id: u64,
+        repo: String,
+        issue_id: u64,
+        reward: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let escrow: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Escrow)
+            .ok_or(Error::NotInitialized)?;
+        creator.require_auth();
+
+        if reward <= 0 {
+            return Err(Error::InvalidReward);
+        }
+        let key = DataKey::Bounty(bounty_id);
+        if env.storage().persistent().has(&key) {
+            return Err(Error::BountyExists);
+        }
+
+        let bounty = BountyDefinition {
+            bounty_id,
+            repo: repo.clone(),
+            issue_id,
+            reward,
+            status: BountyStatus::Open,
+            creator: creator.clone(),
+            created_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &bounty);
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyIndex)
+            .unwrap_or(Vec::new(&env));
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BountyIndex, &index);
+
+        // Any escrow failure aborts the invocation, rolling back the entry.
+        EscrowClient::new(&env, &escrow).lock_funds(&creator, &bounty_id, &reward, &deadline);
+
+        env.events().publish(
+            (symbol_short!("b_create"), bounty_id),
+            BountyCreated {
+                bounty_id,
+                repo,
+                issue_id,
+                reward,
+                creator,
+            },
+        );
+        Ok(())
+    }
+
+    /// Record the outcome of a bounty once its escrow is settled (admin only).
+    pub fn set_status(env: Env, bounty_id: u64, status: BountyStatus) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::Bounty(bounty_id);
+        let mut bounty: BountyDefinition = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(Error::BountyNotFound)?;
+        bounty.status = status.clone();
+        env.storage().persistent().set(&key, &bounty);
+
+        env.events().publish(
+            (symbol_short!("b_status"), bounty_id),
+            BountyStatusChanged { bounty_id, status },
+        );
+        Ok(())
+    }
+
+    /// View: the definition of `bounty_id`.
+    pub fn get_bounty(env: Env, bounty_id: u64) -> Result<BountyDefinition, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Bounty(bounty_id))
+            .ok_or(Error::BountyNotFound)
+    }
+
+    /// View: registered bounties in creation order, paginated.
+    pub fn list_bounties(env: Env, offset: u32, limit: u32) -> Vec<BountyDefinition> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::BountyIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut result = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(bounty) = env.storage().persistent().get(&DataKey::Bounty(bounty_id)) {
+                result.push_back(bounty);
+            }
+        }
+        result
+    }
+
+    /// View: the escrow contract rewards are locked in.
+    pub fn get_escrow(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Escrow)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,130 @@
+#![cfg(test)]
+
+use crate::{BountyRegistryContract, BountyRegistryContractClient, BountyStatus, Error};
+use bounty_escrow::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, token, Address, Env, String};
+
+struct Setup<'a> {
+    env: Env,
+    creator: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+    registry: BountyRegistryContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_address);
+        token::StellarAssetClient::new(&env, &token_address).mint(&creator, &10_000);
+
+        let escrow_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &escrow_id);
+        escrow.init(&admin, &token_address);
+
+        let registry_id = env.register_contract(None, BountyRegistryContract);
+        let registry = BountyRegistryContractClient::new(&env, &registry_id);
+        registry.init(&admin, &escrow_id);
+
+        Self {
+            env,
+            creator,
+            token,
+            escrow,
+            registry,
+        }
+    }
+
+    fn create(&self, bounty_id: u64, reward: i128) {
+        let repo = String::from_str(&self.env, "grainlify/grainlify");
+        let deadline = self.env.ledger().timestamp() + 1_000;
+        self.registry
+            .create_bounty(&self.creator, &bounty_id, &repo, &42, &reward, &deadline);
+    }
+}
+
+#[test]
+fn test_create_bounty_locks_reward_in_escrow() {
+    let s = Setup::new();
+    s.create(1, 1_500);
+
+    let bounty = s.registry.get_bounty(&1);
+    assert_eq!(bounty.issue_id, 42);
+    assert_eq!(bounty.reward, 1_500);
+    assert_eq!(bounty.status, BountyStatus::Open);
+    assert_eq!(bounty.creator, s.creator);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_500);
+    assert_eq!(escrow.depositor, s.creator);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_500);
+    assert_eq!(s.token.balance(&s.creator), 8_500);
+}
+
+#[test]
+fn test_failed_lock_leaves_no_registry_entry() {
+    let s = Setup::new();
+    let repo = String::from_str(&s.env, "grainlify/grainlify");
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    // More than the creator holds: the escrow transfer fails.
+    let res = s
+        .registry
+        .try_create_bounty(&s.creator, &1, &repo, &42, &20_000, &deadline);
+    assert!(res.is_err());
+    assert_eq!(
+        s.registry.try_get_bounty(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.registry.list_bounties(&0, &10).len(), 0);
+    assert_eq!(s.token.balance(&s.creator), 10_000);
+}
+
+#[test]
+fn test_create_bounty_validation() {
+    let s = Setup::new();
+    let repo = String::from_str(&s.env, "grainlify/grainlify");
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    assert_eq!(
+        s.registry
+            .try_create_bounty(&s.creator, &1, &repo, &42, &0, &deadline),
+        Err(Ok(Error::InvalidReward))
+    );
+    s.create(1, 100);
+    assert_eq!(
+        s.registry
+            .try_create_bounty(&s.creator, &1, &repo, &42, &100, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+}
+
+#[test]
+fn test_status_updates_and_listing() {
+    let s = Setup::new();
+    s.create(1, 100);
+    s.create(2, 200);
+    s.create(3, 300);
+
+    s.escrow.release_funds(&2, &Address::generate(&s.env));
+    s.registry.set_status(&2, &BountyStatus::Completed);
+    assert_eq!(s.registry.get_bounty(&2).status, BountyStatus::Completed);
+
+    let page = s.registry.list_bounties(&1, &5);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().bounty_id, 2);
+    assert_eq!(page.get(1).unwrap().bounty_id, 3);
+    assert_eq!(
+        s.registry.try_set_status(&9, &BountyStatus::Cancelled),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -0,0 +1,213 @@
+//! # Completion Badge
+//!
+//! Soulbound (non-transferable) badges recording completed bounties. A
+//! registered minter, normally the bounty escrow configured with
+//! `set_badge_contract`, mints one badge per bounty to the contributor it
+//! paid, recording the bounty id and amount. Badges cannot be transferred,
+//! so a contributor's badges form a portable on-chain résumé that other
+//! contracts and front-ends can read with `badges_of`.
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when the caller is not a registered minter
+    UnauthorizedMinter = 3,
+    /// Returned when the minter already issued a badge for this bounty
+    AlreadyMinted = 4,
+    BadgeNotFound = 5,
+    /// Returned by `transfer`: badges are bound to their owner
+    NonTransferable = 6,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Badge {
+    pub token_id: u64,
+    pub owner: Address,
+    /// Contract that issued the badge, e.g. the bounty escrow.
+    pub issuer: Address,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub minted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BadgeMinted {
+    pub token_id: u64,
+    pub owner: Address,
+    pub issuer: Address,
+    pub bounty_id: u64,
+    pub amount: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// minter Address -> bool
+    Minter(Address),
+    NextTokenId,
+    /// token_id -> Badge
+    Badge(u64),
+    /// owner Address -> Vec<u64> token ids in minting order
+    OwnerBadges(Address),
+    /// (issuer, bounty_id) -> token_id
+    BountyBadge(Address, u64),
+}
+
+#[contract]
+pub struct CompletionBadgeContract;
+
+#[contractimpl]
+impl CompletionBadgeContract {
+    /// Initialize with the admin that manages minters.
+    pub fn init(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    /// Allow or revoke `minter` (typically an escrow contract) to mint
+    /// badges (admin only).
+    pub fn set_minter(env: Env, minter: Address, allowed: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let key = DataKey::Minter(minter);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// Mint a badge to `to` for completing `bounty_id`, paid `amount`.
+    /// Each minter can issue one badge per bounty. Returns the token id.
+    pub fn mint(
+        env: Env,
+        minter: Address,
+        to: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<u64, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        minter.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Minter(minter.clone()))
+        {
+            return Err(Error::UnauthorizedMinter);
+        }
+        let bounty_key = DataKey::BountyBadge(minter.clone(), bounty_id);
+        if env.storage().persistent().has(&bounty_key) {
+            return Err(Error::AlreadyMinted);
+        }
+
+        let token_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextTokenId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextTokenId, &(token_id + 1));
+        let badge = Badge {
+            token_id,
+            owner: to.clone(),
+            issuer: minter.clone(),
+            bounty_id,
+            amount,
+            minted_at: env.ledger().timestamp(),
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Badge(token_id), &badge);
+        env.storage().persistent().set(&bounty_key, &token_id);
+
+        let owner_key = DataKey::OwnerBadges(to.clone());
+        let mut owned: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&owner_key)
+            .unwrap_or(Vec::new(&env));
+        owned.push_back(token_id);
+        env.storage().persistent().set(&owner_key, &owned);
+
+        env.events().publish(
+            (symbol_short!("badge"), to.clone()),
+            BadgeMinted {
+                token_id,
+                owner: to,
+                issuer: minter,
+                bounty_id,
+                amount,
+            },
+        );
+        Ok(token_id)
+    }
+
+    /// Badges are soulbound: always fails with `NonTransferable`. Present so
+    /// wallets probing for a transfer entry point get a clear error.
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _token_id: u64) -> Result<(), Error> {
+        Err(Error::NonTransferable)
+    }
+
+    /// View: the badge with `token_id`.
+    pub fn get_badge(env: Env, token_id: u64) -> Result<Badge, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Badge(token_id))
+            .ok_or(Error::BadgeNotFound)
+    }
+
+    /// View: owner of `token_id`.
+    pub fn owner_of(env: Env, token_id: u64) -> Result<Address, Error> {
+        Self::get_badge(env, token_id).map(|badge| badge.owner)
+    }
+
+    /// View: number of badges held by `owner`.
+    pub fn balance(env: Env, owner: Address) -> u32 {
+        Self::owned(&env, owner).len()
+    }
+
+    /// View: badges held by `owner` in minting order, paginated.
+    pub fn badges_of(env: Env, owner: Address, offset: u32, limit: u32) -> Vec<Badge> {
+        let owned = Self::owned(&env, owner);
+        let mut result = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(owned.len());
+        for i in offset..end {
+            let token_id = owned.get(i).unwrap();
+            if let Some(badge) = env.storage().persistent().get(&DataKey::Badge(token_id)) {
+                result.push_back(badge);
+            }
+        }
+        result
+    }
+
+    fn owned(env: &Env, owner: Address) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::OwnerBadges(owner))
+            .unwrap_or(Vec::new(env))
+    }
+}
+
+#[cfg(test)]
+mod test;
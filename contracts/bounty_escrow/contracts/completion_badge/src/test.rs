@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use crate::{CompletionBadgeContract, CompletionBadgeContractClient, Error};
+use core::ops::Deref;
+use escrow_testutils::TestSetup;
+use soroban_sdk::{testutils::Address as _, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    badge: CompletionBadgeContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let s = TestSetup::new();
+        let badge_id = s.env.register_contract(None, CompletionBadgeContract);
+        let badge = CompletionBadgeContractClient::new(&s.env, &badge_id);
+        badge.init(&s.admin);
+        badge.set_minter(&s.escrow.address, &true);
+        s.escrow.set_badge_contract(&Some(badge_id));
+
+        Self { base: s, badge }
+    }
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &TestSetup<'a> {
+        &self.base
+    }
+}
+
+#[test]
+fn test_release_mints_completion_badge() {
+    let s = Setup::new();
+    s.lock(7, 1_000);
+    s.lock(8, 250);
+    s.escrow.release_funds(&7, &s.contributor);
+    s.escrow.release_funds(&8, &s.contributor);
+
+    assert_eq!(s.badge.balance(&s.contributor), 2);
+    let badges = s.badge.badges_of(&s.contributor, &0, &10);
+    let first = badges.get(0).unwrap();
+    assert_eq!(first.bounty_id, 7);
+    assert_eq!(first.amount, 1_000);
+    assert_eq!(first.issuer, s.escrow.address);
+    assert_eq!(badges.get(1).unwrap().bounty_id, 8);
+    assert_eq!(s.badge.owner_of(&first.token_id), s.contributor);
+}
+
+#[test]
+fn test_badges_are_soulbound() {
+    let s = Setup::new();
+    s.lock(1, 100);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.badge.try_transfer(&s.contributor, &other, &1),
+        Err(Ok(Error::NonTransferable))
+    );
+    assert_eq!(s.badge.owner_of(&1), s.contributor);
+}
+
+#[test]
+fn test_mint_requires_registered_minter_once_per_bounty() {
+    let s = Setup::new();
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.badge.try_mint(&stranger, &s.contributor, &1, &100),
+        Err(Ok(Error::UnauthorizedMinter))
+    );
+
+    s.badge.mint(&s.escrow.address, &s.contributor, &1, &100);
+    assert_eq!(
+        s.badge
+            .try_mint(&s.escrow.address, &s.contributor, &1, &100),
+        Err(Ok(Error::AlreadyMinted))
+    );
+}
+
+#[test]
+fn test_failed_mint_does_not_block_release() {
+    let s = Setup::new();
+    s.badge.set_minter(&s.escrow.address, &false);
+    s.lock(1, 500);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 500);
+    assert_eq!(s.badge.balance(&s.contributor), 0);
+}
@@ -0,0 +1,53 @@
+//! Optional permissioned mode for depositors.
+//!
+//! Private bounty programs can turn on `set_depositor_allowlist_mode` so
+//! that only addresses added with `set_depositor_allowlist_entry` may lock
+//! or top up funds. With the mode off (the default) anyone can deposit and
+//! the list is ignored.
+
+use crate::{ConfigKey, Error};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AllowlistKey {
+    /// depositor -> bool, present while the address is allowlisted
+    AllowedDepositor(Address),
+}
+
+pub fn is_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ConfigKey::DepositorAllowlistMode)
+        .unwrap_or(false)
+}
+
+pub fn set_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&ConfigKey::DepositorAllowlistMode, &enabled);
+}
+
+pub fn is_listed(env: &Env, depositor: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&AllowlistKey::AllowedDepositor(depositor.clone()))
+}
+
+pub fn set_listed(env: &Env, depositor: &Address, listed: bool) {
+    let key = AllowlistKey::AllowedDepositor(depositor.clone());
+    if listed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Returns `Unauthorized` if the allowlist mode is on and `depositor` is
+/// not on the list.
+pub fn ensure_allowed(env: &Env, depositor: &Address) -> Result<(), Error> {
+    if is_enabled(env) && !is_listed(env, depositor) {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
@@ -0,0 +1,43 @@
+//! Delegation of dispute decisions to an external arbitration contract.
+//!
+//! When the admin configures an arbitration contract
+//! (`set_arbitration_contract`) it replaces the single arbiter: every dispute
+//! opened on this escrow is filed as a case with it through `open_case`, and
+//! only that contract may call `resolve_dispute` to release the frozen funds.
+//! Unlike reputation reports, filing a case is not best effort — a dispute
+//! that cannot be filed would freeze the escrow with nobody able to decide it.
+
+use crate::{DataKey, Dispute};
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface the escrow expects from an arbitration contract. The escrow
+/// passes its own address as `escrow`; the arbitration contract answers by
+/// calling `resolve_dispute(bounty_id, contributor_share_bps)` on it.
+#[allow(dead_code)]
+#[contractclient(name = "ArbitrationClient")]
+pub trait ArbitrationInterface {
+    fn open_case(
+        env: Env,
+        escrow: Address,
+        bounty_id: u64,
+        depositor: Address,
+        contributor: Address,
+    );
+}
+
+/// The configured arbitration contract, if any.
+pub fn get(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::ArbitrationContract)
+}
+
+/// File `dispute` with the configured arbitration contract, if any.
+pub fn open_case(env: &Env, dispute: &Dispute) {
+    if let Some(address) = get(env) {
+        ArbitrationClient::new(env, &address).open_case(
+            &env.current_contract_address(),
+            &dispute.bounty_id,
+            &dispute.depositor,
+            &dispute.contributor,
+        );
+    }
+}
@@ -0,0 +1,26 @@
+//! Compact records of escrows removed by `sweep_stale_escrows`.
+//!
+//! An archived bounty id can never be locked again.
+
+use crate::escrow_entries::{self, Entry};
+use crate::ArchivedEscrow;
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ArchiveKey {
+    /// bounty_id -> ArchivedEscrow
+    Summary(u64),
+}
+
+pub fn get(env: &Env, bounty_id: u64) -> Option<ArchivedEscrow> {
+    escrow_entries::get(env, bounty_id, Entry::ArchiveSummary)
+}
+
+pub fn set(env: &Env, bounty_id: u64, summary: &ArchivedEscrow) {
+    escrow_entries::set(env, bounty_id, Entry::ArchiveSummary, summary);
+}
+
+pub fn contains(env: &Env, bounty_id: u64) -> bool {
+    escrow_entries::has(env, bounty_id, Entry::ArchiveSummary)
+}
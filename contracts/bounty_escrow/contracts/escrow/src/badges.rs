@@ -0,0 +1,34 @@
+//! Optional minting of completion badges on full releases.
+//!
+//! When the admin configures a badge contract (`set_badge_contract`),
+//! `release_funds` mints a non-transferable completion badge to the
+//! contributor recording the bounty id and the net amount paid. Minting is
+//! best effort, like reputation reports: a failing badge contract never
+//! blocks a payout.
+
+use crate::DataKey;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface the escrow expects from a badge contract. The escrow passes
+/// its own address as `minter`.
+#[allow(dead_code)]
+#[contractclient(name = "BadgeClient")]
+pub trait BadgeInterface {
+    fn mint(env: Env, minter: Address, to: Address, bounty_id: u64, amount: i128) -> u64;
+}
+
+/// Mint a completion badge for `bounty_id` to `contributor`.
+pub fn mint_completion(env: &Env, bounty_id: u64, contributor: &Address, amount: i128) {
+    if let Some(address) = env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::BadgeContract)
+    {
+        let _ = BadgeClient::new(env, &address).try_mint(
+            &env.current_contract_address(),
+            contributor,
+            &bounty_id,
+            &amount,
+        );
+    }
+}
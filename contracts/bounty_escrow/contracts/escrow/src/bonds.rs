@@ -0,0 +1,71 @@
+//! Contributor bonds.
+//!
+//! A depositor can ask the assignee to post a bond with
+//! `assign_contributor_with_bond`. The bond is pulled from the contributor
+//! when they accept the assignment and held until the escrow settles. If a
+//! dispute over the escrow is resolved against the contributor (less than
+//! half of the funds awarded to them) the bond is slashed to the depositor,
+//! or to the treasury when the admin routes slashed bonds there; otherwise
+//! the contributor takes it back with `withdraw_bond`.
+
+use crate::escrow_entries::{self, Entry};
+use crate::ContributorBond;
+use soroban_sdk::{contracterror, contracttype, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BondError {
+    /// Returned when withdrawing a contributor bond that is not held
+    BondNotFound = 59,
+    /// Returned when withdrawing a contributor bond before the escrow settles
+    BondLocked = 60,
+}
+
+impl BondError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            BondError::BondNotFound => "no contributor bond is held for this bounty",
+            BondError::BondLocked => "bond is held until the escrow settles",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BondKey {
+    /// bounty_id -> ContributorBond held for that escrow
+    Bond(u64),
+    /// i128 sum of all bonds held, counted in the tracked balance
+    TotalHeld,
+}
+
+pub fn get(env: &Env, bounty_id: u64) -> Option<ContributorBond> {
+    escrow_entries::get(env, bounty_id, Entry::Bond)
+}
+
+/// Sum of all bonds the contract currently holds.
+pub fn total_held(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&BondKey::TotalHeld)
+        .unwrap_or(0)
+}
+
+/// Record `bond` as held for `bounty_id`.
+pub fn hold(env: &Env, bounty_id: u64, bond: &ContributorBond) {
+    escrow_entries::set(env, bounty_id, Entry::Bond, bond);
+    env.storage()
+        .instance()
+        .set(&BondKey::TotalHeld, &(total_held(env) + bond.amount));
+}
+
+/// Stop holding the bond of `bounty_id` and return it, if there was one.
+pub fn take(env: &Env, bounty_id: u64) -> Option<ContributorBond> {
+    let bond = get(env, bounty_id)?;
+    escrow_entries::remove(env, bounty_id, Entry::Bond);
+    env.storage()
+        .instance()
+        .set(&BondKey::TotalHeld, &(total_held(env) - bond.amount));
+    Some(bond)
+}
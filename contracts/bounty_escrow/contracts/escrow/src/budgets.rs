@@ -0,0 +1,78 @@
+//! Optional per-depositor budgets.
+//!
+//! The admin can cap how much a depositor (e.g. a team wallet) may lock
+//! across all of its bounties within a rolling period with
+//! `set_depositor_budget`. Locks and top-ups count against the budget;
+//! refunds and cancellations do not give it back. The window restarts the
+//! first time the depositor locks after `period` seconds have passed.
+
+use crate::DepositorBudget;
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BudgetError {
+    /// Returned when a lock would exceed the depositor's budget for the period
+    BudgetExceeded = 53,
+}
+
+impl BudgetError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            BudgetError::BudgetExceeded => "amount exceeds the depositor's remaining budget",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BudgetKey {
+    /// depositor -> DepositorBudget
+    Depositor(Address),
+}
+
+pub fn get(env: &Env, depositor: &Address) -> Option<DepositorBudget> {
+    env.storage()
+        .persistent()
+        .get(&BudgetKey::Depositor(depositor.clone()))
+}
+
+pub fn set(env: &Env, depositor: &Address, budget: &DepositorBudget) {
+    env.storage()
+        .persistent()
+        .set(&BudgetKey::Depositor(depositor.clone()), budget);
+}
+
+pub fn remove(env: &Env, depositor: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&BudgetKey::Depositor(depositor.clone()));
+}
+
+/// `budget` as of now: a window whose period has passed starts over with
+/// nothing spent. A zero period never resets.
+pub fn current(env: &Env, mut budget: DepositorBudget) -> DepositorBudget {
+    let now = env.ledger().timestamp();
+    if budget.period > 0 && now >= budget.window_start.saturating_add(budget.period) {
+        budget.window_start = now;
+        budget.spent = 0;
+    }
+    budget
+}
+
+/// Count `amount` against `depositor`'s budget, if it has one. Returns
+/// `BudgetExceeded` without recording anything when it does not fit.
+pub fn consume(env: &Env, depositor: &Address, amount: i128) -> Result<(), BudgetError> {
+    let mut budget = match get(env, depositor) {
+        Some(budget) => current(env, budget),
+        None => return Ok(()),
+    };
+    let spent = budget.spent.saturating_add(amount);
+    if spent > budget.limit {
+        return Err(BudgetError::BudgetExceeded);
+    }
+    budget.spent = spent;
+    set(env, depositor, &budget);
+    Ok(())
+}
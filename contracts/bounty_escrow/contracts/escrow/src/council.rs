@@ -0,0 +1,90 @@
+//! Storage for the multi-admin council and its proposals.
+//!
+//! Once `set_admin_council` has run, treasury changes, rescues, pausing and
+//! upgrades no longer accept the single admin key: a council member proposes
+//! an `AdminOp`, other members approve it, and it runs once `threshold`
+//! current members have signed off and the timelock delay has passed.
+
+use crate::{AdminCouncil, CouncilProposal, Error};
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CouncilKey {
+    /// AdminCouncil, present once a council has been configured
+    Council,
+    /// u64 id of the most recent proposal
+    ProposalNonce,
+    /// proposal_id -> CouncilProposal awaiting execution
+    Proposal(u64),
+}
+
+pub fn get(env: &Env) -> Option<AdminCouncil> {
+    env.storage().instance().get(&CouncilKey::Council)
+}
+
+/// Validate and store `members` / `threshold` as the council.
+pub fn set(env: &Env, members: Vec<Address>, threshold: u32) -> Result<AdminCouncil, Error> {
+    if threshold == 0 || threshold > members.len() {
+        return Err(Error::InvalidCouncil);
+    }
+    for (i, member) in members.iter().enumerate() {
+        if members.first_index_of(&member) != Some(i as u32) {
+            return Err(Error::InvalidCouncil);
+        }
+    }
+
+    let council = AdminCouncil { members, threshold };
+    env.storage().instance().set(&CouncilKey::Council, &council);
+    Ok(council)
+}
+
+/// Check that `member` sits on the council and require its authorization.
+pub fn require_member(env: &Env, member: &Address) -> Result<AdminCouncil, Error> {
+    let council = get(env).ok_or(Error::NotInitialized)?;
+    if !council.members.contains(member) {
+        return Err(Error::Unauthorized);
+    }
+    member.require_auth();
+    Ok(council)
+}
+
+pub fn next_proposal_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&CouncilKey::ProposalNonce)
+        .unwrap_or(0)
+        + 1;
+    env.storage()
+        .instance()
+        .set(&CouncilKey::ProposalNonce, &id);
+    id
+}
+
+pub fn get_proposal(env: &Env, proposal_id: u64) -> Option<CouncilProposal> {
+    env.storage()
+        .persistent()
+        .get(&CouncilKey::Proposal(proposal_id))
+}
+
+pub fn set_proposal(env: &Env, proposal_id: u64, proposal: &CouncilProposal) {
+    env.storage()
+        .persistent()
+        .set(&CouncilKey::Proposal(proposal_id), proposal);
+}
+
+pub fn remove_proposal(env: &Env, proposal_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&CouncilKey::Proposal(proposal_id));
+}
+
+/// Approvals on `proposal` from addresses that are still council members.
+pub fn current_approvals(council: &AdminCouncil, proposal: &CouncilProposal) -> u32 {
+    proposal
+        .approvals
+        .iter()
+        .filter(|approver| council.members.contains(approver))
+        .count() as u32
+}
@@ -0,0 +1,97 @@
+//! Index of funded escrows by deadline, bucketed by day.
+//!
+//! Escrows that still hold funds (`Locked` or `PartiallyRefunded`) are listed
+//! under the day their deadline falls in, and the days in use are kept in
+//! ascending order. Expiry scans therefore only touch escrows whose deadline
+//! is near or past instead of every escrow ever locked. `save_escrow` keeps
+//! the index in step with the escrow records.
+
+use crate::EscrowStatus;
+use soroban_sdk::{contracttype, Env, Vec};
+
+/// Width of one deadline bucket (1 day).
+const BUCKET_SECONDS: u64 = 86_400;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeadlineKey {
+    /// Vec<u64> of non-empty bucket numbers, ascending
+    Buckets,
+    /// bucket number -> Vec<u64> of bounty ids with a deadline in that day
+    Bucket(u64),
+}
+
+/// Bucket holding escrows whose deadline is `deadline`.
+pub fn bucket_of(deadline: u64) -> u64 {
+    deadline / BUCKET_SECONDS
+}
+
+/// Whether escrows in `status` belong in the index.
+pub fn tracks(status: &EscrowStatus) -> bool {
+    matches!(
+        status,
+        EscrowStatus::Locked | EscrowStatus::PartiallyRefunded
+    )
+}
+
+/// Non-empty buckets in ascending order.
+pub fn buckets(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DeadlineKey::Buckets)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Bounty ids in `bucket`, in the order they were indexed.
+pub fn ids(env: &Env, bucket: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DeadlineKey::Bucket(bucket))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Index `bounty_id` under `deadline`. A no-op if it is already there.
+pub fn insert(env: &Env, bounty_id: u64, deadline: u64) {
+    let bucket = bucket_of(deadline);
+    let mut bucket_ids = ids(env, bucket);
+    if bucket_ids.contains(bounty_id) {
+        return;
+    }
+    if bucket_ids.is_empty() {
+        let mut all = buckets(env);
+        if let Err(pos) = all.binary_search(bucket) {
+            all.insert(pos, bucket);
+            env.storage().persistent().set(&DeadlineKey::Buckets, &all);
+        }
+    }
+    bucket_ids.push_back(bounty_id);
+    env.storage()
+        .persistent()
+        .set(&DeadlineKey::Bucket(bucket), &bucket_ids);
+}
+
+/// Drop `bounty_id` from the bucket of `deadline`, if indexed there.
+pub fn remove(env: &Env, bounty_id: u64, deadline: u64) {
+    let bucket = bucket_of(deadline);
+    let mut bucket_ids = ids(env, bucket);
+    let pos = match bucket_ids.first_index_of(bounty_id) {
+        Some(pos) => pos,
+        None => return,
+    };
+    bucket_ids.remove(pos);
+    if !bucket_ids.is_empty() {
+        env.storage()
+            .persistent()
+            .set(&DeadlineKey::Bucket(bucket), &bucket_ids);
+        return;
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&DeadlineKey::Bucket(bucket));
+    let mut all = buckets(env);
+    if let Ok(pos) = all.binary_search(bucket) {
+        all.remove(pos);
+        env.storage().persistent().set(&DeadlineKey::Buckets, &all);
+    }
+}
@@ -0,0 +1,49 @@
+//! Donation mode for untracked tokens.
+//!
+//! Instead of sweeping escrow tokens nobody is owed to the treasury with
+//! `rescue_untracked_tokens`, the admin can turn on donation mode by
+//! designating a community pool escrow with `set_donation_pool`. The
+//! rescuer then moves untracked tokens into that escrow with
+//! `absorb_untracked_into`, as a top-up no depositor paid for. Setting the
+//! pool to `None` turns donation mode off.
+
+use soroban_sdk::{contracterror, contracttype, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DonationError {
+    /// Donation mode is off, or the escrow is not the designated pool
+    NotDonationPool = 81,
+}
+
+impl DonationError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            DonationError::NotDonationPool => {
+                "donation mode is off or escrow is not the donation pool"
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DonationKey {
+    /// u64 bounty_id of the community pool escrow
+    DonationPool,
+}
+
+pub fn pool(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DonationKey::DonationPool)
+}
+
+pub fn set_pool(env: &Env, bounty_id: Option<u64>) {
+    match bounty_id {
+        Some(bounty_id) => env
+            .storage()
+            .instance()
+            .set(&DonationKey::DonationPool, &bounty_id),
+        None => env.storage().instance().remove(&DonationKey::DonationPool),
+    }
+}
@@ -0,0 +1,52 @@
+//! Storage for the pending `emergency_withdraw_all` announcement and the
+//! progress of its batches.
+
+use crate::{EmergencyExit, EscrowStatus};
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EmergencyExitKey {
+    /// EmergencyExit, present while an announcement is pending
+    Announcement,
+    /// (EscrowStatus, u32): the status list being drained and how many of
+    /// its leading positions are still to be visited
+    ExitCursor,
+}
+
+pub fn get(env: &Env) -> Option<EmergencyExit> {
+    env.storage()
+        .instance()
+        .get(&EmergencyExitKey::Announcement)
+}
+
+pub fn set(env: &Env, exit: &EmergencyExit) {
+    env.storage()
+        .instance()
+        .set(&EmergencyExitKey::Announcement, exit);
+}
+
+/// Drops the announcement together with the batch progress.
+pub fn clear(env: &Env) {
+    env.storage()
+        .instance()
+        .remove(&EmergencyExitKey::Announcement);
+    env.storage()
+        .persistent()
+        .remove(&EmergencyExitKey::ExitCursor);
+}
+
+/// Where the previous batch stopped, or `None` before the first batch.
+/// The list is walked from its end, so the escrows at and after the
+/// position were already visited and left in place.
+pub fn cursor(env: &Env) -> Option<(EscrowStatus, u32)> {
+    env.storage()
+        .persistent()
+        .get(&EmergencyExitKey::ExitCursor)
+}
+
+pub fn set_cursor(env: &Env, status: &EscrowStatus, position: u32) {
+    env.storage()
+        .persistent()
+        .set(&EmergencyExitKey::ExitCursor, &(status.clone(), position));
+}
@@ -0,0 +1,412 @@
+//! Registry of the optional entries each escrow has, and the rest of the
+//! bookkeeping every operation on an escrow reads.
+//!
+//! Features keep their per-escrow data under their own keys and write them
+//! only once used, so a typical escrow has few of them. Reading an absent
+//! key still adds it to the transaction footprint, and releases, refunds and
+//! `bump_escrow_ttl` would otherwise probe every feature's key on every
+//! call. Instead the entries an escrow has are recorded in one bitmask and
+//! all access to them goes through this module, which never touches a key
+//! the mask marks as absent.
+//!
+//! The same `EscrowEntries` record holds the ledger the escrow was last
+//! extended to, its event sequence and its positions in the paged indexes.
+//! While the escrow is stored the record is part of its `EscrowCore`, so the
+//! hot paths read and write one entry for all of them; before the escrow is
+//! locked and after it is removed it lives under `EntriesKey::Entries`.
+//! Those detached records are counted, so locking a fresh id does not look
+//! one up while none exist. An entry added after the escrow was extended is
+//! extended to the same ledger right away, keeping every entry of an escrow
+//! live as long as its record.
+
+use crate::archive::ArchiveKey;
+use crate::bonds::BondKey;
+use crate::escrow_index::IndexKey;
+use crate::funders::FunderKey;
+use crate::insurance::InsuranceKey;
+use crate::issue_links::IssueLinkKey;
+use crate::kyc::KycKey;
+use crate::ledger_deadlines::LedgerDeadlineKey;
+use crate::payout_caps::PayoutCapKey;
+use crate::referrals::ReferralKey;
+use crate::yield_strategy::YieldKey;
+use crate::{DataKey, EscrowCore, EscrowKey};
+use soroban_sdk::{contracttype, Env, IntoVal, TryFromVal, Val};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EntriesKey {
+    /// bounty_id -> EscrowEntries of an id with no escrow stored: entries
+    /// moved to it before it is locked, e.g. by a reassignment, and those
+    /// kept once the escrow is removed, so its event numbering never restarts
+    Entries(u64),
+    /// Instance: number of `Entries` records stored
+    Detached,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct EscrowEntries {
+    /// `Entry` bits stored for the escrow
+    pub mask: u64,
+    /// Ledger the escrow's entries were last extended to, 0 if never
+    pub live_until: u32,
+    /// Sequence number of the escrow's latest event, 0 before the first
+    pub event_sequence: u64,
+    /// Position in the index of all escrows
+    pub escrow_slot: u32,
+    /// Position in the depositor's index
+    pub depositor_slot: u32,
+    /// Position in the index of the escrow's status
+    pub status_slot: u32,
+}
+
+/// An optional per-escrow entry. The discriminant is its bit in the stored
+/// mask, so new cases go at the end.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Entry {
+    Metadata = 0,
+    RefundApproval = 1,
+    ReleaseApproval = 2,
+    PendingClaim = 3,
+    Milestones = 4,
+    Dispute = 5,
+    ReleaseFeeRate = 6,
+    VestingStream = 7,
+    Hook = 8,
+    IdempotencyKey = 9,
+    RefundDestination = 10,
+    Assignment = 11,
+    Submission = 12,
+    SubmissionRequired = 13,
+    ReviewPeriod = 14,
+    ExpiryNotified = 15,
+    Details = 16,
+    Approver = 17,
+    VoucherSigner = 18,
+    VoucherNonce = 19,
+    Freeze = 20,
+    ArchiveSummary = 21,
+    KycVerifier = 22,
+    IssueLink = 23,
+    LedgerDeadline = 24,
+    PayoutCaps = 25,
+    CapReleased = 26,
+    Bond = 27,
+    FunderShares = 28,
+    FundingGoal = 29,
+    InsurancePolicy = 30,
+    Referral = 31,
+    YieldPosition = 32,
+    HeldInterest = 33,
+    Payees = 34,
+    History = 35,
+}
+
+const ALL: [Entry; 36] = [
+    Entry::Metadata,
+    Entry::RefundApproval,
+    Entry::ReleaseApproval,
+    Entry::PendingClaim,
+    Entry::Milestones,
+    Entry::Dispute,
+    Entry::ReleaseFeeRate,
+    Entry::VestingStream,
+    Entry::Hook,
+    Entry::IdempotencyKey,
+    Entry::RefundDestination,
+    Entry::Assignment,
+    Entry::Submission,
+    Entry::SubmissionRequired,
+    Entry::ReviewPeriod,
+    Entry::ExpiryNotified,
+    Entry::Details,
+    Entry::Approver,
+    Entry::VoucherSigner,
+    Entry::VoucherNonce,
+    Entry::Freeze,
+    Entry::ArchiveSummary,
+    Entry::KycVerifier,
+    Entry::IssueLink,
+    Entry::LedgerDeadline,
+    Entry::PayoutCaps,
+    Entry::CapReleased,
+    Entry::Bond,
+    Entry::FunderShares,
+    Entry::FundingGoal,
+    Entry::InsurancePolicy,
+    Entry::Referral,
+    Entry::YieldPosition,
+    Entry::HeldInterest,
+    Entry::Payees,
+    Entry::History,
+];
+
+impl EscrowEntries {
+    /// Whether `entry` is stored for the escrow.
+    pub fn has(&self, entry: Entry) -> bool {
+        self.mask & entry.bit() != 0
+    }
+}
+
+impl Entry {
+    fn bit(self) -> u64 {
+        1 << self as u32
+    }
+
+    /// The storage key the entry of `bounty_id` lives under.
+    fn key(self, env: &Env, bounty_id: u64) -> Val {
+        match self {
+            Entry::Metadata => DataKey::Metadata(bounty_id).into_val(env),
+            Entry::RefundApproval => DataKey::RefundApproval(bounty_id).into_val(env),
+            Entry::ReleaseApproval => DataKey::ReleaseApproval(bounty_id).into_val(env),
+            Entry::PendingClaim => DataKey::PendingClaim(bounty_id).into_val(env),
+            Entry::Milestones => DataKey::Milestones(bounty_id).into_val(env),
+            Entry::Dispute => DataKey::Dispute(bounty_id).into_val(env),
+            Entry::ReleaseFeeRate => DataKey::EscrowReleaseFeeRate(bounty_id).into_val(env),
+            Entry::VestingStream => DataKey::VestingStream(bounty_id).into_val(env),
+            Entry::Hook => DataKey::EscrowHook(bounty_id).into_val(env),
+            Entry::IdempotencyKey => EscrowKey::IdempotencyKey(bounty_id).into_val(env),
+            Entry::RefundDestination => EscrowKey::RefundDestination(bounty_id).into_val(env),
+            Entry::Assignment => EscrowKey::Assignment(bounty_id).into_val(env),
+            Entry::Submission => EscrowKey::Submission(bounty_id).into_val(env),
+            Entry::SubmissionRequired => EscrowKey::SubmissionRequired(bounty_id).into_val(env),
+            Entry::ReviewPeriod => EscrowKey::ReviewPeriod(bounty_id).into_val(env),
+            Entry::ExpiryNotified => EscrowKey::ExpiryNotified(bounty_id).into_val(env),
+            Entry::Details => EscrowKey::Details(bounty_id).into_val(env),
+            Entry::Approver => EscrowKey::Approver(bounty_id).into_val(env),
+            Entry::VoucherSigner => EscrowKey::VoucherSigner(bounty_id).into_val(env),
+            Entry::VoucherNonce => EscrowKey::VoucherNonce(bounty_id).into_val(env),
+            Entry::Freeze => EscrowKey::Freeze(bounty_id).into_val(env),
+            Entry::ArchiveSummary => ArchiveKey::Summary(bounty_id).into_val(env),
+            Entry::KycVerifier => KycKey::Verifier(bounty_id).into_val(env),
+            Entry::IssueLink => IssueLinkKey::Link(bounty_id).into_val(env),
+            Entry::LedgerDeadline => LedgerDeadlineKey::LedgerDeadline(bounty_id).into_val(env),
+            Entry::PayoutCaps => PayoutCapKey::Caps(bounty_id).into_val(env),
+            Entry::CapReleased => PayoutCapKey::Released(bounty_id).into_val(env),
+            Entry::Bond => BondKey::Bond(bounty_id).into_val(env),
+            Entry::FunderShares => FunderKey::Shares(bounty_id).into_val(env),
+            Entry::FundingGoal => FunderKey::Goal(bounty_id).into_val(env),
+            Entry::InsurancePolicy => InsuranceKey::Policy(bounty_id).into_val(env),
+            Entry::Referral => ReferralKey::Referral(bounty_id).into_val(env),
+            Entry::YieldPosition => YieldKey::Position(bounty_id).into_val(env),
+            Entry::HeldInterest => YieldKey::HeldInterest(bounty_id).into_val(env),
+            Entry::Payees => IndexKey::Payees(bounty_id).into_val(env),
+            Entry::History => DataKey::EscrowHistory(bounty_id).into_val(env),
+        }
+    }
+}
+
+/// The bookkeeping record of `bounty_id`, empty if none is stored.
+pub fn load(env: &Env, bounty_id: u64) -> EscrowEntries {
+    let persistent = env.storage().persistent();
+    match persistent.get::<_, EscrowCore>(&DataKey::Escrow(bounty_id)) {
+        Some(core) => core.entries,
+        None => detached(env, bounty_id).unwrap_or_default(),
+    }
+}
+
+pub fn store(env: &Env, bounty_id: u64, entries: &EscrowEntries) {
+    let persistent = env.storage().persistent();
+    let key = DataKey::Escrow(bounty_id);
+    match persistent.get::<_, EscrowCore>(&key) {
+        Some(mut core) => {
+            core.entries = entries.clone();
+            persistent.set(&key, &core);
+        }
+        None => keep_detached(env, bounty_id, entries),
+    }
+}
+
+/// Apply `f` to the record of `bounty_id` and store it, reading the escrow
+/// or record holding it once.
+pub fn update<R>(env: &Env, bounty_id: u64, f: impl FnOnce(&mut EscrowEntries) -> R) -> R {
+    let persistent = env.storage().persistent();
+    let key = DataKey::Escrow(bounty_id);
+    match persistent.get::<_, EscrowCore>(&key) {
+        Some(mut core) => {
+            let result = f(&mut core.entries);
+            persistent.set(&key, &core);
+            result
+        }
+        None => {
+            let mut entries = detached(env, bounty_id).unwrap_or_default();
+            let result = f(&mut entries);
+            keep_detached(env, bounty_id, &entries);
+            result
+        }
+    }
+}
+
+/// Remove and return the record kept for `bounty_id` while no escrow is
+/// stored, for the escrow being locked under the id to take over.
+pub fn take_unlocked(env: &Env, bounty_id: u64) -> EscrowEntries {
+    match detached(env, bounty_id) {
+        Some(entries) => {
+            env.storage()
+                .persistent()
+                .remove(&EntriesKey::Entries(bounty_id));
+            set_detached_count(env, detached_count(env) - 1);
+            entries
+        }
+        None => EscrowEntries::default(),
+    }
+}
+
+/// The record kept under `EntriesKey::Entries` for `bounty_id`, if any.
+fn detached(env: &Env, bounty_id: u64) -> Option<EscrowEntries> {
+    if detached_count(env) == 0 {
+        return None;
+    }
+    env.storage()
+        .persistent()
+        .get(&EntriesKey::Entries(bounty_id))
+}
+
+/// Store `entries` under `EntriesKey::Entries`, counting a new record.
+fn keep_detached(env: &Env, bounty_id: u64, entries: &EscrowEntries) {
+    let persistent = env.storage().persistent();
+    let key = EntriesKey::Entries(bounty_id);
+    if !persistent.has(&key) {
+        set_detached_count(env, detached_count(env) + 1);
+    }
+    persistent.set(&key, entries);
+}
+
+fn detached_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&EntriesKey::Detached)
+        .unwrap_or(0)
+}
+
+fn set_detached_count(env: &Env, count: u32) {
+    env.storage().instance().set(&EntriesKey::Detached, &count);
+}
+
+/// Remove the escrow `bounty_id`, keeping its record under
+/// `EntriesKey::Entries` and live as long as before.
+pub fn remove_escrow(env: &Env, bounty_id: u64) {
+    let persistent = env.storage().persistent();
+    let entries = load(env, bounty_id);
+    persistent.remove(&DataKey::Escrow(bounty_id));
+    keep_detached(env, bounty_id, &entries);
+    let key = EntriesKey::Entries(bounty_id);
+    let extend_to = entries.live_until.saturating_sub(env.ledger().sequence());
+    if extend_to > 0 {
+        persistent.extend_ttl(&key, extend_to, extend_to);
+    }
+}
+
+/// Whether `bounty_id` has `entry`.
+pub fn has(env: &Env, bounty_id: u64, entry: Entry) -> bool {
+    load(env, bounty_id).has(entry)
+}
+
+/// The value of `entry` for `bounty_id`, if stored.
+pub fn get<V: TryFromVal<Env, Val>>(env: &Env, bounty_id: u64, entry: Entry) -> Option<V> {
+    get_in(env, bounty_id, &load(env, bounty_id), entry)
+}
+
+/// `get` for a caller holding the record `entries` of `bounty_id`.
+pub fn get_in<V: TryFromVal<Env, Val>>(
+    env: &Env,
+    bounty_id: u64,
+    entries: &EscrowEntries,
+    entry: Entry,
+) -> Option<V> {
+    if !entries.has(entry) {
+        return None;
+    }
+    env.storage().persistent().get(&entry.key(env, bounty_id))
+}
+
+/// Store `value` as `entry` of `bounty_id`. A new entry is extended to the
+/// ledger the escrow's other entries live until.
+pub fn set<V: IntoVal<Env, Val>>(env: &Env, bounty_id: u64, entry: Entry, value: &V) {
+    let mut entries = load(env, bounty_id);
+    if put(env, bounty_id, &mut entries, entry, value) {
+        store(env, bounty_id, &entries);
+    }
+}
+
+/// `set` for a caller holding the record: `entries` is updated and the
+/// caller stores it if this returns `true`.
+pub fn put<V: IntoVal<Env, Val>>(
+    env: &Env,
+    bounty_id: u64,
+    entries: &mut EscrowEntries,
+    entry: Entry,
+    value: &V,
+) -> bool {
+    let persistent = env.storage().persistent();
+    let key = entry.key(env, bounty_id);
+    persistent.set(&key, value);
+    if entries.has(entry) {
+        return false;
+    }
+    entries.mask |= entry.bit();
+    let extend_to = entries.live_until.saturating_sub(env.ledger().sequence());
+    if extend_to > 0 {
+        persistent.extend_ttl(&key, extend_to, extend_to);
+    }
+    true
+}
+
+/// Remove `entry` of `bounty_id`; a no-op if it is not stored.
+pub fn remove(env: &Env, bounty_id: u64, entry: Entry) {
+    let mut entries = load(env, bounty_id);
+    if take(env, bounty_id, &mut entries, entry) {
+        store(env, bounty_id, &entries);
+    }
+}
+
+/// `remove` for a caller holding the record: `entries` is updated and the
+/// caller stores it if this returns `true`.
+pub fn take(env: &Env, bounty_id: u64, entries: &mut EscrowEntries, entry: Entry) -> bool {
+    if !entries.has(entry) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .remove(&entry.key(env, bounty_id));
+    entries.mask &= !entry.bit();
+    true
+}
+
+/// Move `entry` from bounty `from` to bounty `to` if `from` has it.
+pub fn transfer(env: &Env, from: u64, to: u64, entry: Entry) {
+    if let Some(value) = get::<Val>(env, from, entry) {
+        set(env, to, entry, &value);
+        remove(env, from, entry);
+    }
+}
+
+/// Extend every entry `bounty_id` has, and the escrow or record holding
+/// them, to `extend_to` ledgers. `entries` is the record as it is stored.
+pub fn extend_ttl(env: &Env, bounty_id: u64, entries: &EscrowEntries, extend_to: u32) {
+    let persistent = env.storage().persistent();
+    for entry in ALL {
+        if entries.has(entry) {
+            persistent.extend_ttl(&entry.key(env, bounty_id), extend_to, extend_to);
+        }
+    }
+    let core = DataKey::Escrow(bounty_id);
+    if persistent.has(&core) {
+        persistent.extend_ttl(&core, extend_to, extend_to);
+    } else {
+        persistent.extend_ttl(&EntriesKey::Entries(bounty_id), extend_to, extend_to);
+    }
+}
+
+/// Set the bits of `entries` from the keys actually stored for `bounty_id`,
+/// for escrows written before the registry existed.
+pub fn record_stored(env: &Env, bounty_id: u64, entries: &mut EscrowEntries) {
+    let persistent = env.storage().persistent();
+    for entry in ALL {
+        if persistent.has(&entry.key(env, bounty_id)) {
+            entries.mask |= entry.bit();
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Paged lists of bounty ids: every escrow, escrows by depositor, by status
+//! and by contributor paid.
+//!
+//! A list is stored as full pages of `PAGE_SIZE` ids plus a tail holding
+//! the ids after the last full page, so adding or removing an id reads and
+//! writes at most one page and the tail however long the list grows.
+//! Removing an id moves the last id of the list into its position; callers
+//! keep each escrow's positions in its `EscrowEntries` record and update
+//! them for the id that moved. The contributor lists only ever grow; an
+//! escrow that can still pay out again remembers whose lists it is on.
+
+use crate::EscrowStatus;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Ids per full page.
+pub const PAGE_SIZE: u32 = 32;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexId {
+    /// Every escrow, in lock order until ids are removed
+    All,
+    Depositor(Address),
+    Status(EscrowStatus),
+    /// Escrows that paid out to the contributor, each once
+    Contributor(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IndexKey {
+    /// list -> (number of full pages, Vec<u64> of the ids after them)
+    Tail(IndexId),
+    /// (list, page number) -> Vec<u64> of PAGE_SIZE ids
+    Page(IndexId, u32),
+    /// bounty_id -> Vec<Address> of contributors whose list has the escrow,
+    /// kept while it has funds left to pay them again
+    Payees(u64),
+}
+
+fn tail(env: &Env, index: &IndexId) -> (u32, Vec<u64>) {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::Tail(index.clone()))
+        .unwrap_or((0, Vec::new(env)))
+}
+
+fn set_tail(env: &Env, index: &IndexId, pages: u32, ids: &Vec<u64>) {
+    let key = IndexKey::Tail(index.clone());
+    if pages == 0 && ids.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &(pages, ids.clone()));
+    }
+}
+
+fn page(env: &Env, index: &IndexId, page: u32) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&IndexKey::Page(index.clone(), page))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Number of ids in `index`.
+pub fn len(env: &Env, index: &IndexId) -> u32 {
+    let (pages, ids) = tail(env, index);
+    pages * PAGE_SIZE + ids.len()
+}
+
+/// Append `bounty_id` to `index` and return its position.
+pub fn push(env: &Env, index: &IndexId, bounty_id: u64) -> u32 {
+    let (mut pages, mut ids) = tail(env, index);
+    let pos = pages * PAGE_SIZE + ids.len();
+    ids.push_back(bounty_id);
+    if ids.len() == PAGE_SIZE {
+        env.storage()
+            .persistent()
+            .set(&IndexKey::Page(index.clone(), pages), &ids);
+        pages += 1;
+        ids = Vec::new(env);
+    }
+    set_tail(env, index, pages, &ids);
+    pos
+}
+
+/// Remove the id at `pos` from `index` by moving the last id into its
+/// place. Returns the id that moved to `pos`, or `None` if `pos` was the
+/// last position (or past the end).
+pub fn swap_remove(env: &Env, index: &IndexId, pos: u32) -> Option<u64> {
+    let (mut pages, mut ids) = tail(env, index);
+    if pos >= pages * PAGE_SIZE + ids.len() {
+        return None;
+    }
+    if ids.is_empty() {
+        pages -= 1;
+        ids = page(env, index, pages);
+        env.storage()
+            .persistent()
+            .remove(&IndexKey::Page(index.clone(), pages));
+    }
+    let first = pages * PAGE_SIZE;
+    let last = ids.pop_back().unwrap();
+    let moved = if pos == first + ids.len() {
+        None
+    } else if pos >= first {
+        ids.set(pos - first, last);
+        Some(last)
+    } else {
+        let mut full = page(env, index, pos / PAGE_SIZE);
+        full.set(pos % PAGE_SIZE, last);
+        env.storage()
+            .persistent()
+            .set(&IndexKey::Page(index.clone(), pos / PAGE_SIZE), &full);
+        Some(last)
+    };
+    set_tail(env, index, pages, &ids);
+    moved
+}
+
+/// Up to `limit` ids of `index` from position `offset` on.
+pub fn range(env: &Env, index: &IndexId, offset: u32, limit: u32) -> Vec<u64> {
+    let (pages, tail_ids) = tail(env, index);
+    let end = offset
+        .saturating_add(limit)
+        .min(pages * PAGE_SIZE + tail_ids.len());
+    let mut result = Vec::new(env);
+    let mut pos = offset;
+    while pos < end {
+        let number = pos / PAGE_SIZE;
+        let ids = if number < pages {
+            page(env, index, number)
+        } else {
+            tail_ids.clone()
+        };
+        let start = pos - number * PAGE_SIZE;
+        let stop = (end - number * PAGE_SIZE).min(ids.len());
+        result.append(&ids.slice(start..stop));
+        pos = number * PAGE_SIZE + stop;
+    }
+    result
+}
+
+/// Every id of `index`. Reads every page, so only for callers that visit
+/// all of them anyway.
+pub fn all(env: &Env, index: &IndexId) -> Vec<u64> {
+    range(env, index, 0, u32::MAX)
+}
@@ -1,8 +1,151 @@
-use crate::{CapabilityAction, DisputeOutcome, DisputeReason};
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+//! # Escrow Events
+//!
+//! Every state transition publishes a typed event so indexers can rebuild the
+//! full history of an escrow from events alone. Topics are a tuple whose first
+//! element is a short symbol naming the event; per-escrow events carry the
+//! `bounty_id` as the second topic. The data payload is the struct listed.
+//!
+//! The topics listed below are followed by `EVENT_SCHEMA_VERSION`, and for
+//! per-escrow events by the escrow's sequence number after that, e.g.
+//! `("f_lock", bounty_id, 3, 1)`. Sequence numbers start at 1 and grow by
+//! one with each event of the escrow, so an indexer that sees a gap knows
+//! it missed events and can replay the ledgers in between;
+//! `get_event_sequence` returns the latest one.
+//!
+//! | Transition              | Topics                          | Data                      |
+//! |-------------------------|---------------------------------|---------------------------|
+//! | init                    | `("init",)`                     | `BountyEscrowInitialized` |
+//! | lock                    | `("f_lock", bounty_id)`         | `FundsLocked`             |
+//! | top-up                  | `("esc_incr", bounty_id)`       | `EscrowIncreased`         |
+//! | funder contribution     | `("esc_fund", bounty_id)`       | `EscrowContributed`       |
+//! | funding goal set        | `("fund_goal", bounty_id)`      | `FundingGoalSet`          |
+//! | funding round opened    | `("qf_new", round_id)`          | `FundingRoundCreated`     |
+//! | round match paid        | `("qf_match", bounty_id)`       | `RoundMatchPaid`          |
+//! | funding round closed    | `("qf_close", round_id)`        | `FundingRoundClosed`      |
+//! | escrow insured          | `("ins_buy", bounty_id)`        | `EscrowInsured`           |
+//! | insurance pool funded   | `("ins_fund",)`                 | `InsurancePoolFunded`     |
+//! | insurance claim paid    | `("ins_pay", bounty_id)`        | `InsuranceClaimPaid`      |
+//! | referrer recorded       | `("ref_set", bounty_id)`        | `ReferrerRecorded`        |
+//! | issue linked            | `("iss_link", bounty_id)`       | `IssueLinked`             |
+//! | issue link verified     | `("iss_ok", bounty_id)`         | `IssueLinkVerified`       |
+//! | payout review approved  | `("pay_rev", bounty_id)`        | `PayoutReviewApproved`    |
+//! | referral fee paid       | `("ref_paid", bounty_id)`       | `ReferralPaid`            |
+//! | assign (claim created)  | `("claim", "created")`          | `ClaimCreated`            |
+//! | claim executed          | `("claim", "done")`             | `ClaimExecuted`           |
+//! | claim cancelled         | `("claim", "cancel")`           | `ClaimCancelled`          |
+//! | contributor assigned    | `("asg_set", bounty_id)`        | `ContributorAssigned`     |
+//! | assignment accepted     | `("asg_acc", bounty_id)`        | `AssignmentAccepted`      |
+//! | work submitted          | `("work_sub", bounty_id)`       | `WorkSubmitted`           |
+//! | release (full / split)  | `("f_rel", bounty_id)`          | `FundsReleased`           |
+//! | partial_release         | `("f_prel", bounty_id)`         | `FundsPartiallyReleased`  |
+//! | stream started          | `("strm_new", bounty_id)`       | `StreamStarted`           |
+//! | vested withdrawal       | `("f_prel", bounty_id)`         | `FundsPartiallyReleased`  |
+//! | milestone approved      | `("ms_appr", bounty_id)`        | `MilestoneApproved`       |
+//! | milestone released      | `("ms_rel", bounty_id)`         | `MilestoneReleased`       |
+//! | release approval (msig) | `("approval", bounty_id)`       | `ApprovalAdded`           |
+//! | refund_approved         | `("ref_appr", bounty_id)`       | `RefundApproved`          |
+//! | refund destination set  | `("ref_dest", bounty_id)`       | `RefundDestinationSet`    |
+//! | refund                  | `("f_ref", bounty_id)`          | `FundsRefunded`           |
+//! | split refund share      | `("f_rel", bounty_id)`          | `FundsReleased`           |
+//! | cancel                  | `("esc_cncl", bounty_id)`       | `EscrowCancelled`         |
+//! | status changed          | `("status", bounty_id)`         | `StatusChanged`           |
+//! | moved to new id         | `("esc_move", old_bounty_id)`   | `EscrowReassigned`        |
+//! | migrated out            | `("esc_out", bounty_id)`        | `EscrowMigratedOut`       |
+//! | migrated in             | `("esc_in", bounty_id)`         | `EscrowMigratedIn`        |
+//! | deadline extended       | `("dl_ext", bounty_id)`         | `DeadlineExtended`        |
+//! | escrow frozen           | `("esc_frz", bounty_id)`        | `EscrowFrozen`            |
+//! | escrow unfrozen         | `("esc_ufrz", bounty_id)`       | `EscrowUnfrozen`          |
+//! | dispute opened          | `("dsp_open", bounty_id)`       | `DisputeOpened`           |
+//! | dispute resolved        | `("dsp_res", bounty_id)`        | `DisputeResolved`         |
+//! | bond slashed            | `("bond_slsh", bounty_id)`      | `BondSlashed`             |
+//! | yield deposited         | `("yld_dep", bounty_id)`        | `YieldDeposited`          |
+//! | yield settled           | `("yld_set", bounty_id)`        | `YieldSettled`            |
+//! | rescue                  | `("em_wtd",)`                   | `EmergencyWithdrawEvent`  |
+//! | untracked rescue        | `("rescue", token)`             | `TokensRescued`           |
+//! | untracked absorbed      | `("absorb", bounty_id)`         | `UntrackedAbsorbed`       |
+//! | balance shortfall       | `("inv_bal", token)`            | `BalanceInvariantViolated` |
+//! | shortfall written off   | `("reconcile", bounty_id)`      | `EscrowReconciled`        |
+//! | rescue requested        | `("rsc_req",)`                  | `RescueRequested`         |
+//! | rescue executed         | `("rsc_exec",)`                 | `RescueExecuted`          |
+//! | rescue cancelled        | `("rsc_cncl",)`                 | `RescueCancelled`         |
+//! | exit announced          | `("exit_ann",)`                 | `EmergencyExitAnnounced`  |
+//! | exit executed (refunds) | `("exit_exec",)`                | `EmergencyExitExecuted`   |
+//! | exit cancelled          | `("exit_cncl",)`                | `EmergencyExitCancelled`  |
+//! | code upgraded           | `("upgrade",)`                  | `ContractUpgraded`        |
+//! | storage migrated        | `("migrate",)`                  | `SchemaMigrated`          |
+//! | stale escrows swept     | `("sweep",)`                    | `EscrowsSwept`            |
+//! | escrow expiring         | `("esc_exp", bounty_id)`        | `EscrowExpiring`          |
+//! | fee collected           | `("fee",)`                      | `FeeCollected`            |
+//! | fees withdrawn          | `("fee_wdraw",)`                | `FeesWithdrawn`           |
+//! | batch lock / release    | `("b_lock",)` / `("b_rel",)`    | `BatchFundsLocked` / `BatchFundsReleased` |
+//! | batch refund            | `("b_ref",)`                    | `BatchFundsRefunded`      |
+//! | admin op queued         | `("adm_q", op_id)`              | `AdminOpQueued`           |
+//! | admin op executed       | `("adm_exec", op_id)`           | `AdminOpExecuted`         |
+//! | admin op cancelled      | `("adm_cncl", op_id)`           | `AdminOpCancelled`        |
+//! | council set / changed   | `("cncl_set",)`                 | `CouncilUpdated`          |
+//! | council op proposed     | `("cncl_prop", proposal_id)`    | `CouncilOpProposed`       |
+//! | council op approved     | `("cncl_appr", proposal_id)`    | `CouncilOpApproved`       |
+//! | council op executed     | `("cncl_exec", proposal_id)`    | `CouncilOpExecuted`       |
+//! | council op cancelled    | `("cncl_cncl", proposal_id)`    | `CouncilOpCancelled`      |
+//!
+//! Configuration changes (`fee_cfg`, `trsy_prop`, `trsy_acc`, `pause`,
+//! `role_gr`, `role_rv`, `blocklist`, `dep_allow`) and capability / claim
+//! ticket events follow the same conventions.
+
+use crate::escrow_entries::{self, EscrowEntries};
+use crate::{
+    AdminOp, CapabilityAction, DisputeOutcome, DisputeReason, EscrowStatus, RefundMode, Role,
+};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, IntoVal, String, Val, Vec};
 
 pub const EVENT_VERSION_V2: u32 = 2;
 
+/// Version of the event layout, appended to the topics of every event.
+/// Payloads with a `version` field keep reporting their own (v2) layout.
+pub const EVENT_SCHEMA_VERSION: u32 = 3;
+
+/// Publish a contract-wide event, appending the schema version to `topics`.
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let mut topics: Vec<Val> = topics.into_val(env);
+    topics.push_back(EVENT_SCHEMA_VERSION.into_val(env));
+    env.events().publish(topics, data);
+}
+
+/// Publish an event of escrow `bounty_id`, appending the schema version and
+/// the escrow's next sequence number to `topics`.
+pub fn publish_escrow<T, D>(env: &Env, bounty_id: u64, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    escrow_entries::update(env, bounty_id, |entries| {
+        publish_escrow_in(env, entries, topics, data)
+    });
+}
+
+/// `publish_escrow` for a caller about to store the escrow's record
+/// `entries`, which takes the sequence number.
+pub fn publish_escrow_in<T, D>(env: &Env, entries: &mut EscrowEntries, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    entries.event_sequence += 1;
+    let mut topics: Vec<Val> = topics.into_val(env);
+    topics.push_back(EVENT_SCHEMA_VERSION.into_val(env));
+    topics.push_back(entries.event_sequence.into_val(env));
+    env.events().publish(topics, data);
+}
+
+/// Sequence number of the latest event of `bounty_id`, 0 before the first.
+pub fn sequence(env: &Env, bounty_id: u64) -> u64 {
+    escrow_entries::load(env, bounty_id).event_sequence
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BountyEscrowInitialized {
@@ -14,7 +157,7 @@ pub struct BountyEscrowInitialized {
 
 pub fn emit_bounty_initialized(env: &Env, event: BountyEscrowInitialized) {
     let topics = (symbol_short!("init"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -28,8 +171,15 @@ pub struct FundsLocked {
 }
 
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
+    escrow_entries::update(env, event.bounty_id, |entries| {
+        emit_funds_locked_in(env, entries, event)
+    });
+}
+
+/// Takes the escrow's record, which the caller then stores.
+pub fn emit_funds_locked_in(env: &Env, entries: &mut EscrowEntries, event: FundsLocked) {
     let topics = (symbol_short!("f_lock"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_escrow_in(env, entries, topics, event);
 }
 
 #[contracttype]
@@ -44,7 +194,23 @@ pub struct FundsReleased {
 
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
     let topics = (symbol_short!("f_rel"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct FundsPartiallyReleased {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub remaining_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_funds_partially_released(env: &Env, event: FundsPartiallyReleased) {
+    let topics = (symbol_short!("f_prel"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
 }
 
 #[contracttype]
@@ -59,7 +225,23 @@ pub struct FundsRefunded {
 
 pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
     let topics = (symbol_short!("f_ref"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct RefundApproved {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub mode: RefundMode,
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_approved(env: &Env, event: RefundApproved) {
+    let topics = (symbol_short!("ref_appr"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
 }
 
 #[contracttype]
@@ -81,7 +263,21 @@ pub struct FeeCollected {
 
 pub fn emit_fee_collected(env: &Env, event: FeeCollected) {
     let topics = (symbol_short!("fee"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub amount: i128,
+    pub treasury: Address,
+    pub remaining: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_fees_withdrawn(env: &Env, event: FeesWithdrawn) {
+    let topics = (symbol_short!("fee_wdraw"),);
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -94,7 +290,7 @@ pub struct BatchFundsLocked {
 
 pub fn emit_batch_funds_locked(env: &Env, event: BatchFundsLocked) {
     let topics = (symbol_short!("b_lock"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -109,7 +305,33 @@ pub struct FeeConfigUpdated {
 
 pub fn emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
     let topics = (symbol_short!("fee_cfg"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryProposed {
+    pub current: Address,
+    pub proposed: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_treasury_proposed(env: &Env, event: TreasuryProposed) {
+    let topics = (symbol_short!("trsy_prop"), event.proposed.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryAccepted {
+    pub previous: Address,
+    pub treasury: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_treasury_accepted(env: &Env, event: TreasuryAccepted) {
+    let topics = (symbol_short!("trsy_acc"), event.treasury.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -122,7 +344,20 @@ pub struct BatchFundsReleased {
 
 pub fn emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
     let topics = (symbol_short!("b_rel"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchFundsRefunded {
+    pub count: u32,
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_funds_refunded(env: &Env, event: BatchFundsRefunded) {
+    let topics = (symbol_short!("b_ref"),);
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -136,7 +371,7 @@ pub struct ApprovalAdded {
 
 pub fn emit_approval_added(env: &Env, event: ApprovalAdded) {
     let topics = (symbol_short!("approval"), event.bounty_id);
-    env.events().publish(topics, event.clone());
+    publish_escrow(env, event.bounty_id, topics, event);
 }
 
 #[contracttype]
@@ -184,7 +419,7 @@ pub struct TicketIssued {
 
 pub fn emit_ticket_issued(env: &Env, event: TicketIssued) {
     let topics = (symbol_short!("tkt_iss"), event.ticket_id);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 /// Event emitted when a beneficiary claims their reward using a ticket
@@ -200,12 +435,40 @@ pub struct TicketClaimed {
 
 pub fn emit_ticket_claimed(env: &Env, event: TicketClaimed) {
     let topics = (symbol_short!("tkt_clm"), event.ticket_id);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 pub fn emit_pause_state_changed(env: &Env, event: crate::PauseStateChanged) {
     let topics = (symbol_short!("pause"), event.operation.clone());
-    env.events().publish(topics, event);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlocklistUpdated {
+    pub address: Address,
+    pub blocked: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_blocklist_updated(env: &Env, event: BlocklistUpdated) {
+    let topics = (symbol_short!("blocklist"), event.address.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositorAllowlistUpdated {
+    pub depositor: Address,
+    pub allowed: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_depositor_allowlist_updated(env: &Env, event: DepositorAllowlistUpdated) {
+    let topics = (symbol_short!("dep_allow"), event.depositor.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -219,7 +482,7 @@ pub struct EmergencyWithdrawEvent {
 
 pub fn emit_emergency_withdraw(env: &Env, event: EmergencyWithdrawEvent) {
     let topics = (symbol_short!("em_wtd"),);
-    env.events().publish(topics, event.clone());
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -238,7 +501,7 @@ pub struct CapabilityIssued {
 
 pub fn emit_capability_issued(env: &Env, event: CapabilityIssued) {
     let topics = (symbol_short!("cap_new"), event.capability_id);
-    env.events().publish(topics, event);
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -256,7 +519,7 @@ pub struct CapabilityUsed {
 
 pub fn emit_capability_used(env: &Env, event: CapabilityUsed) {
     let topics = (symbol_short!("cap_use"), event.capability_id);
-    env.events().publish(topics, event);
+    publish(env, topics, event);
 }
 
 #[contracttype]
@@ -269,5 +532,861 @@ pub struct CapabilityRevoked {
 
 pub fn emit_capability_revoked(env: &Env, event: CapabilityRevoked) {
     let topics = (symbol_short!("cap_rev"), event.capability_id);
-    env.events().publish(topics, event);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneApproved {
+    pub bounty_id: u64,
+    pub milestone_index: u32,
+    pub amount: i128,
+    pub approved_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_milestone_approved(env: &Env, event: MilestoneApproved) {
+    let topics = (symbol_short!("ms_appr"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneReleased {
+    pub bounty_id: u64,
+    pub milestone_index: u32,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_milestone_released(env: &Env, event: MilestoneReleased) {
+    let topics = (symbol_short!("ms_rel"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeOpened {
+    pub bounty_id: u64,
+    pub raised_by: Address,
+    pub reason_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_opened(env: &Env, event: DisputeOpened) {
+    let topics = (symbol_short!("dsp_open"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeResolved {
+    pub bounty_id: u64,
+    pub arbiter: Address,
+    pub contributor_amount: i128,
+    pub depositor_amount: i128,
+    pub contributor_share_bps: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_resolved(env: &Env, event: DisputeResolved) {
+    let topics = (symbol_short!("dsp_res"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFrozen {
+    pub bounty_id: u64,
+    pub frozen_by: Address,
+    pub reason_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_frozen(env: &Env, event: EscrowFrozen) {
+    let topics = (symbol_short!("esc_frz"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowUnfrozen {
+    pub bounty_id: u64,
+    pub unfrozen_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_unfrozen(env: &Env, event: EscrowUnfrozen) {
+    let topics = (symbol_short!("esc_ufrz"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleGranted {
+    pub role: Role,
+    pub account: Address,
+    pub granted_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_role_granted(env: &Env, event: RoleGranted) {
+    let topics = (symbol_short!("role_gr"), event.account.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub account: Address,
+    pub revoked_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_role_revoked(env: &Env, event: RoleRevoked) {
+    let topics = (symbol_short!("role_rv"), event.account.clone());
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub bounty_id: u64,
+    pub old_deadline: u64,
+    pub new_deadline: u64,
+    pub extended_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_deadline_extended(env: &Env, event: DeadlineExtended) {
+    let topics = (symbol_short!("dl_ext"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowIncreased {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub additional_amount: i128,
+    pub new_amount: i128,
+    pub remaining_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_increased(env: &Env, event: EscrowIncreased) {
+    let topics = (symbol_short!("esc_incr"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowContributed {
+    pub bounty_id: u64,
+    pub funder: Address,
+    pub amount: i128,
+    pub new_amount: i128,
+    pub remaining_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_contributed(env: &Env, event: EscrowContributed) {
+    let topics = (symbol_short!("esc_fund"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingGoalSet {
+    pub bounty_id: u64,
+    pub goal: i128,
+    pub deadline: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_funding_goal_set(env: &Env, event: FundingGoalSet) {
+    let topics = (symbol_short!("fund_goal"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundCreated {
+    pub round_id: u64,
+    pub sponsor: Address,
+    pub matching_pool: i128,
+    pub bounty_ids: Vec<u64>,
+    pub ends_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_funding_round_created(env: &Env, event: FundingRoundCreated) {
+    let topics = (symbol_short!("qf_new"), event.round_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundMatchPaid {
+    pub round_id: u64,
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_round_match_paid(env: &Env, event: RoundMatchPaid) {
+    let topics = (symbol_short!("qf_match"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// `returned` went back to the sponsor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingRoundClosed {
+    pub round_id: u64,
+    pub matched: i128,
+    pub returned: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_funding_round_closed(env: &Env, event: FundingRoundClosed) {
+    let topics = (symbol_short!("qf_close"), event.round_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInsured {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub coverage: i128,
+    pub premium: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_insured(env: &Env, event: EscrowInsured) {
+    let topics = (symbol_short!("ins_buy"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// `pool` is the pool balance after the top-up.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePoolFunded {
+    pub funder: Address,
+    pub amount: i128,
+    pub pool: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_insurance_pool_funded(env: &Env, event: InsurancePoolFunded) {
+    let topics = (symbol_short!("ins_fund"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceClaimPaid {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub arbiter: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_insurance_claim_paid(env: &Env, event: InsuranceClaimPaid) {
+    let topics = (symbol_short!("ins_pay"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferrerRecorded {
+    pub bounty_id: u64,
+    pub referrer: Address,
+    pub fee_bps: u32,
+}
+
+pub fn emit_referrer_recorded(env: &Env, event: ReferrerRecorded) {
+    let topics = (symbol_short!("ref_set"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// Published by `save_escrow` when it stores a new status, before the event
+/// of the operation that caused it. Carries a `version` like the
+/// lock, release and refund events it accompanies.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusChanged {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub from: EscrowStatus,
+    pub to: EscrowStatus,
+    pub timestamp: u64,
+}
+
+/// Takes the escrow's record, which `save_escrow` is about to store.
+pub fn emit_status_changed(env: &Env, entries: &mut EscrowEntries, event: StatusChanged) {
+    let topics = (symbol_short!("status"), event.bounty_id);
+    publish_escrow_in(env, entries, topics, event);
+}
+
+/// Escrow linked to a GitHub issue by `set_issue_link`, not yet verified.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueLinked {
+    pub bounty_id: u64,
+    pub repo: String,
+    pub issue_number: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_issue_linked(env: &Env, event: IssueLinked) {
+    let topics = (symbol_short!("iss_link"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueLinkVerified {
+    pub bounty_id: u64,
+    pub repo: String,
+    pub issue_number: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_issue_link_verified(env: &Env, event: IssueLinkVerified) {
+    let topics = (symbol_short!("iss_ok"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// `reviewed` is what partial releases paid since the previous review.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutReviewApproved {
+    pub bounty_id: u64,
+    pub reviewed: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_payout_review_approved(env: &Env, event: PayoutReviewApproved) {
+    let topics = (symbol_short!("pay_rev"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralPaid {
+    pub bounty_id: u64,
+    pub referrer: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_referral_paid(env: &Env, event: ReferralPaid) {
+    let topics = (symbol_short!("ref_paid"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowCancelled {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_cancelled(env: &Env, event: EscrowCancelled) {
+    let topics = (symbol_short!("esc_cncl"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// Escrow moved to the instance `target` by `migrate_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMigratedOut {
+    pub bounty_id: u64,
+    pub target: Address,
+    pub target_bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_migrated_out(env: &Env, event: EscrowMigratedOut) {
+    let topics = (symbol_short!("esc_out"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// Escrow taken over from the instance `source` by `receive_migrated_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMigratedIn {
+    pub bounty_id: u64,
+    pub source: Address,
+    pub source_bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_migrated_in(env: &Env, event: EscrowMigratedIn) {
+    let topics = (symbol_short!("esc_in"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// Funds of `old_bounty_id` moved to `new_bounty_id` by `reassign_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReassigned {
+    pub old_bounty_id: u64,
+    pub new_bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_reassigned(env: &Env, event: EscrowReassigned) {
+    let topics = (symbol_short!("esc_move"), event.old_bounty_id);
+    publish_escrow(env, event.old_bounty_id, topics, event);
+}
+
+/// `destination` is `None` when refunds go back to the depositor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundDestinationSet {
+    pub bounty_id: u64,
+    pub destination: Option<Address>,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_destination_set(env: &Env, event: RefundDestinationSet) {
+    let topics = (symbol_short!("ref_dest"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorAssigned {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub accept_by: u64,
+    pub bond: i128,
+}
+
+pub fn emit_contributor_assigned(env: &Env, event: ContributorAssigned) {
+    let topics = (symbol_short!("asg_set"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AssignmentAccepted {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_assignment_accepted(env: &Env, event: AssignmentAccepted) {
+    let topics = (symbol_short!("asg_acc"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondSlashed {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bond_slashed(env: &Env, event: BondSlashed) {
+    let topics = (symbol_short!("bond_slsh"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkSubmitted {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub submission_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+pub fn emit_work_submitted(env: &Env, event: WorkSubmitted) {
+    let topics = (symbol_short!("work_sub"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminOpQueued {
+    pub op_id: u64,
+    pub op: AdminOp,
+    pub eta: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_op_queued(env: &Env, event: AdminOpQueued) {
+    let topics = (symbol_short!("adm_q"), event.op_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminOpExecuted {
+    pub op_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_op_executed(env: &Env, event: AdminOpExecuted) {
+    let topics = (symbol_short!("adm_exec"), event.op_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminOpCancelled {
+    pub op_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_admin_op_cancelled(env: &Env, event: AdminOpCancelled) {
+    let topics = (symbol_short!("adm_cncl"), event.op_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilUpdated {
+    pub members: Vec<Address>,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_council_updated(env: &Env, event: CouncilUpdated) {
+    let topics = (symbol_short!("cncl_set"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilOpProposed {
+    pub proposal_id: u64,
+    pub op: AdminOp,
+    pub proposer: Address,
+    pub eta: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_council_op_proposed(env: &Env, event: CouncilOpProposed) {
+    let topics = (symbol_short!("cncl_prop"), event.proposal_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilOpApproved {
+    pub proposal_id: u64,
+    pub approver: Address,
+    /// Approvals recorded so far, including this one.
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_council_op_approved(env: &Env, event: CouncilOpApproved) {
+    let topics = (symbol_short!("cncl_appr"), event.proposal_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilOpExecuted {
+    pub proposal_id: u64,
+    pub executor: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_council_op_executed(env: &Env, event: CouncilOpExecuted) {
+    let topics = (symbol_short!("cncl_exec"), event.proposal_id);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilOpCancelled {
+    pub proposal_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_council_op_cancelled(env: &Env, event: CouncilOpCancelled) {
+    let topics = (symbol_short!("cncl_cncl"), event.proposal_id);
+    publish(env, topics, event);
+}
+
+/// Published on every `rescue_untracked_tokens` call, with `amount` 0 when
+/// there was nothing to rescue.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokensRescued {
+    pub token: Address,
+    pub amount: i128,
+    pub recipient: Address,
+    pub rescued_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_tokens_rescued(env: &Env, event: TokensRescued) {
+    let topics = (symbol_short!("rescue"), event.token.clone());
+    publish(env, topics, event);
+}
+
+/// Untracked escrow tokens added to the community pool escrow by
+/// `absorb_untracked_into`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UntrackedAbsorbed {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub absorbed_by: Address,
+    pub new_amount: i128,
+    pub remaining_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_untracked_absorbed(env: &Env, event: UntrackedAbsorbed) {
+    let topics = (symbol_short!("absorb"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// Alert raised when the contract holds less of the escrow token than the
+/// value locked in escrows. Releases are paused when this fires.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceInvariantViolated {
+    pub token: Address,
+    pub tracked: i128,
+    pub actual: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_balance_invariant_violated(env: &Env, event: BalanceInvariantViolated) {
+    let topics = (symbol_short!("inv_bal"), event.token.clone());
+    publish(env, topics, event);
+}
+
+/// Shortfall written off against an escrow by `reconcile_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowReconciled {
+    pub bounty_id: u64,
+    pub written_off: i128,
+    pub remaining_amount: i128,
+    pub reconciled_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_reconciled(env: &Env, event: EscrowReconciled) {
+    let topics = (symbol_short!("reconcile"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueRequested {
+    pub amount: i128,
+    pub recipient: Address,
+    pub requested_by: Address,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_rescue_requested(env: &Env, event: RescueRequested) {
+    let topics = (symbol_short!("rsc_req"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueExecuted {
+    pub amount: i128,
+    pub recipient: Address,
+    pub executed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_rescue_executed(env: &Env, event: RescueExecuted) {
+    let topics = (symbol_short!("rsc_exec"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueCancelled {
+    pub amount: i128,
+    pub cancelled_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_rescue_cancelled(env: &Env, event: RescueCancelled) {
+    let topics = (symbol_short!("rsc_cncl"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyExitAnnounced {
+    pub announced_by: Address,
+    pub executable_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_emergency_exit_announced(env: &Env, event: EmergencyExitAnnounced) {
+    let topics = (symbol_short!("exit_ann"),);
+    publish(env, topics, event);
+}
+
+/// Summary of one `emergency_withdraw_all` batch; each depositor is also
+/// notified with a per-escrow `FundsRefunded`. `finished` is set on the
+/// batch that completes the exit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyExitExecuted {
+    pub refunded_count: u32,
+    pub finished: bool,
+    pub total_amount: i128,
+    pub executed_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_emergency_exit_executed(env: &Env, event: EmergencyExitExecuted) {
+    let topics = (symbol_short!("exit_exec"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyExitCancelled {
+    pub cancelled_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_emergency_exit_cancelled(env: &Env, event: EmergencyExitCancelled) {
+    let topics = (symbol_short!("exit_cncl"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub new_wasm_hash: BytesN<32>,
+    /// `get_version` of the code being replaced.
+    pub previous_version: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_upgraded(env: &Env, event: ContractUpgraded) {
+    let topics = (symbol_short!("upgrade"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Number of records this call rewrote in the new layout.
+    pub migrated_count: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_schema_migrated(env: &Env, event: SchemaMigrated) {
+    let topics = (symbol_short!("migrate"),);
+    publish(env, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowsSwept {
+    /// Escrows archived by this call; see `get_archived_escrow`.
+    pub bounty_ids: Vec<u64>,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrows_swept(env: &Env, event: EscrowsSwept) {
+    let topics = (symbol_short!("sweep"),);
+    publish(env, topics, event);
+}
+
+/// Reminder emitted by `ping_expiring` for a funded escrow close to (or
+/// past) its deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowExpiring {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub remaining_amount: i128,
+    pub deadline: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_expiring(env: &Env, event: EscrowExpiring) {
+    let topics = (symbol_short!("esc_exp"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StreamStarted {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub total: i128,
+    pub start: u64,
+    pub duration: u64,
+    pub admin: Address,
+}
+
+pub fn emit_stream_started(env: &Env, event: StreamStarted) {
+    let topics = (symbol_short!("strm_new"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldDeposited {
+    pub bounty_id: u64,
+    pub pool: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_yield_deposited(env: &Env, event: YieldDeposited) {
+    let topics = (symbol_short!("yld_dep"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
+}
+
+/// Emitted when an escrow's funds come back from the yield strategy;
+/// `withdrawn - principal` went to `recipient` if positive.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldSettled {
+    pub bounty_id: u64,
+    pub principal: i128,
+    pub withdrawn: i128,
+    pub recipient: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_yield_settled(env: &Env, event: YieldSettled) {
+    let topics = (symbol_short!("yld_set"), event.bounty_id);
+    publish_escrow(env, event.bounty_id, topics, event);
 }
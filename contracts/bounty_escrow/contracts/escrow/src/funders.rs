@@ -0,0 +1,146 @@
+//! Additional funders of an escrow.
+//!
+//! Anyone may add to a `Locked` escrow with `contribute_to_escrow`, e.g. to
+//! crowdfund a bounty. What each funder put in is kept here; the depositor's
+//! share is the part of `Escrow::amount` no other funder paid. When the
+//! escrow is refunded on its deadline, cancelled, or emptied by an
+//! emergency exit, the refund is split between the depositor and the
+//! funders in proportion to their shares. Refunds made under an
+//! `approve_refund` approval go to the approved recipient as before.
+//!
+//! The depositor can also set a `FundingGoal` with `set_funding_goal`.
+//! Nothing is released until `Escrow::amount` reaches the goal, and if it
+//! has not by the goal's deadline the campaign has failed: contributions
+//! are closed and every funder, the depositor included, takes their share
+//! back with `claim_contribution`.
+
+use crate::escrow_entries::{self, Entry};
+use crate::{Error, FundingGoal};
+use soroban_sdk::{contracterror, contracttype, Address, Env, Map, Vec};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FundingError {
+    /// Escrow already has the most funders allowed besides its depositor
+    TooManyFunders = 65,
+    /// Contributions can only be claimed back once the funding goal was missed
+    FundingNotFailed = 67,
+}
+
+impl FundingError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            FundingError::TooManyFunders => "escrow has reached its maximum number of funders",
+            FundingError::FundingNotFailed => "funding goal was met or its deadline has not passed",
+        }
+    }
+}
+
+/// Most distinct funders besides the depositor, so a refund stays within
+/// the per-call budget.
+pub const MAX_FUNDERS: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FunderKey {
+    /// bounty_id -> Map<Address, i128> of what each funder contributed
+    Shares(u64),
+    /// bounty_id -> FundingGoal set by the depositor
+    Goal(u64),
+}
+
+/// What each funder other than the depositor has put into `bounty_id`.
+pub fn shares(env: &Env, bounty_id: u64) -> Map<Address, i128> {
+    escrow_entries::get(env, bounty_id, Entry::FunderShares).unwrap_or(Map::new(env))
+}
+
+/// Record `amount` contributed by `funder`. Returns `false` without
+/// recording anything if `funder` would exceed `MAX_FUNDERS`.
+pub fn add(env: &Env, bounty_id: u64, funder: &Address, amount: i128) -> bool {
+    let mut all = shares(env, bounty_id);
+    let current = all.get(funder.clone());
+    if current.is_none() && all.len() >= MAX_FUNDERS {
+        return false;
+    }
+    all.set(funder.clone(), current.unwrap_or(0) + amount);
+    escrow_entries::set(env, bounty_id, Entry::FunderShares, &all);
+    true
+}
+
+/// Stop tracking `funder`'s share and return it, if they had one.
+pub fn take(env: &Env, bounty_id: u64, funder: &Address) -> Option<i128> {
+    let mut all = shares(env, bounty_id);
+    let share = all.get(funder.clone())?;
+    all.remove(funder.clone());
+    escrow_entries::set(env, bounty_id, Entry::FunderShares, &all);
+    Some(share)
+}
+
+/// What the depositor put into an escrow that locked `total` in all.
+pub fn depositor_share(env: &Env, bounty_id: u64, total: i128) -> i128 {
+    let mut others = 0;
+    for (_, share) in shares(env, bounty_id).iter() {
+        others += share;
+    }
+    total - others
+}
+
+pub fn goal(env: &Env, bounty_id: u64) -> Option<FundingGoal> {
+    escrow_entries::get(env, bounty_id, Entry::FundingGoal)
+}
+
+pub fn set_goal(env: &Env, bounty_id: u64, goal: &FundingGoal) {
+    escrow_entries::set(env, bounty_id, Entry::FundingGoal, goal);
+}
+
+/// Whether the goal of an escrow holding `total` was missed: its deadline
+/// has passed without reaching it.
+pub fn has_failed(env: &Env, bounty_id: u64, total: i128) -> bool {
+    goal(env, bounty_id).is_some_and(|g| total < g.goal && env.ledger().timestamp() >= g.deadline)
+}
+
+/// Fail with `FundingGoalNotMet` while an escrow holding `total` is short
+/// of its goal.
+pub fn ensure_goal_met(env: &Env, bounty_id: u64, total: i128) -> Result<(), Error> {
+    match goal(env, bounty_id) {
+        Some(g) if total < g.goal => Err(Error::FundingGoalNotMet),
+        _ => Ok(()),
+    }
+}
+
+/// Split `amount` refunded from an escrow that locked `total` in all.
+/// Returns each funder's pro-rata part and what is left for the depositor,
+/// who also receives the rounding dust.
+pub fn split(env: &Env, bounty_id: u64, total: i128, amount: i128) -> (Vec<(Address, i128)>, i128) {
+    let mut parts = Vec::new(env);
+    let mut rest = amount;
+    if total <= 0 {
+        return (parts, rest);
+    }
+    for (funder, share) in shares(env, bounty_id).iter() {
+        let part = amount * share / total;
+        if part > 0 {
+            parts.push_back((funder, part));
+            rest -= part;
+        }
+    }
+    (parts, rest)
+}
+
+/// Move the shares and goal of `from` to `to`, e.g. when an escrow is
+/// reassigned.
+pub fn transfer(env: &Env, from: u64, to: u64) {
+    escrow_entries::transfer(env, from, to, Entry::FunderShares);
+    escrow_entries::transfer(env, from, to, Entry::FundingGoal);
+}
+
+/// Forget every funder's share once `bounty_id` has paid all of them out.
+pub fn clear_shares(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::FunderShares);
+}
+
+pub fn remove(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::FunderShares);
+    escrow_entries::remove(env, bounty_id, Entry::FundingGoal);
+}
@@ -0,0 +1,70 @@
+//! Per-escrow callbacks invoked after funds leave the escrow.
+//!
+//! The admin can attach a hook contract to an escrow with `set_escrow_hook`.
+//! After every payout the escrow calls `on_release` on it, and after every
+//! refund `on_refund`, in the same invocation as the transfer. A failing
+//! hook reverts the whole release or refund, so integrators can rely on the
+//! hook's side effects (closing an issue, minting a badge, updating a
+//! registry) happening atomically with the payment.
+//!
+//! Hooks run with the reentrancy guard held: a hook that calls back into a
+//! guarded escrow entry point panics and reverts the operation.
+
+use crate::escrow_entries::{self, Entry};
+use crate::reentrancy_guard;
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface a hook contract must implement. The escrow passes its own
+/// address as `escrow`.
+#[allow(dead_code)]
+#[contractclient(name = "HookClient")]
+pub trait EscrowHook {
+    fn on_release(env: Env, escrow: Address, bounty_id: u64, recipient: Address, amount: i128);
+    fn on_refund(env: Env, escrow: Address, bounty_id: u64, recipient: Address, amount: i128);
+}
+
+/// The hook attached to `bounty_id`, if any.
+pub fn get(env: &Env, bounty_id: u64) -> Option<Address> {
+    escrow_entries::get(env, bounty_id, Entry::Hook)
+}
+
+/// Call `on_release` on the hook of `bounty_id`, if any.
+pub fn on_release(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+    if let Some(hook) = get(env, bounty_id) {
+        guarded(env, || {
+            HookClient::new(env, &hook).on_release(
+                &env.current_contract_address(),
+                &bounty_id,
+                recipient,
+                &amount,
+            )
+        });
+    }
+}
+
+/// Call `on_refund` on the hook of `bounty_id`, if any.
+pub fn on_refund(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+    if let Some(hook) = get(env, bounty_id) {
+        guarded(env, || {
+            HookClient::new(env, &hook).on_refund(
+                &env.current_contract_address(),
+                &bounty_id,
+                recipient,
+                &amount,
+            )
+        });
+    }
+}
+
+/// Run `call` with the reentrancy guard held, acquiring it only if the
+/// calling entry point does not already hold it.
+fn guarded(env: &Env, call: impl FnOnce()) {
+    let held = reentrancy_guard::is_active(env);
+    if !held {
+        reentrancy_guard::acquire(env);
+    }
+    call();
+    if !held {
+        reentrancy_guard::release(env);
+    }
+}
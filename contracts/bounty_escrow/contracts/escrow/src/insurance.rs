@@ -0,0 +1,101 @@
+//! Escrow insurance.
+//!
+//! Once the admin sets an `InsuranceConfig`, a depositor can insure a
+//! locked escrow with `insure_escrow`, paying `premium_bps` of its amount
+//! into a shared pool. The policy covers up to the escrow amount, capped at
+//! `max_coverage`. If a release proves fraudulent, or a dispute over the
+//! escrow is resolved against the contributor, the dispute arbiter pays
+//! the depositor out of the pool with `pay_insurance_claim`, once per
+//! policy. Anyone can top the pool up with `fund_insurance_pool`.
+
+use crate::escrow_entries::{self, Entry};
+use crate::{InsuranceConfig, InsurancePolicy};
+use soroban_sdk::{contracterror, contracttype, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum InsuranceError {
+    /// Escrow has no insurance policy, or insurance is not offered
+    NotInsured = 71,
+    /// Escrow is already insured
+    AlreadyInsured = 72,
+    /// Policy was already paid out, or no release or dispute outcome
+    /// justifies a claim
+    InsuranceClaimNotAllowed = 73,
+}
+
+impl InsuranceError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            InsuranceError::NotInsured => "escrow is not insured or insurance is not offered",
+            InsuranceError::AlreadyInsured => "escrow is already insured",
+            InsuranceError::InsuranceClaimNotAllowed => {
+                "insurance claim was paid or is not justified"
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InsuranceKey {
+    /// InsuranceConfig set by the admin
+    InsuranceConfig,
+    /// i128 premiums and top-ups not yet paid out, counted in the tracked
+    /// balance
+    InsurancePool,
+    /// bounty_id -> InsurancePolicy
+    Policy(u64),
+}
+
+pub fn config(env: &Env) -> Option<InsuranceConfig> {
+    env.storage().instance().get(&InsuranceKey::InsuranceConfig)
+}
+
+pub fn set_config(env: &Env, config: Option<InsuranceConfig>) {
+    match config {
+        Some(config) => env
+            .storage()
+            .instance()
+            .set(&InsuranceKey::InsuranceConfig, &config),
+        None => env
+            .storage()
+            .instance()
+            .remove(&InsuranceKey::InsuranceConfig),
+    }
+}
+
+/// Funds available for claims.
+pub fn pool(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&InsuranceKey::InsurancePool)
+        .unwrap_or(0)
+}
+
+pub fn add_to_pool(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&InsuranceKey::InsurancePool, &(pool(env) + amount));
+}
+
+pub fn policy(env: &Env, bounty_id: u64) -> Option<InsurancePolicy> {
+    escrow_entries::get(env, bounty_id, Entry::InsurancePolicy)
+}
+
+pub fn set_policy(env: &Env, bounty_id: u64, policy: &InsurancePolicy) {
+    escrow_entries::set(env, bounty_id, Entry::InsurancePolicy, policy);
+}
+
+/// Move the policy of `from` to `to`, e.g. when an escrow is reassigned.
+pub fn transfer(env: &Env, from: u64, to: u64) {
+    if let Some(policy) = policy(env, from) {
+        set_policy(env, to, &policy);
+        remove(env, from);
+    }
+}
+
+pub fn remove(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::InsurancePolicy);
+}
@@ -24,25 +24,33 @@ fn assert_enabled(_env: &Env) {}
 pub(crate) fn assert_escrow(env: &Env, escrow: &Escrow) {
     assert_enabled(env);
     record_call(env);
-    if !verify_escrow_invariants(escrow) {
-        panic!("Invariant violated: escrow state inconsistent");
+    if let Some(violation) = escrow_violation(escrow) {
+        panic!("Invariant violated: {}", violation);
     }
 }
 
 pub(crate) fn verify_escrow_invariants(escrow: &Escrow) -> bool {
+    escrow_violation(escrow).is_none()
+}
+
+/// The first invariant `escrow` breaks, if any.
+fn escrow_violation(escrow: &Escrow) -> Option<&'static str> {
     if escrow.amount < 0 {
-        return false;
+        return Some("amount must be non-negative");
     }
     if escrow.remaining_amount < 0 {
-        return false;
+        return Some("remaining_amount must be non-negative");
     }
     if escrow.remaining_amount > escrow.amount {
-        return false;
+        return Some("remaining_amount cannot exceed amount");
     }
     if escrow.status == EscrowStatus::Released && escrow.remaining_amount != 0 {
-        return false;
+        return Some("released escrow must have zero remaining amount");
+    }
+    if escrow.status == EscrowStatus::Cancelled && escrow.remaining_amount != 0 {
+        return Some("cancelled escrow must have zero remaining amount");
     }
-    true
+    None
 }
 
 #[cfg(test)]
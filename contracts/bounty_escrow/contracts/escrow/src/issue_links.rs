@@ -0,0 +1,107 @@
+//! GitHub issue linkage.
+//!
+//! A depositor can link an escrow to the GitHub issue it pays for with
+//! `set_issue_link`. The link starts out unverified; an off-chain attestor
+//! checks with GitHub that the repository and issue belong to the program,
+//! signs the link, and anyone submits the signature with
+//! `attest_issue_link`. While an escrow has an unverified link,
+//! `release_funds` and `partial_release` fail with `IssueLinkNotVerified`.
+//! Escrows without a link are not affected.
+//!
+//! The attestor's ed25519 key is set by the admin with
+//! `set_issue_attestor`. It signs the XDR of `(escrow address, bounty_id,
+//! repo, issue_number)`, so an attestation can't be replayed on another
+//! instance, escrow or issue.
+
+use crate::escrow_entries::{self, Entry};
+use crate::{Error, IssueLink};
+use soroban_sdk::{contracterror, contracttype, panic_with_error, xdr::ToXdr, BytesN, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum IssueLinkError {
+    /// Escrow is not linked to an issue
+    IssueLinkNotFound = 76,
+    /// Repository name or issue number is malformed
+    InvalidIssueLink = 78,
+}
+
+impl IssueLinkError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            IssueLinkError::IssueLinkNotFound => "escrow is not linked to an issue",
+            IssueLinkError::InvalidIssueLink => "repository or issue number is malformed",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssueLinkKey {
+    /// BytesN<32> ed25519 public key of the attestor
+    Attestor,
+    /// bounty_id -> IssueLink
+    Link(u64),
+}
+
+pub fn attestor(env: &Env) -> Option<BytesN<32>> {
+    env.storage().instance().get(&IssueLinkKey::Attestor)
+}
+
+pub fn set_attestor(env: &Env, attestor: Option<BytesN<32>>) {
+    match attestor {
+        Some(key) => env.storage().instance().set(&IssueLinkKey::Attestor, &key),
+        None => env.storage().instance().remove(&IssueLinkKey::Attestor),
+    }
+}
+
+pub fn get(env: &Env, bounty_id: u64) -> Option<IssueLink> {
+    escrow_entries::get(env, bounty_id, Entry::IssueLink)
+}
+
+pub fn set(env: &Env, bounty_id: u64, link: &IssueLink) {
+    escrow_entries::set(env, bounty_id, Entry::IssueLink, link);
+}
+
+/// Check `signature` by the attestor over the link of `bounty_id` and mark
+/// it verified. Panics on a bad signature, like voucher checks, and aborts
+/// with `IssueLinkNotFound` if the escrow is not linked.
+pub fn attest(env: &Env, bounty_id: u64, signature: &BytesN<64>) -> Result<IssueLink, Error> {
+    let attestor = attestor(env).ok_or(Error::NotInitialized)?;
+    let mut link = get(env, bounty_id)
+        .unwrap_or_else(|| panic_with_error!(env, IssueLinkError::IssueLinkNotFound));
+    let message = (
+        env.current_contract_address(),
+        bounty_id,
+        link.repo.clone(),
+        link.issue_number,
+    )
+        .to_xdr(env);
+    env.crypto().ed25519_verify(&attestor, &message, signature);
+
+    link.verified = true;
+    set(env, bounty_id, &link);
+    Ok(link)
+}
+
+/// Returns `IssueLinkNotVerified` if `bounty_id` is linked to an issue
+/// that has not been attested.
+pub fn ensure_verified(env: &Env, bounty_id: u64) -> Result<(), Error> {
+    match get(env, bounty_id) {
+        Some(link) if !link.verified => Err(Error::IssueLinkNotVerified),
+        _ => Ok(()),
+    }
+}
+
+/// Move the link of `from` to `to`, e.g. when an escrow is reassigned.
+pub fn transfer(env: &Env, from: u64, to: u64) {
+    if let Some(link) = get(env, from) {
+        set(env, to, &link);
+        remove(env, from);
+    }
+}
+
+pub fn remove(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::IssueLink);
+}
@@ -0,0 +1,50 @@
+//! Optional KYC attestation for releases.
+//!
+//! A depositor can attach a verifier contract to an escrow with
+//! `set_escrow_kyc_verifier`, e.g. a grant program that may only pay
+//! identity-checked contributors. While set, `release_funds` and
+//! `partial_release` ask the verifier whether the contributor is verified
+//! and fail with `Unauthorized` if not. Unlike reputation reports this call
+//! is strict: a failing verifier blocks the release.
+
+use crate::escrow_entries::{self, Entry};
+use crate::Error;
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Interface a KYC verifier contract must implement.
+#[allow(dead_code)]
+#[contractclient(name = "KycVerifierClient")]
+pub trait KycVerifier {
+    fn is_verified(env: Env, address: Address) -> bool;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum KycKey {
+    /// bounty_id -> Address of the verifier contributors must pass
+    Verifier(u64),
+}
+
+/// The verifier attached to `bounty_id`, if any.
+pub fn get(env: &Env, bounty_id: u64) -> Option<Address> {
+    escrow_entries::get(env, bounty_id, Entry::KycVerifier)
+}
+
+/// Attach `verifier` to `bounty_id`, or clear the requirement with `None`.
+pub fn set(env: &Env, bounty_id: u64, verifier: Option<Address>) {
+    match verifier {
+        Some(address) => escrow_entries::set(env, bounty_id, Entry::KycVerifier, &address),
+        None => escrow_entries::remove(env, bounty_id, Entry::KycVerifier),
+    }
+}
+
+/// Returns `Unauthorized` if `bounty_id` requires KYC and `recipient` is not
+/// verified.
+pub fn ensure_verified(env: &Env, bounty_id: u64, recipient: &Address) -> Result<(), Error> {
+    if let Some(verifier) = get(env, bounty_id) {
+        if !KycVerifierClient::new(env, &verifier).is_verified(recipient) {
+            return Err(Error::Unauthorized);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//! Deadlines by ledger sequence.
+//!
+//! `lock_funds_with_deadline` accepts a `Deadline::Ledger(sequence)` for
+//! integrators who want expiry that doesn't depend on validators' clocks.
+//! The sequence is stored here and is what refunds check; the escrow's
+//! `deadline` field holds an estimate of when that ledger closes, assuming
+//! `LEDGER_SECONDS` per ledger, so views, sorting and the deadline index keep
+//! working. The refund grace period, set in seconds, is converted to ledgers
+//! the same way. Moving the deadline with `extend_deadline` turns it back
+//! into a timestamp deadline.
+
+use crate::escrow_entries::{self, Entry};
+use soroban_sdk::{contracttype, Env};
+
+/// Expected seconds between ledgers, used to estimate timestamps.
+pub const LEDGER_SECONDS: u64 = 5;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LedgerDeadlineKey {
+    /// bounty_id -> u32 ledger sequence the escrow expires at
+    LedgerDeadline(u64),
+}
+
+pub fn get(env: &Env, bounty_id: u64) -> Option<u32> {
+    escrow_entries::get(env, bounty_id, Entry::LedgerDeadline)
+}
+
+pub fn set(env: &Env, bounty_id: u64, sequence: u32) {
+    escrow_entries::set(env, bounty_id, Entry::LedgerDeadline, &sequence);
+}
+
+/// Estimated close time of ledger `sequence`, or `None` if it has closed.
+pub fn estimate(env: &Env, sequence: u32) -> Option<u64> {
+    let ledgers = sequence.checked_sub(env.ledger().sequence())?;
+    if ledgers == 0 {
+        return None;
+    }
+    Some(
+        env.ledger()
+            .timestamp()
+            .saturating_add(u64::from(ledgers) * LEDGER_SECONDS),
+    )
+}
+
+/// Whether `bounty_id`'s ledger deadline plus `grace` seconds' worth of
+/// ledgers has been reached, or `None` if it has a timestamp deadline.
+pub fn reached(env: &Env, bounty_id: u64, grace: u64) -> Option<bool> {
+    let sequence = get(env, bounty_id)?;
+    let grace_ledgers = u32::try_from(grace.div_ceil(LEDGER_SECONDS)).unwrap_or(u32::MAX);
+    Some(env.ledger().sequence() >= sequence.saturating_add(grace_ledgers))
+}
+
+/// Move the deadline of `from` to `to`, e.g. when an escrow is reassigned.
+pub fn transfer(env: &Env, from: u64, to: u64) {
+    if let Some(sequence) = get(env, from) {
+        set(env, to, sequence);
+        remove(env, from);
+    }
+}
+
+pub fn remove(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::LedgerDeadline);
+}
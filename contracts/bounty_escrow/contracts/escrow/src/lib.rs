@@ -1,24 +1,59 @@
 #![no_std]
+mod allowlist;
+mod arbitration;
+mod archive;
+mod badges;
+mod bonds;
+mod budgets;
+mod council;
+mod deadline_index;
+mod donations;
+mod emergency_exit;
+mod escrow_entries;
+mod escrow_index;
 #[allow(dead_code)]
 mod events;
+mod funders;
+mod hooks;
+mod insurance;
 mod invariants;
+mod issue_links;
+mod kyc;
+mod ledger_deadlines;
+mod migration;
+mod nonce;
+mod payout_caps;
+mod quadratic_funding;
+mod referrals;
 #[cfg(test)]
 mod test_metadata;
 #[cfg(test)]
 mod test_token_math;
 pub mod token_math;
 
+mod rbac;
+mod release_limits;
+mod release_policy;
+mod templates;
+
 #[cfg(test)]
 mod test_claim_tickets;
-#[cfg(test)]
 mod reentrancy_guard;
+mod reputation;
+mod rescue_history;
+mod schema_migration;
+mod state_machine;
+mod storage_policy;
 mod test_cross_contract_interface;
 #[cfg(test)]
 mod test_multi_token_fees;
 #[cfg(test)]
 mod test_rbac;
 mod traits;
+mod yield_strategy;
 
+use escrow_entries::{Entry, EscrowEntries};
+use escrow_index::IndexId;
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized, emit_funds_locked,
     emit_funds_refunded, emit_funds_released, emit_ticket_claimed, emit_ticket_issued,
@@ -26,10 +61,12 @@ use events::{
     ClaimExecuted, FundsLocked, FundsRefunded, FundsReleased, TicketClaimed, TicketIssued,
     EVENT_VERSION_V2,
 };
+pub use rbac::Role;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, panic_with_error, symbol_short, token,
+    vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
+use state_machine::StatusEvent;
 
 pub(crate) mod monitoring {
     use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
@@ -117,7 +154,8 @@ pub(crate) mod monitoring {
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("op")),
             OperationMetric {
                 version: super::EVENT_VERSION_V2,
@@ -143,7 +181,8 @@ pub(crate) mod monitoring {
             .persistent()
             .set(&time_key, &(total + duration));
 
-        env.events().publish(
+        crate::events::publish(
+            env,
             (symbol_short!("metric"), symbol_short!("perf")),
             PerformanceMetric {
                 version: super::EVENT_VERSION_V2,
@@ -219,7 +258,7 @@ pub(crate) mod monitoring {
         let total: u64 = env.storage().persistent().get(&time_key).unwrap_or(0);
         let last: u64 = env.storage().persistent().get(&last_key).unwrap_or(0);
 
-        let avg = if count > 0 { total / count } else { 0 };
+        let avg = total.checked_div(count).unwrap_or(0);
 
         PerformanceStats {
             function_name,
@@ -327,7 +366,8 @@ mod anti_abuse {
                     .last_operation_timestamp
                     .saturating_add(config.cooldown_period)
         {
-            env.events().publish(
+            crate::events::publish(
+                env,
                 (symbol_short!("abuse"), symbol_short!("cooldown")),
                 (address.clone(), now),
             );
@@ -346,7 +386,8 @@ mod anti_abuse {
         } else {
             // Same window
             if state.operation_count >= config.max_operations {
-                env.events().publish(
+                crate::events::publish(
+                    env,
                     (symbol_short!("abuse"), symbol_short!("limit")),
                     (address.clone(), now),
                 );
@@ -365,16 +406,51 @@ mod anti_abuse {
 
 const MAX_FEE_RATE: i128 = token_math::MAX_FEE_RATE;
 const MAX_BATCH_SIZE: u32 = 20;
+const MAX_MILESTONES: u32 = 20;
+/// Oldest entries are dropped once an escrow's history reaches this length.
+const MAX_HISTORY_ENTRIES: u32 = 50;
+/// Latest actions kept in an escrow's core entry; once this many are there
+/// they move to `DataKey::EscrowHistory` together.
+const HISTORY_PAGE: u32 = 4;
+/// Minimum wait between `request_rescue` and `execute_rescue` (24 hours).
+const MIN_RESCUE_DELAY: u64 = 86_400;
+/// Minimum wait between announcing and running `emergency_withdraw_all`
+/// (30 days).
+const EMERGENCY_EXIT_DELAY: u64 = 30 * 86_400;
+/// How long a released or refunded escrow is kept in full before
+/// `sweep_stale_escrows` may archive it (90 days).
+const ESCROW_RETENTION_PERIOD: u64 = 90 * 86_400;
+/// How far ahead of its deadline `ping_expiring` reports an escrow (3 days).
+const EXPIRY_NOTICE_WINDOW: u64 = 3 * 86_400;
+/// Version of the contract code, bumped with every released upgrade.
+const CONTRACT_VERSION: u32 = 1;
+/// Layout version of stored records written by this code.
+///
+/// * 1 - `Escrow` without `metadata_hash` / `label` (stored as `EscrowV1`)
+/// * 2 - current `Escrow`
+/// * 3 - funded escrows listed in the deadline index
+/// * 4 - `Escrow` stored split into `EscrowCoreV4` and `EscrowDetails`
+/// * 5 - paged indexes, and the escrow's `EscrowEntries` record and latest
+///   actions kept in its `EscrowCore`
+const STORAGE_SCHEMA_VERSION: u32 = 5;
 
 extern crate grainlify_core;
 use grainlify_core::asset;
 
+/// Errors returned by the escrow. `get_error_description` maps a code to a
+/// human-readable explanation.
+///
+/// The contract spec allows at most 50 cases per error enum, so failures
+/// specific to one feature live in that feature's own error enum (e.g.
+/// `DisputeError`, `TicketError`, `yield_strategy::YieldError`). Codes are
+/// unique across all of them.
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
 pub enum Error {
     AlreadyInitialized = 1,
     NotInitialized = 2,
+    /// Returned when locking a bounty_id that is, or was, already in use
     BountyExists = 3,
     BountyNotFound = 4,
     FundsNotLocked = 5,
@@ -385,7 +461,8 @@ pub enum Error {
     InvalidBatchSize = 10,
     BatchSizeMismatch = 11,
     DuplicateBountyId = 12,
-    /// Returned when amount is invalid (zero, negative, or exceeds available)
+    /// Returned when an amount is zero or negative, or a sum of amounts
+    /// overflows
     InvalidAmount = 13,
     /// Returned when deadline is invalid (in the past or too far in the future)
     InvalidDeadline = 14,
@@ -401,12 +478,6 @@ pub enum Error {
     /// Returned when refund is blocked by a pending claim/dispute
     NotPaused = 21,
     ClaimPending = 22,
-    /// Returned when claim ticket is not found
-    TicketNotFound = 23,
-    /// Returned when claim ticket has already been used (replay prevention)
-    TicketAlreadyUsed = 24,
-    /// Returned when claim ticket has expired
-    TicketExpired = 25,
     CapabilityNotFound = 23,
     CapabilityExpired = 24,
     CapabilityRevoked = 25,
@@ -415,6 +486,356 @@ pub enum Error {
     CapabilityUsesExhausted = 28,
     CapabilityExceedsAuthority = 29,
     InvalidAssetId = 30,
+    /// Returned when a milestone index does not exist for the bounty
+    MilestoneNotFound = 31,
+    /// Returned when releasing a milestone the depositor has not approved
+    MilestoneNotApproved = 32,
+    /// Returned when a milestone has already been approved or released
+    MilestoneAlreadyReleased = 33,
+    /// Returned when release/refund is attempted while a dispute is open
+    DisputeOpen = 34,
+    /// Returned when a dispute split is outside 0..=10_000 basis points
+    InvalidSplit = 37,
+    /// Returned when a release above the multisig threshold lacks enough approvals
+    InsufficientApprovals = 38,
+    /// Returned when a sensitive admin operation must go through the timelock
+    TimelockRequired = 41,
+    /// Returned when an escrow being streamed is released, refunded or changed
+    StreamActive = 46,
+    /// Returned when council members are duplicated or the threshold is out of range
+    InvalidCouncil = 49,
+    /// Returned when an admin operation must be approved by the admin council
+    CouncilRequired = 50,
+    /// Returned when a payout destination is the escrow contract itself
+    InvalidRecipient = 51,
+    /// Returned when the assigned contributor has not accepted yet
+    AssignmentPending = 55,
+    /// Returned when releasing an escrow that requires a work submission
+    SubmissionRequired = 57,
+    /// Returned when a release would exceed the release rate limit
+    ReleaseRateLimited = 62,
+    /// Escrow is short of its funding goal, so nothing can be released, or
+    /// missed it, so no more contributions are taken
+    FundingGoalNotMet = 66,
+    /// Escrow is linked to an issue the attestor has not verified
+    IssueLinkNotVerified = 77,
+    /// Contract holds less of the escrow token than it owes, e.g. after a
+    /// clawback, until `reconcile_escrow` writes the difference off
+    BalanceMismatch = 82,
+    /// Returned when a refund or claim ticket is for more than the escrow has
+    /// remaining
+    AmountExceedsRemaining = 87,
+    /// Returned when an escrow that pays out in milestones is released other
+    /// than through `release_milestone`
+    MilestoneEscrow = 89,
+}
+
+impl Error {
+    /// Human-readable meaning of the error, returned by
+    /// `get_error_description`.
+    pub fn description(&self) -> &'static str {
+        match self {
+            Error::AlreadyInitialized => "contract is already initialized",
+            Error::NotInitialized => "contract or required feature is not configured",
+            Error::BountyExists => "bounty id is or was already in use",
+            Error::BountyNotFound => "no escrow exists for the bounty id",
+            Error::FundsNotLocked => "escrow is not in a state that holds funds",
+            Error::DeadlineNotPassed => "refund deadline and grace period have not passed",
+            Error::Unauthorized => "caller is not allowed to perform the action",
+            Error::InvalidFeeRate => "fee rate is outside the allowed range",
+            Error::FeeRecipientNotSet => "no fee recipient is configured",
+            Error::InvalidBatchSize => "batch is empty or larger than allowed",
+            Error::BatchSizeMismatch => "batch inputs have different lengths",
+            Error::DuplicateBountyId => "batch contains the same bounty id twice",
+            Error::InvalidAmount => "amount is zero, negative or overflows",
+            Error::InvalidDeadline => "deadline is in the past or too far ahead",
+            Error::InsufficientFunds => "contract balance is too low for the operation",
+            Error::RefundNotApproved => "refund requires an admin approval",
+            Error::FundsPaused => "operation is paused, globally or for this escrow",
+            Error::AmountBelowMinimum => "amount is below the configured minimum",
+            Error::AmountAboveMaximum => "amount is above the configured maximum",
+            Error::NotPaused => "operation requires the contract or escrow to be paused",
+            Error::ClaimPending => "a pending claim blocks the operation",
+            Error::CapabilityNotFound => "capability does not exist",
+            Error::CapabilityExpired => "capability has expired",
+            Error::CapabilityRevoked => "capability has been revoked",
+            Error::CapabilityActionMismatch => "capability does not cover this action",
+            Error::CapabilityAmountExceeded => "amount exceeds what the capability allows",
+            Error::CapabilityUsesExhausted => "capability has no uses left",
+            Error::CapabilityExceedsAuthority => "capability exceeds its issuer's authority",
+            Error::InvalidAssetId => "asset id is not valid",
+            Error::MilestoneNotFound => "milestone does not exist for the bounty",
+            Error::MilestoneNotApproved => "milestone has not been approved",
+            Error::MilestoneAlreadyReleased => "milestone was already approved or released",
+            Error::DisputeOpen => "an open dispute blocks the operation",
+            Error::InvalidSplit => "split is outside 0..=10000 basis points",
+            Error::InsufficientApprovals => "release lacks the required multisig approvals",
+            Error::TimelockRequired => "operation must be queued through the timelock",
+            Error::StreamActive => "escrow is being streamed",
+            Error::InvalidCouncil => "council members repeat or threshold is out of range",
+            Error::CouncilRequired => "operation must be approved by the admin council",
+            Error::InvalidRecipient => "recipient cannot be the escrow contract",
+            Error::AssignmentPending => "assigned contributor has not accepted yet",
+            Error::SubmissionRequired => "work must be submitted before release",
+            Error::ReleaseRateLimited => "release exceeds the amount releasable in this window",
+            Error::FundingGoalNotMet => "escrow has not reached its funding goal",
+            Error::IssueLinkNotVerified => "linked issue has not been verified by the attestor",
+            Error::BalanceMismatch => "contract holds less of the token than escrows are owed",
+            Error::AmountExceedsRemaining => "amount is more than the escrow has remaining",
+            Error::MilestoneEscrow => "escrow pays out only through release_milestone",
+        }
+    }
+}
+
+/// Claim ticket failures.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TicketError {
+    /// Returned when claim ticket is not found
+    TicketNotFound = 91,
+    /// Returned when claim ticket has already been used (replay prevention)
+    TicketAlreadyUsed = 92,
+    /// Returned when claim ticket has expired
+    TicketExpired = 93,
+}
+
+impl TicketError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            TicketError::TicketNotFound => "claim ticket does not exist",
+            TicketError::TicketAlreadyUsed => "claim ticket has already been used",
+            TicketError::TicketExpired => "claim ticket has expired",
+        }
+    }
+}
+
+/// Payout amounts that are not positive, reported apart from the catch-all
+/// `Error::InvalidAmount`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AmountError {
+    /// Returned when a payout amount is zero
+    ZeroAmount = 94,
+    /// Returned when a payout amount is negative
+    NegativeAmount = 95,
+}
+
+impl AmountError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            AmountError::ZeroAmount => "amount is zero",
+            AmountError::NegativeAmount => "amount is negative",
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DisputeError {
+    /// Returned when no open dispute exists for the bounty
+    DisputeNotFound = 35,
+    /// Returned when resolving a dispute before an arbiter is configured
+    ArbiterNotSet = 36,
+}
+
+impl DisputeError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            DisputeError::DisputeNotFound => "no open dispute exists for the bounty",
+            DisputeError::ArbiterNotSet => "no arbiter is configured",
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DeadlineError {
+    /// Returned when a deadline extension exceeds the configured maximum
+    ExtensionTooLong = 39,
+}
+
+impl DeadlineError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            DeadlineError::ExtensionTooLong => "deadline extension exceeds the configured maximum",
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CancellationError {
+    /// Returned when cancelling an escrow that has a contributor or payouts
+    CancellationNotAllowed = 40,
+}
+
+impl CancellationError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            CancellationError::CancellationNotAllowed => "escrow has a contributor or payouts",
+        }
+    }
+}
+
+/// Failures executing or cancelling queued admin and council operations.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TimelockError {
+    /// Returned when executing a queued admin operation before its delay ends
+    TimelockNotExpired = 42,
+    /// Returned when no queued admin operation exists for the given id
+    AdminOpNotFound = 43,
+}
+
+impl TimelockError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            TimelockError::TimelockNotExpired => "timelock delay has not elapsed",
+            TimelockError::AdminOpNotFound => "no queued admin operation has this id",
+        }
+    }
+}
+
+/// Failures of the rescue and emergency exit flows.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RescueError {
+    /// Returned when executing or cancelling a rescue that was never requested
+    RescueNotRequested = 44,
+    /// Returned when requesting a rescue while another one is pending
+    RescuePending = 45,
+}
+
+impl RescueError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            RescueError::RescueNotRequested => "no rescue or emergency exit is pending",
+            RescueError::RescuePending => "a rescue or emergency exit is already pending",
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StreamError {
+    /// Returned when withdrawing from an escrow that has no stream
+    StreamNotFound = 47,
+}
+
+impl StreamError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            StreamError::StreamNotFound => "escrow has no stream",
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AssignmentError {
+    /// Returned when accepting an assignment that does not exist or has lapsed
+    AssignmentNotFound = 54,
+    /// Returned when reassigning a bounty whose assignment was accepted
+    AssignmentAccepted = 56,
+    /// Returned when claiming after a review timeout on an escrow without one
+    ReviewPeriodNotSet = 58,
+}
+
+impl AssignmentError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            AssignmentError::AssignmentNotFound => "no open assignment for this bounty",
+            AssignmentError::AssignmentAccepted => "assignment was already accepted",
+            AssignmentError::ReviewPeriodNotSet => "escrow has no review period",
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReassignmentError {
+    /// Returned when moving an escrow that has a contributor, payouts or yield
+    ReassignmentNotAllowed = 61,
+}
+
+impl ReassignmentError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReassignmentError::ReassignmentNotAllowed => {
+                "escrow has a contributor, payouts or invested funds"
+            }
+        }
+    }
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreasuryError {
+    /// Returned when accepting the treasury role while none is proposed
+    TreasuryNotProposed = 63,
+}
+
+impl TreasuryError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            TreasuryError::TreasuryNotProposed => "no treasury address is awaiting acceptance",
+        }
+    }
+}
+
+/// Unwrap `result`, or abort the invocation with its error. Entry points
+/// return `Error`, so this is how they fail with a code from one of the
+/// per-feature error enums.
+fn or_abort<T, E: Into<soroban_sdk::Error>>(env: &Env, result: Result<T, E>) -> T {
+    result.unwrap_or_else(|error| env.panic_with_error(error))
+}
+
+/// Description of `code` from whichever error enum defines it.
+fn error_description(code: u32) -> Option<&'static str> {
+    let error = soroban_sdk::Error::from_contract_error(code);
+    Error::try_from(error)
+        .map(|e| e.description())
+        .or_else(|_| TicketError::try_from(error).map(|e| e.description()))
+        .or_else(|_| AmountError::try_from(error).map(|e| e.description()))
+        .or_else(|_| DisputeError::try_from(error).map(|e| e.description()))
+        .or_else(|_| DeadlineError::try_from(error).map(|e| e.description()))
+        .or_else(|_| CancellationError::try_from(error).map(|e| e.description()))
+        .or_else(|_| TimelockError::try_from(error).map(|e| e.description()))
+        .or_else(|_| RescueError::try_from(error).map(|e| e.description()))
+        .or_else(|_| StreamError::try_from(error).map(|e| e.description()))
+        .or_else(|_| AssignmentError::try_from(error).map(|e| e.description()))
+        .or_else(|_| ReassignmentError::try_from(error).map(|e| e.description()))
+        .or_else(|_| TreasuryError::try_from(error).map(|e| e.description()))
+        .or_else(|_| nonce::VoucherError::try_from(error).map(|e| e.description()))
+        .or_else(|_| templates::TemplateError::try_from(error).map(|e| e.description()))
+        .or_else(|_| budgets::BudgetError::try_from(error).map(|e| e.description()))
+        .or_else(|_| bonds::BondError::try_from(error).map(|e| e.description()))
+        .or_else(|_| storage_policy::TtlPolicyError::try_from(error).map(|e| e.description()))
+        .or_else(|_| funders::FundingError::try_from(error).map(|e| e.description()))
+        .or_else(|_| quadratic_funding::RoundError::try_from(error).map(|e| e.description()))
+        .or_else(|_| insurance::InsuranceError::try_from(error).map(|e| e.description()))
+        .or_else(|_| referrals::ReferralError::try_from(error).map(|e| e.description()))
+        .or_else(|_| migration::MigrationError::try_from(error).map(|e| e.description()))
+        .or_else(|_| issue_links::IssueLinkError::try_from(error).map(|e| e.description()))
+        .or_else(|_| payout_caps::PayoutCapError::try_from(error).map(|e| e.description()))
+        .or_else(|_| donations::DonationError::try_from(error).map(|e| e.description()))
+        .or_else(|_| release_policy::ReleasePolicyError::try_from(error).map(|e| e.description()))
+        .or_else(|_| yield_strategy::YieldError::try_from(error).map(|e| e.description()))
+        .or_else(|_| {
+            schema_migration::SchemaMigrationError::try_from(error).map(|e| e.description())
+        })
+        .ok()
 }
 
 #[contracttype]
@@ -432,6 +853,8 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    /// Withdrawn by the depositor via `cancel_escrow` before any payout.
+    Cancelled,
 }
 
 #[contracttype]
@@ -446,8 +869,88 @@ pub struct Escrow {
     pub status: EscrowStatus,
     pub deadline: u64,
     pub refund_history: Vec<RefundRecord>,
+    /// 32-byte hash of the off-chain bounty description (e.g. an IPFS CID or
+    /// a digest of the GitHub issue URL). Set by `lock_funds_with_metadata_hash`.
+    /// Held as `Bytes` because `Option<BytesN<_>>` has no XDR conversion.
+    pub metadata_hash: Option<Bytes>,
+    /// Optional short label, e.g. a program or repository tag.
+    pub label: Option<Symbol>,
+}
+
+/// `Escrow` as written under schema v1, kept only so `migrate` can read
+/// entries created before the metadata fields existed.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+struct EscrowV1 {
+    depositor: Address,
+    amount: i128,
+    remaining_amount: i128,
+    status: EscrowStatus,
+    deadline: u64,
+    refund_history: Vec<RefundRecord>,
+}
+
+impl EscrowV1 {
+    fn into_v2(self) -> Escrow {
+        Escrow {
+            depositor: self.depositor,
+            amount: self.amount,
+            remaining_amount: self.remaining_amount,
+            status: self.status,
+            deadline: self.deadline,
+            refund_history: self.refund_history,
+            metadata_hash: None,
+            label: None,
+        }
+    }
 }
 
+/// The `Escrow` fields read and written by every lock, release and refund,
+/// stored under `DataKey::Escrow` together with the escrow's bookkeeping and
+/// latest actions, so the hot paths touch one entry per escrow.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+struct EscrowCore {
+    depositor: Address,
+    amount: i128,
+    remaining_amount: i128,
+    status: EscrowStatus,
+    deadline: u64,
+    /// See `escrow_entries`
+    entries: EscrowEntries,
+    /// Actions after those in `DataKey::EscrowHistory`, oldest first, fewer
+    /// than `HISTORY_PAGE`
+    history: Vec<HistoryEntry>,
+}
+
+/// `EscrowCore` as stored in schema v4, before the bookkeeping and latest
+/// actions moved into it.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+struct EscrowCoreV4 {
+    depositor: Address,
+    amount: i128,
+    remaining_amount: i128,
+    status: EscrowStatus,
+    deadline: u64,
+}
+
+/// The rarely changing `Escrow` fields, stored under `EscrowKey::Details`
+/// only when at least one of them is set.
+#[contracttype(export = false)]
+#[derive(Clone, Debug)]
+struct EscrowDetails {
+    refund_history: Vec<RefundRecord>,
+    metadata_hash: Option<Bytes>,
+    label: Option<Symbol>,
+}
+
+/// Storage keys. A key is stored as its case name and fields only, not the
+/// enum it belongs to, so case names must not repeat across `DataKey` and
+/// the other key enums.
+///
+/// `DataKey` is at the contract-spec limit of 50 union cases. New keys go in
+/// `ConfigKey`, `EscrowKey` or the key enum of the module that owns them.
 #[contracttype]
 pub enum DataKey {
     Admin,
@@ -469,11 +972,6 @@ pub enum DataKey {
     ClaimTicketIndex,       // Vec<u64> of all ticket_ids
     TicketCounter,          // u64 counter for generating unique ticket_ids
     BeneficiaryTickets(Address), // Address -> Vec<u64> of ticket_ids for beneficiary
-    ReleaseApproval(u64), // bounty_id -> ReleaseApproval
-    PendingClaim(u64),    // bounty_id -> ClaimRecord
-    ClaimWindow,          // u64 seconds (global config)
-    PauseFlags,           // PauseFlags struct
-    AmountPolicy, // Option<(i128, i128)> — (min_amount, max_amount) set by set_amount_policy
     CapabilityNonce, // monotonically increasing capability id
     Capability(u64), // capability_id -> Capability
 
@@ -482,6 +980,109 @@ pub enum DataKey {
 
     /// Network identifier (e.g., "mainnet", "testnet", "futurenet") for environment-specific behavior
     NetworkId,
+
+    /// bounty_id -> Vec<MilestoneRecord>
+    Milestones(u64),
+    /// Address allowed to resolve disputes
+    Arbiter,
+    /// bounty_id -> Dispute
+    Dispute(u64),
+    /// (role, account) -> bool, see `rbac`
+    Role(Role, Address),
+    /// u64 seconds a single `extend_deadline` call may push the deadline
+    MaxDeadlineExtension,
+    /// bounty_id -> release fee rate (bps) snapshotted at lock time
+    EscrowReleaseFeeRate(u64),
+    /// bounty_id -> Vec<HistoryEntry>, oldest first, at most
+    /// `MAX_HISTORY_ENTRIES`, but for the latest actions kept in the escrow's
+    /// core entry
+    EscrowHistory(u64),
+    /// status -> Vec<u64> of bounty_ids currently in that status, before
+    /// schema v5 paged the indexes (see `escrow_index`)
+    StatusIndex(EscrowStatus),
+    /// contributor -> Vec<u64> of bounty_ids that paid out to them, moved
+    /// into the paged index on the contributor's next payout
+    ContributorIndex(Address),
+    /// ContractStats, maintained incrementally
+    ContractStats,
+    /// u64 seconds a queued admin operation must wait before execution
+    TimelockDelay,
+    /// monotonically increasing id for queued admin operations
+    AdminOpNonce,
+    /// op_id -> QueuedAdminOp
+    AdminOp(u64),
+    /// RescueRequest awaiting `execute_rescue`
+    RescueRequest,
+    /// u32 layout version of persistent records, see `get_schema_version`
+    SchemaVersion,
+    /// bounty_id -> u32 ledger the escrow record was last extended to,
+    /// before schema v5 kept it in `EscrowEntries`
+    EscrowLiveUntil(u64),
+    /// u64 seconds after the deadline before an expired escrow can be refunded
+    RefundGracePeriod,
+    /// bounty_id -> VestingStream
+    VestingStream(u64),
+    /// Address of the reputation contract payouts are reported to
+    ReputationContract,
+    /// Address of the arbitration contract disputes are delegated to
+    ArbitrationContract,
+    /// bounty_id -> Address of the hook contract notified on release/refund
+    EscrowHook(u64),
+    /// Address of the badge contract completion badges are minted from
+    BadgeContract,
+}
+
+/// Contract-wide configuration keys added once `DataKey` was full.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigKey {
+    /// u64 seconds a refund approval stays executable; 0 means no expiry
+    RefundApprovalWindow,
+    /// Address of the platform contract allowed to act on behalf of users
+    OperatorContract,
+    /// bool, true while only allowlisted depositors may lock funds
+    DepositorAllowlistMode,
+    /// bool, true when slashed contributor bonds go to the treasury
+    SlashBondsToTreasury,
+    /// Address proposed as fee recipient, pending `accept_treasury_role`
+    PendingTreasury,
+    /// i128, release fees held by the contract until `withdraw_fees`
+    AccruedFees,
+    /// Address -> bool, present when payouts to the address are blocked
+    Blocked(Address),
+}
+
+/// Per-escrow keys added once `DataKey` was full.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EscrowKey {
+    /// bounty_id -> BytesN<32> key passed to `lock_funds_idempotent`
+    IdempotencyKey(u64),
+    /// bounty_id -> Address the depositor's refunds go to instead of them
+    RefundDestination(u64),
+    /// bounty_id -> Assignment awaiting or holding the contributor's acceptance
+    Assignment(u64),
+    /// bounty_id -> WorkSubmission, the latest work submitted by the assignee
+    Submission(u64),
+    /// bounty_id -> bool, present when releases need a work submission
+    SubmissionRequired(u64),
+    /// bounty_id -> u64 seconds the depositor has to dispute submitted work
+    ReviewPeriod(u64),
+    /// bounty_id -> u64 deadline last announced by `ping_expiring`
+    ExpiryNotified(u64),
+    /// bounty_id -> EscrowDetails, the cold half of the escrow record
+    Details(u64),
+    /// bounty_id -> u64 sequence number of the escrow's latest event, before
+    /// schema v5 kept it in `EscrowEntries`
+    EventSequence(u64),
+    /// bounty_id -> Address allowed to release that escrow besides releasers
+    Approver(u64),
+    /// bounty_id -> BytesN<32> ed25519 key the approver signs vouchers with
+    VoucherSigner(u64),
+    /// bounty_id -> u64 highest voucher nonce redeemed
+    VoucherNonce(u64),
+    /// bounty_id -> EscrowFreeze while the escrow is under a hold
+    Freeze(u64),
 }
 
 #[contracttype]
@@ -491,6 +1092,27 @@ pub struct EscrowWithId {
     pub escrow: Escrow,
 }
 
+/// One row of `export_escrow_snapshot`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSnapshot {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    /// Assignee, or else the recipient of a pending claim, if any.
+    pub contributor: Option<Address>,
+    pub amount: i128,
+    /// Paid to contributors, gross of fees.
+    pub released: i128,
+    pub refunded: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+    /// Time of the lock, if still in the escrow's history.
+    pub locked_at: Option<u64>,
+    /// Time of the latest entry in the escrow's history.
+    pub updated_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseFlags {
@@ -512,6 +1134,107 @@ pub struct AggregateStats {
     pub count_refunded: u32,
 }
 
+/// Contract-wide totals returned by `get_contract_stats`. Updated on every
+/// escrow write and fund movement, so reading them is O(1).
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContractStats {
+    pub count_locked: u32,
+    pub count_released: u32,
+    pub count_refunded: u32,
+    pub count_partially_refunded: u32,
+    pub count_cancelled: u32,
+    /// Sum of `remaining_amount` across all escrows.
+    pub total_value_locked: i128,
+    /// Gross amount paid out to contributors, including release fees.
+    pub total_released: i128,
+    /// Amount returned to depositors or refund recipients.
+    pub total_refunded: i128,
+    /// Release fees sent to the fee recipient.
+    pub total_fees_collected: i128,
+}
+
+impl ContractStats {
+    fn count_mut(&mut self, status: &EscrowStatus) -> &mut u32 {
+        match status {
+            EscrowStatus::Locked => &mut self.count_locked,
+            EscrowStatus::Released => &mut self.count_released,
+            EscrowStatus::Refunded => &mut self.count_refunded,
+            EscrowStatus::PartiallyRefunded => &mut self.count_partially_refunded,
+            EscrowStatus::Cancelled => &mut self.count_cancelled,
+        }
+    }
+}
+
+/// Contract-wide accounting returned by `check_invariants`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantReport {
+    /// Escrow token the contract owes, as computed for
+    /// `get_untracked_balance`.
+    pub tracked: i128,
+    /// Escrow token the contract holds.
+    pub balance: i128,
+    pub total_value_locked: i128,
+    /// `balance` covers `tracked` and no running total is negative.
+    pub holds: bool,
+}
+
+/// Archival status of an escrow record, returned by `get_escrow_ttl`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTtl {
+    /// Last ledger the record is guaranteed to stay live, or 0 if it has not
+    /// been extended since TTL tracking was introduced.
+    pub live_until_ledger: u32,
+    /// Ledgers left before the record is archived, as of the current ledger.
+    pub ledgers_remaining: u32,
+}
+
+/// When and how far escrow records are extended, see the `storage_policy`
+/// module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlPolicy {
+    /// Records are re-extended once fewer than this many ledgers remain.
+    pub threshold: u32,
+    /// Ledgers a record is kept live for after each extension.
+    pub extend_to: u32,
+}
+
+/// Escrow state with fields derived from the contract's rules, returned by
+/// `get_escrow_summary`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSummary {
+    pub status: EscrowStatus,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    /// Seconds left until the deadline; 0 once it has passed.
+    pub seconds_until_deadline: u64,
+    /// Share of `amount` paid out to contributors, in basis points.
+    pub released_bps: u32,
+    /// Whether `refund` would succeed at the current ledger state.
+    pub refund_eligible: bool,
+    /// Whether the escrow is under a `freeze_escrow` hold.
+    pub frozen: bool,
+}
+
+/// What remains of an escrow after `sweep_stale_escrows`, returned by
+/// `get_archived_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivedEscrow {
+    pub depositor: Address,
+    pub amount: i128,
+    /// `Released` or `Refunded`, or `Locked` for an escrow whose funds were
+    /// moved to another id by `reassign_escrow`.
+    pub status: EscrowStatus,
+    /// Timestamp of the last recorded action on the escrow.
+    pub settled_at: u64,
+    pub archived_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseStateChanged {
@@ -540,99 +1263,432 @@ pub struct FeeConfig {
     pub fee_enabled: bool,
 }
 
+/// Contract-wide settings in one read, returned by `get_config`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct MultisigConfig {
-    pub threshold_amount: i128,
-    pub signers: Vec<Address>,
-    pub required_signatures: u32,
+pub struct Config {
+    pub admin: Address,
+    pub token: Address,
+    /// Fee rates and the treasury they are paid to.
+    pub fee_config: FeeConfig,
+    pub pause_flags: PauseFlags,
+    /// Bounds set with `set_amount_policy`, if any.
+    pub min_escrow_amount: Option<i128>,
+    pub max_escrow_amount: Option<i128>,
+    /// Most items accepted by a batch call.
+    pub max_batch_size: u32,
+    /// Caps set with `set_release_rate_limit`; all zero, i.e. no caps, if
+    /// none is set.
+    pub release_rate_limit: ReleaseRateLimit,
 }
 
+/// Sensitive admin operation that must be queued while a timelock delay is
+/// configured. Each variant carries the arguments of the direct entry point.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ReleaseApproval {
-    pub bounty_id: u64,
-    pub contributor: Address,
-    pub approvals: Vec<Address>,
+pub enum AdminOp {
+    /// `update_fee_config(lock_fee_rate, release_fee_rate, fee_recipient, fee_enabled)`
+    UpdateFeeConfig(Option<i128>, Option<i128>, Option<Address>, Option<bool>),
+    /// `emergency_withdraw(target)`
+    EmergencyWithdraw(Address),
+    /// Lower the timelock delay (raising it never needs the queue).
+    SetTimelockDelay(u64),
+    /// `upgrade(new_wasm_hash)`
+    Upgrade(BytesN<32>),
+    /// `set_paused(lock, release, refund, None)`
+    SetPaused(Option<bool>, Option<bool>, Option<bool>),
+    /// `rescue_untracked_tokens(token)`
+    RescueUntrackedTokens(Address),
+    /// Replace the admin council's members and threshold.
+    SetCouncil(Vec<Address>, u32),
+    /// `set_migration_peer(peer, trusted)`
+    SetMigrationPeer(Address, bool),
+    /// `grant_role(_, role, account)`
+    GrantRole(Role, Address),
+    /// `revoke_role(_, role, account)`
+    RevokeRole(Role, Address),
 }
 
 #[contracttype]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(u32)]
-pub enum DisputeReason {
-    QualityIssue = 1,
-    IncompleteWork = 2,
-    DeadlineMissed = 3,
-    ParticipantFraud = 4,
-    Other = 5,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedAdminOp {
+    pub op: AdminOp,
+    pub queued_at: u64,
+    /// Earliest timestamp at which `execute_admin_op` may run the operation.
+    pub eta: u64,
 }
 
+/// Cap on how much a depositor may lock per period; see
+/// `set_depositor_budget`.
 #[contracttype]
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-#[repr(u32)]
-pub enum DisputeOutcome {
-    ResolvedByPayout = 1,
-    ResolvedByRefund = 2,
-    CancelledByAdmin = 3,
-    NoActionTaken = 4,
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositorBudget {
+    pub limit: i128,
+    /// Length of a budget window in seconds; 0 caps the lifetime total.
+    pub period: u64,
+    pub window_start: u64,
+    /// Amount locked or topped up in the current window.
+    pub spent: i128,
 }
 
+/// Contributor a bounty was assigned to; see `assign_contributor`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct ClaimRecord {
-    pub bounty_id: u64,
-    pub recipient: Address,
-    pub amount: i128,
-    pub expires_at: u64,
-    pub claimed: bool,
-    pub reason: DisputeReason,
+pub struct Assignment {
+    pub contributor: Address,
+    /// Last timestamp at which the contributor may accept.
+    pub accept_by: u64,
+    pub accepted_at: Option<u64>,
+    /// Bond the contributor posts on acceptance; 0 for none.
+    pub bond: i128,
 }
 
+/// Caps on releases per time window, see the `release_limits` module.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum CapabilityAction {
-    Claim,
-    Release,
-    Refund,
+pub struct ReleaseRateLimit {
+    /// Length of a window in seconds.
+    pub window: u64,
+    /// Most that may be released across all escrows per window; 0 for no cap.
+    pub global_limit: i128,
+    /// Most that may be released from one escrow per window; 0 for no cap.
+    pub escrow_limit: i128,
 }
 
+/// Amount released in the window that began at `start`.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Capability {
-    pub owner: Address,
-    pub holder: Address,
-    pub action: CapabilityAction,
-    pub bounty_id: u64,
-    pub amount_limit: i128,
-    pub remaining_amount: i128,
-    pub expiry: u64,
-    pub remaining_uses: u32,
-    pub revoked: bool,
+pub struct ReleaseWindow {
+    pub start: u64,
+    pub released: i128,
 }
 
+/// Crowdfunding target of an escrow, see the `funders` module.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum RefundMode {
-    Full,
-    Partial,
+pub struct FundingGoal {
+    /// `Escrow::amount` that must be reached before anything is released.
+    pub goal: i128,
+    /// Time by which the goal must be reached.
+    pub deadline: u64,
 }
 
+/// Quadratic funding round, see the `quadratic_funding` module.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct RefundApproval {
-    pub bounty_id: u64,
-    pub amount: i128,
-    pub recipient: Address,
-    pub mode: RefundMode,
-    pub approved_by: Address,
-    pub approved_at: u64,
+pub struct FundingRound {
+    /// Address that put up the matching pool and gets back what is unused.
+    pub sponsor: Address,
+    pub matching_pool: i128,
+    /// Escrows that can be backed in the round.
+    pub bounty_ids: Vec<u64>,
+    /// Time after which contributions stop and the round can be closed.
+    pub ends_at: u64,
+    pub closed: bool,
+    /// Matching pushed into escrows when the round was closed.
+    pub matched: i128,
 }
 
-/// Result returned by dry-run simulation entrypoints.
-///
-/// These view functions run the full validation pipeline for lock / release /
-/// refund without mutating any state.  They allow UIs and integrators to
-/// preview what *would* happen and surface errors before submitting a real
+/// Terms of escrow insurance set by the admin, see the `insurance` module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceConfig {
+    /// Premium in basis points of the covered amount.
+    pub premium_bps: u32,
+    /// Most a single policy covers.
+    pub max_coverage: i128,
+}
+
+/// Insurance bought for one escrow with `insure_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsurancePolicy {
+    pub depositor: Address,
+    pub coverage: i128,
+    pub premium: i128,
+    /// Set once `pay_insurance_claim` paid out on the policy.
+    pub claimed: bool,
+}
+
+/// Referrer of an escrow and the referral fee rate in effect when it was
+/// recorded, see the `referrals` module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Referral {
+    pub referrer: Address,
+    pub fee_bps: u32,
+}
+
+/// GitHub issue an escrow pays for, see the `issue_links` module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IssueLink {
+    /// `owner/name`
+    pub repo: String,
+    pub issue_number: u64,
+    /// Set once the attestor's signature was checked by `attest_issue_link`.
+    pub verified: bool,
+}
+
+/// When an escrow expires, see `lock_funds_with_deadline`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Deadline {
+    /// Ledger timestamp, as taken by `lock_funds`.
+    Time(u64),
+    /// Ledger sequence number, see the `ledger_deadlines` module.
+    Ledger(u32),
+}
+
+/// Limits on partial releases of one escrow, see the `payout_caps` module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutCaps {
+    /// Most a single partial release may pay; 0 for no cap.
+    pub max_release: i128,
+    /// Most partial releases may pay in total between payout reviews; 0 for
+    /// no cap.
+    pub max_cumulative: i128,
+}
+
+/// Escrow handed from one instance to another by `migrate_escrow`, see the
+/// `migration` module. The optional assignment, funding goal and referral
+/// are passed alongside it, because test builds can't convert an optional
+/// contract type field.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowMigration {
+    /// Token the funds are held in; must match the receiving instance's.
+    pub token: Address,
+    pub source_bounty_id: u64,
+    pub escrow: Escrow,
+    pub history: Vec<HistoryEntry>,
+    pub funders: Map<Address, i128>,
+}
+
+/// Bond posted by an assignee, see the `bonds` module.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorBond {
+    pub contributor: Address,
+    pub amount: i128,
+}
+
+/// Proof of delivery recorded by `submit_work`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkSubmission {
+    pub contributor: Address,
+    /// Hash of the delivered work, e.g. a commit or an archive digest.
+    pub submission_hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+/// Admin addresses that jointly replace the single admin for sensitive
+/// operations; see `set_admin_council`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminCouncil {
+    pub members: Vec<Address>,
+    /// Number of current members that must approve a proposal.
+    pub threshold: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilProposal {
+    pub op: AdminOp,
+    pub proposer: Address,
+    /// Members that have approved, the proposer included.
+    pub approvals: Vec<Address>,
+    pub proposed_at: u64,
+    /// Earliest timestamp at which `execute_council_op` may run the operation.
+    pub eta: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueRequest {
+    pub amount: i128,
+    /// Fee recipient (treasury) at request time; funds go here on execution.
+    pub recipient: Address,
+    pub requested_by: Address,
+    pub requested_at: u64,
+    pub executable_at: u64,
+}
+
+/// One `rescue_untracked_tokens` call, see `get_rescue_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueRecord {
+    pub token: Address,
+    /// 0 if there was nothing to rescue.
+    pub amount: i128,
+    /// Fee recipient (treasury) at the time.
+    pub recipient: Address,
+    pub rescued_by: Address,
+    pub timestamp: u64,
+}
+
+/// Pending announcement of `emergency_withdraw_all`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyExit {
+    pub announced_by: Address,
+    pub announced_at: u64,
+    pub executable_at: u64,
+}
+
+/// Who receives the interest earned while an escrow's funds sit in the
+/// yield strategy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum YieldBeneficiary {
+    /// The fee recipient.
+    Treasury,
+    Depositor,
+    /// Whoever the release that withdraws the funds pays. If the funds come
+    /// back for a refund or cancellation instead, the depositor.
+    Contributor,
+}
+
+/// An escrow's stake in a lending pool, see `get_yield_position`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldPosition {
+    pub pool: Address,
+    /// Share of the contract's position in `pool`.
+    pub shares: i128,
+    /// Amount deposited; anything withdrawn above it is interest.
+    pub principal: i128,
+    pub beneficiary: YieldBeneficiary,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MultisigConfig {
+    pub threshold_amount: i128,
+    pub signers: Vec<Address>,
+    pub required_signatures: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApproval {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub approvals: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DisputeReason {
+    QualityIssue = 1,
+    IncompleteWork = 2,
+    DeadlineMissed = 3,
+    ParticipantFraud = 4,
+    Other = 5,
+}
+
+#[contracttype]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum DisputeOutcome {
+    ResolvedByPayout = 1,
+    ResolvedByRefund = 2,
+    CancelledByAdmin = 3,
+    NoActionTaken = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRecord {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+    pub claimed: bool,
+    pub reason: DisputeReason,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CapabilityAction {
+    Claim,
+    Release,
+    Refund,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Capability {
+    pub owner: Address,
+    pub holder: Address,
+    pub action: CapabilityAction,
+    pub bounty_id: u64,
+    pub amount_limit: i128,
+    pub remaining_amount: i128,
+    pub expiry: u64,
+    pub remaining_uses: u32,
+    pub revoked: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RefundMode {
+    Full,
+    Partial,
+    /// The approved amount goes to the recipient and the given share to the
+    /// contributor (e.g. compensation for partial work), both paid by one
+    /// `refund` call. The contributor share is paid like a release.
+    Split(Address, i128),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundApproval {
+    pub bounty_id: u64,
+    pub amount: i128,
+    pub recipient: Address,
+    pub mode: RefundMode,
+    pub approved_by: Address,
+    pub approved_at: u64,
+}
+
+/// Linear payout of an escrow started by `release_stream`. The escrow stays
+/// `Locked` until the contributor has withdrawn everything.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VestingStream {
+    pub contributor: Address,
+    /// Amount streamed, i.e. the escrow's `remaining_amount` at the start.
+    pub total: i128,
+    pub withdrawn: i128,
+    pub start: u64,
+    pub duration: u64,
+}
+
+impl VestingStream {
+    /// Amount vested at `now`, growing linearly from 0 at `start` to
+    /// `total` at `start + duration`.
+    fn vested_at(&self, now: u64) -> i128 {
+        let elapsed = now.saturating_sub(self.start);
+        if elapsed >= self.duration {
+            return self.total;
+        }
+        self.total
+            .checked_mul(elapsed as i128)
+            .map(|scaled| scaled / self.duration as i128)
+            .unwrap_or_else(|| self.total / self.duration as i128 * elapsed as i128)
+    }
+}
+
+/// Result returned by dry-run simulation entrypoints.
+///
+/// These view functions run the full validation pipeline for lock / release /
+/// refund without mutating any state.  They allow UIs and integrators to
+/// preview what *would* happen and surface errors before submitting a real
 /// transaction.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -649,6 +1705,11 @@ pub struct SimulationResult {
     pub resulting_status: EscrowStatus,
     /// Remaining amount in the escrow *after* the simulated operation.
     pub remaining_amount: i128,
+    /// Fee the contract would keep for the treasury.
+    pub fee: i128,
+    /// Transfers out of the contract the operation would make, in order, as
+    /// `(recipient, amount)`. Empty on failure and for `simulate_lock`.
+    pub transfers: Vec<(Address, i128)>,
 }
 
 #[contracttype]
@@ -660,6 +1721,30 @@ pub struct RefundRecord {
     pub mode: RefundMode,
 }
 
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EscrowAction {
+    Locked,
+    ToppedUp,
+    Released,
+    Refunded,
+    Cancelled,
+    DeadlineExtended,
+}
+
+/// One entry of an escrow's on-chain audit trail, see `get_escrow_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HistoryEntry {
+    /// Address that authorized the action. Plain `refund` calls are
+    /// permissionless once eligible, so for those the recipient is recorded.
+    pub actor: Address,
+    pub action: EscrowAction,
+    /// Tokens moved by the action (gross of fees); 0 for deadline changes.
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
 /// Single-use claim ticket for bounty winners
 /// Simplifies reward distribution and prevents misdirected payouts
 #[contracttype]
@@ -690,6 +1775,107 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
+/// Outcome of a single item in `batch_release` / `batch_refund`.
+/// `error_code` is the `Error` discriminant, or 0 on success.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchItemResult {
+    pub bounty_id: u64,
+    pub success: bool,
+    pub amount: i128,
+    pub error_code: u32,
+}
+
+/// A single payout stage supplied to `lock_funds_with_milestones`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Milestone {
+    pub amount: i128,
+    /// Hash of the off-chain description of the deliverable.
+    pub description_hash: BytesN<32>,
+    pub deadline: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MilestoneStatus {
+    Pending,
+    Approved,
+    Released,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    Resolved,
+}
+
+/// Dispute raised by the depositor or contributor of a bounty.
+/// While `Open`, release and refund of the escrow are frozen.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub raised_by: Address,
+    pub reason_hash: BytesN<32>,
+    pub status: DisputeStatus,
+    pub opened_at: u64,
+    /// Share of the remaining funds paid to the contributor, in basis points.
+    /// Zero until the dispute is resolved.
+    pub contributor_share_bps: u32,
+    pub resolved_at: u64,
+}
+
+/// Hold placed on a single escrow by the admin or arbiter, e.g. while
+/// suspected fraud is investigated. Release and refund are blocked until
+/// `unfreeze_escrow`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFreeze {
+    pub bounty_id: u64,
+    pub frozen_by: Address,
+    pub reason_hash: BytesN<32>,
+    pub frozen_at: u64,
+}
+
+/// One step of an `EscrowTemplate` milestone schedule.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateMilestone {
+    /// Share of the locked amount, in basis points; shares add up to 10_000.
+    pub share_bps: u32,
+    pub description_hash: BytesN<32>,
+    /// Seconds from lock time to the milestone deadline.
+    pub deadline_offset: u64,
+}
+
+/// Preset lock parameters used by `lock_funds_from_template`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowTemplate {
+    /// Seconds from lock time to the escrow deadline.
+    pub deadline_offset: u64,
+    /// Release fee rate (bps) for escrows locked from the template; `None`
+    /// snapshots the global rate as `lock_funds` does.
+    pub release_fee_rate: Option<i128>,
+    pub approver: Option<Address>,
+    /// Empty for a single payout.
+    pub milestones: Vec<TemplateMilestone>,
+}
+
+/// Stored milestone together with its approval / payout status.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MilestoneRecord {
+    pub amount: i128,
+    pub description_hash: BytesN<32>,
+    pub deadline: u64,
+    pub status: MilestoneStatus,
+}
+
 #[contract]
 pub struct BountyEscrowContract;
 
@@ -706,6 +1892,9 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::Token, &normalized_token);
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
 
         emit_bounty_initialized(
             &env,
@@ -753,6 +1942,10 @@ impl BountyEscrowContract {
             .instance()
             .set(&DataKey::Token, &normalized_token);
 
+        env.storage()
+            .instance()
+            .set(&DataKey::SchemaVersion, &STORAGE_SCHEMA_VERSION);
+
         // Store chain and network identifiers
         env.storage().instance().set(&DataKey::ChainId, &chain_id);
         env.storage()
@@ -791,22 +1984,297 @@ impl BountyEscrowContract {
             })
     }
 
-    /// Update fee configuration (admin only)
-    pub fn update_fee_config(
-        env: Env,
-        lock_fee_rate: Option<i128>,
-        release_fee_rate: Option<i128>,
-        fee_recipient: Option<Address>,
-        fee_enabled: Option<bool>,
-    ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Record the current release fee rate for a newly locked escrow so that
+    /// later fee changes do not apply retroactively.
+    fn snapshot_release_fee(env: &Env, bounty_id: u64) {
+        let config = Self::get_fee_config_internal(env);
+        if config.fee_enabled && config.release_fee_rate > 0 {
+            escrow_entries::set(
+                env,
+                bounty_id,
+                Entry::ReleaseFeeRate,
+                &config.release_fee_rate,
+            );
+        }
+    }
+
+    fn load_stats(env: &Env) -> ContractStats {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContractStats)
+            .unwrap_or_default()
+    }
+
+    fn save_stats(env: &Env, stats: &ContractStats) {
+        env.storage().instance().set(&DataKey::ContractStats, stats);
+    }
+
+    /// Append an entry to the history of `bounty_id`, dropping the oldest
+    /// entry once `MAX_HISTORY_ENTRIES` is reached, and add payouts and
+    /// refunds to the contract-wide totals. Returns the escrow as updated,
+    /// or `None` if it is not stored.
+    fn record_action(
+        env: &Env,
+        bounty_id: u64,
+        actor: &Address,
+        action: EscrowAction,
+        amount: i128,
+    ) -> Option<EscrowCore> {
+        let persistent = env.storage().persistent();
+        let key = DataKey::Escrow(bounty_id);
+        let core = persistent.get::<_, EscrowCore>(&key).map(|mut core| {
+            Self::push_history(env, bounty_id, &mut core, actor, action, amount);
+            persistent.set(&key, &core);
+            core
+        });
+        Self::count_action(env, action, amount);
+        core
+    }
+
+    /// `record_action` followed by an event of the escrow, which `publish`
+    /// numbers in the escrow's record so the escrow is written once.
+    fn record_and_publish(
+        env: &Env,
+        bounty_id: u64,
+        actor: &Address,
+        action: EscrowAction,
+        amount: i128,
+        publish: impl FnOnce(&mut EscrowEntries),
+    ) {
+        let persistent = env.storage().persistent();
+        let key = DataKey::Escrow(bounty_id);
+        match persistent.get::<_, EscrowCore>(&key) {
+            Some(mut core) => {
+                Self::push_history(env, bounty_id, &mut core, actor, action, amount);
+                publish(&mut core.entries);
+                persistent.set(&key, &core);
+            }
+            None => escrow_entries::update(env, bounty_id, publish),
+        }
+        Self::count_action(env, action, amount);
+    }
+
+    /// Append to the history of `bounty_id` kept in its `core`, which the
+    /// caller stores, moving a full page of actions to
+    /// `DataKey::EscrowHistory`.
+    fn push_history(
+        env: &Env,
+        bounty_id: u64,
+        core: &mut EscrowCore,
+        actor: &Address,
+        action: EscrowAction,
+        amount: i128,
+    ) {
+        core.history.push_back(HistoryEntry {
+            actor: actor.clone(),
+            action,
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        if core.history.len() < HISTORY_PAGE {
+            return;
+        }
+        let mut history: Vec<HistoryEntry> =
+            escrow_entries::get_in(env, bounty_id, &core.entries, Entry::History)
+                .unwrap_or(Vec::new(env));
+        history.append(&core.history);
+        let len = history.len();
+        let history = history.slice(len.saturating_sub(MAX_HISTORY_ENTRIES)..len);
+        escrow_entries::put(env, bounty_id, &mut core.entries, Entry::History, &history);
+        core.history = Vec::new(env);
+    }
+
+    fn count_action(env: &Env, action: EscrowAction, amount: i128) {
+        match action {
+            EscrowAction::Released => {
+                let mut stats = Self::load_stats(env);
+                stats.total_released += amount;
+                Self::save_stats(env, &stats);
+            }
+            EscrowAction::Refunded | EscrowAction::Cancelled => {
+                let mut stats = Self::load_stats(env);
+                stats.total_refunded += amount;
+                Self::save_stats(env, &stats);
+            }
+            _ => {}
+        }
+    }
+
+    /// Transfer a release payout to `recipient`, deducting the fee snapshotted
+    /// for `bounty_id` and sending it to the fee recipient (treasury), and
+    /// the referral fee, if the escrow has a referrer, sent to the referrer.
+    /// The payout is recorded in the escrow history under `actor` and the
+    /// bounty is added to the recipient's contributor index.
+    /// Returns the net amount received by `recipient`, or `Unauthorized` if
+    /// the recipient is on the payout blocklist, so no release path can pay
+    /// a blocked address.
+    fn pay_release(
+        env: &Env,
+        client: &token::Client,
+        bounty_id: u64,
+        actor: &Address,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        Self::ensure_not_blocked(env, recipient)?;
+        let EscrowCore {
+            amount: escrow_amount,
+            entries,
+            ..
+        } = Self::record_action(env, bounty_id, actor, EscrowAction::Released, amount)
+            .ok_or(Error::BountyNotFound)?;
+        Self::index_payout(env, bounty_id, recipient, amount >= escrow_amount);
+
+        let contract_address = env.current_contract_address();
+        let fee_rate = Self::escrow_fee_rate(env, bounty_id, &entries);
+        let (fee, referral, net) = Self::release_shares(env, bounty_id, &entries, amount);
+        if fee > 0 {
+            let mut stats = Self::load_stats(env);
+            stats.total_fees_collected += fee;
+            Self::save_stats(env, &stats);
+
+            // Fees stay in the contract until the treasury is paid out by
+            // `withdraw_fees`.
+            let accrued = Self::get_accrued_fees(env.clone());
+            env.storage()
+                .instance()
+                .set(&ConfigKey::AccruedFees, &(accrued + fee));
+            let fee_recipient = Self::get_fee_config_internal(env).fee_recipient;
+            events::emit_fee_collected(
+                env,
+                events::FeeCollected {
+                    operation_type: events::FeeOperationType::Release,
+                    amount: fee,
+                    fee_rate,
+                    recipient: fee_recipient,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        if let Some((referrer, referral_fee)) = referral {
+            referrals::add_earnings(env, &referrer, referral_fee);
+            client.transfer(&contract_address, &referrer, &referral_fee);
+            events::emit_referral_paid(
+                env,
+                events::ReferralPaid {
+                    bounty_id,
+                    referrer,
+                    amount: referral_fee,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        client.transfer(&contract_address, recipient, &net);
+        if entries.has(Entry::HeldInterest) {
+            if let Some((principal, withdrawn)) = yield_strategy::take_held_interest(env, bounty_id)
+            {
+                Self::pay_interest(env, bounty_id, recipient, principal, withdrawn);
+            }
+        }
+        Self::notify_release(env, bounty_id, &entries, recipient, net);
+        Ok(net)
+    }
+
+    /// How a release of `amount` from `bounty_id` is divided: the fee kept
+    /// for the treasury, the referrer's cut if the escrow has one, and the
+    /// net paid to the contributor. `entries` is the escrow's bookkeeping
+    /// record.
+    fn release_shares(
+        env: &Env,
+        bounty_id: u64,
+        entries: &EscrowEntries,
+        amount: i128,
+    ) -> (i128, Option<(Address, i128)>, i128) {
+        let fee_rate = Self::escrow_fee_rate(env, bounty_id, entries);
+        let (fee, mut net) = token_math::split_amount(amount, fee_rate);
+        let mut referral = None;
+        let link: Option<Referral> =
+            escrow_entries::get_in(env, bounty_id, entries, Entry::Referral);
+        if let Some(link) = link {
+            let referral_fee = token_math::calculate_fee(amount, link.fee_bps as i128).min(net);
+            if referral_fee > 0 {
+                net -= referral_fee;
+                referral = Some((link.referrer, referral_fee));
+            }
+        }
+        (fee, referral, net)
+    }
+
+    /// Transfers `pay_release` makes for a release of `amount`, and the fee
+    /// it keeps, for the simulations.
+    fn release_transfers(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+        amount: i128,
+    ) -> (i128, Vec<(Address, i128)>) {
+        let entries = escrow_entries::load(env, bounty_id);
+        let (fee, referral, net) = Self::release_shares(env, bounty_id, &entries, amount);
+        let mut transfers = Vec::new(env);
+        if let Some(referral) = referral {
+            transfers.push_back(referral);
+        }
+        transfers.push_back((recipient.clone(), net));
+        (fee, transfers)
+    }
+
+    /// Report a payout to the reputation contract and the escrow's hook.
+    /// `entries` is the escrow's bookkeeping record.
+    fn notify_release(
+        env: &Env,
+        bounty_id: u64,
+        entries: &EscrowEntries,
+        recipient: &Address,
+        amount: i128,
+    ) {
+        reputation::report_release(env, bounty_id, recipient, amount);
+        if entries.has(Entry::Hook) {
+            hooks::on_release(env, bounty_id, recipient, amount);
+        }
+    }
+
+    /// Report a refund to the reputation contract and the escrow's hook.
+    fn notify_refund(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+        reputation::report_refund(env, bounty_id, recipient, amount);
+        hooks::on_refund(env, bounty_id, recipient, amount);
+    }
+
+    /// Update fee configuration (admin only). A new `fee_recipient` only
+    /// proposes the treasury; fees and rescues keep going to the current one
+    /// until the proposed address calls `accept_treasury_role`.
+    pub fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        Self::ensure_no_council(&env)?;
+        Self::ensure_no_timelock(&env)?;
 
-        let mut fee_config = Self::get_fee_config_internal(&env);
+        Self::apply_fee_config(
+            &env,
+            lock_fee_rate,
+            release_fee_rate,
+            fee_recipient,
+            fee_enabled,
+        )
+    }
+
+    fn apply_fee_config(
+        env: &Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    ) -> Result<(), Error> {
+        let mut fee_config = Self::get_fee_config_internal(env);
 
         if let Some(rate) = lock_fee_rate {
             if !(0..=MAX_FEE_RATE).contains(&rate) {
@@ -823,7 +2291,21 @@ impl BountyEscrowContract {
         }
 
         if let Some(recipient) = fee_recipient {
-            fee_config.fee_recipient = recipient;
+            if recipient == fee_config.fee_recipient {
+                env.storage().instance().remove(&ConfigKey::PendingTreasury);
+            } else {
+                env.storage()
+                    .instance()
+                    .set(&ConfigKey::PendingTreasury, &recipient);
+                events::emit_treasury_proposed(
+                    env,
+                    events::TreasuryProposed {
+                        current: fee_config.fee_recipient.clone(),
+                        proposed: recipient,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
         }
 
         if let Some(enabled) = fee_enabled {
@@ -835,7 +2317,7 @@ impl BountyEscrowContract {
             .set(&DataKey::FeeConfig, &fee_config);
 
         events::emit_fee_config_updated(
-            &env,
+            env,
             events::FeeConfigUpdated {
                 lock_fee_rate: fee_config.lock_fee_rate,
                 release_fee_rate: fee_config.release_fee_rate,
@@ -856,18 +2338,44 @@ impl BountyEscrowContract {
         refund: Option<bool>,
         reason: Option<soroban_sdk::String>,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
+        Self::set_paused_logic(env, None, lock, release, refund, reason)
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        
-        // Validate and increment nonce to prevent replay
-        nonce::validate_and_increment_nonce(&env, &admin, nonce)
-            .map_err(|_| Error::InvalidNonce)?;
+    /// Update pause flags as a holder of the `Pauser` role.
+    pub fn set_paused_with_role(
+        env: Env,
+        caller: Address,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<soroban_sdk::String>,
+    ) -> Result<(), Error> {
+        Self::set_paused_logic(env, Some(caller), lock, release, refund, reason)
+    }
+
+    fn set_paused_logic(
+        env: Env,
+        caller: Option<Address>,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<soroban_sdk::String>,
+    ) -> Result<(), Error> {
+        let admin = rbac::authorize(&env, caller, Role::Pauser)?;
+        Self::ensure_not_sole_admin(&env, &admin)?;
+        Self::apply_pause_flags(&env, admin, lock, release, refund, reason);
+        Ok(())
+    }
 
-        let mut flags = Self::get_pause_flags(&env);
+    fn apply_pause_flags(
+        env: &Env,
+        admin: Address,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<soroban_sdk::String>,
+    ) {
+        let mut flags = Self::get_pause_flags(env);
         let timestamp = env.ledger().timestamp();
 
         if reason.is_some() {
@@ -877,7 +2385,7 @@ impl BountyEscrowContract {
         if let Some(paused) = lock {
             flags.lock_paused = paused;
             events::emit_pause_state_changed(
-                &env,
+                env,
                 PauseStateChanged {
                     operation: symbol_short!("lock"),
                     paused,
@@ -891,7 +2399,7 @@ impl BountyEscrowContract {
         if let Some(paused) = release {
             flags.release_paused = paused;
             events::emit_pause_state_changed(
-                &env,
+                env,
                 PauseStateChanged {
                     operation: symbol_short!("release"),
                     paused,
@@ -905,7 +2413,7 @@ impl BountyEscrowContract {
         if let Some(paused) = refund {
             flags.refund_paused = paused;
             events::emit_pause_state_changed(
-                &env,
+                env,
                 PauseStateChanged {
                     operation: symbol_short!("refund"),
                     paused,
@@ -928,7 +2436,24 @@ impl BountyEscrowContract {
         }
 
         env.storage().instance().set(&DataKey::PauseFlags, &flags);
-        Ok(())
+    }
+
+    /// Emergency stop: pause lock, release, and refund in one call (admin only).
+    ///
+    /// View functions (`get_*`, `query_*`) remain available while paused.
+    pub fn pause(env: Env) -> Result<(), Error> {
+        Self::set_paused(env, Some(true), Some(true), Some(true), None)
+    }
+
+    /// Lift the emergency stop, re-enabling lock, release, and refund (admin only).
+    pub fn unpause(env: Env) -> Result<(), Error> {
+        Self::set_paused(env, Some(false), Some(false), Some(false), None)
+    }
+
+    /// View: `true` if any of lock, release, or refund is currently paused.
+    pub fn is_paused(env: Env) -> bool {
+        let flags = Self::get_pause_flags(&env);
+        flags.lock_paused || flags.release_paused || flags.refund_paused
     }
 
     /// Emergency withdraw all funds (admin only, must have lock_paused = true)
@@ -937,23 +2462,46 @@ impl BountyEscrowContract {
     /// Protected by the shared reentrancy guard. The token transfer is the
     /// last operation (checks-effects-interactions).
     pub fn emergency_withdraw(env: Env, target: Address) -> Result<(), Error> {
+        Self::emergency_withdraw_logic(env, None, target)
+    }
+
+    /// Emergency withdraw as a holder of the `Rescuer` role.
+    pub fn emergency_withdraw_with_role(
+        env: Env,
+        caller: Address,
+        target: Address,
+    ) -> Result<(), Error> {
+        Self::emergency_withdraw_logic(env, Some(caller), target)
+    }
+
+    fn emergency_withdraw_logic(
+        env: Env,
+        caller: Option<Address>,
+        target: Address,
+    ) -> Result<(), Error> {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(Error::NotInitialized)?;
-        admin.require_auth();
+        let admin = rbac::authorize(&env, caller, Role::Rescuer)?;
+        Self::ensure_not_sole_admin(&env, &admin)?;
+        Self::ensure_no_timelock(&env)?;
+        Self::withdraw_all(&env, admin, target)?;
 
-        let flags = Self::get_pause_flags(&env);
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Move the whole token balance to `target`. Only allowed while lock
+    /// operations are paused.
+    fn withdraw_all(env: &Env, admin: Address, target: Address) -> Result<(), Error> {
+        let flags = Self::get_pause_flags(env);
         if !flags.lock_paused {
             return Err(Error::NotPaused);
         }
 
         let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::TokenClient::new(&env, &token_address);
+        let token_client = token::TokenClient::new(env, &token_address);
 
         let contract_address = env.current_contract_address();
         let balance = token_client.balance(&contract_address);
@@ -962,7 +2510,7 @@ impl BountyEscrowContract {
             // INTERACTION: external token transfer is last
             token_client.transfer(&contract_address, &target, &balance);
             events::emit_emergency_withdraw(
-                &env,
+                env,
                 events::EmergencyWithdrawEvent {
                     admin,
                     recipient: target,
@@ -971,519 +2519,5880 @@ impl BountyEscrowContract {
                 },
             );
         }
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Get current pause flags
-    pub fn get_pause_flags(env: &Env) -> PauseFlags {
-        env.storage()
-            .instance()
-            .get(&DataKey::PauseFlags)
-            .unwrap_or(PauseFlags {
-                lock_paused: false,
-                release_paused: false,
-                refund_paused: false,
-                pause_reason: None,
-                paused_at: 0,
-            })
+    /// Amount of `token` the contract owes and must hold itself: the total
+    /// remaining amount, less principal deposited in the yield strategy, plus
+    /// interest held for contributors, contributor bonds, the matching
+    /// pools of open funding rounds and the insurance pool, for the escrow
+    /// token and zero for any other asset.
+    fn tracked_balance(env: &Env, token: &Address) -> i128 {
+        if !Self::list_tracked_tokens(env.clone()).contains(token) {
+            return 0;
+        }
+        Self::get_tvl_by_token(env.clone(), token.clone()) - yield_strategy::invested_principal(env)
+            + yield_strategy::held_interest(env)
+            + bonds::total_held(env)
+            + quadratic_funding::total_pooled(env)
+            + insurance::pool(env)
+            + Self::get_accrued_fees(env.clone())
     }
 
-    /// Check if an operation is paused
-    fn check_paused(env: &Env, operation: Symbol) -> bool {
-        let flags = Self::get_pause_flags(env);
-        if operation == symbol_short!("lock") {
-            return flags.lock_paused;
-        } else if operation == symbol_short!("release") {
-            return flags.release_paused;
-        } else if operation == symbol_short!("refund") {
-            return flags.refund_paused;
+    /// View: the assets escrows hold value in. An instance escrows the
+    /// single token it was initialized with, so this is that token, or
+    /// nothing before `init`.
+    pub fn list_tracked_tokens(env: Env) -> Vec<Address> {
+        let mut tokens = Vec::new(&env);
+        let token: Option<Address> = env.storage().instance().get(&DataKey::Token);
+        if let Some(token) = token {
+            tokens.push_back(token);
         }
-        false
+        tokens
     }
 
-    fn next_capability_id(env: &Env) -> u64 {
-        let last_id: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::CapabilityNonce)
-            .unwrap_or(0);
-        let next_id = last_id.saturating_add(1);
-        env.storage()
-            .instance()
-            .set(&DataKey::CapabilityNonce, &next_id);
-        next_id
+    /// View: total remaining amount of escrows denominated in `token`; 0 for
+    /// assets not in `list_tracked_tokens`.
+    pub fn get_tvl_by_token(env: Env, token: Address) -> i128 {
+        if Self::list_tracked_tokens(env.clone()).contains(&token) {
+            Self::load_stats(&env).total_value_locked
+        } else {
+            0
+        }
     }
 
-    fn load_capability(env: &Env, capability_id: u64) -> Result<Capability, Error> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Capability(capability_id))
-            .ok_or(Error::CapabilityNotFound)
+    /// Circuit breaker run at the end of every guarded call: the escrow
+    /// token balance must cover the value locked. A shortfall means tracked
+    /// accounting and the token ledger disagree (a fee-on-transfer token, a
+    /// clawback, or an exploit), so releases are paused and an alert is
+    /// emitted before later payouts can drain other escrows' funds. The
+    /// triggering call itself is not reverted, so the pause persists.
+    fn check_balance_invariant(env: &Env) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let actual = token::Client::new(env, &token_addr).balance(&env.current_contract_address());
+        Self::check_balance_invariant_at(env, token_addr, actual);
     }
 
-    fn validate_capability_scope_at_issue(
-        env: &Env,
-        owner: &Address,
-        action: &CapabilityAction,
-        bounty_id: u64,
-        amount_limit: i128,
-    ) -> Result<(), Error> {
-        if amount_limit <= 0 {
-            return Err(Error::InvalidAmount);
+    /// `check_balance_invariant` for a caller that read the contract's
+    /// `actual` balance of `token_addr` after its last transfer.
+    fn check_balance_invariant_at(env: &Env, token_addr: Address, actual: i128) {
+        let tracked = Self::tracked_balance(env, &token_addr);
+        if tracked <= actual {
+            return;
         }
 
-        match action {
-            CapabilityAction::Claim => {
-                let claim: ClaimRecord = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::PendingClaim(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if claim.claimed {
-                    return Err(Error::FundsNotLocked);
-                }
-                if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
-                }
-                if claim.recipient != owner.clone() {
-                    return Err(Error::Unauthorized);
-                }
-                if amount_limit > claim.amount {
-                    return Err(Error::CapabilityExceedsAuthority);
-                }
-            }
-            CapabilityAction::Release => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != owner.clone() {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
-                }
-                if amount_limit > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+        let timestamp = env.ledger().timestamp();
+        events::emit_balance_invariant_violated(
+            env,
+            events::BalanceInvariantViolated {
+                token: token_addr,
+                tracked,
+                actual,
+                timestamp,
+            },
+        );
+
+        let mut flags = Self::get_pause_flags(env);
+        if flags.release_paused {
+            return;
+        }
+        let reason = soroban_sdk::String::from_str(env, "balance invariant violated");
+        flags.release_paused = true;
+        flags.pause_reason = Some(reason.clone());
+        if flags.paused_at == 0 {
+            flags.paused_at = timestamp;
+        }
+        env.storage().instance().set(&DataKey::PauseFlags, &flags);
+        events::emit_pause_state_changed(
+            env,
+            PauseStateChanged {
+                operation: symbol_short!("release"),
+                paused: true,
+                admin: env.current_contract_address(),
+                reason: Some(reason),
+                timestamp,
+            },
+        );
+    }
+
+    /// View: balance of `token` held by the contract beyond what escrows are
+    /// owed, i.e. what `rescue_untracked_tokens` would transfer. Returns
+    /// `BalanceMismatch` while the contract holds less than it owes.
+    pub fn get_untracked_balance(env: Env, token: Address) -> Result<i128, Error> {
+        if !env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::NotInitialized);
+        }
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let untracked = balance - Self::tracked_balance(&env, &token);
+        if untracked < 0 {
+            return Err(Error::BalanceMismatch);
+        }
+        Ok(untracked)
+    }
+
+    /// View: how much less of the escrow token the contract holds than it
+    /// owes; 0 when the balance covers every escrow.
+    pub fn get_balance_shortfall(env: Env) -> Result<i128, Error> {
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let balance =
+            token::Client::new(&env, &token_addr).balance(&env.current_contract_address());
+        Ok((Self::tracked_balance(&env, &token_addr) - balance).max(0))
+    }
+
+    /// Write off the escrow token shortfall against `bounty_id`, e.g. after
+    /// the issuer of a clawback-enabled asset clawed tokens back from the
+    /// contract (admin only). The escrow's `amount` and `remaining_amount`
+    /// drop by the shortfall, or by all that remains if that is less, so
+    /// tracked balances match the token again. Returns the amount written
+    /// off. Releases paused by the circuit breaker stay paused until the
+    /// admin lifts the pause.
+    pub fn reconcile_escrow(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+
+        let written_off = Self::get_balance_shortfall(env.clone())?.min(escrow.remaining_amount);
+        if written_off == 0 {
+            return Ok(0);
+        }
+        escrow.amount -= written_off;
+        escrow.remaining_amount -= written_off;
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+
+        events::emit_escrow_reconciled(
+            &env,
+            events::EscrowReconciled {
+                bounty_id,
+                written_off,
+                remaining_amount: escrow.remaining_amount,
+                reconciled_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(written_off)
+    }
+
+    /// View: check the contract-wide invariants the circuit breaker relies
+    /// on, without tripping it. Meant for monitoring and for test harnesses
+    /// that drive random operation sequences; per-escrow invariants are
+    /// checked by `check_escrow_invariants`.
+    pub fn check_invariants(env: Env) -> Result<InvariantReport, Error> {
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let stats = Self::load_stats(&env);
+        let tracked = Self::tracked_balance(&env, &token_addr);
+        let balance =
+            token::Client::new(&env, &token_addr).balance(&env.current_contract_address());
+        let holds = tracked <= balance
+            && stats.total_value_locked >= 0
+            && stats.total_released >= 0
+            && stats.total_refunded >= 0
+            && stats.total_fees_collected >= 0;
+        Ok(InvariantReport {
+            tracked,
+            balance,
+            total_value_locked: stats.total_value_locked,
+            holds,
+        })
+    }
+
+    /// View: ids of escrows at positions `offset..offset + limit` of the
+    /// escrow index whose amounts are negative, exceed what was locked, or
+    /// don't match their status. Empty when all hold.
+    pub fn check_escrow_invariants(env: Env, offset: u32, limit: u32) -> Vec<u64> {
+        let mut invalid = Vec::new(&env);
+        for bounty_id in escrow_index::range(&env, &IndexId::All, offset, limit).iter() {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
+                if !invariants::verify_escrow_invariants(&escrow) {
+                    invalid.push_back(bounty_id);
                 }
             }
-            CapabilityAction::Refund => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != owner.clone() {
-                    return Err(Error::Unauthorized);
+        }
+        invalid
+    }
+
+    /// Send tokens that are not owed to any escrow, e.g. transferred to the
+    /// contract by mistake, to the fee recipient (treasury). For the escrow
+    /// token only the surplus above the value locked is moved; for any other
+    /// asset the whole balance is. Admin only; while a timelock delay is set
+    /// it must be queued as `AdminOp::RescueUntrackedTokens`. Returns the
+    /// rescued amount. Every call is published and recorded, see
+    /// `get_rescue_history`.
+    pub fn rescue_untracked_tokens(env: Env, token: Address) -> Result<i128, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        Self::ensure_no_timelock(&env)?;
+        let amount = Self::apply_rescue_untracked(&env, admin, token)?;
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(amount)
+    }
+
+    fn apply_rescue_untracked(env: &Env, admin: Address, token: Address) -> Result<i128, Error> {
+        let amount = Self::get_untracked_balance(env.clone(), token.clone())?;
+        let recipient = Self::get_fee_config_internal(env).fee_recipient;
+        let timestamp = env.ledger().timestamp();
+        rescue_history::push(
+            env,
+            &RescueRecord {
+                token: token.clone(),
+                amount,
+                recipient: recipient.clone(),
+                rescued_by: admin.clone(),
+                timestamp,
+            },
+        );
+
+        if amount > 0 {
+            // INTERACTION: external token transfer is last
+            token::Client::new(env, &token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &amount,
+            );
+        }
+        events::emit_tokens_rescued(
+            env,
+            events::TokensRescued {
+                token,
+                amount,
+                recipient,
+                rescued_by: admin,
+                timestamp,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(env);
+        Ok(amount)
+    }
+
+    /// Turn donation mode on by designating `bounty_id` as the community
+    /// pool escrow untracked tokens are absorbed into, or off with `None`
+    /// (admin only). See the `donations` module.
+    pub fn set_donation_pool(env: Env, bounty_id: Option<u64>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if let Some(bounty_id) = bounty_id {
+            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                return Err(Error::BountyNotFound);
+            }
+        }
+        donations::set_pool(&env, bounty_id);
+        Ok(())
+    }
+
+    /// View: the community pool escrow, if donation mode is on.
+    pub fn get_donation_pool(env: Env) -> Option<u64> {
+        donations::pool(&env)
+    }
+
+    /// Add `amount` of the escrow token the contract holds beyond what it
+    /// owes to the community pool escrow `bounty_id`, growing its `amount`
+    /// and `remaining_amount` like a top-up (rescuer only).
+    ///
+    /// # Errors
+    /// * NotDonationPool - if donation mode is off or `bounty_id` is not the
+    ///   designated pool
+    /// * InsufficientFunds - if `amount` exceeds the untracked balance
+    pub fn absorb_untracked_into(env: Env, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        if donations::pool(&env) != Some(bounty_id) {
+            panic_with_error!(&env, donations::DonationError::NotDonationPool);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_no_stream(&env, bounty_id)?;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        if amount > Self::get_untracked_balance(env.clone(), token_addr)? {
+            return Err(Error::InsufficientFunds);
+        }
+
+        escrow.amount = escrow
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        escrow.remaining_amount += amount;
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::record_action(&env, bounty_id, &admin, EscrowAction::ToppedUp, amount);
+
+        events::emit_untracked_absorbed(
+            &env,
+            events::UntrackedAbsorbed {
+                bounty_id,
+                amount,
+                absorbed_by: admin,
+                new_amount: escrow.amount,
+                remaining_amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// View: up to `limit` recorded `rescue_untracked_tokens` calls from
+    /// position `offset`, oldest first.
+    pub fn get_rescue_history(env: Env, offset: u32, limit: u32) -> Vec<RescueRecord> {
+        rescue_history::page(&env, offset, limit)
+    }
+
+    /// Announce a rescue of `amount` escrow tokens to the treasury (admin
+    /// only). It can be executed with `execute_rescue` once the longer of
+    /// `MIN_RESCUE_DELAY` and the timelock delay has passed, giving
+    /// depositors time to react. Only one request may be pending.
+    pub fn request_rescue(env: Env, amount: i128) -> Result<(), Error> {
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if env.storage().instance().has(&DataKey::RescueRequest) {
+            panic_with_error!(&env, RescueError::RescuePending);
+        }
+
+        let now = env.ledger().timestamp();
+        let delay = MIN_RESCUE_DELAY.max(Self::get_timelock_delay(env.clone()));
+        let request = RescueRequest {
+            amount,
+            recipient: Self::get_fee_config_internal(&env).fee_recipient,
+            requested_by: admin,
+            requested_at: now,
+            executable_at: now.saturating_add(delay),
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::RescueRequest, &request);
+
+        events::emit_rescue_requested(
+            &env,
+            events::RescueRequested {
+                amount,
+                recipient: request.recipient,
+                requested_by: request.requested_by,
+                executable_at: request.executable_at,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Carry out the pending rescue once its delay has passed (admin only).
+    /// Like `emergency_withdraw`, lock operations must be paused.
+    pub fn execute_rescue(env: Env) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        let request: RescueRequest = env
+            .storage()
+            .instance()
+            .get(&DataKey::RescueRequest)
+            .unwrap_or_else(|| panic_with_error!(&env, RescueError::RescueNotRequested));
+        if env.ledger().timestamp() < request.executable_at {
+            panic_with_error!(&env, TimelockError::TimelockNotExpired);
+        }
+        if !Self::get_pause_flags(&env).lock_paused {
+            return Err(Error::NotPaused);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let contract_address = env.current_contract_address();
+        if client.balance(&contract_address) < request.amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // EFFECTS: clear the request before the external call (CEI)
+        env.storage().instance().remove(&DataKey::RescueRequest);
+
+        // INTERACTION: external token transfer is last
+        client.transfer(&contract_address, &request.recipient, &request.amount);
+
+        events::emit_rescue_executed(
+            &env,
+            events::RescueExecuted {
+                amount: request.amount,
+                recipient: request.recipient,
+                executed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Withdraw the pending rescue request (admin only).
+    pub fn cancel_rescue(env: Env) -> Result<(), Error> {
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        let request: RescueRequest = env
+            .storage()
+            .instance()
+            .get(&DataKey::RescueRequest)
+            .unwrap_or_else(|| panic_with_error!(&env, RescueError::RescueNotRequested));
+        env.storage().instance().remove(&DataKey::RescueRequest);
+
+        events::emit_rescue_cancelled(
+            &env,
+            events::RescueCancelled {
+                amount: request.amount,
+                cancelled_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the pending rescue request, if any.
+    pub fn get_rescue_request(env: Env) -> Option<RescueRequest> {
+        env.storage().instance().get(&DataKey::RescueRequest)
+    }
+
+    /// Announce that every open escrow will be refunded to its depositor
+    /// (admin only), e.g. to sunset the contract or recover from a critical
+    /// bug. `emergency_withdraw_all` becomes callable once the longer of
+    /// `EMERGENCY_EXIT_DELAY` and the timelock delay has passed.
+    pub fn announce_emergency_withdraw_all(env: Env) -> Result<(), Error> {
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        if emergency_exit::get(&env).is_some() {
+            panic_with_error!(&env, RescueError::RescuePending);
+        }
+
+        let now = env.ledger().timestamp();
+        let delay = EMERGENCY_EXIT_DELAY.max(Self::get_timelock_delay(env.clone()));
+        let exit = EmergencyExit {
+            announced_by: admin,
+            announced_at: now,
+            executable_at: now.saturating_add(delay),
+        };
+        emergency_exit::set(&env, &exit);
+
+        events::emit_emergency_exit_announced(
+            &env,
+            events::EmergencyExitAnnounced {
+                announced_by: exit.announced_by,
+                executable_at: exit.executable_at,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Refund the remaining amount of locked or partially refunded escrows to
+    /// their depositors once the announced delay has passed (admin only).
+    /// Lock operations must be paused.
+    ///
+    /// Works in batches of up to `max_count` escrows; call again until
+    /// `get_emergency_exit` returns `None`, which happens once a batch finds
+    /// nothing left to refund. Each batch resumes where the previous one
+    /// stopped. Frozen escrows, escrows with an open dispute and escrows
+    /// whose depositor is blocklisted are skipped for the rest of the exit:
+    /// a disputed escrow is left for the arbiter to settle through
+    /// `resolve_dispute`. Returns the number of escrows refunded by this
+    /// batch.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `max_count` is 0 or above MAX_BATCH_SIZE
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Every escrow in the batch is
+    /// settled before the first token transfer.
+    pub fn emergency_withdraw_all(env: Env, max_count: u32) -> Result<u32, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        if max_count == 0 || max_count > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let exit = emergency_exit::get(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, RescueError::RescueNotRequested));
+        let now = env.ledger().timestamp();
+        if now < exit.executable_at {
+            panic_with_error!(&env, TimelockError::TimelockNotExpired);
+        }
+        if !Self::get_pause_flags(&env).lock_paused {
+            return Err(Error::NotPaused);
+        }
+
+        // EFFECTS: settle every escrow in the batch before any external call
+        // (CEI). The Locked list and then the PartiallyRefunded list are
+        // walked from the end: a refunded escrow leaves its list and the last
+        // id, already visited, moves into its place, so the stored cursor
+        // stays valid between batches. Partial refunds only move escrows
+        // from the Locked list to the end of the other one.
+        let (mut status, mut cursor) = emergency_exit::cursor(&env).unwrap_or_else(|| {
+            let locked = IndexId::Status(EscrowStatus::Locked);
+            (EscrowStatus::Locked, escrow_index::len(&env, &locked))
+        });
+        let mut refunds: Vec<(u64, Address, i128)> = Vec::new(&env);
+        let mut refunded = 0u32;
+        let mut finished = true;
+        loop {
+            let index = IndexId::Status(status.clone());
+            // Escrows that left the list since the last batch shorten it.
+            cursor = cursor.min(escrow_index::len(&env, &index));
+            if cursor == 0 {
+                if status == EscrowStatus::Locked {
+                    status = EscrowStatus::PartiallyRefunded;
+                    let partial = IndexId::Status(status.clone());
+                    cursor = escrow_index::len(&env, &partial);
+                    continue;
                 }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
+                break;
+            }
+            cursor -= 1;
+            let bounty_id = escrow_index::range(&env, &index, cursor, 1).get_unchecked(0);
+            let mut escrow = match Self::load_escrow(&env, bounty_id) {
+                Some(escrow)
+                    if escrow.remaining_amount > 0
+                        && Self::ensure_not_frozen(&env, bounty_id).is_ok()
+                        && Self::ensure_no_open_dispute(&env, bounty_id).is_ok() =>
                 {
-                    return Err(Error::FundsNotLocked);
-                }
-                if amount_limit > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
+                    escrow
                 }
+                _ => continue,
+            };
+            let refund_to = Self::refund_destination(&env, bounty_id, &escrow);
+            let payouts = match Self::refund_payouts(
+                &env,
+                bounty_id,
+                &escrow,
+                escrow.remaining_amount,
+                &refund_to,
+            ) {
+                Ok(payouts) => payouts,
+                Err(_) => continue,
+            };
+            if refunded >= max_count {
+                // Leave this escrow for the next batch.
+                cursor += 1;
+                finished = false;
+                break;
+            }
+
+            escrow.remaining_amount = 0;
+            state_machine::transition(&mut escrow, StatusEvent::Refund)?;
+            for (payee, amount) in payouts.iter() {
+                escrow.refund_history.push_back(RefundRecord {
+                    amount,
+                    recipient: payee.clone(),
+                    timestamp: now,
+                    mode: RefundMode::Full,
+                });
+                Self::record_action(&env, bounty_id, &payee, EscrowAction::Refunded, amount);
+                refunds.push_back((bounty_id, payee, amount));
             }
+            Self::save_escrow(&env, bounty_id, &escrow);
+            Self::settle_yield(&env, bounty_id, &escrow);
+            refunded += 1;
+        }
+        // The announcement stays in place until the last batch is done.
+        if finished {
+            emergency_exit::clear(&env);
+        } else {
+            emergency_exit::set_cursor(&env, &status, cursor);
         }
 
+        // INTERACTION: token transfers are last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let mut total = 0i128;
+        for (bounty_id, depositor, amount) in refunds.iter() {
+            client.transfer(&env.current_contract_address(), &depositor, &amount);
+            total += amount;
+            emit_funds_refunded(
+                &env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    refund_to: depositor,
+                    timestamp: now,
+                },
+            );
+        }
+
+        events::emit_emergency_exit_executed(
+            &env,
+            events::EmergencyExitExecuted {
+                refunded_count: refunded,
+                finished,
+                total_amount: total,
+                executed_by: admin,
+                timestamp: now,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(refunded)
+    }
+
+    /// Withdraw a pending `emergency_withdraw_all` announcement (admin only).
+    pub fn cancel_emergency_withdraw_all(env: Env) -> Result<(), Error> {
+        let admin = rbac::authorize(&env, None, Role::Rescuer)?;
+        Self::ensure_no_council(&env)?;
+        if emergency_exit::get(&env).is_none() {
+            panic_with_error!(&env, RescueError::RescueNotRequested);
+        }
+        emergency_exit::clear(&env);
+
+        events::emit_emergency_exit_cancelled(
+            &env,
+            events::EmergencyExitCancelled {
+                cancelled_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the pending `emergency_withdraw_all` announcement, if any.
+    pub fn get_emergency_exit(env: Env) -> Option<EmergencyExit> {
+        emergency_exit::get(&env)
+    }
+
+    /// Delay in seconds that queued admin operations must wait; 0 (the
+    /// default) disables the timelock.
+    pub fn get_timelock_delay(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TimelockDelay)
+            .unwrap_or(0)
+    }
+
+    /// Enable or raise the timelock delay (admin only). Lowering an active
+    /// delay must be queued as `AdminOp::SetTimelockDelay`.
+    ///
+    /// While the delay is non-zero, `update_fee_config`,
+    /// `rescue_untracked_tokens` and the emergency withdraw entry points
+    /// return `TimelockRequired` and the same actions have to go through
+    /// `queue_admin_op` / `execute_admin_op`.
+    pub fn set_timelock_delay(env: Env, delay: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_no_council(&env)?;
+
+        if delay < Self::get_timelock_delay(env.clone()) {
+            return Err(Error::TimelockRequired);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::TimelockDelay, &delay);
+        Ok(())
+    }
+
+    /// Schedule a sensitive admin operation (admin only). It becomes
+    /// executable once the current timelock delay has elapsed. Returns the
+    /// id to pass to `execute_admin_op` or `cancel_admin_op`.
+    pub fn queue_admin_op(env: Env, op: AdminOp) -> Result<u64, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_no_council(&env)?;
+
+        let op_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AdminOpNonce)
+            .unwrap_or(0)
+            + 1;
+        env.storage().instance().set(&DataKey::AdminOpNonce, &op_id);
+
+        let now = env.ledger().timestamp();
+        let eta = now.saturating_add(Self::get_timelock_delay(env.clone()));
+        let queued = QueuedAdminOp {
+            op: op.clone(),
+            queued_at: now,
+            eta,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::AdminOp(op_id), &queued);
+
+        events::emit_admin_op_queued(
+            &env,
+            events::AdminOpQueued {
+                op_id,
+                op,
+                eta,
+                timestamp: now,
+            },
+        );
+        Ok(op_id)
+    }
+
+    /// Run a queued admin operation after its delay has elapsed (admin only).
+    /// The operation's own preconditions (fee bounds, pause state for
+    /// withdrawals) are checked at execution time.
+    pub fn execute_admin_op(env: Env, op_id: u64) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_no_council(&env)?;
+
+        let queued: QueuedAdminOp = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AdminOp(op_id))
+            .unwrap_or_else(|| panic_with_error!(&env, TimelockError::AdminOpNotFound));
+        if env.ledger().timestamp() < queued.eta {
+            panic_with_error!(&env, TimelockError::TimelockNotExpired);
+        }
+        env.storage().persistent().remove(&DataKey::AdminOp(op_id));
+
+        let drains = matches!(queued.op, AdminOp::EmergencyWithdraw(_));
+        Self::apply_admin_op(&env, admin, queued.op)?;
+
+        events::emit_admin_op_executed(
+            &env,
+            events::AdminOpExecuted {
+                op_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: emergency withdrawals drain the balance on purpose
+        if !drains {
+            Self::check_balance_invariant(&env);
+        }
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Carry out `op` on behalf of `admin`, once the timelock or the council
+    /// has cleared it.
+    fn apply_admin_op(env: &Env, admin: Address, op: AdminOp) -> Result<(), Error> {
+        match op {
+            AdminOp::UpdateFeeConfig(
+                lock_fee_rate,
+                release_fee_rate,
+                fee_recipient,
+                fee_enabled,
+            ) => Self::apply_fee_config(
+                env,
+                lock_fee_rate,
+                release_fee_rate,
+                fee_recipient,
+                fee_enabled,
+            )?,
+            AdminOp::EmergencyWithdraw(target) => Self::withdraw_all(env, admin, target)?,
+            AdminOp::SetTimelockDelay(delay) => env
+                .storage()
+                .instance()
+                .set(&DataKey::TimelockDelay, &delay),
+            AdminOp::Upgrade(new_wasm_hash) => Self::apply_upgrade(env, admin, new_wasm_hash),
+            AdminOp::SetPaused(lock, release, refund) => {
+                Self::apply_pause_flags(env, admin, lock, release, refund, None)
+            }
+            AdminOp::RescueUntrackedTokens(token) => {
+                Self::apply_rescue_untracked(env, admin, token)?;
+            }
+            AdminOp::SetCouncil(members, threshold) => {
+                let council = council::set(env, members, threshold)?;
+                events::emit_council_updated(
+                    env,
+                    events::CouncilUpdated {
+                        members: council.members,
+                        threshold: council.threshold,
+                        timestamp: env.ledger().timestamp(),
+                    },
+                );
+            }
+            AdminOp::SetMigrationPeer(peer, trusted) => migration::set_peer(env, &peer, trusted),
+            AdminOp::GrantRole(role, account) => Self::apply_grant_role(env, admin, role, account),
+            AdminOp::RevokeRole(role, account) => {
+                Self::apply_revoke_role(env, admin, role, account)
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop a queued admin operation without running it (admin only).
+    pub fn cancel_admin_op(env: Env, op_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::AdminOp(op_id)) {
+            panic_with_error!(&env, TimelockError::AdminOpNotFound);
+        }
+        env.storage().persistent().remove(&DataKey::AdminOp(op_id));
+
+        events::emit_admin_op_cancelled(
+            &env,
+            events::AdminOpCancelled {
+                op_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: a queued admin operation, if it has not been executed or cancelled.
+    pub fn get_admin_op(env: Env, op_id: u64) -> Option<QueuedAdminOp> {
+        env.storage().persistent().get(&DataKey::AdminOp(op_id))
+    }
+
+    /// Hand sensitive admin operations to a council of `members`, any
+    /// `threshold` of whom must approve each one (admin only, once).
+    ///
+    /// From then on fee/treasury changes, pausing, rescues, emergency
+    /// withdrawals, upgrades, role changes and the timelock queue return
+    /// `CouncilRequired` for the admin key, including through the
+    /// `*_with_role` entry points, and go through `propose_council_op`
+    /// instead. Changing the council itself takes an `AdminOp::SetCouncil`
+    /// proposal. Addresses granted a role keep their delegated powers.
+    pub fn set_admin_council(env: Env, members: Vec<Address>, threshold: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_no_council(&env)?;
+
+        let council = council::set(&env, members, threshold)?;
+        events::emit_council_updated(
+            &env,
+            events::CouncilUpdated {
+                members: council.members,
+                threshold: council.threshold,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the admin council, if one has been configured.
+    pub fn get_admin_council(env: Env) -> Option<AdminCouncil> {
+        council::get(&env)
+    }
+
+    /// Propose an admin operation as a council member; the proposal counts
+    /// as the proposer's approval. It becomes executable once `threshold`
+    /// members have approved and the current timelock delay has elapsed.
+    /// Returns the id to pass to `approve_council_op` / `execute_council_op`.
+    pub fn propose_council_op(env: Env, proposer: Address, op: AdminOp) -> Result<u64, Error> {
+        council::require_member(&env, &proposer)?;
+
+        let proposal_id = council::next_proposal_id(&env);
+        let now = env.ledger().timestamp();
+        let eta = now.saturating_add(Self::get_timelock_delay(env.clone()));
+        let proposal = CouncilProposal {
+            op: op.clone(),
+            proposer: proposer.clone(),
+            approvals: vec![&env, proposer.clone()],
+            proposed_at: now,
+            eta,
+        };
+        council::set_proposal(&env, proposal_id, &proposal);
+
+        events::emit_council_op_proposed(
+            &env,
+            events::CouncilOpProposed {
+                proposal_id,
+                op,
+                proposer,
+                eta,
+                timestamp: now,
+            },
+        );
+        Ok(proposal_id)
+    }
+
+    /// Record `approver`'s sign-off on a council proposal. Approving twice
+    /// is a no-op.
+    pub fn approve_council_op(env: Env, approver: Address, proposal_id: u64) -> Result<(), Error> {
+        council::require_member(&env, &approver)?;
+        let mut proposal = council::get_proposal(&env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(&env, TimelockError::AdminOpNotFound));
+        if proposal.approvals.contains(&approver) {
+            return Ok(());
+        }
+
+        proposal.approvals.push_back(approver.clone());
+        council::set_proposal(&env, proposal_id, &proposal);
+
+        events::emit_council_op_approved(
+            &env,
+            events::CouncilOpApproved {
+                proposal_id,
+                approver,
+                approvals: proposal.approvals.len(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Run a council proposal (any member). Only approvals from current
+    /// members count towards the threshold; the operation's own
+    /// preconditions are checked at execution time.
+    pub fn execute_council_op(env: Env, executor: Address, proposal_id: u64) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let council = council::require_member(&env, &executor)?;
+        let proposal = council::get_proposal(&env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(&env, TimelockError::AdminOpNotFound));
+        if council::current_approvals(&council, &proposal) < council.threshold {
+            return Err(Error::InsufficientApprovals);
+        }
+        if env.ledger().timestamp() < proposal.eta {
+            panic_with_error!(&env, TimelockError::TimelockNotExpired);
+        }
+        council::remove_proposal(&env, proposal_id);
+
+        let drains = matches!(proposal.op, AdminOp::EmergencyWithdraw(_));
+        Self::apply_admin_op(&env, executor.clone(), proposal.op)?;
+
+        events::emit_council_op_executed(
+            &env,
+            events::CouncilOpExecuted {
+                proposal_id,
+                executor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: emergency withdrawals drain the balance on purpose
+        if !drains {
+            Self::check_balance_invariant(&env);
+        }
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Withdraw a council proposal (its proposer only).
+    pub fn cancel_council_op(env: Env, proposer: Address, proposal_id: u64) -> Result<(), Error> {
+        council::require_member(&env, &proposer)?;
+        let proposal = council::get_proposal(&env, proposal_id)
+            .unwrap_or_else(|| panic_with_error!(&env, TimelockError::AdminOpNotFound));
+        if proposal.proposer != proposer {
+            return Err(Error::Unauthorized);
+        }
+        council::remove_proposal(&env, proposal_id);
+
+        events::emit_council_op_cancelled(
+            &env,
+            events::CouncilOpCancelled {
+                proposal_id,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: a council proposal, if it has not been executed or cancelled.
+    pub fn get_council_op(env: Env, proposal_id: u64) -> Option<CouncilProposal> {
+        council::get_proposal(&env, proposal_id)
+    }
+
+    /// Replace the contract code in place, keeping its address, balance and
+    /// storage (admin only). While a timelock delay is configured the
+    /// upgrade must be queued as `AdminOp::Upgrade` instead.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_no_council(&env)?;
+        Self::ensure_no_timelock(&env)?;
+
+        Self::apply_upgrade(&env, admin, new_wasm_hash);
+        Ok(())
+    }
+
+    fn apply_upgrade(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) {
+        events::emit_contract_upgraded(
+            env,
+            events::ContractUpgraded {
+                new_wasm_hash: new_wasm_hash.clone(),
+                previous_version: CONTRACT_VERSION,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// View: human-readable description of an error code returned by this
+    /// contract, or "unknown error" for codes it does not define.
+    pub fn get_error_description(env: Env, code: u32) -> String {
+        let description = error_description(code).unwrap_or("unknown error");
+        String::from_str(&env, description)
+    }
+
+    /// Version of the deployed contract code.
+    pub fn get_version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    /// Layout version of the stored records. Instances initialized before
+    /// the key existed report 1.
+    pub fn get_schema_version(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SchemaVersion)
+            .unwrap_or(1)
+    }
+
+    /// Bring stored records up to `STORAGE_SCHEMA_VERSION` after an upgrade
+    /// (admin only), converting up to `max_count` escrows per call.
+    ///
+    /// Runs each converter from the stored schema version upwards. Start
+    /// with `cursor` 0 and pass the returned cursor to the next call until
+    /// it returns `None`; the schema version is bumped once a converter has
+    /// seen every escrow. A no-op once current.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `max_count` is 0 or above MAX_BATCH_SIZE
+    ///
+    /// # Panics
+    /// * MigrationCursorMismatch - if `cursor` is not where the previous
+    ///   call stopped
+    pub fn migrate(env: Env, cursor: u32, max_count: u32) -> Result<Option<u32>, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let from_version = Self::get_schema_version(env.clone());
+        if from_version >= STORAGE_SCHEMA_VERSION {
+            return Ok(None);
+        }
+        if max_count == 0 || max_count > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut version = from_version;
+        let (mut position, mut end) = schema_migration::progress(&env, version)
+            .unwrap_or_else(|| (0, Self::stored_escrow_count(&env)));
+        if cursor != position {
+            panic_with_error!(
+                &env,
+                schema_migration::SchemaMigrationError::MigrationCursorMismatch
+            );
+        }
+
+        let mut migrated_count = 0u32;
+        let mut budget = max_count;
+        while version < STORAGE_SCHEMA_VERSION {
+            let limit = budget.min(end - position);
+            let ids = Self::stored_escrow_ids(&env, position, limit);
+            migrated_count += match version {
+                1 => Self::migrate_escrows_v1_to_v2(&env, &ids),
+                2 => Self::index_deadlines_v2_to_v3(&env, &ids),
+                3 => Self::split_escrows_v3_to_v4(&env, &ids),
+                4 => Self::page_indexes_v4_to_v5(&env, &ids),
+                // Add a converter here whenever the stored layout changes.
+                _ => 0,
+            };
+            position += ids.len();
+            budget -= ids.len();
+            if ids.len() < limit {
+                // Escrows left the index since the converter started.
+                position = end;
+            }
+            if position < end {
+                schema_migration::set_progress(&env, version, position, end);
+                break;
+            }
+
+            if version == 4 {
+                Self::drop_indexes_v4(&env);
+            }
+            schema_migration::clear_progress(&env, version);
+            version += 1;
+            env.storage()
+                .instance()
+                .set(&DataKey::SchemaVersion, &version);
+            position = 0;
+            end = Self::stored_escrow_count(&env);
+        }
+
+        if version > from_version {
+            events::emit_schema_migrated(
+                &env,
+                events::SchemaMigrated {
+                    from_version,
+                    to_version: version,
+                    migrated_count,
+                    admin,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+        Ok((version < STORAGE_SCHEMA_VERSION).then_some(position))
+    }
+
+    /// Rewrite the v1 escrows among `ids` in the v2 layout. Entries that
+    /// already carry the v2 fields, or are split (written by newer code
+    /// before `migrate` ran), are left untouched.
+    fn migrate_escrows_v1_to_v2(env: &Env, ids: &Vec<u64>) -> u32 {
+        let whole_field = Symbol::new(env, "refund_history");
+        let v2_field = Symbol::new(env, "metadata_hash");
+        let mut migrated = 0u32;
+        for bounty_id in ids.iter() {
+            let key = DataKey::Escrow(bounty_id);
+            let fields: Option<Map<Symbol, Val>> = env.storage().persistent().get(&key);
+            match fields {
+                Some(fields)
+                    if fields.contains_key(whole_field.clone())
+                        && !fields.contains_key(v2_field.clone()) =>
+                {
+                    let old: EscrowV1 = env.storage().persistent().get(&key).unwrap();
+                    env.storage().persistent().set(&key, &old.into_v2());
+                    migrated += 1;
+                }
+                _ => {}
+            }
+        }
+        migrated
+    }
+
+    /// Add the funded escrows among `ids` to the deadline index, which did
+    /// not exist before schema v3.
+    fn index_deadlines_v2_to_v3(env: &Env, ids: &Vec<u64>) -> u32 {
+        let mut indexed = 0u32;
+        for bounty_id in ids.iter() {
+            let escrow = Self::load_escrow_any_layout(env, bounty_id);
+            if let Some(escrow) = escrow.filter(|e| deadline_index::tracks(&e.status)) {
+                deadline_index::insert(env, bounty_id, escrow.deadline);
+                indexed += 1;
+            }
+        }
+        indexed
+    }
+
+    /// Split the escrows among `ids` still stored whole into their core and
+    /// details entries.
+    fn split_escrows_v3_to_v4(env: &Env, ids: &Vec<u64>) -> u32 {
+        let mut migrated = 0u32;
+        for bounty_id in ids.iter() {
+            if Self::is_stored_whole(env, bounty_id) {
+                let persistent = env.storage().persistent();
+                let escrow: Escrow = persistent.get(&DataKey::Escrow(bounty_id)).unwrap();
+                persistent.set(
+                    &DataKey::Escrow(bounty_id),
+                    &EscrowCoreV4 {
+                        depositor: escrow.depositor,
+                        amount: escrow.amount,
+                        remaining_amount: escrow.remaining_amount,
+                        status: escrow.status,
+                        deadline: escrow.deadline,
+                    },
+                );
+                if !escrow.refund_history.is_empty()
+                    || escrow.metadata_hash.is_some()
+                    || escrow.label.is_some()
+                {
+                    persistent.set(
+                        &EscrowKey::Details(bounty_id),
+                        &EscrowDetails {
+                            refund_history: escrow.refund_history,
+                            metadata_hash: escrow.metadata_hash,
+                            label: escrow.label,
+                        },
+                    );
+                }
+                migrated += 1;
+            }
+        }
+        migrated
+    }
+
+    /// Move the escrows among `ids` into the paged escrow, depositor and
+    /// status indexes and give each its `EscrowEntries` record, listing the
+    /// entries it has and taking over its event sequence, in its core
+    /// entry. Contributor lists move on the contributor's next payout, see
+    /// `index_payout`.
+    fn page_indexes_v4_to_v5(env: &Env, ids: &Vec<u64>) -> u32 {
+        let persistent = env.storage().persistent();
+        let v5_field = Symbol::new(env, "entries");
+        let mut migrated = 0u32;
+        for bounty_id in ids.iter() {
+            let key = DataKey::Escrow(bounty_id);
+            let fields: Option<Map<Symbol, Val>> = persistent.get(&key);
+            if fields.is_none_or(|fields| fields.contains_key(v5_field.clone())) {
+                continue;
+            }
+            let core: EscrowCoreV4 = persistent.get(&key).unwrap();
+            let mut entries = EscrowEntries::default();
+            escrow_entries::record_stored(env, bounty_id, &mut entries);
+            let sequence_key = EscrowKey::EventSequence(bounty_id);
+            entries.event_sequence = persistent.get(&sequence_key).unwrap_or(0);
+            entries.escrow_slot = escrow_index::push(env, &IndexId::All, bounty_id);
+            entries.depositor_slot =
+                escrow_index::push(env, &IndexId::Depositor(core.depositor.clone()), bounty_id);
+            entries.status_slot =
+                escrow_index::push(env, &IndexId::Status(core.status.clone()), bounty_id);
+            persistent.set(
+                &key,
+                &EscrowCore {
+                    depositor: core.depositor.clone(),
+                    amount: core.amount,
+                    remaining_amount: core.remaining_amount,
+                    status: core.status,
+                    deadline: core.deadline,
+                    entries,
+                    history: Vec::new(env),
+                },
+            );
+            Self::bump_escrow_ttl(env, bounty_id, true);
+
+            persistent.remove(&sequence_key);
+            persistent.remove(&DataKey::EscrowLiveUntil(bounty_id));
+            persistent.remove(&DataKey::DepositorIndex(core.depositor));
+            migrated += 1;
+        }
+        migrated
+    }
+
+    /// Drop the unpaged indexes once `page_indexes_v4_to_v5` has moved every
+    /// escrow out of them.
+    fn drop_indexes_v4(env: &Env) {
+        let persistent = env.storage().persistent();
+        persistent.remove(&DataKey::EscrowIndex);
+        for status in [
+            EscrowStatus::Locked,
+            EscrowStatus::Released,
+            EscrowStatus::Refunded,
+            EscrowStatus::PartiallyRefunded,
+            EscrowStatus::Cancelled,
+        ] {
+            persistent.remove(&DataKey::StatusIndex(status));
+        }
+    }
+
+    /// Escrow ids the converters walk: those in the index of schema v4 and
+    /// earlier, then those locked by newer code before `migrate` ran.
+    fn legacy_escrow_ids(env: &Env) -> Vec<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(env))
+    }
+
+    fn stored_escrow_count(env: &Env) -> u32 {
+        Self::legacy_escrow_ids(env).len() + escrow_index::len(env, &IndexId::All)
+    }
+
+    /// Up to `limit` of the ids the converters walk, from `offset` on.
+    fn stored_escrow_ids(env: &Env, offset: u32, limit: u32) -> Vec<u64> {
+        let legacy = Self::legacy_escrow_ids(env);
+        let end = offset.saturating_add(limit);
+        let mut ids = legacy.slice(offset.min(legacy.len())..end.min(legacy.len()));
+        let paged_from = offset.max(legacy.len());
+        if end > paged_from {
+            ids.append(&escrow_index::range(
+                env,
+                &IndexId::All,
+                paged_from - legacy.len(),
+                end - paged_from,
+            ));
+        }
+        ids
+    }
+
+    /// Whether `bounty_id` is still a whole `Escrow` (schema v2 and v3)
+    /// rather than split into core and details.
+    fn is_stored_whole(env: &Env, bounty_id: u64) -> bool {
+        let fields: Option<Map<Symbol, Val>> =
+            env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+        fields.is_some_and(|fields| fields.contains_key(Symbol::new(env, "refund_history")))
+    }
+
+    /// Read an escrow in any layout, for converters that run before
+    /// `split_escrows_v3_to_v4`.
+    fn load_escrow_any_layout(env: &Env, bounty_id: u64) -> Option<Escrow> {
+        let persistent = env.storage().persistent();
+        let key = DataKey::Escrow(bounty_id);
+        let fields: Map<Symbol, Val> = persistent.get(&key)?;
+        if fields.contains_key(Symbol::new(env, "refund_history")) {
+            return persistent.get(&key);
+        }
+        if fields.contains_key(Symbol::new(env, "entries")) {
+            return Self::load_escrow(env, bounty_id);
+        }
+        let core: EscrowCoreV4 = persistent.get(&key)?;
+        let details: Option<EscrowDetails> = persistent.get(&EscrowKey::Details(bounty_id));
+        let (refund_history, metadata_hash, label) = match details {
+            Some(details) => (details.refund_history, details.metadata_hash, details.label),
+            None => (Vec::new(env), None, None),
+        };
+        Some(Escrow {
+            depositor: core.depositor,
+            amount: core.amount,
+            remaining_amount: core.remaining_amount,
+            status: core.status,
+            deadline: core.deadline,
+            refund_history,
+            metadata_hash,
+            label,
+        })
+    }
+
+    fn ensure_no_council(env: &Env) -> Result<(), Error> {
+        if council::get(env).is_some() {
+            return Err(Error::CouncilRequired);
+        }
+        Ok(())
+    }
+
+    /// `ensure_no_council` when `account` is the stored admin, which holds
+    /// every role, so the `*_with_role` entry points don't bypass the
+    /// council either.
+    fn ensure_not_sole_admin(env: &Env, account: &Address) -> Result<(), Error> {
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if admin.as_ref() == Some(account) {
+            Self::ensure_no_council(env)?;
+        }
+        Ok(())
+    }
+
+    fn ensure_no_timelock(env: &Env) -> Result<(), Error> {
+        if Self::get_timelock_delay(env.clone()) > 0 {
+            return Err(Error::TimelockRequired);
+        }
+        Ok(())
+    }
+
+    /// Get current pause flags
+    pub fn get_pause_flags(env: &Env) -> PauseFlags {
+        env.storage()
+            .instance()
+            .get(&DataKey::PauseFlags)
+            .unwrap_or(PauseFlags {
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                pause_reason: None,
+                paused_at: 0,
+            })
+    }
+
+    /// Check if an operation is paused
+    fn check_paused(env: &Env, operation: Symbol) -> bool {
+        let flags = Self::get_pause_flags(env);
+        if operation == symbol_short!("lock") {
+            return flags.lock_paused;
+        } else if operation == symbol_short!("release") {
+            return flags.release_paused;
+        } else if operation == symbol_short!("refund") {
+            return flags.refund_paused;
+        }
+        false
+    }
+
+    fn next_capability_id(env: &Env) -> u64 {
+        let last_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::CapabilityNonce)
+            .unwrap_or(0);
+        let next_id = last_id.saturating_add(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::CapabilityNonce, &next_id);
+        next_id
+    }
+
+    fn load_capability(env: &Env, capability_id: u64) -> Result<Capability, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Capability(capability_id))
+            .ok_or(Error::CapabilityNotFound)
+    }
+
+    fn validate_capability_scope_at_issue(
+        env: &Env,
+        owner: &Address,
+        action: &CapabilityAction,
+        bounty_id: u64,
+        amount_limit: i128,
+    ) -> Result<(), Error> {
+        if amount_limit <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        match action {
+            CapabilityAction::Claim => {
+                let claim: ClaimRecord = escrow_entries::get(env, bounty_id, Entry::PendingClaim)
+                    .ok_or(Error::BountyNotFound)?;
+                if claim.claimed {
+                    return Err(Error::FundsNotLocked);
+                }
+                if env.ledger().timestamp() > claim.expires_at {
+                    return Err(Error::DeadlineNotPassed);
+                }
+                if claim.recipient != owner.clone() {
+                    return Err(Error::Unauthorized);
+                }
+                if amount_limit > claim.amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Release => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != owner.clone() {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow =
+                    Self::load_escrow(env, bounty_id).ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked {
+                    return Err(Error::FundsNotLocked);
+                }
+                if amount_limit > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Refund => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != owner.clone() {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow =
+                    Self::load_escrow(env, bounty_id).ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked
+                    && escrow.status != EscrowStatus::PartiallyRefunded
+                {
+                    return Err(Error::FundsNotLocked);
+                }
+                if amount_limit > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ensure_owner_still_authorized(
+        env: &Env,
+        capability: &Capability,
+        requested_amount: i128,
+    ) -> Result<(), Error> {
+        if requested_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        match capability.action {
+            CapabilityAction::Claim => {
+                let claim: ClaimRecord =
+                    escrow_entries::get(env, capability.bounty_id, Entry::PendingClaim)
+                        .ok_or(Error::BountyNotFound)?;
+                if claim.claimed {
+                    return Err(Error::FundsNotLocked);
+                }
+                if env.ledger().timestamp() > claim.expires_at {
+                    return Err(Error::DeadlineNotPassed);
+                }
+                if claim.recipient != capability.owner {
+                    return Err(Error::Unauthorized);
+                }
+                if requested_amount > claim.amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Release => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != capability.owner {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow =
+                    Self::load_escrow(env, capability.bounty_id).ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked {
+                    return Err(Error::FundsNotLocked);
+                }
+                if requested_amount > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+            CapabilityAction::Refund => {
+                let admin: Address = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(Error::NotInitialized)?;
+                if admin != capability.owner {
+                    return Err(Error::Unauthorized);
+                }
+                let escrow: Escrow =
+                    Self::load_escrow(env, capability.bounty_id).ok_or(Error::BountyNotFound)?;
+                if escrow.status != EscrowStatus::Locked
+                    && escrow.status != EscrowStatus::PartiallyRefunded
+                {
+                    return Err(Error::FundsNotLocked);
+                }
+                if requested_amount > escrow.remaining_amount {
+                    return Err(Error::CapabilityExceedsAuthority);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn consume_capability(
+        env: &Env,
+        holder: &Address,
+        capability_id: u64,
+        expected_action: CapabilityAction,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<Capability, Error> {
+        let mut capability = Self::load_capability(env, capability_id)?;
+
+        if capability.revoked {
+            return Err(Error::CapabilityRevoked);
+        }
+        if capability.action != expected_action {
+            return Err(Error::CapabilityActionMismatch);
+        }
+        if capability.bounty_id != bounty_id {
+            return Err(Error::CapabilityActionMismatch);
+        }
+        if capability.holder != holder.clone() {
+            return Err(Error::Unauthorized);
+        }
+        if env.ledger().timestamp() > capability.expiry {
+            return Err(Error::CapabilityExpired);
+        }
+        if capability.remaining_uses == 0 {
+            return Err(Error::CapabilityUsesExhausted);
+        }
+        if amount > capability.remaining_amount {
+            return Err(Error::CapabilityAmountExceeded);
+        }
+
+        holder.require_auth();
+        Self::ensure_owner_still_authorized(env, &capability, amount)?;
+
+        capability.remaining_amount -= amount;
+        capability.remaining_uses -= 1;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Capability(capability_id), &capability);
+
+        events::emit_capability_used(
+            env,
+            events::CapabilityUsed {
+                capability_id,
+                holder: holder.clone(),
+                action: capability.action.clone(),
+                bounty_id,
+                amount_used: amount,
+                remaining_amount: capability.remaining_amount,
+                remaining_uses: capability.remaining_uses,
+                used_at: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(capability)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn issue_capability(
+        env: Env,
+        owner: Address,
+        holder: Address,
+        action: CapabilityAction,
+        bounty_id: u64,
+        amount_limit: i128,
+        expiry: u64,
+        max_uses: u32,
+    ) -> Result<u64, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if max_uses == 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let now = env.ledger().timestamp();
+        if expiry <= now {
+            return Err(Error::InvalidDeadline);
+        }
+
+        owner.require_auth();
+        Self::validate_capability_scope_at_issue(&env, &owner, &action, bounty_id, amount_limit)?;
+
+        let capability_id = Self::next_capability_id(&env);
+        let capability = Capability {
+            owner: owner.clone(),
+            holder: holder.clone(),
+            action: action.clone(),
+            bounty_id,
+            amount_limit,
+            remaining_amount: amount_limit,
+            expiry,
+            remaining_uses: max_uses,
+            revoked: false,
+        };
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::Capability(capability_id), &capability);
+
+        events::emit_capability_issued(
+            &env,
+            events::CapabilityIssued {
+                capability_id,
+                owner,
+                holder,
+                action,
+                bounty_id,
+                amount_limit,
+                expires_at: expiry,
+                max_uses,
+                timestamp: now,
+            },
+        );
+
+        Ok(capability_id)
+    }
+
+    pub fn revoke_capability(env: Env, owner: Address, capability_id: u64) -> Result<(), Error> {
+        let mut capability = Self::load_capability(&env, capability_id)?;
+        if capability.owner != owner {
+            return Err(Error::Unauthorized);
+        }
+        owner.require_auth();
+
+        if capability.revoked {
+            return Ok(());
+        }
+
+        capability.revoked = true;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Capability(capability_id), &capability);
+
+        events::emit_capability_revoked(
+            &env,
+            events::CapabilityRevoked {
+                capability_id,
+                owner,
+                revoked_at: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    pub fn get_capability(env: Env, capability_id: u64) -> Result<Capability, Error> {
+        Self::load_capability(&env, capability_id)
+    }
+
+    /// View: admin, token, fees, pause flags and limits in one call, for
+    /// integrators that would otherwise read each getter separately.
+    pub fn get_config(env: Env) -> Result<Config, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let amount_policy: Option<(i128, i128)> =
+            env.storage().instance().get(&DataKey::AmountPolicy);
+        Ok(Config {
+            admin,
+            token,
+            fee_config: Self::get_fee_config_internal(&env),
+            pause_flags: Self::get_pause_flags(&env),
+            min_escrow_amount: amount_policy.map(|(min_amount, _)| min_amount),
+            max_escrow_amount: amount_policy.map(|(_, max_amount)| max_amount),
+            max_batch_size: MAX_BATCH_SIZE,
+            release_rate_limit: release_limits::get(&env).unwrap_or(ReleaseRateLimit {
+                window: 0,
+                global_limit: 0,
+                escrow_limit: 0,
+            }),
+        })
+    }
+
+    /// Get current fee configuration (view function)
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Propose `treasury` as the fee recipient (admin only, same checks as
+    /// `update_fee_config`). It takes effect once `treasury` calls
+    /// `accept_treasury_role`, so funds are never routed to an address
+    /// nobody controls.
+    pub fn set_treasury_address(env: Env, treasury: Address) -> Result<(), Error> {
+        Self::update_fee_config(env, None, None, Some(treasury), None)
+    }
+
+    /// Become the fee recipient (proposed treasury only). From now on
+    /// release fees, rescues and yield for the treasury go to the caller.
+    pub fn accept_treasury_role(env: Env) -> Result<(), Error> {
+        let treasury: Address = env
+            .storage()
+            .instance()
+            .get(&ConfigKey::PendingTreasury)
+            .unwrap_or_else(|| panic_with_error!(&env, TreasuryError::TreasuryNotProposed));
+        treasury.require_auth();
+
+        let mut fee_config = Self::get_fee_config_internal(&env);
+        let previous = fee_config.fee_recipient.clone();
+        fee_config.fee_recipient = treasury.clone();
+        env.storage()
+            .instance()
+            .set(&DataKey::FeeConfig, &fee_config);
+        env.storage().instance().remove(&ConfigKey::PendingTreasury);
+
+        events::emit_treasury_accepted(
+            &env,
+            events::TreasuryAccepted {
+                previous,
+                treasury,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the treasury address awaiting `accept_treasury_role`, if any.
+    pub fn get_pending_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&ConfigKey::PendingTreasury)
+    }
+
+    /// Pay `amount` of the accrued release fees to the fee recipient
+    /// (admin only).
+    pub fn withdraw_fees(env: Env, amount: i128) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let accrued = Self::get_accrued_fees(env.clone());
+        if amount > accrued {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // EFFECTS: reduce the accrued balance before transferring
+        env.storage()
+            .instance()
+            .set(&ConfigKey::AccruedFees, &(accrued - amount));
+
+        let treasury = Self::get_fee_config_internal(&env).fee_recipient;
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        // INTERACTION: external token transfer is last
+        token::Client::new(&env, &token_addr).transfer(
+            &env.current_contract_address(),
+            &treasury,
+            &amount,
+        );
+
+        events::emit_fees_withdrawn(
+            &env,
+            events::FeesWithdrawn {
+                amount,
+                treasury,
+                remaining: accrued - amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// View: release fees collected but not yet withdrawn to the treasury.
+    pub fn get_accrued_fees(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::AccruedFees)
+            .unwrap_or(0)
+    }
+
+    /// Get the release fee rate (basis points) snapshotted when `bounty_id`
+    /// was locked. Escrows locked while fees were disabled pay no fee.
+    pub fn get_escrow_fee_rate(env: Env, bounty_id: u64) -> i128 {
+        Self::escrow_fee_rate(&env, bounty_id, &escrow_entries::load(&env, bounty_id))
+    }
+
+    /// `get_escrow_fee_rate` for a caller holding the record `entries`.
+    fn escrow_fee_rate(env: &Env, bounty_id: u64, entries: &EscrowEntries) -> i128 {
+        escrow_entries::get_in(env, bounty_id, entries, Entry::ReleaseFeeRate).unwrap_or(0)
+    }
+
+    /// Grant `role` to `account`. `caller` must hold the `Admin` role.
+    /// Once an admin council is set, roles change only through
+    /// `AdminOp::GrantRole` proposals.
+    pub fn grant_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), Error> {
+        rbac::require_role(&env, &caller, Role::Admin)?;
+        Self::ensure_no_council(&env)?;
+        Self::apply_grant_role(&env, caller, role, account);
+        Ok(())
+    }
+
+    fn apply_grant_role(env: &Env, granted_by: Address, role: Role, account: Address) {
+        rbac::grant(env, role, &account);
+        events::emit_role_granted(
+            env,
+            events::RoleGranted {
+                role,
+                account,
+                granted_by,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Revoke `role` from `account`. `caller` must hold the `Admin` role.
+    /// The stored admin always keeps every role and cannot be revoked here.
+    /// Once an admin council is set, roles change only through
+    /// `AdminOp::RevokeRole` proposals.
+    pub fn revoke_role(
+        env: Env,
+        caller: Address,
+        role: Role,
+        account: Address,
+    ) -> Result<(), Error> {
+        rbac::require_role(&env, &caller, Role::Admin)?;
+        Self::ensure_no_council(&env)?;
+        Self::apply_revoke_role(&env, caller, role, account);
+        Ok(())
+    }
+
+    fn apply_revoke_role(env: &Env, revoked_by: Address, role: Role, account: Address) {
+        rbac::revoke(env, role, &account);
+        events::emit_role_revoked(
+            env,
+            events::RoleRevoked {
+                role,
+                account,
+                revoked_by,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// View: whether `account` holds `role` (the admin holds every role).
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        rbac::has_role(&env, role, &account)
+    }
+
+    /// Retrieves the chain identifier.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<String>` - Chain identifier if set, None if not initialized with network config
+    ///
+    /// # Usage
+    /// Use this to verify the chain environment for:
+    /// - Cross-network protection
+    /// - Replay attack prevention
+    /// - Environment-specific behavior
+    pub fn get_chain_id(env: Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::ChainId)
+    }
+
+    /// Retrieves the network identifier.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `Option<String>` - Network identifier if set, None if not initialized with network config
+    ///
+    /// # Usage
+    /// Use this to verify the network environment for:
+    /// - Environment-specific behavior
+    /// - Testnet vs mainnet differentiation
+    /// - Safe replay protection
+    pub fn get_network_id(env: Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::NetworkId)
+    }
+
+    /// Gets both chain and network identifiers as a tuple.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// * `(Option<String>, Option<String>)` - Tuple of (chain_id, network_id)
+    ///
+    /// # Usage
+    /// Convenience function to get both identifiers in one call.
+    pub fn get_network_info(env: Env) -> (Option<String>, Option<String>) {
+        (
+            env.storage().instance().get(&DataKey::ChainId),
+            env.storage().instance().get(&DataKey::NetworkId),
+        )
+    }
+
+    /// Update multisig configuration (admin only)
+    pub fn update_multisig_config(
+        env: Env,
+        threshold_amount: i128,
+        signers: Vec<Address>,
+        required_signatures: u32,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if required_signatures > signers.len() {
+            return Err(Error::InvalidAmount);
+        }
+
+        let config = MultisigConfig {
+            threshold_amount,
+            signers,
+            required_signatures,
+        };
+
+        env.storage()
+            .instance()
+            .set(&DataKey::MultisigConfig, &config);
+
+        Ok(())
+    }
+
+    /// Get multisig configuration
+    pub fn get_multisig_config(env: Env) -> MultisigConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::MultisigConfig)
+            .unwrap_or(MultisigConfig {
+                threshold_amount: i128::MAX,
+                signers: vec![&env],
+                required_signatures: 0,
+            })
+    }
+
+    /// Approve release for large amount (requires multisig).
+    ///
+    /// Alias of [`approve_release`](Self::approve_release) kept for existing
+    /// integrations.
+    pub fn approve_large_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<(), Error> {
+        Self::approve_release(env, bounty_id, contributor, approver)
+    }
+
+    /// Record `approver`'s sign-off on releasing `bounty_id` to `contributor`.
+    ///
+    /// Releases whose amount is at or above the multisig `threshold_amount`
+    /// only execute once `required_signatures` distinct signers have approved
+    /// the same contributor. Approvals are consumed by the release.
+    pub fn approve_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        approver: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let multisig_config: MultisigConfig = Self::get_multisig_config(env.clone());
+
+        let mut is_signer = false;
+        for signer in multisig_config.signers.iter() {
+            if signer == approver {
+                is_signer = true;
+                break;
+            }
+        }
+
+        if !is_signer {
+            return Err(Error::Unauthorized);
+        }
+
+        approver.require_auth();
+
+        let mut approval: ReleaseApproval =
+            escrow_entries::get(&env, bounty_id, Entry::ReleaseApproval).unwrap_or(
+                ReleaseApproval {
+                    bounty_id,
+                    contributor: contributor.clone(),
+                    approvals: vec![&env],
+                },
+            );
+        if approval.contributor != contributor {
+            return Err(Error::Unauthorized);
+        }
+
+        for existing in approval.approvals.iter() {
+            if existing == approver {
+                return Ok(());
+            }
+        }
+
+        approval.approvals.push_back(approver.clone());
+        escrow_entries::set(&env, bounty_id, Entry::ReleaseApproval, &approval);
+
+        events::emit_approval_added(
+            &env,
+            events::ApprovalAdded {
+                bounty_id,
+                contributor: contributor.clone(),
+                approver,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Get the approvals collected so far for releasing `bounty_id`.
+    pub fn get_release_approval(env: Env, bounty_id: u64) -> Option<ReleaseApproval> {
+        escrow_entries::get(&env, bounty_id, Entry::ReleaseApproval)
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// The escrow holds what the contract actually received: for a token
+    /// that charges a fee on transfer that is less than `amount`.
+    ///
+    /// # Errors
+    /// * BountyExists - if `bounty_id` is already in use, including escrows
+    ///   archived by `sweep_stale_escrows`. Services that retry submissions
+    ///   should use `lock_funds_idempotent` instead.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. State (escrow record,
+    /// indexes) is written before the inbound token transfer so that
+    /// a malicious token callback cannot re-enter with stale state.
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            None,
+            None,
+            None,
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Lock funds like `lock_funds`, called by the operator contract set with
+    /// `set_operator_contract`. The depositor authorizes only
+    /// `(bounty_id, amount, deadline)`, so the signature holds whichever
+    /// contract workflow routes the call.
+    pub fn lock_funds_as_operator(
+        env: Env,
+        operator: Address,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            None,
+            None,
+            Some(operator),
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Lock funds like `lock_funds`, additionally recording a hash of the
+    /// off-chain bounty description and an optional label in the escrow
+    /// record so tooling can verify which issue the bounty belongs to.
+    pub fn lock_funds_with_metadata_hash(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        metadata_hash: BytesN<32>,
+        label: Option<Symbol>,
+    ) -> Result<(), Error> {
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            Some(metadata_hash.into()),
+            label,
+            None,
+        );
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
+    }
+
+    /// Lock funds like `lock_funds`, tagged with an idempotency key chosen
+    /// by the caller (e.g. a hash of the off-chain submission). Retrying with
+    /// the same key and depositor once the lock has gone through is a no-op
+    /// returning `false`; any other lock of an existing `bounty_id` fails with
+    /// `BountyExists`. Returns `true` when this call locked the funds.
+    pub fn lock_funds_idempotent(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        idempotency_key: BytesN<32>,
+    ) -> Result<bool, Error> {
+        if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
+            let locked_with: Option<BytesN<32>> =
+                escrow_entries::get(&env, bounty_id, Entry::IdempotencyKey);
+            if escrow.depositor == depositor && locked_with == Some(idempotency_key) {
+                return Ok(false);
+            }
+            return Err(Error::BountyExists);
+        }
+
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        escrow_entries::set(&env, bounty_id, Entry::IdempotencyKey, &idempotency_key);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(true)
+    }
+
+    /// Lock funds like `lock_funds` with a deadline given either as a
+    /// timestamp or as a ledger sequence number. See the `ledger_deadlines`
+    /// module.
+    ///
+    /// # Errors
+    /// * InvalidDeadline - if a ledger deadline has already been reached
+    pub fn lock_funds_with_deadline(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: Deadline,
+    ) -> Result<(), Error> {
+        match deadline {
+            Deadline::Time(timestamp) => {
+                Self::lock_funds(env, depositor, bounty_id, amount, timestamp)
+            }
+            Deadline::Ledger(sequence) => {
+                let estimate =
+                    ledger_deadlines::estimate(&env, sequence).ok_or(Error::InvalidDeadline)?;
+                Self::lock_funds(env.clone(), depositor, bounty_id, amount, estimate)?;
+                ledger_deadlines::set(&env, bounty_id, sequence);
+                Self::bump_escrow_ttl(&env, bounty_id, true);
+                Ok(())
+            }
+        }
+    }
+
+    /// View: the deadline of `bounty_id` as it was set, by ledger sequence
+    /// or timestamp.
+    pub fn get_deadline(env: Env, bounty_id: u64) -> Result<Deadline, Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        Ok(match ledger_deadlines::get(&env, bounty_id) {
+            Some(sequence) => Deadline::Ledger(sequence),
+            None => Deadline::Time(escrow.deadline),
+        })
+    }
+
+    /// Lock funds like `lock_funds` and designate `approver`, e.g. the
+    /// project maintainer, who may then release this bounty through
+    /// `release_funds_with_role` / `partial_release_with_role` without
+    /// holding the `Releaser` role.
+    pub fn lock_funds_with_approver(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        approver: Address,
+    ) -> Result<(), Error> {
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        escrow_entries::set(&env, bounty_id, Entry::Approver, &approver);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// Lock funds like `lock_funds` and record `referrer`, who is paid the
+    /// current referral fee on every release of the bounty. See the
+    /// `referrals` module.
+    pub fn lock_funds_with_referrer(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        referrer: Address,
+    ) -> Result<(), Error> {
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        Self::record_referrer(&env, bounty_id, referrer)
+    }
+
+    /// Lock funds like `lock_funds` with caps on its partial releases. See
+    /// the `payout_caps` module.
+    ///
+    /// # Errors
+    /// * InvalidAmount - if either cap is negative
+    pub fn lock_funds_with_payout_caps(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        caps: PayoutCaps,
+    ) -> Result<(), Error> {
+        if caps.max_release < 0 || caps.max_cumulative < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        payout_caps::set(&env, bounty_id, &caps);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// Approve the partial releases of `bounty_id` so far, starting its
+    /// cumulative payout count over (depositor only).
+    pub fn approve_payout_review(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+        if payout_caps::get(&env, bounty_id).is_none() {
+            return Err(Error::NotInitialized);
+        }
+
+        let reviewed = payout_caps::review(&env, bounty_id);
+        events::emit_payout_review_approved(
+            &env,
+            events::PayoutReviewApproved {
+                bounty_id,
+                reviewed,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the partial release caps of `bounty_id`, if any.
+    pub fn get_payout_caps(env: Env, bounty_id: u64) -> Option<PayoutCaps> {
+        payout_caps::get(&env, bounty_id)
+    }
+
+    /// View: what partial releases of `bounty_id` paid since its last
+    /// payout review.
+    pub fn get_released_since_review(env: Env, bounty_id: u64) -> i128 {
+        payout_caps::released(&env, bounty_id)
+    }
+
+    /// Set the referral fee, in basis points of each release payout, for
+    /// referrers recorded from now on (admin only). 0 stops paying new
+    /// referrers.
+    pub fn set_referral_fee(env: Env, fee_bps: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if fee_bps as i128 > token_math::MAX_FEE_RATE {
+            return Err(Error::InvalidFeeRate);
+        }
+        referrals::set_rate(&env, fee_bps);
+        Ok(())
+    }
+
+    /// View: the referral fee new referrers are recorded with.
+    pub fn get_referral_fee(env: Env) -> u32 {
+        referrals::rate(&env)
+    }
+
+    /// View: the referrer of `bounty_id` and the fee they are paid, if any.
+    pub fn get_escrow_referral(env: Env, bounty_id: u64) -> Option<Referral> {
+        referrals::get(&env, bounty_id)
+    }
+
+    /// View: referral fees paid to `referrer` across all escrows.
+    pub fn get_referral_earnings(env: Env, referrer: Address) -> i128 {
+        referrals::earnings(&env, &referrer)
+    }
+
+    /// Record `referrer` for `bounty_id` at the current referral fee. An
+    /// escrow has at most one referrer.
+    fn record_referrer(env: &Env, bounty_id: u64, referrer: Address) -> Result<(), Error> {
+        if referrals::get(env, bounty_id).is_some() {
+            panic_with_error!(&env, referrals::ReferralError::ReferrerAlreadySet);
+        }
+        Self::ensure_not_blocked(env, &referrer)?;
+        let fee_bps = referrals::rate(env);
+        referrals::set(
+            env,
+            bounty_id,
+            &Referral {
+                referrer: referrer.clone(),
+                fee_bps,
+            },
+        );
+        Self::bump_escrow_ttl(env, bounty_id, true);
+
+        events::emit_referrer_recorded(
+            env,
+            events::ReferrerRecorded {
+                bounty_id,
+                referrer,
+                fee_bps,
+            },
+        );
+        Ok(())
+    }
+
+    /// Lock funds like `lock_funds` and deposit them into the lending pool
+    /// configured with `set_yield_strategy` until they are paid out. Interest
+    /// goes to `beneficiary`, or the strategy's default when `None`. Aborts
+    /// with `YieldError::PoolNotSet` if no pool is configured.
+    pub fn lock_funds_with_yield(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        beneficiary: Option<YieldBeneficiary>,
+    ) -> Result<(), Error> {
+        let pool = yield_strategy::pool(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, yield_strategy::YieldError::PoolNotSet));
+        Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let beneficiary = beneficiary.unwrap_or_else(|| yield_strategy::beneficiary(&env));
+        yield_strategy::invest(
+            &env,
+            bounty_id,
+            &pool,
+            &token_addr,
+            escrow.remaining_amount,
+            beneficiary,
+        );
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        events::emit_yield_deposited(
+            &env,
+            events::YieldDeposited {
+                bounty_id,
+                pool,
+                amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Replace the approver of `bounty_id`, or `None` to remove it (depositor
+    /// only).
+    pub fn set_escrow_approver(
+        env: Env,
+        bounty_id: u64,
+        approver: Option<Address>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        match approver {
+            Some(address) => escrow_entries::set(&env, bounty_id, Entry::Approver, &address),
+            None => escrow_entries::remove(&env, bounty_id, Entry::Approver),
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// Send the depositor's refunds of `bounty_id` to `destination`, e.g. a
+    /// DAO treasury or a wallet after key rotation, or `None` to refund the
+    /// depositor again (depositor only). Applies to deadline refunds,
+    /// cancellation and emergency exits; an admin-approved refund still
+    /// goes to the recipient named in the approval.
+    pub fn set_refund_destination(
+        env: Env,
+        bounty_id: u64,
+        destination: Option<Address>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        match &destination {
+            Some(address) => {
+                if *address == env.current_contract_address() {
+                    return Err(Error::InvalidRecipient);
+                }
+                Self::ensure_not_blocked(&env, address)?;
+                escrow_entries::set(&env, bounty_id, Entry::RefundDestination, address);
+            }
+            None => escrow_entries::remove(&env, bounty_id, Entry::RefundDestination),
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        events::emit_refund_destination_set(
+            &env,
+            events::RefundDestinationSet {
+                bounty_id,
+                destination,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: where the depositor's refunds of `bounty_id` go, the depositor
+    /// unless `set_refund_destination` chose another address.
+    pub fn get_refund_destination(env: Env, bounty_id: u64) -> Result<Address, Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        Ok(Self::refund_destination(&env, bounty_id, &escrow))
+    }
+
+    fn refund_destination(env: &Env, bounty_id: u64, escrow: &Escrow) -> Address {
+        escrow_entries::get(env, bounty_id, Entry::RefundDestination)
+            .unwrap_or_else(|| escrow.depositor.clone())
+    }
+
+    /// Who a refund of `amount` from `bounty_id` is paid to: each funder
+    /// their pro-rata part (see the `funders` module) and `refund_to` the
+    /// depositor's. Fails if any of them is blocklisted.
+    fn refund_payouts(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        amount: i128,
+        refund_to: &Address,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        let (mut payouts, rest) = funders::split(env, bounty_id, escrow.amount, amount);
+        if rest > 0 {
+            payouts.push_front((refund_to.clone(), rest));
+        }
+        for (payee, _) in payouts.iter() {
+            Self::ensure_not_blocked(env, &payee)?;
+        }
+        Ok(payouts)
+    }
+
+    /// View: the approver designated for `bounty_id`, if any.
+    pub fn get_escrow_approver(env: Env, bounty_id: u64) -> Option<Address> {
+        escrow_entries::get(&env, bounty_id, Entry::Approver)
+    }
+
+    /// Require contributors of `bounty_id` to be verified by the `verifier`
+    /// contract before release, or `None` to drop the requirement (depositor
+    /// only). See the `kyc` module for the verifier interface.
+    pub fn set_escrow_kyc_verifier(
+        env: Env,
+        bounty_id: u64,
+        verifier: Option<Address>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        kyc::set(&env, bounty_id, verifier);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// View: the KYC verifier contributors of `bounty_id` must pass, if any.
+    pub fn get_escrow_kyc_verifier(env: Env, bounty_id: u64) -> Option<Address> {
+        kyc::get(&env, bounty_id)
+    }
+
+    /// Set the ed25519 key of the service attesting issue links, or `None`
+    /// to stop accepting attestations (admin only). Links verified before a
+    /// change stay verified. See the `issue_links` module.
+    pub fn set_issue_attestor(env: Env, attestor: Option<BytesN<32>>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        issue_links::set_attestor(&env, attestor);
+        Ok(())
+    }
+
+    /// View: the issue link attestor's key, if any.
+    pub fn get_issue_attestor(env: Env) -> Option<BytesN<32>> {
+        issue_links::attestor(&env)
+    }
+
+    /// Link the `Locked` escrow `bounty_id` to issue `issue_number` of the
+    /// GitHub repository `repo` (`owner/name`), replacing any previous link
+    /// (depositor only). The link is unverified, and blocks releases, until
+    /// `attest_issue_link` is called for it.
+    ///
+    /// # Errors
+    /// * InvalidIssueLink - if `repo` is not 3 to 140 bytes or
+    ///   `issue_number` is 0
+    pub fn set_issue_link(
+        env: Env,
+        bounty_id: u64,
+        repo: String,
+        issue_number: u64,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if repo.len() < 3 || repo.len() > 140 || issue_number == 0 {
+            panic_with_error!(&env, issue_links::IssueLinkError::InvalidIssueLink);
+        }
+
+        let link = IssueLink {
+            repo: repo.clone(),
+            issue_number,
+            verified: false,
+        };
+        issue_links::set(&env, bounty_id, &link);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        events::emit_issue_linked(
+            &env,
+            events::IssueLinked {
+                bounty_id,
+                repo,
+                issue_number,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Mark the issue link of `bounty_id` verified with the attestor's
+    /// `signature` over the XDR of `(escrow address, bounty_id, repo,
+    /// issue_number)`. Anyone can submit it, so the attestor needs no
+    /// account of its own.
+    ///
+    /// # Errors
+    /// * NotInitialized - if no attestor is set
+    /// * IssueLinkNotFound - if the escrow has no issue link
+    pub fn attest_issue_link(env: Env, bounty_id: u64, signature: BytesN<64>) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let link = issue_links::attest(&env, bounty_id, &signature)?;
+        events::emit_issue_link_verified(
+            &env,
+            events::IssueLinkVerified {
+                bounty_id,
+                repo: link.repo,
+                issue_number: link.issue_number,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the issue `bounty_id` is linked to, if any.
+    pub fn get_issue_link(env: Env, bounty_id: u64) -> Option<IssueLink> {
+        issue_links::get(&env, bounty_id)
+    }
+
+    /// Assign `bounty_id` to `contributor`, who has `acceptance_window`
+    /// seconds to call `accept_assignment` (depositor only). Until then
+    /// releases fail with `AssignmentPending`; once accepted, funds can only
+    /// be released to the assignee. If the window lapses without acceptance
+    /// the depositor can assign someone else or `cancel_escrow`.
+    pub fn assign_contributor(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        acceptance_window: u64,
+    ) -> Result<(), Error> {
+        Self::assign_contributor_with_bond(env, bounty_id, contributor, acceptance_window, 0)
+    }
+
+    /// Like `assign_contributor`, but the contributor must post `bond`
+    /// escrow tokens when accepting. See the `bonds` module for when it is
+    /// slashed or returned.
+    pub fn assign_contributor_with_bond(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        acceptance_window: u64,
+        bond: i128,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if acceptance_window == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        if bond < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if let Some(current) = Self::active_assignment(&env, bounty_id) {
+            if current.accepted_at.is_some() {
+                panic_with_error!(&env, AssignmentError::AssignmentAccepted);
+            }
+            return Err(Error::AssignmentPending);
+        }
+        Self::ensure_not_blocked(&env, &contributor)?;
+
+        let accept_by = env.ledger().timestamp().saturating_add(acceptance_window);
+        escrow_entries::set(
+            &env,
+            bounty_id,
+            Entry::Assignment,
+            &Assignment {
+                contributor: contributor.clone(),
+                accept_by,
+                accepted_at: None,
+                bond,
+            },
+        );
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        events::emit_contributor_assigned(
+            &env,
+            events::ContributorAssigned {
+                bounty_id,
+                contributor,
+                accept_by,
+                bond,
+            },
+        );
+        Ok(())
+    }
+
+    /// Like `assign_contributor`, also recording `referrer` for the bounty
+    /// unless it already has one. See the `referrals` module.
+    pub fn assign_contributor_with_referrer(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        acceptance_window: u64,
+        referrer: Address,
+    ) -> Result<(), Error> {
+        Self::assign_contributor(env.clone(), bounty_id, contributor, acceptance_window)?;
+        Self::record_referrer(&env, bounty_id, referrer)
+    }
+
+    /// Accept the assignment of `bounty_id` (assigned contributor only, before
+    /// its `accept_by`), posting the assignment's bond if it has one.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The bond is recorded before
+    /// the inbound token transfer (CEI pattern).
+    pub fn accept_assignment(env: Env, bounty_id: u64) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        // Posting a bond moves tokens in, like a lock.
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let mut assignment = match Self::active_assignment(&env, bounty_id) {
+            Some(assignment) if assignment.accepted_at.is_none() => assignment,
+            _ => panic_with_error!(&env, AssignmentError::AssignmentNotFound),
+        };
+        assignment.contributor.require_auth();
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        // EFFECTS: update state before external call (CEI)
+        let now = env.ledger().timestamp();
+        assignment.accepted_at = Some(now);
+        escrow_entries::set(&env, bounty_id, Entry::Assignment, &assignment);
+        if assignment.bond > 0 {
+            bonds::hold(
+                &env,
+                bounty_id,
+                &ContributorBond {
+                    contributor: assignment.contributor.clone(),
+                    amount: assignment.bond,
+                },
+            );
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        // INTERACTION: external token transfer is last
+        if assignment.bond > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            token::Client::new(&env, &token_addr).transfer(
+                &assignment.contributor,
+                &env.current_contract_address(),
+                &assignment.bond,
+            );
+        }
+
+        events::emit_assignment_accepted(
+            &env,
+            events::AssignmentAccepted {
+                bounty_id,
+                contributor: assignment.contributor,
+                timestamp: now,
+            },
+        );
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// View: the contributor bond held for `bounty_id`, if any.
+    pub fn get_contributor_bond(env: Env, bounty_id: u64) -> Option<ContributorBond> {
+        bonds::get(&env, bounty_id)
+    }
+
+    /// Return the bond of `bounty_id` to the contributor who posted it (that
+    /// contributor only) once the escrow has settled without the bond being
+    /// slashed.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard.
+    pub fn withdraw_bond(env: Env, bounty_id: u64) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let bond = bonds::get(&env, bounty_id)
+            .unwrap_or_else(|| panic_with_error!(&env, bonds::BondError::BondNotFound));
+        bond.contributor.require_auth();
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status == EscrowStatus::Locked
+            || escrow.status == EscrowStatus::PartiallyRefunded
+            || Self::ensure_no_open_dispute(&env, bounty_id).is_err()
+        {
+            panic_with_error!(&env, bonds::BondError::BondLocked);
+        }
+
+        // EFFECTS: update state before external call (CEI)
+        bonds::take(&env, bounty_id);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token_addr).transfer(
+            &env.current_contract_address(),
+            &bond.contributor,
+            &bond.amount,
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Send contributor bonds slashed in disputes to the treasury (the fee
+    /// recipient) instead of the depositor (admin only).
+    pub fn set_slash_bonds_to_treasury(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&ConfigKey::SlashBondsToTreasury, &enabled);
+        Ok(())
+    }
+
+    /// View: `true` if slashed contributor bonds go to the treasury.
+    pub fn get_slash_bonds_to_treasury(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::SlashBondsToTreasury)
+            .unwrap_or(false)
+    }
+
+    /// View: the contributor assignment of `bounty_id`, including one that
+    /// lapsed without acceptance.
+    pub fn get_assignment(env: Env, bounty_id: u64) -> Option<Assignment> {
+        escrow_entries::get(&env, bounty_id, Entry::Assignment)
+    }
+
+    /// Record `submission_hash` as the delivered work for `bounty_id` (the
+    /// contributor who accepted its assignment only). A later submission
+    /// replaces the earlier one.
+    pub fn submit_work(env: Env, bounty_id: u64, submission_hash: BytesN<32>) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        let assignment = Self::active_assignment(&env, bounty_id)
+            .unwrap_or_else(|| panic_with_error!(&env, AssignmentError::AssignmentNotFound));
+        if assignment.accepted_at.is_none() {
+            return Err(Error::AssignmentPending);
+        }
+        assignment.contributor.require_auth();
+
+        let now = env.ledger().timestamp();
+        escrow_entries::set(
+            &env,
+            bounty_id,
+            Entry::Submission,
+            &WorkSubmission {
+                contributor: assignment.contributor.clone(),
+                submission_hash: submission_hash.clone(),
+                submitted_at: now,
+            },
+        );
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        events::emit_work_submitted(
+            &env,
+            events::WorkSubmitted {
+                bounty_id,
+                contributor: assignment.contributor,
+                submission_hash,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the latest work submitted for `bounty_id`, if any.
+    pub fn get_submission(env: Env, bounty_id: u64) -> Option<WorkSubmission> {
+        escrow_entries::get(&env, bounty_id, Entry::Submission)
+    }
+
+    /// Require a `submit_work` record before `bounty_id` can be released, or
+    /// drop the requirement (depositor only).
+    pub fn set_submission_required(env: Env, bounty_id: u64, required: bool) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if required {
+            escrow_entries::set(&env, bounty_id, Entry::SubmissionRequired, &true);
+        } else {
+            escrow_entries::remove(&env, bounty_id, Entry::SubmissionRequired);
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// View: `true` if releases of `bounty_id` need a work submission.
+    pub fn is_submission_required(env: Env, bounty_id: u64) -> bool {
+        escrow_entries::has(&env, bounty_id, Entry::SubmissionRequired)
+    }
+
+    /// Give the depositor `review_period` seconds after each `submit_work` to
+    /// dispute the work, after which the assignee can collect the escrow with
+    /// `claim_after_review_timeout`; `None` turns auto-release off (depositor
+    /// only). Fixed once the assignment has been accepted.
+    pub fn set_review_period(
+        env: Env,
+        bounty_id: u64,
+        review_period: Option<u64>,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if let Some(assignment) = Self::active_assignment(&env, bounty_id) {
+            if assignment.accepted_at.is_some() {
+                panic_with_error!(&env, AssignmentError::AssignmentAccepted);
+            }
+        }
+
+        match review_period {
+            Some(0) => return Err(Error::InvalidDeadline),
+            Some(seconds) => escrow_entries::set(&env, bounty_id, Entry::ReviewPeriod, &seconds),
+            None => escrow_entries::remove(&env, bounty_id, Entry::ReviewPeriod),
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// View: seconds the depositor has to dispute submitted work on
+    /// `bounty_id`, if auto-release is on.
+    pub fn get_review_period(env: Env, bounty_id: u64) -> Option<u64> {
+        escrow_entries::get(&env, bounty_id, Entry::ReviewPeriod)
+    }
+
+    /// Release `bounty_id` to the contributor who submitted work once the
+    /// review period has passed without the depositor opening a dispute
+    /// (that contributor only). A newer submission restarts the period.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard; see `release_funds`.
+    pub fn claim_after_review_timeout(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let review_period = Self::get_review_period(env.clone(), bounty_id)
+            .unwrap_or_else(|| panic_with_error!(&env, AssignmentError::ReviewPeriodNotSet));
+        let submission =
+            Self::get_submission(env.clone(), bounty_id).ok_or(Error::SubmissionRequired)?;
+        submission.contributor.require_auth();
+        if env.ledger().timestamp() < submission.submitted_at.saturating_add(review_period) {
+            return Err(Error::DeadlineNotPassed);
+        }
+        Self::release_escrow(
+            &env,
+            bounty_id,
+            &submission.contributor,
+            &submission.contributor,
+        )?;
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Returns `SubmissionRequired` if `bounty_id` needs a work submission and
+    /// none was recorded.
+    fn ensure_work_submitted(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if escrow_entries::has(env, bounty_id, Entry::SubmissionRequired)
+            && !escrow_entries::has(env, bounty_id, Entry::Submission)
+        {
+            return Err(Error::SubmissionRequired);
+        }
+        Ok(())
+    }
+
+    /// The assignment of `bounty_id` unless it lapsed without acceptance.
+    fn active_assignment(env: &Env, bounty_id: u64) -> Option<Assignment> {
+        Self::get_assignment(env.clone(), bounty_id).filter(|assignment| {
+            assignment.accepted_at.is_some() || env.ledger().timestamp() <= assignment.accept_by
+        })
+    }
+
+    /// Whether `contributor` is recorded on `bounty_id`, as the assignee of
+    /// an assignment that has not lapsed or the recipient of an unclaimed
+    /// pending claim.
+    fn is_escrow_contributor(env: &Env, bounty_id: u64, contributor: &Address) -> bool {
+        if Self::active_assignment(env, bounty_id)
+            .is_some_and(|assignment| assignment.contributor == *contributor)
+        {
+            return true;
+        }
+        escrow_entries::get::<ClaimRecord>(env, bounty_id, Entry::PendingClaim)
+            .is_some_and(|claim| !claim.claimed && claim.recipient == *contributor)
+    }
+
+    /// Returns `AssignmentPending` while the assignee of `bounty_id` has yet
+    /// to accept, and `Unauthorized` if `recipient` is not the assignee.
+    fn ensure_assignment_accepted(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+    ) -> Result<(), Error> {
+        if let Some(assignment) = Self::active_assignment(env, bounty_id) {
+            if assignment.accepted_at.is_none() {
+                return Err(Error::AssignmentPending);
+            }
+            if assignment.contributor != *recipient {
+                return Err(Error::Unauthorized);
+            }
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn lock_funds_logic(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        metadata_hash: Option<Bytes>,
+        label: Option<Symbol>,
+        operator: Option<Address>,
+    ) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(&env, depositor.clone());
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let _start = env.ledger().timestamp();
+        let _caller = depositor.clone();
+
+        // Verify depositor authorization; through the operator contract the
+        // depositor signs only the lock terms.
+        match &operator {
+            Some(operator) => {
+                Self::require_operator(&env, operator)?;
+                depositor.require_auth_for_args((bounty_id, amount, deadline).into_val(&env));
+            }
+            None => depositor.require_auth(),
+        }
+
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || archive::contains(&env, bounty_id)
+        {
+            return Err(Error::BountyExists);
+        }
+
+        Self::check_amount_policy(&env, amount)?;
+        allowlist::ensure_allowed(&env, &depositor)?;
+        or_abort(&env, budgets::consume(&env, &depositor, amount));
+
+        // EFFECTS: write escrow state and indexes before the external call
+        let mut escrow = Escrow {
+            depositor: depositor.clone(),
+            amount,
+            status: EscrowStatus::Locked,
+            deadline,
+            refund_history: vec![&env],
+            remaining_amount: amount,
+            metadata_hash,
+            label,
+        };
+        invariants::assert_escrow(&env, &escrow);
+
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::snapshot_release_fee(&env, bounty_id);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let (received, balance) = Self::receive_tokens(&env, &client, &depositor, amount)?;
+        if received < amount {
+            escrow.amount = received;
+            escrow.remaining_amount = received;
+            Self::save_escrow(&env, bounty_id, &escrow);
+        }
+        // Emit value allows for off-chain indexing
+        let event = FundsLocked {
+            version: EVENT_VERSION_V2,
+            bounty_id,
+            amount: received,
+            depositor: depositor.clone(),
+            deadline,
+        };
+        Self::record_and_publish(
+            &env,
+            bounty_id,
+            &depositor,
+            EscrowAction::Locked,
+            received,
+            |entries| events::emit_funds_locked_in(&env, entries, event),
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant_at(&env, token_addr, balance);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Transfer `amount` from `from` to the contract and return what the
+    /// contract's balance actually grew by, which is less for tokens that
+    /// charge a fee on transfer, and the balance after. Only what arrived
+    /// can be tracked, or the tracked balance would exceed the real one and
+    /// later releases fail.
+    fn receive_tokens(
+        env: &Env,
+        client: &token::Client,
+        from: &Address,
+        amount: i128,
+    ) -> Result<(i128, i128), Error> {
+        let contract = env.current_contract_address();
+        let before = client.balance(&contract);
+        client.transfer(from, &contract, &amount);
+        let balance = client.balance(&contract);
+        let received = balance - before;
+        if amount > 0 && received <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Ok((received.min(amount), balance))
+    }
+
+    /// Divide `received` over `amounts`, which add up to `total`, in
+    /// proportion, the last one taking the rounding remainder. Used when a
+    /// combined transfer of `total` delivered only `received`.
+    fn split_received(
+        env: &Env,
+        amounts: &Vec<i128>,
+        total: i128,
+        received: i128,
+    ) -> Result<Vec<i128>, Error> {
+        let mut shares = Vec::new(env);
+        let mut left = received;
+        for (i, amount) in amounts.iter().enumerate() {
+            let share = if i as u32 + 1 == amounts.len() {
+                left
+            } else {
+                amount.checked_mul(received).ok_or(Error::InvalidAmount)? / total
+            };
+            left -= share;
+            shares.push_back(share);
+        }
+        Ok(shares)
+    }
+
+    /// Add funds to an existing `Locked` escrow, e.g. when bounty scope grows.
+    /// Only the original depositor can top up.
+    ///
+    /// Both `amount` and `remaining_amount` grow by what the contract
+    /// received, which is less than `additional_amount` for a token that
+    /// charges a fee on transfer. If an amount policy is configured, the new
+    /// total must stay within its maximum.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The escrow record is updated
+    /// before the inbound token transfer (CEI pattern).
+    pub fn increase_escrow(env: Env, bounty_id: u64, additional_amount: i128) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+        if additional_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_no_stream(&env, bounty_id)?;
+        escrow.depositor.require_auth();
+
+        let new_amount = escrow
+            .amount
+            .checked_add(additional_amount)
+            .ok_or(Error::InvalidAmount)?;
+        if let Some((_, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if new_amount > max_amount {
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+        allowlist::ensure_allowed(&env, &escrow.depositor)?;
+        or_abort(
+            &env,
+            budgets::consume(&env, &escrow.depositor, additional_amount),
+        );
+
+        // EFFECTS: update state before external call (CEI)
+        escrow.amount = new_amount;
+        escrow.remaining_amount += additional_amount;
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let (received, _) =
+            Self::receive_tokens(&env, &client, &escrow.depositor, additional_amount)?;
+        if received < additional_amount {
+            escrow.amount -= additional_amount - received;
+            escrow.remaining_amount -= additional_amount - received;
+            Self::save_escrow(&env, bounty_id, &escrow);
+        }
+        Self::record_action(
+            &env,
+            bounty_id,
+            &escrow.depositor,
+            EscrowAction::ToppedUp,
+            received,
+        );
+
+        events::emit_escrow_increased(
+            &env,
+            events::EscrowIncreased {
+                bounty_id,
+                depositor: escrow.depositor.clone(),
+                additional_amount: received,
+                new_amount: escrow.amount,
+                remaining_amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Add `amount` from `funder` to a `Locked` escrow, e.g. to crowdfund a
+    /// bounty. The funder's share is recorded so that deadline refunds,
+    /// cancellation and emergency exit return the remaining funds pro-rata
+    /// (see the `funders` module). A contribution by the depositor counts
+    /// towards the depositor's own share. For a token that charges a fee on
+    /// transfer, what the contract received is recorded instead of `amount`.
+    ///
+    /// # Errors
+    /// * TooManyFunders - if `funder` would be one more than `MAX_FUNDERS`
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The escrow record and the
+    /// funder's share are updated before the inbound token transfer (CEI
+    /// pattern).
+    pub fn contribute_to_escrow(
+        env: Env,
+        funder: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::contribute_logic(env, funder, bounty_id, amount).map(|_| ())
+    }
+
+    /// `contribute_to_escrow`, returning what the contract received.
+    fn contribute_logic(
+        env: Env,
+        funder: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<i128, Error> {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        funder.require_auth();
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_no_stream(&env, bounty_id)?;
+
+        let new_amount = escrow
+            .amount
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if let Some((_, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if new_amount > max_amount {
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+        if funders::has_failed(&env, bounty_id, escrow.amount) {
+            return Err(Error::FundingGoalNotMet);
+        }
+        // Refunds are paid back to the funder, so they must be able to
+        // receive them.
+        Self::ensure_not_blocked(&env, &funder)?;
+        allowlist::ensure_allowed(&env, &funder)?;
+        or_abort(&env, budgets::consume(&env, &funder, amount));
+
+        // EFFECTS: update state before external call (CEI)
+        if funder != escrow.depositor && !funders::add(&env, bounty_id, &funder, amount) {
+            panic_with_error!(&env, funders::FundingError::TooManyFunders);
+        }
+        escrow.amount = new_amount;
+        escrow.remaining_amount += amount;
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let (received, _) = Self::receive_tokens(&env, &client, &funder, amount)?;
+        if received < amount {
+            let shortfall = amount - received;
+            if funder != escrow.depositor {
+                funders::add(&env, bounty_id, &funder, -shortfall);
+            }
+            escrow.amount -= shortfall;
+            escrow.remaining_amount -= shortfall;
+            Self::save_escrow(&env, bounty_id, &escrow);
+        }
+        Self::record_action(&env, bounty_id, &funder, EscrowAction::ToppedUp, received);
+
+        events::emit_escrow_contributed(
+            &env,
+            events::EscrowContributed {
+                bounty_id,
+                funder,
+                amount: received,
+                new_amount: escrow.amount,
+                remaining_amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(received)
+    }
+
+    /// View: what each funder other than the depositor has contributed to
+    /// `bounty_id` with `contribute_to_escrow`.
+    pub fn get_escrow_funders(env: Env, bounty_id: u64) -> Result<Map<Address, i128>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        Ok(funders::shares(&env, bounty_id))
+    }
+
+    /// Hold releases of `bounty_id` until its amount reaches `goal`, and let
+    /// funders claim their contributions back if it has not by
+    /// `funding_deadline` (depositor only). Can be set or changed while
+    /// nothing has been paid out and any earlier goal is still open. See the
+    /// `funders` module.
+    ///
+    /// # Errors
+    /// * InvalidDeadline - if `funding_deadline` is not in the future or is
+    ///   after the escrow deadline
+    /// * FundingGoalNotMet - if an earlier goal was already missed
+    pub fn set_funding_goal(
+        env: Env,
+        bounty_id: u64,
+        goal: i128,
+        funding_deadline: u64,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if !Self::is_unclaimed(&env, bounty_id, &escrow) {
+            panic_with_error!(&env, CancellationError::CancellationNotAllowed);
+        }
+        if funders::has_failed(&env, bounty_id, escrow.amount) {
+            return Err(Error::FundingGoalNotMet);
+        }
+        if goal <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if funding_deadline <= now || funding_deadline > escrow.deadline {
+            return Err(Error::InvalidDeadline);
+        }
+
+        funders::set_goal(
+            &env,
+            bounty_id,
+            &FundingGoal {
+                goal,
+                deadline: funding_deadline,
+            },
+        );
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        events::emit_funding_goal_set(
+            &env,
+            events::FundingGoalSet {
+                bounty_id,
+                goal,
+                deadline: funding_deadline,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: the funding goal of `bounty_id`, if one is set.
+    pub fn get_funding_goal(env: Env, bounty_id: u64) -> Option<FundingGoal> {
+        funders::goal(&env, bounty_id)
+    }
+
+    /// Pay `funder` back their share of `bounty_id` after its funding goal
+    /// was missed. The depositor's share goes to their refund destination.
+    /// Each funder gets their pro-rata part of what is left, which is their
+    /// full contribution unless an approved refund already paid some out.
+    /// Returns the amount paid.
+    ///
+    /// # Errors
+    /// * FundingNotFailed - if the goal was met or its deadline has not passed
+    /// * Unauthorized - if `funder` has nothing left in the escrow
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The escrow record and the
+    /// funder's share are updated before the outbound token transfer (CEI
+    /// pattern).
+    pub fn claim_contribution(env: Env, funder: Address, bounty_id: u64) -> Result<i128, Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        funder.require_auth();
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        state_machine::ensure_allowed(&escrow, StatusEvent::PartialRefund)?;
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        if !funders::has_failed(&env, bounty_id, escrow.amount) {
+            panic_with_error!(&env, funders::FundingError::FundingNotFailed);
+        }
+
+        let is_depositor = funder == escrow.depositor;
+        let share = if is_depositor {
+            funders::depositor_share(&env, bounty_id, escrow.amount)
+        } else {
+            funders::shares(&env, bounty_id)
+                .get(funder.clone())
+                .unwrap_or(0)
+        };
+        if share <= 0 {
+            return Err(Error::Unauthorized);
+        }
+        let payee = if is_depositor {
+            Self::refund_destination(&env, bounty_id, &escrow)
+        } else {
+            funder.clone()
+        };
+        Self::ensure_not_blocked(&env, &payee)?;
+
+        // EFFECTS: update state before external call (CEI)
+        let amount = escrow.remaining_amount * share / escrow.amount;
+        if !is_depositor {
+            funders::take(&env, bounty_id, &funder);
+        }
+        escrow.amount -= share;
+        escrow.remaining_amount -= amount;
+        let event = if escrow.remaining_amount == 0 {
+            StatusEvent::Refund
+        } else {
+            StatusEvent::PartialRefund
+        };
+        state_machine::transition(&mut escrow, event)?;
+        let now = env.ledger().timestamp();
+        escrow.refund_history.push_back(RefundRecord {
+            amount,
+            recipient: payee.clone(),
+            timestamp: now,
+            mode: RefundMode::Partial,
+        });
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+        Self::record_action(&env, bounty_id, &funder, EscrowAction::Refunded, amount);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &payee, &amount);
+        Self::notify_refund(&env, bounty_id, &payee, amount);
+
+        emit_funds_refunded(
+            &env,
+            FundsRefunded {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount,
+                refund_to: payee,
+                timestamp: now,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(amount)
+    }
+
+    /// Open a quadratic funding round over `bounty_ids`, depositing
+    /// `matching_pool` from the admin (admin only). Returns the round id.
+    /// See the `quadratic_funding` module.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `bounty_ids` is empty or longer than MAX_BATCH_SIZE
+    /// * DuplicateBountyId - if a bounty is listed twice
+    /// * FundsNotLocked - if a listed escrow is not `Locked`
+    /// * InvalidDeadline - if `ends_at` is not in the future
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The round is recorded before
+    /// the inbound token transfer (CEI pattern).
+    pub fn create_funding_round(
+        env: Env,
+        bounty_ids: Vec<u64>,
+        matching_pool: i128,
+        ends_at: u64,
+    ) -> Result<u64, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        if matching_pool <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let now = env.ledger().timestamp();
+        if ends_at <= now {
+            return Err(Error::InvalidDeadline);
+        }
+        for (i, bounty_id) in bounty_ids.iter().enumerate() {
+            if bounty_ids.first_index_of(bounty_id) != Some(i as u32) {
+                return Err(Error::DuplicateBountyId);
+            }
+            let escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+            if escrow.status != EscrowStatus::Locked {
+                return Err(Error::FundsNotLocked);
+            }
+        }
+
+        // EFFECTS: record the round before external call (CEI)
+        let round_id = quadratic_funding::create(
+            &env,
+            &FundingRound {
+                sponsor: admin.clone(),
+                matching_pool,
+                bounty_ids: bounty_ids.clone(),
+                ends_at,
+                closed: false,
+                matched: 0,
+            },
+        );
+        quadratic_funding::add_pooled(&env, matching_pool);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&admin, &env.current_contract_address(), &matching_pool);
+
+        events::emit_funding_round_created(
+            &env,
+            events::FundingRoundCreated {
+                round_id,
+                sponsor: admin,
+                matching_pool,
+                bounty_ids,
+                ends_at,
+                timestamp: now,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(round_id)
+    }
+
+    /// Back `bounty_id` with `amount` from `funder` in an open round. The
+    /// funds go into the escrow as with `contribute_to_escrow` and count
+    /// towards the bounty's matching.
+    ///
+    /// # Errors
+    /// * RoundNotFound - if there is no round `round_id`
+    /// * RoundClosed - if the round has ended
+    /// * BountyNotFound - if `bounty_id` is not part of the round
+    pub fn contribute_in_round(
+        env: Env,
+        funder: Address,
+        round_id: u64,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let round = quadratic_funding::get(&env, round_id)
+            .unwrap_or_else(|| panic_with_error!(&env, quadratic_funding::RoundError::NotFound));
+        if round.closed || env.ledger().timestamp() >= round.ends_at {
+            panic_with_error!(&env, quadratic_funding::RoundError::Closed);
+        }
+        if !round.bounty_ids.contains(bounty_id) {
+            return Err(Error::BountyNotFound);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let received = Self::contribute_logic(env.clone(), funder.clone(), bounty_id, amount)?;
+        quadratic_funding::record(&env, round_id, bounty_id, &funder, received);
+        Ok(())
+    }
+
+    /// Close a round that has ended and push each bounty's match into its
+    /// escrow as a contribution of the sponsor. Matches of escrows that are
+    /// no longer `Locked` or cannot take another funder, and rounding dust,
+    /// go back to the sponsor. Callable by anyone. Returns the amount
+    /// matched.
+    ///
+    /// # Errors
+    /// * RoundNotFound - if there is no round `round_id`
+    /// * RoundClosed - if the round was already closed
+    /// * RoundStillOpen - if the round has not ended
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Every escrow and the round
+    /// are updated before the outbound token transfer (CEI pattern).
+    pub fn close_funding_round(env: Env, round_id: u64) -> Result<i128, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let mut round = quadratic_funding::get(&env, round_id)
+            .unwrap_or_else(|| panic_with_error!(&env, quadratic_funding::RoundError::NotFound));
+        if round.closed {
+            panic_with_error!(&env, quadratic_funding::RoundError::Closed);
+        }
+        let now = env.ledger().timestamp();
+        if now < round.ends_at {
+            panic_with_error!(&env, quadratic_funding::RoundError::StillOpen);
+        }
+
+        // EFFECTS: push matches and close the round before external call (CEI)
+        let mut matched = 0i128;
+        for (bounty_id, amount) in quadratic_funding::allocate(&env, round_id, &round).iter() {
+            if amount <= 0 {
+                continue;
+            }
+            let mut escrow = match Self::load_escrow(&env, bounty_id) {
+                Some(escrow) if escrow.status == EscrowStatus::Locked => escrow,
+                _ => continue,
+            };
+            if round.sponsor != escrow.depositor
+                && !funders::add(&env, bounty_id, &round.sponsor, amount)
+            {
+                continue;
+            }
+            escrow.amount += amount;
+            escrow.remaining_amount += amount;
+            invariants::assert_escrow(&env, &escrow);
+            Self::save_escrow(&env, bounty_id, &escrow);
+            Self::bump_escrow_ttl(&env, bounty_id, true);
+            Self::record_action(
+                &env,
+                bounty_id,
+                &round.sponsor,
+                EscrowAction::ToppedUp,
+                amount,
+            );
+            matched += amount;
+            events::emit_round_match_paid(
+                &env,
+                events::RoundMatchPaid {
+                    round_id,
+                    bounty_id,
+                    amount,
+                    timestamp: now,
+                },
+            );
+        }
+        let returned = round.matching_pool - matched;
+        round.closed = true;
+        round.matched = matched;
+        quadratic_funding::set(&env, round_id, &round);
+        quadratic_funding::add_pooled(&env, -round.matching_pool);
+
+        // INTERACTION: external token transfer is last
+        if returned > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let client = token::Client::new(&env, &token_addr);
+            client.transfer(&env.current_contract_address(), &round.sponsor, &returned);
+        }
+
+        events::emit_funding_round_closed(
+            &env,
+            events::FundingRoundClosed {
+                round_id,
+                matched,
+                returned,
+                timestamp: now,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(matched)
+    }
+
+    /// View: the funding round `round_id`.
+    pub fn get_funding_round(
+        env: Env,
+        round_id: u64,
+    ) -> Result<FundingRound, quadratic_funding::RoundError> {
+        quadratic_funding::get(&env, round_id).ok_or(quadratic_funding::RoundError::NotFound)
+    }
+
+    /// View: what each funder put into `bounty_id` in round `round_id`.
+    pub fn get_round_contributions(env: Env, round_id: u64, bounty_id: u64) -> Map<Address, i128> {
+        quadratic_funding::contributions(&env, round_id, bounty_id)
+    }
+
+    /// View: each bounty's match if round `round_id` were closed now, before
+    /// dropping escrows that can no longer receive it.
+    pub fn get_round_matches(
+        env: Env,
+        round_id: u64,
+    ) -> Result<Map<u64, i128>, quadratic_funding::RoundError> {
+        let round = quadratic_funding::get(&env, round_id)
+            .ok_or(quadratic_funding::RoundError::NotFound)?;
+        Ok(quadratic_funding::allocate(&env, round_id, &round))
+    }
+
+    /// Offer escrow insurance on `config` terms, or `None` to stop selling
+    /// new policies (admin only). Existing policies and the pool are kept.
+    /// See the `insurance` module.
+    pub fn set_insurance_config(env: Env, config: Option<InsuranceConfig>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if let Some(config) = &config {
+            if config.premium_bps == 0 || config.premium_bps as i128 > token_math::BASIS_POINTS {
+                return Err(Error::InvalidFeeRate);
+            }
+            if config.max_coverage <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+        }
+        insurance::set_config(&env, config);
+        Ok(())
+    }
+
+    /// View: the insurance terms on offer, if any.
+    pub fn get_insurance_config(env: Env) -> Option<InsuranceConfig> {
+        insurance::config(&env)
+    }
+
+    /// View: funds in the insurance pool available for claims.
+    pub fn get_insurance_pool(env: Env) -> i128 {
+        insurance::pool(&env)
+    }
+
+    /// View: the insurance policy of `bounty_id`, if it is insured.
+    pub fn get_insurance_policy(env: Env, bounty_id: u64) -> Option<InsurancePolicy> {
+        insurance::policy(&env, bounty_id)
+    }
+
+    /// Insure a `Locked` escrow on the current terms (depositor only). The
+    /// policy covers the escrow amount up to `max_coverage`, and the premium
+    /// is taken from the depositor into the insurance pool.
+    ///
+    /// # Errors
+    /// * NotInsured - if no insurance is offered
+    /// * AlreadyInsured - if the escrow already has a policy
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The policy is recorded
+    /// before the inbound token transfer (CEI pattern).
+    pub fn insure_escrow(env: Env, bounty_id: u64) -> Result<InsurancePolicy, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let config = insurance::config(&env)
+            .unwrap_or_else(|| panic_with_error!(&env, insurance::InsuranceError::NotInsured));
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if insurance::policy(&env, bounty_id).is_some() {
+            panic_with_error!(&env, insurance::InsuranceError::AlreadyInsured);
+        }
+        let coverage = escrow.amount.min(config.max_coverage);
+        let premium = token_math::calculate_fee(coverage, config.premium_bps as i128);
+        if premium <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // EFFECTS: record the policy before external call (CEI)
+        let policy = InsurancePolicy {
+            depositor: escrow.depositor.clone(),
+            coverage,
+            premium,
+            claimed: false,
+        };
+        insurance::set_policy(&env, bounty_id, &policy);
+        insurance::add_to_pool(&env, premium);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&escrow.depositor, &env.current_contract_address(), &premium);
+
+        events::emit_escrow_insured(
+            &env,
+            events::EscrowInsured {
+                bounty_id,
+                depositor: escrow.depositor,
+                coverage,
+                premium,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(policy)
+    }
+
+    /// Add `amount` from `funder` to the insurance pool.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The pool is updated before
+    /// the inbound token transfer (CEI pattern).
+    pub fn fund_insurance_pool(env: Env, funder: Address, amount: i128) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        // EFFECTS: update the pool before external call (CEI)
+        insurance::add_to_pool(&env, amount);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&funder, &env.current_contract_address(), &amount);
+
+        events::emit_insurance_pool_funded(
+            &env,
+            events::InsurancePoolFunded {
+                funder,
+                amount,
+                pool: insurance::pool(&env),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Compensate the depositor of an insured escrow with `amount` from the
+    /// insurance pool (dispute arbiter only). Allowed once per policy, after
+    /// the escrow was released (the arbiter attesting the release was
+    /// fraudulent) or a dispute over it was resolved against the contributor.
+    ///
+    /// # Errors
+    /// * NotInsured - if the escrow has no policy
+    /// * InsuranceClaimNotAllowed - if the policy was already paid out, or
+    ///   the escrow was neither released nor lost in a dispute
+    /// * InvalidAmount - if `amount` is not positive or exceeds the coverage
+    /// * InsufficientFunds - if the pool holds less than `amount`
+    /// * FundsPaused - if refunds are paused
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The policy and pool are
+    /// updated before the outbound token transfer (CEI pattern).
+    pub fn pay_insurance_claim(env: Env, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let arbiter = Self::get_dispute_arbiter(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, DisputeError::ArbiterNotSet));
+        arbiter.require_auth();
+        let mut policy = insurance::policy(&env, bounty_id)
+            .unwrap_or_else(|| panic_with_error!(&env, insurance::InsuranceError::NotInsured));
+        if policy.claimed {
+            panic_with_error!(&env, insurance::InsuranceError::InsuranceClaimNotAllowed);
+        }
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        let dispute: Option<Dispute> = escrow_entries::get(&env, bounty_id, Entry::Dispute);
+        let dispute_lost = dispute.is_some_and(|d| {
+            d.status == DisputeStatus::Resolved
+                && (d.contributor_share_bps as i128) * 2 < token_math::BASIS_POINTS
+        });
+        if escrow.status != EscrowStatus::Released && !dispute_lost {
+            panic_with_error!(&env, insurance::InsuranceError::InsuranceClaimNotAllowed);
+        }
+        if amount <= 0 || amount > policy.coverage {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > insurance::pool(&env) {
+            return Err(Error::InsufficientFunds);
+        }
+        Self::ensure_not_blocked(&env, &policy.depositor)?;
+
+        // EFFECTS: settle the policy before external call (CEI)
+        policy.claimed = true;
+        insurance::set_policy(&env, bounty_id, &policy);
+        insurance::add_to_pool(&env, -amount);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &policy.depositor, &amount);
+
+        events::emit_insurance_claim_paid(
+            &env,
+            events::InsuranceClaimPaid {
+                bounty_id,
+                depositor: policy.depositor,
+                amount,
+                arbiter,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Cap how much `depositor` may lock, through new escrows and top-ups,
+    /// within each `period` seconds (0 caps the lifetime total), or `None`
+    /// to remove the cap (admin only). Changing an existing budget keeps
+    /// what was already spent in the current window.
+    pub fn set_depositor_budget(
+        env: Env,
+        depositor: Address,
+        budget: Option<(i128, u64)>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let (limit, period) = match budget {
+            Some(budget) => budget,
+            None => {
+                budgets::remove(&env, &depositor);
+                return Ok(());
+            }
+        };
+        if limit < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let (window_start, spent) = match budgets::get(&env, &depositor) {
+            Some(existing) => {
+                let existing = budgets::current(&env, existing);
+                (existing.window_start, existing.spent)
+            }
+            None => (env.ledger().timestamp(), 0),
+        };
+        budgets::set(
+            &env,
+            &depositor,
+            &DepositorBudget {
+                limit,
+                period,
+                window_start,
+                spent,
+            },
+        );
+        Ok(())
+    }
+
+    /// View: `depositor`'s budget as of now, if it has one.
+    pub fn get_depositor_budget(env: Env, depositor: Address) -> Option<DepositorBudget> {
+        budgets::get(&env, &depositor).map(|budget| budgets::current(&env, budget))
+    }
+
+    /// View: how much `depositor` may still lock in the current window, or
+    /// `None` when it has no budget.
+    pub fn get_remaining_budget(env: Env, depositor: Address) -> Option<i128> {
+        Self::get_depositor_budget(env, depositor)
+            .map(|budget| (budget.limit - budget.spent).max(0))
+    }
+
+    /// Enforce min/max amount policy if one has been configured (Issue #62).
+    /// When no policy is set this is a no-op, preserving backward-compatible
+    /// behaviour for callers that never call set_amount_policy.
+    fn check_amount_policy(env: &Env, amount: i128) -> Result<(), Error> {
+        if let Some((min_amount, max_amount)) = env
+            .storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        {
+            if amount < min_amount {
+                return Err(Error::AmountBelowMinimum);
+            }
+            if amount > max_amount {
+                return Err(Error::AmountAboveMaximum);
+            }
+        }
+        Ok(())
+    }
+
+    /// Persist `escrow` under `bounty_id`, adding a new escrow to the indexes
+    /// or moving the id between status indexes and publishing
+    /// `status_changed` when its status changes, and keeping the status
+    /// counts and value locked in `ContractStats` current.
+    /// All escrow writes go through here so that the indexes never drift
+    /// from the stored records.
+    fn save_escrow(env: &Env, bounty_id: u64, escrow: &Escrow) {
+        let stored: Option<EscrowCore> =
+            env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+        let previous = stored
+            .as_ref()
+            .map(|core| Self::escrow_from_core(env, bounty_id, core));
+        let details_changed = previous.as_ref().is_none_or(|prev| {
+            prev.refund_history != escrow.refund_history
+                || prev.metadata_hash != escrow.metadata_hash
+                || prev.label != escrow.label
+        });
+
+        let mut stats = Self::load_stats(env);
+        stats.total_value_locked += escrow.remaining_amount;
+        if let Some(prev) = previous.as_ref() {
+            stats.total_value_locked -= prev.remaining_amount;
+            let count = stats.count_mut(&prev.status);
+            *count = count.saturating_sub(1);
+        }
+        *stats.count_mut(&escrow.status) += 1;
+        Self::save_stats(env, &stats);
+
+        let was_indexed = previous
+            .as_ref()
+            .filter(|prev| deadline_index::tracks(&prev.status))
+            .map(|prev| prev.deadline);
+        let indexed = Some(escrow.deadline).filter(|_| deadline_index::tracks(&escrow.status));
+        if was_indexed != indexed {
+            if let Some(deadline) = was_indexed {
+                deadline_index::remove(env, bounty_id, deadline);
+            }
+            if let Some(deadline) = indexed {
+                deadline_index::insert(env, bounty_id, deadline);
+            }
+        }
+
+        // A new escrow takes over the record stored for its id before it was
+        // locked.
+        let (mut entries, history) = match stored {
+            Some(core) => (core.entries, core.history),
+            None => (escrow_entries::take_unlocked(env, bounty_id), Vec::new(env)),
+        };
+        let previous = previous.map(|e| e.status);
+        if previous.as_ref() != Some(&escrow.status) {
+            match previous {
+                Some(status) => {
+                    Self::unindex(
+                        env,
+                        &IndexId::Status(status.clone()),
+                        entries.status_slot,
+                        |e| &mut e.status_slot,
+                    );
+                    events::emit_status_changed(
+                        env,
+                        &mut entries,
+                        events::StatusChanged {
+                            version: EVENT_VERSION_V2,
+                            bounty_id,
+                            from: status,
+                            to: escrow.status.clone(),
+                            timestamp: env.ledger().timestamp(),
+                        },
+                    );
+                }
+                None => {
+                    entries.escrow_slot = escrow_index::push(env, &IndexId::All, bounty_id);
+                    entries.depositor_slot = escrow_index::push(
+                        env,
+                        &IndexId::Depositor(escrow.depositor.clone()),
+                        bounty_id,
+                    );
+                }
+            }
+            entries.status_slot =
+                escrow_index::push(env, &IndexId::Status(escrow.status.clone()), bounty_id);
+        }
+        let extend_to = Self::renew_ttl(env, &mut entries, false);
+        let entries = Self::write_escrow(env, bounty_id, escrow, details_changed, entries, history);
+        if let Some(extend_to) = extend_to {
+            Self::extend_entries(env, bounty_id, &entries, extend_to);
+        }
+    }
+
+    /// Read an escrow record, joining its core and details entries.
+    fn load_escrow(env: &Env, bounty_id: u64) -> Option<Escrow> {
+        Self::load_escrow_record(env, bounty_id).map(|(escrow, _)| escrow)
+    }
+
+    /// Read an escrow record together with its bookkeeping record, for
+    /// callers that check several of its entries.
+    fn load_escrow_record(env: &Env, bounty_id: u64) -> Option<(Escrow, EscrowEntries)> {
+        let core: EscrowCore = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))?;
+        Some((Self::escrow_from_core(env, bounty_id, &core), core.entries))
+    }
+
+    /// The escrow stored as `core`, with its details entry if it has one.
+    fn escrow_from_core(env: &Env, bounty_id: u64, core: &EscrowCore) -> Escrow {
+        let details: Option<EscrowDetails> =
+            escrow_entries::get_in(env, bounty_id, &core.entries, Entry::Details);
+        let (refund_history, metadata_hash, label) = match details {
+            Some(details) => (details.refund_history, details.metadata_hash, details.label),
+            None => (Vec::new(env), None, None),
+        };
+        Escrow {
+            depositor: core.depositor.clone(),
+            amount: core.amount,
+            remaining_amount: core.remaining_amount,
+            status: core.status.clone(),
+            deadline: core.deadline,
+            refund_history,
+            metadata_hash,
+            label,
+        }
+    }
+
+    /// Write `escrow` keeping its stored bookkeeping record and latest
+    /// actions, for tests that plant records the indexes and stats don't know
+    /// about.
+    #[cfg(test)]
+    fn write_escrow_only(env: &Env, bounty_id: u64, escrow: &Escrow, with_details: bool) {
+        let stored: Option<EscrowCore> =
+            env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+        let (entries, history) = match stored {
+            Some(core) => (core.entries, core.history),
+            None => (escrow_entries::load(env, bounty_id), Vec::new(env)),
+        };
+        Self::write_escrow(env, bounty_id, escrow, with_details, entries, history);
+    }
+
+    /// Write `escrow` with its bookkeeping record `entries` and latest
+    /// actions `history` without touching stats or indexes (see
+    /// `save_escrow`), and return the record as stored. The details entry is only rewritten when
+    /// `with_details` is set, and is dropped once all of its fields are empty.
+    fn write_escrow(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        with_details: bool,
+        mut entries: EscrowEntries,
+        history: Vec<HistoryEntry>,
+    ) -> EscrowEntries {
+        if with_details {
+            if escrow.refund_history.is_empty()
+                && escrow.metadata_hash.is_none()
+                && escrow.label.is_none()
+            {
+                escrow_entries::take(env, bounty_id, &mut entries, Entry::Details);
+            } else {
+                let details = EscrowDetails {
+                    refund_history: escrow.refund_history.clone(),
+                    metadata_hash: escrow.metadata_hash.clone(),
+                    label: escrow.label.clone(),
+                };
+                escrow_entries::put(env, bounty_id, &mut entries, Entry::Details, &details);
+            }
+        }
+        env.storage().persistent().set(
+            &DataKey::Escrow(bounty_id),
+            &EscrowCore {
+                depositor: escrow.depositor.clone(),
+                amount: escrow.amount,
+                remaining_amount: escrow.remaining_amount,
+                status: escrow.status.clone(),
+                deadline: escrow.deadline,
+                entries: entries.clone(),
+                history,
+            },
+        );
+        entries
+    }
+
+    /// The action history of `bounty_id`, empty if none is recorded.
+    fn history(env: &Env, bounty_id: u64) -> Vec<HistoryEntry> {
+        let stored: Option<EscrowCore> =
+            env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+        let core = match stored {
+            Some(core) => core,
+            None => {
+                return escrow_entries::get(env, bounty_id, Entry::History).unwrap_or(Vec::new(env))
+            }
+        };
+        let mut history: Vec<HistoryEntry> =
+            escrow_entries::get_in(env, bounty_id, &core.entries, Entry::History)
+                .unwrap_or(Vec::new(env));
+        history.append(&core.history);
+        let len = history.len();
+        history.slice(len.saturating_sub(MAX_HISTORY_ENTRIES)..len)
+    }
+
+    /// Replace the action history of the escrow `bounty_id`.
+    fn set_history(env: &Env, bounty_id: u64, history: &Vec<HistoryEntry>) {
+        let persistent = env.storage().persistent();
+        let key = DataKey::Escrow(bounty_id);
+        if let Some(mut core) = persistent.get::<_, EscrowCore>(&key) {
+            core.history = Vec::new(env);
+            if history.is_empty() {
+                escrow_entries::take(env, bounty_id, &mut core.entries, Entry::History);
+            } else {
+                escrow_entries::put(env, bounty_id, &mut core.entries, Entry::History, history);
+            }
+            persistent.set(&key, &core);
+        }
+    }
+
+    /// Withdraw `bounty_id`'s funds from the yield strategy, if invested, and
+    /// pay the interest earned to the escrow's yield beneficiary. Interest for
+    /// the contributor is held until `pay_release` pays them. Every path that
+    /// pays funds out of an escrow calls this right after saving it, so the
+    /// funds are back in the contract before they move.
+    fn settle_yield(env: &Env, bounty_id: u64, escrow: &Escrow) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let (position, withdrawn) = match yield_strategy::divest(env, bounty_id, &token_addr) {
+            Some(settled) => settled,
+            None => return,
+        };
+        let releasing = matches!(escrow.status, EscrowStatus::Locked | EscrowStatus::Released);
+        let recipient = match position.beneficiary {
+            YieldBeneficiary::Treasury => Self::get_fee_config_internal(env).fee_recipient,
+            YieldBeneficiary::Contributor if releasing && withdrawn > position.principal => {
+                yield_strategy::hold_interest(env, bounty_id, position.principal, withdrawn);
+                return;
+            }
+            YieldBeneficiary::Depositor | YieldBeneficiary::Contributor => escrow.depositor.clone(),
+        };
+        Self::pay_interest(env, bounty_id, &recipient, position.principal, withdrawn);
+    }
+
+    /// Send the interest `withdrawn - principal`, if any, to `recipient`.
+    fn pay_interest(
+        env: &Env,
+        bounty_id: u64,
+        recipient: &Address,
+        principal: i128,
+        withdrawn: i128,
+    ) {
+        let interest = withdrawn - principal;
+        if interest > 0 {
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            token::Client::new(env, &token_addr).transfer(
+                &env.current_contract_address(),
+                recipient,
+                &interest,
+            );
+        }
+        events::emit_yield_settled(
+            env,
+            events::YieldSettled {
+                bounty_id,
+                principal,
+                withdrawn,
+                recipient: recipient.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Keep the records of `bounty_id` (and the contract instance) from being
+    /// archived. Cheap when nothing is due: the entries are only extended once
+    /// the tracked TTL drops below the `TtlPolicy` threshold, or always with
+    /// `force`. Entries added later are extended to the same ledger by
+    /// `escrow_entries::set`.
+    fn bump_escrow_ttl(env: &Env, bounty_id: u64, force: bool) {
+        Self::bump_entries_ttl(env, bounty_id, escrow_entries::load(env, bounty_id), force);
+    }
+
+    /// `bump_escrow_ttl` for an escrow whose entries are already loaded.
+    fn bump_entries_ttl(env: &Env, bounty_id: u64, mut entries: EscrowEntries, force: bool) {
+        if let Some(extend_to) = Self::renew_ttl(env, &mut entries, force) {
+            escrow_entries::store(env, bounty_id, &entries);
+            Self::extend_entries(env, bounty_id, &entries, extend_to);
+        }
+    }
+
+    /// Move `entries.live_until` forward if an extension is due (see
+    /// `bump_escrow_ttl`), returning the ledgers to extend by.
+    fn renew_ttl(env: &Env, entries: &mut EscrowEntries, force: bool) -> Option<u32> {
+        let policy = storage_policy::get(env);
+        let seq = env.ledger().sequence();
+        if !force && entries.live_until.saturating_sub(seq) > policy.threshold {
+            return None;
+        }
+        entries.live_until = seq
+            .saturating_add(policy.extend_to)
+            .min(env.ledger().max_live_until_ledger());
+        Some(policy.extend_to)
+    }
+
+    /// Extend the instance and every entry of `bounty_id` by `extend_to`
+    /// ledgers. `entries` is the record as stored.
+    fn extend_entries(env: &Env, bounty_id: u64, entries: &EscrowEntries, extend_to: u32) {
+        let policy = storage_policy::get(env);
+        env.storage()
+            .instance()
+            .extend_ttl(policy.threshold, policy.extend_to);
+        escrow_entries::extend_ttl(env, bounty_id, entries, extend_to);
+    }
+
+    /// Drop `bounty_id` from the escrow, depositor and status indexes.
+    fn unindex_escrow(env: &Env, bounty_id: u64, depositor: &Address, status: EscrowStatus) {
+        let entries = escrow_entries::load(env, bounty_id);
+        Self::unindex(env, &IndexId::All, entries.escrow_slot, |e| {
+            &mut e.escrow_slot
+        });
+        Self::unindex(
+            env,
+            &IndexId::Depositor(depositor.clone()),
+            entries.depositor_slot,
+            |e| &mut e.depositor_slot,
+        );
+        Self::unindex(env, &IndexId::Status(status), entries.status_slot, |e| {
+            &mut e.status_slot
+        });
+    }
+
+    /// Remove position `slot` from `index`, and record the new position of
+    /// the escrow moved into it in the slot `slot_of` picks.
+    fn unindex(env: &Env, index: &IndexId, slot: u32, slot_of: fn(&mut EscrowEntries) -> &mut u32) {
+        if let Some(moved) = escrow_index::swap_remove(env, index, slot) {
+            escrow_entries::update(env, moved, |entries| *slot_of(entries) = slot);
+        }
+    }
+
+    /// List `bounty_id` among the escrows that paid out to `recipient`, once,
+    /// first moving over the list kept before schema v5, if any. A payout of
+    /// the whole escrow (`whole`) is the only one it ever makes, so only
+    /// escrows paid in parts remember their payees.
+    fn index_payout(env: &Env, bounty_id: u64, recipient: &Address, whole: bool) {
+        let index = IndexId::Contributor(recipient.clone());
+        let legacy_key = DataKey::ContributorIndex(recipient.clone());
+        let persistent = env.storage().persistent();
+        let mut listed = false;
+        if let Some(legacy) = persistent.get::<DataKey, Vec<u64>>(&legacy_key) {
+            for id in legacy.iter() {
+                escrow_index::push(env, &index, id);
+                if persistent.has(&DataKey::Escrow(id)) {
+                    Self::add_payee(env, id, recipient);
+                }
+            }
+            listed = legacy.contains(bounty_id);
+            persistent.remove(&legacy_key);
+        }
+
+        if !whole {
+            listed = !Self::add_payee(env, bounty_id, recipient);
+        }
+        if !listed {
+            escrow_index::push(env, &index, bounty_id);
+        }
+    }
+
+    /// Remember that `bounty_id` is on `recipient`'s contributor list.
+    /// Returns whether it was not before.
+    fn add_payee(env: &Env, bounty_id: u64, recipient: &Address) -> bool {
+        let mut payees: Vec<Address> =
+            escrow_entries::get(env, bounty_id, Entry::Payees).unwrap_or(Vec::new(env));
+        if payees.contains(recipient) {
+            return false;
+        }
+        payees.push_back(recipient.clone());
+        escrow_entries::set(env, bounty_id, Entry::Payees, &payees);
+        true
+    }
+
+    /// Release funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow state is updated
+    /// to `Released` *before* the outbound token transfer (CEI pattern).
+    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        let res = Self::release_funds_logic(env.clone(), None, bounty_id, contributor.clone());
+        monitoring::track_operation(&env, symbol_short!("release"), contributor, res.is_ok());
+        res
+    }
+
+    /// Release funds to the contributor as a holder of the `Releaser` role
+    /// or as the escrow's approver.
+    pub fn release_funds_with_role(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let res =
+            Self::release_funds_logic(env.clone(), Some(caller), bounty_id, contributor.clone());
+        monitoring::track_operation(&env, symbol_short!("release"), contributor, res.is_ok());
+        res
+    }
+
+    /// Release funds to the contributor through the operator contract. Both
+    /// the operator and the escrow's depositor must authorize; the depositor
+    /// signs only `(bounty_id, contributor)`.
+    pub fn release_funds_as_operator(
+        env: Env,
+        operator: Address,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let res = Self::release_as_operator_logic(&env, operator, bounty_id, contributor.clone());
+        monitoring::track_operation(&env, symbol_short!("release"), contributor, res.is_ok());
+        res
+    }
+
+    fn release_as_operator_logic(
+        env: &Env,
+        operator: Address,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        if Self::check_paused(env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(env);
+
+        Self::require_operator(env, &operator)?;
+        let escrow: Escrow = Self::load_escrow(env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow
+            .depositor
+            .require_auth_for_args((bounty_id, contributor.clone()).into_val(env));
+        Self::release_escrow(env, bounty_id, &operator, &contributor)?;
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(env);
+        Ok(())
+    }
+
+    /// Check that `operator` is the configured operator contract and require
+    /// its authorization.
+    fn require_operator(env: &Env, operator: &Address) -> Result<(), Error> {
+        if Self::get_operator_contract(env.clone()).as_ref() != Some(operator) {
+            return Err(Error::Unauthorized);
+        }
+        operator.require_auth();
+        Ok(())
+    }
+
+    fn release_funds_logic(
+        env: Env,
+        caller: Option<Address>,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+
+        // GUARD: acquire reentrancy lock (replaces inline guard)
+        reentrancy_guard::acquire(&env);
+
+        let caller = Self::authorize_release(&env, caller, bounty_id)?;
+        Self::release_escrow(&env, bounty_id, &caller, &contributor)?;
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Authorize a release of `bounty_id`: the escrow's approver may release
+    /// its own bounty, anyone else needs the `Releaser` role.
+    fn authorize_release(
+        env: &Env,
+        caller: Option<Address>,
+        bounty_id: u64,
+    ) -> Result<Address, Error> {
+        if let Some(caller) = &caller {
+            if Self::get_escrow_approver(env.clone(), bounty_id).as_ref() == Some(caller) {
+                caller.require_auth();
+                return Ok(caller.clone());
+            }
+        }
+        rbac::authorize(env, caller, Role::Releaser)
+    }
+
+    /// Authorize a partial release of `bounty_id`: besides the callers
+    /// `authorize_release` accepts, the depositor may pay out its own escrow.
+    fn authorize_partial_release(
+        env: &Env,
+        caller: Option<Address>,
+        bounty_id: u64,
+    ) -> Result<Address, Error> {
+        if let Some(caller) = &caller {
+            let escrow: Option<Escrow> = Self::load_escrow(env, bounty_id);
+            if escrow.map(|escrow| escrow.depositor).as_ref() == Some(caller) {
+                caller.require_auth();
+                return Ok(caller.clone());
+            }
+        }
+        Self::authorize_release(env, caller, bounty_id)
+    }
+
+    /// Checks shared by full, partial and split releases (and their
+    /// simulations) of the `Locked` escrow `bounty_id` to `contributor`, other
+    /// than amounts and release limits. Some checks fail with codes from
+    /// per-feature error enums, hence the plain `soroban_sdk::Error`.
+    /// `entries` is the escrow's bookkeeping record; the checks of features
+    /// it has no entry for are skipped.
+    fn ensure_releasable(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        entries: &EscrowEntries,
+        contributor: &Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        if entries.has(Entry::Dispute) {
+            Self::ensure_no_open_dispute(env, bounty_id)?;
+        }
+        if entries.has(Entry::Freeze) {
+            Self::ensure_not_frozen(env, bounty_id)?;
+        }
+        Self::ensure_not_blocked(env, contributor)?;
+        release_policy::ensure_allowed(env, escrow, contributor)?;
+        if entries.has(Entry::KycVerifier) {
+            kyc::ensure_verified(env, bounty_id, contributor)?;
+        }
+        if entries.has(Entry::IssueLink) {
+            issue_links::ensure_verified(env, bounty_id)?;
+        }
+        if entries.has(Entry::Assignment) {
+            Self::ensure_assignment_accepted(env, bounty_id, contributor)?;
+        }
+        if entries.has(Entry::SubmissionRequired) {
+            Self::ensure_work_submitted(env, bounty_id)?;
+        }
+        if entries.has(Entry::VestingStream) {
+            Self::ensure_no_stream(env, bounty_id)?;
+        }
+        if entries.has(Entry::Milestones) {
+            Self::ensure_no_milestones(env, bounty_id)?;
+        }
+        if entries.has(Entry::FundingGoal) {
+            funders::ensure_goal_met(env, bounty_id, escrow.amount)?;
+        }
+        Ok(())
+    }
+
+    /// Release the full escrow of `bounty_id` to `contributor` on behalf of the
+    /// already authorized `actor` and return the released amount. Pause
+    /// checks, authorization and the reentrancy guard are the caller's
+    /// responsibility. No state is changed on error.
+    fn release_escrow(
+        env: &Env,
+        bounty_id: u64,
+        actor: &Address,
+        contributor: &Address,
+    ) -> Result<i128, Error> {
+        let (mut escrow, entries) =
+            Self::load_escrow_record(env, bounty_id).ok_or(Error::BountyNotFound)?;
+
+        // Block direct release while an active dispute (pending claim) exists.
+        if let Some(claim) =
+            escrow_entries::get_in::<ClaimRecord>(env, bounty_id, &entries, Entry::PendingClaim)
+        {
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
+            }
+        }
+
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+        or_abort(
+            env,
+            Self::ensure_releasable(env, bounty_id, &escrow, &entries, contributor),
+        );
+        Self::check_release_approvals(
+            env,
+            bounty_id,
+            &escrow,
+            contributor,
+            escrow.remaining_amount,
+        )?;
+        release_limits::consume(env, bounty_id, escrow.remaining_amount)?;
+
+        // EFFECTS: update state before external call (CEI)
+        let release_amount = escrow.remaining_amount;
+        state_machine::transition(&mut escrow, StatusEvent::Release)?;
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(env, &escrow);
+        Self::save_escrow(env, bounty_id, &escrow);
+        Self::settle_yield(env, bounty_id, &escrow);
+        if entries.has(Entry::ReleaseApproval) {
+            escrow_entries::remove(env, bounty_id, Entry::ReleaseApproval);
+        }
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        let net = Self::pay_release(env, &client, bounty_id, actor, contributor, release_amount)?;
+        badges::mint_completion(env, bounty_id, contributor, net);
+
+        emit_funds_released(
+            env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: release_amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(release_amount)
+    }
+
+    /// Delegated release flow using a capability instead of admin auth.
+    /// The capability amount limit is consumed by `payout_amount`.
+    pub fn release_with_capability(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if payout_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+        if payout_amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+        Self::ensure_no_open_dispute(&env, bounty_id)?;
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        Self::ensure_no_stream(&env, bounty_id)?;
+        Self::ensure_no_milestones(&env, bounty_id)?;
+        Self::check_release_approvals(&env, bounty_id, &escrow, &contributor, payout_amount)?;
+
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Release,
+            bounty_id,
+            payout_amount,
+        )?;
+
+        // EFFECTS: update escrow state before the external call
+        escrow.remaining_amount -= payout_amount;
+        if escrow.remaining_amount == 0 {
+            state_machine::transition(&mut escrow, StatusEvent::Release)?;
+        }
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+        escrow_entries::remove(&env, bounty_id, Entry::ReleaseApproval);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::pay_release(
+            &env,
+            &client,
+            bounty_id,
+            &holder,
+            &contributor,
+            payout_amount,
+        )?;
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: payout_amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Set the claim window duration (admin only).
+    /// claim_window: seconds beneficiary has to claim after release is authorized.
+    pub fn set_claim_window(env: Env, claim_window: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimWindow, &claim_window);
+        Ok(())
+    }
+
+    /// Authorize a release as a pending claim instead of immediate transfer.
+    /// Admin calls this instead of release_funds when claim period is active.
+    /// Beneficiary must call claim() within the window to receive funds.
+    pub fn authorize_claim(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        reason: DisputeReason,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_no_milestones(&env, bounty_id)?;
+
+        let now = env.ledger().timestamp();
+        let claim_window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimWindow)
+            .unwrap_or(0);
+        let claim = ClaimRecord {
+            bounty_id,
+            recipient: recipient.clone(),
+            amount: escrow.remaining_amount,
+            expires_at: now.saturating_add(claim_window),
+            claimed: false,
+            reason,
+        };
+
+        escrow_entries::set(&env, bounty_id, Entry::PendingClaim, &claim);
+
+        events::publish_escrow(
+            &env,
+            bounty_id,
+            (symbol_short!("claim"), symbol_short!("created")),
+            ClaimCreated {
+                bounty_id,
+                recipient,
+                amount: claim.amount,
+                expires_at: claim.expires_at,
+                reason,
+            },
+        );
+        Ok(())
+    }
+
+    /// Beneficiary calls this to claim their authorized funds within the window.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow and claim state
+    /// are updated *before* the outbound token transfer (CEI pattern).
+    pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        if !escrow_entries::has(&env, bounty_id, Entry::PendingClaim) {
+            return Err(Error::BountyNotFound);
+        }
+        let mut claim: ClaimRecord =
+            escrow_entries::get(&env, bounty_id, Entry::PendingClaim).unwrap();
+
+        claim.recipient.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now > claim.expires_at {
+            return Err(Error::DeadlineNotPassed); // reuse or add ClaimExpired error
+        }
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_frozen(&env, bounty_id)?;
+
+        // EFFECTS: update escrow and claim state before external call (CEI)
+        let claim_amount = claim.amount;
+        let claim_recipient = claim.recipient.clone();
+
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        state_machine::transition(&mut escrow, StatusEvent::Release)?;
+        escrow.remaining_amount = 0;
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+
+        claim.claimed = true;
+        escrow_entries::set(&env, bounty_id, Entry::PendingClaim, &claim);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::pay_release(
+            &env,
+            &client,
+            bounty_id,
+            &claim_recipient,
+            &claim_recipient,
+            claim_amount,
+        )?;
+
+        events::publish_escrow(
+            &env,
+            bounty_id,
+            (symbol_short!("claim"), symbol_short!("done")),
+            ClaimExecuted {
+                bounty_id,
+                recipient: claim_recipient,
+                amount: claim_amount,
+                claimed_at: now,
+                outcome: DisputeOutcome::ResolvedByPayout,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Delegated claim execution using a capability.
+    /// Funds are still transferred to the pending claim recipient.
+    pub fn claim_with_capability(
+        env: Env,
+        bounty_id: u64,
+        holder: Address,
+        capability_id: u64,
+    ) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if !escrow_entries::has(&env, bounty_id, Entry::PendingClaim) {
+            return Err(Error::BountyNotFound);
+        }
+
+        let mut claim: ClaimRecord =
+            escrow_entries::get(&env, bounty_id, Entry::PendingClaim).unwrap();
+
+        let now = env.ledger().timestamp();
+        if now > claim.expires_at {
+            return Err(Error::DeadlineNotPassed);
+        }
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_frozen(&env, bounty_id)?;
+
+        Self::consume_capability(
+            &env,
+            &holder,
+            capability_id,
+            CapabilityAction::Claim,
+            bounty_id,
+            claim.amount,
+        )?;
+
+        // EFFECTS: update escrow and claim state before the external call
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        state_machine::transition(&mut escrow, StatusEvent::Release)?;
+        Self::save_escrow(&env, bounty_id, &escrow);
+
+        claim.claimed = true;
+        escrow_entries::set(&env, bounty_id, Entry::PendingClaim, &claim);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::pay_release(
+            &env,
+            &client,
+            bounty_id,
+            &holder,
+            &claim.recipient,
+            claim.amount,
+        )?;
+
+        events::publish_escrow(
+            &env,
+            bounty_id,
+            (symbol_short!("claim"), symbol_short!("done")),
+            ClaimExecuted {
+                bounty_id,
+                recipient: claim.recipient,
+                amount: claim.amount,
+                claimed_at: now,
+                outcome: DisputeOutcome::ResolvedByPayout,
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    fn ensure_owner_still_authorized(
-        env: &Env,
-        capability: &Capability,
-        requested_amount: i128,
+    /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
+    pub fn cancel_pending_claim(
+        env: Env,
+        bounty_id: u64,
+        outcome: DisputeOutcome,
     ) -> Result<(), Error> {
-        if requested_amount <= 0 {
-            return Err(Error::InvalidAmount);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
         }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        match capability.action {
-            CapabilityAction::Claim => {
-                let claim: ClaimRecord = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::PendingClaim(capability.bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if claim.claimed {
-                    return Err(Error::FundsNotLocked);
-                }
-                if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
-                }
-                if claim.recipient != capability.owner {
-                    return Err(Error::Unauthorized);
-                }
-                if requested_amount > claim.amount {
-                    return Err(Error::CapabilityExceedsAuthority);
-                }
-            }
-            CapabilityAction::Release => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != capability.owner {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(capability.bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
-                    return Err(Error::FundsNotLocked);
-                }
-                if requested_amount > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
-                }
-            }
-            CapabilityAction::Refund => {
-                let admin: Address = env
-                    .storage()
-                    .instance()
-                    .get(&DataKey::Admin)
-                    .ok_or(Error::NotInitialized)?;
-                if admin != capability.owner {
-                    return Err(Error::Unauthorized);
-                }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(capability.bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
-                    return Err(Error::FundsNotLocked);
-                }
-                if requested_amount > escrow.remaining_amount {
-                    return Err(Error::CapabilityExceedsAuthority);
-                }
-            }
+        if !escrow_entries::has(&env, bounty_id, Entry::PendingClaim) {
+            return Err(Error::BountyNotFound);
+        }
+        let claim: ClaimRecord = escrow_entries::get(&env, bounty_id, Entry::PendingClaim).unwrap();
+
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
         }
+
+        escrow_entries::remove(&env, bounty_id, Entry::PendingClaim);
+
+        events::publish_escrow(
+            &env,
+            bounty_id,
+            (symbol_short!("claim"), symbol_short!("cancel")),
+            ClaimCancelled {
+                bounty_id,
+                recipient: claim.recipient,
+                amount: claim.amount,
+                cancelled_at: env.ledger().timestamp(),
+                cancelled_by: admin,
+                outcome,
+            },
+        );
         Ok(())
     }
 
-    fn consume_capability(
-        env: &Env,
-        holder: &Address,
-        capability_id: u64,
-        expected_action: CapabilityAction,
+    /// View: get pending claim for a bounty.
+    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Result<ClaimRecord, Error> {
+        escrow_entries::get(&env, bounty_id, Entry::PendingClaim).ok_or(Error::BountyNotFound)
+    }
+
+    /// Approve a refund before deadline (admin only).
+    /// This allows early refunds with admin approval. The approval lapses
+    /// once the refund approval window, if set, has passed.
+    pub fn approve_refund(
+        env: Env,
         bounty_id: u64,
         amount: i128,
-    ) -> Result<Capability, Error> {
-        let mut capability = Self::load_capability(env, capability_id)?;
+        recipient: Address,
+        mode: RefundMode,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
 
-        if capability.revoked {
-            return Err(Error::CapabilityRevoked);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
         }
-        if capability.action != expected_action {
-            return Err(Error::CapabilityActionMismatch);
+
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
         }
-        if capability.bounty_id != bounty_id {
-            return Err(Error::CapabilityActionMismatch);
+
+        let contributor_share = match &mode {
+            RefundMode::Split(_, share) if *share <= 0 => return Err(Error::InvalidAmount),
+            RefundMode::Split(_, share) => *share,
+            _ => 0,
+        };
+        let settled = amount
+            .checked_add(contributor_share)
+            .ok_or(Error::InvalidAmount)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
         }
-        if capability.holder != holder.clone() {
-            return Err(Error::Unauthorized);
+        if settled > escrow.remaining_amount {
+            return Err(Error::AmountExceedsRemaining);
         }
-        if env.ledger().timestamp() > capability.expiry {
-            return Err(Error::CapabilityExpired);
+
+        let approval = RefundApproval {
+            bounty_id,
+            amount,
+            recipient: recipient.clone(),
+            mode: mode.clone(),
+            approved_by: admin.clone(),
+            approved_at: env.ledger().timestamp(),
+        };
+
+        escrow_entries::set(&env, bounty_id, Entry::RefundApproval, &approval);
+
+        events::emit_refund_approved(
+            &env,
+            events::RefundApproved {
+                bounty_id,
+                amount,
+                recipient,
+                mode,
+                approved_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Release a partial amount of the locked funds to the contributor.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
+    /// - `remaining_amount` is decremented by `payout_amount` after each call.
+    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
+    /// - The bounty stays Locked while any funds remain unreleased.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow state is updated
+    /// *before* the outbound token transfer (CEI pattern).
+    pub fn partial_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+    ) -> Result<(), Error> {
+        Self::partial_release_logic(env, None, bounty_id, contributor, payout_amount)
+    }
+
+    /// Release a partial amount as a holder of the `Releaser` role, as the
+    /// escrow's approver, or as the escrow's depositor, who can pay out
+    /// their own escrow incrementally without the platform admin.
+    pub fn partial_release_with_role(
+        env: Env,
+        caller: Address,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+    ) -> Result<(), Error> {
+        Self::partial_release_logic(env, Some(caller), bounty_id, contributor, payout_amount)
+    }
+
+    fn partial_release_logic(
+        env: Env,
+        caller: Option<Address>,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
         }
-        if capability.remaining_uses == 0 {
-            return Err(Error::CapabilityUsesExhausted);
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let caller = Self::authorize_partial_release(&env, caller, bounty_id)?;
+        Self::partial_release_escrow(&env, bounty_id, &caller, &contributor, payout_amount)?;
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Pay `payout_amount` of a `Locked` escrow to `contributor` on behalf
+    /// of the already authorized `actor`. Pause checks, authorization and the
+    /// reentrancy guard are the caller's responsibility.
+    fn partial_release_escrow(
+        env: &Env,
+        bounty_id: u64,
+        actor: &Address,
+        contributor: &Address,
+        payout_amount: i128,
+    ) -> Result<(), Error> {
+        let (mut escrow, entries) =
+            Self::load_escrow_record(env, bounty_id).ok_or(Error::BountyNotFound)?;
+
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+        or_abort(
+            env,
+            Self::ensure_releasable(env, bounty_id, &escrow, &entries, contributor),
+        );
+
+        // Guard: zero or negative payout makes no sense and would corrupt state
+        if payout_amount == 0 {
+            panic_with_error!(env, AmountError::ZeroAmount);
         }
-        if amount > capability.remaining_amount {
-            return Err(Error::CapabilityAmountExceeded);
+        if payout_amount < 0 {
+            panic_with_error!(env, AmountError::NegativeAmount);
         }
 
-        holder.require_auth();
-        Self::ensure_owner_still_authorized(env, &capability, amount)?;
+        // Guard: prevent overpayment — payout cannot exceed what is still owed
+        if payout_amount > escrow.remaining_amount {
+            return Err(Error::AmountExceedsRemaining);
+        }
+        Self::check_release_approvals(env, bounty_id, &escrow, contributor, payout_amount)?;
+        or_abort(env, payout_caps::consume(env, bounty_id, payout_amount));
+        release_limits::consume(env, bounty_id, payout_amount)?;
 
-        capability.remaining_amount -= amount;
-        capability.remaining_uses -= 1;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Capability(capability_id), &capability);
+        // EFFECTS: update escrow state before external call (CEI)
+        escrow.remaining_amount -= payout_amount;
+        if escrow.remaining_amount == 0 {
+            state_machine::transition(&mut escrow, StatusEvent::Release)?;
+            escrow_entries::remove(env, bounty_id, Entry::ReleaseApproval);
+        }
+        Self::save_escrow(env, bounty_id, &escrow);
+        Self::settle_yield(env, bounty_id, &escrow);
 
-        events::emit_capability_used(
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        Self::pay_release(env, &client, bounty_id, actor, contributor, payout_amount)?;
+
+        events::emit_funds_partially_released(
             env,
-            events::CapabilityUsed {
-                capability_id,
-                holder: holder.clone(),
-                action: capability.action.clone(),
-                bounty_id,
-                amount_used: amount,
-                remaining_amount: capability.remaining_amount,
-                remaining_uses: capability.remaining_uses,
-                used_at: env.ledger().timestamp(),
+            events::FundsPartiallyReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: payout_amount,
+                recipient: contributor.clone(),
+                remaining_amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(capability)
+        Ok(())
     }
 
-    #[allow(clippy::too_many_arguments)]
-    pub fn issue_capability(
+    /// Register the ed25519 public key that signs release vouchers for
+    /// `bounty_id`, or `None` to stop accepting vouchers (approver only).
+    pub fn set_voucher_signer(
         env: Env,
-        owner: Address,
-        holder: Address,
-        action: CapabilityAction,
         bounty_id: u64,
-        amount_limit: i128,
-        expiry: u64,
-        max_uses: u32,
-    ) -> Result<u64, Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        signer: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let approver =
+            Self::get_escrow_approver(env.clone(), bounty_id).ok_or(Error::Unauthorized)?;
+        approver.require_auth();
+        match signer {
+            Some(public_key) => {
+                escrow_entries::set(&env, bounty_id, Entry::VoucherSigner, &public_key)
+            }
+            None => escrow_entries::remove(&env, bounty_id, Entry::VoucherSigner),
         }
-        if max_uses == 0 {
-            return Err(Error::InvalidAmount);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// View: the voucher signing key registered for `bounty_id`, if any.
+    pub fn get_voucher_signer(env: Env, bounty_id: u64) -> Option<BytesN<32>> {
+        escrow_entries::get(&env, bounty_id, Entry::VoucherSigner)
+    }
+
+    /// View: the highest voucher nonce redeemed for `bounty_id` (0 if none).
+    pub fn get_voucher_nonce(env: Env, bounty_id: u64) -> u64 {
+        escrow_entries::get(&env, bounty_id, Entry::VoucherNonce).unwrap_or(0)
+    }
+
+    /// View: the highest voucher nonce redeemed on behalf of `address`
+    /// across all escrows (0 if none). See the `nonce` module.
+    pub fn get_nonce(env: Env, address: Address) -> u64 {
+        nonce::get(&env, &address)
+    }
+
+    /// Release `amount` of `bounty_id` to `contributor` using a voucher
+    /// signed off-chain by the escrow's voucher signer. Anyone can submit
+    /// it, so a bot can settle payouts without holding the approver's key.
+    ///
+    /// The signature covers the XDR of `(escrow address, bounty_id,
+    /// contributor, amount, nonce)`. `nonce` must be above the last one
+    /// redeemed for the bounty and not yet redeemed on behalf of the
+    /// escrow's approver, so each voucher can be used once. Fails with
+    /// `Unauthorized` if the escrow has no approver or no registered signer.
+    pub fn release_with_voucher(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
         }
 
-        let now = env.ledger().timestamp();
-        if expiry <= now {
-            return Err(Error::InvalidDeadline);
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let approver =
+            Self::get_escrow_approver(env.clone(), bounty_id).ok_or(Error::Unauthorized)?;
+        let signer = Self::get_voucher_signer(env.clone(), bounty_id).ok_or(Error::Unauthorized)?;
+        if nonce <= Self::get_voucher_nonce(env.clone(), bounty_id) {
+            panic_with_error!(&env, nonce::VoucherError::VoucherNonceUsed);
         }
+        let message = (
+            env.current_contract_address(),
+            bounty_id,
+            contributor.clone(),
+            amount,
+            nonce,
+        )
+            .to_xdr(&env);
+        env.crypto().ed25519_verify(&signer, &message, &signature);
 
-        owner.require_auth();
-        Self::validate_capability_scope_at_issue(&env, &owner, &action, bounty_id, amount_limit)?;
+        or_abort(&env, nonce::consume(&env, &approver, nonce));
+        escrow_entries::set(&env, bounty_id, Entry::VoucherNonce, &nonce);
+        Self::partial_release_escrow(&env, bounty_id, &approver, &contributor, amount)?;
 
-        let capability_id = Self::next_capability_id(&env);
-        let capability = Capability {
-            owner: owner.clone(),
-            holder: holder.clone(),
-            action: action.clone(),
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Pay the remaining balance of a `Locked` escrow to `contributor`
+    /// linearly over `duration` seconds instead of as a lump sum, e.g. for
+    /// retainer-style bounties. Only the admin (backend) can authorize this.
+    ///
+    /// The contributor collects vested funds with `withdraw_vested`. While
+    /// the stream runs the escrow cannot be released, refunded, topped up or
+    /// cancelled.
+    pub fn release_stream(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        duration: u64,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        let admin = rbac::authorize(&env, None, Role::Releaser)?;
+
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_no_open_dispute(&env, bounty_id)?;
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        Self::ensure_no_stream(&env, bounty_id)?;
+        Self::ensure_no_milestones(&env, bounty_id)?;
+        if duration == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        if escrow.remaining_amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::check_release_approvals(
+            &env,
             bounty_id,
-            amount_limit,
-            remaining_amount: amount_limit,
-            expiry,
-            remaining_uses: max_uses,
-            revoked: false,
-        };
+            &escrow,
+            &contributor,
+            escrow.remaining_amount,
+        )?;
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Capability(capability_id), &capability);
+        let stream = VestingStream {
+            contributor,
+            total: escrow.remaining_amount,
+            withdrawn: 0,
+            start: env.ledger().timestamp(),
+            duration,
+        };
+        escrow_entries::set(&env, bounty_id, Entry::VestingStream, &stream);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
 
-        events::emit_capability_issued(
+        events::emit_stream_started(
             &env,
-            events::CapabilityIssued {
-                capability_id,
-                owner,
-                holder,
-                action,
+            events::StreamStarted {
                 bounty_id,
-                amount_limit,
-                expires_at: expiry,
-                max_uses,
-                timestamp: now,
+                contributor: stream.contributor,
+                total: stream.total,
+                start: stream.start,
+                duration,
+                admin,
             },
         );
-
-        Ok(capability_id)
+        Ok(())
     }
 
-    pub fn revoke_capability(env: Env, owner: Address, capability_id: u64) -> Result<(), Error> {
-        let mut capability = Self::load_capability(&env, capability_id)?;
-        if capability.owner != owner {
-            return Err(Error::Unauthorized);
+    /// Withdraw everything vested so far from the stream of `bounty_id`.
+    /// Requires the contributor's authorization and returns the gross amount
+    /// paid out (release fees apply as for any release). The escrow becomes
+    /// `Released` with the final withdrawal.
+    pub fn withdraw_vested(env: Env, bounty_id: u64) -> Result<i128, Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
         }
-        owner.require_auth();
 
-        if capability.revoked {
-            return Ok(());
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let mut stream: VestingStream = escrow_entries::get(&env, bounty_id, Entry::VestingStream)
+            .unwrap_or_else(|| panic_with_error!(&env, StreamError::StreamNotFound));
+        stream.contributor.require_auth();
+        Self::ensure_not_frozen(&env, bounty_id)?;
+
+        let amount = stream.vested_at(env.ledger().timestamp()) - stream.withdrawn;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        capability.revoked = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Capability(capability_id), &capability);
+        // EFFECTS: update state before external call (CEI)
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        escrow.remaining_amount -= amount;
+        if escrow.remaining_amount == 0 {
+            state_machine::transition(&mut escrow, StatusEvent::Release)?;
+        }
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
 
-        events::emit_capability_revoked(
+        stream.withdrawn += amount;
+        if stream.withdrawn == stream.total {
+            escrow_entries::remove(&env, bounty_id, Entry::VestingStream);
+        } else {
+            escrow_entries::set(&env, bounty_id, Entry::VestingStream, &stream);
+        }
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::pay_release(
             &env,
-            events::CapabilityRevoked {
-                capability_id,
-                owner,
-                revoked_at: env.ledger().timestamp(),
+            &client,
+            bounty_id,
+            &stream.contributor,
+            &stream.contributor,
+            amount,
+        )?;
+
+        events::emit_funds_partially_released(
+            &env,
+            events::FundsPartiallyReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount,
+                recipient: stream.contributor,
+                remaining_amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
             },
         );
 
-        Ok(())
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(amount)
     }
 
-    pub fn get_capability(env: Env, capability_id: u64) -> Result<Capability, Error> {
-        Self::load_capability(&env, capability_id)
+    /// View: the active stream of `bounty_id`, if any.
+    pub fn get_vesting_stream(env: Env, bounty_id: u64) -> Option<VestingStream> {
+        escrow_entries::get(&env, bounty_id, Entry::VestingStream)
     }
 
-    /// Get current fee configuration (view function)
-    pub fn get_fee_config(env: Env) -> FeeConfig {
-        Self::get_fee_config_internal(&env)
+    /// View: amount the contributor could withdraw from the stream right now.
+    pub fn get_withdrawable(env: Env, bounty_id: u64) -> Result<i128, StreamError> {
+        let stream =
+            Self::get_vesting_stream(env.clone(), bounty_id).ok_or(StreamError::StreamNotFound)?;
+        Ok(stream.vested_at(env.ledger().timestamp()) - stream.withdrawn)
     }
 
-    /// Retrieves the chain identifier.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
+    /// Release funds to several contributors in one transaction.
+    /// Only the admin (backend) can authorize this.
     ///
-    /// # Returns
-    /// * `Option<String>` - Chain identifier if set, None if not initialized with network config
+    /// Each `(recipient, amount)` pair must have a positive amount and the
+    /// total must not exceed `remaining_amount`. Every recipient goes through
+    /// the same checks as a partial release to them, and each share counts
+    /// against the escrow's payout caps and the release rate limit. The
+    /// escrow becomes `Released` once nothing remains; otherwise it stays
+    /// `Locked`.
     ///
-    /// # Usage
-    /// Use this to verify the chain environment for:
-    /// - Cross-network protection
-    /// - Replay attack prevention
-    /// - Environment-specific behavior
-    pub fn get_chain_id(env: Env) -> Option<String> {
-        env.storage().instance().get(&DataKey::ChainId)
+    /// # Errors
+    /// * InvalidBatchSize - if no recipients are given or more than MAX_BATCH_SIZE
+    /// * InvalidAmount - if any amount is zero or negative
+    /// * InsufficientFunds - if the total exceeds `remaining_amount`
+    /// * InsufficientApprovals - if a recipient is paid once the released
+    ///   total reaches the multisig threshold without release approvals for
+    ///   that recipient
+    pub fn release_split(
+        env: Env,
+        bounty_id: u64,
+        splits: Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if splits.is_empty() || splits.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let caller = rbac::authorize(&env, None, Role::Releaser)?;
+
+        let (mut escrow, entries) =
+            Self::load_escrow_record(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+
+        let mut total: i128 = 0;
+        for (recipient, amount) in splits.iter() {
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total.checked_add(amount).ok_or(Error::InvalidAmount)?;
+            or_abort(
+                &env,
+                Self::ensure_releasable(&env, bounty_id, &escrow, &entries, &recipient),
+            );
+            Self::check_release_approvals(&env, bounty_id, &escrow, &recipient, total)?;
+            or_abort(&env, payout_caps::consume(&env, bounty_id, amount));
+            release_limits::consume(&env, bounty_id, amount)?;
+        }
+        if total > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+
+        // EFFECTS: update escrow state before external calls (CEI)
+        escrow.remaining_amount -= total;
+        if escrow.remaining_amount == 0 {
+            state_machine::transition(&mut escrow, StatusEvent::Release)?;
+            escrow_entries::remove(&env, bounty_id, Entry::ReleaseApproval);
+        }
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+
+        // INTERACTION: external token transfers are last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        let timestamp = env.ledger().timestamp();
+        for (recipient, amount) in splits.iter() {
+            Self::pay_release(&env, &client, bounty_id, &caller, &recipient, amount)?;
+            emit_funds_released(
+                &env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    recipient,
+                    timestamp,
+                },
+            );
+        }
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
     }
 
-    /// Retrieves the network identifier.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
+    /// Lock funds for a bounty that pays out in milestones.
     ///
-    /// # Returns
-    /// * `Option<String>` - Network identifier if set, None if not initialized with network config
+    /// The escrow amount is the sum of all milestone amounts and the escrow
+    /// deadline is the latest milestone deadline. Each milestone must be
+    /// approved by the depositor (`approve_milestone`) before the admin can
+    /// pay it out with `release_milestone`; the other release paths reject
+    /// the escrow with `MilestoneEscrow`.
     ///
-    /// # Usage
-    /// Use this to verify the network environment for:
-    /// - Environment-specific behavior
-    /// - Testnet vs mainnet differentiation
-    /// - Safe replay protection
-    pub fn get_network_id(env: Env) -> Option<String> {
-        env.storage().instance().get(&DataKey::NetworkId)
+    /// # Errors
+    /// * InvalidBatchSize - if no milestones are given or more than MAX_MILESTONES
+    /// * InvalidAmount - if any milestone amount is zero or negative
+    /// * Any error returned by `lock_funds`
+    pub fn lock_funds_with_milestones(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        milestones: Vec<Milestone>,
+    ) -> Result<(), Error> {
+        if milestones.is_empty() || milestones.len() > MAX_MILESTONES {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let mut total: i128 = 0;
+        let mut deadline: u64 = 0;
+        let mut records: Vec<MilestoneRecord> = Vec::new(&env);
+        for milestone in milestones.iter() {
+            if milestone.amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            total = total
+                .checked_add(milestone.amount)
+                .ok_or(Error::InvalidAmount)?;
+            deadline = deadline.max(milestone.deadline);
+            records.push_back(MilestoneRecord {
+                amount: milestone.amount,
+                description_hash: milestone.description_hash,
+                deadline: milestone.deadline,
+                status: MilestoneStatus::Pending,
+            });
+        }
+
+        escrow_entries::set(&env, bounty_id, Entry::Milestones, &records);
+
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            total,
+            deadline,
+            None,
+            None,
+            None,
+        );
+        if res.is_ok() {
+            // A token that charges a fee on transfer delivers less than the
+            // milestones add up to; scale them to what the escrow holds so
+            // every milestone can still be paid.
+            let locked = Self::load_escrow(&env, bounty_id).unwrap().amount;
+            if locked < total {
+                let mut amounts: Vec<i128> = Vec::new(&env);
+                for record in records.iter() {
+                    amounts.push_back(record.amount);
+                }
+                let shares = Self::split_received(&env, &amounts, total, locked)?;
+                for (i, share) in shares.iter().enumerate() {
+                    let mut record = records.get(i as u32).unwrap();
+                    record.amount = share;
+                    records.set(i as u32, record);
+                }
+                escrow_entries::set(&env, bounty_id, Entry::Milestones, &records);
+            }
+        }
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
+        res
     }
 
-    /// Gets both chain and network identifiers as a tuple.
-    ///
-    /// # Arguments
-    /// * `env` - The contract environment
-    ///
-    /// # Returns
-    /// * `(Option<String>, Option<String>)` - Tuple of (chain_id, network_id)
+    /// Create or replace escrow template `template_id` (admin only).
     ///
-    /// # Usage
-    /// Convenience function to get both identifiers in one call.
-    pub fn get_network_info(env: Env) -> (Option<String>, Option<String>) {
-        (
-            env.storage().instance().get(&DataKey::ChainId),
-            env.storage().instance().get(&DataKey::NetworkId),
-        )
-    }
-
-    /// Update multisig configuration (admin only)
-    pub fn update_multisig_config(
+    /// # Errors
+    /// * InvalidDeadline - if an offset is zero or a milestone deadline falls
+    ///   after the template deadline
+    /// * InvalidFeeRate - if the release fee rate is out of range
+    /// * InvalidBatchSize - if there are more than MAX_MILESTONES milestones
+    /// * InvalidSplit - if milestone shares are zero or do not add up to 10_000
+    pub fn set_escrow_template(
         env: Env,
-        threshold_amount: i128,
-        signers: Vec<Address>,
-        required_signatures: u32,
+        template_id: u64,
+        template: EscrowTemplate,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
-        if required_signatures > signers.len() {
-            return Err(Error::InvalidAmount);
+        if template.deadline_offset == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        if let Some(rate) = template.release_fee_rate {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+        }
+        if template.milestones.len() > MAX_MILESTONES {
+            return Err(Error::InvalidBatchSize);
+        }
+        let mut total_share: i128 = 0;
+        for milestone in template.milestones.iter() {
+            if milestone.share_bps == 0 {
+                return Err(Error::InvalidSplit);
+            }
+            if milestone.deadline_offset == 0
+                || milestone.deadline_offset > template.deadline_offset
+            {
+                return Err(Error::InvalidDeadline);
+            }
+            total_share += milestone.share_bps as i128;
+        }
+        if !template.milestones.is_empty() && total_share != token_math::BASIS_POINTS {
+            return Err(Error::InvalidSplit);
         }
 
-        let config = MultisigConfig {
-            threshold_amount,
-            signers,
-            required_signatures,
-        };
+        templates::set(&env, template_id, &template);
+        Ok(())
+    }
 
-        env.storage()
+    /// Delete escrow template `template_id` (admin only). Escrows already
+    /// locked from it are unaffected.
+    pub fn remove_escrow_template(env: Env, template_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
             .instance()
-            .set(&DataKey::MultisigConfig, &config);
-
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if templates::get(&env, template_id).is_none() {
+            panic_with_error!(&env, templates::TemplateError::TemplateNotFound);
+        }
+        templates::remove(&env, template_id);
         Ok(())
     }
 
-    /// Get multisig configuration
-    pub fn get_multisig_config(env: Env) -> MultisigConfig {
-        env.storage()
-            .instance()
-            .get(&DataKey::MultisigConfig)
-            .unwrap_or(MultisigConfig {
-                threshold_amount: i128::MAX,
-                signers: vec![&env],
-                required_signatures: 0,
-            })
+    /// View: escrow template `template_id`, if defined.
+    pub fn get_escrow_template(env: Env, template_id: u64) -> Option<EscrowTemplate> {
+        templates::get(&env, template_id)
     }
 
-    /// Approve release for large amount (requires multisig)
-    pub fn approve_large_release(
+    /// Lock `amount` for `bounty_id` with the parameters of template
+    /// `template_id`: the deadline is the template offset from now, the
+    /// template's fee rate and approver are applied, and a milestone
+    /// schedule splits `amount` by share, rounding dust going to the last
+    /// milestone. As with `lock_funds_with_milestones`, the latest milestone
+    /// deadline then becomes the escrow deadline.
+    pub fn lock_funds_from_template(
         env: Env,
+        depositor: Address,
+        template_id: u64,
         bounty_id: u64,
-        contributor: Address,
-        approver: Address,
+        amount: i128,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        let template = templates::get(&env, template_id)
+            .unwrap_or_else(|| panic_with_error!(&env, templates::TemplateError::TemplateNotFound));
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
         }
 
-        let multisig_config: MultisigConfig = Self::get_multisig_config(env.clone());
+        let now = env.ledger().timestamp();
+        if template.milestones.is_empty() {
+            let deadline = now.saturating_add(template.deadline_offset);
+            Self::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)?;
+        } else {
+            let milestones = templates::milestones(&env, &template, amount, now);
+            Self::lock_funds_with_milestones(env.clone(), depositor, bounty_id, milestones)?;
+        }
 
-        let mut is_signer = false;
-        for signer in multisig_config.signers.iter() {
-            if signer == approver {
-                is_signer = true;
-                break;
+        if let Some(rate) = template.release_fee_rate {
+            if rate > 0 {
+                escrow_entries::set(&env, bounty_id, Entry::ReleaseFeeRate, &rate);
+            } else {
+                escrow_entries::remove(&env, bounty_id, Entry::ReleaseFeeRate);
             }
         }
-
-        if !is_signer {
-            return Err(Error::Unauthorized);
+        if let Some(approver) = template.approver {
+            escrow_entries::set(&env, bounty_id, Entry::Approver, &approver);
         }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
 
-        approver.require_auth();
+    /// Approve a milestone for payout. Only the escrow depositor can approve.
+    pub fn approve_milestone(env: Env, bounty_id: u64, milestone_index: u32) -> Result<(), Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
 
-        let approval_key = DataKey::ReleaseApproval(bounty_id);
-        let mut approval: ReleaseApproval = env
-            .storage()
-            .persistent()
-            .get(&approval_key)
-            .unwrap_or(ReleaseApproval {
-                bounty_id,
-                contributor: contributor.clone(),
-                approvals: vec![&env],
-            });
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
 
-        for existing in approval.approvals.iter() {
-            if existing == approver {
-                return Ok(());
-            }
+        let mut records: Vec<MilestoneRecord> =
+            escrow_entries::get(&env, bounty_id, Entry::Milestones)
+                .ok_or(Error::MilestoneNotFound)?;
+        let mut record = records
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+        if record.status != MilestoneStatus::Pending {
+            return Err(Error::MilestoneAlreadyReleased);
         }
 
-        approval.approvals.push_back(approver.clone());
-        env.storage().persistent().set(&approval_key, &approval);
+        record.status = MilestoneStatus::Approved;
+        records.set(milestone_index, record.clone());
+        escrow_entries::set(&env, bounty_id, Entry::Milestones, &records);
 
-        events::emit_approval_added(
+        events::emit_milestone_approved(
             &env,
-            events::ApprovalAdded {
+            events::MilestoneApproved {
                 bounty_id,
-                contributor: contributor.clone(),
-                approver,
+                milestone_index,
+                amount: record.amount,
+                approved_by: escrow.depositor,
                 timestamp: env.ledger().timestamp(),
             },
         );
@@ -1491,852 +8400,1508 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Lock funds for a specific bounty.
+    /// Pay out an approved milestone to the contributor (admin only).
     ///
     /// # Reentrancy
-    /// Protected by the shared reentrancy guard. State (escrow record,
-    /// indexes) is written before the inbound token transfer so that
-    /// a malicious token callback cannot re-enter with stale state.
-    pub fn lock_funds(
+    /// Protected by the shared reentrancy guard. Milestone and escrow state
+    /// are updated *before* the outbound token transfer (CEI pattern).
+    pub fn release_milestone(
         env: Env,
-        depositor: Address,
         bounty_id: u64,
-        amount: i128,
-        deadline: u64,
+        milestone_index: u32,
+        contributor: Address,
     ) -> Result<(), Error> {
-        let res =
-            Self::lock_funds_logic(env.clone(), depositor.clone(), bounty_id, amount, deadline);
-        monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
-        res
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+        Self::ensure_no_open_dispute(&env, bounty_id)?;
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        Self::ensure_no_stream(&env, bounty_id)?;
+
+        let mut records: Vec<MilestoneRecord> =
+            escrow_entries::get(&env, bounty_id, Entry::Milestones)
+                .ok_or(Error::MilestoneNotFound)?;
+        let mut record = records
+            .get(milestone_index)
+            .ok_or(Error::MilestoneNotFound)?;
+        match record.status {
+            MilestoneStatus::Pending => return Err(Error::MilestoneNotApproved),
+            MilestoneStatus::Released => return Err(Error::MilestoneAlreadyReleased),
+            MilestoneStatus::Approved => {}
+        }
+        if record.amount > escrow.remaining_amount {
+            return Err(Error::InsufficientFunds);
+        }
+        Self::check_release_approvals(&env, bounty_id, &escrow, &contributor, record.amount)?;
+
+        // EFFECTS: update milestone and escrow state before external call (CEI)
+        record.status = MilestoneStatus::Released;
+        records.set(milestone_index, record.clone());
+        escrow_entries::set(&env, bounty_id, Entry::Milestones, &records);
+
+        escrow.remaining_amount -= record.amount;
+        if escrow.remaining_amount == 0 {
+            state_machine::transition(&mut escrow, StatusEvent::Release)?;
+            escrow_entries::remove(&env, bounty_id, Entry::ReleaseApproval);
+        }
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::pay_release(
+            &env,
+            &client,
+            bounty_id,
+            &admin,
+            &contributor,
+            record.amount,
+        )?;
+
+        events::emit_milestone_released(
+            &env,
+            events::MilestoneReleased {
+                bounty_id,
+                milestone_index,
+                amount: record.amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: record.amount,
+                recipient: contributor,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// View: get the milestones of a bounty with their current status.
+    pub fn get_milestones(env: Env, bounty_id: u64) -> Result<Vec<MilestoneRecord>, Error> {
+        escrow_entries::get(&env, bounty_id, Entry::Milestones).ok_or(Error::MilestoneNotFound)
+    }
+
+    /// Set the arbiter allowed to resolve disputes (admin only).
+    pub fn set_arbiter(env: Env, arbiter: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Arbiter, &arbiter);
+        Ok(())
+    }
+
+    /// View: get the configured arbiter, if any.
+    pub fn get_arbiter(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Arbiter)
+    }
+
+    /// Delegate dispute decisions to an arbitration contract, or `None` to
+    /// return them to the arbiter set with `set_arbiter` (admin only).
+    ///
+    /// While set, each `open_dispute` files a case with the contract and only
+    /// the contract can call `resolve_dispute`. The arbitration contract must
+    /// accept this escrow, otherwise opening disputes fails.
+    pub fn set_arbitration_contract(env: Env, arbitration: Option<Address>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        match arbitration {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&DataKey::ArbitrationContract, &address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::ArbitrationContract),
+        }
+        Ok(())
+    }
+
+    /// View: get the configured arbitration contract, if any.
+    pub fn get_arbitration_contract(env: Env) -> Option<Address> {
+        arbitration::get(&env)
+    }
+
+    /// View: the address allowed to resolve disputes — the arbitration
+    /// contract when configured, otherwise the arbiter.
+    pub fn get_dispute_arbiter(env: Env) -> Option<Address> {
+        arbitration::get(&env).or_else(|| Self::get_arbiter(env))
+    }
+
+    /// Attach a hook contract to `bounty_id`, or `None` to detach it (admin
+    /// only). The hook's `on_release` / `on_refund` run after every payout
+    /// and refund of the escrow; a failing hook reverts the operation.
+    pub fn set_escrow_hook(env: Env, bounty_id: u64, hook: Option<Address>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        match hook {
+            Some(address) => escrow_entries::set(&env, bounty_id, Entry::Hook, &address),
+            None => escrow_entries::remove(&env, bounty_id, Entry::Hook),
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Ok(())
+    }
+
+    /// View: get the hook contract attached to `bounty_id`, if any.
+    pub fn get_escrow_hook(env: Env, bounty_id: u64) -> Option<Address> {
+        hooks::get(&env, bounty_id)
+    }
+
+    /// Set the reputation contract that releases, refunds and lost disputes
+    /// are reported to, or `None` to stop reporting (admin only). The
+    /// reputation contract must accept this escrow as a reporter.
+    pub fn set_reputation_contract(env: Env, reputation: Option<Address>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        match reputation {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&DataKey::ReputationContract, &address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&DataKey::ReputationContract),
+        }
+        Ok(())
+    }
+
+    /// View: get the configured reputation contract, if any.
+    pub fn get_reputation_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::ReputationContract)
+    }
+
+    /// Set the badge contract `release_funds` mints completion badges from,
+    /// or `None` to stop minting (admin only). The badge contract must accept
+    /// this escrow as a minter.
+    pub fn set_badge_contract(env: Env, badge: Option<Address>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        match badge {
+            Some(address) => env
+                .storage()
+                .instance()
+                .set(&DataKey::BadgeContract, &address),
+            None => env.storage().instance().remove(&DataKey::BadgeContract),
+        }
+        Ok(())
+    }
+
+    /// View: get the configured badge contract, if any.
+    pub fn get_badge_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::BadgeContract)
     }
 
-    fn lock_funds_logic(
+    /// Whitelist the lending pool `lock_funds_with_yield` deposits into, or
+    /// `None` to stop new deposits, and choose who receives the interest when
+    /// the depositor does not (admin only). Escrows already invested stay in their pool until paid
+    /// out.
+    pub fn set_yield_strategy(
         env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
+        pool: Option<Address>,
+        beneficiary: YieldBeneficiary,
     ) -> Result<(), Error> {
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
-
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        yield_strategy::configure(&env, pool, beneficiary);
+        Ok(())
+    }
 
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
-        }
+    /// View: the lending pool new yield deposits go to, if any.
+    pub fn get_yield_pool(env: Env) -> Option<Address> {
+        yield_strategy::pool(&env)
+    }
 
-        let _start = env.ledger().timestamp();
-        let _caller = depositor.clone();
+    /// View: who receives interest when the depositor does not choose.
+    pub fn get_yield_beneficiary(env: Env) -> YieldBeneficiary {
+        yield_strategy::beneficiary(&env)
+    }
 
-        // Verify depositor authorization
-        depositor.require_auth();
+    /// View: `bounty_id`'s stake in the yield strategy while invested.
+    pub fn get_yield_position(env: Env, bounty_id: u64) -> Option<YieldPosition> {
+        yield_strategy::position(&env, bounty_id)
+    }
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Ensure a release of `amount` from `escrow` has enough multisig
+    /// approvals for `contributor`. The threshold applies to what the escrow
+    /// has released in total once `amount` is paid, so paying out in smaller
+    /// releases does not get around it; releases that stay below it pass
+    /// through.
+    fn check_release_approvals(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        contributor: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let config = Self::get_multisig_config(env.clone());
+        let refunded: i128 = escrow.refund_history.iter().map(|r| r.amount).sum();
+        let released = escrow.amount - escrow.remaining_amount - refunded;
+        if config.required_signatures == 0
+            || released.saturating_add(amount) < config.threshold_amount
+        {
+            return Ok(());
         }
 
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyExists);
+        let approval: ReleaseApproval = escrow_entries::get(env, bounty_id, Entry::ReleaseApproval)
+            .ok_or(Error::InsufficientApprovals)?;
+        if approval.contributor != *contributor {
+            return Err(Error::InsufficientApprovals);
         }
 
-        // Enforce min/max amount policy if one has been configured (Issue #62).
-        // When no policy is set this block is skipped entirely, preserving
-        // backward-compatible behaviour for callers that never call set_amount_policy.
-        if let Some((min_amount, max_amount)) = env
-            .storage()
-            .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                return Err(Error::AmountBelowMinimum);
-            }
-            if amount > max_amount {
-                return Err(Error::AmountAboveMaximum);
+        // Only count approvers that are still configured signers.
+        let mut valid = 0u32;
+        for approver in approval.approvals.iter() {
+            if config.signers.contains(&approver) {
+                valid += 1;
             }
         }
-
-        // EFFECTS: write escrow state and indexes before the external call
-        let escrow = Escrow {
-            depositor: depositor.clone(),
-            amount,
-            status: EscrowStatus::Locked,
-            deadline,
-            refund_history: vec![&env],
-            remaining_amount: amount,
-        };
-        invariants::assert_escrow(&env, &escrow);
-
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Update indexes
-        let mut index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.push_back(bounty_id);
-        env.storage()
-            .persistent()
-            .set(&DataKey::EscrowIndex, &index);
-
-        let mut depositor_index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::DepositorIndex(depositor.clone()))
-            .unwrap_or(Vec::new(&env));
-        depositor_index.push_back(bounty_id);
-        env.storage().persistent().set(
-            &DataKey::DepositorIndex(depositor.clone()),
-            &depositor_index,
-        );
-
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&depositor, &env.current_contract_address(), &amount);
-
-        // Emit value allows for off-chain indexing
-        emit_funds_locked(
-            &env,
-            FundsLocked {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount,
-                depositor: depositor.clone(),
-                deadline,
-            },
-        );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
+        if valid < config.required_signatures {
+            return Err(Error::InsufficientApprovals);
+        }
         Ok(())
     }
 
-    /// Release funds to the contributor.
-    /// Only the admin (backend) can authorize this.
-    ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. Escrow state is updated
-    /// to `Released` *before* the outbound token transfer (CEI pattern).
-    pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        let res = Self::release_funds_logic(env.clone(), bounty_id, contributor.clone());
-        monitoring::track_operation(&env, symbol_short!("release"), contributor, res.is_ok());
-        res
+    /// Returns `MilestoneEscrow` if the bounty pays out in milestones, which
+    /// only `release_milestone` may release.
+    fn ensure_no_milestones(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if escrow_entries::has(env, bounty_id, Entry::Milestones) {
+            return Err(Error::MilestoneEscrow);
+        }
+        Ok(())
     }
 
-    fn release_funds_logic(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
+    /// Returns `StreamActive` if the bounty is being paid out by a stream.
+    fn ensure_no_stream(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if escrow_entries::has(env, bounty_id, Entry::VestingStream) {
+            return Err(Error::StreamActive);
         }
+        Ok(())
+    }
 
-        // Block direct release while an active dispute (pending claim) exists.
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
+    /// Returns `DisputeOpen` if the bounty has an unresolved dispute.
+    fn ensure_no_open_dispute(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if let Some(dispute) = escrow_entries::get::<Dispute>(env, bounty_id, Entry::Dispute) {
+            if dispute.status == DisputeStatus::Open {
+                return Err(Error::DisputeOpen);
             }
         }
+        Ok(())
+    }
 
-        let _start = env.ledger().timestamp();
-
-        // GUARD: acquire reentrancy lock (replaces inline guard)
-        reentrancy_guard::acquire(&env);
-
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+    /// Returns `FundsPaused` if the bounty is frozen.
+    fn ensure_not_frozen(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if escrow_entries::has(env, bounty_id, Entry::Freeze) {
+            return Err(Error::FundsPaused);
         }
+        Ok(())
+    }
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+    /// Returns `Unauthorized` if `recipient` is on the payout blocklist.
+    fn ensure_not_blocked(env: &Env, recipient: &Address) -> Result<(), Error> {
+        if Self::is_blocked(env.clone(), recipient.clone()) {
+            return Err(Error::Unauthorized);
         }
+        Ok(())
+    }
 
-        let mut escrow: Escrow = env
+    /// Require that `caller` is the admin or the dispute arbiter.
+    fn authorize_freeze(env: &Env, caller: &Address) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if *caller != admin && Self::get_dispute_arbiter(env.clone()).as_ref() != Some(caller) {
+            return Err(Error::Unauthorized);
         }
-
-        // EFFECTS: update state before external call (CEI)
-        let release_amount = escrow.amount;
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
-        invariants::assert_escrow(&env, &escrow);
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &release_amount,
-        );
-
-        emit_funds_released(
-            &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: release_amount,
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
+        caller.require_auth();
         Ok(())
     }
 
-    /// Delegated release flow using a capability instead of admin auth.
-    /// The capability amount limit is consumed by `payout_amount`.
-    pub fn release_with_capability(
+    /// Freeze a single escrow (admin or arbiter only), blocking its release
+    /// and refund with `FundsPaused` until `unfreeze_escrow`. Unlike
+    /// `set_paused` this leaves every other escrow untouched. `reason_hash`
+    /// identifies the off-chain case, e.g. a fraud report. Freezing an
+    /// already frozen escrow replaces the recorded hold.
+    pub fn freeze_escrow(
         env: Env,
+        caller: Address,
         bounty_id: u64,
-        contributor: Address,
-        payout_amount: i128,
-        holder: Address,
-        capability_id: u64,
+        reason_hash: BytesN<32>,
     ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
+        Self::authorize_freeze(&env, &caller)?;
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
-        }
-
-        Self::consume_capability(
-            &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Release,
+        let timestamp = env.ledger().timestamp();
+        let freeze = EscrowFreeze {
             bounty_id,
-            payout_amount,
-        )?;
+            frozen_by: caller.clone(),
+            reason_hash: reason_hash.clone(),
+            frozen_at: timestamp,
+        };
+        escrow_entries::set(&env, bounty_id, Entry::Freeze, &freeze);
+        Self::bump_escrow_ttl(&env, bounty_id, true);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
+        events::emit_escrow_frozen(
+            &env,
+            events::EscrowFrozen {
+                bounty_id,
+                frozen_by: caller,
+                reason_hash,
+                timestamp,
+            },
         );
+        Ok(())
+    }
 
-        escrow.remaining_amount -= payout_amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
+    /// Lift the hold on a frozen escrow (admin or arbiter only). Returns
+    /// `NotPaused` if the escrow is not frozen.
+    pub fn unfreeze_escrow(env: Env, caller: Address, bounty_id: u64) -> Result<(), Error> {
+        Self::authorize_freeze(&env, &caller)?;
+        if !escrow_entries::has(&env, bounty_id, Entry::Freeze) {
+            return Err(Error::NotPaused);
         }
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        escrow_entries::remove(&env, bounty_id, Entry::Freeze);
 
-        emit_funds_released(
+        events::emit_escrow_unfrozen(
             &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
+            events::EscrowUnfrozen {
                 bounty_id,
-                amount: payout_amount,
-                recipient: contributor,
+                unfrozen_by: caller,
                 timestamp: env.ledger().timestamp(),
             },
         );
-
         Ok(())
     }
 
-    /// Set the claim window duration (admin only).
-    /// claim_window: seconds beneficiary has to claim after release is authorized.
-    pub fn set_claim_window(env: Env, claim_window: u64) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-        env.storage()
-            .instance()
-            .set(&DataKey::ClaimWindow, &claim_window);
-        Ok(())
+    /// View: the hold on `bounty_id`, if it is frozen.
+    pub fn get_escrow_freeze(env: Env, bounty_id: u64) -> Option<EscrowFreeze> {
+        escrow_entries::get(&env, bounty_id, Entry::Freeze)
     }
 
-    /// Authorize a release as a pending claim instead of immediate transfer.
-    /// Admin calls this instead of release_funds when claim period is active.
-    /// Beneficiary must call claim() within the window to receive funds.
-    pub fn authorize_claim(
+    /// Open a dispute between the depositor and a contributor.
+    ///
+    /// `contributor` must be the escrow's assignee or the recipient of its
+    /// pending claim, and `caller` either the escrow depositor or
+    /// `contributor`. While the dispute is open, release and refund of the
+    /// escrow are blocked until the arbiter calls `resolve_dispute`.
+    pub fn open_dispute(
         env: Env,
+        caller: Address,
         bounty_id: u64,
-        recipient: Address,
-        reason: DisputeReason,
+        contributor: Address,
+        reason_hash: BytesN<32>,
     ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if !Self::is_escrow_contributor(&env, bounty_id, &contributor) {
+            return Err(Error::Unauthorized);
         }
+        if caller != escrow.depositor && caller != contributor {
+            return Err(Error::Unauthorized);
+        }
+        caller.require_auth();
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_no_open_dispute(&env, bounty_id)?;
+        Self::ensure_no_stream(&env, bounty_id)?;
 
         let now = env.ledger().timestamp();
-        let claim_window: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ClaimWindow)
-            .unwrap_or(0);
-        let claim = ClaimRecord {
+        let dispute = Dispute {
             bounty_id,
-            recipient: recipient.clone(),
-            amount: escrow.amount,
-            expires_at: now.saturating_add(claim_window),
-            claimed: false,
-            reason: reason.clone(),
+            depositor: escrow.depositor,
+            contributor,
+            raised_by: caller.clone(),
+            reason_hash: reason_hash.clone(),
+            status: DisputeStatus::Open,
+            opened_at: now,
+            contributor_share_bps: 0,
+            resolved_at: 0,
         };
+        escrow_entries::set(&env, bounty_id, Entry::Dispute, &dispute);
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
-
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("created")),
-            ClaimCreated {
+        events::emit_dispute_opened(
+            &env,
+            events::DisputeOpened {
                 bounty_id,
-                recipient,
-                amount: escrow.amount,
-                expires_at: claim.expires_at,
-                reason,
+                raised_by: caller,
+                reason_hash,
+                timestamp: now,
             },
         );
+
+        // INTERACTION: file the case with the arbitration contract, if any
+        arbitration::open_case(&env, &dispute);
+
         Ok(())
     }
 
-    /// Beneficiary calls this to claim their authorized funds within the window.
+    /// Resolve an open dispute (arbiter or arbitration contract only).
+    ///
+    /// The escrow's remaining funds are split: `contributor_share_bps` basis
+    /// points go to the disputed contributor and the rest is refunded: each
+    /// funder gets their pro-rata part and the depositor the remainder. The
+    /// escrow ends `Released` if the contributor received anything,
+    /// otherwise `Refunded`.
     ///
     /// # Reentrancy
-    /// Protected by the shared reentrancy guard. Escrow and claim state
-    /// are updated *before* the outbound token transfer (CEI pattern).
-    pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-
+    /// Protected by the shared reentrancy guard. Dispute and escrow state
+    /// are updated *before* the outbound token transfers (CEI pattern).
+    pub fn resolve_dispute(
+        env: Env,
+        bounty_id: u64,
+        contributor_share_bps: u32,
+    ) -> Result<(), Error> {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
+        // A resolution both releases and refunds.
+        if Self::check_paused(&env, symbol_short!("release"))
+            || Self::check_paused(&env, symbol_short!("refund"))
         {
-            return Err(Error::BountyNotFound);
+            return Err(Error::FundsPaused);
         }
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
 
-        claim.recipient.require_auth();
+        let arbiter = Self::get_dispute_arbiter(env.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, DisputeError::ArbiterNotSet));
+        arbiter.require_auth();
 
-        let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed); // reuse or add ClaimExpired error
+        if contributor_share_bps as i128 > token_math::BASIS_POINTS {
+            return Err(Error::InvalidSplit);
         }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
+
+        let mut dispute: Dispute = escrow_entries::get(&env, bounty_id, Entry::Dispute)
+            .unwrap_or_else(|| panic_with_error!(&env, DisputeError::DisputeNotFound));
+        if dispute.status != DisputeStatus::Open {
+            panic_with_error!(&env, DisputeError::DisputeNotFound);
         }
+        Self::ensure_not_frozen(&env, bounty_id)?;
 
-        // EFFECTS: update escrow and claim state before external call (CEI)
-        let claim_amount = claim.amount;
-        let claim_recipient = claim.recipient.clone();
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        let (contributor_amount, depositor_amount) =
+            token_math::split_amount(escrow.remaining_amount, contributor_share_bps as i128);
+        // Any payout to the contributor releases the escrow, which the table
+        // doesn't allow once it was partially refunded.
+        let event = if contributor_amount > 0 {
+            StatusEvent::Release
+        } else {
+            StatusEvent::Refund
+        };
+        state_machine::ensure_allowed(&escrow, event)?;
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        // EFFECTS: update dispute and escrow state before external calls (CEI)
+        let now = env.ledger().timestamp();
 
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        dispute.status = DisputeStatus::Resolved;
+        dispute.contributor_share_bps = contributor_share_bps;
+        dispute.resolved_at = now;
+        escrow_entries::set(&env, bounty_id, Entry::Dispute, &dispute);
 
-        // INTERACTION: external token transfer is last
+        // The depositor's share goes back to the funders pro rata, like any
+        // other refund; nothing is left for them to reclaim afterwards.
+        let refund_to = Self::refund_destination(&env, bounty_id, &escrow);
+        let payouts = Self::refund_payouts(&env, bounty_id, &escrow, depositor_amount, &refund_to)?;
+        escrow.remaining_amount = 0;
+        for (payee, amount) in payouts.iter() {
+            escrow.refund_history.push_back(RefundRecord {
+                amount,
+                recipient: payee,
+                timestamp: now,
+                mode: RefundMode::Partial,
+            });
+        }
+        state_machine::transition(&mut escrow, event)?;
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+        funders::clear_shares(&env, bounty_id);
+        if depositor_amount > 0 {
+            Self::record_action(
+                &env,
+                bounty_id,
+                &arbiter,
+                EscrowAction::Refunded,
+                depositor_amount,
+            );
+        }
+        let contributor_lost = (contributor_share_bps as i128) * 2 < token_math::BASIS_POINTS;
+        let slashed = match bonds::get(&env, bounty_id) {
+            Some(bond) if contributor_lost && bond.contributor == dispute.contributor => {
+                bonds::take(&env, bounty_id)
+            }
+            _ => None,
+        };
+
+        // INTERACTION: external token transfers are last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim_recipient,
-            &claim_amount,
-        );
+        if contributor_amount > 0 {
+            Self::pay_release(
+                &env,
+                &client,
+                bounty_id,
+                &arbiter,
+                &dispute.contributor,
+                contributor_amount,
+            )?;
+        }
+        for (payee, amount) in payouts.iter() {
+            client.transfer(&env.current_contract_address(), &payee, &amount);
+            Self::notify_refund(&env, bounty_id, &payee, amount);
+        }
+        if let Some(bond) = slashed {
+            let recipient = if Self::get_slash_bonds_to_treasury(env.clone()) {
+                Self::get_fee_config_internal(&env).fee_recipient
+            } else {
+                dispute.depositor.clone()
+            };
+            client.transfer(&env.current_contract_address(), &recipient, &bond.amount);
+            events::emit_bond_slashed(
+                &env,
+                events::BondSlashed {
+                    bounty_id,
+                    contributor: bond.contributor,
+                    amount: bond.amount,
+                    recipient,
+                    timestamp: now,
+                },
+            );
+        }
+        if contributor_lost {
+            reputation::report_dispute_lost(&env, bounty_id, &dispute.contributor);
+        } else if (contributor_share_bps as i128) * 2 > token_math::BASIS_POINTS {
+            reputation::report_dispute_lost(&env, bounty_id, &dispute.depositor);
+        }
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
+        events::emit_dispute_resolved(
+            &env,
+            events::DisputeResolved {
                 bounty_id,
-                recipient: claim_recipient,
-                amount: claim_amount,
-                claimed_at: now,
-                outcome: DisputeOutcome::ResolvedByPayout,
+                arbiter,
+                contributor_amount,
+                depositor_amount,
+                contributor_share_bps,
+                timestamp: now,
             },
         );
 
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Delegated claim execution using a capability.
-    /// Funds are still transferred to the pending claim recipient.
-    pub fn claim_with_capability(
-        env: Env,
-        bounty_id: u64,
-        holder: Address,
-        capability_id: u64,
-    ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
-        }
+    /// View: get the dispute record for a bounty.
+    pub fn get_dispute(env: Env, bounty_id: u64) -> Result<Dispute, DisputeError> {
+        escrow_entries::get(&env, bounty_id, Entry::Dispute).ok_or(DisputeError::DisputeNotFound)
+    }
 
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
+    /// Refund funds to the original depositor if the deadline has passed.
+    /// Refunds the full remaining_amount (accounts for any prior partial releases).
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow state, refund
+    /// history, and approval cleanup are performed *before* the outbound
+    /// token transfer (CEI pattern).
+    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let res = Self::refund_logic(env.clone(), bounty_id, false);
+        monitoring::track_operation(
+            &env,
+            symbol_short!("refund"),
+            env.current_contract_address(),
+            res.is_ok(),
+        );
+        res
+    }
+
+    /// Return the full remaining balance of an expired escrow to its depositor.
+    ///
+    /// Callable by anyone once the deadline has passed and the escrow is still
+    /// `Locked`; funds always go to the depositor. Any pending refund approval
+    /// is ignored and cleared, so no admin step is needed.
+    pub fn claim_expired_refund(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let res = Self::refund_logic(env.clone(), bounty_id, true);
+        monitoring::track_operation(
+            &env,
+            symbol_short!("refund"),
+            env.current_contract_address(),
+            res.is_ok(),
+        );
+        res
+    }
+
+    /// Emit an `EscrowExpiring` event for up to `max_count` funded escrows
+    /// whose deadline is within `EXPIRY_NOTICE_WINDOW` (or already passed),
+    /// so bots and UIs can remind depositors. Callable by anyone; each
+    /// deadline is announced once, and again only if it is extended. Returns
+    /// the number of events emitted.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `max_count` is 0 or above MAX_BATCH_SIZE
+    pub fn ping_expiring(env: Env, max_count: u32) -> Result<u32, Error> {
+        if max_count == 0 || max_count > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
 
         let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed);
+        let notice_until = now.saturating_add(EXPIRY_NOTICE_WINDOW);
+        let mut pinged = 0;
+        for bucket in deadline_index::buckets(&env).iter() {
+            if bucket > deadline_index::bucket_of(notice_until) {
+                break;
+            }
+            for bounty_id in deadline_index::ids(&env, bucket).iter() {
+                if pinged >= max_count {
+                    return Ok(pinged);
+                }
+                let escrow: Escrow = match Self::load_escrow(&env, bounty_id) {
+                    Some(escrow) => escrow,
+                    None => continue,
+                };
+                if escrow.deadline > notice_until
+                    || escrow_entries::get(&env, bounty_id, Entry::ExpiryNotified)
+                        == Some(escrow.deadline)
+                {
+                    continue;
+                }
+
+                escrow_entries::set(&env, bounty_id, Entry::ExpiryNotified, &escrow.deadline);
+                events::emit_escrow_expiring(
+                    &env,
+                    events::EscrowExpiring {
+                        bounty_id,
+                        depositor: escrow.depositor,
+                        remaining_amount: escrow.remaining_amount,
+                        deadline: escrow.deadline,
+                        timestamp: now,
+                    },
+                );
+                pinged += 1;
+            }
         }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
+        Ok(pinged)
+    }
+
+    /// Cancel an escrow and return the full amount to the depositor.
+    ///
+    /// Only the depositor can cancel, and only while the escrow is `Locked`,
+    /// nothing has been paid out, and no contributor has been assigned
+    /// (pending claim, release approvals, a dispute, or an assignment that
+    /// was accepted or can still be). The escrow ends in the `Cancelled`
+    /// status.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The escrow record is updated
+    /// before the outbound token transfer (CEI pattern).
+    pub fn cancel_escrow(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
         }
 
-        Self::consume_capability(
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        state_machine::ensure_allowed(&escrow, StatusEvent::Cancel)?;
+        escrow.depositor.require_auth();
+        Self::ensure_not_frozen(&env, bounty_id)?;
+
+        if !Self::is_unclaimed(&env, bounty_id, &escrow) {
+            panic_with_error!(&env, CancellationError::CancellationNotAllowed);
+        }
+
+        // EFFECTS: update state before external call (CEI)
+        let amount = escrow.remaining_amount;
+        let now = env.ledger().timestamp();
+        let refund_to = Self::refund_destination(&env, bounty_id, &escrow);
+        let payouts = Self::refund_payouts(&env, bounty_id, &escrow, amount, &refund_to)?;
+        escrow.remaining_amount = 0;
+        state_machine::transition(&mut escrow, StatusEvent::Cancel)?;
+        for (payee, amount) in payouts.iter() {
+            escrow.refund_history.push_back(RefundRecord {
+                amount,
+                recipient: payee,
+                timestamp: now,
+                mode: RefundMode::Full,
+            });
+        }
+        invariants::assert_escrow(&env, &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+        escrow_entries::remove(&env, bounty_id, Entry::RefundApproval);
+        Self::record_action(
             &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Claim,
             bounty_id,
-            claim.amount,
-        )?;
+            &escrow.depositor,
+            EscrowAction::Cancelled,
+            amount,
+        );
 
+        // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
+        for (payee, amount) in payouts.iter() {
+            client.transfer(&env.current_contract_address(), &payee, &amount);
+            Self::notify_refund(&env, bounty_id, &payee, amount);
+        }
+
+        events::emit_escrow_cancelled(
+            &env,
+            events::EscrowCancelled {
+                bounty_id,
+                depositor: escrow.depositor.clone(),
+                amount,
+                timestamp: now,
+            },
         );
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
 
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
-                bounty_id,
-                recipient: claim.recipient,
-                amount: claim.amount,
-                claimed_at: now,
-                outcome: DisputeOutcome::ResolvedByPayout,
+    /// `true` while nothing of `bounty_id` has been paid out and no
+    /// contributor is attached to it: no pending claim, release approvals,
+    /// dispute, stream, or an assignment that was accepted or can still be.
+    fn is_unclaimed(env: &Env, bounty_id: u64, escrow: &Escrow) -> bool {
+        escrow.remaining_amount == escrow.amount
+            && !escrow_entries::has(env, bounty_id, Entry::PendingClaim)
+            && !escrow_entries::has(env, bounty_id, Entry::ReleaseApproval)
+            && !escrow_entries::has(env, bounty_id, Entry::Dispute)
+            && !escrow_entries::has(env, bounty_id, Entry::VestingStream)
+            && Self::active_assignment(env, bounty_id).is_none()
+    }
+
+    /// Drop the `Locked` escrow `bounty_id` from the stats and the escrow,
+    /// status and deadline indexes, archive it and remove its entries, once
+    /// its funds have moved elsewhere without a payout or refund.
+    fn retire_escrow(env: &Env, bounty_id: u64, escrow: &Escrow) {
+        let mut stats = Self::load_stats(env);
+        stats.total_value_locked -= escrow.remaining_amount;
+        let count = stats.count_mut(&EscrowStatus::Locked);
+        *count = count.saturating_sub(1);
+        Self::save_stats(env, &stats);
+        deadline_index::remove(env, bounty_id, escrow.deadline);
+        let now = env.ledger().timestamp();
+        archive::set(
+            env,
+            bounty_id,
+            &ArchivedEscrow {
+                depositor: escrow.depositor.clone(),
+                amount: escrow.amount,
+                status: EscrowStatus::Locked,
+                settled_at: now,
+                archived_at: now,
+            },
+        );
+        Self::remove_escrow_entries(env, bounty_id, escrow);
+    }
+
+    /// Move the funds of `old_bounty_id` to `new_bounty_id` without a refund
+    /// and a second lock, e.g. when the issue was superseded or closed as a
+    /// duplicate (depositor only). Allowed under the same conditions as
+    /// `cancel_escrow`, and not while the funds are invested. Settings and
+    /// history move with the escrow; refund approvals and redeemed voucher
+    /// nonces are dropped. The old id is archived and can't be locked again.
+    pub fn reassign_escrow(env: Env, old_bounty_id: u64, new_bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let escrow: Escrow = Self::load_escrow(&env, old_bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::ensure_not_frozen(&env, old_bounty_id)?;
+        if !Self::is_unclaimed(&env, old_bounty_id, &escrow)
+            || yield_strategy::position(&env, old_bounty_id).is_some()
+        {
+            panic_with_error!(&env, ReassignmentError::ReassignmentNotAllowed);
+        }
+        let persistent = env.storage().persistent();
+        if new_bounty_id == old_bounty_id
+            || persistent.has(&DataKey::Escrow(new_bounty_id))
+            || archive::contains(&env, new_bounty_id)
+        {
+            return Err(Error::BountyExists);
+        }
+
+        for entry in [
+            Entry::ReleaseFeeRate,
+            Entry::Metadata,
+            Entry::Milestones,
+            Entry::Hook,
+            Entry::RefundDestination,
+            Entry::SubmissionRequired,
+            Entry::ReviewPeriod,
+            Entry::Approver,
+            Entry::VoucherSigner,
+        ] {
+            escrow_entries::transfer(&env, old_bounty_id, new_bounty_id, entry);
+        }
+        kyc::set(&env, new_bounty_id, kyc::get(&env, old_bounty_id));
+        issue_links::transfer(&env, old_bounty_id, new_bounty_id);
+        ledger_deadlines::transfer(&env, old_bounty_id, new_bounty_id);
+        payout_caps::transfer(&env, old_bounty_id, new_bounty_id);
+        funders::transfer(&env, old_bounty_id, new_bounty_id);
+        insurance::transfer(&env, old_bounty_id, new_bounty_id);
+        referrals::transfer(&env, old_bounty_id, new_bounty_id);
+
+        Self::save_escrow(&env, new_bounty_id, &escrow);
+        Self::set_history(&env, new_bounty_id, &Self::history(&env, old_bounty_id));
+
+        // The new record was just added to the stats and indexes; the old
+        // one leaves them.
+        Self::retire_escrow(&env, old_bounty_id, &escrow);
+        let now = env.ledger().timestamp();
+
+        events::emit_escrow_reassigned(
+            &env,
+            events::EscrowReassigned {
+                old_bounty_id,
+                new_bounty_id,
+                depositor: escrow.depositor,
+                amount: escrow.remaining_amount,
+                timestamp: now,
             },
         );
         Ok(())
     }
 
-    /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
-    pub fn cancel_pending_claim(
+    /// Trust `peer`, another escrow instance, to send escrows to and
+    /// receive escrows from this one, or stop trusting it (admin only). A
+    /// trusted peer can take over any escrow's funds, so this goes through
+    /// the timelock or council like other sensitive operations. See the
+    /// `migration` module.
+    pub fn set_migration_peer(env: Env, peer: Address, trusted: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Self::ensure_no_council(&env)?;
+        Self::ensure_no_timelock(&env)?;
+        migration::set_peer(&env, &peer, trusted);
+        Ok(())
+    }
+
+    /// View: whether escrows may be migrated to and from `peer`.
+    pub fn is_migration_peer(env: Env, peer: Address) -> bool {
+        migration::is_peer(&env, &peer)
+    }
+
+    /// Move the `Locked` escrow `bounty_id` to the trusted instance `target`,
+    /// where it continues as `target_bounty_id` (depositor only). The
+    /// remaining funds are transferred along; here the old id is archived
+    /// and can't be locked again.
+    ///
+    /// # Errors
+    /// * Unauthorized - if `target` is not a migration peer
+    /// * MigrationNotAllowed - if the escrow has a bond, insurance, invested
+    ///   funds, milestones, an issue link (attested for this instance only),
+    ///   or an open claim, approval, dispute or stream
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. The escrow is retired
+    /// before the token transfer and the call into `target` (CEI pattern).
+    pub fn migrate_escrow(
         env: Env,
         bounty_id: u64,
-        outcome: DisputeOutcome,
+        target: Address,
+        target_bounty_id: u64,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
         }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
 
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
-        }
-        let claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
 
-        if claim.claimed {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        if !migration::is_peer(&env, &target) {
+            return Err(Error::Unauthorized);
+        }
+        if escrow_entries::has(&env, bounty_id, Entry::PendingClaim)
+            || escrow_entries::has(&env, bounty_id, Entry::ReleaseApproval)
+            || escrow_entries::has(&env, bounty_id, Entry::Dispute)
+            || escrow_entries::has(&env, bounty_id, Entry::VestingStream)
+            || escrow_entries::has(&env, bounty_id, Entry::Milestones)
+            || yield_strategy::position(&env, bounty_id).is_some()
+            || bonds::get(&env, bounty_id).is_some()
+            || insurance::policy(&env, bounty_id).is_some()
+            || issue_links::get(&env, bounty_id).is_some()
+            || payout_caps::get(&env, bounty_id).is_some()
+            || ledger_deadlines::get(&env, bounty_id).is_some()
+        {
+            panic_with_error!(&env, migration::MigrationError::MigrationNotAllowed);
+        }
 
-        env.storage()
-            .persistent()
-            .remove(&DataKey::PendingClaim(bounty_id));
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let migration = EscrowMigration {
+            token: token_addr.clone(),
+            source_bounty_id: bounty_id,
+            escrow: escrow.clone(),
+            history: Self::history(&env, bounty_id),
+            funders: funders::shares(&env, bounty_id),
+        };
+        let assignment = Self::get_assignment(env.clone(), bounty_id);
+        let funding_goal = funders::goal(&env, bounty_id);
+        let referral = referrals::get(&env, bounty_id);
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("cancel")),
-            ClaimCancelled {
+        // EFFECTS: retire the escrow here before external calls (CEI)
+        Self::retire_escrow(&env, bounty_id, &escrow);
+
+        // INTERACTION: hand the record to the target, which pulls the funds
+        migration::authorize_transfer(&env, &token_addr, &target, escrow.remaining_amount);
+        BountyEscrowContractClient::new(&env, &target).receive_migrated_escrow(
+            &env.current_contract_address(),
+            &target_bounty_id,
+            &migration,
+            &assignment,
+            &funding_goal,
+            &referral,
+        );
+
+        events::emit_escrow_migrated_out(
+            &env,
+            events::EscrowMigratedOut {
                 bounty_id,
-                recipient: claim.recipient,
-                amount: claim.amount,
-                cancelled_at: env.ledger().timestamp(),
-                cancelled_by: admin,
-                outcome,
+                target,
+                target_bounty_id,
+                depositor: escrow.depositor,
+                amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
             },
         );
-        Ok(())
-    }
 
-    /// View: get pending claim for a bounty.
-    pub fn get_pending_claim(env: Env, bounty_id: u64) -> Result<ClaimRecord, Error> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .ok_or(Error::BountyNotFound)
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
     }
 
-    /// Approve a refund before deadline (admin only).
-    /// This allows early refunds with admin approval.
-    pub fn approve_refund(
+    /// Take over an escrow sent by the migration peer `source` with
+    /// `migrate_escrow`, as `bounty_id`, along with its assignment, funding
+    /// goal and referral if it had them. Only callable by `source` itself,
+    /// which authorizes the escrow's remaining funds to be pulled from it.
+    ///
+    /// # Errors
+    /// * Unauthorized - if `source` is not a migration peer
+    /// * InvalidAssetId - if the escrow is held in another token
+    /// * BountyExists - if `bounty_id` is in use or archived here
+    /// * MigratedEscrowNotLocked - if the escrow is not `Locked` or empty
+    /// * MigratedFundsMissing - if less than its remaining amount arrived
+    /// * TooManyFunders - if it has more funders than allowed here
+    pub fn receive_migrated_escrow(
         env: Env,
+        source: Address,
         bounty_id: u64,
-        amount: i128,
-        recipient: Address,
-        mode: RefundMode,
+        migration: EscrowMigration,
+        assignment: Option<Assignment>,
+        funding_goal: Option<FundingGoal>,
+        referral: Option<Referral>,
     ) -> Result<(), Error> {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            return Err(Error::FundsPaused);
         }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        source.require_auth();
+        if !migration::is_peer(&env, &source) {
+            return Err(Error::Unauthorized);
         }
-
-        let escrow: Escrow = env
+        let token_addr: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        if migration.token != token_addr {
+            return Err(Error::InvalidAssetId);
+        }
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id))
+            || archive::contains(&env, bounty_id)
         {
-            return Err(Error::FundsNotLocked);
+            return Err(Error::BountyExists);
         }
 
-        if amount <= 0 || amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let escrow = migration.escrow;
+        if escrow.status != EscrowStatus::Locked || escrow.remaining_amount <= 0 {
+            panic_with_error!(&env, migration::MigrationError::MigratedEscrowNotLocked);
         }
 
-        let approval = RefundApproval {
-            bounty_id,
-            amount,
-            recipient: recipient.clone(),
-            mode: mode.clone(),
-            approved_by: admin.clone(),
-            approved_at: env.ledger().timestamp(),
-        };
+        // INTERACTION: pull the funds first and check all of them arrived,
+        // as the record below must match what this instance holds
+        let client = token::Client::new(&env, &token_addr);
+        let contract = env.current_contract_address();
+        let before = client.balance(&contract);
+        client.transfer(&source, &contract, &escrow.remaining_amount);
+        if client.balance(&contract) - before != escrow.remaining_amount {
+            panic_with_error!(&env, migration::MigrationError::MigratedFundsMissing);
+        }
+
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::snapshot_release_fee(&env, bounty_id);
+        Self::set_history(&env, bounty_id, &migration.history);
+        if let Some(assignment) = assignment {
+            escrow_entries::set(&env, bounty_id, Entry::Assignment, &assignment);
+        }
+        for (funder, amount) in migration.funders.iter() {
+            if !funders::add(&env, bounty_id, &funder, amount) {
+                panic_with_error!(&env, funders::FundingError::TooManyFunders);
+            }
+        }
+        if let Some(goal) = funding_goal {
+            funders::set_goal(&env, bounty_id, &goal);
+        }
+        if let Some(referral) = referral {
+            referrals::set(&env, bounty_id, &referral);
+        }
+        Self::bump_escrow_ttl(&env, bounty_id, true);
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::RefundApproval(bounty_id), &approval);
+        events::emit_escrow_migrated_in(
+            &env,
+            events::EscrowMigratedIn {
+                bounty_id,
+                source,
+                source_bounty_id: migration.source_bounty_id,
+                depositor: escrow.depositor,
+                amount: escrow.remaining_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Release a partial amount of the locked funds to the contributor.
-    /// Only the admin (backend) can authorize this.
-    ///
-    /// - `payout_amount` must be > 0 and <= `remaining_amount`.
-    /// - `remaining_amount` is decremented by `payout_amount` after each call.
-    /// - When `remaining_amount` reaches 0 the escrow status is set to Released.
-    /// - The bounty stays Locked while any funds remain unreleased.
-    ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. Escrow state is updated
-    /// *before* the outbound token transfer (CEI pattern).
-    pub fn partial_release(
-        env: Env,
-        bounty_id: u64,
-        contributor: Address,
-        payout_amount: i128,
-    ) -> Result<(), Error> {
+    fn refund_logic(env: Env, bounty_id: u64, expired_only: bool) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
+        Self::refund_escrow(&env, bounty_id, expired_only)?;
 
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Refund `bounty_id` (see `refund`) and return the refunded amount.
+    /// With `expired_only`, pending approvals are ignored and only a full
+    /// refund of an expired `Locked` escrow is allowed. Pause checks and the
+    /// reentrancy guard are the caller's responsibility. No state is changed
+    /// on error.
+    fn refund_escrow(env: &Env, bounty_id: u64, expired_only: bool) -> Result<i128, Error> {
+        let (mut escrow, entries) =
+            Self::load_escrow_record(env, bounty_id).ok_or(Error::BountyNotFound)?;
+
+        state_machine::ensure_allowed(&escrow, StatusEvent::Refund)?;
+        if expired_only && escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        // Block refund if there is a pending claim (Issue #391 fix)
+        if let Some(claim) =
+            escrow_entries::get_in::<ClaimRecord>(env, bounty_id, &entries, Entry::PendingClaim)
+        {
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
+            }
+        }
+        if entries.has(Entry::Dispute) {
+            Self::ensure_no_open_dispute(env, bounty_id)?;
+        }
+        if entries.has(Entry::Freeze) {
+            Self::ensure_not_frozen(env, bounty_id)?;
+        }
+        if entries.has(Entry::VestingStream) {
+            Self::ensure_no_stream(env, bounty_id)?;
+        }
 
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
+        let now = env.ledger().timestamp();
+        let has_approval = entries.has(Entry::RefundApproval);
+        let approval: Option<RefundApproval> = if expired_only || !has_approval {
+            None
+        } else {
+            Self::active_refund_approval(env, bounty_id)
+        };
+
+        // Refund is allowed if:
+        // 1. Deadline plus grace period has passed (returns full amount to depositor)
+        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
+        if !Self::refund_unlocked(env, bounty_id, &escrow) && approval.is_none() {
+            return Err(Error::DeadlineNotPassed);
         }
 
-        // Guard: zero or negative payout makes no sense and would corrupt state
-        if payout_amount <= 0 {
+        let (refund_amount, refund_to, is_full, split) = if let Some(app) = approval.clone() {
+            let split = match app.mode.clone() {
+                RefundMode::Split(contributor, share) => {
+                    Some((app.approved_by, contributor, share))
+                }
+                _ => None,
+            };
+            let settled = app.amount + split.as_ref().map_or(0, |(_, _, share)| *share);
+            let full = app.mode == RefundMode::Full || settled >= escrow.remaining_amount;
+            (app.amount, app.recipient, full, split)
+        } else {
+            // Standard refund after deadline
+            (
+                escrow.remaining_amount,
+                Self::refund_destination(env, bounty_id, &escrow),
+                true,
+                None,
+            )
+        };
+        let contributor_amount = split.as_ref().map_or(0, |(_, _, share)| *share);
+        if let Some((_, contributor, _)) = &split {
+            Self::ensure_not_blocked(env, contributor)?;
+        }
+
+        if refund_amount <= 0 {
             return Err(Error::InvalidAmount);
         }
+        if refund_amount + contributor_amount > escrow.remaining_amount {
+            return Err(Error::AmountExceedsRemaining);
+        }
+        // Approved refunds go where the approval says; deadline refunds are
+        // shared between the funders.
+        let payouts = if approval.is_some() {
+            Self::ensure_not_blocked(env, &refund_to)?;
+            Vec::from_array(env, [(refund_to.clone(), refund_amount)])
+        } else {
+            Self::refund_payouts(env, bounty_id, &escrow, refund_amount, &refund_to)?
+        };
 
-        // Guard: prevent overpayment — payout cannot exceed what is still owed
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
+        // EFFECTS: update state before external call (CEI)
+        invariants::assert_escrow(env, &escrow);
+        escrow.remaining_amount -= refund_amount + contributor_amount;
+        let event = if is_full || escrow.remaining_amount == 0 {
+            StatusEvent::Refund
+        } else {
+            StatusEvent::PartialRefund
+        };
+        state_machine::transition(&mut escrow, event)?;
+
+        // Add to refund history
+        let mode = match split.as_ref() {
+            Some((_, contributor, share)) => RefundMode::Split(contributor.clone(), *share),
+            None if is_full => RefundMode::Full,
+            None => RefundMode::Partial,
+        };
+        for (payee, amount) in payouts.iter() {
+            escrow.refund_history.push_back(RefundRecord {
+                amount,
+                recipient: payee,
+                timestamp: now,
+                mode: mode.clone(),
+            });
         }
 
-        // EFFECTS: update escrow state before external call (CEI)
-        escrow.remaining_amount -= payout_amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
+        // Save updated escrow
+        Self::save_escrow(env, bounty_id, &escrow);
+        Self::settle_yield(env, bounty_id, &escrow);
+        for (payee, amount) in payouts.iter() {
+            Self::record_action(env, bounty_id, &payee, EscrowAction::Refunded, amount);
+        }
+
+        // Remove approval after successful execution
+        if has_approval {
+            escrow_entries::remove(env, bounty_id, Entry::RefundApproval);
         }
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
 
         // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &contributor,
-            &payout_amount,
-        );
+        let client = token::Client::new(env, &token_addr);
+        for (payee, amount) in payouts.iter() {
+            client.transfer(&env.current_contract_address(), &payee, &amount);
+            Self::notify_refund(env, bounty_id, &payee, amount);
+        }
+        if let Some((approver, contributor, share)) = split {
+            Self::pay_release(env, &client, bounty_id, &approver, &contributor, share)?;
+            emit_funds_released(
+                env,
+                FundsReleased {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount: share,
+                    recipient: contributor,
+                    timestamp: now,
+                },
+            );
+        }
 
-        events::emit_funds_released(
-            &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: payout_amount,
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
-        );
+        for (payee, amount) in payouts.iter() {
+            emit_funds_refunded(
+                env,
+                FundsRefunded {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    amount,
+                    refund_to: payee,
+                    timestamp: now,
+                },
+            );
+        }
 
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
+        Ok(refund_amount)
+    }
+
+    /// Set the maximum number of seconds a single `extend_deadline` call may
+    /// push a deadline forward (admin only).
+    pub fn set_max_deadline_extension(env: Env, max_extension: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxDeadlineExtension, &max_extension);
         Ok(())
     }
 
-    /// Refund funds to the original depositor if the deadline has passed.
-    /// Refunds the full remaining_amount (accounts for any prior partial releases).
-    ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. Escrow state, refund
-    /// history, and approval cleanup are performed *before* the outbound
-    /// token transfer (CEI pattern).
-    pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
-        let res = Self::refund_logic(env.clone(), bounty_id);
-        monitoring::track_operation(
-            &env,
-            symbol_short!("refund"),
-            env.current_contract_address(),
-            res.is_ok(),
-        );
-        res
+    /// View: get the maximum deadline extension. Unlimited when unset.
+    pub fn get_max_deadline_extension(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxDeadlineExtension)
+            .unwrap_or(u64::MAX)
     }
 
-    fn refund_logic(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
-        }
-
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
+    /// Set how many seconds past its deadline an escrow stays locked before
+    /// it can be refunded without an approval (admin only). Gives
+    /// contributors a window to finish submission review.
+    pub fn set_refund_grace_period(env: Env, grace_period: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundGracePeriod, &grace_period);
+        Ok(())
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+    /// View: get the refund grace period. Zero when unset.
+    pub fn get_refund_grace_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundGracePeriod)
+            .unwrap_or(0)
+    }
 
-        let mut escrow: Escrow = env
+    /// Set how many seconds an `approve_refund` approval stays executable
+    /// (admin only). Once it lapses the refund has to be approved again.
+    /// Zero, the default, keeps approvals valid until used.
+    pub fn set_refund_approval_window(env: Env, window: u64) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&ConfigKey::RefundApprovalWindow, &window);
+        Ok(())
+    }
 
-        // Block refund if there is a pending claim (Issue #391 fix)
-        if env
+    /// Designate the platform contract (e.g. the bounty registry) that may
+    /// call `lock_funds_as_operator` / `release_funds_as_operator` on behalf
+    /// of users, or `None` to remove it (admin only).
+    pub fn set_operator_contract(env: Env, operator: Option<Address>) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        match operator {
+            Some(address) => env
                 .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
+                .instance()
+                .set(&ConfigKey::OperatorContract, &address),
+            None => env
+                .storage()
+                .instance()
+                .remove(&ConfigKey::OperatorContract),
         }
+        Ok(())
+    }
 
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+    /// View: the operator contract, if one is designated.
+    pub fn get_operator_contract(env: Env) -> Option<Address> {
+        env.storage().instance().get(&ConfigKey::OperatorContract)
+    }
 
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
+    /// View: get the refund approval window. Zero (no expiry) when unset.
+    pub fn get_refund_approval_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&ConfigKey::RefundApprovalWindow)
+            .unwrap_or(0)
+    }
+
+    /// The refund approval for `bounty_id`, unless it has lapsed.
+    fn active_refund_approval(env: &Env, bounty_id: u64) -> Option<RefundApproval> {
+        let approval: RefundApproval = escrow_entries::get(env, bounty_id, Entry::RefundApproval)?;
+        let window = Self::get_refund_approval_window(env.clone());
+        if window > 0 && env.ledger().timestamp() > approval.approved_at.saturating_add(window) {
+            return None;
         }
+        Some(approval)
+    }
 
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
-        };
+    /// Timestamp from which `escrow` can be refunded without an approval.
+    fn refund_unlocks_at(env: &Env, escrow: &Escrow) -> u64 {
+        escrow
+            .deadline
+            .saturating_add(Self::get_refund_grace_period(env.clone()))
+    }
 
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
+    /// Whether the deadline and grace period of `bounty_id` have passed, by
+    /// ledger sequence for escrows locked with a ledger deadline.
+    fn refund_unlocked(env: &Env, bounty_id: u64, escrow: &Escrow) -> bool {
+        let grace = Self::get_refund_grace_period(env.clone());
+        ledger_deadlines::reached(env, bounty_id, grace)
+            .unwrap_or_else(|| env.ledger().timestamp() >= Self::refund_unlocks_at(env, escrow))
+    }
 
-        // EFFECTS: update state before external call (CEI)
-        invariants::assert_escrow(&env, &escrow);
-        escrow.remaining_amount -= refund_amount;
-        if is_full || escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
-        } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
+    /// Push the deadline of a `Locked` escrow further into the future.
+    ///
+    /// Requires the depositor's authorization. Once a contributor has been
+    /// assigned through a pending claim, that contributor must consent too.
+    /// `new_deadline` must be later than the current deadline and within the
+    /// configured maximum extension.
+    pub fn extend_deadline(env: Env, bounty_id: u64, new_deadline: u64) -> Result<(), Error> {
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
         }
 
-        // Add to refund history
-        escrow.refund_history.push_back(RefundRecord {
-            amount: refund_amount,
-            recipient: refund_to.clone(),
-            timestamp: now,
-            mode: if is_full {
-                RefundMode::Full
-            } else {
-                RefundMode::Partial
-            },
-        });
-
-        // Save updated escrow
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        escrow.depositor.require_auth();
+        if let Some(claim) =
+            escrow_entries::get::<ClaimRecord>(&env, bounty_id, Entry::PendingClaim)
+        {
+            if !claim.claimed {
+                claim.recipient.require_auth();
+            }
+        }
 
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
+        let old_deadline = escrow.deadline;
+        if new_deadline <= old_deadline {
+            return Err(Error::InvalidDeadline);
+        }
+        if new_deadline - old_deadline > Self::get_max_deadline_extension(env.clone()) {
+            panic_with_error!(&env, DeadlineError::ExtensionTooLong);
         }
 
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        escrow.deadline = new_deadline;
+        ledger_deadlines::remove(&env, bounty_id);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::record_action(
+            &env,
+            bounty_id,
+            &escrow.depositor,
+            EscrowAction::DeadlineExtended,
+            0,
+        );
 
-        emit_funds_refunded(
+        events::emit_deadline_extended(
             &env,
-            FundsRefunded {
-                version: EVENT_VERSION_V2,
+            events::DeadlineExtended {
                 bounty_id,
-                amount: refund_amount,
-                refund_to: refund_to.clone(),
-                timestamp: now,
+                old_deadline,
+                new_deadline,
+                extended_by: escrow.depositor,
+                timestamp: env.ledger().timestamp(),
             },
         );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
         Ok(())
     }
 
@@ -2349,6 +9914,9 @@ impl BountyEscrowContract {
         holder: Address,
         capability_id: u64,
     ) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
         if Self::check_paused(&env, symbol_short!("refund")) {
             return Err(Error::FundsPaused);
         }
@@ -2359,34 +9927,23 @@ impl BountyEscrowContract {
             return Err(Error::BountyNotFound);
         }
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let mut escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
+        state_machine::ensure_allowed(&escrow, StatusEvent::Refund)?;
         if amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
+            return Err(Error::AmountExceedsRemaining);
         }
 
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
+        if escrow_entries::has(&env, bounty_id, Entry::PendingClaim) {
+            let claim: ClaimRecord =
+                escrow_entries::get(&env, bounty_id, Entry::PendingClaim).unwrap();
             if !claim.claimed {
                 return Err(Error::ClaimPending);
             }
         }
+        Self::ensure_no_open_dispute(&env, bounty_id)?;
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        Self::ensure_no_stream(&env, bounty_id)?;
 
         Self::consume_capability(
             &env,
@@ -2400,16 +9957,17 @@ impl BountyEscrowContract {
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
         let now = env.ledger().timestamp();
-        let refund_to = escrow.depositor.clone();
-
-        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        let refund_to = Self::refund_destination(&env, bounty_id, &escrow);
+        Self::ensure_not_blocked(&env, &refund_to)?;
 
+        // EFFECTS: update escrow state before the external call
         escrow.remaining_amount -= amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Refunded;
+        let event = if escrow.remaining_amount == 0 {
+            StatusEvent::Refund
         } else {
-            escrow.status = EscrowStatus::PartiallyRefunded;
-        }
+            StatusEvent::PartialRefund
+        };
+        state_machine::transition(&mut escrow, event)?;
 
         escrow.refund_history.push_back(RefundRecord {
             amount,
@@ -2422,9 +9980,13 @@ impl BountyEscrowContract {
             },
         });
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::save_escrow(&env, bounty_id, &escrow);
+        Self::settle_yield(&env, bounty_id, &escrow);
+        Self::record_action(&env, bounty_id, &holder, EscrowAction::Refunded, amount);
+
+        // INTERACTION: external token transfer is last
+        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        Self::notify_refund(&env, bounty_id, &refund_to, amount);
 
         emit_funds_refunded(
             &env,
@@ -2437,19 +9999,246 @@ impl BountyEscrowContract {
             },
         );
 
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
     /// view function to get escrow info
     pub fn get_escrow_info(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
+        let (escrow, entries) =
+            Self::load_escrow_record(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        Self::bump_entries_ttl(&env, bounty_id, entries, false);
+        Ok(escrow)
+    }
+
+    /// View: `get_escrow_info` plus fields derived from the contract's rules,
+    /// so clients do not have to reimplement them.
+    pub fn get_escrow_summary(env: Env, bounty_id: u64) -> Result<EscrowSummary, Error> {
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+
+        let refunded: i128 = escrow.refund_history.iter().map(|r| r.amount).sum();
+        let released = escrow.amount - escrow.remaining_amount - refunded;
+        let released_bps = if escrow.amount > 0 {
+            (released * 10_000 / escrow.amount) as u32
+        } else {
+            0
+        };
+        let frozen = Self::ensure_not_frozen(&env, bounty_id).is_err();
+        let refund_eligible = !frozen
+            && Self::ensure_no_open_dispute(&env, bounty_id).is_ok()
+            && Self::ensure_no_stream(&env, bounty_id).is_ok()
+            && Self::simulate_refund(env.clone(), bounty_id).success;
+
+        Ok(EscrowSummary {
+            seconds_until_deadline: escrow.deadline.saturating_sub(env.ledger().timestamp()),
+            released_bps,
+            refund_eligible,
+            frozen,
+            status: escrow.status,
+            amount: escrow.amount,
+            remaining_amount: escrow.remaining_amount,
+        })
+    }
+
+    /// Extend the escrow record and its history/metadata to the maximum
+    /// retention, regardless of how much TTL is left. Callable by anyone.
+    pub fn extend_escrow_ttl(env: Env, bounty_id: u64) -> Result<EscrowTtl, Error> {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        Ok(env
+        Self::bump_escrow_ttl(&env, bounty_id, true);
+        Self::get_escrow_ttl(env, bounty_id)
+    }
+
+    /// Extend the records of every escrow in `bounty_ids` like
+    /// `extend_escrow_ttl`, skipping ids with no escrow. Callable by anyone.
+    /// Returns the number of escrows extended.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `bounty_ids` is empty or longer than MAX_BATCH_SIZE
+    pub fn bump_all(env: Env, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+        let mut bumped = 0u32;
+        for bounty_id in bounty_ids.iter() {
+            if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                Self::bump_escrow_ttl(&env, bounty_id, true);
+                bumped += 1;
+            }
+        }
+        Ok(bumped)
+    }
+
+    /// Set when and how far escrow records are extended, or `None` to go
+    /// back to the defaults (admin only). Applies from each escrow's next
+    /// extension. See the `storage_policy` module.
+    ///
+    /// # Errors
+    /// * InvalidTtlPolicy - if `extend_to` is not above `threshold` or is
+    ///   beyond the network's maximum entry TTL
+    pub fn set_ttl_policy(env: Env, policy: Option<TtlPolicy>) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if let Some(policy) = &policy {
+            if !storage_policy::is_valid(&env, policy) {
+                panic_with_error!(&env, storage_policy::TtlPolicyError::InvalidTtlPolicy);
+            }
+        }
+        storage_policy::set(&env, policy);
+        Ok(())
+    }
+
+    /// View: the TTL policy in force.
+    pub fn get_ttl_policy(env: Env) -> TtlPolicy {
+        storage_policy::get(&env)
+    }
+
+    /// View: how long the escrow record stays live before it is archived.
+    pub fn get_escrow_ttl(env: Env, bounty_id: u64) -> Result<EscrowTtl, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let live_until_ledger = escrow_entries::load(&env, bounty_id).live_until;
+        Ok(EscrowTtl {
+            live_until_ledger,
+            ledgers_remaining: live_until_ledger.saturating_sub(env.ledger().sequence()),
+        })
+    }
+
+    /// Delete up to `max_count` escrows that were fully released or refunded
+    /// more than `ESCROW_RETENTION_PERIOD` ago (admin only), keeping only an
+    /// `ArchivedEscrow` summary. This drops the escrow record, its history,
+    /// metadata and per-escrow settings, and removes it from the escrow,
+    /// depositor and status indexes. Contributor payout indexes and contract
+    /// stats are left as they are. Frozen escrows are skipped. Returns the
+    /// number of escrows swept.
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if `max_count` is 0 or above MAX_BATCH_SIZE
+    pub fn sweep_stale_escrows(env: Env, max_count: u32) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if max_count == 0 || max_count > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut swept: Vec<u64> = Vec::new(&env);
+        'statuses: for status in [EscrowStatus::Released, EscrowStatus::Refunded] {
+            let ids = escrow_index::all(&env, &IndexId::Status(status.clone()));
+            for bounty_id in ids.iter() {
+                if swept.len() >= max_count {
+                    break 'statuses;
+                }
+                let settled_at = Self::escrow_settled_at(&env, bounty_id);
+                let escrow: Option<Escrow> = Self::load_escrow(&env, bounty_id);
+                let escrow = match escrow {
+                    Some(escrow)
+                        if now >= settled_at.saturating_add(ESCROW_RETENTION_PERIOD)
+                            && Self::ensure_not_frozen(&env, bounty_id).is_ok()
+                            && bonds::get(&env, bounty_id).is_none() =>
+                    {
+                        escrow
+                    }
+                    _ => continue,
+                };
+
+                archive::set(
+                    &env,
+                    bounty_id,
+                    &ArchivedEscrow {
+                        depositor: escrow.depositor.clone(),
+                        amount: escrow.amount,
+                        status: status.clone(),
+                        settled_at,
+                        archived_at: now,
+                    },
+                );
+                Self::remove_escrow_entries(&env, bounty_id, &escrow);
+                swept.push_back(bounty_id);
+            }
+        }
+
+        if !swept.is_empty() {
+            events::emit_escrows_swept(
+                &env,
+                events::EscrowsSwept {
+                    bounty_ids: swept.clone(),
+                    admin,
+                    timestamp: now,
+                },
+            );
+        }
+        Ok(swept.len())
+    }
+
+    /// View: the summary left behind by `sweep_stale_escrows`, if the escrow
+    /// has been archived.
+    pub fn get_archived_escrow(env: Env, bounty_id: u64) -> Option<ArchivedEscrow> {
+        archive::get(&env, bounty_id)
+    }
+
+    /// Timestamp of the last recorded action on an escrow, or its deadline
+    /// when no history is kept.
+    fn escrow_settled_at(env: &Env, bounty_id: u64) -> u64 {
+        match Self::history(env, bounty_id).last() {
+            Some(entry) => entry.timestamp,
+            None => Self::load_escrow(env, bounty_id).map_or(0, |e| e.deadline),
+        }
+    }
+
+    /// Delete every persistent entry kept for `bounty_id` and drop it from
+    /// the escrow, depositor and status indexes.
+    fn remove_escrow_entries(env: &Env, bounty_id: u64, escrow: &Escrow) {
+        Self::unindex_escrow(env, bounty_id, &escrow.depositor, escrow.status.clone());
+        escrow_entries::remove_escrow(env, bounty_id);
+        kyc::set(env, bounty_id, None);
+        issue_links::remove(env, bounty_id);
+        ledger_deadlines::remove(env, bounty_id);
+        payout_caps::remove(env, bounty_id);
+        funders::remove(env, bounty_id);
+        insurance::remove(env, bounty_id);
+        referrals::remove(env, bounty_id);
+        for entry in [
+            Entry::ReleaseFeeRate,
+            Entry::Metadata,
+            Entry::VestingStream,
+            Entry::Hook,
+            Entry::Milestones,
+            Entry::Dispute,
+            Entry::RefundApproval,
+            Entry::ReleaseApproval,
+            Entry::PendingClaim,
+            Entry::IdempotencyKey,
+            Entry::RefundDestination,
+            Entry::Assignment,
+            Entry::Submission,
+            Entry::SubmissionRequired,
+            Entry::ReviewPeriod,
+            Entry::ExpiryNotified,
+            Entry::Details,
+            Entry::Approver,
+            Entry::VoucherSigner,
+            Entry::VoucherNonce,
+            Entry::Payees,
+            Entry::History,
+        ] {
+            escrow_entries::remove(env, bounty_id, entry);
+        }
+        release_limits::remove_escrow(env, bounty_id);
     }
 
     /// view function to get contract balance of the token
@@ -2492,6 +10281,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2502,6 +10293,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2512,6 +10305,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2522,6 +10317,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2532,6 +10329,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2548,6 +10347,8 @@ impl BountyEscrowContract {
                     amount: 0,
                     resulting_status: EscrowStatus::Locked,
                     remaining_amount: 0,
+                    fee: 0,
+                    transfers: Vec::new(&env),
                 };
             }
             if amount > max_amount {
@@ -2557,6 +10358,8 @@ impl BountyEscrowContract {
                     amount: 0,
                     resulting_status: EscrowStatus::Locked,
                     remaining_amount: 0,
+                    fee: 0,
+                    transfers: Vec::new(&env),
                 };
             }
         }
@@ -2572,6 +10375,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2582,15 +10387,18 @@ impl BountyEscrowContract {
             amount,
             resulting_status: EscrowStatus::Locked,
             remaining_amount: amount,
+            fee: 0,
+            transfers: Vec::new(&env),
         }
     }
 
     /// Simulate a `release_funds` call.
     ///
-    /// Checks initialisation, existence, pause state, and escrow status
-    /// exactly as the real function does.  Returns the projected released
-    /// state.  No auth is required.
-    pub fn simulate_release(env: Env, bounty_id: u64, _contributor: Address) -> SimulationResult {
+    /// Runs the same checks as the real function, including disputes,
+    /// freezes, KYC, approvals and release limits, and returns the projected
+    /// released state with the fee and the transfers to the contributor and
+    /// any referrer. No auth is required.
+    pub fn simulate_release(env: Env, bounty_id: u64, contributor: Address) -> SimulationResult {
         if Self::check_paused(&env, symbol_short!("release")) {
             return SimulationResult {
                 success: false,
@@ -2598,6 +10406,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2608,6 +10418,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2618,32 +10430,139 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        let amount = escrow.remaining_amount;
+        let checks = Self::check_simulated_release(&env, bounty_id, &escrow, &contributor, amount)
+            .and_then(|()| {
+                Self::check_release_approvals(&env, bounty_id, &escrow, &contributor, amount)
+                    .map_err(Into::into)
+            });
+        if let Err(error) = checks {
+            return Self::simulated_failure(&env, error, &escrow);
+        }
 
-        if escrow.status != EscrowStatus::Locked {
+        // --- Would succeed ---
+        let (fee, transfers) = Self::release_transfers(&env, bounty_id, &contributor, amount);
+        SimulationResult {
+            success: true,
+            error_code: 0,
+            amount,
+            resulting_status: EscrowStatus::Released,
+            remaining_amount: 0,
+            fee,
+            transfers,
+        }
+    }
+
+    /// Simulate a `partial_release` of `amount` to `contributor`, with the
+    /// same checks and outputs as `simulate_release`.
+    pub fn simulate_partial_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        amount: i128,
+    ) -> SimulationResult {
+        if Self::check_paused(&env, symbol_short!("release")) {
             return SimulationResult {
                 success: false,
-                error_code: Error::FundsNotLocked as u32,
+                error_code: Error::FundsPaused as u32,
                 amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
+                resulting_status: EscrowStatus::Locked,
+                remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
+        let escrow = match Self::load_escrow(&env, bounty_id) {
+            Some(escrow) => escrow,
+            None => {
+                return SimulationResult {
+                    success: false,
+                    error_code: Error::BountyNotFound as u32,
+                    amount: 0,
+                    resulting_status: EscrowStatus::Locked,
+                    remaining_amount: 0,
+                    fee: 0,
+                    transfers: Vec::new(&env),
+                }
+            }
+        };
+        let checks = if amount <= 0 {
+            Err(Error::InvalidAmount.into())
+        } else if amount > escrow.remaining_amount {
+            Err(Error::InsufficientFunds.into())
+        } else {
+            Self::check_simulated_release(&env, bounty_id, &escrow, &contributor, amount)
+                .and_then(|()| payout_caps::check(&env, bounty_id, amount).map_err(Into::into))
+        };
+        if let Err(error) = checks {
+            return Self::simulated_failure(&env, error, &escrow);
+        }
 
         // --- Would succeed ---
+        let remaining_amount = escrow.remaining_amount - amount;
+        let resulting_status = if remaining_amount == 0 {
+            EscrowStatus::Released
+        } else {
+            EscrowStatus::Locked
+        };
+        let (fee, transfers) = Self::release_transfers(&env, bounty_id, &contributor, amount);
         SimulationResult {
             success: true,
             error_code: 0,
-            amount: escrow.amount,
-            resulting_status: EscrowStatus::Released,
-            remaining_amount: 0,
+            amount,
+            resulting_status,
+            remaining_amount,
+            fee,
+            transfers,
+        }
+    }
+
+    /// Checks of a release of `amount` from `escrow` that don't depend on
+    /// whether it is full or partial.
+    fn check_simulated_release(
+        env: &Env,
+        bounty_id: u64,
+        escrow: &Escrow,
+        contributor: &Address,
+        amount: i128,
+    ) -> Result<(), soroban_sdk::Error> {
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked.into());
+        }
+        let entries = escrow_entries::load(env, bounty_id);
+        let claim: Option<ClaimRecord> =
+            escrow_entries::get_in(env, bounty_id, &entries, Entry::PendingClaim);
+        if claim.is_some_and(|claim| !claim.claimed) {
+            return Err(Error::ClaimPending.into());
+        }
+        Self::ensure_releasable(env, bounty_id, escrow, &entries, contributor)?;
+        if release_limits::remaining(env, bounty_id).is_some_and(|left| amount > left) {
+            return Err(Error::ReleaseRateLimited.into());
+        }
+        Ok(())
+    }
+
+    /// `SimulationResult` for an operation on `escrow` that would fail with
+    /// `error`, leaving it as it is.
+    fn simulated_failure(
+        env: &Env,
+        error: impl Into<soroban_sdk::Error>,
+        escrow: &Escrow,
+    ) -> SimulationResult {
+        SimulationResult {
+            success: false,
+            error_code: error.into().get_code(),
+            amount: 0,
+            resulting_status: escrow.status.clone(),
+            remaining_amount: escrow.remaining_amount,
+            fee: 0,
+            transfers: Vec::new(env),
         }
     }
 
@@ -2661,6 +10580,8 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
@@ -2671,14 +10592,12 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: EscrowStatus::Locked,
                 remaining_amount: 0,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
 
         if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
         {
@@ -2688,20 +10607,15 @@ impl BountyEscrowContract {
                 amount: 0,
                 resulting_status: escrow.status,
                 remaining_amount: escrow.remaining_amount,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
         // Block if there is an active pending claim
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
+        if escrow_entries::has(&env, bounty_id, Entry::PendingClaim) {
+            let claim: ClaimRecord =
+                escrow_entries::get(&env, bounty_id, Entry::PendingClaim).unwrap();
             if !claim.claimed {
                 return SimulationResult {
                     success: false,
@@ -2709,44 +10623,92 @@ impl BountyEscrowContract {
                     amount: 0,
                     resulting_status: escrow.status,
                     remaining_amount: escrow.remaining_amount,
+                    fee: 0,
+                    transfers: Vec::new(&env),
                 };
             }
         }
 
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+        let checks = Self::ensure_no_open_dispute(&env, bounty_id)
+            .and_then(|()| Self::ensure_not_frozen(&env, bounty_id))
+            .and_then(|()| Self::ensure_no_stream(&env, bounty_id));
+        if let Err(error) = checks {
+            return Self::simulated_failure(&env, error, &escrow);
+        }
+
+        let approval = Self::active_refund_approval(&env, bounty_id);
 
-        if now < escrow.deadline && approval.is_none() {
+        if !Self::refund_unlocked(&env, bounty_id, &escrow) && approval.is_none() {
             return SimulationResult {
                 success: false,
                 error_code: Error::DeadlineNotPassed as u32,
                 amount: 0,
                 resulting_status: escrow.status,
                 remaining_amount: escrow.remaining_amount,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
         // Calculate refund parameters (same logic as real refund)
-        let (refund_amount, is_full) = if let Some(app) = approval {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, full)
+        let (refund_amount, contributor_amount, is_full) = if let Some(app) = &approval {
+            let contributor_amount = match app.mode {
+                RefundMode::Split(_, share) => share,
+                _ => 0,
+            };
+            let settled = app.amount + contributor_amount;
+            let full = app.mode == RefundMode::Full || settled >= escrow.remaining_amount;
+            (app.amount, contributor_amount, full)
         } else {
-            (escrow.remaining_amount, true)
+            (escrow.remaining_amount, 0, true)
         };
 
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
+        if refund_amount <= 0 || refund_amount + contributor_amount > escrow.remaining_amount {
+            let error = if refund_amount <= 0 {
+                Error::InvalidAmount
+            } else {
+                Error::AmountExceedsRemaining
+            };
             return SimulationResult {
                 success: false,
-                error_code: Error::InvalidAmount as u32,
+                error_code: error as u32,
                 amount: 0,
                 resulting_status: escrow.status,
                 remaining_amount: escrow.remaining_amount,
+                fee: 0,
+                transfers: Vec::new(&env),
             };
         }
 
+        // Approved refunds go to the approval's recipient; deadline refunds
+        // are shared between the funders, as in `refund`.
+        let payouts = match &approval {
+            Some(app) => Self::ensure_not_blocked(&env, &app.recipient)
+                .map(|()| Vec::from_array(&env, [(app.recipient.clone(), refund_amount)])),
+            None => {
+                let refund_to = Self::refund_destination(&env, bounty_id, &escrow);
+                Self::refund_payouts(&env, bounty_id, &escrow, refund_amount, &refund_to)
+            }
+        };
+        let mut transfers = match payouts {
+            Ok(payouts) => payouts,
+            Err(error) => return Self::simulated_failure(&env, error, &escrow),
+        };
+
+        // A split's contributor share is paid like a release, after the refund.
+        let mut fee = 0;
+        if let Some(RefundMode::Split(contributor, share)) = approval.map(|app| app.mode) {
+            if let Err(error) = Self::ensure_not_blocked(&env, &contributor) {
+                return Self::simulated_failure(&env, error, &escrow);
+            }
+            let (share_fee, share_transfers) =
+                Self::release_transfers(&env, bounty_id, &contributor, share);
+            fee = share_fee;
+            transfers.append(&share_transfers);
+        }
+
         // --- Would succeed ---
-        let new_remaining = escrow.remaining_amount - refund_amount;
+        let new_remaining = escrow.remaining_amount - refund_amount - contributor_amount;
         let new_status = if is_full || new_remaining == 0 {
             EscrowStatus::Refunded
         } else {
@@ -2759,6 +10721,8 @@ impl BountyEscrowContract {
             amount: refund_amount,
             resulting_status: new_status,
             remaining_amount: new_remaining,
+            fee,
+            transfers,
         }
     }
 
@@ -2770,11 +10734,7 @@ impl BountyEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
+        let index = escrow_index::all(&env, &IndexId::All);
         let mut results = Vec::new(&env);
         let mut count = 0u32;
         let mut skipped = 0u32;
@@ -2785,11 +10745,7 @@ impl BountyEscrowContract {
             }
 
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
                 if escrow.status == status {
                     if skipped < offset {
                         skipped += 1;
@@ -2811,11 +10767,7 @@ impl BountyEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
+        let index = escrow_index::all(&env, &IndexId::All);
         let mut results = Vec::new(&env);
         let mut count = 0u32;
         let mut skipped = 0u32;
@@ -2826,11 +10778,7 @@ impl BountyEscrowContract {
             }
 
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
                 if escrow.amount >= min_amount && escrow.amount <= max_amount {
                     if skipped < offset {
                         skipped += 1;
@@ -2852,11 +10800,7 @@ impl BountyEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
+        let index = escrow_index::all(&env, &IndexId::All);
         let mut results = Vec::new(&env);
         let mut count = 0u32;
         let mut skipped = 0u32;
@@ -2867,11 +10811,7 @@ impl BountyEscrowContract {
             }
 
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
                 if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
                     if skipped < offset {
                         skipped += 1;
@@ -2885,6 +10825,30 @@ impl BountyEscrowContract {
         results
     }
 
+    /// List up to `limit` funded escrows (`Locked` or `PartiallyRefunded`)
+    /// whose deadline is at or before `now`, earliest deadline day first.
+    /// Reads the deadline index, so the cost grows with the number of
+    /// expired escrows rather than with every escrow ever locked.
+    pub fn list_expired_escrows(env: Env, now: u64, limit: u32) -> Vec<EscrowWithId> {
+        let mut results = Vec::new(&env);
+        for bucket in deadline_index::buckets(&env).iter() {
+            if bucket > deadline_index::bucket_of(now) {
+                break;
+            }
+            for bounty_id in deadline_index::ids(&env, bucket).iter() {
+                if results.len() >= limit {
+                    return results;
+                }
+                if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
+                    if escrow.deadline <= now {
+                        results.push_back(EscrowWithId { bounty_id, escrow });
+                    }
+                }
+            }
+        }
+        results
+    }
+
     /// Query escrows by depositor
     pub fn query_escrows_by_depositor(
         env: Env,
@@ -2892,35 +10856,60 @@ impl BountyEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<EscrowWithId> {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::DepositorIndex(depositor))
-            .unwrap_or(Vec::new(&env));
         let mut results = Vec::new(&env);
-        let start = offset.min(index.len());
-        let end = (offset + limit).min(index.len());
-
-        for i in start..end {
-            let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+        let index = escrow_index::range(&env, &IndexId::Depositor(depositor), offset, limit);
+        for bounty_id in index.iter() {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
                 results.push_back(EscrowWithId { bounty_id, escrow });
             }
         }
         results
     }
 
+    /// Export up to `limit` escrows from position `offset` in lock order as
+    /// flat `EscrowSnapshot` rows, for reconciling with off-chain books.
+    /// `released` is derived as what left the escrow other than by refund.
+    pub fn export_escrow_snapshot(env: Env, offset: u32, limit: u32) -> Vec<EscrowSnapshot> {
+        let mut results = Vec::new(&env);
+        for bounty_id in escrow_index::range(&env, &IndexId::All, offset, limit).iter() {
+            let escrow = match Self::load_escrow(&env, bounty_id) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+            let refunded: i128 = escrow.refund_history.iter().map(|r| r.amount).sum();
+            let history = Self::history(&env, bounty_id);
+            let locked_at = history
+                .iter()
+                .find(|entry| entry.action == EscrowAction::Locked)
+                .map(|entry| entry.timestamp);
+            let updated_at = history.last().map_or(0, |entry| entry.timestamp);
+            let contributor = Self::get_assignment(env.clone(), bounty_id)
+                .map(|assignment| assignment.contributor)
+                .or_else(|| {
+                    escrow_entries::get::<ClaimRecord>(&env, bounty_id, Entry::PendingClaim)
+                        .map(|claim| claim.recipient)
+                });
+
+            results.push_back(EscrowSnapshot {
+                bounty_id,
+                depositor: escrow.depositor,
+                contributor,
+                amount: escrow.amount,
+                released: escrow.amount - escrow.remaining_amount - refunded,
+                refunded,
+                remaining_amount: escrow.remaining_amount,
+                status: escrow.status,
+                deadline: escrow.deadline,
+                locked_at,
+                updated_at,
+            });
+        }
+        results
+    }
+
     /// Get aggregate statistics
     pub fn get_aggregate_stats(env: Env) -> AggregateStats {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
+        let index = escrow_index::all(&env, &IndexId::All);
         let mut stats = AggregateStats {
             total_locked: 0,
             total_released: 0,
@@ -2932,11 +10921,7 @@ impl BountyEscrowContract {
 
         for i in 0..index.len() {
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
                 match escrow.status {
                     EscrowStatus::Locked => {
                         stats.total_locked += escrow.amount;
@@ -2946,7 +10931,9 @@ impl BountyEscrowContract {
                         stats.total_released += escrow.amount;
                         stats.count_released += 1;
                     }
-                    EscrowStatus::Refunded | EscrowStatus::PartiallyRefunded => {
+                    EscrowStatus::Refunded
+                    | EscrowStatus::PartiallyRefunded
+                    | EscrowStatus::Cancelled => {
                         stats.total_refunded += escrow.amount;
                         stats.count_refunded += 1;
                     }
@@ -2956,14 +10943,14 @@ impl BountyEscrowContract {
         stats
     }
 
+    /// Contract-wide escrow counts and token totals, see `ContractStats`.
+    pub fn get_contract_stats(env: Env) -> ContractStats {
+        Self::load_stats(&env)
+    }
+
     /// Get total count of escrows
     pub fn get_escrow_count(env: Env) -> u32 {
-        let index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.len()
+        escrow_index::len(&env, &IndexId::All)
     }
 
     /// Set the minimum and maximum allowed lock amount (admin only).
@@ -3003,18 +10990,81 @@ impl BountyEscrowContract {
         Ok(())
     }
 
-    /// Get escrow IDs by status
-    pub fn get_escrow_ids_by_status(
+    /// Minimum lock amount configured via `set_amount_policy`, if any.
+    pub fn get_min_escrow_amount(env: Env) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+            .map(|(min_amount, _)| min_amount)
+    }
+
+    /// Maximum lock amount configured via `set_amount_policy`, if any.
+    pub fn get_max_escrow_amount(env: Env) -> Option<i128> {
+        env.storage()
+            .instance()
+            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+            .map(|(_, max_amount)| max_amount)
+    }
+
+    /// List bounty ids in lock order, skipping the first `offset`. A swept
+    /// or reassigned escrow's place is taken by the last id.
+    pub fn list_escrows(env: Env, offset: u32, limit: u32) -> Vec<u64> {
+        escrow_index::range(&env, &IndexId::All, offset, limit)
+    }
+
+    /// List bounty ids currently in `status`, skipping the first `offset`.
+    /// Backed by a per-status index, so the cost does not depend on how many
+    /// escrows are in other statuses. An escrow leaving `status` has its
+    /// place taken by the last id.
+    pub fn list_escrows_by_status(
         env: Env,
         status: EscrowStatus,
         offset: u32,
         limit: u32,
     ) -> Vec<u64> {
-        let index: Vec<u64> = env
+        escrow_index::range(&env, &IndexId::Status(status), offset, limit)
+    }
+
+    /// List bounty ids funded by `depositor`, in lock order.
+    pub fn get_escrows_by_depositor(
+        env: Env,
+        depositor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        escrow_index::range(&env, &IndexId::Depositor(depositor), offset, limit)
+    }
+
+    /// List bounty ids that have paid out to `contributor`, in order of their
+    /// first payout. Each bounty appears once even after several payouts.
+    pub fn get_payouts_by_contributor(
+        env: Env,
+        contributor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let legacy: Option<Vec<u64>> = env
             .storage()
             .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
+            .get(&DataKey::ContributorIndex(contributor.clone()));
+        match legacy {
+            Some(ids) => {
+                let start = offset.min(ids.len());
+                let end = start.saturating_add(limit).min(ids.len());
+                ids.slice(start..end)
+            }
+            None => escrow_index::range(&env, &IndexId::Contributor(contributor), offset, limit),
+        }
+    }
+
+    /// Get escrow IDs by status
+    pub fn get_escrow_ids_by_status(
+        env: Env,
+        status: EscrowStatus,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let index = escrow_index::all(&env, &IndexId::All);
         let mut results = Vec::new(&env);
         let mut count = 0u32;
         let mut skipped = 0u32;
@@ -3024,11 +11074,7 @@ impl BountyEscrowContract {
                 break;
             }
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
                 if escrow.status == status {
                     if skipped < offset {
                         skipped += 1;
@@ -3073,6 +11119,140 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Add `address` to or remove it from the payout blocklist (admin only),
+    /// e.g. to comply with sanctions. `release_funds`, `partial_release` and
+    /// `refund` reject transfers to blocked addresses with `Unauthorized`.
+    pub fn set_blocklist_entry(env: Env, address: Address, blocked: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let key = ConfigKey::Blocked(address.clone());
+        if blocked {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        events::emit_blocklist_updated(
+            &env,
+            events::BlocklistUpdated {
+                address,
+                blocked,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Allow or forbid releases that pay an escrow back to its own depositor
+    /// (admin only). Forbidden by default. See the `release_policy` module.
+    pub fn set_release_to_depositor_allowed(env: Env, allowed: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        release_policy::set_depositor_allowed(&env, allowed);
+        Ok(())
+    }
+
+    /// View: whether releases to the escrow's own depositor are allowed.
+    pub fn is_release_to_depositor_allowed(env: Env) -> bool {
+        release_policy::depositor_allowed(&env)
+    }
+
+    /// Cap how much may be released per `window` seconds across the contract
+    /// and per escrow, or `None` to lift the caps (admin only). See the
+    /// `release_limits` module.
+    pub fn set_release_rate_limit(env: Env, limit: Option<ReleaseRateLimit>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if let Some(limit) = &limit {
+            if limit.window == 0 {
+                return Err(Error::InvalidDeadline);
+            }
+            if limit.global_limit < 0 || limit.escrow_limit < 0 {
+                return Err(Error::InvalidAmount);
+            }
+        }
+        release_limits::set(&env, limit);
+        Ok(())
+    }
+
+    /// View: the release rate limit, if one is set.
+    pub fn get_release_rate_limit(env: Env) -> Option<ReleaseRateLimit> {
+        release_limits::get(&env)
+    }
+
+    /// View: how much of `bounty_id` the rate limits let through right now,
+    /// or `None` if no limit applies.
+    pub fn get_releasable_now(env: Env, bounty_id: u64) -> Option<i128> {
+        release_limits::remaining(&env, bounty_id)
+    }
+
+    /// View: `true` if payouts to `address` are blocked.
+    pub fn is_blocked(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&ConfigKey::Blocked(address))
+    }
+
+    /// Turn the permissioned depositor mode on or off (admin only). While it
+    /// is on, `lock_funds` and its variants, batch locks and top-ups fail
+    /// with `Unauthorized` for depositors not on the allowlist.
+    pub fn set_depositor_allowlist_mode(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        allowlist::set_enabled(&env, enabled);
+        Ok(())
+    }
+
+    /// View: `true` while only allowlisted depositors may lock funds.
+    pub fn is_depositor_allowlist_mode(env: Env) -> bool {
+        allowlist::is_enabled(&env)
+    }
+
+    /// Add `depositor` to or remove it from the depositor allowlist (admin
+    /// only). Escrows it already locked are unaffected by removal.
+    pub fn set_depositor_allowlist_entry(
+        env: Env,
+        depositor: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        allowlist::set_listed(&env, &depositor, allowed);
+        events::emit_depositor_allowlist_updated(
+            &env,
+            events::DepositorAllowlistUpdated {
+                depositor,
+                allowed,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// View: `true` if `depositor` is on the depositor allowlist.
+    pub fn is_allowlisted_depositor(env: Env, depositor: Address) -> bool {
+        allowlist::is_listed(&env, &depositor)
+    }
+
     /// Update anti-abuse config (rate limit window, max operations per window, cooldown). Admin only.
     pub fn update_anti_abuse_config(
         env: Env,
@@ -3118,21 +11298,42 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        Ok(escrow.refund_history)
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
+        Ok(escrow.refund_history)
+    }
+
+    /// View: sequence number of the latest event of `bounty_id`, 0 if it
+    /// has none. Indexers compare it with the last one they processed to
+    /// detect missed events; see the `events` module.
+    pub fn get_event_sequence(env: Env, bounty_id: u64) -> u64 {
+        events::sequence(&env, bounty_id)
+    }
+
+    /// Retrieves up to `limit` entries of the action log for a bounty, oldest
+    /// first, skipping the first `offset`. Only the most recent
+    /// `MAX_HISTORY_ENTRIES` actions are retained.
+    ///
+    /// # Returns
+    /// * `Ok(Vec<HistoryEntry>)` - The requested page, possibly empty
+    /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
+    pub fn get_escrow_history(
+        env: Env,
+        bounty_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<HistoryEntry>, Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let history = Self::history(&env, bounty_id);
+        let start = offset.min(history.len());
+        let end = start.saturating_add(limit).min(history.len());
+        Ok(history.slice(start..end))
     }
 
     /// NEW: Verify escrow invariants for a specific bounty
     pub fn verify_state(env: Env, bounty_id: u64) -> bool {
-        if let Some(escrow) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-        {
+        if let Some(escrow) = Self::load_escrow(&env, bounty_id) {
             invariants::verify_escrow_invariants(&escrow)
         } else {
             false
@@ -3158,36 +11359,20 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
 
-        let now = env.ledger().timestamp();
-        let deadline_passed = now >= escrow.deadline;
+        let deadline_passed = ledger_deadlines::reached(&env, bounty_id, 0)
+            .unwrap_or_else(|| env.ledger().timestamp() >= escrow.deadline);
 
-        let approval = if env
-            .storage()
-            .persistent()
-            .has(&DataKey::RefundApproval(bounty_id))
-        {
-            Some(
-                env.storage()
-                    .persistent()
-                    .get(&DataKey::RefundApproval(bounty_id))
-                    .unwrap(),
-            )
-        } else {
-            None
-        };
+        let approval = Self::active_refund_approval(&env, bounty_id);
 
         // can_refund is true if:
         // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
+        // 2. (deadline plus grace period has passed OR there's an approval)
+        let grace_passed = Self::refund_unlocked(&env, bounty_id, &escrow);
         let can_refund = (escrow.status == EscrowStatus::Locked
             || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+            && (grace_passed || approval.is_some());
 
         Ok((
             can_refund,
@@ -3210,9 +11395,13 @@ impl BountyEscrowContract {
     /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
     /// * BountyExists - if any bounty_id already exists
     /// * NotInitialized - if contract is not initialized
+    /// * AmountBelowMinimum / AmountAboveMaximum - if any amount violates the amount policy
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
+    /// Each depositor is charged with a single transfer covering all of their items.
+    /// If a token that charges a fee on transfer delivers less, what arrived is
+    /// split over that depositor's escrows in proportion to their amounts.
     /// # Reentrancy
     /// Protected by the shared reentrancy guard. All escrow records are
     /// written first; token transfers happen in a second pass (CEI).
@@ -3239,7 +11428,6 @@ impl BountyEscrowContract {
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
         // Validate all items before processing (all-or-nothing approach)
@@ -3249,6 +11437,7 @@ impl BountyEscrowContract {
                 .storage()
                 .persistent()
                 .has(&DataKey::Escrow(item.bounty_id))
+                || archive::contains(&env, item.bounty_id)
             {
                 return Err(Error::BountyExists);
             }
@@ -3257,6 +11446,7 @@ impl BountyEscrowContract {
             if item.amount <= 0 {
                 return Err(Error::InvalidAmount);
             }
+            Self::check_amount_policy(&env, item.amount)?;
 
             // Check for duplicate bounty_ids in the batch
             let mut count = 0u32;
@@ -3270,22 +11460,30 @@ impl BountyEscrowContract {
             }
         }
 
-        // Collect unique depositors and require auth once for each
-        // This prevents "frame is already authorized" errors when same depositor appears multiple times
-        let mut seen_depositors: Vec<Address> = Vec::new(&env);
+        // Collect unique depositors with their aggregate amount and require
+        // auth once for each. This prevents "frame is already authorized"
+        // errors when same depositor appears multiple times, and lets each
+        // depositor fund the whole batch with a single transfer.
+        let mut deposits: Vec<(Address, i128)> = Vec::new(&env);
         for item in items.iter() {
             let mut found = false;
-            for seen in seen_depositors.iter() {
-                if seen.clone() == item.depositor {
+            for (idx, (seen, total)) in deposits.iter().enumerate() {
+                if seen == item.depositor {
+                    let total = total.checked_add(item.amount).ok_or(Error::InvalidAmount)?;
+                    deposits.set(idx as u32, (seen, total));
                     found = true;
                     break;
                 }
             }
             if !found {
-                seen_depositors.push_back(item.depositor.clone());
+                deposits.push_back((item.depositor.clone(), item.amount));
                 item.depositor.require_auth();
             }
         }
+        for (depositor, total) in deposits.iter() {
+            allowlist::ensure_allowed(&env, &depositor)?;
+            or_abort(&env, budgets::consume(&env, &depositor, total));
+        }
 
         // EFFECTS: write all escrow records before any external calls (CEI)
         let mut locked_count = 0u32;
@@ -3297,25 +11495,63 @@ impl BountyEscrowContract {
                 deadline: item.deadline,
                 refund_history: vec![&env],
                 remaining_amount: item.amount,
+                metadata_hash: None,
+                label: None,
             };
 
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            Self::save_escrow(&env, item.bounty_id, &escrow);
+            Self::snapshot_release_fee(&env, item.bounty_id);
 
             locked_count += 1;
         }
 
-        // INTERACTION: all external token transfers happen after state is finalized
+        // INTERACTION: one aggregate transfer per depositor after state is finalized
+        let mut locked: Vec<i128> = Vec::new(&env);
         for item in items.iter() {
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+            locked.push_back(item.amount);
+        }
+        for (depositor, total) in deposits.iter() {
+            let (received, _) = Self::receive_tokens(&env, &client, &depositor, total)?;
+            if received < total {
+                // Spread what arrived over the depositor's escrows.
+                let mut indices: Vec<u32> = Vec::new(&env);
+                let mut amounts: Vec<i128> = Vec::new(&env);
+                for (idx, item) in items.iter().enumerate() {
+                    if item.depositor == depositor {
+                        indices.push_back(idx as u32);
+                        amounts.push_back(item.amount);
+                    }
+                }
+                let shares = Self::split_received(&env, &amounts, total, received)?;
+                for (idx, share) in indices.iter().zip(shares.iter()) {
+                    locked.set(idx, share);
+                }
+            }
+        }
 
+        let mut total_amount: i128 = 0;
+        for (idx, item) in items.iter().enumerate() {
+            let amount = locked.get(idx as u32).unwrap();
+            if amount < item.amount {
+                let mut escrow = Self::load_escrow(&env, item.bounty_id).unwrap();
+                escrow.amount = amount;
+                escrow.remaining_amount = amount;
+                Self::save_escrow(&env, item.bounty_id, &escrow);
+            }
+            Self::record_action(
+                &env,
+                item.bounty_id,
+                &item.depositor,
+                EscrowAction::Locked,
+                amount,
+            );
+            total_amount += amount;
             emit_funds_locked(
                 &env,
                 FundsLocked {
                     version: EVENT_VERSION_V2,
                     bounty_id: item.bounty_id,
-                    amount: item.amount,
+                    amount,
                     depositor: item.depositor.clone(),
                     deadline: item.deadline,
                 },
@@ -3327,11 +11563,14 @@ impl BountyEscrowContract {
             &env,
             BatchFundsLocked {
                 count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
+                total_amount,
                 timestamp,
             },
         );
 
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(locked_count)
@@ -3384,7 +11623,6 @@ impl BountyEscrowContract {
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
         // Validate all items before processing (all-or-nothing approach)
@@ -3398,15 +11636,24 @@ impl BountyEscrowContract {
                 return Err(Error::BountyNotFound);
             }
 
-            let escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
+            let escrow: Escrow = Self::load_escrow(&env, item.bounty_id).unwrap();
 
-            if escrow.status != EscrowStatus::Locked {
-                return Err(Error::FundsNotLocked);
-            }
+            state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
+            Self::ensure_no_open_dispute(&env, item.bounty_id)?;
+            Self::ensure_not_frozen(&env, item.bounty_id)?;
+            Self::ensure_no_stream(&env, item.bounty_id)?;
+            Self::ensure_no_milestones(&env, item.bounty_id)?;
+            or_abort(
+                &env,
+                release_policy::ensure_allowed(&env, &escrow, &item.contributor),
+            );
+            Self::check_release_approvals(
+                &env,
+                item.bounty_id,
+                &escrow,
+                &item.contributor,
+                escrow.remaining_amount,
+            )?;
 
             let mut count = 0u32;
             for other_item in items.iter() {
@@ -3419,7 +11666,7 @@ impl BountyEscrowContract {
             }
 
             total_amount = total_amount
-                .checked_add(escrow.amount)
+                .checked_add(escrow.remaining_amount)
                 .ok_or(Error::InvalidAmount)?;
         }
 
@@ -3428,18 +11675,14 @@ impl BountyEscrowContract {
         let mut release_pairs: Vec<(Address, i128)> = Vec::new(&env);
         let mut released_count = 0u32;
         for item in items.iter() {
-            let mut escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
+            let mut escrow: Escrow = Self::load_escrow(&env, item.bounty_id).unwrap();
 
-            let amount = escrow.amount;
-            escrow.status = EscrowStatus::Released;
+            let amount = escrow.remaining_amount;
+            state_machine::transition(&mut escrow, StatusEvent::Release)?;
             escrow.remaining_amount = 0;
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            Self::save_escrow(&env, item.bounty_id, &escrow);
+            Self::settle_yield(&env, item.bounty_id, &escrow);
+            escrow_entries::remove(&env, item.bounty_id, Entry::ReleaseApproval);
 
             release_pairs.push_back((item.contributor.clone(), amount));
             released_count += 1;
@@ -3448,7 +11691,7 @@ impl BountyEscrowContract {
         // INTERACTION: all external token transfers happen after state is finalized
         for (idx, item) in items.iter().enumerate() {
             let (ref contributor, amount) = release_pairs.get(idx as u32).unwrap();
-            client.transfer(&contract_address, contributor, &amount);
+            Self::pay_release(&env, &client, item.bounty_id, &admin, contributor, amount)?;
 
             emit_funds_released(
                 &env,
@@ -3472,10 +11715,142 @@ impl BountyEscrowContract {
             },
         );
 
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(released_count)
     }
+
+    /// Release many bounties, reporting success or failure per item.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// Unlike `batch_release_funds`, a failing item does not revert the
+    /// batch: it is reported with its error code and left untouched, and the
+    /// remaining items are still processed.
+    ///
+    /// # Errors
+    /// * FundsPaused - if releases are paused
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    pub fn batch_release(
+        env: Env,
+        items: Vec<(u64, Address)>,
+    ) -> Result<Vec<BatchItemResult>, Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let caller = rbac::authorize(&env, None, Role::Releaser)?;
+
+        let mut results: Vec<BatchItemResult> = Vec::new(&env);
+        let mut count = 0u32;
+        let mut total_amount: i128 = 0;
+        for (bounty_id, contributor) in items.iter() {
+            let res = Self::release_escrow(&env, bounty_id, &caller, &contributor);
+            if let Ok(amount) = res {
+                count += 1;
+                total_amount += amount;
+            }
+            results.push_back(Self::batch_item_result(bounty_id, res));
+        }
+
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count,
+                total_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(results)
+    }
+
+    /// Refund many bounties, reporting success or failure per item.
+    /// Only the admin (backend) can authorize this.
+    ///
+    /// Each item follows the same rules as `refund` (deadline passed or an
+    /// approved refund). Failing items are reported and skipped.
+    ///
+    /// # Errors
+    /// * FundsPaused - if refunds are paused
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    pub fn batch_refund(env: Env, bounty_ids: Vec<u64>) -> Result<Vec<BatchItemResult>, Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+        if bounty_ids.is_empty() || bounty_ids.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut results: Vec<BatchItemResult> = Vec::new(&env);
+        let mut count = 0u32;
+        let mut total_amount: i128 = 0;
+        for bounty_id in bounty_ids.iter() {
+            let res = Self::refund_escrow(&env, bounty_id, false);
+            if let Ok(amount) = res {
+                count += 1;
+                total_amount += amount;
+            }
+            results.push_back(Self::batch_item_result(bounty_id, res));
+        }
+
+        events::emit_batch_funds_refunded(
+            &env,
+            events::BatchFundsRefunded {
+                count,
+                total_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(results)
+    }
+
+    fn batch_item_result(bounty_id: u64, res: Result<i128, Error>) -> BatchItemResult {
+        match res {
+            Ok(amount) => BatchItemResult {
+                bounty_id,
+                success: true,
+                amount,
+                error_code: 0,
+            },
+            Err(err) => BatchItemResult {
+                bounty_id,
+                success: false,
+                amount: 0,
+                error_code: err as u32,
+            },
+        }
+    }
+
     pub fn update_metadata(
         env: Env,
         _admin: Address,
@@ -3496,9 +11871,10 @@ impl BountyEscrowContract {
             issue_id,
             bounty_type,
         };
-        env.storage()
-            .persistent()
-            .set(&DataKey::Metadata(bounty_id), &metadata);
+        escrow_entries::set(&env, bounty_id, Entry::Metadata, &metadata);
+        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            Self::bump_escrow_ttl(&env, bounty_id, true);
+        }
         Ok(())
     }
 
@@ -3519,10 +11895,7 @@ impl BountyEscrowContract {
     }
 
     pub fn get_metadata(env: Env, bounty_id: u64) -> Result<EscrowMetadata, Error> {
-        env.storage()
-            .persistent()
-            .get(&DataKey::Metadata(bounty_id))
-            .ok_or(Error::BountyNotFound)
+        escrow_entries::get(&env, bounty_id, Entry::Metadata).ok_or(Error::BountyNotFound)
     }
 
     /// Issue a single-use claim ticket to a bounty winner (admin only)
@@ -3543,7 +11916,9 @@ impl BountyEscrowContract {
     /// * `Err(Error::Unauthorized)` - Caller is not admin
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
     /// * `Err(Error::InvalidDeadline)` - Expiry time is in the past
-    /// * `Err(Error::InvalidAmount)` - Amount is invalid or exceeds escrow amount
+    /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
+    /// * `Err(Error::AmountExceedsRemaining)` - Amount exceeds what the escrow has left
+    /// * `Err(Error::MilestoneEscrow)` - Escrow pays out in milestones
     pub fn issue_claim_ticket(
         env: Env,
         bounty_id: u64,
@@ -3562,21 +11937,21 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = Self::load_escrow(&env, bounty_id).unwrap();
 
         // Verify escrow is in locked state
         if escrow.status != EscrowStatus::Locked {
             return Err(Error::FundsNotLocked);
         }
+        Self::ensure_no_milestones(&env, bounty_id)?;
 
         // Validate amount
-        if amount <= 0 || amount > escrow.amount {
+        if amount <= 0 {
             return Err(Error::InvalidAmount);
         }
+        if amount > escrow.remaining_amount {
+            return Err(Error::AmountExceedsRemaining);
+        }
 
         // Validate expiry
         let now = env.ledger().timestamp();
@@ -3662,13 +12037,16 @@ impl BountyEscrowContract {
     ///
     /// # Returns
     /// * `Ok(())` - Funds successfully transferred
-    /// * `Err(Error::TicketNotFound)` - Ticket doesn't exist
-    /// * `Err(Error::TicketAlreadyUsed)` - Ticket has already been used (replay prevention)
-    /// * `Err(Error::TicketExpired)` - Ticket has expired
+    /// * `TicketError::TicketNotFound` - Ticket doesn't exist
+    /// * `TicketError::TicketAlreadyUsed` - Ticket has already been used (replay prevention)
+    /// * `TicketError::TicketExpired` - Ticket has expired
     /// * `Err(Error::Unauthorized)` - Caller is not the ticket beneficiary
     /// * `Err(Error::FundsPaused)` - Release operations are paused
     /// * `Err(Error::BountyNotFound)` - Associated bounty doesn't exist
     pub fn claim_with_ticket(env: Env, ticket_id: u64) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
         // Check if release is paused
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
@@ -3680,7 +12058,7 @@ impl BountyEscrowContract {
             .persistent()
             .has(&DataKey::ClaimTicket(ticket_id))
         {
-            return Err(Error::TicketNotFound);
+            panic_with_error!(&env, TicketError::TicketNotFound);
         }
 
         let mut ticket: ClaimTicket = env
@@ -3691,13 +12069,13 @@ impl BountyEscrowContract {
 
         // Verify ticket hasn't been used (single-use enforcement)
         if ticket.used {
-            return Err(Error::TicketAlreadyUsed);
+            panic_with_error!(&env, TicketError::TicketAlreadyUsed);
         }
 
         // Verify ticket hasn't expired
         let now = env.ledger().timestamp();
         if now > ticket.expires_at {
-            return Err(Error::TicketExpired);
+            panic_with_error!(&env, TicketError::TicketExpired);
         }
 
         // Verify caller is the beneficiary
@@ -3711,26 +12089,12 @@ impl BountyEscrowContract {
         {
             return Err(Error::BountyNotFound);
         }
+        Self::ensure_not_frozen(&env, ticket.bounty_id)?;
 
         // Get escrow and verify it's locked
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(ticket.bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
+        let mut escrow: Escrow = Self::load_escrow(&env, ticket.bounty_id).unwrap();
 
-        // Transfer funds to beneficiary
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &ticket.beneficiary,
-            &ticket.amount,
-        );
+        state_machine::ensure_allowed(&escrow, StatusEvent::Release)?;
 
         // Mark ticket as used (prevent replay)
         ticket.used = true;
@@ -3739,12 +12103,23 @@ impl BountyEscrowContract {
             .set(&DataKey::ClaimTicket(ticket_id), &ticket);
 
         // Update escrow status to Released
-        escrow.status = EscrowStatus::Released;
+        state_machine::transition(&mut escrow, StatusEvent::Release)?;
         escrow.remaining_amount = 0;
         invariants::assert_escrow(&env, &escrow);
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(ticket.bounty_id), &escrow);
+        Self::save_escrow(&env, ticket.bounty_id, &escrow);
+        Self::settle_yield(&env, ticket.bounty_id, &escrow);
+
+        // INTERACTION: transfer funds to beneficiary last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        Self::pay_release(
+            &env,
+            &client,
+            ticket.bounty_id,
+            &ticket.beneficiary,
+            &ticket.beneficiary,
+            ticket.amount,
+        )?;
 
         // Emit event
         emit_ticket_claimed(
@@ -3758,6 +12133,11 @@ impl BountyEscrowContract {
             },
         );
 
+        // INVARIANT: trip the circuit breaker on a balance shortfall
+        Self::check_balance_invariant(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
@@ -3769,12 +12149,12 @@ impl BountyEscrowContract {
     ///
     /// # Returns
     /// * `Ok(ClaimTicket)` - The ticket details
-    /// * `Err(Error::TicketNotFound)` - Ticket doesn't exist
-    pub fn get_claim_ticket(env: Env, ticket_id: u64) -> Result<ClaimTicket, Error> {
+    /// * `Err(TicketError::TicketNotFound)` - Ticket doesn't exist
+    pub fn get_claim_ticket(env: Env, ticket_id: u64) -> Result<ClaimTicket, TicketError> {
         env.storage()
             .persistent()
             .get(&DataKey::ClaimTicket(ticket_id))
-            .ok_or(Error::TicketNotFound)
+            .ok_or(TicketError::TicketNotFound)
     }
 
     /// Get all claim tickets for a beneficiary
@@ -3890,7 +12270,7 @@ impl traits::EscrowInterface for BountyEscrowContract {
 impl traits::UpgradeInterface for BountyEscrowContract {
     /// Get contract version
     fn get_version(_env: &Env) -> u32 {
-        1 // Current version
+        CONTRACT_VERSION
     }
 
     /// Set contract version (admin only)
@@ -3907,9 +12287,17 @@ mod test_state_verification;
 #[cfg(test)]
 mod test;
 #[cfg(test)]
+mod test_admin_council;
+#[cfg(test)]
+mod test_admin_timelock;
+#[cfg(test)]
 mod test_analytics_monitoring;
 #[cfg(test)]
+mod test_assignment;
+#[cfg(test)]
 mod test_auto_refund_permissions;
+#[cfg(test)]
+mod test_balance_circuit_breaker;
 // #[cfg(test)]
 #[cfg(test)]
 // Temporarily disabled: this suite targets a different blacklist API surface
@@ -3917,31 +12305,173 @@ mod test_auto_refund_permissions;
 // Re-enable after API/test alignment.
 // mod test_blacklist_and_whitelist;
 #[cfg(test)]
+mod test_batch_lock;
+#[cfg(test)]
+mod test_batch_settlement;
+#[cfg(test)]
 mod test_bounty_escrow;
 #[cfg(test)]
+mod test_cancel_escrow;
+#[cfg(test)]
 mod test_compatibility;
 #[cfg(test)]
+mod test_config;
+#[cfg(test)]
+mod test_contract_stats;
+#[cfg(test)]
+mod test_contributor_bond;
+#[cfg(test)]
+mod test_depositor_allowlist;
+#[cfg(test)]
+mod test_depositor_budget;
+#[cfg(test)]
+mod test_depositor_partial_release;
+#[cfg(test)]
 mod test_dispute_resolution;
 #[cfg(test)]
+mod test_donations;
+#[cfg(test)]
 mod test_dry_run_simulation;
 #[cfg(test)]
+mod test_emergency_exit;
+#[cfg(test)]
+mod test_error_descriptions;
+#[cfg(test)]
+mod test_escrow_approver;
+#[cfg(test)]
+mod test_escrow_disputes;
+#[cfg(test)]
+mod test_escrow_freeze;
+#[cfg(test)]
+mod test_escrow_history;
+#[cfg(test)]
+mod test_escrow_hooks;
+#[cfg(test)]
+mod test_escrow_listing;
+#[cfg(test)]
+mod test_escrow_metadata_hash;
+#[cfg(test)]
+mod test_escrow_migration;
+#[cfg(test)]
+mod test_escrow_storage_layout;
+#[cfg(test)]
+mod test_escrow_summary;
+#[cfg(test)]
+mod test_escrow_templates;
+#[cfg(test)]
+mod test_escrow_top_up;
+#[cfg(test)]
+mod test_escrow_ttl;
+#[cfg(test)]
+mod test_event_schema;
+#[cfg(test)]
 mod test_expiration_and_dispute;
 #[cfg(test)]
+mod test_expired_refund;
+#[cfg(test)]
+mod test_expiry_notifications;
+#[cfg(test)]
+mod test_export_snapshot;
+#[cfg(test)]
+mod test_fee_on_transfer;
+#[cfg(test)]
+mod test_fee_withdrawal;
+#[cfg(test)]
 mod test_front_running_ordering;
 #[cfg(test)]
+mod test_funders;
+#[cfg(test)]
+mod test_funding_goal;
+#[cfg(test)]
 mod test_granular_pause;
 #[cfg(test)]
+mod test_idempotent_lock;
+#[cfg(test)]
+mod test_insurance;
+#[cfg(test)]
+mod test_invariant_fuzz;
+#[cfg(test)]
 mod test_invariants;
+#[cfg(test)]
+mod test_issue_links;
+#[cfg(test)]
+mod test_kyc_attestation;
+#[cfg(test)]
+mod test_ledger_deadlines;
 mod test_lifecycle;
 #[cfg(test)]
 mod test_metadata_tagging;
 #[cfg(test)]
+mod test_milestones;
+#[cfg(test)]
+mod test_multisig_release;
+#[cfg(test)]
+mod test_operator_contract;
+#[cfg(test)]
 mod test_partial_payout_rounding;
 #[cfg(test)]
+mod test_participant_indexes;
+#[cfg(test)]
 mod test_pause;
 #[cfg(test)]
+mod test_payout_blocklist;
+#[cfg(test)]
+mod test_payout_caps;
+#[cfg(test)]
+mod test_reassign_escrow;
+#[cfg(test)]
 mod test_reentrancy_guard;
 #[cfg(test)]
+mod test_reentrancy_lock;
+#[cfg(test)]
+mod test_referrals;
+#[cfg(test)]
+mod test_refund_approval_expiry;
+#[cfg(test)]
+mod test_refund_destination;
+#[cfg(test)]
+mod test_refund_grace_period;
+#[cfg(test)]
+mod test_release_fees;
+#[cfg(test)]
+mod test_release_policy;
+#[cfg(test)]
+mod test_release_rate_limit;
+#[cfg(test)]
+mod test_release_split;
+#[cfg(test)]
+mod test_release_vouchers;
+#[cfg(test)]
+mod test_rescue_request;
+#[cfg(test)]
+mod test_rescue_tokens;
+#[cfg(test)]
+mod test_review_timeout;
+#[cfg(test)]
+mod test_roles;
+// Also built with the `testutils` feature, which exports `escrow_test_setup!`
+// for `escrow-testutils`.
+#[cfg(any(test, feature = "testutils"))]
+mod test_setup;
+#[cfg(test)]
+mod test_split_refund;
+#[cfg(test)]
+mod test_stale_escrow_sweep;
+#[cfg(test)]
+mod test_storage_migration;
+#[cfg(test)]
+mod test_storage_policy;
+#[cfg(test)]
+mod test_submit_work;
+#[cfg(test)]
+mod test_treasury_acceptance;
+#[cfg(test)]
+mod test_upgrade;
+#[cfg(test)]
+mod test_vesting_stream;
+#[cfg(test)]
+mod test_yield_strategy;
+#[cfg(test)]
 mod escrow_status_transition_tests {
     use super::*;
     use soroban_sdk::{
@@ -3978,6 +12508,8 @@ mod escrow_status_transition_tests {
             status,
             deadline,
             refund_history: vec![env],
+            metadata_hash: None,
+            label: None,
         }
     }
 
@@ -3987,7 +12519,6 @@ mod escrow_status_transition_tests {
         contract_id: Address,
         client: BountyEscrowContractClient<'static>,
         token_admin: token::StellarAssetClient<'static>,
-        admin: Address,
         depositor: Address,
         contributor: Address,
     }
@@ -4001,7 +12532,9 @@ mod escrow_status_transition_tests {
             let depositor = Address::generate(&env);
             let contributor = Address::generate(&env);
 
-            let token_id = env.register_stellar_asset_contract(admin.clone());
+            let token_id = env
+                .register_stellar_asset_contract_v2(admin.clone())
+                .address();
             let token_admin = token::StellarAssetClient::new(&env, &token_id);
 
             let contract_id = env.register_contract(None, BountyEscrowContract);
@@ -4014,7 +12547,6 @@ mod escrow_status_transition_tests {
                 contract_id,
                 client,
                 token_admin,
-                admin,
                 depositor,
                 contributor,
             }
@@ -4036,10 +12568,7 @@ mod escrow_status_transition_tests {
 
             // Write escrow directly to contract storage
             self.env.as_contract(&self.contract_id, || {
-                self.env
-                    .storage()
-                    .persistent()
-                    .set(&DataKey::Escrow(bounty_id), &escrow);
+                BountyEscrowContract::write_escrow_only(&self.env, bounty_id, &escrow, true);
             });
         }
     }
@@ -4156,48 +12685,50 @@ mod escrow_status_transition_tests {
                     let result = setup
                         .client
                         .try_release_funds(&bounty_id, &setup.contributor);
-                    if case.expected_result.is_ok() {
-                        assert!(
+                    match case.expected_result {
+                        Ok(()) => assert!(
                             result.is_ok(),
                             "Transition '{}' failed: expected Ok but got {:?}",
                             case.label,
                             result
-                        );
-                    } else {
-                        assert!(
-                            result.is_err(),
-                            "Transition '{}' failed: expected Err but got Ok",
-                            case.label
-                        );
-                        assert_eq!(
-                            result.unwrap_err().unwrap(),
-                            case.expected_result.unwrap_err(),
-                            "Transition '{}' failed: mismatched error variant",
-                            case.label
-                        );
+                        ),
+                        Err(expected) => {
+                            assert!(
+                                result.is_err(),
+                                "Transition '{}' failed: expected Err but got Ok",
+                                case.label
+                            );
+                            assert_eq!(
+                                result.unwrap_err().unwrap(),
+                                expected,
+                                "Transition '{}' failed: mismatched error variant",
+                                case.label
+                            );
+                        }
                     }
                 }
                 TransitionAction::Refund => {
                     let result = setup.client.try_refund(&bounty_id);
-                    if case.expected_result.is_ok() {
-                        assert!(
+                    match case.expected_result {
+                        Ok(()) => assert!(
                             result.is_ok(),
                             "Transition '{}' failed: expected Ok but got {:?}",
                             case.label,
                             result
-                        );
-                    } else {
-                        assert!(
-                            result.is_err(),
-                            "Transition '{}' failed: expected Err but got Ok",
-                            case.label
-                        );
-                        assert_eq!(
-                            result.unwrap_err().unwrap(),
-                            case.expected_result.unwrap_err(),
-                            "Transition '{}' failed: mismatched error variant",
-                            case.label
-                        );
+                        ),
+                        Err(expected) => {
+                            assert!(
+                                result.is_err(),
+                                "Transition '{}' failed: expected Err but got Ok",
+                                case.label
+                            );
+                            assert_eq!(
+                                result.unwrap_err().unwrap(),
+                                expected,
+                                "Transition '{}' failed: mismatched error variant",
+                                case.label
+                            );
+                        }
                     }
                 }
             }
@@ -4358,8 +12889,16 @@ mod escrow_status_transition_tests {
     }
 }
 #[cfg(test)]
+mod test_deadline_extension;
+#[cfg(test)]
+mod test_deadline_index;
+#[cfg(test)]
 mod test_deadline_variants;
 #[cfg(test)]
+mod test_quadratic_funding;
+#[cfg(test)]
 mod test_query_filters;
 #[cfg(test)]
+mod test_state_machine;
+#[cfg(test)]
 mod test_status_transitions;
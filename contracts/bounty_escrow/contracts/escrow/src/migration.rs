@@ -0,0 +1,84 @@
+//! Moving escrows between instances.
+//!
+//! Each organization can run its own escrow instance (see the escrow
+//! factory). To move a bounty to another organization, the admins of both
+//! instances first trust each other with `set_migration_peer`. The
+//! depositor then calls `migrate_escrow` on the source instance, which
+//! retires the escrow there like `reassign_escrow` does and hands the
+//! target an `EscrowMigration` through `receive_migrated_escrow`. The target
+//! pulls the remaining funds, which the source authorizes for that call, and
+//! checks that all of them arrived. It re-creates the escrow under the
+//! requested id with the same depositor, amounts, deadline, refund history
+//! and audit trail, along with its assignee, funders, funding goal and
+//! referrer. Both instances must hold the same token.
+//!
+//! Escrows with state tied to the source's balances or flows (a contributor
+//! bond, insurance, invested funds, milestones, or an open claim, release
+//! approval, dispute or stream) can't be migrated.
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contracterror, contracttype, vec, Address, Env, IntoVal, Symbol,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum MigrationError {
+    /// Escrow holds state that can't leave this instance, see the
+    /// `migration` module
+    MigrationNotAllowed = 75,
+    /// Returned when a migrated escrow is not `Locked` or holds no funds
+    MigratedEscrowNotLocked = 84,
+    /// Returned when less than a migrated escrow's remaining amount arrived
+    MigratedFundsMissing = 85,
+}
+
+impl MigrationError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            MigrationError::MigrationNotAllowed => "escrow cannot be migrated to another instance",
+            MigrationError::MigratedEscrowNotLocked => "migrated escrow has no locked funds",
+            MigrationError::MigratedFundsMissing => {
+                "migrated escrow's funds did not arrive in full"
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MigrationKey {
+    /// Present for instances escrows may be migrated to and from
+    Peer(Address),
+}
+
+pub fn is_peer(env: &Env, peer: &Address) -> bool {
+    env.storage()
+        .instance()
+        .has(&MigrationKey::Peer(peer.clone()))
+}
+
+pub fn set_peer(env: &Env, peer: &Address, trusted: bool) {
+    let key = MigrationKey::Peer(peer.clone());
+    if trusted {
+        env.storage().instance().set(&key, &true);
+    } else {
+        env.storage().instance().remove(&key);
+    }
+}
+
+/// Let `target`, called next, pull `amount` of `token` from this contract.
+pub fn authorize_transfer(env: &Env, token: &Address, target: &Address, amount: i128) {
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (env.current_contract_address(), target.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+}
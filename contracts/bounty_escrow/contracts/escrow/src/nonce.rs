@@ -0,0 +1,64 @@
+//! Per-address replay protection for delegated operations.
+//!
+//! Voucher flows let anyone submit an operation on an address's behalf, so
+//! Soroban's own auth nonces don't cover them. Each address instead has the
+//! set of nonces redeemed on its behalf, and every voucher must carry one
+//! that isn't in it yet. Vouchers an address signed for different escrows
+//! can then be redeemed in any order, while `get_nonce` still reports the
+//! highest one so signers know where to continue. Unlike the per-bounty
+//! voucher nonce the set survives the escrow being removed, so vouchers
+//! signed for an earlier escrow under the same id can't be redeemed again.
+
+use crate::storage_policy;
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VoucherError {
+    /// Returned when a voucher nonce was already redeemed
+    VoucherNonceUsed = 48,
+}
+
+impl VoucherError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            VoucherError::VoucherNonceUsed => "voucher nonce was already redeemed",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum NonceKey {
+    /// address -> u64 highest nonce redeemed on its behalf
+    Address(Address),
+    /// (address, nonce) -> () present once the nonce was redeemed
+    Used(Address, u64),
+}
+
+pub fn get(env: &Env, address: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&NonceKey::Address(address.clone()))
+        .unwrap_or(0)
+}
+
+/// Record `nonce` as redeemed for `address`, or return `VoucherNonceUsed` if
+/// it already was.
+pub fn consume(env: &Env, address: &Address, nonce: u64) -> Result<(), VoucherError> {
+    let persistent = env.storage().persistent();
+    let used = NonceKey::Used(address.clone(), nonce);
+    if persistent.has(&used) {
+        return Err(VoucherError::VoucherNonceUsed);
+    }
+    let extend_to = storage_policy::get(env).extend_to;
+    persistent.set(&used, &());
+    persistent.extend_ttl(&used, extend_to, extend_to);
+    if nonce > get(env, address) {
+        let highest = NonceKey::Address(address.clone());
+        persistent.set(&highest, &nonce);
+        persistent.extend_ttl(&highest, extend_to, extend_to);
+    }
+    Ok(())
+}
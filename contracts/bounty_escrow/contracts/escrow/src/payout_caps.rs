@@ -0,0 +1,115 @@
+//! Per-escrow partial release caps.
+//!
+//! A depositor locking with `lock_funds_with_payout_caps` can bound how the
+//! escrow is paid out in increments: `max_release` caps a single partial
+//! release, and `max_cumulative` caps what partial releases may pay in total
+//! before the depositor reviews the work so far with `approve_payout_review`,
+//! which starts the count over. Partial releases past either cap fail with
+//! `PayoutCapExceeded` or `PayoutReviewRequired`. A full release pays out
+//! the rest in one step and is not capped.
+
+use crate::escrow_entries::{self, Entry};
+use crate::PayoutCaps;
+use soroban_sdk::{contracterror, contracttype, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum PayoutCapError {
+    /// Partial release is larger than the escrow's per-release cap
+    PayoutCapExceeded = 79,
+    /// Partial releases reached the escrow's cumulative cap and the depositor
+    /// has to approve a payout review first
+    PayoutReviewRequired = 80,
+}
+
+impl PayoutCapError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            PayoutCapError::PayoutCapExceeded => {
+                "partial release exceeds the escrow's per-release cap"
+            }
+            PayoutCapError::PayoutReviewRequired => {
+                "partial releases reached the cap until a payout review"
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutCapKey {
+    /// bounty_id -> PayoutCaps
+    Caps(u64),
+    /// bounty_id -> i128 paid by partial releases since the last review
+    Released(u64),
+}
+
+pub fn get(env: &Env, bounty_id: u64) -> Option<PayoutCaps> {
+    escrow_entries::get(env, bounty_id, Entry::PayoutCaps)
+}
+
+pub fn set(env: &Env, bounty_id: u64, caps: &PayoutCaps) {
+    escrow_entries::set(env, bounty_id, Entry::PayoutCaps, caps);
+}
+
+pub fn released(env: &Env, bounty_id: u64) -> i128 {
+    escrow_entries::get(env, bounty_id, Entry::CapReleased).unwrap_or(0)
+}
+
+/// Released amount `amount` more would bring `bounty_id` to, or the error a
+/// partial release of `amount` fails with. A cap of 0 is not enforced.
+fn after(env: &Env, bounty_id: u64, amount: i128) -> Result<Option<i128>, PayoutCapError> {
+    let caps = match get(env, bounty_id) {
+        Some(caps) => caps,
+        None => return Ok(None),
+    };
+    if caps.max_release > 0 && amount > caps.max_release {
+        return Err(PayoutCapError::PayoutCapExceeded);
+    }
+    let released = released(env, bounty_id).saturating_add(amount);
+    if caps.max_cumulative > 0 && released > caps.max_cumulative {
+        return Err(PayoutCapError::PayoutReviewRequired);
+    }
+    Ok(Some(released))
+}
+
+/// Check a partial release of `amount` from `bounty_id` against its caps
+/// without recording it.
+pub fn check(env: &Env, bounty_id: u64, amount: i128) -> Result<(), PayoutCapError> {
+    after(env, bounty_id, amount).map(|_| ())
+}
+
+/// Count a partial release of `amount` from `bounty_id` against its caps.
+/// Records nothing when a cap would be exceeded.
+pub fn consume(env: &Env, bounty_id: u64, amount: i128) -> Result<(), PayoutCapError> {
+    if let Some(released) = after(env, bounty_id, amount)? {
+        escrow_entries::set(env, bounty_id, Entry::CapReleased, &released);
+    }
+    Ok(())
+}
+
+/// Start the cumulative count of `bounty_id` over, returning what had been
+/// released since the previous review.
+pub fn review(env: &Env, bounty_id: u64) -> i128 {
+    let released = released(env, bounty_id);
+    escrow_entries::remove(env, bounty_id, Entry::CapReleased);
+    released
+}
+
+/// Move the caps of `from` to `to`, e.g. when an escrow is reassigned.
+pub fn transfer(env: &Env, from: u64, to: u64) {
+    if let Some(caps) = get(env, from) {
+        set(env, to, &caps);
+        let released = released(env, from);
+        if released > 0 {
+            escrow_entries::set(env, to, Entry::CapReleased, &released);
+        }
+        remove(env, from);
+    }
+}
+
+pub fn remove(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::PayoutCaps);
+    escrow_entries::remove(env, bounty_id, Entry::CapReleased);
+}
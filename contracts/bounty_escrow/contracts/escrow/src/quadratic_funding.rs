@@ -0,0 +1,149 @@
+//! Quadratic funding rounds.
+//!
+//! The admin opens a round over a set of locked escrows with
+//! `create_funding_round`, depositing a matching pool. Until the round ends
+//! anyone can back those bounties with `contribute_in_round`, which adds to
+//! the escrow like `contribute_to_escrow` and counts the contribution
+//! towards the round. Once the round has ended, `close_funding_round`
+//! splits the pool between the bounties in proportion to
+//!
+//! ```text
+//! (sum of sqrt(contribution))^2 - sum of contribution
+//! ```
+//!
+//! so many small backers attract more matching than one large one. Each
+//! match is added to its escrow as a contribution of the round's sponsor;
+//! whatever cannot be pushed (rounding, bounties no longer locked, no
+//! contributions at all) goes back to the sponsor.
+
+use crate::FundingRound;
+use soroban_sdk::{contracterror, contracttype, Address, Env, Map};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum RoundError {
+    /// No quadratic funding round with that id
+    NotFound = 68,
+    /// Funding round has ended or was already closed
+    Closed = 69,
+    /// Funding round cannot be closed before it ends
+    StillOpen = 70,
+}
+
+impl RoundError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            RoundError::NotFound => "funding round not found",
+            RoundError::Closed => "funding round has ended or is closed",
+            RoundError::StillOpen => "funding round has not ended yet",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundKey {
+    /// u64 id the next round gets
+    NextId,
+    /// round_id -> FundingRound
+    Round(u64),
+    /// (round_id, bounty_id) -> Map<Address, i128> of contributions in the round
+    Contributions(u64, u64),
+    /// i128 matching funds held for rounds not yet closed, counted in the
+    /// tracked balance
+    TotalPooled,
+}
+
+pub fn get(env: &Env, round_id: u64) -> Option<FundingRound> {
+    env.storage().persistent().get(&RoundKey::Round(round_id))
+}
+
+pub fn set(env: &Env, round_id: u64, round: &FundingRound) {
+    env.storage()
+        .persistent()
+        .set(&RoundKey::Round(round_id), round);
+}
+
+/// Store `round` under a fresh id and return the id.
+pub fn create(env: &Env, round: &FundingRound) -> u64 {
+    let round_id: u64 = env.storage().instance().get(&RoundKey::NextId).unwrap_or(1);
+    env.storage()
+        .instance()
+        .set(&RoundKey::NextId, &(round_id + 1));
+    set(env, round_id, round);
+    round_id
+}
+
+/// Matching funds the contract holds for open rounds.
+pub fn total_pooled(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&RoundKey::TotalPooled)
+        .unwrap_or(0)
+}
+
+pub fn add_pooled(env: &Env, amount: i128) {
+    env.storage()
+        .instance()
+        .set(&RoundKey::TotalPooled, &(total_pooled(env) + amount));
+}
+
+pub fn contributions(env: &Env, round_id: u64, bounty_id: u64) -> Map<Address, i128> {
+    env.storage()
+        .persistent()
+        .get(&RoundKey::Contributions(round_id, bounty_id))
+        .unwrap_or(Map::new(env))
+}
+
+pub fn record(env: &Env, round_id: u64, bounty_id: u64, funder: &Address, amount: i128) {
+    let mut all = contributions(env, round_id, bounty_id);
+    let current = all.get(funder.clone()).unwrap_or(0);
+    all.set(funder.clone(), current + amount);
+    env.storage()
+        .persistent()
+        .set(&RoundKey::Contributions(round_id, bounty_id), &all);
+}
+
+/// Each bounty's share of the pool of `round`, before checking whether it
+/// can still receive it. Bounties without a quadratic score get nothing.
+pub fn allocate(env: &Env, round_id: u64, round: &FundingRound) -> Map<u64, i128> {
+    let mut scores = Map::new(env);
+    let mut total_score = 0i128;
+    for bounty_id in round.bounty_ids.iter() {
+        let mut root_sum = 0i128;
+        let mut sum = 0i128;
+        for (_, amount) in contributions(env, round_id, bounty_id).iter() {
+            root_sum += isqrt(amount);
+            sum += amount;
+        }
+        let score = (root_sum * root_sum - sum).max(0);
+        scores.set(bounty_id, score);
+        total_score += score;
+    }
+
+    let mut matches = Map::new(env);
+    for (bounty_id, score) in scores.iter() {
+        let amount = if total_score > 0 {
+            round.matching_pool * score / total_score
+        } else {
+            0
+        };
+        matches.set(bounty_id, amount);
+    }
+    matches
+}
+
+/// Largest integer whose square does not exceed `n` (0 for `n <= 0`).
+fn isqrt(n: i128) -> i128 {
+    if n <= 0 {
+        return 0;
+    }
+    let mut x = n;
+    let mut y = (x + 1) / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
@@ -0,0 +1,76 @@
+//! # Role-Based Access Control
+//!
+//! Lets the admin delegate operational duties to separate addresses so that
+//! large bounty programs do not need to share a single admin key.
+//!
+//! ## Roles
+//!
+//! - `Admin`    – may grant and revoke roles
+//! - `Releaser` – may release funds to contributors
+//! - `Rescuer`  – may withdraw funds while the contract is paused
+//! - `Pauser`   – may pause and unpause lock / release / refund
+//!
+//! The address stored under `DataKey::Admin` implicitly holds every role, so
+//! existing admin-only entry points keep working unchanged.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    Admin,
+    Releaser,
+    Rescuer,
+    Pauser,
+}
+
+/// Returns `true` if `account` is the admin or has been granted `role`.
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+    if admin.as_ref() == Some(account) {
+        return true;
+    }
+    env.storage()
+        .persistent()
+        .has(&DataKey::Role(role, account.clone()))
+}
+
+pub fn grant(env: &Env, role: Role, account: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Role(role, account.clone()), &true);
+}
+
+pub fn revoke(env: &Env, role: Role, account: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Role(role, account.clone()));
+}
+
+/// Check that `caller` holds `role` and require its authorization.
+pub fn require_role(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::NotInitialized);
+    }
+    if !has_role(env, role, caller) {
+        return Err(Error::Unauthorized);
+    }
+    caller.require_auth();
+    Ok(())
+}
+
+/// Authorize an operation either as an explicit role holder or, when
+/// `caller` is `None`, as the stored admin. Returns the authorized address.
+pub fn authorize(env: &Env, caller: Option<Address>, role: Role) -> Result<Address, Error> {
+    let caller = match caller {
+        Some(caller) => caller,
+        None => env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?,
+    };
+    require_role(env, &caller, role)?;
+    Ok(caller)
+}
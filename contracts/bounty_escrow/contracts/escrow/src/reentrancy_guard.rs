@@ -54,8 +54,7 @@ pub fn release(env: &Env) {
     env.storage().instance().remove(&DataKey::ReentrancyGuard);
 }
 
-/// Check whether the guard is currently held (useful in tests).
-#[cfg(test)]
+/// Check whether the guard is currently held.
 pub fn is_active(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::ReentrancyGuard)
 }
@@ -0,0 +1,86 @@
+//! Referral fees.
+//!
+//! The depositor can record who referred a bounty, either when locking it
+//! with `lock_funds_with_referrer` or when assigning it with
+//! `assign_contributor_with_referrer`. The referral fee rate set by the
+//! admin with `set_referral_fee` is snapshotted at that point, like the
+//! release fee, so later changes do not apply retroactively. Every release
+//! payout of the escrow then sends that many basis points of the payout to
+//! the referrer, taken out of the contributor's share, and adds it to the
+//! referrer's lifetime earnings.
+
+use crate::escrow_entries::{self, Entry};
+use crate::Referral;
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReferralError {
+    /// Escrow already has a referrer recorded
+    ReferrerAlreadySet = 74,
+}
+
+impl ReferralError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReferralError::ReferrerAlreadySet => "escrow already has a referrer",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReferralKey {
+    /// u32 referral fee in basis points for newly recorded referrers
+    Rate,
+    /// bounty_id -> Referral
+    Referral(u64),
+    /// referrer -> i128 referral fees paid out to them so far
+    Earnings(Address),
+}
+
+pub fn rate(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&ReferralKey::Rate)
+        .unwrap_or(0)
+}
+
+pub fn set_rate(env: &Env, fee_bps: u32) {
+    env.storage().instance().set(&ReferralKey::Rate, &fee_bps);
+}
+
+pub fn get(env: &Env, bounty_id: u64) -> Option<Referral> {
+    escrow_entries::get(env, bounty_id, Entry::Referral)
+}
+
+pub fn set(env: &Env, bounty_id: u64, referral: &Referral) {
+    escrow_entries::set(env, bounty_id, Entry::Referral, referral);
+}
+
+pub fn earnings(env: &Env, referrer: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&ReferralKey::Earnings(referrer.clone()))
+        .unwrap_or(0)
+}
+
+pub fn add_earnings(env: &Env, referrer: &Address, amount: i128) {
+    let key = ReferralKey::Earnings(referrer.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &(earnings(env, referrer) + amount));
+}
+
+/// Move the referral of `from` to `to`, e.g. when an escrow is reassigned.
+pub fn transfer(env: &Env, from: u64, to: u64) {
+    if let Some(referral) = get(env, from) {
+        set(env, to, &referral);
+        remove(env, from);
+    }
+}
+
+pub fn remove(env: &Env, bounty_id: u64) {
+    escrow_entries::remove(env, bounty_id, Entry::Referral);
+}
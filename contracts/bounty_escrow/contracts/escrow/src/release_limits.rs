@@ -0,0 +1,109 @@
+//! Release rate limits.
+//!
+//! The admin can cap how much may be released within a rolling window with
+//! `set_release_rate_limit`, both across the contract and per bounty. It
+//! bounds the damage if an approver or releaser key is compromised: full
+//! and partial releases past the cap fail with `ReleaseRateLimited` until
+//! the window restarts, which happens on the first release after `window`
+//! seconds have passed.
+
+use crate::{Error, ReleaseRateLimit, ReleaseWindow};
+use soroban_sdk::{contracttype, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseLimitKey {
+    /// ReleaseRateLimit set by the admin
+    RateLimit,
+    /// ReleaseWindow of contract-wide releases
+    GlobalWindow,
+    /// bounty_id -> ReleaseWindow of that bounty's releases
+    EscrowWindow(u64),
+}
+
+pub fn get(env: &Env) -> Option<ReleaseRateLimit> {
+    env.storage().instance().get(&ReleaseLimitKey::RateLimit)
+}
+
+pub fn set(env: &Env, limit: Option<ReleaseRateLimit>) {
+    match limit {
+        Some(limit) => env
+            .storage()
+            .instance()
+            .set(&ReleaseLimitKey::RateLimit, &limit),
+        None => env.storage().instance().remove(&ReleaseLimitKey::RateLimit),
+    }
+}
+
+/// Amount released in the window stored under `key` as of now; a window
+/// whose time has passed starts over.
+fn current(env: &Env, key: &ReleaseLimitKey, window: u64) -> ReleaseWindow {
+    let now = env.ledger().timestamp();
+    let stored: Option<ReleaseWindow> = env.storage().persistent().get(key);
+    match stored {
+        Some(state) if now < state.start.saturating_add(window) => state,
+        _ => ReleaseWindow {
+            start: now,
+            released: 0,
+        },
+    }
+}
+
+/// Count `amount` released from `bounty_id` against the configured limits.
+/// Returns `ReleaseRateLimited` without recording anything when either
+/// limit would be exceeded. A limit of 0 is not enforced.
+pub fn consume(env: &Env, bounty_id: u64, amount: i128) -> Result<(), Error> {
+    let limit = match get(env) {
+        Some(limit) => limit,
+        None => return Ok(()),
+    };
+    let mut updates = [
+        (ReleaseLimitKey::GlobalWindow, limit.global_limit, None),
+        (ReleaseLimitKey::EscrowWindow(bounty_id), limit.escrow_limit, None),
+    ];
+    for (key, max, update) in updates.iter_mut() {
+        if *max == 0 {
+            continue;
+        }
+        let mut state = current(env, key, limit.window);
+        state.released = state
+            .released
+            .checked_add(amount)
+            .ok_or(Error::InvalidAmount)?;
+        if state.released > *max {
+            return Err(Error::ReleaseRateLimited);
+        }
+        *update = Some(state);
+    }
+    for (key, _, update) in updates.iter() {
+        if let Some(state) = update {
+            env.storage().persistent().set(key, state);
+        }
+    }
+    Ok(())
+}
+
+/// What can still be released from `bounty_id` in the current windows, or
+/// `None` if neither limit applies.
+pub fn remaining(env: &Env, bounty_id: u64) -> Option<i128> {
+    let limit = get(env)?;
+    let mut remaining: Option<i128> = None;
+    for (key, max) in [
+        (ReleaseLimitKey::GlobalWindow, limit.global_limit),
+        (ReleaseLimitKey::EscrowWindow(bounty_id), limit.escrow_limit),
+    ] {
+        if max == 0 {
+            continue;
+        }
+        let left = (max - current(env, &key, limit.window).released).max(0);
+        remaining = Some(remaining.map_or(left, |r| r.min(left)));
+    }
+    remaining
+}
+
+/// Drop the window kept for `bounty_id`.
+pub fn remove_escrow(env: &Env, bounty_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&ReleaseLimitKey::EscrowWindow(bounty_id));
+}
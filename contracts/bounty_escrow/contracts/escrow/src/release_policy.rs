@@ -0,0 +1,67 @@
+//! Release-to-depositor guard.
+//!
+//! A release that pays an escrow back to its own depositor works like a
+//! fee-free refund that skips `approve_refund`, so full, partial and batch
+//! releases to the depositor fail with `ReleaseToDepositor` unless the
+//! admin allows them with `set_release_to_depositor_allowed`.
+
+use crate::Escrow;
+use soroban_sdk::{contracterror, contracttype, Address, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ReleasePolicyError {
+    /// Release would pay the escrow back to its depositor, which is off
+    /// unless the admin allows it
+    ReleaseToDepositor = 83,
+}
+
+impl ReleasePolicyError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            ReleasePolicyError::ReleaseToDepositor => {
+                "release to the escrow's own depositor is not allowed"
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleasePolicyKey {
+    /// bool, true if releases to the depositor are allowed
+    DepositorAllowed,
+}
+
+pub fn depositor_allowed(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&ReleasePolicyKey::DepositorAllowed)
+        .unwrap_or(false)
+}
+
+pub fn set_depositor_allowed(env: &Env, allowed: bool) {
+    if allowed {
+        env.storage()
+            .instance()
+            .set(&ReleasePolicyKey::DepositorAllowed, &true);
+    } else {
+        env.storage()
+            .instance()
+            .remove(&ReleasePolicyKey::DepositorAllowed);
+    }
+}
+
+/// Returns `ReleaseToDepositor` if `contributor` is the escrow's depositor
+/// and the admin hasn't allowed that.
+pub fn ensure_allowed(
+    env: &Env,
+    escrow: &Escrow,
+    contributor: &Address,
+) -> Result<(), ReleasePolicyError> {
+    if *contributor == escrow.depositor && !depositor_allowed(env) {
+        return Err(ReleasePolicyError::ReleaseToDepositor);
+    }
+    Ok(())
+}
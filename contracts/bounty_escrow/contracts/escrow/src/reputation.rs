@@ -0,0 +1,68 @@
+//! Optional reporting of escrow outcomes to an external reputation contract.
+//!
+//! When the admin configures a reputation contract (`set_reputation_contract`)
+//! the escrow reports every payout, refund and lost dispute to it. Reports
+//! are best effort: they use `try_` calls so a failing or misconfigured
+//! reputation contract can never block funds from moving.
+
+use crate::{BountyEscrowContract, DataKey, EscrowStatus};
+use soroban_sdk::{contractclient, Address, Env};
+
+/// Interface the escrow expects from a reputation contract. The escrow
+/// passes its own address as `reporter`.
+#[allow(dead_code)]
+#[contractclient(name = "ReputationClient")]
+pub trait ReputationInterface {
+    fn record_release(
+        env: Env,
+        reporter: Address,
+        contributor: Address,
+        bounty_id: u64,
+        amount: i128,
+        completed: bool,
+    );
+    fn record_refund(env: Env, reporter: Address, recipient: Address, bounty_id: u64, amount: i128);
+    fn record_dispute_lost(env: Env, reporter: Address, party: Address, bounty_id: u64);
+}
+
+fn client(env: &Env) -> Option<ReputationClient<'_>> {
+    env.storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::ReputationContract)
+        .map(|address| ReputationClient::new(env, &address))
+}
+
+/// Report a payout of `amount` to `contributor`. The bounty counts as
+/// completed when the escrow has been fully released.
+pub fn report_release(env: &Env, bounty_id: u64, contributor: &Address, amount: i128) {
+    if let Some(client) = client(env) {
+        let completed = BountyEscrowContract::load_escrow(env, bounty_id)
+            .is_some_and(|escrow| escrow.status == EscrowStatus::Released);
+        let _ = client.try_record_release(
+            &env.current_contract_address(),
+            contributor,
+            &bounty_id,
+            &amount,
+            &completed,
+        );
+    }
+}
+
+/// Report a refund of `amount` to `recipient`.
+pub fn report_refund(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+    if let Some(client) = client(env) {
+        let _ = client.try_record_refund(
+            &env.current_contract_address(),
+            recipient,
+            &bounty_id,
+            &amount,
+        );
+    }
+}
+
+/// Report that `party` lost the dispute on `bounty_id`.
+pub fn report_dispute_lost(env: &Env, bounty_id: u64, party: &Address) {
+    if let Some(client) = client(env) {
+        let _ = client.try_record_dispute_lost(&env.current_contract_address(), party, &bounty_id);
+    }
+}
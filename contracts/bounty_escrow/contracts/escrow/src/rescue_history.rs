@@ -0,0 +1,54 @@
+//! Audit trail of `rescue_untracked_tokens` calls.
+//!
+//! Every call, including one that found nothing to rescue, appends a
+//! `RescueRecord` read back page by page with `get_rescue_history`. Records
+//! are kept one per entry under a running count so the trail can grow
+//! without rewriting it, and written with the `TtlPolicy` extension.
+
+use crate::{storage_policy, RescueRecord};
+use soroban_sdk::{contracttype, Env, Vec};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RescueHistoryKey {
+    /// u32 number of records
+    Count,
+    /// index -> RescueRecord, oldest first
+    Entry(u32),
+}
+
+pub fn count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&RescueHistoryKey::Count)
+        .unwrap_or(0)
+}
+
+pub fn push(env: &Env, record: &RescueRecord) {
+    let index = count(env);
+    let key = RescueHistoryKey::Entry(index);
+    let extend_to = storage_policy::get(env).extend_to;
+    env.storage().persistent().set(&key, record);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, extend_to, extend_to);
+    env.storage()
+        .instance()
+        .set(&RescueHistoryKey::Count, &(index + 1));
+}
+
+/// Up to `limit` records from `offset`, oldest first.
+pub fn page(env: &Env, offset: u32, limit: u32) -> Vec<RescueRecord> {
+    let end = offset.saturating_add(limit).min(count(env));
+    let mut records = Vec::new(env);
+    for index in offset.min(end)..end {
+        if let Some(record) = env
+            .storage()
+            .persistent()
+            .get(&RescueHistoryKey::Entry(index))
+        {
+            records.push_back(record);
+        }
+    }
+    records
+}
@@ -0,0 +1,53 @@
+//! Progress of a paged `migrate` run.
+//!
+//! Each converter walks the stored escrow ids a page at a time and records
+//! how far it got, so the next call resumes there. The end of its walk is
+//! fixed when it starts: ids the converter itself appends to the index are
+//! already in the new layout.
+
+use soroban_sdk::{contracterror, contracttype, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum SchemaMigrationError {
+    /// Returned when `migrate` is called with a cursor other than the one
+    /// the previous page returned
+    MigrationCursorMismatch = 90,
+}
+
+impl SchemaMigrationError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            SchemaMigrationError::MigrationCursorMismatch => {
+                "migration cursor does not match the stored progress"
+            }
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SchemaMigrationKey {
+    /// schema version the converter starts from -> (position reached,
+    /// position the walk ends at)
+    Progress(u32),
+}
+
+pub fn progress(env: &Env, version: u32) -> Option<(u32, u32)> {
+    env.storage()
+        .instance()
+        .get(&SchemaMigrationKey::Progress(version))
+}
+
+pub fn set_progress(env: &Env, version: u32, position: u32, end: u32) {
+    env.storage()
+        .instance()
+        .set(&SchemaMigrationKey::Progress(version), &(position, end));
+}
+
+pub fn clear_progress(env: &Env, version: u32) {
+    env.storage()
+        .instance()
+        .remove(&SchemaMigrationKey::Progress(version));
+}
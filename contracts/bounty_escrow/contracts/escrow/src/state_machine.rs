@@ -0,0 +1,56 @@
+//! Escrow status machine.
+//!
+//! Every status change goes through `transition`, which looks it up in the
+//! table below and fails with `FundsNotLocked` when it isn't allowed;
+//! `save_escrow` publishes a `status_changed` event when it stores the new
+//! status. Entry points check the transition
+//! they are about to make with `ensure_allowed` before doing any work, so
+//! the error callers see is unchanged.
+//!
+//! | From \ event        | Release    | Refund     | PartialRefund       | Cancel      |
+//! |---------------------|------------|------------|---------------------|-------------|
+//! | `Locked`            | `Released` | `Refunded` | `PartiallyRefunded` | `Cancelled` |
+//! | `PartiallyRefunded` | -          | `Refunded` | `PartiallyRefunded` | -           |
+//! | `Released`          | -          | -          | -                   | -           |
+//! | `Refunded`          | -          | -          | -                   | -           |
+//! | `Cancelled`         | -          | -          | -                   | -           |
+//!
+//! `Release` is the release of whatever remains, by a full release or the
+//! last partial one; a partial release that leaves funds in the escrow
+//! keeps it `Locked` and is checked with `ensure_allowed(.., Release)`.
+//! New escrows start `Locked` without a transition.
+
+use crate::{Error, Escrow, EscrowStatus};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusEvent {
+    Release,
+    Refund,
+    PartialRefund,
+    Cancel,
+}
+
+/// The status `event` moves an escrow in `from` to, or `None` if the table
+/// doesn't allow it.
+pub fn next(from: &EscrowStatus, event: StatusEvent) -> Option<EscrowStatus> {
+    use EscrowStatus::*;
+    match (from, event) {
+        (Locked, StatusEvent::Release) => Some(Released),
+        (Locked | PartiallyRefunded, StatusEvent::Refund) => Some(Refunded),
+        (Locked | PartiallyRefunded, StatusEvent::PartialRefund) => Some(PartiallyRefunded),
+        (Locked, StatusEvent::Cancel) => Some(Cancelled),
+        _ => None,
+    }
+}
+
+pub fn ensure_allowed(escrow: &Escrow, event: StatusEvent) -> Result<(), Error> {
+    next(&escrow.status, event)
+        .map(|_| ())
+        .ok_or(Error::FundsNotLocked)
+}
+
+/// Apply `event` to the status of `escrow`, which the caller then saves.
+pub fn transition(escrow: &mut Escrow, event: StatusEvent) -> Result<(), Error> {
+    escrow.status = next(&escrow.status, event).ok_or(Error::FundsNotLocked)?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! Storage placement and TTL extension.
+//!
+//! Where each kind of data lives:
+//!
+//! | Storage    | Data                                                      |
+//! |------------|-----------------------------------------------------------|
+//! | instance   | contract-wide config: admin, token, fees, pause flags,    |
+//! |            | limits, counters and the reentrancy guard                 |
+//! | persistent | per-escrow records and settings, history, and the escrow, |
+//! |            | depositor, status and deadline indexes                    |
+//! | temporary  | nothing - every entry is needed to settle funds, so none  |
+//! |            | may be lost to expiry                                     |
+//!
+//! Instance entries share the contract instance's TTL and are paid for on
+//! every call, so only small, frequently read values belong there.
+//! Persistent entries of an escrow are extended together by
+//! `bump_escrow_ttl` following the `TtlPolicy` set with `set_ttl_policy`:
+//! once fewer than `threshold` ledgers remain they are extended to
+//! `extend_to`. Operators trade rent against how often escrows need
+//! touching by tuning the two, and can extend many escrows at once with
+//! `bump_all`.
+
+use crate::TtlPolicy;
+use soroban_sdk::{contracterror, contracttype, Env};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TtlPolicyError {
+    /// TTL policy does not extend past its threshold or exceeds the
+    /// network's maximum entry TTL
+    InvalidTtlPolicy = 64,
+}
+
+impl TtlPolicyError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            TtlPolicyError::InvalidTtlPolicy => "TTL policy threshold or extension is out of range",
+        }
+    }
+}
+
+/// Roughly one day of ledgers at 5s close time.
+const LEDGERS_PER_DAY: u32 = 17_280;
+/// Escrow records are re-extended once fewer than this many ledgers remain.
+const DEFAULT_THRESHOLD: u32 = 30 * LEDGERS_PER_DAY;
+/// Ledgers an escrow record is kept live for after each extension (clamped
+/// by the network's maximum entry TTL).
+const DEFAULT_EXTEND_TO: u32 = 180 * LEDGERS_PER_DAY;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PolicyKey {
+    /// TtlPolicy set by the admin
+    Ttl,
+}
+
+/// The TTL policy in force: the admin's, or the defaults.
+pub fn get(env: &Env) -> TtlPolicy {
+    env.storage()
+        .instance()
+        .get(&PolicyKey::Ttl)
+        .unwrap_or(TtlPolicy {
+            threshold: DEFAULT_THRESHOLD,
+            extend_to: DEFAULT_EXTEND_TO,
+        })
+}
+
+/// Store `policy`, or go back to the defaults with `None`.
+pub fn set(env: &Env, policy: Option<TtlPolicy>) {
+    match policy {
+        Some(policy) => env.storage().instance().set(&PolicyKey::Ttl, &policy),
+        None => env.storage().instance().remove(&PolicyKey::Ttl),
+    }
+}
+
+/// Whether `policy` can be applied on this network: it must extend past its
+/// own threshold and no further than the maximum entry TTL.
+pub fn is_valid(env: &Env, policy: &TtlPolicy) -> bool {
+    let max_ttl = env
+        .ledger()
+        .max_live_until_ledger()
+        .saturating_sub(env.ledger().sequence());
+    policy.threshold < policy.extend_to && policy.extend_to <= max_ttl
+}
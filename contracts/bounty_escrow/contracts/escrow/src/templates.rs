@@ -0,0 +1,72 @@
+//! Admin-defined escrow templates for `lock_funds_from_template`.
+//!
+//! Programs that post many identical bounties store the shared parameters
+//! once (deadline offset, release fee rate, approver, milestone schedule)
+//! and lock each bounty with just a template id and an amount.
+
+use crate::{token_math, EscrowTemplate, Milestone};
+use soroban_sdk::{contracterror, contracttype, Env, Vec};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TemplateError {
+    /// Returned when no escrow template exists for the given id
+    TemplateNotFound = 52,
+}
+
+impl TemplateError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            TemplateError::TemplateNotFound => "no escrow template has this id",
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TemplateKey {
+    /// template_id -> EscrowTemplate
+    Template(u64),
+}
+
+pub fn get(env: &Env, template_id: u64) -> Option<EscrowTemplate> {
+    env.storage()
+        .persistent()
+        .get(&TemplateKey::Template(template_id))
+}
+
+pub fn set(env: &Env, template_id: u64, template: &EscrowTemplate) {
+    env.storage()
+        .persistent()
+        .set(&TemplateKey::Template(template_id), template);
+}
+
+pub fn remove(env: &Env, template_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&TemplateKey::Template(template_id));
+}
+
+/// Split `amount` over the template's milestone schedule, starting at
+/// `now`. Rounding dust goes to the last milestone so the parts add up to
+/// `amount`.
+pub fn milestones(env: &Env, template: &EscrowTemplate, amount: i128, now: u64) -> Vec<Milestone> {
+    let mut milestones = Vec::new(env);
+    let mut allocated: i128 = 0;
+    let last = template.milestones.len().saturating_sub(1);
+    for (i, milestone) in template.milestones.iter().enumerate() {
+        let part = if i as u32 == last {
+            amount - allocated
+        } else {
+            token_math::calculate_fee(amount, milestone.share_bps as i128)
+        };
+        allocated += part;
+        milestones.push_back(Milestone {
+            amount: part,
+            description_hash: milestone.description_hash,
+            deadline: now.saturating_add(milestone.deadline_offset),
+        });
+    }
+    milestones
+}
@@ -0,0 +1,275 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, AdminOp, Error, Role, TimelockError};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    members: [Address; 3],
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let env = &base.env;
+        let treasury = Address::generate(env);
+        let members = [
+            Address::generate(env),
+            Address::generate(env),
+            Address::generate(env),
+        ];
+
+        let council = vec![
+            env,
+            members[0].clone(),
+            members[1].clone(),
+            members[2].clone(),
+        ];
+        base.escrow.set_admin_council(&council, &2);
+
+        Self {
+            base,
+            members,
+            treasury,
+        }
+    }
+
+    fn treasury_op(&self) -> AdminOp {
+        AdminOp::UpdateFeeConfig(None, None, Some(self.treasury.clone()), None)
+    }
+}
+
+#[test]
+fn test_council_blocks_single_admin() {
+    let s = Setup::new();
+    let council = s.escrow.get_admin_council().unwrap();
+    assert_eq!(council.threshold, 2);
+    assert_eq!(council.members.len(), 3);
+
+    assert_eq!(
+        s.escrow
+            .try_update_fee_config(&None, &None, &Some(s.treasury.clone()), &None),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        s.escrow.try_set_paused(&Some(true), &None, &None, &None),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        s.escrow.try_request_rescue(&100),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        s.escrow.try_queue_admin_op(&s.treasury_op()),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        s.escrow
+            .try_set_admin_council(&vec![&s.env, s.treasury.clone()], &1),
+        Err(Ok(Error::CouncilRequired))
+    );
+}
+
+#[test]
+fn test_council_blocks_admin_through_role_entry_points() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow
+            .try_set_paused_with_role(&s.admin, &Some(true), &None, &None, &None),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        s.escrow
+            .try_emergency_withdraw_with_role(&s.admin, &s.treasury),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert!(!s.escrow.is_paused());
+}
+
+#[test]
+fn test_council_blocks_admin_emergency_exit_and_rescue_cancel() {
+    let base = TestSetup::new();
+    let env = &base.env;
+    base.lock(1, 1_000);
+    base.escrow.request_rescue(&100);
+    base.escrow.announce_emergency_withdraw_all();
+    let council = vec![env, Address::generate(env), Address::generate(env)];
+    base.escrow.set_admin_council(&council, &2);
+
+    assert_eq!(
+        base.escrow.try_cancel_rescue(),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        base.escrow.try_cancel_emergency_withdraw_all(),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        base.escrow.try_announce_emergency_withdraw_all(),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert_eq!(
+        base.escrow.try_emergency_withdraw_all(&20),
+        Err(Ok(Error::CouncilRequired))
+    );
+    assert!(base.escrow.get_rescue_request().is_some());
+    assert!(base.escrow.get_emergency_exit().is_some());
+}
+
+#[test]
+fn test_roles_change_only_through_the_council() {
+    let s = Setup::new();
+    let pauser = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_grant_role(&s.admin, &Role::Pauser, &pauser),
+        Err(Ok(Error::CouncilRequired))
+    );
+
+    let id = s.escrow.propose_council_op(
+        &s.members[0],
+        &AdminOp::GrantRole(Role::Pauser, pauser.clone()),
+    );
+    s.escrow.approve_council_op(&s.members[1], &id);
+    s.escrow.execute_council_op(&s.members[1], &id);
+    assert!(s.escrow.has_role(&Role::Pauser, &pauser));
+    s.escrow
+        .set_paused_with_role(&pauser, &Some(true), &None, &None, &None);
+    assert!(s.escrow.is_paused());
+
+    assert_eq!(
+        s.escrow.try_revoke_role(&s.admin, &Role::Pauser, &pauser),
+        Err(Ok(Error::CouncilRequired))
+    );
+    let id = s.escrow.propose_council_op(
+        &s.members[0],
+        &AdminOp::RevokeRole(Role::Pauser, pauser.clone()),
+    );
+    s.escrow.approve_council_op(&s.members[2], &id);
+    s.escrow.execute_council_op(&s.members[2], &id);
+    assert!(!s.escrow.has_role(&Role::Pauser, &pauser));
+}
+
+#[test]
+fn test_proposal_executes_at_threshold() {
+    let s = Setup::new();
+    let id = s.escrow.propose_council_op(&s.members[0], &s.treasury_op());
+    assert_eq!(
+        s.escrow.try_execute_council_op(&s.members[0], &id),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    // Approving twice does not count twice.
+    s.escrow.approve_council_op(&s.members[0], &id);
+    assert_eq!(s.escrow.get_council_op(&id).unwrap().approvals.len(), 1);
+
+    s.escrow.approve_council_op(&s.members[1], &id);
+    s.escrow.execute_council_op(&s.members[2], &id);
+    s.escrow.accept_treasury_role();
+    assert_eq!(s.escrow.get_fee_config().fee_recipient, s.treasury);
+    assert_eq!(s.escrow.get_council_op(&id), None);
+    assert_eq!(
+        s.escrow.try_execute_council_op(&s.members[2], &id),
+        Err(Err(TimelockError::AdminOpNotFound.into()))
+    );
+}
+
+#[test]
+fn test_council_pause_waits_for_timelock() {
+    let s = Setup::new();
+    let id = s
+        .escrow
+        .propose_council_op(&s.members[0], &AdminOp::SetTimelockDelay(3_600));
+    s.escrow.approve_council_op(&s.members[1], &id);
+    s.escrow.execute_council_op(&s.members[1], &id);
+    assert_eq!(s.escrow.get_timelock_delay(), 3_600);
+
+    let id = s
+        .escrow
+        .propose_council_op(&s.members[1], &AdminOp::SetPaused(Some(true), None, None));
+    s.escrow.approve_council_op(&s.members[2], &id);
+    assert_eq!(
+        s.escrow.try_execute_council_op(&s.members[2], &id),
+        Err(Err(TimelockError::TimelockNotExpired.into()))
+    );
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 3_600);
+    s.escrow.execute_council_op(&s.members[2], &id);
+    assert!(s.escrow.is_paused());
+}
+
+#[test]
+fn test_removed_members_lose_their_approvals() {
+    let s = Setup::new();
+    let pending = s.escrow.propose_council_op(&s.members[0], &s.treasury_op());
+
+    // Shrink the council to members 1 and 2.
+    let new_council = vec![&s.env, s.members[1].clone(), s.members[2].clone()];
+    let id = s
+        .escrow
+        .propose_council_op(&s.members[1], &AdminOp::SetCouncil(new_council, 2));
+    s.escrow.approve_council_op(&s.members[2], &id);
+    s.escrow.execute_council_op(&s.members[2], &id);
+
+    assert_eq!(
+        s.escrow.try_approve_council_op(&s.members[0], &pending),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.escrow.approve_council_op(&s.members[1], &pending);
+    assert_eq!(
+        s.escrow.try_execute_council_op(&s.members[1], &pending),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    s.escrow.approve_council_op(&s.members[2], &pending);
+    s.escrow.execute_council_op(&s.members[1], &pending);
+    s.escrow.accept_treasury_role();
+    assert_eq!(s.escrow.get_fee_config().fee_recipient, s.treasury);
+}
+
+#[test]
+fn test_invalid_council_and_cancel() {
+    let s = Setup::new();
+    let duplicated = vec![&s.env, s.members[0].clone(), s.members[0].clone()];
+    let id = s
+        .escrow
+        .propose_council_op(&s.members[0], &AdminOp::SetCouncil(duplicated, 1));
+    s.escrow.approve_council_op(&s.members[1], &id);
+    assert_eq!(
+        s.escrow.try_execute_council_op(&s.members[1], &id),
+        Err(Ok(Error::InvalidCouncil))
+    );
+
+    assert_eq!(
+        s.escrow.try_cancel_council_op(&s.members[1], &id),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.escrow.cancel_council_op(&s.members[0], &id);
+    assert_eq!(s.escrow.get_council_op(&id), None);
+
+    assert_eq!(
+        s.escrow
+            .try_propose_council_op(&s.treasury, &s.treasury_op()),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_council_does_not_overwrite_anti_abuse_config() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_anti_abuse_config().window_size, 3600);
+    assert_eq!(s.escrow.get_admin_council().unwrap().threshold, 2);
+}
@@ -0,0 +1,173 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, AdminOp, Error, TimelockError};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+const DELAY: u64 = 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.escrow.set_timelock_delay(&DELAY);
+        Self { base, treasury }
+    }
+
+    fn fee_op(&self) -> AdminOp {
+        AdminOp::UpdateFeeConfig(None, Some(250), Some(self.treasury.clone()), Some(true))
+    }
+}
+
+#[test]
+fn test_direct_sensitive_ops_blocked_while_timelocked() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_timelock_delay(), DELAY);
+
+    let res = s
+        .escrow
+        .try_update_fee_config(&None, &Some(250), &None, &Some(true));
+    assert_eq!(res, Err(Ok(Error::TimelockRequired)));
+
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    let res = s.escrow.try_emergency_withdraw(&s.treasury);
+    assert_eq!(res, Err(Ok(Error::TimelockRequired)));
+
+    let res = s.escrow.try_set_migration_peer(&s.treasury, &true);
+    assert_eq!(res, Err(Ok(Error::TimelockRequired)));
+}
+
+#[test]
+fn test_queued_migration_peer_executes_after_delay() {
+    let s = Setup::new();
+    let peer = Address::generate(&s.env);
+    let op_id = s
+        .escrow
+        .queue_admin_op(&AdminOp::SetMigrationPeer(peer.clone(), true));
+    assert!(!s.escrow.is_migration_peer(&peer));
+
+    s.advance_time(DELAY);
+    s.escrow.execute_admin_op(&op_id);
+    assert!(s.escrow.is_migration_peer(&peer));
+}
+
+#[test]
+fn test_queued_fee_update_executes_after_delay() {
+    let s = Setup::new();
+    let op_id = s.escrow.queue_admin_op(&s.fee_op());
+
+    let queued = s.escrow.get_admin_op(&op_id).unwrap();
+    assert_eq!(queued.op, s.fee_op());
+    assert_eq!(queued.eta, s.env.ledger().timestamp() + DELAY);
+
+    s.advance_time(DELAY - 1);
+    assert_eq!(
+        s.escrow.try_execute_admin_op(&op_id),
+        Err(Err(TimelockError::TimelockNotExpired.into()))
+    );
+
+    s.advance_time(1);
+    s.escrow.execute_admin_op(&op_id);
+    s.escrow.accept_treasury_role();
+    let config = s.escrow.get_fee_config();
+    assert_eq!(config.release_fee_rate, 250);
+    assert_eq!(config.fee_recipient, s.treasury);
+    assert!(config.fee_enabled);
+
+    assert_eq!(s.escrow.get_admin_op(&op_id), None);
+    assert_eq!(
+        s.escrow.try_execute_admin_op(&op_id),
+        Err(Err(TimelockError::AdminOpNotFound.into()))
+    );
+}
+
+#[test]
+fn test_cancel_admin_op() {
+    let s = Setup::new();
+    let op_id = s.escrow.queue_admin_op(&s.fee_op());
+
+    s.escrow.cancel_admin_op(&op_id);
+    assert_eq!(s.escrow.get_admin_op(&op_id), None);
+
+    s.advance_time(DELAY);
+    assert_eq!(
+        s.escrow.try_execute_admin_op(&op_id),
+        Err(Err(TimelockError::AdminOpNotFound.into()))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_admin_op(&op_id),
+        Err(Err(TimelockError::AdminOpNotFound.into()))
+    );
+    assert!(!s.escrow.get_fee_config().fee_enabled);
+}
+
+#[test]
+fn test_lowering_delay_requires_queue() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_set_timelock_delay(&0),
+        Err(Ok(Error::TimelockRequired))
+    );
+    s.escrow.set_timelock_delay(&(DELAY * 2));
+
+    let op_id = s.escrow.queue_admin_op(&AdminOp::SetTimelockDelay(0));
+    s.advance_time(DELAY * 2);
+    s.escrow.execute_admin_op(&op_id);
+    assert_eq!(s.escrow.get_timelock_delay(), 0);
+
+    s.escrow
+        .update_fee_config(&None, &Some(100), &None, &Some(true));
+    assert_eq!(s.escrow.get_fee_config().release_fee_rate, 100);
+}
+
+#[test]
+fn test_queued_rescue_requires_pause_at_execution() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + DELAY * 10;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    let op_id = s
+        .escrow
+        .queue_admin_op(&AdminOp::EmergencyWithdraw(s.treasury.clone()));
+    s.advance_time(DELAY);
+    assert_eq!(
+        s.escrow.try_execute_admin_op(&op_id),
+        Err(Ok(Error::NotPaused))
+    );
+
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.execute_admin_op(&op_id);
+    assert_eq!(s.token.balance(&s.treasury), 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
+
+#[test]
+fn test_rescue_untracked_tokens_waits_for_the_delay() {
+    let s = Setup::new();
+    s.token_admin.mint(&s.escrow.address, &300);
+    assert_eq!(
+        s.escrow.try_rescue_untracked_tokens(&s.token.address),
+        Err(Ok(Error::TimelockRequired))
+    );
+
+    let op_id = s
+        .escrow
+        .queue_admin_op(&AdminOp::RescueUntrackedTokens(s.token.address.clone()));
+    s.advance_time(DELAY);
+    s.escrow.execute_admin_op(&op_id);
+    assert_eq!(s.token.balance(&s.admin), 300);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
@@ -997,7 +997,7 @@ fn test_count_matches_query_by_status_total() {
 
     assert_eq!(
         total_count,
-        (locked.len() + released.len() + refunded.len()) as u32,
+        locked.len() + released.len() + refunded.len(),
         "get_escrow_count must equal sum of all status buckets"
     );
 }
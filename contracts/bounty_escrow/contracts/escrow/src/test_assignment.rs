@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, AssignmentError, CancellationError, Error, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, Address};
+
+const WINDOW: u64 = 3 * 86_400;
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    let deadline = s.env.ledger().timestamp() + 30 * 86_400;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s
+}
+
+#[test]
+fn test_release_requires_acceptance() {
+    let s = setup();
+    s.escrow.assign_contributor(&1, &s.contributor, &WINDOW);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::AssignmentPending))
+    );
+
+    s.escrow.accept_assignment(&1);
+    assert_eq!(s.env.auths()[0].0, s.contributor);
+    let assignment = s.escrow.get_assignment(&1).unwrap();
+    assert_eq!(assignment.accepted_at, Some(s.env.ledger().timestamp()));
+
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &other),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_active_assignment_blocks_reassign_and_cancel() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+    s.escrow.assign_contributor(&1, &s.contributor, &WINDOW);
+
+    assert_eq!(
+        s.escrow.try_assign_contributor(&1, &other, &WINDOW),
+        Err(Ok(Error::AssignmentPending))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Err(CancellationError::CancellationNotAllowed.into()))
+    );
+
+    s.escrow.accept_assignment(&1);
+    s.advance_time(WINDOW + 1);
+    assert_eq!(
+        s.escrow.try_assign_contributor(&1, &other, &WINDOW),
+        Err(Err(AssignmentError::AssignmentAccepted.into()))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Err(CancellationError::CancellationNotAllowed.into()))
+    );
+}
+
+#[test]
+fn test_lapsed_assignment_can_be_reassigned() {
+    let s = setup();
+    s.escrow.assign_contributor(&1, &s.contributor, &WINDOW);
+    s.advance_time(WINDOW + 1);
+    assert_eq!(
+        s.escrow.try_accept_assignment(&1),
+        Err(Err(AssignmentError::AssignmentNotFound.into()))
+    );
+
+    let other = Address::generate(&s.env);
+    s.escrow.assign_contributor(&1, &other, &WINDOW);
+    assert_eq!(s.escrow.get_assignment(&1).unwrap().contributor, other);
+    s.escrow.accept_assignment(&1);
+    s.escrow.release_funds(&1, &other);
+    assert_eq!(s.token.balance(&other), 1_000);
+}
+
+#[test]
+fn test_lapsed_assignment_can_be_cancelled() {
+    let s = setup();
+    s.escrow.assign_contributor(&1, &s.contributor, &WINDOW);
+    s.advance_time(WINDOW + 1);
+
+    s.escrow.cancel_escrow(&1);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Cancelled);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
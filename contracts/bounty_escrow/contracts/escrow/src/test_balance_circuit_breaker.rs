@@ -0,0 +1,150 @@
+#![cfg(test)]
+
+use crate::{events::BalanceInvariantViolated, test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, Symbol, TryFromVal, Vec,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.lock(1, 1_000);
+        base.lock(2, 1_000);
+        Self { base }
+    }
+
+    /// Move tokens out of the escrow behind its back, as a clawback or an
+    /// exploit in the token would.
+    fn leak(&self, amount: i128) {
+        let thief = Address::generate(&self.env);
+        self.token.transfer(&self.escrow.address, &thief, &amount);
+    }
+
+    fn alerts(&self) -> Vec<BalanceInvariantViolated> {
+        let mut alerts = Vec::new(&self.env);
+        for (contract, topics, data) in self.env.events().all().iter() {
+            if contract == self.escrow.address
+                && Symbol::try_from_val(&self.env, &topics.get(0).unwrap())
+                    == Ok(symbol_short!("inv_bal"))
+            {
+                alerts.push_back(BalanceInvariantViolated::try_from_val(&self.env, &data).unwrap());
+            }
+        }
+        alerts
+    }
+}
+
+#[test]
+fn test_covered_balance_does_not_trip_breaker() {
+    let s = Setup::new();
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    assert!(s.alerts().is_empty());
+    s.escrow.release_funds(&2, &s.contributor);
+
+    assert!(s.alerts().is_empty());
+    assert!(!s.escrow.get_pause_flags().release_paused);
+    assert_eq!(s.token.balance(&s.contributor), 1_400);
+}
+
+#[test]
+fn test_shortfall_pauses_releases_and_emits_alert() {
+    let s = Setup::new();
+    s.leak(500);
+
+    // The call that observes the shortfall still succeeds...
+    s.escrow.partial_release(&1, &s.contributor, &100);
+    let alerts = s.alerts();
+    assert_eq!(alerts.len(), 1);
+    let alert = alerts.get(0).unwrap();
+    assert_eq!(alert.token, s.token.address);
+    assert_eq!(alert.tracked, 1_900);
+    assert_eq!(alert.actual, 1_400);
+
+    // ...but further releases are blocked until the admin investigates.
+    let flags = s.escrow.get_pause_flags();
+    assert!(flags.release_paused);
+    assert!(!flags.lock_paused);
+    assert!(!flags.refund_paused);
+    assert_eq!(
+        s.escrow.try_release_funds(&2, &s.contributor),
+        Err(Ok(Error::FundsPaused))
+    );
+}
+
+#[test]
+fn test_breaker_can_be_reset_once_balance_is_restored() {
+    let s = Setup::new();
+    s.leak(500);
+    s.lock(3, 100);
+    assert!(s.escrow.get_pause_flags().release_paused);
+
+    // Top the contract back up, then lift the pause.
+    s.token_admin.mint(&s.escrow.address, &500);
+    s.escrow.set_paused(&None, &Some(false), &None, &None);
+    s.escrow.release_funds(&2, &s.contributor);
+
+    assert!(!s.escrow.get_pause_flags().release_paused);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_shortfall_is_reported_instead_of_untracked_balance() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_balance_shortfall(), 0);
+    s.leak(300);
+
+    assert_eq!(s.escrow.get_balance_shortfall(), 300);
+    assert_eq!(
+        s.escrow.try_get_untracked_balance(&s.token.address),
+        Err(Ok(Error::BalanceMismatch))
+    );
+    assert_eq!(
+        s.escrow.try_rescue_untracked_tokens(&s.token.address),
+        Err(Ok(Error::BalanceMismatch))
+    );
+}
+
+#[test]
+fn test_reconcile_writes_shortfall_off_against_escrow() {
+    let s = Setup::new();
+    s.leak(300);
+
+    assert_eq!(s.escrow.reconcile_escrow(&1), 300);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!((escrow.amount, escrow.remaining_amount), (700, 700));
+    assert_eq!(s.escrow.get_balance_shortfall(), 0);
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 0);
+    assert_eq!(s.escrow.check_invariants().total_value_locked, 1_700);
+
+    // Nothing left to write off.
+    assert_eq!(s.escrow.reconcile_escrow(&2), 0);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 700);
+}
+
+#[test]
+fn test_reconcile_is_capped_at_remaining_amount() {
+    let s = Setup::new();
+    s.leak(1_500);
+
+    assert_eq!(s.escrow.reconcile_escrow(&1), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 0);
+    assert_eq!(s.escrow.get_balance_shortfall(), 500);
+    assert_eq!(s.escrow.reconcile_escrow(&2), 500);
+    assert_eq!(s.escrow.get_balance_shortfall(), 0);
+}
@@ -0,0 +1,104 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, LockFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, Symbol, TryFromVal, Vec,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    fn item(&self, bounty_id: u64, depositor: &Address, amount: i128) -> LockFundsItem {
+        LockFundsItem {
+            bounty_id,
+            depositor: depositor.clone(),
+            amount,
+            deadline: self.deadline(),
+        }
+    }
+}
+
+#[test]
+fn test_batch_lock_aggregates_transfers_per_depositor() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+    s.token_admin.mint(&other, &1_000);
+
+    let items = vec![
+        &s.env,
+        s.item(1, &s.depositor, 1_000),
+        s.item(2, &other, 500),
+        s.item(3, &s.depositor, 2_000),
+    ];
+    assert_eq!(s.escrow.batch_lock_funds(&items), 3);
+
+    assert_eq!(s.token.balance(&s.depositor), 7_000);
+    assert_eq!(s.token.balance(&other), 500);
+    assert_eq!(s.token.balance(&s.escrow.address), 3_500);
+
+    // The token contract emits exactly one transfer event per depositor.
+    let transfers = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            *contract == s.token.address
+                && Symbol::try_from_val(&s.env, &topics.get(0).unwrap())
+                    == Ok(symbol_short!("transfer"))
+        })
+        .count();
+    assert_eq!(transfers, 2);
+}
+
+#[test]
+fn test_batch_lock_updates_indexes() {
+    let s = Setup::new();
+    let items = vec![
+        &s.env,
+        s.item(1, &s.depositor, 1_000),
+        s.item(2, &s.depositor, 1_000),
+    ];
+    s.escrow.batch_lock_funds(&items);
+
+    let mine = s.escrow.query_escrows_by_depositor(&s.depositor, &0, &10);
+    assert_eq!(mine.len(), 2);
+    assert_eq!(s.escrow.get_aggregate_stats().count_locked, 2);
+}
+
+#[test]
+fn test_batch_lock_is_all_or_nothing() {
+    let s = Setup::new();
+    s.escrow.set_amount_policy(&s.admin, &100, &1_500);
+
+    let items: Vec<LockFundsItem> = vec![
+        &s.env,
+        s.item(1, &s.depositor, 1_000),
+        s.item(2, &s.depositor, 2_000),
+    ];
+    assert_eq!(
+        s.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::AmountAboveMaximum))
+    );
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert!(s.escrow.try_get_escrow_info(&1).is_err());
+}
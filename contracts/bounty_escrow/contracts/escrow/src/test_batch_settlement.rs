@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Ledger, vec};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    deadline: u64,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    /// Locks bounties 1..=3 for 1_000 each.
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.deadline();
+        for bounty_id in 1..=3u64 {
+            base.escrow
+                .lock_funds(&base.depositor, &bounty_id, &1_000, &deadline);
+        }
+        Self { base, deadline }
+    }
+}
+
+#[test]
+fn test_batch_release_reports_each_item() {
+    let s = Setup::new();
+    s.escrow.release_funds(&2, &s.contributor);
+
+    let results = s.escrow.batch_release(&vec![
+        &s.env,
+        (1, s.contributor.clone()),
+        (2, s.contributor.clone()),
+        (9, s.contributor.clone()),
+        (3, s.contributor.clone()),
+    ]);
+
+    assert_eq!(results.len(), 4);
+    let ok = results.get(0).unwrap();
+    assert!(ok.success);
+    assert_eq!(ok.amount, 1_000);
+    assert_eq!(ok.error_code, 0);
+
+    let already_released = results.get(1).unwrap();
+    assert!(!already_released.success);
+    assert_eq!(already_released.error_code, Error::FundsNotLocked as u32);
+
+    let missing = results.get(2).unwrap();
+    assert_eq!(missing.bounty_id, 9);
+    assert_eq!(missing.error_code, Error::BountyNotFound as u32);
+
+    assert!(results.get(3).unwrap().success);
+    assert_eq!(s.token.balance(&s.contributor), 3_000);
+    assert_eq!(s.escrow.get_escrow_info(&3).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_batch_refund_reports_each_item() {
+    let s = Setup::new();
+    s.escrow.release_funds(&1, &s.contributor);
+    s.env.ledger().set_timestamp(s.deadline + 1);
+
+    let results = s.escrow.batch_refund(&vec![&s.env, 1, 2, 3]);
+
+    assert!(!results.get(0).unwrap().success);
+    assert_eq!(
+        results.get(0).unwrap().error_code,
+        Error::FundsNotLocked as u32
+    );
+    assert!(results.get(1).unwrap().success);
+    assert!(results.get(2).unwrap().success);
+    assert_eq!(s.token.balance(&s.depositor), 7_000 + 2_000);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_batch_refund_before_deadline_fails_per_item() {
+    let s = Setup::new();
+    let results = s.escrow.batch_refund(&vec![&s.env, 1, 2]);
+    for result in results.iter() {
+        assert!(!result.success);
+        assert_eq!(result.error_code, Error::DeadlineNotPassed as u32);
+    }
+    assert_eq!(s.token.balance(&s.escrow.address), 3_000);
+}
+
+#[test]
+fn test_batch_settlement_size_limits() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_batch_release(&vec![&s.env]),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        s.escrow.try_batch_refund(&vec![&s.env]),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
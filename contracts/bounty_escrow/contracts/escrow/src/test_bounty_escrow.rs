@@ -488,6 +488,7 @@ fn test_update_fee_config_with_zero_lock_fee() {
         &None, // fee_enabled: unchanged
     );
     assert!(result.is_ok());
+    client.accept_treasury_role();
 
     let config = client.get_fee_config();
     assert_eq!(config.lock_fee_rate, 0);
@@ -513,6 +514,7 @@ fn test_update_fee_config_with_zero_release_fee() {
         &None, // fee_enabled: unchanged
     );
     assert!(result.is_ok());
+    client.accept_treasury_role();
 
     let config = client.get_fee_config();
     assert_eq!(config.release_fee_rate, 0);
@@ -538,6 +540,7 @@ fn test_update_fee_config_with_max_lock_fee() {
         &None, // fee_enabled: unchanged
     );
     assert!(result.is_ok());
+    client.accept_treasury_role();
 
     let config = client.get_fee_config();
     assert_eq!(config.lock_fee_rate, 5000);
@@ -563,6 +566,7 @@ fn test_update_fee_config_with_max_release_fee() {
         &None, // fee_enabled: unchanged
     );
     assert!(result.is_ok());
+    client.accept_treasury_role();
 
     let config = client.get_fee_config();
     assert_eq!(config.release_fee_rate, 5000);
@@ -813,6 +817,7 @@ fn test_update_fee_config_partial_updates_preserve_existing_values() {
         &Some(fee_recipient_1.clone()),
         &Some(true),
     );
+    client.accept_treasury_role();
 
     // Second update: Only update lock fee, other values should remain unchanged
     client.update_fee_config(&Some(300), &None, &None, &None);
@@ -825,6 +830,9 @@ fn test_update_fee_config_partial_updates_preserve_existing_values() {
 
     // Third update: Update recipient and enabled flag
     client.update_fee_config(&None, &None, &Some(fee_recipient_2.clone()), &Some(false));
+    // The new recipient only takes over once it accepts.
+    assert_eq!(client.get_fee_config().fee_recipient, fee_recipient_1);
+    client.accept_treasury_role();
 
     let config = client.get_fee_config();
     assert_eq!(config.lock_fee_rate, 300); // Should remain 300
@@ -1159,6 +1167,26 @@ fn test_amount_policy_can_be_updated_by_admin() {
     assert_eq!(client.get_escrow_info(&8).amount, 500);
 }
 
+/// The configured limits are readable back, and unset until a policy exists.
+#[test]
+fn test_amount_policy_getters() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, _) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    assert_eq!(client.get_min_escrow_amount(), None);
+    assert_eq!(client.get_max_escrow_amount(), None);
+
+    client.set_amount_policy(&admin, &100_i128, &10_000_i128);
+    assert_eq!(client.get_min_escrow_amount(), Some(100));
+    assert_eq!(client.get_max_escrow_amount(), Some(10_000));
+}
+
 /// min - 1 is the tightest possible value below the minimum boundary and must
 /// be rejected (off-by-one lower).
 #[test]
@@ -1427,36 +1455,36 @@ fn test_non_admin_cannot_update_anti_abuse_config() {
 
 #[test]
 fn test_pause_functionality() {
-    let (env, client, contract_id) = create_test_env();
+    let (env, client, _contract_id) = create_test_env();
     env.mock_all_auths();
 
     let admin = Address::generate(&env);
 
     // Create and setup token
-    let (token_address, token_client, token_admin) = create_token_contract(&env, &admin);
+    let (token_address, _token_client, _token_admin) = create_token_contract(&env, &admin);
 
     // Initialize escrow
     client.init(&admin, &token_address);
 
     // Initially not paused
-    assert_eq!(client.is_paused(), false);
+    assert!(!client.is_paused());
 
     // Pause contract
     client.pause();
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 
     // Unpause contract
     client.unpause();
-    assert_eq!(client.is_paused(), false);
+    assert!(!client.is_paused());
 
     // Pause again for emergency test
     client.pause();
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 
     // Unpause to verify idempotent
     client.unpause();
     client.unpause(); // Call again - should not error
-    assert_eq!(client.is_paused(), false);
+    assert!(!client.is_paused());
 }
 
 #[test]
@@ -1474,7 +1502,7 @@ fn test_emergency_withdraw() {
 
     // Pause contract
     client.pause();
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 
     // Call emergency_withdraw (it will fail gracefully if no funds)
     // The important thing is that it's callable when paused
@@ -1482,5 +1510,5 @@ fn test_emergency_withdraw() {
     client.emergency_withdraw(&emergency_recipient);
 
     // Verify pause state still true
-    assert_eq!(client.is_paused(), true);
+    assert!(client.is_paused());
 }
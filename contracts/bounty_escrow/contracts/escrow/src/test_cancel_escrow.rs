@@ -0,0 +1,66 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, CancellationError, DisputeReason, Error, EscrowStatus};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_cancel_escrow_refunds_depositor() {
+    let s = setup();
+
+    s.escrow.cancel_escrow(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Cancelled);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 1);
+
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_cannot_cancel_after_partial_release() {
+    let s = setup();
+    s.escrow.partial_release(&1, &s.contributor, &100);
+
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Err(CancellationError::CancellationNotAllowed.into()))
+    );
+}
+
+#[test]
+fn test_cannot_cancel_with_assigned_contributor() {
+    let s = setup();
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Err(CancellationError::CancellationNotAllowed.into()))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_cancelled_escrow_counts_as_refunded_in_stats() {
+    let s = setup();
+    s.escrow.cancel_escrow(&1);
+
+    let stats = s.escrow.get_aggregate_stats();
+    assert_eq!(stats.count_locked, 0);
+    assert_eq!(stats.count_refunded, 1);
+}
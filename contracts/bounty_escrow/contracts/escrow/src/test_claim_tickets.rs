@@ -186,7 +186,7 @@ mod test_claim_tickets {
         );
 
         assert!(
-            matches!(result, Err(Error::InvalidAmount)),
+            matches!(result, Err(Error::AmountExceedsRemaining)),
             "Should reject amount exceeding escrow"
         );
     }
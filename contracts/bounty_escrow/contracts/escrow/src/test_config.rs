@@ -0,0 +1,49 @@
+#![cfg(test)]
+
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, ReleaseRateLimit};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+#[test]
+fn test_config_collects_settings() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let escrow_id = env.register_contract(None, BountyEscrowContract);
+    let escrow = BountyEscrowContractClient::new(&env, &escrow_id);
+    assert_eq!(escrow.try_get_config(), Err(Ok(Error::NotInitialized)));
+
+    escrow.init(&admin, &token);
+    let config = escrow.get_config();
+    assert_eq!(config.admin, admin);
+    assert_eq!(config.token, token);
+    assert_eq!(config.fee_config, escrow.get_fee_config());
+    assert!(!config.pause_flags.lock_paused);
+    assert_eq!(config.min_escrow_amount, None);
+    assert_eq!(config.max_batch_size, 20);
+    assert_eq!(config.release_rate_limit.global_limit, 0);
+    assert_eq!(config.release_rate_limit.escrow_limit, 0);
+
+    let limit = ReleaseRateLimit {
+        window: 3_600,
+        global_limit: 5_000,
+        escrow_limit: 0,
+    };
+    escrow.update_fee_config(&None, &Some(250), &None, &Some(true));
+    escrow.set_amount_policy(&admin, &10, &1_000);
+    escrow.set_release_rate_limit(&Some(limit.clone()));
+    escrow.pause();
+
+    let config = escrow.get_config();
+    assert_eq!(config.fee_config.release_fee_rate, 250);
+    assert!(config.fee_config.fee_enabled);
+    assert_eq!(config.fee_config.fee_recipient, admin);
+    assert_eq!(config.pause_flags, escrow.get_pause_flags());
+    assert!(config.pause_flags.release_paused);
+    assert_eq!(config.min_escrow_amount, Some(10));
+    assert_eq!(config.max_escrow_amount, Some(1_000));
+    assert_eq!(config.release_rate_limit, limit);
+}
@@ -0,0 +1,86 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, ContractStats, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        Self { base, treasury }
+    }
+}
+
+#[test]
+fn test_stats_empty_contract() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_contract_stats(), ContractStats::default());
+}
+
+#[test]
+fn test_stats_track_every_transition() {
+    let s = Setup::new();
+    for id in 1..=5 {
+        s.lock(id, 1_000);
+    }
+    s.escrow.increase_escrow(&1, &500);
+
+    let stats = s.escrow.get_contract_stats();
+    assert_eq!(stats.count_locked, 5);
+    assert_eq!(stats.total_value_locked, 5_500);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.partial_release(&2, &s.contributor, &300);
+    s.escrow.cancel_escrow(&3);
+    s.escrow
+        .approve_refund(&4, &400, &s.depositor, &RefundMode::Partial);
+    s.escrow.refund(&4);
+    s.env.ledger().set_timestamp(2_000);
+    s.escrow.refund(&5);
+
+    let stats = s.escrow.get_contract_stats();
+    assert_eq!(stats.count_locked, 1);
+    assert_eq!(stats.count_released, 1);
+    assert_eq!(stats.count_refunded, 1);
+    assert_eq!(stats.count_partially_refunded, 1);
+    assert_eq!(stats.count_cancelled, 1);
+    assert_eq!(stats.total_released, 1_800);
+    assert_eq!(stats.total_refunded, 2_400);
+    assert_eq!(stats.total_value_locked, 700 + 600);
+    assert_eq!(stats.total_fees_collected, 0);
+}
+
+#[test]
+fn test_stats_track_release_fees() {
+    let s = Setup::new();
+    s.escrow
+        .update_fee_config(&None, &Some(500), &Some(s.treasury.clone()), &Some(true));
+    s.escrow.accept_treasury_role();
+    s.lock(1, 1_000);
+    s.lock(2, 2_000);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.partial_release(&2, &s.contributor, &1_000);
+
+    let stats = s.escrow.get_contract_stats();
+    assert_eq!(stats.total_released, 2_000);
+    assert_eq!(stats.total_fees_collected, 100);
+    assert_eq!(stats.total_value_locked, 1_000);
+}
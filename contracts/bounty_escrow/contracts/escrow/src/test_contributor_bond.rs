@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use crate::bonds::BondError;
+use crate::{test_setup::TestSetup, ContributorBond, Error};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.token_admin.mint(&base.contributor, &500);
+        base.escrow.set_arbiter(&base.admin);
+
+        let deadline = base.env.ledger().timestamp() + 30 * 86_400;
+        base.escrow
+            .lock_funds(&base.depositor, &1, &1_000, &deadline);
+        base.escrow
+            .assign_contributor_with_bond(&1, &base.contributor, &86_400, &200);
+        base.escrow.accept_assignment(&1);
+        Self { base }
+    }
+
+    fn dispute(&self) {
+        let reason = BytesN::from_array(&self.env, &[3; 32]);
+        self.escrow
+            .open_dispute(&self.depositor, &1, &self.contributor, &reason);
+    }
+}
+
+#[test]
+fn test_bond_is_posted_on_acceptance() {
+    let s = Setup::new();
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(
+        s.escrow.get_contributor_bond(&1),
+        Some(ContributorBond {
+            contributor: s.contributor.clone(),
+            amount: 200,
+        })
+    );
+    // The bond is owed to the contributor, not surplus the admin can rescue.
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 0);
+}
+
+#[test]
+fn test_bond_slashed_to_depositor_on_lost_dispute() {
+    let s = Setup::new();
+    s.dispute();
+    s.escrow.resolve_dispute(&1, &2_000);
+
+    assert_eq!(s.escrow.get_contributor_bond(&1), None);
+    assert_eq!(s.token.balance(&s.contributor), 300 + 200);
+    assert_eq!(s.token.balance(&s.depositor), 9_000 + 800 + 200);
+    assert_eq!(
+        s.escrow.try_withdraw_bond(&1),
+        Err(Err(BondError::BondNotFound.into()))
+    );
+}
+
+#[test]
+fn test_bond_slashed_to_treasury_when_configured() {
+    let s = Setup::new();
+    let treasury = Address::generate(&s.env);
+    s.escrow
+        .update_fee_config(&None, &None, &Some(treasury.clone()), &None);
+    s.escrow.accept_treasury_role();
+    s.escrow.set_slash_bonds_to_treasury(&true);
+
+    s.dispute();
+    s.escrow.resolve_dispute(&1, &0);
+    assert_eq!(s.token.balance(&treasury), 200);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_bond_returned_after_settlement() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_withdraw_bond(&1),
+        Err(Err(BondError::BondLocked.into()))
+    );
+
+    s.dispute();
+    s.escrow.resolve_dispute(&1, &5_000);
+    assert!(s.escrow.get_contributor_bond(&1).is_some());
+
+    s.escrow.withdraw_bond(&1);
+    assert_eq!(s.env.auths()[0].0, s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 500 + 500);
+    assert_eq!(s.escrow.get_contributor_bond(&1), None);
+}
+
+#[test]
+fn test_bond_moves_respect_pause() {
+    let s = Setup::new();
+    s.dispute();
+    s.escrow.resolve_dispute(&1, &5_000);
+    s.escrow.set_paused(&None, &None, &Some(true), &None);
+    assert_eq!(s.escrow.try_withdraw_bond(&1), Err(Ok(Error::FundsPaused)));
+    s.escrow.set_paused(&None, &None, &Some(false), &None);
+    s.escrow.withdraw_bond(&1);
+
+    let deadline = s.env.ledger().timestamp() + 86_400;
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &deadline);
+    s.escrow
+        .assign_contributor_with_bond(&2, &s.contributor, &86_400, &200);
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    assert_eq!(
+        s.escrow.try_accept_assignment(&2),
+        Err(Ok(Error::FundsPaused))
+    );
+}
@@ -0,0 +1,99 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, DeadlineError, DisputeReason, Error};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Ledger, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    deadline: u64,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.deadline();
+        base.lock(1, 1_000);
+        Self { base, deadline }
+    }
+
+    /// Whether `addr` authorized the last contract invocation.
+    fn authorized(&self, addr: &Address) -> bool {
+        self.env.auths().iter().any(|(a, _)| a == addr)
+    }
+}
+
+#[test]
+fn test_depositor_extends_deadline() {
+    let s = Setup::new();
+    let new_deadline = s.deadline + 5_000;
+
+    s.escrow.extend_deadline(&1, &new_deadline);
+    assert!(s.authorized(&s.depositor));
+    assert!(!s.authorized(&s.contributor));
+
+    assert_eq!(s.escrow.get_escrow_info(&1).deadline, new_deadline);
+
+    // The refund window moves with the deadline.
+    s.env.ledger().set_timestamp(s.deadline + 1);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+}
+
+#[test]
+fn test_extension_requires_later_deadline() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_extend_deadline(&1, &s.deadline),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.escrow.try_extend_deadline(&2, &(s.deadline + 1)),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_extension_capped_by_max() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_max_deadline_extension(), u64::MAX);
+
+    s.escrow.set_max_deadline_extension(&500);
+    assert_eq!(
+        s.escrow.try_extend_deadline(&1, &(s.deadline + 501)),
+        Err(Err(DeadlineError::ExtensionTooLong.into()))
+    );
+
+    s.escrow.extend_deadline(&1, &(s.deadline + 500));
+    s.escrow.extend_deadline(&1, &(s.deadline + 1_000));
+    assert_eq!(s.escrow.get_escrow_info(&1).deadline, s.deadline + 1_000);
+}
+
+#[test]
+fn test_assigned_contributor_must_consent() {
+    let s = Setup::new();
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+
+    s.escrow.extend_deadline(&1, &(s.deadline + 100));
+
+    assert!(s.authorized(&s.depositor));
+    assert!(s.authorized(&s.contributor));
+}
+
+#[test]
+fn test_cannot_extend_settled_escrow() {
+    let s = Setup::new();
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(
+        s.escrow.try_extend_deadline(&1, &(s.deadline + 100)),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
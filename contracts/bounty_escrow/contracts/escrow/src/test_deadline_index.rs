@@ -0,0 +1,77 @@
+#![cfg(test)]
+
+use crate::{deadline_index::DeadlineKey, test_setup::TestSetup, DataKey};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address, Vec};
+
+const DAY: u64 = 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let s = Self {
+            base: TestSetup::new(),
+        };
+        s.lock(1, 100);
+        s.lock(2, 3 * DAY);
+        s.lock(3, 200);
+        s
+    }
+
+    fn lock(&self, bounty_id: u64, deadline: u64) {
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &1_000, &deadline);
+    }
+
+    fn expired(&self, now: u64, limit: u32) -> Vec<u64> {
+        let mut ids = Vec::new(&self.env);
+        for entry in self.escrow.list_expired_escrows(&now, &limit).iter() {
+            ids.push_back(entry.bounty_id);
+        }
+        ids
+    }
+}
+
+#[test]
+fn test_lists_only_expired_funded_escrows() {
+    let s = Setup::new();
+    assert_eq!(s.expired(99, 10).len(), 0);
+    assert_eq!(s.expired(100, 10), Vec::from_array(&s.env, [1]));
+    assert_eq!(s.expired(250, 10), Vec::from_array(&s.env, [1, 3]));
+    assert_eq!(s.expired(250, 1), Vec::from_array(&s.env, [1]));
+    assert_eq!(s.expired(4 * DAY, 10), Vec::from_array(&s.env, [1, 3, 2]));
+
+    let contributor = Address::generate(&s.env);
+    s.escrow.release_funds(&1, &contributor);
+    s.escrow.extend_deadline(&3, &(5 * DAY));
+    assert_eq!(s.expired(4 * DAY, 10), Vec::from_array(&s.env, [2]));
+    assert_eq!(s.expired(5 * DAY, 10), Vec::from_array(&s.env, [2, 3]));
+}
+
+#[test]
+fn test_migration_backfills_index() {
+    let s = Setup::new();
+    s.env.as_contract(&s.escrow.address, || {
+        let storage = s.env.storage();
+        for bucket in [0, 3] {
+            storage.persistent().remove(&DeadlineKey::Bucket(bucket));
+        }
+        storage.persistent().remove(&DeadlineKey::Buckets);
+        storage.instance().set(&DataKey::SchemaVersion, &2u32);
+    });
+    assert_eq!(s.expired(4 * DAY, 10).len(), 0);
+
+    assert_eq!(s.escrow.migrate(&0, &20), None);
+    assert_eq!(s.expired(4 * DAY, 10), Vec::from_array(&s.env, [1, 3, 2]));
+}
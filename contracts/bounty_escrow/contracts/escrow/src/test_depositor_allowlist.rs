@@ -0,0 +1,110 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, LockFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    partner: Address,
+    outsider: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let partner = Address::generate(&base.env);
+        let outsider = Address::generate(&base.env);
+        base.token_admin.mint(&partner, &10_000);
+        base.token_admin.mint(&outsider, &10_000);
+        Self {
+            base,
+            partner,
+            outsider,
+        }
+    }
+}
+
+#[test]
+fn test_mode_off_by_default() {
+    let s = Setup::new();
+    assert!(!s.escrow.is_depositor_allowlist_mode());
+    s.escrow.lock_funds(&s.outsider, &1, &100, &s.deadline());
+}
+
+#[test]
+fn test_only_allowlisted_depositors_lock_when_enabled() {
+    let s = Setup::new();
+    s.escrow.set_depositor_allowlist_entry(&s.partner, &true);
+    s.escrow.set_depositor_allowlist_mode(&true);
+    assert!(s.escrow.is_allowlisted_depositor(&s.partner));
+    assert!(!s.escrow.is_allowlisted_depositor(&s.outsider));
+
+    s.escrow.lock_funds(&s.partner, &1, &100, &s.deadline());
+    assert_eq!(
+        s.escrow
+            .try_lock_funds(&s.outsider, &2, &100, &s.deadline()),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let items = vec![
+        &s.env,
+        LockFundsItem {
+            bounty_id: 3,
+            depositor: s.partner.clone(),
+            amount: 100,
+            deadline: s.deadline(),
+        },
+        LockFundsItem {
+            bounty_id: 4,
+            depositor: s.outsider.clone(),
+            amount: 100,
+            deadline: s.deadline(),
+        },
+    ];
+    assert_eq!(
+        s.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_removed_depositor_cannot_top_up() {
+    let s = Setup::new();
+    s.escrow.set_depositor_allowlist_entry(&s.partner, &true);
+    s.escrow.set_depositor_allowlist_mode(&true);
+    s.escrow.lock_funds(&s.partner, &1, &100, &s.deadline());
+
+    s.escrow.set_depositor_allowlist_entry(&s.partner, &false);
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &50),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Turning the mode off opens deposits to everyone again.
+    s.escrow.set_depositor_allowlist_mode(&false);
+    s.escrow.increase_escrow(&1, &50);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 150);
+}
+
+#[test]
+fn test_budget_does_not_allowlist_depositor() {
+    let s = Setup::new();
+    s.escrow
+        .set_depositor_budget(&s.outsider, &Some((1_000, 0)));
+    s.escrow.set_depositor_allowlist_mode(&true);
+    assert!(!s.escrow.is_allowlisted_depositor(&s.outsider));
+
+    s.escrow.set_depositor_allowlist_entry(&s.partner, &true);
+    s.escrow.set_depositor_budget(&s.partner, &Some((1_000, 0)));
+    assert!(s.escrow.is_allowlisted_depositor(&s.partner));
+    assert_eq!(s.escrow.get_remaining_budget(&s.partner), Some(1_000));
+}
@@ -0,0 +1,96 @@
+#![cfg(test)]
+
+use crate::budgets::BudgetError;
+use crate::{test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const PERIOD: u64 = 30 * 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    team: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let team = Address::generate(&base.env);
+        base.token_admin.mint(&team, &100_000);
+        base.escrow
+            .set_depositor_budget(&team, &Some((1_000, PERIOD)));
+        Self { base, team }
+    }
+
+    fn deadline(&self) -> u64 {
+        self.env.ledger().timestamp() + PERIOD * 2
+    }
+}
+
+#[test]
+fn test_locks_and_top_ups_count_against_budget() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), Some(1_000));
+
+    s.escrow.lock_funds(&s.team, &1, &600, &s.deadline());
+    s.escrow.increase_escrow(&1, &300);
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), Some(100));
+    assert_eq!(s.escrow.get_depositor_budget(&s.team).unwrap().spent, 900);
+
+    assert_eq!(
+        s.escrow.try_lock_funds(&s.team, &2, &101, &s.deadline()),
+        Err(Err(BudgetError::BudgetExceeded.into()))
+    );
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &101),
+        Err(Err(BudgetError::BudgetExceeded.into()))
+    );
+    s.escrow.lock_funds(&s.team, &2, &100, &s.deadline());
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), Some(0));
+}
+
+#[test]
+fn test_budget_window_resets_after_period() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.team, &1, &1_000, &s.deadline());
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + PERIOD);
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), Some(1_000));
+    s.escrow.lock_funds(&s.team, &2, &1_000, &s.deadline());
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), Some(0));
+}
+
+#[test]
+fn test_changing_and_removing_budget() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.team, &1, &400, &s.deadline());
+
+    // Raising the limit keeps what was spent in the current window.
+    s.escrow
+        .set_depositor_budget(&s.team, &Some((2_000, PERIOD)));
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), Some(1_600));
+
+    s.escrow.set_depositor_budget(&s.team, &None);
+    assert_eq!(s.escrow.get_depositor_budget(&s.team), None);
+    assert_eq!(s.escrow.get_remaining_budget(&s.team), None);
+    s.escrow.lock_funds(&s.team, &2, &5_000, &s.deadline());
+
+    assert_eq!(
+        s.escrow
+            .try_set_depositor_budget(&s.team, &Some((-1, PERIOD))),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
@@ -0,0 +1,44 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use soroban_sdk::{testutils::Address as _, Address};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_depositor_pays_out_incrementally() {
+    let s = setup();
+    s.escrow
+        .partial_release_with_role(&s.depositor, &1, &s.contributor, &300);
+    assert_eq!(s.env.auths()[0].0, s.depositor);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 700);
+
+    s.escrow
+        .partial_release_with_role(&s.depositor, &1, &s.contributor, &700);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_depositor_path_is_limited_to_own_partial_releases() {
+    let s = setup();
+    let other = Address::generate(&s.env);
+    s.token_admin.mint(&other, &1_000);
+    s.escrow.lock_funds(&other, &2, &1_000, &1_000);
+
+    assert_eq!(
+        s.escrow
+            .try_partial_release_with_role(&s.depositor, &2, &s.contributor, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow
+            .try_release_funds_with_role(&s.depositor, &1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 2_000);
+}
@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use crate::donations::DonationError;
+use crate::{test_setup::TestSetup, Error};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s.lock(2, 1_000);
+    s
+}
+
+#[test]
+fn test_absorb_into_pool() {
+    let s = setup();
+    s.escrow.set_donation_pool(&Some(1));
+    assert_eq!(s.escrow.get_donation_pool(), Some(1));
+
+    // Tokens sent straight to the contract.
+    s.token_admin.mint(&s.escrow.address, &300);
+    s.escrow.absorb_untracked_into(&1, &200);
+
+    let pool = s.escrow.get_escrow_info(&1);
+    assert_eq!((pool.amount, pool.remaining_amount), (1_200, 1_200));
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 100);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_200);
+    assert_eq!(s.token.balance(&s.depositor), 8_000);
+}
+
+#[test]
+fn test_absorb_requires_donation_mode_and_untracked_funds() {
+    let s = setup();
+    s.token_admin.mint(&s.escrow.address, &300);
+
+    assert_eq!(
+        s.escrow.try_absorb_untracked_into(&1, &100),
+        Err(Err(DonationError::NotDonationPool.into()))
+    );
+
+    s.escrow.set_donation_pool(&Some(1));
+    assert_eq!(
+        s.escrow.try_absorb_untracked_into(&2, &100),
+        Err(Err(DonationError::NotDonationPool.into()))
+    );
+    // Tokens owed to escrows can't be absorbed.
+    assert_eq!(
+        s.escrow.try_absorb_untracked_into(&1, &301),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    assert_eq!(
+        s.escrow.try_set_donation_pool(&Some(9)),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    s.escrow.set_donation_pool(&None);
+    assert_eq!(
+        s.escrow.try_absorb_untracked_into(&1, &100),
+        Err(Err(DonationError::NotDonationPool.into()))
+    );
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 300);
+}
+
+#[test]
+fn test_donation_pool_is_separate_from_yield_pool() {
+    let s = setup();
+    s.escrow.set_donation_pool(&Some(1));
+    assert_eq!(s.escrow.get_yield_pool(), None);
+    assert_eq!(s.escrow.get_donation_pool(), Some(1));
+}
@@ -376,3 +376,78 @@ fn test_simulate_refund_with_pending_claim_fails() {
     assert!(!result.success);
     assert_eq!(result.error_code, Error::ClaimPending as u32);
 }
+
+// ===========================================================================
+// Fees and transfers
+// ===========================================================================
+
+#[test]
+fn test_simulate_release_previews_fee_and_transfers() {
+    let s = SimSetup::new();
+    s.escrow
+        .update_fee_config(&None, &Some(100), &None, &Some(true));
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let sim = s.escrow.simulate_release(&1_u64, &s.contributor);
+    assert!(sim.success);
+    assert_eq!(sim.fee, 10);
+    assert_eq!(
+        sim.transfers,
+        soroban_sdk::vec![&s.env, (s.contributor.clone(), 990_i128)]
+    );
+
+    s.escrow.release_funds(&1_u64, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 990);
+}
+
+#[test]
+fn test_simulate_partial_release() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let sim = s
+        .escrow
+        .simulate_partial_release(&1_u64, &s.contributor, &400);
+    assert!(sim.success);
+    assert_eq!(sim.amount, 400);
+    assert_eq!(sim.resulting_status, EscrowStatus::Locked);
+    assert_eq!(sim.remaining_amount, 600);
+    assert_eq!(
+        sim.transfers,
+        soroban_sdk::vec![&s.env, (s.contributor.clone(), 400_i128)]
+    );
+
+    let too_much = s
+        .escrow
+        .simulate_partial_release(&1_u64, &s.contributor, &1_001);
+    assert_eq!(too_much.error_code, Error::InsufficientFunds as u32);
+    assert!(too_much.transfers.is_empty());
+
+    let last = s
+        .escrow
+        .simulate_partial_release(&1_u64, &s.contributor, &1_000);
+    assert_eq!(last.resulting_status, EscrowStatus::Released);
+
+    s.escrow.partial_release(&1_u64, &s.contributor, &400);
+    let info = s.escrow.get_escrow_info(&1_u64);
+    assert_eq!(info.remaining_amount, sim.remaining_amount);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+}
+
+#[test]
+fn test_simulate_refund_previews_transfers() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.env.ledger().set_timestamp(deadline + 1);
+
+    let sim = s.escrow.simulate_refund(&1_u64);
+    assert!(sim.success);
+    assert_eq!(sim.fee, 0);
+    assert_eq!(
+        sim.transfers,
+        soroban_sdk::vec![&s.env, (s.depositor.clone(), 1_000_i128)]
+    );
+}
@@ -0,0 +1,217 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus, RescueError, TimelockError};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, BytesN, Symbol, TryFromVal,
+};
+
+const THIRTY_DAYS: u64 = 30 * 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.env.ledger().timestamp() + THIRTY_DAYS * 2;
+        base.escrow
+            .lock_funds(&base.depositor, &1, &1_000, &deadline);
+        base.escrow
+            .lock_funds(&base.depositor, &2, &2_000, &deadline);
+        Self { base }
+    }
+
+    fn count_events(&self, name: Symbol) -> usize {
+        self.env
+            .events()
+            .all()
+            .iter()
+            .filter(|(contract, topics, _)| {
+                *contract == self.escrow.address
+                    && Symbol::try_from_val(&self.env, &topics.get(0).unwrap()) == Ok(name.clone())
+            })
+            .count()
+    }
+}
+
+#[test]
+fn test_withdraw_all_waits_for_announced_delay() {
+    let s = Setup::new();
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    assert_eq!(
+        s.escrow.try_emergency_withdraw_all(&20),
+        Err(Err(RescueError::RescueNotRequested.into()))
+    );
+
+    s.escrow.announce_emergency_withdraw_all();
+    assert_eq!(s.count_events(symbol_short!("exit_ann")), 1);
+    let exit = s.escrow.get_emergency_exit().unwrap();
+    assert_eq!(exit.announced_by, s.admin);
+    assert_eq!(exit.executable_at, exit.announced_at + THIRTY_DAYS);
+    assert_eq!(
+        s.escrow.try_announce_emergency_withdraw_all(),
+        Err(Err(RescueError::RescuePending.into()))
+    );
+
+    s.advance_time(THIRTY_DAYS - 1);
+    assert_eq!(
+        s.escrow.try_emergency_withdraw_all(&20),
+        Err(Err(TimelockError::TimelockNotExpired.into()))
+    );
+}
+
+#[test]
+fn test_withdraw_all_refunds_each_depositor() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+    s.token_admin.mint(&other, &500);
+    let deadline = s.env.ledger().timestamp() + THIRTY_DAYS * 2;
+    s.escrow.lock_funds(&other, &3, &500, &deadline);
+    s.escrow
+        .partial_release(&2, &Address::generate(&s.env), &400);
+
+    s.escrow.announce_emergency_withdraw_all();
+    s.advance_time(THIRTY_DAYS);
+    assert_eq!(
+        s.escrow.try_emergency_withdraw_all(&20),
+        Err(Ok(Error::NotPaused))
+    );
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+
+    assert_eq!(s.escrow.emergency_withdraw_all(&20), 3);
+    assert_eq!(s.token.balance(&s.depositor), 10_000 - 400);
+    assert_eq!(s.token.balance(&other), 500);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    for id in 1..=3 {
+        let info = s.escrow.get_escrow_info(&id);
+        assert_eq!(info.status, EscrowStatus::Refunded);
+        assert_eq!(info.remaining_amount, 0);
+    }
+    assert_eq!(s.count_events(symbol_short!("f_ref")), 3);
+    assert_eq!(s.count_events(symbol_short!("exit_exec")), 1);
+    assert_eq!(s.escrow.get_emergency_exit(), None);
+}
+
+#[test]
+fn test_withdraw_all_skips_frozen_escrows() {
+    let s = Setup::new();
+    s.escrow
+        .freeze_escrow(&s.admin, &2, &BytesN::from_array(&s.env, &[1; 32]));
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.announce_emergency_withdraw_all();
+    s.advance_time(THIRTY_DAYS);
+
+    assert_eq!(s.escrow.emergency_withdraw_all(&20), 1);
+    assert_eq!(s.token.balance(&s.escrow.address), 2_000);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_cancel_announcement() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_cancel_emergency_withdraw_all(),
+        Err(Err(RescueError::RescueNotRequested.into()))
+    );
+    s.escrow.announce_emergency_withdraw_all();
+    s.escrow.cancel_emergency_withdraw_all();
+    assert_eq!(s.count_events(symbol_short!("exit_cncl")), 1);
+
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.advance_time(THIRTY_DAYS);
+    assert_eq!(
+        s.escrow.try_emergency_withdraw_all(&20),
+        Err(Err(RescueError::RescueNotRequested.into()))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 3_000);
+}
+
+#[test]
+fn test_withdraw_all_runs_in_batches() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + THIRTY_DAYS * 2;
+    s.escrow.lock_funds(&s.depositor, &3, &3_000, &deadline);
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.announce_emergency_withdraw_all();
+    s.advance_time(THIRTY_DAYS);
+    assert_eq!(
+        s.escrow.try_emergency_withdraw_all(&0),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    // Escrows are refunded from the most recently locked one back.
+    assert_eq!(s.escrow.emergency_withdraw_all(&2), 2);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+    // The announcement stays until the last escrow is refunded.
+    assert!(s.escrow.get_emergency_exit().is_some());
+
+    assert_eq!(s.escrow.emergency_withdraw_all(&2), 1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.escrow.get_emergency_exit(), None);
+    assert_eq!(s.count_events(symbol_short!("exit_exec")), 2);
+}
+
+#[test]
+fn test_withdraw_all_resumes_after_skipped_escrow_leaves() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + THIRTY_DAYS * 2;
+    s.escrow.lock_funds(&s.depositor, &3, &3_000, &deadline);
+    s.escrow.lock_funds(&s.depositor, &4, &4_000, &deadline);
+    s.escrow
+        .freeze_escrow(&s.admin, &4, &BytesN::from_array(&s.env, &[1; 32]));
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.announce_emergency_withdraw_all();
+    s.advance_time(THIRTY_DAYS);
+
+    assert_eq!(s.escrow.emergency_withdraw_all(&1), 1);
+    assert_eq!(s.escrow.get_escrow_info(&3).status, EscrowStatus::Refunded);
+
+    // The skipped escrow is settled between batches; the exit still reaches
+    // every escrow it had not visited yet.
+    s.escrow.unfreeze_escrow(&s.admin, &4);
+    s.escrow.release_funds(&4, &Address::generate(&s.env));
+    assert_eq!(s.escrow.emergency_withdraw_all(&1), 1);
+    assert_eq!(s.escrow.emergency_withdraw_all(&1), 1);
+    for id in 1..=2 {
+        assert_eq!(s.escrow.get_escrow_info(&id).status, EscrowStatus::Refunded);
+    }
+    assert_eq!(s.escrow.get_escrow_info(&4).status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.depositor), 10_000 - 4_000);
+    assert_eq!(s.escrow.get_emergency_exit(), None);
+}
+
+#[test]
+fn test_withdraw_all_leaves_disputed_escrows_to_the_arbiter() {
+    let s = Setup::new();
+    let contributor = Address::generate(&s.env);
+    s.escrow.assign_contributor(&1, &contributor, &86_400);
+    s.escrow.accept_assignment(&1);
+    s.escrow.open_dispute(
+        &contributor,
+        &1,
+        &contributor,
+        &BytesN::from_array(&s.env, &[9; 32]),
+    );
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.announce_emergency_withdraw_all();
+    s.advance_time(THIRTY_DAYS);
+
+    // The disputed escrow is passed over, not counted against the batch.
+    assert_eq!(s.escrow.emergency_withdraw_all(&1), 1);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Refunded);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+    assert_eq!(s.escrow.get_emergency_exit(), None);
+}
@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use crate::nonce::VoucherError;
+use crate::yield_strategy::YieldError;
+use crate::{test_setup::TestSetup, AmountError, DisputeError, Error, RefundMode, TicketError};
+use soroban_sdk::String;
+
+#[test]
+fn test_every_error_code_is_described() {
+    let s = TestSetup::new();
+    let unknown = String::from_str(&s.env, "unknown error");
+
+    let errors = [
+        Error::AlreadyInitialized,
+        Error::BountyNotFound,
+        Error::InvalidAmount,
+        Error::FundsPaused,
+        Error::CapabilityRevoked,
+        Error::DisputeOpen,
+        Error::CouncilRequired,
+        Error::AmountExceedsRemaining,
+    ];
+    for error in errors {
+        let description = s.escrow.get_error_description(&(error as u32));
+        assert_eq!(description, String::from_str(&s.env, error.description()));
+        assert_ne!(description, unknown);
+    }
+
+    let feature_errors = [
+        (
+            DisputeError::DisputeNotFound as u32,
+            DisputeError::DisputeNotFound.description(),
+        ),
+        (
+            VoucherError::VoucherNonceUsed as u32,
+            VoucherError::VoucherNonceUsed.description(),
+        ),
+        (
+            TicketError::TicketExpired as u32,
+            TicketError::TicketExpired.description(),
+        ),
+        (
+            YieldError::PoolNotSet as u32,
+            YieldError::PoolNotSet.description(),
+        ),
+    ];
+    for (code, expected) in feature_errors {
+        let description = s.escrow.get_error_description(&code);
+        assert_eq!(description, String::from_str(&s.env, expected));
+    }
+
+    assert_eq!(s.escrow.get_error_description(&0), unknown);
+    assert_eq!(s.escrow.get_error_description(&15), unknown);
+    assert_eq!(s.escrow.get_error_description(&9_999), unknown);
+}
+
+#[test]
+fn test_failures_map_to_descriptions() {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &0),
+        Err(Err(AmountError::ZeroAmount.into()))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &-1),
+        Err(Err(AmountError::NegativeAmount.into()))
+    );
+    assert_eq!(
+        s.escrow
+            .get_error_description(&(AmountError::NegativeAmount as u32)),
+        String::from_str(&s.env, "amount is negative")
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &1_001),
+        Err(Ok(Error::AmountExceedsRemaining))
+    );
+
+    let error = s
+        .escrow
+        .try_approve_refund(&1, &1_001, &s.depositor, &RefundMode::Full)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(
+        s.escrow.get_error_description(&(error as u32)),
+        String::from_str(&s.env, "amount is more than the escrow has remaining")
+    );
+
+    let error = s.escrow.try_get_escrow_info(&7).unwrap_err().unwrap();
+    assert_eq!(
+        s.escrow.get_error_description(&(error as u32)),
+        String::from_str(&s.env, "no escrow exists for the bounty id")
+    );
+}
+
+#[test]
+fn test_feature_failures_map_to_descriptions() {
+    let s = TestSetup::new();
+
+    let error = s.escrow.try_get_dispute(&7).unwrap_err().unwrap();
+    assert_eq!(error, DisputeError::DisputeNotFound);
+    assert_eq!(
+        s.escrow.get_error_description(&(error as u32)),
+        String::from_str(&s.env, "no open dispute exists for the bounty")
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    maintainer: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let maintainer = Address::generate(&base.env);
+        base.escrow.lock_funds_with_approver(
+            &base.depositor,
+            &1,
+            &1_000,
+            &base.deadline(),
+            &maintainer,
+        );
+        base.lock(2, 1_000);
+        Self { base, maintainer }
+    }
+}
+
+#[test]
+fn test_approver_releases_own_bounty() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_escrow_approver(&1), Some(s.maintainer.clone()));
+    assert_eq!(s.escrow.get_escrow_approver(&2), None);
+
+    s.escrow
+        .partial_release_with_role(&s.maintainer, &1, &s.contributor, &400);
+    assert_eq!(s.env.auths()[0].0, s.maintainer);
+    s.escrow
+        .partial_release_with_role(&s.maintainer, &1, &s.contributor, &600);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_approver_cannot_release_other_bounties() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow
+            .try_release_funds_with_role(&s.maintainer, &2, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow
+            .try_partial_release_with_role(&s.maintainer, &2, &s.contributor, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 2_000);
+}
+
+#[test]
+fn test_depositor_can_replace_or_remove_approver() {
+    let s = Setup::new();
+    let new_maintainer = Address::generate(&s.env);
+    s.escrow
+        .set_escrow_approver(&1, &Some(new_maintainer.clone()));
+    assert_eq!(s.env.auths()[0].0, s.depositor);
+
+    assert_eq!(
+        s.escrow
+            .try_release_funds_with_role(&s.maintainer, &1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.escrow.set_escrow_approver(&1, &None);
+    assert_eq!(
+        s.escrow
+            .try_release_funds_with_role(&new_maintainer, &1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_set_escrow_approver(&9, &None),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    // The admin keeps its usual release rights.
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
@@ -0,0 +1,214 @@
+#![cfg(test)]
+
+use crate::{
+    test_setup::TestSetup, DisputeError, DisputeReason, DisputeStatus, Error, EscrowStatus,
+};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    arbiter: Address,
+    reason: BytesN<32>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let arbiter = Address::generate(&base.env);
+        base.escrow.set_arbiter(&arbiter);
+
+        base.lock(1, 1_000);
+        base.escrow
+            .assign_contributor(&1, &base.contributor, &86_400);
+        base.escrow.accept_assignment(&1);
+
+        let reason = BytesN::from_array(&base.env, &[9u8; 32]);
+        Self {
+            base,
+            arbiter,
+            reason,
+        }
+    }
+}
+
+#[test]
+fn test_open_dispute_freezes_release_and_refund() {
+    let s = Setup::new();
+    s.escrow
+        .open_dispute(&s.contributor, &1, &s.contributor, &s.reason);
+
+    let dispute = s.escrow.get_dispute(&1);
+    assert_eq!(dispute.status, DisputeStatus::Open);
+    assert_eq!(dispute.raised_by, s.contributor);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::DisputeOpen))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::DisputeOpen))
+    );
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 2_000);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DisputeOpen)));
+}
+
+#[test]
+fn test_only_parties_can_open_dispute() {
+    let s = Setup::new();
+    let stranger = Address::generate(&s.env);
+
+    let res = s
+        .escrow
+        .try_open_dispute(&stranger, &1, &s.contributor, &s.reason);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    // The contributor has to be the one recorded on the escrow.
+    let res = s
+        .escrow
+        .try_open_dispute(&stranger, &1, &stranger, &s.reason);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    let res = s
+        .escrow
+        .try_open_dispute(&s.depositor, &1, &stranger, &s.reason);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.reason);
+    let res = s
+        .escrow
+        .try_open_dispute(&s.contributor, &1, &s.contributor, &s.reason);
+    assert_eq!(res, Err(Ok(Error::DisputeOpen)));
+}
+
+#[test]
+fn test_resolve_dispute_splits_funds() {
+    let s = Setup::new();
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.reason);
+
+    s.escrow.resolve_dispute(&1, &7_000);
+
+    assert_eq!(s.token.balance(&s.contributor), 700);
+    assert_eq!(s.token.balance(&s.depositor), 9_300);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    let dispute = s.escrow.get_dispute(&1);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.contributor_share_bps, 7_000);
+}
+
+#[test]
+fn test_resolve_dispute_full_refund() {
+    let s = Setup::new();
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.reason);
+
+    s.escrow.resolve_dispute(&1, &0);
+
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_resolve_dispute_refunds_funders_pro_rata() {
+    let s = Setup::new();
+    let funder = Address::generate(&s.env);
+    s.token_admin.mint(&funder, &10_000);
+    s.escrow.contribute_to_escrow(&funder, &1, &1_000);
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.reason);
+
+    s.escrow.resolve_dispute(&1, &5_000);
+
+    // Half of the 2_000 goes to the contributor, the other half back to
+    // the depositor and the funder in proportion to what each put in.
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 9_000 + 500);
+    assert_eq!(s.token.balance(&funder), 9_000 + 500);
+    assert_eq!(s.escrow.get_escrow_info(&1).refund_history.len(), 2);
+    assert!(s.escrow.get_escrow_funders(&1).is_empty());
+}
+
+#[test]
+fn test_resolve_dispute_validation() {
+    let s = Setup::new();
+
+    assert_eq!(
+        s.escrow.try_resolve_dispute(&1, &5_000),
+        Err(Err(DisputeError::DisputeNotFound.into()))
+    );
+
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.reason);
+    assert_eq!(
+        s.escrow.try_resolve_dispute(&1, &10_001),
+        Err(Ok(Error::InvalidSplit))
+    );
+
+    s.escrow.resolve_dispute(&1, &5_000);
+    assert_eq!(
+        s.escrow.try_resolve_dispute(&1, &5_000),
+        Err(Err(DisputeError::DisputeNotFound.into()))
+    );
+    assert_eq!(s.escrow.get_arbiter(), Some(s.arbiter.clone()));
+}
+
+#[test]
+fn test_claim_recipient_can_open_dispute() {
+    let s = Setup::new();
+    let claimant = Address::generate(&s.env);
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &deadline);
+    assert_eq!(
+        s.escrow
+            .try_open_dispute(&claimant, &2, &claimant, &s.reason),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.escrow
+        .authorize_claim(&2, &claimant, &DisputeReason::Other);
+    s.escrow.open_dispute(&claimant, &2, &claimant, &s.reason);
+    assert_eq!(s.escrow.get_dispute(&2).contributor, claimant);
+}
+
+#[test]
+fn test_resolve_dispute_respects_pause() {
+    let s = Setup::new();
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.reason);
+
+    s.escrow.set_paused(&None, &Some(true), &None, &None);
+    assert_eq!(
+        s.escrow.try_resolve_dispute(&1, &5_000),
+        Err(Ok(Error::FundsPaused))
+    );
+    s.escrow.set_paused(&None, &Some(false), &Some(true), &None);
+    assert_eq!(
+        s.escrow.try_resolve_dispute(&1, &5_000),
+        Err(Ok(Error::FundsPaused))
+    );
+
+    s.escrow.set_paused(&None, &None, &Some(false), &None);
+    s.escrow.resolve_dispute(&1, &5_000);
+    assert_eq!(s.token.balance(&s.contributor), 500);
+}
@@ -0,0 +1,102 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, BytesN,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.lock(1, 1_000);
+        base.lock(2, 1_000);
+        Self { base }
+    }
+
+    fn reason(&self) -> BytesN<32> {
+        BytesN::from_array(&self.env, &[4; 32])
+    }
+}
+
+#[test]
+fn test_freeze_blocks_only_that_escrow() {
+    let s = Setup::new();
+    s.escrow.freeze_escrow(&s.admin, &1, &s.reason());
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::FundsPaused))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::FundsPaused))
+    );
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::FundsPaused)));
+
+    // Other escrows and the global pause flags are unaffected.
+    assert!(!s.escrow.is_paused());
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_unfreeze_restores_release() {
+    let s = Setup::new();
+    s.escrow.freeze_escrow(&s.admin, &1, &s.reason());
+    let freeze = s.escrow.get_escrow_freeze(&1).unwrap();
+    assert_eq!(freeze.frozen_by, s.admin);
+    assert_eq!(freeze.reason_hash, s.reason());
+
+    s.escrow.unfreeze_escrow(&s.admin, &1);
+    assert_eq!(s.escrow.get_escrow_freeze(&1), None);
+    assert_eq!(
+        s.escrow.try_unfreeze_escrow(&s.admin, &1),
+        Err(Ok(Error::NotPaused))
+    );
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_only_admin_or_arbiter_can_freeze() {
+    let s = Setup::new();
+    let arbiter = Address::generate(&s.env);
+    let stranger = Address::generate(&s.env);
+    s.escrow.set_arbiter(&arbiter);
+
+    assert_eq!(
+        s.escrow.try_freeze_escrow(&stranger, &1, &s.reason()),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_freeze_escrow(&arbiter, &9, &s.reason()),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    s.escrow.freeze_escrow(&arbiter, &1, &s.reason());
+    assert_eq!(s.escrow.get_escrow_freeze(&1).unwrap().frozen_by, arbiter);
+    assert_eq!(
+        s.escrow.try_unfreeze_escrow(&stranger, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.escrow.unfreeze_escrow(&arbiter, &1);
+}
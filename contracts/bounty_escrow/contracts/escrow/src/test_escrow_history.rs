@@ -0,0 +1,85 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowAction};
+use soroban_sdk::testutils::Ledger;
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_history_records_lifecycle_in_order() {
+    let s = setup();
+    s.env.ledger().set_timestamp(100);
+    s.escrow.increase_escrow(&1, &500);
+    s.escrow.extend_deadline(&1, &2_000);
+    s.escrow.partial_release(&1, &s.contributor, &600);
+    s.env.ledger().set_timestamp(3_000);
+    s.escrow.refund(&1);
+
+    let history = s.escrow.get_escrow_history(&1, &0, &10);
+    assert_eq!(history.len(), 5);
+
+    let actions = [
+        (EscrowAction::Locked, &s.depositor, 1_000),
+        (EscrowAction::ToppedUp, &s.depositor, 500),
+        (EscrowAction::DeadlineExtended, &s.depositor, 0),
+        (EscrowAction::Released, &s.admin, 600),
+        (EscrowAction::Refunded, &s.depositor, 900),
+    ];
+    for (i, (action, actor, amount)) in actions.iter().enumerate() {
+        let entry = history.get(i as u32).unwrap();
+        assert_eq!(entry.action, *action);
+        assert_eq!(entry.actor, **actor);
+        assert_eq!(entry.amount, *amount);
+    }
+    assert_eq!(history.get(0).unwrap().timestamp, 0);
+    assert_eq!(history.get(1).unwrap().timestamp, 100);
+    assert_eq!(history.get(4).unwrap().timestamp, 3_000);
+}
+
+#[test]
+fn test_history_pagination() {
+    let s = setup();
+    s.escrow.increase_escrow(&1, &100);
+    s.escrow.increase_escrow(&1, &200);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    let page = s.escrow.get_escrow_history(&1, &1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().amount, 100);
+    assert_eq!(page.get(1).unwrap().amount, 200);
+
+    let tail = s.escrow.get_escrow_history(&1, &3, &10);
+    assert_eq!(tail.len(), 1);
+    assert_eq!(tail.get(0).unwrap().action, EscrowAction::Released);
+    assert_eq!(tail.get(0).unwrap().amount, 1_300);
+
+    assert!(s.escrow.get_escrow_history(&1, &10, &10).is_empty());
+    assert!(s.escrow.get_escrow_history(&1, &0, &0).is_empty());
+}
+
+#[test]
+fn test_history_is_bounded() {
+    let s = setup();
+    for _ in 0..60 {
+        s.escrow.increase_escrow(&1, &1);
+    }
+
+    let history = s.escrow.get_escrow_history(&1, &0, &100);
+    assert_eq!(history.len(), 50);
+    for entry in history.iter() {
+        assert_eq!(entry.action, EscrowAction::ToppedUp);
+    }
+}
+
+#[test]
+fn test_history_unknown_bounty() {
+    let s = setup();
+    assert_eq!(
+        s.escrow.try_get_escrow_history(&99, &0, &10),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -0,0 +1,181 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Ledger, Address, Env, Symbol};
+
+/// Hook that records the last call of each kind in its own storage.
+#[contract]
+pub struct RecordingHook;
+
+#[contractimpl]
+impl RecordingHook {
+    pub fn on_release(env: Env, escrow: Address, bounty_id: u64, recipient: Address, amount: i128) {
+        Self::record(
+            &env,
+            symbol_short!("release"),
+            escrow,
+            bounty_id,
+            recipient,
+            amount,
+        );
+    }
+
+    pub fn on_refund(env: Env, escrow: Address, bounty_id: u64, recipient: Address, amount: i128) {
+        Self::record(
+            &env,
+            symbol_short!("refund"),
+            escrow,
+            bounty_id,
+            recipient,
+            amount,
+        );
+    }
+
+    pub fn calls(env: Env, kind: Symbol) -> u32 {
+        env.storage().instance().get(&(kind, 0u32)).unwrap_or(0)
+    }
+
+    pub fn last(env: Env, kind: Symbol) -> Option<(Address, u64, Address, i128)> {
+        env.storage().instance().get(&kind)
+    }
+}
+
+impl RecordingHook {
+    fn record(
+        env: &Env,
+        kind: Symbol,
+        escrow: Address,
+        bounty_id: u64,
+        recipient: Address,
+        amount: i128,
+    ) {
+        let calls = Self::calls(env.clone(), kind.clone()) + 1;
+        env.storage().instance().set(&(kind.clone(), 0u32), &calls);
+        env.storage()
+            .instance()
+            .set(&kind, &(escrow, bounty_id, recipient, amount));
+    }
+}
+
+mod reentrant {
+    use crate::BountyEscrowContractClient;
+    use soroban_sdk::{contract, contractimpl, Address, Env};
+
+    /// Hook that tries to pull the remaining funds back out through the escrow.
+    #[contract]
+    pub struct ReentrantHook;
+
+    #[contractimpl]
+    impl ReentrantHook {
+        pub fn on_release(
+            env: Env,
+            escrow: Address,
+            bounty_id: u64,
+            recipient: Address,
+            _amount: i128,
+        ) {
+            BountyEscrowContractClient::new(&env, &escrow)
+                .partial_release(&bounty_id, &recipient, &1);
+        }
+
+        pub fn on_refund(
+            _env: Env,
+            _escrow: Address,
+            _bounty_id: u64,
+            _recipient: Address,
+            _amount: i128,
+        ) {
+            panic!("refunds are not allowed");
+        }
+    }
+}
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    hook: RecordingHookClient<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.lock(1, 1_000);
+
+        let hook_id = base.env.register_contract(None, RecordingHook);
+        let hook = RecordingHookClient::new(&base.env, &hook_id);
+        base.escrow.set_escrow_hook(&1, &Some(hook_id));
+        Self { base, hook }
+    }
+}
+
+#[test]
+fn test_release_invokes_hook() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_escrow_hook(&1), Some(s.hook.address.clone()));
+
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    s.escrow.partial_release(&1, &s.contributor, &600);
+
+    assert_eq!(s.hook.calls(&symbol_short!("release")), 2);
+    assert_eq!(
+        s.hook.last(&symbol_short!("release")),
+        Some((s.escrow.address.clone(), 1, s.contributor.clone(), 600))
+    );
+    assert_eq!(s.hook.calls(&symbol_short!("refund")), 0);
+}
+
+#[test]
+fn test_refund_invokes_hook() {
+    let s = Setup::new();
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    s.escrow.refund(&1);
+
+    assert_eq!(s.hook.calls(&symbol_short!("refund")), 1);
+    assert_eq!(
+        s.hook.last(&symbol_short!("refund")),
+        Some((s.escrow.address.clone(), 1, s.depositor.clone(), 1_000))
+    );
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_hook_is_per_escrow_and_removable() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &500, &deadline);
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.hook.calls(&symbol_short!("release")), 0);
+
+    s.escrow.set_escrow_hook(&1, &None);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.hook.calls(&symbol_short!("release")), 0);
+    assert_eq!(
+        s.escrow
+            .try_set_escrow_hook(&9, &Some(s.hook.address.clone())),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_reentrant_or_failing_hook_reverts_operation() {
+    let s = Setup::new();
+    let hook_id = s.env.register_contract(None, reentrant::ReentrantHook);
+    s.escrow.set_escrow_hook(&1, &Some(hook_id));
+
+    assert!(s.escrow.try_release_funds(&1, &s.contributor).is_err());
+    assert!(s.escrow.try_cancel_escrow(&1).is_err());
+
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
@@ -0,0 +1,111 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Ledger, vec};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    fn lock(&self, bounty_id: u64) {
+        self.base.lock(bounty_id, 1_000);
+    }
+}
+
+#[test]
+fn test_list_escrows_paginates_in_lock_order() {
+    let s = Setup::new();
+    for id in [7, 3, 5, 1] {
+        s.lock(id);
+    }
+
+    assert_eq!(s.escrow.list_escrows(&0, &10), vec![&s.env, 7, 3, 5, 1]);
+    assert_eq!(s.escrow.list_escrows(&1, &2), vec![&s.env, 3, 5]);
+    assert_eq!(s.escrow.list_escrows(&3, &5), vec![&s.env, 1]);
+    assert!(s.escrow.list_escrows(&4, &5).is_empty());
+    assert!(s.escrow.list_escrows(&0, &0).is_empty());
+}
+
+#[test]
+fn test_list_escrows_by_status_follows_transitions() {
+    let s = Setup::new();
+    for id in 1..=5 {
+        s.lock(id);
+    }
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::Locked, &0, &10),
+        vec![&s.env, 1, 2, 3, 4, 5]
+    );
+
+    s.escrow.release_funds(&2, &s.contributor);
+    s.escrow.cancel_escrow(&4);
+    s.escrow
+        .approve_refund(&5, &400, &s.depositor, &RefundMode::Partial);
+    s.escrow.refund(&5);
+    s.env.ledger().set_timestamp(2_000);
+    s.escrow.refund(&1);
+
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::Locked, &0, &10),
+        vec![&s.env, 3]
+    );
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::Released, &0, &10),
+        vec![&s.env, 2]
+    );
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::Refunded, &0, &10),
+        vec![&s.env, 1]
+    );
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::PartiallyRefunded, &0, &10),
+        vec![&s.env, 5]
+    );
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::Cancelled, &0, &10),
+        vec![&s.env, 4]
+    );
+    assert_eq!(s.escrow.list_escrows(&0, &10).len(), 5);
+}
+
+#[test]
+fn test_list_escrows_by_status_pagination() {
+    let s = Setup::new();
+    for id in 1..=4 {
+        s.lock(id);
+    }
+    s.escrow.release_funds(&2, &s.contributor);
+
+    // 4 took the place of 2 when it left the list
+    assert_eq!(
+        s.escrow
+            .list_escrows_by_status(&EscrowStatus::Locked, &1, &1),
+        vec![&s.env, 4]
+    );
+    assert!(s
+        .escrow
+        .list_escrows_by_status(&EscrowStatus::Refunded, &0, &10)
+        .is_empty());
+}
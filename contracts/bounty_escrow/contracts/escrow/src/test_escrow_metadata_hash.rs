@@ -0,0 +1,50 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error};
+use soroban_sdk::{symbol_short, BytesN};
+
+#[test]
+fn test_lock_with_metadata_hash_and_label() {
+    let s = TestSetup::new();
+    let hash = BytesN::from_array(&s.env, &[7u8; 32]);
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    s.escrow.lock_funds_with_metadata_hash(
+        &s.depositor,
+        &1,
+        &1_000,
+        &deadline,
+        &hash,
+        &Some(symbol_short!("gfi")),
+    );
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.metadata_hash, Some(hash.into()));
+    assert_eq!(escrow.label, Some(symbol_short!("gfi")));
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_lock_without_label_and_plain_lock() {
+    let s = TestSetup::new();
+    let hash = BytesN::from_array(&s.env, &[1u8; 32]);
+    let deadline = s.env.ledger().timestamp() + 1_000;
+
+    s.escrow
+        .lock_funds_with_metadata_hash(&s.depositor, &1, &500, &deadline, &hash, &None);
+    s.escrow.lock_funds(&s.depositor, &2, &500, &deadline);
+
+    let first = s.escrow.get_escrow_info(&1);
+    assert_eq!(first.metadata_hash, Some(hash.clone().into()));
+    assert_eq!(first.label, None);
+
+    let second = s.escrow.get_escrow_info(&2);
+    assert_eq!(second.metadata_hash, None);
+    assert_eq!(second.label, None);
+
+    let res =
+        s.escrow
+            .try_lock_funds_with_metadata_hash(&s.depositor, &1, &500, &deadline, &hash, &None);
+    assert_eq!(res, Err(Ok(Error::BountyExists)));
+}
@@ -0,0 +1,206 @@
+#![cfg(test)]
+
+use crate::funders::{FundingError, MAX_FUNDERS};
+use crate::migration::MigrationError;
+use crate::test_fee_on_transfer::{FeeToken, FeeTokenClient};
+use crate::{
+    test_setup::TestSetup, BountyEscrowContract, BountyEscrowContractClient, Error,
+    EscrowMigration, EscrowStatus,
+};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address, Map, Vec};
+
+const DEADLINE: u64 = 1_000;
+
+/// `escrow` is the contract escrows migrate from.
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    target: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let target_id = base.env.register_contract(None, BountyEscrowContract);
+        let target = BountyEscrowContractClient::new(&base.env, &target_id);
+        target.init(&base.admin, &base.token.address);
+        base.escrow.set_migration_peer(&target_id, &true);
+        target.set_migration_peer(&base.escrow.address, &true);
+
+        base.escrow
+            .lock_funds(&base.depositor, &1, &1_000, &DEADLINE);
+        Self { base, target }
+    }
+
+    /// What `migrate_escrow` would send for escrow 1.
+    fn migration(&self) -> EscrowMigration {
+        EscrowMigration {
+            token: self.token.address.clone(),
+            source_bounty_id: 1,
+            escrow: self.escrow.get_escrow_info(&1),
+            history: Vec::new(&self.env),
+            funders: Map::new(&self.env),
+        }
+    }
+}
+
+#[test]
+fn test_migrated_escrow_keeps_its_records() {
+    let s = Setup::new();
+    let funder = Address::generate(&s.env);
+    s.token_admin.mint(&funder, &500);
+    s.escrow.contribute_to_escrow(&funder, &1, &500);
+    s.escrow.assign_contributor(&1, &s.contributor, &100);
+    s.escrow.accept_assignment(&1);
+
+    s.escrow.migrate_escrow(&1, &s.target.address, &7);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.token.balance(&s.target.address), 1_500);
+    assert_eq!(
+        s.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_lock_funds(&s.depositor, &1, &100, &DEADLINE),
+        Err(Ok(Error::BountyExists))
+    );
+
+    let escrow = s.target.get_escrow_info(&7);
+    assert_eq!(escrow.depositor, s.depositor);
+    assert_eq!(escrow.amount, 1_500);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(s.target.get_escrow_funders(&7).get(funder), Some(500));
+    assert_eq!(s.target.get_escrow_history(&7, &0, &10).len(), 2);
+    assert_eq!(
+        s.target.get_assignment(&7).unwrap().contributor,
+        s.contributor
+    );
+
+    // The assignee is still the only one the target can pay.
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.target.try_release_funds(&7, &stranger),
+        Err(Ok(Error::Unauthorized))
+    );
+    s.target.release_funds(&7, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_500);
+}
+
+#[test]
+fn test_migration_needs_trusted_peers() {
+    let s = Setup::new();
+    let other = s.env.register_contract(None, BountyEscrowContract);
+    assert_eq!(
+        s.escrow.try_migrate_escrow(&1, &other, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // The target must trust the source too; its refusal undoes the move.
+    s.target.set_migration_peer(&s.escrow.address, &false);
+    assert!(!s.target.is_migration_peer(&s.escrow.address));
+    assert!(s
+        .escrow
+        .try_migrate_escrow(&1, &s.target.address, &1)
+        .is_err());
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_escrow_with_bond_stays() {
+    let s = Setup::new();
+    s.escrow
+        .assign_contributor_with_bond(&1, &s.contributor, &100, &50);
+    s.token_admin.mint(&s.contributor, &50);
+    s.escrow.accept_assignment(&1);
+    assert_eq!(
+        s.escrow.try_migrate_escrow(&1, &s.target.address, &1),
+        Err(Err(MigrationError::MigrationNotAllowed.into()))
+    );
+
+    s.target.lock_funds(&s.depositor, &2, &100, &DEADLINE);
+    s.escrow.lock_funds(&s.depositor, &3, &100, &DEADLINE);
+    // Taken ids on the target abort the migration.
+    assert!(s
+        .escrow
+        .try_migrate_escrow(&3, &s.target.address, &2)
+        .is_err());
+    assert_eq!(s.escrow.get_escrow_info(&3).status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_target_only_takes_locked_funds() {
+    let s = Setup::new();
+    let source = s.escrow.address.clone();
+    let mut migration = s.migration();
+    migration.escrow.status = EscrowStatus::Released;
+    assert_eq!(
+        s.target
+            .try_receive_migrated_escrow(&source, &7, &migration, &None, &None, &None),
+        Err(Err(MigrationError::MigratedEscrowNotLocked.into()))
+    );
+
+    let mut migration = s.migration();
+    migration.escrow.remaining_amount = 0;
+    assert_eq!(
+        s.target
+            .try_receive_migrated_escrow(&source, &7, &migration, &None, &None, &None),
+        Err(Err(MigrationError::MigratedEscrowNotLocked.into()))
+    );
+    assert_eq!(
+        s.target.try_get_escrow_info(&7),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_target_rejects_more_funders_than_it_tracks() {
+    let s = Setup::new();
+    let mut migration = s.migration();
+    for _ in 0..=MAX_FUNDERS {
+        migration.funders.set(Address::generate(&s.env), 1);
+    }
+    assert_eq!(
+        s.target.try_receive_migrated_escrow(
+            &s.escrow.address,
+            &7,
+            &migration,
+            &None,
+            &None,
+            &None
+        ),
+        Err(Err(FundingError::TooManyFunders.into()))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_target_checks_the_funds_arrived() {
+    let s = Setup::new();
+    let token = FeeTokenClient::new(&s.env, &s.env.register_contract(None, FeeToken));
+    let target = BountyEscrowContractClient::new(
+        &s.env,
+        &s.env.register_contract(None, BountyEscrowContract),
+    );
+    target.init(&s.admin, &token.address);
+    let source = Address::generate(&s.env);
+    target.set_migration_peer(&source, &true);
+    token.mint(&source, &1_000);
+
+    // The token keeps 1% of the transfer, so the escrow would be short.
+    let mut migration = s.migration();
+    migration.token = token.address.clone();
+    assert_eq!(
+        target.try_receive_migrated_escrow(&source, &7, &migration, &None, &None, &None),
+        Err(Err(MigrationError::MigratedFundsMissing.into()))
+    );
+    assert_eq!(token.balance(&source), 1_000);
+}
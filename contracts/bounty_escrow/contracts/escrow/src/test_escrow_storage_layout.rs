@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, DataKey, Escrow, EscrowKey, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, BytesN, Vec};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    fn has_details(&self, bounty_id: u64) -> bool {
+        self.env.as_contract(&self.escrow.address, || {
+            self.env
+                .storage()
+                .persistent()
+                .has(&EscrowKey::Details(bounty_id))
+        })
+    }
+}
+
+#[test]
+fn test_plain_escrow_has_no_details_entry() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    assert!(!s.has_details(1));
+
+    let contributor = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &contributor, &400);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.remaining_amount, 600);
+    assert_eq!(info.refund_history.len(), 0);
+    assert!(!s.has_details(1));
+}
+
+#[test]
+fn test_details_survive_core_updates() {
+    let s = Setup::new();
+    let hash = BytesN::from_array(&s.env, &[7; 32]);
+    s.escrow.lock_funds_with_metadata_hash(
+        &s.depositor,
+        &1,
+        &1_000,
+        &s.deadline(),
+        &hash,
+        &Some(symbol_short!("repo")),
+    );
+    assert!(s.has_details(1));
+
+    let contributor = Address::generate(&s.env);
+    s.escrow.release_funds(&1, &contributor);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Released);
+    assert_eq!(info.metadata_hash, Some(hash.into()));
+    assert_eq!(info.label, Some(symbol_short!("repo")));
+}
+
+#[test]
+fn test_migration_splits_whole_escrows() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &s.deadline());
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &s.deadline());
+
+    let labelled = Escrow {
+        label: Some(symbol_short!("repo")),
+        ..s.escrow.get_escrow_info(&2)
+    };
+    let plain = s.escrow.get_escrow_info(&1);
+    s.env.as_contract(&s.escrow.address, || {
+        let storage = s.env.storage();
+        storage.persistent().set(&DataKey::Escrow(1), &plain);
+        storage.persistent().set(&DataKey::Escrow(2), &labelled);
+        storage.instance().set(&DataKey::SchemaVersion, &3u32);
+    });
+
+    assert_eq!(s.escrow.migrate(&0, &20), None);
+    assert!(!s.has_details(1));
+    assert!(s.has_details(2));
+    assert_eq!(
+        s.escrow.get_escrow_info(&1).refund_history,
+        Vec::new(&s.env)
+    );
+    assert_eq!(s.escrow.get_escrow_info(&2), labelled);
+}
@@ -0,0 +1,63 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use soroban_sdk::BytesN;
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_summary_tracks_deadline_and_payouts() {
+    let s = setup();
+    let summary = s.escrow.get_escrow_summary(&1);
+    assert_eq!(summary.status, EscrowStatus::Locked);
+    assert_eq!(summary.seconds_until_deadline, 1_000);
+    assert_eq!(summary.released_bps, 0);
+    assert!(!summary.refund_eligible);
+    assert!(!summary.frozen);
+
+    s.escrow.partial_release(&1, &s.contributor, &250);
+    s.advance_time(400);
+    let summary = s.escrow.get_escrow_summary(&1);
+    assert_eq!(summary.remaining_amount, 750);
+    assert_eq!(summary.seconds_until_deadline, 600);
+    assert_eq!(summary.released_bps, 2_500);
+
+    s.advance_time(601);
+    let summary = s.escrow.get_escrow_summary(&1);
+    assert_eq!(summary.seconds_until_deadline, 0);
+    assert!(summary.refund_eligible);
+
+    // Refunded funds do not count as released.
+    s.escrow.refund(&1);
+    let summary = s.escrow.get_escrow_summary(&1);
+    assert_eq!(summary.remaining_amount, 0);
+    assert_eq!(summary.released_bps, 2_500);
+    assert!(!summary.refund_eligible);
+}
+
+#[test]
+fn test_frozen_escrow_is_not_refund_eligible() {
+    let s = setup();
+    s.advance_time(1_001);
+    assert!(s.escrow.get_escrow_summary(&1).refund_eligible);
+
+    let reason = BytesN::from_array(&s.env, &[4; 32]);
+    s.escrow.freeze_escrow(&s.admin, &1, &reason);
+    let summary = s.escrow.get_escrow_summary(&1);
+    assert!(summary.frozen);
+    assert!(!summary.refund_eligible);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::FundsPaused)));
+}
+
+#[test]
+fn test_summary_of_missing_escrow() {
+    let s = setup();
+    assert_eq!(
+        s.escrow.try_get_escrow_summary(&9),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
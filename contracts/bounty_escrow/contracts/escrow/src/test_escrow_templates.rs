@@ -0,0 +1,138 @@
+#![cfg(test)]
+
+use crate::templates::TemplateError;
+use crate::{test_setup::TestSetup, Error, EscrowTemplate, TemplateMilestone};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN};
+
+const OFFSET: u64 = 7 * 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    maintainer: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let maintainer = Address::generate(&base.env);
+        Self { base, maintainer }
+    }
+
+    fn template(&self) -> EscrowTemplate {
+        EscrowTemplate {
+            deadline_offset: OFFSET,
+            release_fee_rate: Some(300),
+            approver: Some(self.maintainer.clone()),
+            milestones: vec![&self.env],
+        }
+    }
+
+    fn milestone(&self, share_bps: u32, deadline_offset: u64) -> TemplateMilestone {
+        TemplateMilestone {
+            share_bps,
+            description_hash: BytesN::from_array(&self.env, &[share_bps as u8; 32]),
+            deadline_offset,
+        }
+    }
+}
+
+#[test]
+fn test_lock_from_template_applies_presets() {
+    let s = Setup::new();
+    s.escrow.set_escrow_template(&1, &s.template());
+    assert_eq!(s.escrow.get_escrow_template(&1), Some(s.template()));
+
+    s.escrow
+        .lock_funds_from_template(&s.depositor, &1, &10, &1_000);
+    let escrow = s.escrow.get_escrow_info(&10);
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(escrow.deadline, s.env.ledger().timestamp() + OFFSET);
+    assert_eq!(s.escrow.get_escrow_fee_rate(&10), 300);
+    assert_eq!(
+        s.escrow.get_escrow_approver(&10),
+        Some(s.maintainer.clone())
+    );
+}
+
+#[test]
+fn test_milestone_template_splits_amount() {
+    let s = Setup::new();
+    let mut template = s.template();
+    template.milestones = vec![
+        &s.env,
+        s.milestone(3_333, OFFSET / 2),
+        s.milestone(6_667, OFFSET),
+    ];
+    s.escrow.set_escrow_template(&2, &template);
+
+    s.escrow
+        .lock_funds_from_template(&s.depositor, &2, &20, &1_001);
+    let milestones = s.escrow.get_milestones(&20);
+    assert_eq!(milestones.len(), 2);
+    assert_eq!(milestones.get(0).unwrap().amount, 333);
+    assert_eq!(milestones.get(1).unwrap().amount, 668);
+    assert_eq!(
+        s.escrow.get_escrow_info(&20).deadline,
+        s.env.ledger().timestamp() + OFFSET
+    );
+}
+
+#[test]
+fn test_invalid_templates_are_rejected() {
+    let s = Setup::new();
+    let mut template = s.template();
+    template.release_fee_rate = Some(10_000);
+    assert_eq!(
+        s.escrow.try_set_escrow_template(&1, &template),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+
+    let mut template = s.template();
+    template.milestones = vec![&s.env, s.milestone(5_000, OFFSET)];
+    assert_eq!(
+        s.escrow.try_set_escrow_template(&1, &template),
+        Err(Ok(Error::InvalidSplit))
+    );
+
+    template.milestones = vec![&s.env, s.milestone(10_000, OFFSET + 1)];
+    assert_eq!(
+        s.escrow.try_set_escrow_template(&1, &template),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_from_template(&s.depositor, &9, &1, &1_000),
+        Err(Err(TemplateError::TemplateNotFound.into()))
+    );
+}
+
+#[test]
+fn test_removed_template_no_longer_locks() {
+    let s = Setup::new();
+    s.escrow.set_escrow_template(&1, &s.template());
+    s.escrow
+        .lock_funds_from_template(&s.depositor, &1, &10, &1_000);
+
+    s.escrow.remove_escrow_template(&1);
+    assert_eq!(s.escrow.get_escrow_template(&1), None);
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_from_template(&s.depositor, &1, &11, &1_000),
+        Err(Err(TemplateError::TemplateNotFound.into()))
+    );
+    assert_eq!(
+        s.escrow.try_remove_escrow_template(&1),
+        Err(Err(TemplateError::TemplateNotFound.into()))
+    );
+    assert_eq!(s.escrow.get_escrow_info(&10).amount, 1_000);
+}
@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_increase_escrow_updates_amounts() {
+    let s = setup();
+
+    s.escrow.increase_escrow(&1, &500);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_500);
+    assert_eq!(escrow.remaining_amount, 1_500);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_500);
+    assert_eq!(s.token.balance(&s.depositor), 8_500);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_500);
+}
+
+#[test]
+fn test_increase_after_partial_release() {
+    let s = setup();
+    s.escrow.partial_release(&1, &s.contributor, &400);
+
+    s.escrow.increase_escrow(&1, &200);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_200);
+    assert_eq!(escrow.remaining_amount, 800);
+    assert_eq!(s.token.balance(&s.escrow.address), 800);
+}
+
+#[test]
+fn test_increase_escrow_validation() {
+    let s = setup();
+
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow.try_increase_escrow(&2, &100),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &100),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_increase_escrow_respects_amount_policy() {
+    let s = setup();
+    s.escrow.set_amount_policy(&s.admin, &100, &1_200);
+
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &201),
+        Err(Ok(Error::AmountAboveMaximum))
+    );
+    s.escrow.increase_escrow(&1, &200);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_200);
+}
+
+#[test]
+fn test_increase_escrow_blocked_while_paused() {
+    let s = setup();
+    s.escrow.pause();
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &100),
+        Err(Ok(Error::FundsPaused))
+    );
+}
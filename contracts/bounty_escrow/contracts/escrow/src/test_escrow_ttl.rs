@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, DataKey, Error};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Ledger, LedgerInfo},
+    Env,
+};
+
+const DAY: u32 = 17_280;
+const EXTEND_TO: u32 = 180 * DAY;
+const START: u32 = 100;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        // Long minimum TTL so the token entries outlive the ledger jumps below.
+        env.ledger().set(LedgerInfo {
+            timestamp: 1_000,
+            protocol_version: 20,
+            sequence_number: START,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1_000,
+            min_persistent_entry_ttl: 200 * DAY,
+            max_entry_ttl: 400 * DAY,
+        });
+
+        let base = TestSetup::with_env(env);
+        base.escrow
+            .lock_funds(&base.depositor, &1, &1_000, &1_000_000_000);
+        Self { base }
+    }
+
+    fn advance_to(&self, sequence: u32) {
+        self.env
+            .ledger()
+            .with_mut(|li| li.sequence_number = sequence);
+    }
+
+    fn stored_ttl(&self, bounty_id: u64) -> u32 {
+        self.env.as_contract(&self.escrow.address, || {
+            self.env
+                .storage()
+                .persistent()
+                .get_ttl(&DataKey::Escrow(bounty_id))
+        })
+    }
+}
+
+#[test]
+fn test_lock_reports_ttl() {
+    let s = Setup::new();
+    let ttl = s.escrow.get_escrow_ttl(&1);
+    assert_eq!(ttl.live_until_ledger, START + EXTEND_TO);
+    assert_eq!(ttl.ledgers_remaining, EXTEND_TO);
+    assert!(s.stored_ttl(1) >= ttl.ledgers_remaining);
+
+    assert_eq!(
+        s.escrow.try_get_escrow_ttl(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_write_near_expiry_bumps_ttl() {
+    let s = Setup::new();
+
+    // Well above the threshold: nothing to do.
+    s.advance_to(START + 100 * DAY);
+    s.escrow.partial_release(&1, &s.contributor, &100);
+    assert_eq!(
+        s.escrow.get_escrow_ttl(&1).live_until_ledger,
+        START + EXTEND_TO
+    );
+
+    // Inside the last 30 days: the next write extends the record again.
+    let now = START + 160 * DAY;
+    s.advance_to(now);
+    s.escrow.partial_release(&1, &s.contributor, &100);
+    let ttl = s.escrow.get_escrow_ttl(&1);
+    assert_eq!(ttl.live_until_ledger, now + EXTEND_TO);
+    assert_eq!(s.stored_ttl(1), EXTEND_TO);
+}
+
+#[test]
+fn test_extend_escrow_ttl_forces_extension() {
+    let s = Setup::new();
+    let now = START + 10 * DAY;
+    s.advance_to(now);
+
+    let ttl = s.escrow.extend_escrow_ttl(&1);
+    assert_eq!(ttl.live_until_ledger, now + EXTEND_TO);
+    assert_eq!(ttl.ledgers_remaining, EXTEND_TO);
+    assert!(s.stored_ttl(1) >= EXTEND_TO);
+
+    assert_eq!(
+        s.escrow.try_extend_escrow_ttl(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+extern crate std;
+
+use crate::{events::EVENT_SCHEMA_VERSION, test_setup::TestSetup, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.lock(1, 1_000);
+        Self { base }
+    }
+
+    /// Topics of every event the escrow contract published so far.
+    fn escrow_topics(&self) -> std::vec::Vec<Vec<Val>> {
+        self.env
+            .events()
+            .all()
+            .iter()
+            .filter(|(contract, _, _)| *contract == self.escrow.address)
+            .map(|(_, topics, _)| topics)
+            .collect()
+    }
+
+    fn emitted(&self, name: Symbol, bounty_id: u64) -> bool {
+        self.escrow_topics().iter().any(|topics| {
+            topics.len() == 4
+                && Symbol::try_from_val(&self.env, &topics.get(0).unwrap()) == Ok(name.clone())
+                && u64::try_from_val(&self.env, &topics.get(1).unwrap()) == Ok(bounty_id)
+        })
+    }
+
+    /// Sequence numbers of the events of `bounty_id` published so far,
+    /// checking each carries the schema version before it.
+    fn sequences(&self, bounty_id: u64) -> std::vec::Vec<u64> {
+        self.escrow_topics()
+            .iter()
+            .filter(|topics| u64::try_from_val(&self.env, &topics.get(1).unwrap()) == Ok(bounty_id))
+            .map(|topics| {
+                let version = u32::try_from_val(&self.env, &topics.get(2).unwrap());
+                assert_eq!(version, Ok(EVENT_SCHEMA_VERSION));
+                u64::try_from_val(&self.env, &topics.get(3).unwrap()).unwrap()
+            })
+            .collect()
+    }
+}
+
+#[test]
+fn test_lock_emits_event() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &500, &deadline);
+    assert!(s.emitted(symbol_short!("f_lock"), 2));
+}
+
+#[test]
+fn test_partial_release_emits_distinct_event() {
+    let s = Setup::new();
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    assert!(s.emitted(symbol_short!("f_prel"), 1));
+    assert!(!s.emitted(symbol_short!("f_rel"), 1));
+}
+
+#[test]
+fn test_refund_approval_emits_event() {
+    let s = Setup::new();
+    s.escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+    assert!(s.emitted(symbol_short!("ref_appr"), 1));
+
+    s.escrow.refund(&1);
+    assert!(s.emitted(symbol_short!("f_ref"), 1));
+}
+
+#[test]
+fn test_rescue_emits_event() {
+    let s = Setup::new();
+    s.escrow.pause();
+    let target = Address::generate(&s.env);
+    s.escrow.emergency_withdraw(&target);
+
+    let rescue: Val = symbol_short!("em_wtd").into_val(&s.env);
+    assert!(s
+        .escrow_topics()
+        .iter()
+        .any(|topics| topics.len() == 2 && topics.get(0).unwrap().shallow_eq(&rescue)));
+}
+
+#[test]
+fn test_escrow_events_are_numbered_without_gaps() {
+    let s = Setup::new();
+    let locked = s.escrow.get_event_sequence(&1);
+    assert!(locked > 0);
+    assert_eq!(s.escrow.get_event_sequence(&2), 0);
+
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    let sequences = s.sequences(1);
+    assert_eq!(sequences.first(), Some(&1));
+    assert!(sequences.contains(&(locked + 1)));
+    for pair in sequences.windows(2) {
+        assert_eq!(pair[1], pair[0] + 1);
+    }
+    assert_eq!(s.escrow.get_event_sequence(&1), *sequences.last().unwrap());
+}
@@ -0,0 +1,89 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::testutils::Ledger;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    deadline: u64,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.deadline();
+        base.lock(1, 1_000);
+        Self { base, deadline }
+    }
+
+    fn expire(&self) {
+        self.env.ledger().set_timestamp(self.deadline + 1);
+    }
+}
+
+#[test]
+fn test_claim_expired_refund_returns_funds_to_depositor() {
+    let s = Setup::new();
+    s.expire();
+
+    s.escrow.claim_expired_refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(escrow.refund_history.len(), 1);
+}
+
+#[test]
+fn test_claim_expired_refund_before_deadline_fails() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_claim_expired_refund(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_claim_expired_refund_ignores_pending_approval() {
+    let s = Setup::new();
+    s.escrow
+        .approve_refund(&1, &300, &s.contributor, &RefundMode::Partial);
+    s.expire();
+
+    s.escrow.claim_expired_refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    let (_, _, remaining, approval) = s.escrow.get_refund_eligibility(&1);
+    assert_eq!(remaining, 0);
+    assert_eq!(approval, None);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_claim_expired_refund_requires_locked_escrow() {
+    let s = Setup::new();
+    s.escrow.release_funds(&1, &s.contributor);
+    s.expire();
+
+    assert_eq!(
+        s.escrow.try_claim_expired_refund(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        s.escrow.try_claim_expired_refund(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+
+use crate::{events::EscrowExpiring, test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    Address, Symbol, TryFromVal, Vec,
+};
+
+const DAY: u64 = 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let s = Self {
+            base: TestSetup::new(),
+        };
+        s.lock(1, DAY);
+        s.lock(2, 2 * DAY);
+        s.lock(3, 10 * DAY);
+        s
+    }
+
+    fn lock(&self, bounty_id: u64, expires_in: u64) {
+        let deadline = self.env.ledger().timestamp() + expires_in;
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &1_000, &deadline);
+    }
+
+    fn pings(&self) -> Vec<EscrowExpiring> {
+        let mut pings = Vec::new(&self.env);
+        for (contract, topics, data) in self.env.events().all().iter() {
+            if contract == self.escrow.address
+                && Symbol::try_from_val(&self.env, &topics.get(0).unwrap())
+                    == Ok(symbol_short!("esc_exp"))
+            {
+                pings.push_back(EscrowExpiring::try_from_val(&self.env, &data).unwrap());
+            }
+        }
+        pings
+    }
+}
+
+#[test]
+fn test_escrows_near_deadline_are_announced_once() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.ping_expiring(&10), 2);
+    let pings = s.pings();
+    assert_eq!(pings.len(), 2);
+    assert_eq!(pings.get(0).unwrap().bounty_id, 1);
+    assert_eq!(pings.get(0).unwrap().depositor, s.depositor);
+    assert_eq!(pings.get(0).unwrap().remaining_amount, 1_000);
+    assert_eq!(pings.get(1).unwrap().bounty_id, 2);
+
+    assert_eq!(s.escrow.ping_expiring(&10), 0);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 7 * DAY);
+    assert_eq!(s.escrow.ping_expiring(&10), 1);
+    assert_eq!(s.pings().last().unwrap().bounty_id, 3);
+}
+
+#[test]
+fn test_extended_deadline_is_announced_again() {
+    let s = Setup::new();
+    // Settled escrows are not reported.
+    let contributor = Address::generate(&s.env);
+    s.escrow.release_funds(&2, &contributor);
+    assert_eq!(s.escrow.ping_expiring(&10), 1);
+
+    let deadline = s.escrow.get_escrow_info(&1).deadline;
+    s.escrow.extend_deadline(&1, &(deadline + DAY));
+    assert_eq!(s.escrow.ping_expiring(&10), 1);
+    assert_eq!(s.pings().last().unwrap().deadline, deadline + DAY);
+}
+
+#[test]
+fn test_ping_is_batched() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.ping_expiring(&1), 1);
+    assert_eq!(s.escrow.ping_expiring(&1), 1);
+    assert_eq!(s.escrow.ping_expiring(&1), 0);
+    assert_eq!(
+        s.escrow.try_ping_expiring(&0),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
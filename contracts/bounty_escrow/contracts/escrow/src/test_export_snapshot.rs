@@ -0,0 +1,101 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::testutils::Ledger;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.env.ledger().set_timestamp(1_000);
+        Self { base }
+    }
+
+    fn lock_at(&self, bounty_id: u64, timestamp: u64) {
+        self.env.ledger().set_timestamp(timestamp);
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &1_000, &(timestamp + 5_000));
+    }
+}
+
+#[test]
+fn test_snapshot_rows() {
+    let s = Setup::new();
+    s.lock_at(1, 1_000);
+    s.lock_at(2, 1_100);
+    s.lock_at(3, 1_200);
+
+    s.env.ledger().set_timestamp(1_300);
+    s.escrow.assign_contributor(&1, &s.contributor, &600);
+    s.escrow.accept_assignment(&1);
+    s.escrow.partial_release(&1, &s.contributor, &400);
+
+    s.env.ledger().set_timestamp(1_400);
+    s.escrow
+        .approve_refund(&2, &250, &s.depositor, &RefundMode::Partial);
+    s.escrow.refund(&2);
+
+    let rows = s.escrow.export_escrow_snapshot(&0, &10);
+    assert_eq!(rows.len(), 3);
+
+    let first = rows.get(0).unwrap();
+    assert_eq!(first.bounty_id, 1);
+    assert_eq!(first.depositor, s.depositor);
+    assert_eq!(first.contributor, Some(s.contributor.clone()));
+    assert_eq!(
+        (
+            first.amount,
+            first.released,
+            first.refunded,
+            first.remaining_amount
+        ),
+        (1_000, 400, 0, 600)
+    );
+    assert_eq!(first.status, EscrowStatus::Locked);
+    assert_eq!((first.locked_at, first.updated_at), (Some(1_000), 1_300));
+
+    let second = rows.get(1).unwrap();
+    assert_eq!(second.contributor, None);
+    assert_eq!(
+        (second.released, second.refunded, second.remaining_amount),
+        (0, 250, 750)
+    );
+    assert_eq!(second.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!((second.locked_at, second.updated_at), (Some(1_100), 1_400));
+
+    let third = rows.get(2).unwrap();
+    assert_eq!(third.deadline, 6_200);
+    assert_eq!((third.locked_at, third.updated_at), (Some(1_200), 1_200));
+}
+
+#[test]
+fn test_snapshot_pagination() {
+    let s = Setup::new();
+    for bounty_id in 1..=5 {
+        s.lock_at(bounty_id, 1_000 + 100 * bounty_id);
+    }
+
+    let page = s.escrow.export_escrow_snapshot(&1, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().bounty_id, 2);
+    assert_eq!(page.get(1).unwrap().bounty_id, 3);
+
+    assert_eq!(s.escrow.export_escrow_snapshot(&4, &10).len(), 1);
+    assert!(s.escrow.export_escrow_snapshot(&5, &10).is_empty());
+    assert!(s
+        .escrow
+        .export_escrow_snapshot(&u32::MAX, &u32::MAX)
+        .is_empty());
+}
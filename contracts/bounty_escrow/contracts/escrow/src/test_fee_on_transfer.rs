@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, EscrowAction, LockFundsItem, Milestone,
+};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, testutils::Address as _, token, vec, Address, BytesN, Env,
+};
+
+#[contracttype]
+enum FeeTokenKey {
+    Balance(Address),
+}
+
+/// Token that burns 1% of every transfer, like a deflationary asset.
+#[contract]
+pub struct FeeToken;
+
+#[contractimpl]
+impl FeeToken {
+    pub fn mint(env: Env, to: Address, amount: i128) {
+        Self::credit(&env, &to, amount);
+    }
+
+    pub fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+        from.require_auth();
+        Self::credit(&env, &from, -amount);
+        Self::credit(&env, &to, amount - amount / 100);
+    }
+
+    pub fn balance(env: Env, id: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&FeeTokenKey::Balance(id))
+            .unwrap_or(0)
+    }
+
+    fn credit(env: &Env, owner: &Address, amount: i128) {
+        let key = FeeTokenKey::Balance(owner.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    depositor: Address,
+    contributor: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let token_id = env.register_contract(None, FeeToken);
+        FeeTokenClient::new(&env, &token_id).mint(&depositor, &10_000);
+        let token = token::Client::new(&env, &token_id);
+
+        let escrow_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &escrow_id);
+        escrow.init(&admin, &token_id);
+
+        Self {
+            env,
+            depositor,
+            contributor,
+            token,
+            escrow,
+        }
+    }
+}
+
+#[test]
+fn test_lock_tracks_received_amount() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!((escrow.amount, escrow.remaining_amount), (990, 990));
+    assert_eq!(s.token.balance(&s.escrow.address), 990);
+    assert_eq!(s.escrow.get_balance_shortfall(), 0);
+    assert_eq!(s.escrow.check_invariants().total_value_locked, 990);
+
+    let history = s.escrow.get_escrow_history(&1, &0, &10);
+    assert_eq!(history.get(0).unwrap().action, EscrowAction::Locked);
+    assert_eq!(history.get(0).unwrap().amount, 990);
+
+    // The release can pay out everything that was tracked.
+    s.escrow.release_funds(&1, &s.contributor);
+    assert!(!s.escrow.get_pause_flags().release_paused);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.token.balance(&s.contributor), 990 - 9);
+}
+
+#[test]
+fn test_top_ups_track_received_amount() {
+    let s = Setup::new();
+    let funder = Address::generate(&s.env);
+    FeeTokenClient::new(&s.env, &s.token.address).mint(&funder, &1_000);
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    s.escrow.increase_escrow(&1, &500);
+    s.escrow.contribute_to_escrow(&funder, &1, &200);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 990 + 495 + 198);
+    assert_eq!(escrow.remaining_amount, escrow.amount);
+    assert_eq!(s.escrow.get_escrow_funders(&1).get(funder), Some(198));
+    assert_eq!(s.token.balance(&s.escrow.address), escrow.amount);
+    assert_eq!(s.escrow.get_balance_shortfall(), 0);
+}
+
+#[test]
+fn test_batch_lock_splits_received_amount() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let item = |bounty_id: u64, amount: i128| LockFundsItem {
+        bounty_id,
+        depositor: s.depositor.clone(),
+        amount,
+        deadline,
+    };
+    s.escrow
+        .batch_lock_funds(&vec![&s.env, item(1, 1_000), item(2, 3_000)]);
+
+    // 4_000 arrives as 3_960: 990 for the first escrow and the rest for the
+    // second.
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 990);
+    assert_eq!(s.escrow.get_escrow_info(&2).amount, 2_970);
+    assert_eq!(s.token.balance(&s.escrow.address), 3_960);
+    assert_eq!(s.escrow.get_balance_shortfall(), 0);
+}
+
+#[test]
+fn test_milestones_scaled_to_received_amount() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let milestone = |amount: i128| Milestone {
+        amount,
+        description_hash: BytesN::from_array(&s.env, &[1; 32]),
+        deadline,
+    };
+    s.escrow.lock_funds_with_milestones(
+        &s.depositor,
+        &1,
+        &vec![&s.env, milestone(400), milestone(600)],
+    );
+
+    let milestones = s.escrow.get_milestones(&1);
+    assert_eq!(milestones.get(0).unwrap().amount, 396);
+    assert_eq!(milestones.get(1).unwrap().amount, 594);
+
+    for index in 0..2 {
+        s.escrow.approve_milestone(&1, &index);
+        s.escrow.release_milestone(&1, &index, &s.contributor);
+    }
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
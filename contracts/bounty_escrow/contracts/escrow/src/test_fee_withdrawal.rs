@@ -0,0 +1,75 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.escrow
+            .update_fee_config(&None, &Some(1_000), &Some(treasury.clone()), &Some(true));
+        base.escrow.accept_treasury_role();
+
+        base.lock(1, 4_000);
+        base.lock(2, 1_000);
+        Self { base, treasury }
+    }
+}
+
+#[test]
+fn test_fees_accrue_until_withdrawn() {
+    let s = Setup::new();
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.partial_release(&2, &s.contributor, &500);
+
+    assert_eq!(s.escrow.get_accrued_fees(), 400 + 50);
+    assert_eq!(s.token.balance(&s.treasury), 0);
+    // Accrued fees are owed to the treasury, not surplus the admin can rescue.
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 0);
+
+    s.escrow.withdraw_fees(&300);
+    assert_eq!(s.env.auths()[0].0, s.admin);
+    assert_eq!(s.token.balance(&s.treasury), 300);
+    assert_eq!(s.escrow.get_accrued_fees(), 150);
+
+    s.escrow.withdraw_fees(&150);
+    assert_eq!(s.token.balance(&s.treasury), 450);
+    assert_eq!(s.token.balance(&s.escrow.address), 500);
+}
+
+#[test]
+fn test_withdrawal_is_bounded_by_accrued_fees() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_accrued_fees(), 0);
+    assert_eq!(
+        s.escrow.try_withdraw_fees(&1),
+        Err(Ok(Error::InsufficientFunds))
+    );
+
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(
+        s.escrow.try_withdraw_fees(&101),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    assert_eq!(
+        s.escrow.try_withdraw_fees(&0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    // Locked escrow funds cannot be pulled out as fees.
+    assert_eq!(s.token.balance(&s.escrow.address), 4_100);
+}
@@ -26,10 +26,8 @@ fn create_escrow_contract<'a>(env: &Env) -> BountyEscrowContractClient<'a> {
 
 struct TestSetup<'a> {
     env: Env,
-    admin: Address,
     depositor: Address,
     token: token::Client<'a>,
-    token_admin: token::StellarAssetClient<'a>,
     escrow: BountyEscrowContractClient<'a>,
 }
 
@@ -48,10 +46,8 @@ impl<'a> TestSetup<'a> {
 
         Self {
             env,
-            admin,
             depositor,
             token,
-            token_admin,
             escrow,
         }
     }
@@ -202,7 +198,7 @@ fn test_partial_release_race_prevents_double_spend() {
     let second_partial = setup
         .escrow
         .try_partial_release(&bounty_id, &recipient, &50_000);
-    assert_eq!(second_partial, Err(Ok(Error::InsufficientFunds)));
+    assert_eq!(second_partial, Err(Ok(Error::AmountExceedsRemaining)));
 
     assert_eq!(setup.token.balance(&recipient), 60_000);
 }
@@ -0,0 +1,103 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    alice: Address,
+    bob: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let alice = Address::generate(&base.env);
+        let bob = Address::generate(&base.env);
+        for funder in [&alice, &bob] {
+            base.token_admin.mint(funder, &10_000);
+        }
+
+        base.lock(1, 1_000);
+        Self { base, alice, bob }
+    }
+}
+
+#[test]
+fn test_deadline_refund_is_shared_pro_rata() {
+    let s = Setup::new();
+    s.escrow.contribute_to_escrow(&s.alice, &1, &500);
+    s.escrow.contribute_to_escrow(&s.bob, &1, &200);
+    s.escrow.contribute_to_escrow(&s.bob, &1, &300);
+    assert_eq!(s.env.auths()[0].0, s.bob);
+
+    let funders = s.escrow.get_escrow_funders(&1);
+    assert_eq!(funders.len(), 2);
+    assert_eq!(funders.get(s.bob.clone()), Some(500));
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.amount, 2_000);
+    assert_eq!(info.remaining_amount, 2_000);
+
+    let contributor = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &contributor, &400);
+    s.env.ledger().set_timestamp(2_000);
+    s.escrow.refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 9_000 + 800);
+    assert_eq!(s.token.balance(&s.alice), 9_500 + 400);
+    assert_eq!(s.token.balance(&s.bob), 9_500 + 400);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.refund_history.len(), 3);
+}
+
+#[test]
+fn test_cancel_returns_each_share() {
+    let s = Setup::new();
+    s.escrow.contribute_to_escrow(&s.alice, &1, &333);
+    s.escrow.contribute_to_escrow(&s.bob, &1, &1);
+    s.escrow.cancel_escrow(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    assert_eq!(s.token.balance(&s.alice), 10_000);
+    assert_eq!(s.token.balance(&s.bob), 10_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Cancelled);
+}
+
+#[test]
+fn test_contribution_rules() {
+    let s = Setup::new();
+    // The depositor adds to their own share.
+    s.escrow.contribute_to_escrow(&s.depositor, &1, &500);
+    assert_eq!(s.escrow.get_escrow_funders(&1).len(), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_500);
+
+    assert_eq!(
+        s.escrow.try_contribute_to_escrow(&s.alice, &1, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow.try_contribute_to_escrow(&s.alice, &2, &100),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    let contributor = Address::generate(&s.env);
+    s.escrow.release_funds(&1, &contributor);
+    assert_eq!(
+        s.escrow.try_contribute_to_escrow(&s.alice, &1, &100),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
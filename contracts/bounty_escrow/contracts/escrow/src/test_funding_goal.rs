@@ -0,0 +1,124 @@
+#![cfg(test)]
+
+use crate::funders::FundingError;
+use crate::{test_setup::TestSetup, Error, EscrowStatus, FundingGoal};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const GOAL_DEADLINE: u64 = 500;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    alice: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new(goal: i128) -> Self {
+        let base = TestSetup::new();
+        let alice = Address::generate(&base.env);
+        base.token_admin.mint(&alice, &10_000);
+
+        base.escrow.lock_funds(&base.depositor, &1, &1_000, &1_000);
+        base.escrow.set_funding_goal(&1, &goal, &GOAL_DEADLINE);
+        Self { base, alice }
+    }
+}
+
+#[test]
+fn test_release_waits_for_goal() {
+    let s = Setup::new(2_000);
+    assert_eq!(
+        s.escrow.get_funding_goal(&1),
+        Some(FundingGoal {
+            goal: 2_000,
+            deadline: GOAL_DEADLINE,
+        })
+    );
+
+    let contributor = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &contributor),
+        Err(Ok(Error::FundingGoalNotMet))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &contributor, &100),
+        Err(Ok(Error::FundingGoalNotMet))
+    );
+
+    s.escrow.contribute_to_escrow(&s.alice, &1, &1_000);
+    s.env.ledger().set_timestamp(GOAL_DEADLINE);
+    assert_eq!(
+        s.escrow.try_claim_contribution(&s.alice, &1),
+        Err(Err(FundingError::FundingNotFailed.into()))
+    );
+    s.escrow.release_funds(&1, &contributor);
+    assert_eq!(s.token.balance(&contributor), 2_000);
+}
+
+#[test]
+fn test_funders_claim_back_after_missed_goal() {
+    let s = Setup::new(3_000);
+    s.escrow.contribute_to_escrow(&s.alice, &1, &500);
+    assert_eq!(
+        s.escrow.try_claim_contribution(&s.alice, &1),
+        Err(Err(FundingError::FundingNotFailed.into()))
+    );
+
+    s.env.ledger().set_timestamp(GOAL_DEADLINE);
+    assert_eq!(
+        s.escrow.try_contribute_to_escrow(&s.alice, &1, &100),
+        Err(Ok(Error::FundingGoalNotMet))
+    );
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_claim_contribution(&stranger, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    assert_eq!(s.escrow.claim_contribution(&s.alice, &1), 500);
+    assert_eq!(s.token.balance(&s.alice), 10_000);
+    assert_eq!(
+        s.escrow.try_claim_contribution(&s.alice, &1),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.get_escrow_info(&1).status,
+        EscrowStatus::PartiallyRefunded
+    );
+
+    assert_eq!(s.escrow.claim_contribution(&s.depositor, &1), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.remaining_amount, 0);
+}
+
+#[test]
+fn test_goal_validation() {
+    let s = Setup::new(2_000);
+    assert_eq!(
+        s.escrow.try_set_funding_goal(&1, &0, &GOAL_DEADLINE),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow.try_set_funding_goal(&1, &2_000, &1_001),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    s.env.ledger().set_timestamp(GOAL_DEADLINE);
+    assert_eq!(
+        s.escrow.try_set_funding_goal(&1, &1_000, &900),
+        Err(Ok(Error::FundingGoalNotMet))
+    );
+}
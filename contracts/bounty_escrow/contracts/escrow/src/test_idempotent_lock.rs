@@ -0,0 +1,80 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    deadline: u64,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.deadline();
+        Self { base, deadline }
+    }
+
+    fn key(&self, byte: u8) -> BytesN<32> {
+        BytesN::from_array(&self.env, &[byte; 32])
+    }
+}
+
+#[test]
+fn test_retry_with_same_key_locks_once() {
+    let s = Setup::new();
+    assert!(s
+        .escrow
+        .lock_funds_idempotent(&s.depositor, &1, &1_000, &s.deadline, &s.key(1)));
+    assert!(!s
+        .escrow
+        .lock_funds_idempotent(&s.depositor, &1, &1_000, &s.deadline, &s.key(1)));
+
+    assert_eq!(s.token.balance(&s.depositor), 9_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_000);
+}
+
+#[test]
+fn test_duplicate_bounty_id_is_rejected() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_idempotent(&s.depositor, &1, &1_000, &s.deadline, &s.key(1));
+
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_idempotent(&s.depositor, &1, &1_000, &s.deadline, &s.key(2)),
+        Err(Ok(Error::BountyExists))
+    );
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_idempotent(&other, &1, &1_000, &s.deadline, &s.key(1)),
+        Err(Ok(Error::BountyExists))
+    );
+    assert_eq!(
+        s.escrow
+            .try_lock_funds(&s.depositor, &1, &1_000, &s.deadline),
+        Err(Ok(Error::BountyExists))
+    );
+    assert_eq!(s.token.balance(&s.depositor), 9_000);
+}
+
+#[test]
+fn test_key_does_not_match_plain_lock() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &s.deadline);
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_idempotent(&s.depositor, &1, &1_000, &s.deadline, &s.key(1)),
+        Err(Ok(Error::BountyExists))
+    );
+}
@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::insurance::InsuranceError;
+use crate::{test_setup::TestSetup, Error, InsuranceConfig};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    arbiter: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let arbiter = Address::generate(&base.env);
+        base.escrow.set_arbiter(&arbiter);
+
+        base.lock(1, 1_000);
+        base.lock(2, 1_000);
+        Self { base, arbiter }
+    }
+
+    fn offer(&self) {
+        self.escrow.set_insurance_config(&Some(InsuranceConfig {
+            premium_bps: 200,
+            max_coverage: 800,
+        }));
+    }
+}
+
+#[test]
+fn test_premium_goes_to_pool() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_insure_escrow(&1),
+        Err(Err(InsuranceError::NotInsured.into()))
+    );
+
+    s.offer();
+    let policy = s.escrow.insure_escrow(&1);
+    assert_eq!(s.env.auths()[0].0, s.depositor);
+    assert_eq!(policy.coverage, 800);
+    assert_eq!(policy.premium, 16);
+    assert_eq!(s.escrow.get_insurance_policy(&1), Some(policy));
+    assert_eq!(s.escrow.get_insurance_pool(), 16);
+    assert_eq!(s.token.balance(&s.depositor), 8_000 - 16);
+    // The pool is owed to policy holders, not surplus.
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 0);
+
+    assert_eq!(
+        s.escrow.try_insure_escrow(&1),
+        Err(Err(InsuranceError::AlreadyInsured.into()))
+    );
+}
+
+#[test]
+fn test_claim_after_fraudulent_release() {
+    let s = Setup::new();
+    s.offer();
+    s.escrow.insure_escrow(&1);
+    assert_eq!(
+        s.escrow.try_pay_insurance_claim(&1, &100),
+        Err(Err(InsuranceError::InsuranceClaimNotAllowed.into()))
+    );
+
+    let backer = Address::generate(&s.env);
+    s.token_admin.mint(&backer, &500);
+    s.escrow.fund_insurance_pool(&backer, &500);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(
+        s.escrow.try_pay_insurance_claim(&1, &801),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow.try_pay_insurance_claim(&1, &600),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    s.escrow.pay_insurance_claim(&1, &500);
+    assert_eq!(s.env.auths()[0].0, s.arbiter);
+    assert_eq!(s.token.balance(&s.depositor), 8_000 - 16 + 500);
+    assert_eq!(s.escrow.get_insurance_pool(), 16);
+    assert!(s.escrow.get_insurance_policy(&1).unwrap().claimed);
+    assert_eq!(
+        s.escrow.try_pay_insurance_claim(&1, &10),
+        Err(Err(InsuranceError::InsuranceClaimNotAllowed.into()))
+    );
+}
+
+#[test]
+fn test_claim_after_lost_dispute() {
+    let s = Setup::new();
+    s.offer();
+    s.escrow.insure_escrow(&2);
+    assert_eq!(
+        s.escrow.try_pay_insurance_claim(&1, &10),
+        Err(Err(InsuranceError::NotInsured.into()))
+    );
+
+    s.escrow.assign_contributor(&2, &s.contributor, &86_400);
+    s.escrow.accept_assignment(&2);
+    s.escrow.open_dispute(
+        &s.depositor,
+        &2,
+        &s.contributor,
+        &BytesN::from_array(&s.env, &[1; 32]),
+    );
+    s.escrow.resolve_dispute(&2, &0);
+    s.escrow.set_paused(&None, &None, &Some(true), &None);
+    assert_eq!(
+        s.escrow.try_pay_insurance_claim(&2, &16),
+        Err(Ok(Error::FundsPaused))
+    );
+    s.escrow.set_paused(&None, &None, &Some(false), &None);
+    s.escrow.pay_insurance_claim(&2, &16);
+    assert_eq!(s.escrow.get_insurance_pool(), 0);
+}
+
+#[test]
+fn test_insurance_does_not_overwrite_other_config() {
+    let s = Setup::new();
+    s.offer();
+    s.escrow.insure_escrow(&1);
+    assert_eq!(s.escrow.get_anti_abuse_config().window_size, 3600);
+    assert_eq!(s.escrow.get_yield_pool(), None);
+    assert_eq!(s.escrow.get_insurance_pool(), 16);
+}
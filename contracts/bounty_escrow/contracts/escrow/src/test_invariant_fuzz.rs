@@ -0,0 +1,232 @@
+#![cfg(test)]
+
+//! Property-based harness: random sequences of lock, release, refund and
+//! rescue calls must never break the contract's accounting. Failing calls
+//! are expected (most random calls are invalid) and roll back; only the
+//! state left behind is checked.
+
+use crate::{
+    test_setup::{TestSetup, DEPOSITOR_BALANCE},
+    EscrowStatus, RefundMode,
+};
+use core::ops::Deref;
+use proptest::prelude::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+const BOUNTIES: u64 = 4;
+const MINTED: i128 = 1_000_000;
+
+#[derive(Clone, Debug)]
+enum Op {
+    Lock {
+        bounty_id: u64,
+        amount: i128,
+        duration: u64,
+    },
+    Release {
+        bounty_id: u64,
+    },
+    PartialRelease {
+        bounty_id: u64,
+        amount: i128,
+    },
+    ApproveRefund {
+        bounty_id: u64,
+        amount: i128,
+        full: bool,
+    },
+    Refund {
+        bounty_id: u64,
+    },
+    Cancel {
+        bounty_id: u64,
+    },
+    /// Tokens sent straight to the contract.
+    Donate {
+        amount: i128,
+    },
+    Rescue,
+    AdvanceTime {
+        seconds: u64,
+    },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let bounty_id = 0..BOUNTIES;
+    let amount = 1..3_000_i128;
+    prop_oneof![
+        (bounty_id.clone(), amount.clone(), 1..2_000_u64).prop_map(
+            |(bounty_id, amount, duration)| Op::Lock {
+                bounty_id,
+                amount,
+                duration
+            }
+        ),
+        bounty_id
+            .clone()
+            .prop_map(|bounty_id| Op::Release { bounty_id }),
+        (bounty_id.clone(), amount.clone())
+            .prop_map(|(bounty_id, amount)| Op::PartialRelease { bounty_id, amount }),
+        (bounty_id.clone(), amount.clone(), any::<bool>()).prop_map(|(bounty_id, amount, full)| {
+            Op::ApproveRefund {
+                bounty_id,
+                amount,
+                full,
+            }
+        }),
+        bounty_id
+            .clone()
+            .prop_map(|bounty_id| Op::Refund { bounty_id }),
+        bounty_id.prop_map(|bounty_id| Op::Cancel { bounty_id }),
+        amount.prop_map(|amount| Op::Donate { amount }),
+        Just(Op::Rescue),
+        (0..3_000_u64).prop_map(|seconds| Op::AdvanceTime { seconds }),
+    ]
+}
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+    minted: i128,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.token_admin
+            .mint(&base.depositor, &(MINTED - DEPOSITOR_BALANCE));
+        base.escrow
+            .update_fee_config(&None, &None, &Some(treasury.clone()), &None);
+        base.escrow.accept_treasury_role();
+
+        Self {
+            base,
+            treasury,
+            minted: MINTED,
+        }
+    }
+
+    /// Run `op`, ignoring whether the contract accepted it.
+    fn apply(&mut self, op: &Op) {
+        let escrow = &self.escrow;
+        match *op {
+            Op::Lock {
+                bounty_id,
+                amount,
+                duration,
+            } => {
+                let deadline = self.env.ledger().timestamp() + duration;
+                let _ = escrow.try_lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+            }
+            Op::Release { bounty_id } => {
+                let _ = escrow.try_release_funds(&bounty_id, &self.contributor);
+            }
+            Op::PartialRelease { bounty_id, amount } => {
+                let _ = escrow.try_partial_release(&bounty_id, &self.contributor, &amount);
+            }
+            Op::ApproveRefund {
+                bounty_id,
+                amount,
+                full,
+            } => {
+                let mode = if full {
+                    RefundMode::Full
+                } else {
+                    RefundMode::Partial
+                };
+                let _ = escrow.try_approve_refund(&bounty_id, &amount, &self.depositor, &mode);
+            }
+            Op::Refund { bounty_id } => {
+                let _ = escrow.try_refund(&bounty_id);
+            }
+            Op::Cancel { bounty_id } => {
+                let _ = escrow.try_cancel_escrow(&bounty_id);
+            }
+            Op::Donate { amount } => {
+                self.token_admin.mint(&escrow.address, &amount);
+                self.minted += amount;
+            }
+            Op::Rescue => {
+                let _ = escrow.try_rescue_untracked_tokens(&self.token.address);
+            }
+            Op::AdvanceTime { seconds } => {
+                let now = self.env.ledger().timestamp();
+                self.env.ledger().set_timestamp(now + seconds);
+            }
+        }
+    }
+
+    fn status(&self, bounty_id: u64) -> Option<EscrowStatus> {
+        match self.escrow.try_get_escrow_info(&bounty_id) {
+            Ok(Ok(escrow)) => Some(escrow.status),
+            _ => None,
+        }
+    }
+
+    fn assert_invariants(&self) {
+        let report = self.escrow.check_invariants();
+        assert!(report.holds, "{report:?}");
+        assert!(report.tracked <= report.balance);
+        assert!(self
+            .escrow
+            .check_escrow_invariants(&0, &(BOUNTIES as u32))
+            .is_empty());
+
+        // Tokens only move between the parties; none are created or lost.
+        let held = self.token.balance(&self.depositor)
+            + self.token.balance(&self.contributor)
+            + self.token.balance(&self.treasury)
+            + report.balance;
+        assert_eq!(held, self.minted);
+    }
+}
+
+/// Escrows never leave a final status or return to `Locked`.
+fn legal(from: &EscrowStatus, to: &EscrowStatus) -> bool {
+    from == to
+        || match from {
+            EscrowStatus::Locked => true,
+            EscrowStatus::PartiallyRefunded => *to != EscrowStatus::Locked,
+            EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Cancelled => false,
+        }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn test_random_operations_preserve_invariants(ops in prop::collection::vec(op(), 1..40)) {
+        let mut s = Setup::new();
+        let mut statuses: [Option<EscrowStatus>; BOUNTIES as usize] = Default::default();
+
+        for op in &ops {
+            // Each operation is its own transaction, metered against the
+            // default per-transaction budget.
+            s.env.budget().reset_default();
+            s.apply(op);
+            s.assert_invariants();
+
+            for (bounty_id, previous) in statuses.iter_mut().enumerate() {
+                let current = s.status(bounty_id as u64);
+                if let (Some(from), Some(to)) = (previous.as_ref(), current.as_ref()) {
+                    prop_assert!(legal(from, to), "{:?} -> {:?} after {:?}", from, to, op);
+                }
+                if current.is_some() {
+                    *previous = current;
+                }
+            }
+        }
+    }
+}
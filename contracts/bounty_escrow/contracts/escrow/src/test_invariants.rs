@@ -149,7 +149,7 @@ fn test_invariant_checker_healthy_released_state() {
 #[test]
 fn test_invariant_checker_healthy_refunded_state() {
     let env = Env::default();
-    let (client, admin, depositor) = setup_bounty(&env);
+    let (client, _admin, depositor) = setup_bounty(&env);
     env.as_contract(&client.address, || invariants::reset_test_state(&env));
 
     let bounty_id = 42_u64;
@@ -191,6 +191,8 @@ fn test_invariant_checker_catches_negative_amount() {
         status: EscrowStatus::Locked,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -213,6 +215,8 @@ fn test_invariant_checker_catches_negative_remaining_amount() {
         status: EscrowStatus::Locked,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -235,6 +239,8 @@ fn test_invariant_checker_catches_remaining_amount_exceeds_amount() {
         status: EscrowStatus::Locked,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -257,6 +263,8 @@ fn test_invariant_checker_catches_released_with_nonzero_remaining() {
         status: EscrowStatus::Released,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -278,6 +286,8 @@ fn test_invariant_checker_allows_valid_edge_cases() {
         status: EscrowStatus::Released,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -292,6 +302,8 @@ fn test_invariant_checker_allows_valid_edge_cases() {
         status: EscrowStatus::Locked,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -306,6 +318,8 @@ fn test_invariant_checker_allows_valid_edge_cases() {
         status: EscrowStatus::Released,
         deadline: env.ledger().timestamp() + 1000,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     env.as_contract(&client.address, || {
@@ -334,6 +348,8 @@ fn test_invariant_checker_partial_refund_state() {
         status: EscrowStatus::Locked,
         deadline,
         refund_history: vec![&env],
+        metadata_hash: None,
+        label: None,
     };
 
     // This should pass invariants
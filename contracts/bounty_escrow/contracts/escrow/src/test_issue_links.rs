@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use crate::issue_links::IssueLinkError;
+use crate::{test_setup::TestSetup, Error, IssueLink};
+use core::ops::Deref;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{xdr::ToXdr, BytesN, String};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    key: SigningKey,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.lock(1, 1_000);
+
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let public_key = BytesN::from_array(&base.env, &key.verifying_key().to_bytes());
+        base.escrow.set_issue_attestor(&Some(public_key));
+
+        Self { base, key }
+    }
+
+    fn repo(&self, repo: &str) -> String {
+        String::from_str(&self.env, repo)
+    }
+
+    /// Sign a link the way the attestor service would.
+    fn sign(&self, key: &SigningKey, repo: &str, issue_number: u64) -> BytesN<64> {
+        let message = (
+            self.escrow.address.clone(),
+            1_u64,
+            self.repo(repo),
+            issue_number,
+        )
+            .to_xdr(&self.env);
+        let signature = key.sign(&message.to_alloc_vec());
+        BytesN::from_array(&self.env, &signature.to_bytes())
+    }
+}
+
+#[test]
+fn test_unverified_link_blocks_release_until_attested() {
+    let s = Setup::new();
+    s.escrow.set_issue_link(&1, &s.repo("grainlify/app"), &42);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::IssueLinkNotVerified))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::IssueLinkNotVerified))
+    );
+
+    let signature = s.sign(&s.key, "grainlify/app", 42);
+    s.escrow.attest_issue_link(&1, &signature);
+    assert_eq!(
+        s.escrow.get_issue_link(&1),
+        Some(IssueLink {
+            repo: s.repo("grainlify/app"),
+            issue_number: 42,
+            verified: true,
+        })
+    );
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_relinking_requires_new_attestation() {
+    let s = Setup::new();
+    s.escrow.set_issue_link(&1, &s.repo("grainlify/app"), &42);
+    s.escrow
+        .attest_issue_link(&1, &s.sign(&s.key, "grainlify/app", 42));
+
+    s.escrow.set_issue_link(&1, &s.repo("grainlify/app"), &43);
+    assert!(!s.escrow.get_issue_link(&1).unwrap().verified);
+    // The old attestation doesn't cover the new issue.
+    assert!(s
+        .escrow
+        .try_attest_issue_link(&1, &s.sign(&s.key, "grainlify/app", 42))
+        .is_err());
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::IssueLinkNotVerified))
+    );
+}
+
+#[test]
+fn test_foreign_signatures_and_bad_links_are_rejected() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow
+            .try_attest_issue_link(&1, &s.sign(&s.key, "grainlify/app", 42)),
+        Err(Err(IssueLinkError::IssueLinkNotFound.into()))
+    );
+    assert_eq!(
+        s.escrow.try_set_issue_link(&1, &s.repo("ab"), &42),
+        Err(Err(IssueLinkError::InvalidIssueLink.into()))
+    );
+    assert_eq!(
+        s.escrow
+            .try_set_issue_link(&1, &s.repo("grainlify/app"), &0),
+        Err(Err(IssueLinkError::InvalidIssueLink.into()))
+    );
+
+    s.escrow.set_issue_link(&1, &s.repo("grainlify/app"), &42);
+    let stranger = SigningKey::from_bytes(&[9; 32]);
+    assert!(s
+        .escrow
+        .try_attest_issue_link(&1, &s.sign(&stranger, "grainlify/app", 42))
+        .is_err());
+
+    s.escrow.set_issue_attestor(&None);
+    assert_eq!(
+        s.escrow
+            .try_attest_issue_link(&1, &s.sign(&s.key, "grainlify/app", 42)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unlinked_escrows_are_not_gated() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_issue_link(&1), None);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
@@ -0,0 +1,92 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{contract, contractimpl, Address, Env};
+
+/// Verifier that approves addresses registered with `verify`.
+#[contract]
+pub struct MockVerifier;
+
+#[contractimpl]
+impl MockVerifier {
+    pub fn verify(env: Env, address: Address) {
+        env.storage().persistent().set(&address, &true);
+    }
+
+    pub fn is_verified(env: Env, address: Address) -> bool {
+        env.storage().persistent().has(&address)
+    }
+}
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    verifier: MockVerifierClient<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.lock(1, 1_000);
+        base.lock(2, 1_000);
+
+        let verifier_id = base.env.register_contract(None, MockVerifier);
+        let verifier = MockVerifierClient::new(&base.env, &verifier_id);
+        base.escrow.set_escrow_kyc_verifier(&1, &Some(verifier_id));
+
+        Self { base, verifier }
+    }
+}
+
+#[test]
+fn test_unverified_contributor_cannot_be_paid() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Escrows without a verifier are unaffected.
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_verified_contributor_is_paid() {
+    let s = Setup::new();
+    s.verifier.verify(&s.contributor);
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    s.escrow.partial_release(&1, &s.contributor, &700);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_depositor_manages_requirement() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.get_escrow_kyc_verifier(&1),
+        Some(s.verifier.address.clone())
+    );
+    s.escrow.set_escrow_kyc_verifier(&1, &None);
+    assert_eq!(s.env.auths()[0].0, s.depositor);
+    assert_eq!(s.escrow.get_escrow_kyc_verifier(&1), None);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(
+        s.escrow.try_set_escrow_kyc_verifier(&9, &None),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
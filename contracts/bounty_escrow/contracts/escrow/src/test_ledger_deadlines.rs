@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Deadline, Error};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Ledger, Env};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.ledger().with_mut(|ledger| {
+            ledger.sequence_number = 1_000;
+            ledger.timestamp = 50_000;
+        });
+        Self {
+            base: TestSetup::with_env(env),
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, deadline: Deadline) {
+        self.escrow
+            .lock_funds_with_deadline(&self.depositor, &bounty_id, &1_000, &deadline);
+    }
+
+    fn set_ledger(&self, sequence: u32, timestamp: u64) {
+        self.env.ledger().with_mut(|ledger| {
+            ledger.sequence_number = sequence;
+            ledger.timestamp = timestamp;
+        });
+    }
+}
+
+#[test]
+fn test_ledger_deadline_ignores_clock() {
+    let s = Setup::new();
+    s.lock(1, Deadline::Ledger(1_100));
+    assert_eq!(s.escrow.get_deadline(&1), Deadline::Ledger(1_100));
+    // The timestamp field holds an estimate at 5 seconds per ledger.
+    assert_eq!(s.escrow.get_escrow_info(&1).deadline, 50_500);
+
+    // Far past the estimate by the clock, but the ledger hasn't closed.
+    s.set_ledger(1_099, 90_000);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+    assert!(!s.escrow.get_refund_eligibility(&1).1);
+
+    // Reached early by the clock's standard.
+    s.set_ledger(1_100, 50_100);
+    assert!(s.escrow.get_refund_eligibility(&1).0);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_grace_period_counts_in_ledgers() {
+    let s = Setup::new();
+    s.escrow.set_refund_grace_period(&50);
+    s.lock(1, Deadline::Ledger(1_100));
+
+    s.set_ledger(1_109, 100_000);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+    s.set_ledger(1_110, 100_000);
+    s.escrow.refund(&1);
+}
+
+#[test]
+fn test_time_deadline_and_validation() {
+    let s = Setup::new();
+    s.lock(1, Deadline::Time(51_000));
+    assert_eq!(s.escrow.get_deadline(&1), Deadline::Time(51_000));
+
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_with_deadline(&s.depositor, &2, &1_000, &Deadline::Ledger(1_000)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+}
+
+#[test]
+fn test_extending_switches_to_timestamp() {
+    let s = Setup::new();
+    s.lock(1, Deadline::Ledger(1_100));
+    s.escrow.extend_deadline(&1, &51_000);
+    assert_eq!(s.escrow.get_deadline(&1), Deadline::Time(51_000));
+
+    s.set_ledger(2_000, 50_999);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+}
@@ -0,0 +1,166 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus, Milestone, MilestoneStatus};
+use core::ops::Deref;
+use soroban_sdk::{vec, BytesN, Vec};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    fn milestones(&self, amounts: &[i128]) -> Vec<Milestone> {
+        let mut out = vec![&self.env];
+        let now = self.env.ledger().timestamp();
+        for (i, amount) in amounts.iter().enumerate() {
+            out.push_back(Milestone {
+                amount: *amount,
+                description_hash: BytesN::from_array(&self.env, &[i as u8; 32]),
+                deadline: now + 1_000 * (i as u64 + 1),
+            });
+        }
+        out
+    }
+}
+
+#[test]
+fn test_lock_with_milestones_sums_amounts() {
+    let s = Setup::new();
+    let milestones = s.milestones(&[300, 200, 500]);
+
+    s.escrow
+        .lock_funds_with_milestones(&s.depositor, &1, &milestones);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(escrow.remaining_amount, 1_000);
+    assert_eq!(escrow.deadline, s.env.ledger().timestamp() + 3_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+
+    let records = s.escrow.get_milestones(&1);
+    assert_eq!(records.len(), 3);
+    for record in records.iter() {
+        assert_eq!(record.status, MilestoneStatus::Pending);
+    }
+}
+
+#[test]
+fn test_release_milestones_incrementally() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_milestones(&s.depositor, &1, &s.milestones(&[400, 600]));
+
+    s.escrow.approve_milestone(&1, &0);
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 600);
+    assert_eq!(
+        s.escrow.get_milestones(&1).get(0).unwrap().status,
+        MilestoneStatus::Released
+    );
+
+    s.escrow.approve_milestone(&1, &1);
+    s.escrow.release_milestone(&1, &1, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+}
+
+#[test]
+fn test_release_unapproved_milestone_fails() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_milestones(&s.depositor, &1, &s.milestones(&[400, 600]));
+
+    let res = s.escrow.try_release_milestone(&1, &1, &s.contributor);
+    assert_eq!(res, Err(Ok(Error::MilestoneNotApproved)));
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_milestones_only_pay_out_through_release_milestone() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_milestones(&s.depositor, &1, &s.milestones(&[400, 600]));
+    s.escrow.approve_milestone(&1, &0);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::MilestoneEscrow))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &400),
+        Err(Ok(Error::MilestoneEscrow))
+    );
+    assert_eq!(
+        s.escrow
+            .try_release_split(&1, &vec![&s.env, (s.contributor.clone(), 400_i128)]),
+        Err(Ok(Error::MilestoneEscrow))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+}
+
+#[test]
+fn test_milestone_cannot_be_released_twice() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_milestones(&s.depositor, &1, &s.milestones(&[400, 600]));
+
+    s.escrow.approve_milestone(&1, &0);
+    s.escrow.release_milestone(&1, &0, &s.contributor);
+
+    let res = s.escrow.try_release_milestone(&1, &0, &s.contributor);
+    assert_eq!(res, Err(Ok(Error::MilestoneAlreadyReleased)));
+    let res = s.escrow.try_approve_milestone(&1, &0);
+    assert_eq!(res, Err(Ok(Error::MilestoneAlreadyReleased)));
+}
+
+#[test]
+fn test_unknown_milestone_index() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_milestones(&s.depositor, &1, &s.milestones(&[400]));
+
+    let res = s.escrow.try_approve_milestone(&1, &5);
+    assert_eq!(res, Err(Ok(Error::MilestoneNotFound)));
+}
+
+#[test]
+fn test_lock_with_invalid_milestones() {
+    let s = Setup::new();
+
+    let res = s
+        .escrow
+        .try_lock_funds_with_milestones(&s.depositor, &1, &vec![&s.env]);
+    assert_eq!(res, Err(Ok(Error::InvalidBatchSize)));
+
+    let res = s
+        .escrow
+        .try_lock_funds_with_milestones(&s.depositor, &1, &s.milestones(&[100, 0]));
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+
+    assert!(s.escrow.try_get_escrow_info(&1).is_err());
+    assert!(s.escrow.try_get_milestones(&1).is_err());
+}
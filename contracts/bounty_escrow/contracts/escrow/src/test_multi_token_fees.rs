@@ -9,9 +9,9 @@
 //
 // Fee accounting note: `FeeConfig` is stored per-instance and is independent
 // across contract instances. The `lock_funds` function transfers the full `amount`
-// to the contract, and `release_funds` transfers the full stored `escrow.amount`
-// to the contributor. Fee configuration is a per-instance setting that governs
-// which rate applies when fee collection is triggered.
+// to the contract and snapshots the instance's release fee rate if fees are
+// enabled; `release_funds` then transfers `escrow.amount` minus that fee to the
+// contributor. Escrows locked with fees disabled are released in full.
 //
 // The tests here verify:
 //   1. Fee configuration is independent per contract instance.
@@ -21,7 +21,7 @@
 //   5. Refunding on one instance does not affect the other token's balances.
 
 #[cfg(test)]
-mod test_multi_token_fees {
+mod multi_token_fees_tests {
     use crate::{BountyEscrowContract, BountyEscrowContractClient, RefundMode};
     use soroban_sdk::{testutils::Address as _, token, Address, Env};
 
@@ -84,6 +84,8 @@ mod test_multi_token_fees {
             &Some(fee_recipient_b.clone()),
             &Some(false),
         );
+        client_a.accept_treasury_role();
+        client_b.accept_treasury_role();
 
         let config_a = client_a.get_fee_config();
         let config_b = client_b.get_fee_config();
@@ -0,0 +1,211 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus, Milestone};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    signers: [Address; 3],
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    /// 2-of-3 signers required for releases of 1_000 or more.
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let signers = [
+            Address::generate(&base.env),
+            Address::generate(&base.env),
+            Address::generate(&base.env),
+        ];
+        base.escrow.update_multisig_config(
+            &1_000,
+            &vec![
+                &base.env,
+                signers[0].clone(),
+                signers[1].clone(),
+                signers[2].clone(),
+            ],
+            &2,
+        );
+
+        base.lock(1, 5_000);
+        base.lock(2, 500);
+        Self { base, signers }
+    }
+
+    fn approve(&self, bounty_id: u64, contributor: &Address) {
+        self.escrow
+            .approve_release(&bounty_id, contributor, &self.signers[0]);
+        self.escrow
+            .approve_release(&bounty_id, contributor, &self.signers[1]);
+    }
+}
+
+#[test]
+fn test_large_release_requires_approvals() {
+    let s = Setup::new();
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[0]);
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[2]);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 5_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.escrow.get_release_approval(&1), None);
+}
+
+#[test]
+fn test_release_below_threshold_needs_no_approvals() {
+    let s = Setup::new();
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 500);
+}
+
+#[test]
+fn test_duplicate_approval_counts_once() {
+    let s = Setup::new();
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[0]);
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[0]);
+
+    assert_eq!(
+        s.escrow.get_release_approval(&1).unwrap().approvals.len(),
+        1
+    );
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+}
+
+#[test]
+fn test_non_signer_cannot_approve() {
+    let s = Setup::new();
+    let outsider = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow.try_approve_release(&1, &s.contributor, &outsider),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_approvals_are_bound_to_contributor() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[0]);
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[1]);
+
+    assert_eq!(
+        s.escrow.try_approve_release(&1, &other, &s.signers[2]),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &other),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    assert_eq!(s.token.balance(&other), 0);
+}
+
+#[test]
+fn test_removed_signer_approval_no_longer_counts() {
+    let s = Setup::new();
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[0]);
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[1]);
+
+    s.escrow.update_multisig_config(
+        &1_000,
+        &vec![&s.env, s.signers[1].clone(), s.signers[2].clone()],
+        &2,
+    );
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    s.escrow.approve_release(&1, &s.contributor, &s.signers[2]);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 5_000);
+}
+
+#[test]
+fn test_partial_releases_count_towards_threshold() {
+    let s = Setup::new();
+    s.escrow.partial_release(&1, &s.contributor, &600);
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &400),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    s.approve(1, &s.contributor);
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    // The approvals cover the rest of the escrow.
+    s.escrow.partial_release(&1, &s.contributor, &4_000);
+    assert_eq!(s.token.balance(&s.contributor), 5_000);
+    assert_eq!(s.escrow.get_release_approval(&1), None);
+}
+
+#[test]
+fn test_split_release_requires_approvals() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+    let splits = vec![
+        &s.env,
+        (other.clone(), 600_i128),
+        (s.contributor.clone(), 400_i128),
+    ];
+    assert_eq!(
+        s.escrow.try_release_split(&1, &splits),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+
+    s.approve(1, &s.contributor);
+    s.escrow.release_split(&1, &splits);
+    assert_eq!(s.token.balance(&other), 600);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+}
+
+#[test]
+fn test_milestone_release_requires_approvals() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    let milestone = |amount: i128| Milestone {
+        amount,
+        description_hash: BytesN::from_array(&s.env, &[1; 32]),
+        deadline,
+    };
+    s.escrow.lock_funds_with_milestones(
+        &s.depositor,
+        &3,
+        &vec![&s.env, milestone(600), milestone(600)],
+    );
+    s.escrow.approve_milestone(&3, &0);
+    s.escrow.approve_milestone(&3, &1);
+
+    s.escrow.release_milestone(&3, &0, &s.contributor);
+    assert_eq!(
+        s.escrow.try_release_milestone(&3, &1, &s.contributor),
+        Err(Ok(Error::InsufficientApprovals))
+    );
+    s.approve(3, &s.contributor);
+    s.escrow.release_milestone(&3, &1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_200);
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction},
+    Address, IntoVal, Symbol, Val, Vec,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    operator: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let operator = Address::generate(&base.env);
+        base.escrow.set_operator_contract(&Some(operator.clone()));
+        Self { base, operator }
+    }
+
+    /// Arguments `account` authorized for `function` on the escrow.
+    fn authorized_args(&self, account: &Address, function: &str) -> Option<Vec<Val>> {
+        let function = Symbol::new(&self.env, function);
+        self.env
+            .auths()
+            .into_iter()
+            .find_map(|(address, invocation)| match invocation.function {
+                AuthorizedFunction::Contract((contract, name, args))
+                    if address == *account
+                        && contract == self.escrow.address
+                        && name == function =>
+                {
+                    Some(args)
+                }
+                _ => None,
+            })
+    }
+}
+
+#[test]
+fn test_operator_locks_and_releases_with_scoped_auth() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_operator_contract(), Some(s.operator.clone()));
+
+    s.escrow
+        .lock_funds_as_operator(&s.operator, &s.depositor, &1, &1_000, &5_000);
+    assert_eq!(
+        s.authorized_args(&s.depositor, "lock_funds_as_operator"),
+        Some((1u64, 1_000i128, 5_000u64).into_val(&s.env))
+    );
+    assert_eq!(s.escrow.get_escrow_info(&1).depositor, s.depositor);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+
+    s.escrow
+        .release_funds_as_operator(&s.operator, &1, &s.contributor);
+    assert_eq!(
+        s.authorized_args(&s.depositor, "release_funds_as_operator"),
+        Some((1u64, s.contributor.clone()).into_val(&s.env))
+    );
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_only_designated_operator_is_accepted() {
+    let s = Setup::new();
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_as_operator(&stranger, &s.depositor, &1, &1_000, &5_000),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &5_000);
+    assert_eq!(
+        s.escrow
+            .try_release_funds_as_operator(&stranger, &1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    s.escrow.set_operator_contract(&None);
+    assert_eq!(s.escrow.get_operator_contract(), None);
+    assert_eq!(
+        s.escrow
+            .try_release_funds_as_operator(&s.operator, &1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::test_setup::TestSetup;
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    alice: Address,
+    bob: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let alice = Address::generate(&base.env);
+        let bob = Address::generate(&base.env);
+        base.token_admin.mint(&alice, &100_000);
+        base.token_admin.mint(&bob, &100_000);
+        Self { base, alice, bob }
+    }
+
+    fn lock(&self, depositor: &Address, bounty_id: u64) {
+        self.escrow
+            .lock_funds(depositor, &bounty_id, &1_000, &self.deadline());
+    }
+}
+
+#[test]
+fn test_escrows_by_depositor() {
+    let s = Setup::new();
+    s.lock(&s.alice, 1);
+    s.lock(&s.bob, 2);
+    s.lock(&s.alice, 3);
+    s.lock(&s.alice, 4);
+
+    assert_eq!(
+        s.escrow.get_escrows_by_depositor(&s.alice, &0, &10),
+        vec![&s.env, 1, 3, 4]
+    );
+    assert_eq!(
+        s.escrow.get_escrows_by_depositor(&s.alice, &1, &1),
+        vec![&s.env, 3]
+    );
+    assert_eq!(
+        s.escrow.get_escrows_by_depositor(&s.bob, &0, &10),
+        vec![&s.env, 2]
+    );
+    assert!(s
+        .escrow
+        .get_escrows_by_depositor(&s.contributor, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_payouts_by_contributor() {
+    let s = Setup::new();
+    let other = Address::generate(&s.env);
+    s.lock(&s.alice, 1);
+    s.lock(&s.bob, 2);
+    s.lock(&s.alice, 3);
+
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    s.escrow.partial_release(&1, &s.contributor, &200);
+    s.escrow.release_funds(&2, &other);
+    s.escrow.release_split(
+        &3,
+        &vec![&s.env, (s.contributor.clone(), 600), (other.clone(), 400)],
+    );
+
+    assert_eq!(
+        s.escrow.get_payouts_by_contributor(&s.contributor, &0, &10),
+        vec![&s.env, 1, 3]
+    );
+    assert_eq!(
+        s.escrow.get_payouts_by_contributor(&other, &0, &10),
+        vec![&s.env, 2, 3]
+    );
+    assert_eq!(
+        s.escrow.get_payouts_by_contributor(&other, &1, &10),
+        vec![&s.env, 3]
+    );
+    assert!(s
+        .escrow
+        .get_payouts_by_contributor(&s.alice, &0, &10)
+        .is_empty());
+}
@@ -501,7 +501,7 @@ fn test_rbac_emergency_withdraw_emits_event() {
             &env,
             (
                 escrow_client.address.clone(),
-                (symbol_short!("em_wtd"),).into_val(&env),
+                (symbol_short!("em_wtd"), events::EVENT_SCHEMA_VERSION).into_val(&env),
                 events::EmergencyWithdrawEvent {
                     admin: admin.clone(),
                     recipient: target.clone(),
@@ -637,3 +637,47 @@ fn test_rbac_after_emergency_withdraw_can_unpause_and_reuse() {
     escrow_client.lock_funds(&new_depositor, &99u64, &200i128, &deadline);
     assert_eq!(token_client.balance(&escrow_client.address), 200);
 }
+
+#[test]
+fn test_pause_blocks_all_fund_movements() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token_client, token_admin_client) = create_token_contract(&env, &admin);
+    let (escrow_client, _) = create_escrow_contract(&env);
+    escrow_client.init(&admin, &token_client.address);
+    token_admin_client.mint(&depositor, &1000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow_client.lock_funds(&depositor, &1u64, &500i128, &deadline);
+
+    escrow_client.pause();
+    assert!(escrow_client.is_paused());
+
+    assert_eq!(
+        escrow_client.try_lock_funds(&depositor, &2u64, &100i128, &deadline),
+        Err(Ok(Error::FundsPaused))
+    );
+    assert_eq!(
+        escrow_client.try_release_funds(&1u64, &contributor),
+        Err(Ok(Error::FundsPaused))
+    );
+    assert_eq!(
+        escrow_client.try_partial_release(&1u64, &contributor, &100i128),
+        Err(Ok(Error::FundsPaused))
+    );
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(escrow_client.try_refund(&1u64), Err(Ok(Error::FundsPaused)));
+
+    // Views keep working while paused
+    assert_eq!(escrow_client.get_escrow_info(&1u64).amount, 500);
+    assert_eq!(escrow_client.get_balance(), 500);
+
+    escrow_client.unpause();
+    assert!(!escrow_client.is_paused());
+    escrow_client.partial_release(&1u64, &contributor, &100i128);
+    assert_eq!(token_client.balance(&contributor), 100);
+}
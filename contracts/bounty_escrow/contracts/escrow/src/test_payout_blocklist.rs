@@ -0,0 +1,106 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, DisputeReason, Error, Milestone, RefundMode};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, BytesN, Symbol, TryFromVal,
+};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_blocked_contributor_cannot_be_paid() {
+    let s = setup();
+    s.escrow.set_blocklist_entry(&s.contributor, &true);
+    assert!(s.escrow.is_blocked(&s.contributor));
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+
+    // Another contributor can still be paid from the same escrow.
+    let other = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &other, &100);
+    assert_eq!(s.token.balance(&other), 100);
+}
+
+#[test]
+fn test_blocked_contributor_cannot_be_paid_by_other_release_paths() {
+    let s = setup();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds_with_milestones(
+        &s.depositor,
+        &2,
+        &vec![
+            &s.env,
+            Milestone {
+                amount: 500,
+                description_hash: BytesN::from_array(&s.env, &[1; 32]),
+                deadline,
+            },
+        ],
+    );
+    s.escrow.approve_milestone(&2, &0);
+    s.escrow
+        .authorize_claim(&1, &s.contributor, &DisputeReason::Other);
+    s.escrow.set_blocklist_entry(&s.contributor, &true);
+
+    assert_eq!(s.escrow.try_claim(&1), Err(Ok(Error::Unauthorized)));
+    assert_eq!(
+        s.escrow.try_release_milestone(&2, &0, &s.contributor),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_blocked_refund_recipient_is_rejected() {
+    let s = setup();
+    s.escrow.set_blocklist_entry(&s.depositor, &true);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::Unauthorized)));
+
+    // An approved refund to a clean address still goes through.
+    let treasury = Address::generate(&s.env);
+    s.escrow
+        .approve_refund(&1, &1_000, &treasury, &RefundMode::Full);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&treasury), 1_000);
+}
+
+#[test]
+fn test_unblocking_restores_payouts_and_emits_events() {
+    let s = setup();
+    s.escrow.set_blocklist_entry(&s.contributor, &true);
+    s.escrow.set_blocklist_entry(&s.contributor, &false);
+    assert!(!s.escrow.is_blocked(&s.contributor));
+
+    let updates = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            *contract == s.escrow.address
+                && Symbol::try_from_val(&s.env, &topics.get(0).unwrap())
+                    == Ok(symbol_short!("blocklist"))
+        })
+        .count();
+    assert_eq!(updates, 2);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
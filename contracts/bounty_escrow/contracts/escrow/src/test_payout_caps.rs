@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use crate::payout_caps::PayoutCapError;
+use crate::{test_setup::TestSetup, Error, EscrowStatus, PayoutCaps};
+use core::ops::Deref;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, max_release: i128, max_cumulative: i128) {
+        self.escrow.lock_funds_with_payout_caps(
+            &self.depositor,
+            &bounty_id,
+            &1_000,
+            &self.deadline(),
+            &PayoutCaps {
+                max_release,
+                max_cumulative,
+            },
+        );
+    }
+}
+
+#[test]
+fn test_single_release_cap() {
+    let s = Setup::new();
+    s.lock(1, 300, 0);
+
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &301),
+        Err(Err(PayoutCapError::PayoutCapExceeded.into()))
+    );
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    assert_eq!(s.token.balance(&s.contributor), 600);
+
+    // Full releases pay the rest at once and are not capped.
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_cumulative_cap_requires_review() {
+    let s = Setup::new();
+    s.lock(1, 0, 500);
+
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    s.escrow.partial_release(&1, &s.contributor, &200);
+    assert_eq!(s.escrow.get_released_since_review(&1), 500);
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &1),
+        Err(Err(PayoutCapError::PayoutReviewRequired.into()))
+    );
+    assert!(
+        !s.escrow
+            .simulate_partial_release(&1, &s.contributor, &1)
+            .success
+    );
+
+    s.escrow.approve_payout_review(&1);
+    assert_eq!(s.escrow.get_released_since_review(&1), 0);
+    s.escrow.partial_release(&1, &s.contributor, &500);
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+}
+
+#[test]
+fn test_caps_are_optional_and_validated() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    assert_eq!(s.escrow.get_payout_caps(&1), None);
+    s.escrow.partial_release(&1, &s.contributor, &900);
+    assert_eq!(
+        s.escrow.try_approve_payout_review(&1),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    let caps = PayoutCaps {
+        max_release: -1,
+        max_cumulative: 0,
+    };
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_with_payout_caps(&s.depositor, &2, &1_000, &deadline, &caps),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert!(s.escrow.try_get_escrow_info(&2).is_err());
+}
@@ -0,0 +1,135 @@
+#![cfg(test)]
+
+use crate::quadratic_funding::RoundError;
+use crate::{test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Vec,
+};
+
+const ENDS_AT: u64 = 100;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    backers: Vec<Address>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        base.token_admin.mint(&base.admin, &1_000);
+        let mut backers = Vec::new(&base.env);
+        for _ in 0..4 {
+            let backer = Address::generate(&base.env);
+            base.token_admin.mint(&backer, &1_000);
+            backers.push_back(backer);
+        }
+
+        base.lock(1, 1_000);
+        base.lock(2, 1_000);
+        Self { base, backers }
+    }
+
+    fn back(&self, round_id: u64, bounty_id: u64, backer: u32, amount: i128) {
+        self.escrow.contribute_in_round(
+            &self.backers.get(backer).unwrap(),
+            &round_id,
+            &bounty_id,
+            &amount,
+        );
+    }
+}
+
+#[test]
+fn test_many_small_backers_attract_more_matching() {
+    let s = Setup::new();
+    let round_id = s
+        .escrow
+        .create_funding_round(&vec![&s.env, 1, 2], &1_000, &ENDS_AT);
+    assert_eq!(s.token.balance(&s.admin), 0);
+
+    // Two backers of 100 on bounty 1, four of 25 on bounty 2.
+    s.back(round_id, 1, 0, 100);
+    s.back(round_id, 1, 1, 100);
+    for backer in 0..4 {
+        s.back(round_id, 2, backer, 25);
+    }
+    let matches = s.escrow.get_round_matches(&round_id);
+    assert_eq!(matches.get(1), Some(400));
+    assert_eq!(matches.get(2), Some(600));
+    assert_eq!(
+        s.escrow.try_close_funding_round(&round_id),
+        Err(Err(RoundError::StillOpen.into()))
+    );
+
+    s.env.ledger().set_timestamp(ENDS_AT);
+    assert_eq!(
+        s.escrow
+            .try_contribute_in_round(&s.backers.get(0).unwrap(), &round_id, &1, &10),
+        Err(Err(RoundError::Closed.into()))
+    );
+    assert_eq!(s.escrow.close_funding_round(&round_id), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_000 + 200 + 400);
+    assert_eq!(s.escrow.get_escrow_info(&2).amount, 1_000 + 100 + 600);
+    assert_eq!(
+        s.escrow.get_escrow_funders(&1).get(s.admin.clone()),
+        Some(400)
+    );
+    let round = s.escrow.get_funding_round(&round_id);
+    assert!(round.closed);
+    assert_eq!(round.matched, 1_000);
+    assert_eq!(
+        s.escrow.try_close_funding_round(&round_id),
+        Err(Err(RoundError::Closed.into()))
+    );
+}
+
+#[test]
+fn test_unmatched_pool_returns_to_sponsor() {
+    let s = Setup::new();
+    let round_id = s
+        .escrow
+        .create_funding_round(&vec![&s.env, 1, 2], &1_000, &ENDS_AT);
+    // A single backer has no quadratic bonus.
+    s.back(round_id, 1, 0, 100);
+    assert_eq!(
+        s.escrow
+            .try_contribute_in_round(&s.backers.get(0).unwrap(), &round_id, &3, &10),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    s.env.ledger().set_timestamp(ENDS_AT);
+    assert_eq!(s.escrow.close_funding_round(&round_id), 0);
+    assert_eq!(s.token.balance(&s.admin), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_100);
+}
+
+#[test]
+fn test_round_needs_locked_escrows() {
+    let s = Setup::new();
+    let contributor = Address::generate(&s.env);
+    s.escrow.release_funds(&2, &contributor);
+    assert_eq!(
+        s.escrow
+            .try_create_funding_round(&vec![&s.env, 1, 2], &1_000, &ENDS_AT),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        s.escrow
+            .try_create_funding_round(&vec![&s.env, 1, 1], &1_000, &ENDS_AT),
+        Err(Ok(Error::DuplicateBountyId))
+    );
+    assert_eq!(
+        s.escrow.try_get_funding_round(&1),
+        Err(Ok(RoundError::NotFound))
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus, ReassignmentError};
+use soroban_sdk::{testutils::Address as _, Address};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_funds_move_to_new_id() {
+    let s = setup();
+    let approver = Address::generate(&s.env);
+    s.escrow.set_escrow_approver(&1, &Some(approver.clone()));
+
+    s.escrow.reassign_escrow(&1, &2);
+    assert_eq!(s.env.auths()[0].0, s.depositor);
+
+    let escrow = s.escrow.get_escrow_info(&2);
+    assert_eq!(escrow.amount, 1_000);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(s.escrow.get_escrow_approver(&2), Some(approver));
+    assert_eq!(
+        s.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(s.escrow.get_escrow_count(), 1);
+    assert_eq!(s.escrow.get_contract_stats().count_locked, 1);
+    assert_eq!(s.escrow.get_contract_stats().total_value_locked, 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+
+    // The old id stays reserved.
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        s.escrow.try_lock_funds(&s.depositor, &1, &100, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+}
+
+#[test]
+fn test_reassign_rejected_after_payout_or_onto_used_id() {
+    let s = setup();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &deadline);
+    assert_eq!(
+        s.escrow.try_reassign_escrow(&1, &2),
+        Err(Ok(Error::BountyExists))
+    );
+    assert_eq!(
+        s.escrow.try_reassign_escrow(&1, &1),
+        Err(Ok(Error::BountyExists))
+    );
+
+    let contributor = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &contributor, &100);
+    assert_eq!(
+        s.escrow.try_reassign_escrow(&1, &3),
+        Err(Err(ReassignmentError::ReassignmentNotAllowed.into()))
+    );
+
+    s.escrow.assign_contributor(&2, &contributor, &86_400);
+    assert_eq!(
+        s.escrow.try_reassign_escrow(&2, &3),
+        Err(Err(ReassignmentError::ReassignmentNotAllowed.into()))
+    );
+}
@@ -0,0 +1,153 @@
+#![cfg(test)]
+
+use crate::{
+    reentrancy_guard, test_setup::TestSetup, BountyEscrowContractClient, CapabilityAction, DataKey,
+    EscrowStatus,
+};
+use core::ops::Deref;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    Address, Env,
+};
+
+/// Hook that tries to release the same escrow a second time while the
+/// first payout is still in flight.
+#[contract]
+pub struct DoubleSpendHook;
+
+#[contractimpl]
+impl DoubleSpendHook {
+    pub fn on_release(
+        env: Env,
+        escrow: Address,
+        bounty_id: u64,
+        recipient: Address,
+        _amount: i128,
+    ) {
+        BountyEscrowContractClient::new(&env, &escrow).release_funds(&bounty_id, &recipient);
+    }
+
+    pub fn on_refund(
+        _env: Env,
+        _escrow: Address,
+        _bounty_id: u64,
+        _recipient: Address,
+        _amount: i128,
+    ) {
+    }
+}
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    delegate: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let delegate = Address::generate(&base.env);
+        base.lock(1, 1_000);
+        Self { base, delegate }
+    }
+
+    /// Hold the lock as if an outer escrow call were still in flight.
+    fn hold_lock(&self) {
+        self.env.as_contract(&self.escrow.address, || {
+            reentrancy_guard::acquire(&self.env)
+        });
+    }
+
+    fn lock_held(&self) -> bool {
+        self.env.as_contract(&self.escrow.address, || {
+            reentrancy_guard::is_active(&self.env)
+        })
+    }
+
+    fn release_capability(&self, amount_limit: i128) -> u64 {
+        let expiry = self.env.ledger().timestamp() + 300;
+        self.escrow.issue_capability(
+            &self.admin,
+            &self.delegate,
+            &CapabilityAction::Release,
+            &1,
+            &amount_limit,
+            &expiry,
+            &2,
+        )
+    }
+}
+
+#[test]
+fn test_transfer_entry_points_reject_reentry() {
+    let s = Setup::new();
+    let capability_id = s.release_capability(500);
+    s.hold_lock();
+
+    assert!(s.escrow.try_release_funds(&1, &s.contributor).is_err());
+    assert!(s
+        .escrow
+        .try_partial_release(&1, &s.contributor, &100)
+        .is_err());
+    assert!(s
+        .escrow
+        .try_rescue_untracked_tokens(&s.token.address)
+        .is_err());
+    assert!(s
+        .escrow
+        .try_release_with_capability(&1, &s.contributor, &100, &s.delegate, &capability_id)
+        .is_err());
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    assert!(s.escrow.try_refund(&1).is_err());
+
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+    s.env.as_contract(&s.escrow.address, || {
+        assert!(s.env.storage().instance().has(&DataKey::ReentrancyGuard));
+    });
+}
+
+#[test]
+fn test_lock_is_released_after_successful_calls() {
+    let s = Setup::new();
+    let capability_id = s.release_capability(500);
+
+    s.escrow
+        .release_with_capability(&1, &s.contributor, &300, &s.delegate, &capability_id);
+    assert!(!s.lock_held());
+    s.escrow.partial_release(&1, &s.contributor, &200);
+    assert!(!s.lock_held());
+    s.escrow.partial_release(&1, &s.contributor, &500);
+    assert!(!s.lock_held());
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_hook_cannot_double_spend_capability_release() {
+    let s = Setup::new();
+    let capability_id = s.release_capability(400);
+    let hook_id = s.env.register_contract(None, DoubleSpendHook);
+    s.escrow.set_escrow_hook(&1, &Some(hook_id));
+
+    assert!(s
+        .escrow
+        .try_release_with_capability(&1, &s.contributor, &400, &s.delegate, &capability_id)
+        .is_err());
+
+    assert_eq!(s.token.balance(&s.contributor), 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 1_000);
+    assert!(!s.lock_held());
+}
@@ -0,0 +1,88 @@
+#![cfg(test)]
+
+use crate::referrals::ReferralError;
+use crate::{test_setup::TestSetup, Error, Referral};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+const DEADLINE: u64 = 1_000;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    referrer: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let referrer = Address::generate(&base.env);
+        base.escrow.set_referral_fee(&500);
+        Self { base, referrer }
+    }
+}
+
+#[test]
+fn test_referrer_paid_on_release() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_referrer(&s.depositor, &1, &1_000, &DEADLINE, &s.referrer);
+    s.escrow
+        .lock_funds_with_referrer(&s.depositor, &2, &1_000, &DEADLINE, &s.referrer);
+    // Changing the rate only affects referrers recorded afterwards.
+    s.escrow.set_referral_fee(&100);
+    assert_eq!(
+        s.escrow.get_escrow_referral(&1),
+        Some(Referral {
+            referrer: s.referrer.clone(),
+            fee_bps: 500,
+        })
+    );
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.referrer), 50);
+    assert_eq!(s.token.balance(&s.contributor), 950);
+
+    s.escrow.partial_release(&2, &s.contributor, &200);
+    assert_eq!(s.token.balance(&s.contributor), 950 + 190);
+    assert_eq!(s.escrow.get_referral_earnings(&s.referrer), 60);
+}
+
+#[test]
+fn test_referrer_recorded_at_assignment() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &DEADLINE);
+    s.escrow
+        .assign_contributor_with_referrer(&1, &s.contributor, &100, &s.referrer);
+    assert_eq!(s.escrow.get_escrow_referral(&1).unwrap().fee_bps, 500);
+
+    s.escrow.accept_assignment(&1);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 950);
+    assert_eq!(s.escrow.get_referral_earnings(&s.referrer), 50);
+}
+
+#[test]
+fn test_one_referrer_per_escrow() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_referrer(&s.depositor, &1, &1_000, &DEADLINE, &s.referrer);
+    let other = Address::generate(&s.env);
+    assert_eq!(
+        s.escrow
+            .try_assign_contributor_with_referrer(&1, &s.contributor, &100, &other),
+        Err(Err(ReferralError::ReferrerAlreadySet.into()))
+    );
+    assert_eq!(s.escrow.get_assignment(&1), None);
+    assert_eq!(
+        s.escrow.try_set_referral_fee(&5_001),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, RefundMode};
+
+const WINDOW: u64 = 7 * 86_400;
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    let deadline = s.env.ledger().timestamp() + 30 * 86_400;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s.escrow.set_refund_approval_window(&WINDOW);
+    s
+}
+
+#[test]
+fn test_approval_executes_within_window() {
+    let s = setup();
+    assert_eq!(s.escrow.get_refund_approval_window(), WINDOW);
+    s.escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+    s.advance_time(WINDOW);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_lapsed_approval_must_be_renewed() {
+    let s = setup();
+    s.escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+    s.advance_time(WINDOW + 1);
+
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+    assert!(!s.escrow.simulate_refund(&1).success);
+    let (can_refund, _, _, approval) = s.escrow.get_refund_eligibility(&1);
+    assert!(!can_refund);
+    assert_eq!(approval, None);
+
+    s.escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_zero_window_keeps_approvals() {
+    let s = setup();
+    s.escrow.set_refund_approval_window(&0);
+    s.escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+    s.advance_time(WINDOW * 3);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
@@ -0,0 +1,91 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.lock(1, 1_000);
+        Self { base, treasury }
+    }
+}
+
+#[test]
+fn test_expired_refund_goes_to_destination() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_refund_destination(&1), s.depositor);
+
+    s.escrow
+        .set_refund_destination(&1, &Some(s.treasury.clone()));
+    assert_eq!(s.escrow.get_refund_destination(&1), s.treasury);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    s.escrow.refund(&1);
+
+    assert_eq!(s.token.balance(&s.treasury), 1_000);
+    assert_eq!(s.token.balance(&s.depositor), 9_000);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Refunded);
+    assert_eq!(escrow.refund_history.get(0).unwrap().recipient, s.treasury);
+}
+
+#[test]
+fn test_cancel_goes_to_destination_until_cleared() {
+    let s = Setup::new();
+    s.escrow
+        .set_refund_destination(&1, &Some(s.treasury.clone()));
+    s.escrow.set_refund_destination(&1, &None);
+    assert_eq!(s.escrow.get_refund_destination(&1), s.depositor);
+
+    s.escrow
+        .set_refund_destination(&1, &Some(s.treasury.clone()));
+    s.escrow.cancel_escrow(&1);
+    assert_eq!(s.token.balance(&s.treasury), 1_000);
+    assert_eq!(
+        s.escrow
+            .try_set_refund_destination(&1, &Some(s.depositor.clone())),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_invalid_destinations_are_rejected() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow
+            .try_set_refund_destination(&1, &Some(s.escrow.address.clone())),
+        Err(Ok(Error::InvalidRecipient))
+    );
+
+    s.escrow.set_blocklist_entry(&s.treasury, &true);
+    assert_eq!(
+        s.escrow
+            .try_set_refund_destination(&1, &Some(s.treasury.clone())),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        s.escrow.try_set_refund_destination(&2, &None),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, RefundMode};
+use soroban_sdk::testutils::Ledger;
+
+const DEADLINE: u64 = 1_000;
+const GRACE: u64 = 500;
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.escrow.set_refund_grace_period(&GRACE);
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &DEADLINE);
+    s
+}
+
+#[test]
+fn test_refund_waits_for_grace_period() {
+    let s = setup();
+    assert_eq!(s.escrow.get_refund_grace_period(), GRACE);
+
+    s.env.ledger().set_timestamp(DEADLINE);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+    let (can_refund, deadline_passed, _, _) = s.escrow.get_refund_eligibility(&1);
+    assert!(!can_refund);
+    assert!(deadline_passed);
+    assert!(!s.escrow.simulate_refund(&1).success);
+
+    s.env.ledger().set_timestamp(DEADLINE + GRACE - 1);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::DeadlineNotPassed)));
+
+    s.env.ledger().set_timestamp(DEADLINE + GRACE);
+    assert!(s.escrow.get_refund_eligibility(&1).0);
+    assert!(s.escrow.simulate_refund(&1).success);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_approved_refund_ignores_grace_period() {
+    let s = setup();
+    s.escrow
+        .approve_refund(&1, &1_000, &s.depositor, &RefundMode::Full);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_zero_grace_period_refunds_at_deadline() {
+    let s = setup();
+    s.escrow.set_refund_grace_period(&0);
+    assert_eq!(s.escrow.get_refund_grace_period(), 0);
+
+    s.env.ledger().set_timestamp(DEADLINE);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
@@ -0,0 +1,120 @@
+#![cfg(test)]
+
+use crate::{
+    test_setup::{TestSetup, DEPOSITOR_BALANCE},
+    Error,
+};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.token_admin
+            .mint(&base.depositor, &(100_000 - DEPOSITOR_BALANCE));
+        Self { base, treasury }
+    }
+
+    fn set_release_fee(&self, rate: i128, enabled: bool) {
+        self.escrow.update_fee_config(
+            &None,
+            &Some(rate),
+            &Some(self.treasury.clone()),
+            &Some(enabled),
+        );
+        if self.escrow.get_pending_treasury().is_some() {
+            self.escrow.accept_treasury_role();
+        }
+    }
+}
+
+#[test]
+fn test_release_accrues_fee_for_treasury() {
+    let s = Setup::new();
+    s.set_release_fee(250, true);
+    s.lock(1, 10_000);
+    assert_eq!(s.escrow.get_escrow_fee_rate(&1), 250);
+
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.escrow.get_accrued_fees(), 250);
+    assert_eq!(s.token.balance(&s.contributor), 9_750);
+    assert_eq!(s.token.balance(&s.escrow.address), 250);
+}
+
+#[test]
+fn test_fee_change_does_not_affect_locked_escrows() {
+    let s = Setup::new();
+    s.set_release_fee(100, true);
+    s.lock(1, 10_000);
+
+    s.set_release_fee(1_000, true);
+    s.lock(2, 10_000);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.escrow.get_accrued_fees(), 100);
+
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.escrow.get_accrued_fees(), 1_100);
+    assert_eq!(s.token.balance(&s.contributor), 9_900 + 9_000);
+}
+
+#[test]
+fn test_escrow_locked_without_fees_stays_fee_free() {
+    let s = Setup::new();
+    s.set_release_fee(500, false);
+    s.lock(1, 10_000);
+    s.set_release_fee(500, true);
+
+    assert_eq!(s.escrow.get_escrow_fee_rate(&1), 0);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 10_000);
+    assert_eq!(s.escrow.get_accrued_fees(), 0);
+}
+
+#[test]
+fn test_partial_and_split_releases_pay_fees() {
+    let s = Setup::new();
+    s.set_release_fee(1_000, true);
+    s.lock(1, 10_000);
+
+    s.escrow.partial_release(&1, &s.contributor, &4_000);
+    let other = Address::generate(&s.env);
+    s.escrow.release_split(
+        &1,
+        &vec![
+            &s.env,
+            (s.contributor.clone(), 3_000),
+            (other.clone(), 3_000),
+        ],
+    );
+
+    assert_eq!(s.token.balance(&s.contributor), 3_600 + 2_700);
+    assert_eq!(s.token.balance(&other), 2_700);
+    assert_eq!(s.escrow.get_accrued_fees(), 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_fee_rate_is_capped() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow
+            .try_update_fee_config(&None, &Some(5_001), &None, &Some(true)),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+}
@@ -0,0 +1,56 @@
+#![cfg(test)]
+
+use crate::release_policy::ReleasePolicyError;
+use crate::{test_setup::TestSetup, ReleaseFundsItem};
+use soroban_sdk::{testutils::Address as _, vec, Address};
+
+fn setup<'a>() -> TestSetup<'a> {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s
+}
+
+#[test]
+fn test_release_to_depositor_rejected_by_default() {
+    let s = setup();
+    assert!(!s.escrow.is_release_to_depositor_allowed());
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.depositor),
+        Err(Err(ReleasePolicyError::ReleaseToDepositor.into()))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.depositor, &100),
+        Err(Err(ReleasePolicyError::ReleaseToDepositor.into()))
+    );
+    let items = vec![
+        &s.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: s.depositor.clone(),
+        },
+    ];
+    assert_eq!(
+        s.escrow.try_batch_release_funds(&items),
+        Err(Err(ReleasePolicyError::ReleaseToDepositor.into()))
+    );
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 1_000);
+
+    // Anyone else can still be paid.
+    let contributor = Address::generate(&s.env);
+    s.escrow.partial_release(&1, &contributor, &100);
+    assert_eq!(s.token.balance(&contributor), 100);
+}
+
+#[test]
+fn test_release_to_depositor_when_allowed() {
+    let s = setup();
+    s.escrow.set_release_to_depositor_allowed(&true);
+    assert!(s.escrow.is_release_to_depositor_allowed());
+
+    s.escrow.release_funds(&1, &s.depositor);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+
+    s.escrow.set_release_to_depositor_allowed(&false);
+    assert!(!s.escrow.is_release_to_depositor_allowed());
+}
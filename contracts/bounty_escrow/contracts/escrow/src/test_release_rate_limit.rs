@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, ReleaseRateLimit};
+use soroban_sdk::testutils::Ledger;
+
+const WINDOW: u64 = 86_400;
+
+fn setup<'a>(global_limit: i128, escrow_limit: i128) -> TestSetup<'a> {
+    let s = TestSetup::new();
+    let deadline = s.env.ledger().timestamp() + 30 * 86_400;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &deadline);
+    s.escrow.set_release_rate_limit(&Some(ReleaseRateLimit {
+        window: WINDOW,
+        global_limit,
+        escrow_limit,
+    }));
+    s
+}
+
+#[test]
+fn test_per_escrow_limit_caps_partial_releases() {
+    let s = setup(0, 400);
+    assert_eq!(s.escrow.get_releasable_now(&1), Some(400));
+
+    s.escrow.partial_release(&1, &s.contributor, &300);
+    assert_eq!(s.escrow.get_releasable_now(&1), Some(100));
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 700);
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &101),
+        Err(Ok(Error::ReleaseRateLimited))
+    );
+    // Other escrows have their own window.
+    s.escrow.partial_release(&2, &s.contributor, &400);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + WINDOW);
+    s.escrow.partial_release(&1, &s.contributor, &400);
+}
+
+#[test]
+fn test_global_limit_spans_escrows() {
+    let s = setup(1_500, 0);
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.escrow.get_releasable_now(&2), Some(500));
+    assert_eq!(
+        s.escrow.try_release_funds(&2, &s.contributor),
+        Err(Ok(Error::ReleaseRateLimited))
+    );
+
+    s.escrow.set_release_rate_limit(&None);
+    assert_eq!(s.escrow.get_releasable_now(&2), None);
+    s.escrow.release_funds(&2, &s.contributor);
+}
+
+#[test]
+fn test_invalid_limits_are_rejected() {
+    let s = setup(0, 0);
+    assert_eq!(
+        s.escrow.try_set_release_rate_limit(&Some(ReleaseRateLimit {
+            window: 0,
+            global_limit: 100,
+            escrow_limit: 0,
+        })),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.escrow.try_set_release_rate_limit(&Some(ReleaseRateLimit {
+            window: WINDOW,
+            global_limit: -1,
+            escrow_limit: 0,
+        })),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
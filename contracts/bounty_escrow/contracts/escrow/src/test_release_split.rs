@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::release_policy::ReleasePolicyError;
+use crate::{test_setup::TestSetup, Error, EscrowStatus, ReleaseRateLimit};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address, Vec};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    alice: Address,
+    bob: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let alice = Address::generate(&base.env);
+        let bob = Address::generate(&base.env);
+        base.lock(1, 1_000);
+        Self { base, alice, bob }
+    }
+
+    fn splits(&self, alice: i128, bob: i128) -> Vec<(Address, i128)> {
+        vec![
+            &self.env,
+            (self.alice.clone(), alice),
+            (self.bob.clone(), bob),
+        ]
+    }
+}
+
+#[test]
+fn test_release_split_pays_every_recipient() {
+    let s = Setup::new();
+
+    s.escrow.release_split(&1, &s.splits(600, 400));
+
+    assert_eq!(s.token.balance(&s.alice), 600);
+    assert_eq!(s.token.balance(&s.bob), 400);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining_amount, 0);
+}
+
+#[test]
+fn test_release_split_leaves_remainder_locked() {
+    let s = Setup::new();
+
+    s.escrow.release_split(&1, &s.splits(300, 200));
+
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 500);
+    assert_eq!(s.token.balance(&s.escrow.address), 500);
+}
+
+#[test]
+fn test_release_split_rejects_overdraw() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_release_split(&1, &s.splits(600, 401)),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    assert_eq!(s.token.balance(&s.alice), 0);
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 1_000);
+}
+
+#[test]
+fn test_release_split_validation() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_release_split(&1, &vec![&s.env]),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        s.escrow.try_release_split(&1, &s.splits(500, 0)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        s.escrow.try_release_split(&2, &s.splits(1, 1)),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_release_split_checks_every_recipient() {
+    let s = Setup::new();
+    let splits = vec![
+        &s.env,
+        (s.alice.clone(), 500_i128),
+        (s.depositor.clone(), 500_i128),
+    ];
+    assert_eq!(
+        s.escrow.try_release_split(&1, &splits),
+        Err(Err(ReleasePolicyError::ReleaseToDepositor.into()))
+    );
+    assert_eq!(s.token.balance(&s.alice), 0);
+}
+
+#[test]
+fn test_release_split_counts_against_rate_limit() {
+    let s = Setup::new();
+    s.escrow.set_release_rate_limit(&Some(ReleaseRateLimit {
+        window: 3_600,
+        global_limit: 0,
+        escrow_limit: 700,
+    }));
+    assert_eq!(
+        s.escrow.try_release_split(&1, &s.splits(400, 400)),
+        Err(Ok(Error::ReleaseRateLimited))
+    );
+    s.escrow.release_split(&1, &s.splits(400, 300));
+    assert_eq!(s.escrow.get_escrow_info(&1).remaining_amount, 300);
+}
@@ -0,0 +1,204 @@
+#![cfg(test)]
+
+use crate::nonce::VoucherError;
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, xdr::ToXdr, Address, BytesN};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    maintainer: Address,
+    key: SigningKey,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let maintainer = Address::generate(&base.env);
+        base.escrow.lock_funds_with_approver(
+            &base.depositor,
+            &1,
+            &1_000,
+            &base.deadline(),
+            &maintainer,
+        );
+
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let public_key = BytesN::from_array(&base.env, &key.verifying_key().to_bytes());
+        base.escrow.set_voucher_signer(&1, &Some(public_key));
+
+        Self {
+            base,
+            maintainer,
+            key,
+        }
+    }
+
+    /// Sign a voucher the way the approver's off-chain service would.
+    fn sign(&self, key: &SigningKey, amount: i128, nonce: u64) -> BytesN<64> {
+        self.sign_for(key, 1, amount, nonce)
+    }
+
+    fn sign_for(&self, key: &SigningKey, bounty_id: u64, amount: i128, nonce: u64) -> BytesN<64> {
+        let message = (
+            self.escrow.address.clone(),
+            bounty_id,
+            self.contributor.clone(),
+            amount,
+            nonce,
+        )
+            .to_xdr(&self.env);
+        let signature = key.sign(&message.to_alloc_vec());
+        BytesN::from_array(&self.env, &signature.to_bytes())
+    }
+}
+
+#[test]
+fn test_anyone_can_submit_signed_voucher() {
+    let s = Setup::new();
+    let signature = s.sign(&s.key, 400, 1);
+    s.escrow
+        .release_with_voucher(&1, &s.contributor, &400, &1, &signature);
+
+    // No address had to authorize the submission.
+    assert!(s.env.auths().is_empty());
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    assert_eq!(s.escrow.get_voucher_nonce(&1), 1);
+
+    let signature = s.sign(&s.key, 600, 5);
+    s.escrow
+        .release_with_voucher(&1, &s.contributor, &600, &5, &signature);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_voucher_cannot_be_replayed() {
+    let s = Setup::new();
+    let signature = s.sign(&s.key, 100, 3);
+    s.escrow
+        .release_with_voucher(&1, &s.contributor, &100, &3, &signature);
+
+    assert_eq!(
+        s.escrow
+            .try_release_with_voucher(&1, &s.contributor, &100, &3, &signature),
+        Err(Err(VoucherError::VoucherNonceUsed.into()))
+    );
+    let older = s.sign(&s.key, 100, 2);
+    assert_eq!(
+        s.escrow
+            .try_release_with_voucher(&1, &s.contributor, &100, &2, &older),
+        Err(Err(VoucherError::VoucherNonceUsed.into()))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 100);
+}
+
+#[test]
+fn test_approver_nonce_spans_escrows() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow
+        .lock_funds_with_approver(&s.depositor, &2, &500, &deadline, &s.maintainer);
+    let public_key = BytesN::from_array(&s.env, &s.key.verifying_key().to_bytes());
+    s.escrow.set_voucher_signer(&2, &Some(public_key));
+
+    let signature = s.sign(&s.key, 100, 3);
+    s.escrow
+        .release_with_voucher(&1, &s.contributor, &100, &3, &signature);
+    assert_eq!(s.escrow.get_nonce(&s.maintainer), 3);
+
+    // Bounty 2 has redeemed nothing yet, but the approver used nonce 3.
+    let reused = s.sign_for(&s.key, 2, 100, 3);
+    assert_eq!(
+        s.escrow
+            .try_release_with_voucher(&2, &s.contributor, &100, &3, &reused),
+        Err(Err(VoucherError::VoucherNonceUsed.into()))
+    );
+    let signature = s.sign_for(&s.key, 2, 100, 4);
+    s.escrow
+        .release_with_voucher(&2, &s.contributor, &100, &4, &signature);
+    assert_eq!(s.escrow.get_nonce(&s.maintainer), 4);
+    assert_eq!(s.escrow.get_nonce(&s.contributor), 0);
+    assert_eq!(s.token.balance(&s.contributor), 200);
+}
+
+#[test]
+fn test_interleaved_vouchers_redeem_in_any_order() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow
+        .lock_funds_with_approver(&s.depositor, &2, &500, &deadline, &s.maintainer);
+    let public_key = BytesN::from_array(&s.env, &s.key.verifying_key().to_bytes());
+    s.escrow.set_voucher_signer(&2, &Some(public_key));
+
+    // Signed in order for two escrows, submitted the other way round.
+    let first = s.sign_for(&s.key, 1, 100, 1);
+    let second = s.sign_for(&s.key, 2, 200, 2);
+    s.escrow
+        .release_with_voucher(&2, &s.contributor, &200, &2, &second);
+    s.escrow
+        .release_with_voucher(&1, &s.contributor, &100, &1, &first);
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(s.escrow.get_nonce(&s.maintainer), 2);
+
+    // Each of them still works only once.
+    assert_eq!(
+        s.escrow
+            .try_release_with_voucher(&1, &s.contributor, &100, &1, &first),
+        Err(Err(VoucherError::VoucherNonceUsed.into()))
+    );
+    assert_eq!(
+        s.escrow
+            .try_release_with_voucher(&2, &s.contributor, &200, &2, &second),
+        Err(Err(VoucherError::VoucherNonceUsed.into()))
+    );
+}
+
+#[test]
+fn test_tampered_or_foreign_voucher_is_rejected() {
+    let s = Setup::new();
+    let signature = s.sign(&s.key, 100, 1);
+    assert!(s
+        .escrow
+        .try_release_with_voucher(&1, &s.contributor, &900, &1, &signature)
+        .is_err());
+
+    let stranger = SigningKey::from_bytes(&[9; 32]);
+    let forged = s.sign(&stranger, 100, 1);
+    assert!(s
+        .escrow
+        .try_release_with_voucher(&1, &s.contributor, &100, &1, &forged)
+        .is_err());
+
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+    assert_eq!(s.escrow.get_voucher_nonce(&1), 0);
+}
+
+#[test]
+fn test_voucher_requires_registered_signer() {
+    let s = Setup::new();
+    s.escrow.set_voucher_signer(&1, &None);
+    let signature = s.sign(&s.key, 100, 1);
+    assert_eq!(
+        s.escrow
+            .try_release_with_voucher(&1, &s.contributor, &100, &1, &signature),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    // Escrows without an approver cannot take a signer at all.
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &500, &deadline);
+    assert_eq!(
+        s.escrow.try_set_voucher_signer(&2, &None),
+        Err(Ok(Error::Unauthorized))
+    );
+}
@@ -0,0 +1,116 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, RescueError, TimelockError};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+const DAY: u64 = 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.escrow
+            .update_fee_config(&None, &None, &Some(treasury.clone()), &None);
+        base.escrow.accept_treasury_role();
+
+        let deadline = base.env.ledger().timestamp() + 10 * DAY;
+        base.escrow
+            .lock_funds(&base.depositor, &1, &5_000, &deadline);
+        Self { base, treasury }
+    }
+}
+
+#[test]
+fn test_rescue_executes_only_after_delay() {
+    let s = Setup::new();
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.request_rescue(&2_000);
+
+    let request = s.escrow.get_rescue_request().unwrap();
+    assert_eq!(request.amount, 2_000);
+    assert_eq!(request.recipient, s.treasury);
+    assert_eq!(request.executable_at, s.env.ledger().timestamp() + DAY);
+
+    s.advance_time(DAY - 1);
+    assert_eq!(
+        s.escrow.try_execute_rescue(),
+        Err(Err(TimelockError::TimelockNotExpired.into()))
+    );
+
+    s.advance_time(1);
+    s.escrow.execute_rescue();
+    assert_eq!(s.token.balance(&s.treasury), 2_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 3_000);
+    assert_eq!(s.escrow.get_rescue_request(), None);
+    assert_eq!(
+        s.escrow.try_execute_rescue(),
+        Err(Err(RescueError::RescueNotRequested.into()))
+    );
+}
+
+#[test]
+fn test_rescue_delay_follows_longer_timelock() {
+    let s = Setup::new();
+    s.escrow.set_timelock_delay(&(3 * DAY));
+    s.escrow.request_rescue(&1_000);
+    assert_eq!(
+        s.escrow.get_rescue_request().unwrap().executable_at,
+        s.env.ledger().timestamp() + 3 * DAY
+    );
+}
+
+#[test]
+fn test_rescue_request_validation() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_request_rescue(&0),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    s.escrow.request_rescue(&1_000);
+    assert_eq!(
+        s.escrow.try_request_rescue(&500),
+        Err(Err(RescueError::RescuePending.into()))
+    );
+
+    s.advance_time(DAY);
+    assert_eq!(s.escrow.try_execute_rescue(), Err(Ok(Error::NotPaused)));
+
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.cancel_rescue();
+    assert_eq!(
+        s.escrow.try_execute_rescue(),
+        Err(Err(RescueError::RescueNotRequested.into()))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_rescue(),
+        Err(Err(RescueError::RescueNotRequested.into()))
+    );
+    assert_eq!(s.token.balance(&s.escrow.address), 5_000);
+}
+
+#[test]
+fn test_rescue_cannot_exceed_balance() {
+    let s = Setup::new();
+    s.escrow.set_paused(&Some(true), &None, &None, &None);
+    s.escrow.request_rescue(&6_000);
+    s.advance_time(DAY);
+    assert_eq!(
+        s.escrow.try_execute_rescue(),
+        Err(Ok(Error::InsufficientFunds))
+    );
+}
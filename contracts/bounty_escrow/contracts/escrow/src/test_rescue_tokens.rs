@@ -0,0 +1,162 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, RescueRecord};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Symbol, TryFromVal,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.escrow
+            .update_fee_config(&None, &None, &Some(treasury.clone()), &None);
+        base.escrow.accept_treasury_role();
+        Self { base, treasury }
+    }
+
+    /// A second token the escrow does not track.
+    fn other_token(&self) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
+        let address = self
+            .env
+            .register_stellar_asset_contract_v2(self.admin.clone())
+            .address();
+        (
+            token::Client::new(&self.env, &address),
+            token::StellarAssetClient::new(&self.env, &address),
+        )
+    }
+}
+
+#[test]
+fn test_rescue_surplus_of_escrow_token() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s.escrow.lock_funds(&s.depositor, &2, &2_000, &deadline);
+    s.escrow.partial_release(&2, &s.contributor, &500);
+
+    // Someone sends tokens straight to the contract.
+    s.token_admin.mint(&s.escrow.address, &300);
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 300);
+
+    assert_eq!(s.escrow.rescue_untracked_tokens(&s.token.address), 300);
+    assert_eq!(s.token.balance(&s.treasury), 300);
+    assert_eq!(s.token.balance(&s.escrow.address), 2_500);
+
+    // Escrowed funds are untouched and still fully payable.
+    assert_eq!(s.escrow.rescue_untracked_tokens(&s.token.address), 0);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.partial_release(&2, &s.contributor, &1_500);
+    assert_eq!(s.token.balance(&s.contributor), 3_000);
+}
+
+#[test]
+fn test_rescue_foreign_token_whole_balance() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+
+    let (other, other_admin) = s.other_token();
+    other_admin.mint(&s.escrow.address, &750);
+    assert_eq!(s.escrow.get_untracked_balance(&other.address), 750);
+
+    assert_eq!(s.escrow.rescue_untracked_tokens(&other.address), 750);
+    assert_eq!(other.balance(&s.treasury), 750);
+    assert_eq!(other.balance(&s.escrow.address), 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 1_000);
+}
+
+#[test]
+fn test_tvl_by_token() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s.escrow.lock_funds(&s.depositor, &2, &2_000, &deadline);
+    s.escrow.partial_release(&2, &s.contributor, &500);
+
+    let (other, _) = s.other_token();
+    assert_eq!(
+        s.escrow.list_tracked_tokens(),
+        vec![&s.env, s.token.address.clone()]
+    );
+    assert_eq!(s.escrow.get_tvl_by_token(&s.token.address), 2_500);
+    assert_eq!(s.escrow.get_tvl_by_token(&other.address), 0);
+}
+
+#[test]
+fn test_rescue_emits_event_per_rescue() {
+    let s = Setup::new();
+    let (other, other_admin) = s.other_token();
+    other_admin.mint(&s.escrow.address, &10);
+    s.token_admin.mint(&s.escrow.address, &20);
+
+    s.escrow.rescue_untracked_tokens(&other.address);
+    s.escrow.rescue_untracked_tokens(&s.token.address);
+    s.escrow.rescue_untracked_tokens(&s.token.address);
+
+    let rescue = Symbol::new(&s.env, "rescue");
+    let count = s
+        .env
+        .events()
+        .all()
+        .iter()
+        .filter(|(contract, topics, _)| {
+            *contract == s.escrow.address
+                && Symbol::try_from_val(&s.env, &topics.get(0).unwrap())
+                    .map(|sym| sym == rescue)
+                    .unwrap_or(false)
+        })
+        .count();
+    // Including the second escrow token rescue, which found nothing.
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn test_rescue_history() {
+    let s = Setup::new();
+    let (other, other_admin) = s.other_token();
+    other_admin.mint(&s.escrow.address, &10);
+    s.token_admin.mint(&s.escrow.address, &20);
+
+    s.escrow.rescue_untracked_tokens(&other.address);
+    s.env.ledger().set_timestamp(500);
+    s.escrow.rescue_untracked_tokens(&s.token.address);
+    s.escrow.rescue_untracked_tokens(&s.token.address);
+
+    let history = s.escrow.get_rescue_history(&0, &10);
+    assert_eq!(history.len(), 3);
+    assert_eq!(
+        history.get(0).unwrap(),
+        RescueRecord {
+            token: other.address.clone(),
+            amount: 10,
+            recipient: s.treasury.clone(),
+            rescued_by: s.admin.clone(),
+            timestamp: 0,
+        }
+    );
+    assert_eq!(history.get(1).unwrap().amount, 20);
+    assert_eq!(history.get(1).unwrap().timestamp, 500);
+    assert_eq!(history.get(2).unwrap().amount, 0);
+
+    let page = s.escrow.get_rescue_history(&1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap(), history.get(1).unwrap());
+    assert!(s.escrow.get_rescue_history(&3, &10).is_empty());
+}
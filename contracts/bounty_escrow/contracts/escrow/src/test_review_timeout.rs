@@ -0,0 +1,95 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, AssignmentError, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::BytesN;
+
+const REVIEW: u64 = 5 * 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.env.ledger().timestamp() + 30 * 86_400;
+        base.escrow
+            .lock_funds(&base.depositor, &1, &1_000, &deadline);
+        base.escrow.set_review_period(&1, &Some(REVIEW));
+        base.escrow
+            .assign_contributor(&1, &base.contributor, &86_400);
+        base.escrow.accept_assignment(&1);
+        Self { base }
+    }
+
+    fn hash(&self) -> BytesN<32> {
+        BytesN::from_array(&self.env, &[1; 32])
+    }
+}
+
+#[test]
+fn test_contributor_claims_after_silent_review() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_claim_after_review_timeout(&1),
+        Err(Ok(Error::SubmissionRequired))
+    );
+
+    s.escrow.submit_work(&1, &s.hash());
+    s.advance_time(REVIEW - 1);
+    assert_eq!(
+        s.escrow.try_claim_after_review_timeout(&1),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+
+    s.advance_time(1);
+    s.escrow.claim_after_review_timeout(&1);
+    assert_eq!(s.env.auths()[0].0, s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_dispute_during_review_blocks_claim() {
+    let s = Setup::new();
+    s.escrow.submit_work(&1, &s.hash());
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &s.hash());
+
+    s.advance_time(REVIEW);
+    assert_eq!(
+        s.escrow.try_claim_after_review_timeout(&1),
+        Err(Ok(Error::DisputeOpen))
+    );
+    assert_eq!(s.token.balance(&s.contributor), 0);
+}
+
+#[test]
+fn test_review_period_is_fixed_after_acceptance() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_review_period(&1), Some(REVIEW));
+    assert_eq!(
+        s.escrow.try_set_review_period(&1, &None),
+        Err(Err(AssignmentError::AssignmentAccepted.into()))
+    );
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &deadline);
+    assert_eq!(
+        s.escrow.try_set_review_period(&2, &Some(0)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.escrow.try_claim_after_review_timeout(&2),
+        Err(Err(AssignmentError::ReviewPeriodNotSet.into()))
+    );
+}
@@ -0,0 +1,132 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, Role};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    random: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let random = Address::generate(&base.env);
+        Self { base, random }
+    }
+}
+
+#[test]
+fn test_admin_holds_every_role() {
+    let setup = Setup::new();
+
+    assert!(setup.escrow.has_role(&Role::Admin, &setup.admin));
+    assert!(setup.escrow.has_role(&Role::Releaser, &setup.admin));
+    assert!(setup.escrow.has_role(&Role::Rescuer, &setup.admin));
+    assert!(setup.escrow.has_role(&Role::Pauser, &setup.admin));
+    assert!(!setup.escrow.has_role(&Role::Releaser, &setup.random));
+}
+
+#[test]
+fn test_grant_and_revoke_role() {
+    let setup = Setup::new();
+
+    setup
+        .escrow
+        .grant_role(&setup.admin, &Role::Pauser, &setup.random);
+    assert!(setup.escrow.has_role(&Role::Pauser, &setup.random));
+    assert!(!setup.escrow.has_role(&Role::Releaser, &setup.random));
+
+    setup
+        .escrow
+        .revoke_role(&setup.admin, &Role::Pauser, &setup.random);
+    assert!(!setup.escrow.has_role(&Role::Pauser, &setup.random));
+}
+
+#[test]
+fn test_non_admin_cannot_grant_role() {
+    let setup = Setup::new();
+
+    let res = setup
+        .escrow
+        .try_grant_role(&setup.random, &Role::Releaser, &setup.random);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_releaser_can_release_but_not_pause() {
+    let setup = Setup::new();
+    let releaser = Address::generate(&setup.env);
+    let contributor = Address::generate(&setup.env);
+    setup
+        .escrow
+        .grant_role(&setup.admin, &Role::Releaser, &releaser);
+
+    let deadline = setup.env.ledger().timestamp() + 3600;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1u64, &1000i128, &deadline);
+
+    setup
+        .escrow
+        .partial_release_with_role(&releaser, &1u64, &contributor, &400i128);
+    assert_eq!(setup.token.balance(&contributor), 400);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2u64, &500i128, &deadline);
+    setup
+        .escrow
+        .release_funds_with_role(&releaser, &2u64, &contributor);
+    assert_eq!(setup.token.balance(&contributor), 900);
+
+    let res = setup
+        .escrow
+        .try_set_paused_with_role(&releaser, &Some(true), &None, &None, &None);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_pauser_and_rescuer_roles() {
+    let setup = Setup::new();
+    let pauser = Address::generate(&setup.env);
+    let rescuer = Address::generate(&setup.env);
+    let target = Address::generate(&setup.env);
+    setup
+        .escrow
+        .grant_role(&setup.admin, &Role::Pauser, &pauser);
+    setup
+        .escrow
+        .grant_role(&setup.admin, &Role::Rescuer, &rescuer);
+
+    let deadline = setup.env.ledger().timestamp() + 3600;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1u64, &600i128, &deadline);
+
+    let res = setup
+        .escrow
+        .try_release_funds_with_role(&pauser, &1u64, &target);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+    let res = setup
+        .escrow
+        .try_emergency_withdraw_with_role(&pauser, &target);
+    assert_eq!(res, Err(Ok(Error::Unauthorized)));
+
+    setup
+        .escrow
+        .set_paused_with_role(&pauser, &Some(true), &None, &None, &None);
+    assert!(setup.escrow.get_pause_flags().lock_paused);
+
+    setup.escrow.emergency_withdraw_with_role(&rescuer, &target);
+    assert_eq!(setup.token.balance(&target), 600);
+}
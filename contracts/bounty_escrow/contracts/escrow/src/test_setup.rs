@@ -0,0 +1,102 @@
+//! Shared fixture for the tests of this crate and of `escrow-testutils`:
+//! an initialized escrow with a Stellar asset token and a funded depositor.
+//!
+//! Unit tests here compile their own copy of the contract types and can't
+//! use `escrow-testutils`, so the fixture is a macro that both expand
+//! against their copy: `$crate` names whichever one is being built. A test
+//! module that needs more state wraps a `TestSetup` in its own `Setup` and
+//! derefs to it, so its tests keep writing `s.escrow` and `s.env`.
+
+/// Expands to `TestSetup`, `DEPOSITOR_BALANCE` and `LOCK_DURATION`. The
+/// crate expanding it needs `soroban-sdk` with the `testutils` feature.
+#[macro_export]
+macro_rules! escrow_test_setup {
+    () => {
+        /// Tokens minted to the depositor by `TestSetup::new`.
+        pub const DEPOSITOR_BALANCE: i128 = 10_000;
+
+        /// Seconds from now until the deadline of escrows locked with `lock`.
+        pub const LOCK_DURATION: u64 = 1_000;
+
+        pub struct TestSetup<'a> {
+            pub env: ::soroban_sdk::Env,
+            pub admin: ::soroban_sdk::Address,
+            pub depositor: ::soroban_sdk::Address,
+            pub contributor: ::soroban_sdk::Address,
+            pub token: ::soroban_sdk::token::Client<'a>,
+            pub token_admin: ::soroban_sdk::token::StellarAssetClient<'a>,
+            pub escrow: $crate::BountyEscrowContractClient<'a>,
+        }
+
+        impl<'a> TestSetup<'a> {
+            /// An initialized escrow with all auths mocked and
+            /// `DEPOSITOR_BALANCE` tokens minted to the depositor.
+            pub fn new() -> Self {
+                Self::with_env(::soroban_sdk::Env::default())
+            }
+
+            /// Like `new`, on an `env` whose ledger the test has already
+            /// configured.
+            pub fn with_env(env: ::soroban_sdk::Env) -> Self {
+                use ::soroban_sdk::testutils::Address as _;
+                use ::soroban_sdk::{token, Address};
+
+                env.mock_all_auths();
+
+                let admin = Address::generate(&env);
+                let depositor = Address::generate(&env);
+                let contributor = Address::generate(&env);
+
+                let token_address = env
+                    .register_stellar_asset_contract_v2(admin.clone())
+                    .address();
+                let token = token::Client::new(&env, &token_address);
+                let token_admin = token::StellarAssetClient::new(&env, &token_address);
+                token_admin.mint(&depositor, &DEPOSITOR_BALANCE);
+
+                let escrow_id = env.register_contract(None, $crate::BountyEscrowContract);
+                let escrow = $crate::BountyEscrowContractClient::new(&env, &escrow_id);
+                escrow.init(&admin, &token_address);
+
+                Self {
+                    env,
+                    admin,
+                    depositor,
+                    contributor,
+                    token,
+                    token_admin,
+                    escrow,
+                }
+            }
+
+            /// Deadline for an escrow locked now.
+            pub fn deadline(&self) -> u64 {
+                self.env.ledger().timestamp() + LOCK_DURATION
+            }
+
+            /// A `Locked` escrow of `amount` from the depositor.
+            pub fn lock(&self, bounty_id: u64, amount: i128) {
+                self.escrow
+                    .lock_funds(&self.depositor, &bounty_id, &amount, &self.deadline());
+            }
+
+            /// Move the ledger clock forward by `seconds`.
+            pub fn advance_time(&self, seconds: u64) {
+                use ::soroban_sdk::testutils::Ledger as _;
+
+                self.env
+                    .ledger()
+                    .set_timestamp(self.env.ledger().timestamp() + seconds);
+            }
+        }
+
+        impl Default for TestSetup<'_> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+crate::escrow_test_setup!();
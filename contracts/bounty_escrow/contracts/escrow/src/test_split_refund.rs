@@ -0,0 +1,107 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.lock(1, 1_000);
+        Self { base, treasury }
+    }
+
+    fn split(&self, share: i128) -> RefundMode {
+        RefundMode::Split(self.contributor.clone(), share)
+    }
+}
+
+#[test]
+fn test_split_refund_pays_both_parties() {
+    let s = Setup::new();
+    s.escrow
+        .approve_refund(&1, &700, &s.depositor, &s.split(300));
+    s.escrow.refund(&1);
+
+    assert_eq!(s.token.balance(&s.depositor), 9_700);
+    assert_eq!(s.token.balance(&s.contributor), 300);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.remaining_amount, 0);
+    assert_eq!(info.refund_history.get(0).unwrap().mode, s.split(300));
+    assert_eq!(
+        s.escrow
+            .get_payouts_by_contributor(&s.contributor, &0, &10)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_split_refund_leaves_remainder_locked() {
+    let s = Setup::new();
+    s.escrow
+        .approve_refund(&1, &400, &s.depositor, &s.split(100));
+    s.escrow.refund(&1);
+
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(info.remaining_amount, 500);
+    assert_eq!(s.token.balance(&s.escrow.address), 500);
+}
+
+#[test]
+fn test_split_share_pays_release_fee() {
+    let s = Setup::new();
+    s.escrow
+        .update_fee_config(&None, &Some(1_000), &Some(s.treasury.clone()), &Some(true));
+    s.escrow.accept_treasury_role();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &deadline);
+
+    s.escrow
+        .approve_refund(&2, &500, &s.depositor, &s.split(500));
+    s.escrow.refund(&2);
+    assert_eq!(s.token.balance(&s.contributor), 450);
+    assert_eq!(s.escrow.get_accrued_fees(), 50);
+}
+
+#[test]
+fn test_split_refund_validation() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow
+            .try_approve_refund(&1, &700, &s.depositor, &s.split(301)),
+        Err(Ok(Error::AmountExceedsRemaining))
+    );
+    assert_eq!(
+        s.escrow
+            .try_approve_refund(&1, &700, &s.depositor, &s.split(0)),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    // The approval no longer fits once part of the escrow has been paid out.
+    s.escrow
+        .approve_refund(&1, &700, &s.depositor, &s.split(300));
+    s.escrow.partial_release(&1, &s.contributor, &100);
+    assert_eq!(
+        s.escrow.try_refund(&1),
+        Err(Ok(Error::AmountExceedsRemaining))
+    );
+}
@@ -0,0 +1,122 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::BytesN;
+
+const RETENTION: u64 = 90 * 86_400;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    fn lock(&self, bounty_id: u64) {
+        self.base.lock(bounty_id, 1_000);
+    }
+}
+
+#[test]
+fn test_sweep_archives_settled_escrows_after_retention() {
+    let s = Setup::new();
+    s.lock(1);
+    s.lock(2);
+    s.lock(3);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.advance_time(1_001);
+    s.escrow.refund(&2);
+
+    // Escrow 1 was released 1_001 seconds before escrow 2 was refunded.
+    s.advance_time(RETENTION - 1_002);
+    assert_eq!(s.escrow.sweep_stale_escrows(&10), 0);
+
+    s.advance_time(1);
+    assert_eq!(s.escrow.sweep_stale_escrows(&10), 1);
+    assert_eq!(
+        s.escrow.try_get_escrow_info(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    let archived = s.escrow.get_archived_escrow(&1).unwrap();
+    assert_eq!(archived.depositor, s.depositor);
+    assert_eq!(archived.amount, 1_000);
+    assert_eq!(archived.status, EscrowStatus::Released);
+
+    s.advance_time(1_001);
+    assert_eq!(s.escrow.sweep_stale_escrows(&10), 1);
+    assert_eq!(
+        s.escrow.get_archived_escrow(&2).unwrap().status,
+        EscrowStatus::Refunded
+    );
+
+    // Locked escrows are never swept and the indexes only list live escrows.
+    assert_eq!(s.escrow.get_escrow_info(&3).status, EscrowStatus::Locked);
+    assert_eq!(s.escrow.get_escrow_count(), 1);
+    assert_eq!(
+        s.escrow
+            .get_escrows_by_depositor(&s.depositor, &0, &10)
+            .len(),
+        1
+    );
+    assert_eq!(
+        s.escrow
+            .get_escrow_ids_by_status(&EscrowStatus::Released, &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_sweep_respects_max_count_and_frozen_escrows() {
+    let s = Setup::new();
+    for id in 1..=3 {
+        s.lock(id);
+        s.escrow.release_funds(&id, &s.contributor);
+    }
+    s.escrow
+        .freeze_escrow(&s.admin, &1, &BytesN::from_array(&s.env, &[7; 32]));
+    s.advance_time(RETENTION);
+
+    assert_eq!(s.escrow.sweep_stale_escrows(&1), 1);
+    assert_eq!(s.escrow.sweep_stale_escrows(&5), 1);
+    assert_eq!(s.escrow.sweep_stale_escrows(&5), 0);
+    assert!(s.escrow.get_archived_escrow(&1).is_none());
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+
+    assert_eq!(
+        s.escrow.try_sweep_stale_escrows(&0),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+    assert_eq!(
+        s.escrow.try_sweep_stale_escrows(&21),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_archived_bounty_id_cannot_be_reused() {
+    let s = Setup::new();
+    s.lock(1);
+    s.escrow.release_funds(&1, &s.contributor);
+    s.advance_time(RETENTION);
+    s.escrow.sweep_stale_escrows(&1);
+
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        s.escrow.try_lock_funds(&s.depositor, &1, &1_000, &deadline),
+        Err(Ok(Error::BountyExists))
+    );
+}
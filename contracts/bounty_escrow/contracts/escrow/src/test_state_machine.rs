@@ -0,0 +1,196 @@
+#![cfg(test)]
+
+use crate::events::StatusChanged;
+use crate::state_machine::{next, StatusEvent};
+use crate::test_setup::TestSetup;
+use crate::{Error, EscrowStatus, RefundMode};
+use core::cell::Cell;
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    Address, BytesN, Symbol, TryFromVal, Vec,
+};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    /// `status` events already returned by `status_changes`.
+    seen: Cell<u32>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+            seen: Cell::new(0),
+        }
+    }
+
+    fn lock(&self, bounty_id: u64) {
+        self.base.lock(bounty_id, 1_000);
+    }
+
+    /// `status` events published since the previous call of this method.
+    /// The test environment keeps the events of earlier calls.
+    fn status_changes(&self) -> Vec<StatusChanged> {
+        let mut changes = Vec::new(&self.env);
+        for (contract, topics, data) in self.env.events().all().iter() {
+            if contract == self.escrow.address
+                && Symbol::try_from_val(&self.env, &topics.get(0).unwrap())
+                    == Ok(symbol_short!("status"))
+            {
+                changes.push_back(StatusChanged::try_from_val(&self.env, &data).unwrap());
+            }
+        }
+        let new = changes.slice(self.seen.get()..);
+        self.seen.set(changes.len());
+        new
+    }
+
+    fn assert_changed(&self, bounty_id: u64, from: EscrowStatus, to: EscrowStatus) {
+        let changes = self.status_changes();
+        assert_eq!(changes.len(), 1);
+        let change = changes.get(0).unwrap();
+        assert_eq!(change.bounty_id, bounty_id);
+        assert_eq!(change.from, from);
+        assert_eq!(change.to, to);
+    }
+}
+
+#[test]
+fn test_transition_table() {
+    use EscrowStatus::*;
+    let events = [
+        StatusEvent::Release,
+        StatusEvent::Refund,
+        StatusEvent::PartialRefund,
+        StatusEvent::Cancel,
+    ];
+    let table = [
+        (
+            Locked,
+            [
+                Some(Released),
+                Some(Refunded),
+                Some(PartiallyRefunded),
+                Some(Cancelled),
+            ],
+        ),
+        (
+            PartiallyRefunded,
+            [None, Some(Refunded), Some(PartiallyRefunded), None],
+        ),
+        (Released, [None, None, None, None]),
+        (Refunded, [None, None, None, None]),
+        (Cancelled, [None, None, None, None]),
+    ];
+    for (from, row) in table {
+        for (event, to) in events.iter().zip(row) {
+            assert_eq!(next(&from, *event), to, "{from:?} on {event:?}");
+        }
+    }
+}
+
+#[test]
+fn test_status_changes_are_published() {
+    let s = Setup::new();
+
+    s.lock(1);
+    assert!(s.status_changes().is_empty());
+    s.escrow.release_funds(&1, &s.contributor);
+    s.assert_changed(1, EscrowStatus::Locked, EscrowStatus::Released);
+
+    s.lock(2);
+    s.escrow.cancel_escrow(&2);
+    s.assert_changed(2, EscrowStatus::Locked, EscrowStatus::Cancelled);
+
+    s.lock(3);
+    s.escrow
+        .approve_refund(&3, &400, &s.depositor, &RefundMode::Partial);
+    s.escrow.refund(&3);
+    s.assert_changed(3, EscrowStatus::Locked, EscrowStatus::PartiallyRefunded);
+
+    // Staying in `PartiallyRefunded` is not a change.
+    s.escrow
+        .approve_refund(&3, &100, &s.depositor, &RefundMode::Partial);
+    s.escrow.refund(&3);
+    assert!(s.status_changes().is_empty());
+
+    s.escrow
+        .approve_refund(&3, &500, &s.depositor, &RefundMode::Full);
+    s.escrow.refund(&3);
+    s.assert_changed(3, EscrowStatus::PartiallyRefunded, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_partial_release_keeps_escrow_locked() {
+    let s = Setup::new();
+    s.lock(1);
+
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    assert!(s.status_changes().is_empty());
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Locked);
+
+    s.escrow.partial_release(&1, &s.contributor, &600);
+    s.assert_changed(1, EscrowStatus::Locked, EscrowStatus::Released);
+}
+
+#[test]
+fn test_final_statuses_reject_transitions() {
+    let s = Setup::new();
+    s.lock(1);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_dispute_resolution_follows_transition_table() {
+    let s = Setup::new();
+    let arbiter = Address::generate(&s.env);
+    s.escrow.set_arbiter(&arbiter);
+    s.lock(1);
+    s.escrow.assign_contributor(&1, &s.contributor, &86_400);
+    s.escrow.accept_assignment(&1);
+    s.escrow
+        .approve_refund(&1, &400, &s.depositor, &RefundMode::Partial);
+    s.escrow.refund(&1);
+    s.assert_changed(1, EscrowStatus::Locked, EscrowStatus::PartiallyRefunded);
+    let reason = BytesN::from_array(&s.env, &[7; 32]);
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &reason);
+
+    // Paying the contributor would release a partially refunded escrow.
+    assert_eq!(
+        s.escrow.try_resolve_dispute(&1, &5_000),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert!(s.status_changes().is_empty());
+
+    s.escrow.resolve_dispute(&1, &0);
+    s.assert_changed(1, EscrowStatus::PartiallyRefunded, EscrowStatus::Refunded);
+    let escrow = s.escrow.get_escrow_info(&1);
+    assert_eq!(escrow.remaining_amount, 0);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
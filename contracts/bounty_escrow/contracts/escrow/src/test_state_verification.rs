@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::{BountyEscrowContract, BountyEscrowContractClient, DataKey, EscrowStatus};
+    use crate::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus};
     use soroban_sdk::testutils::Address as _;
     use soroban_sdk::{token, Address, Env};
 
@@ -55,9 +55,7 @@ mod test {
         let mut escrow = client.get_escrow_info(&bounty_id);
         escrow.remaining_amount = 2000;
         env.as_contract(&contract_id, || {
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(bounty_id), &escrow);
+            BountyEscrowContract::write_escrow_only(&env, bounty_id, &escrow, false);
         });
 
         assert!(
@@ -78,9 +76,7 @@ mod test {
         let mut escrow = client.get_escrow_info(&bounty_id);
         escrow.amount = -1;
         env.as_contract(&contract_id, || {
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(bounty_id), &escrow);
+            BountyEscrowContract::write_escrow_only(&env, bounty_id, &escrow, false);
         });
 
         assert!(
@@ -102,9 +98,7 @@ mod test {
         escrow.status = EscrowStatus::Released;
         escrow.remaining_amount = 100;
         env.as_contract(&contract_id, || {
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(bounty_id), &escrow);
+            BountyEscrowContract::write_escrow_only(&env, bounty_id, &escrow, false);
         });
 
         assert!(
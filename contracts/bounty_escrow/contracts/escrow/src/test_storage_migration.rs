@@ -0,0 +1,165 @@
+#![cfg(test)]
+
+use crate::{
+    schema_migration::SchemaMigrationError, test_setup::TestSetup, BountyEscrowContract,
+    BountyEscrowContractClient, DataKey, Error, EscrowStatus, EscrowV1,
+};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Events, Env, Symbol, TryFromVal, Vec};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        Self {
+            base: TestSetup::new(),
+        }
+    }
+
+    /// Write an escrow the way schema v1 code stored it and roll the
+    /// instance back to v1, as if it predated the metadata fields.
+    fn store_v1_escrow(&self, bounty_id: u64, amount: i128) {
+        let deadline = self.deadline();
+        self.token_admin.mint(&self.escrow.address, &amount);
+        self.env.as_contract(&self.escrow.address, || {
+            let old = EscrowV1 {
+                depositor: self.depositor.clone(),
+                amount,
+                remaining_amount: amount,
+                status: EscrowStatus::Locked,
+                deadline,
+                refund_history: Vec::new(&self.env),
+            };
+            let storage = self.env.storage();
+            storage.persistent().set(&DataKey::Escrow(bounty_id), &old);
+            let mut index: Vec<u64> = storage
+                .persistent()
+                .get(&DataKey::EscrowIndex)
+                .unwrap_or(Vec::new(&self.env));
+            index.push_back(bounty_id);
+            storage.persistent().set(&DataKey::EscrowIndex, &index);
+            storage.instance().remove(&DataKey::SchemaVersion);
+        });
+    }
+
+    /// Run one page of the migration as its own transaction, metered
+    /// against the default per-transaction budget.
+    fn migrate_page(&self, cursor: u32, max_count: u32) -> Option<u32> {
+        self.env.budget().reset_default();
+        self.escrow.migrate(&cursor, &max_count)
+    }
+
+    fn migrate_events(&self) -> usize {
+        let migrate = Symbol::new(&self.env, "migrate");
+        self.env
+            .events()
+            .all()
+            .iter()
+            .filter(|(contract, topics, _)| {
+                *contract == self.escrow.address
+                    && Symbol::try_from_val(&self.env, &topics.get(0).unwrap())
+                        .map(|sym| sym == migrate)
+                        .unwrap_or(false)
+            })
+            .count()
+    }
+}
+
+#[test]
+fn test_fresh_instance_is_current() {
+    let s = Setup::new();
+    assert_eq!(s.escrow.get_schema_version(), 5);
+    assert_eq!(s.escrow.migrate(&0, &20), None);
+    assert_eq!(s.migrate_events(), 0);
+}
+
+#[test]
+fn test_migrate_v1_escrows() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &deadline);
+    s.store_v1_escrow(2, 2_000);
+
+    assert_eq!(s.escrow.get_schema_version(), 1);
+    assert!(s.escrow.try_get_escrow_info(&2).is_err());
+
+    assert_eq!(s.escrow.migrate(&0, &20), None);
+    assert_eq!(s.escrow.get_schema_version(), 5);
+    assert_eq!(s.migrate_events(), 1);
+
+    let migrated = s.escrow.get_escrow_info(&2);
+    assert_eq!(migrated.amount, 2_000);
+    assert_eq!(migrated.remaining_amount, 2_000);
+    assert_eq!(migrated.status, EscrowStatus::Locked);
+    assert_eq!(migrated.metadata_hash, None);
+    assert_eq!(migrated.label, None);
+    assert_eq!(s.escrow.get_escrow_info(&1).amount, 1_000);
+
+    // Migrated escrows behave like any other.
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 2_000);
+
+    // Running again is a no-op.
+    assert_eq!(s.escrow.migrate(&0, &20), None);
+    assert_eq!(s.migrate_events(), 1);
+}
+
+#[test]
+fn test_migrate_in_pages() {
+    let s = Setup::new();
+    for id in 1..=12 {
+        s.store_v1_escrow(id, 100 * id as i128);
+    }
+
+    // Every converter walks all 12 escrows, 5 at a time.
+    assert_eq!(s.migrate_page(0, 5), Some(5));
+    assert_eq!(s.escrow.get_schema_version(), 1);
+    assert_eq!(
+        s.escrow.try_migrate(&0, &5),
+        Err(Err(SchemaMigrationError::MigrationCursorMismatch.into()))
+    );
+    assert_eq!(s.migrate_page(5, 5), Some(10));
+    // The last page of one converter runs into the first of the next.
+    assert_eq!(s.migrate_page(10, 5), Some(3));
+    assert_eq!(s.escrow.get_schema_version(), 2);
+    assert_eq!(s.migrate_events(), 1);
+
+    let mut cursor = 3;
+    let mut calls = 3;
+    while let Some(next) = s.migrate_page(cursor, 5) {
+        cursor = next;
+        calls += 1;
+    }
+    assert_eq!(calls + 1, 10);
+    assert_eq!(s.escrow.get_schema_version(), 5);
+
+    s.env.budget().reset_default();
+    for id in 1..=12 {
+        let escrow = s.escrow.get_escrow_info(&id);
+        assert_eq!(escrow.amount, 100 * id as i128);
+        assert_eq!(escrow.status, EscrowStatus::Locked);
+    }
+    let locked = s
+        .escrow
+        .list_escrows_by_status(&EscrowStatus::Locked, &0, &20);
+    assert_eq!(locked.len(), 12);
+}
+
+#[test]
+fn test_migrate_requires_init() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let escrow =
+        BountyEscrowContractClient::new(&env, &env.register_contract(None, BountyEscrowContract));
+    assert_eq!(escrow.try_migrate(&0, &20), Err(Ok(Error::NotInitialized)));
+}
@@ -0,0 +1,113 @@
+#![cfg(test)]
+
+use crate::storage_policy::TtlPolicyError;
+use crate::{test_setup::TestSetup, Error, TtlPolicy};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Ledger, LedgerInfo},
+    vec, Env,
+};
+
+const DAY: u32 = 17_280;
+const START: u32 = 100;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.ledger().set(LedgerInfo {
+            timestamp: 1_000,
+            protocol_version: 20,
+            sequence_number: START,
+            network_id: Default::default(),
+            base_reserve: 10,
+            min_temp_entry_ttl: 1_000,
+            min_persistent_entry_ttl: 200 * DAY,
+            max_entry_ttl: 400 * DAY,
+        });
+        Self {
+            base: TestSetup::with_env(env),
+        }
+    }
+
+    /// Lock `bounty_id` a minute after the previous lock, clear of the
+    /// depositor's anti-abuse cooldown.
+    fn lock(&self, bounty_id: u64) {
+        self.advance_time(60);
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &1_000, &1_000_000_000);
+    }
+
+    fn live_until(&self, bounty_id: u64) -> u32 {
+        self.escrow.get_escrow_ttl(&bounty_id).live_until_ledger
+    }
+}
+
+#[test]
+fn test_policy_sets_extension_length() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.get_ttl_policy(),
+        TtlPolicy {
+            threshold: 30 * DAY,
+            extend_to: 180 * DAY,
+        }
+    );
+    s.lock(1);
+    assert_eq!(s.live_until(1), START + 180 * DAY);
+
+    let policy = TtlPolicy {
+        threshold: 10 * DAY,
+        extend_to: 60 * DAY,
+    };
+    s.escrow.set_ttl_policy(&Some(policy.clone()));
+    assert_eq!(s.escrow.get_ttl_policy(), policy);
+    s.lock(2);
+    assert_eq!(s.live_until(2), START + 60 * DAY);
+
+    s.escrow.set_ttl_policy(&None);
+    assert_eq!(s.escrow.get_ttl_policy().extend_to, 180 * DAY);
+}
+
+#[test]
+fn test_invalid_policy_is_rejected() {
+    let s = Setup::new();
+    for (threshold, extend_to) in [(60 * DAY, 60 * DAY), (DAY, 500 * DAY)] {
+        assert_eq!(
+            s.escrow.try_set_ttl_policy(&Some(TtlPolicy {
+                threshold,
+                extend_to,
+            })),
+            Err(Err(TtlPolicyError::InvalidTtlPolicy.into()))
+        );
+    }
+}
+
+#[test]
+fn test_bump_all_extends_each_escrow() {
+    let s = Setup::new();
+    s.lock(1);
+    s.lock(2);
+
+    let now = START + 100 * DAY;
+    s.env.ledger().with_mut(|li| li.sequence_number = now);
+    assert_eq!(s.escrow.bump_all(&vec![&s.env, 1, 2, 3]), 2);
+    assert_eq!(s.live_until(1), now + 180 * DAY);
+    assert_eq!(s.live_until(2), now + 180 * DAY);
+
+    assert_eq!(
+        s.escrow.try_bump_all(&vec![&s.env]),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
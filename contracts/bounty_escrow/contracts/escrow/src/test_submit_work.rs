@@ -0,0 +1,82 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, Error};
+use core::ops::Deref;
+use soroban_sdk::BytesN;
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let deadline = base.env.ledger().timestamp() + 30 * 86_400;
+        base.escrow
+            .lock_funds(&base.depositor, &1, &1_000, &deadline);
+        base.escrow
+            .assign_contributor(&1, &base.contributor, &86_400);
+        Self { base }
+    }
+
+    fn hash(&self, byte: u8) -> BytesN<32> {
+        BytesN::from_array(&self.env, &[byte; 32])
+    }
+}
+
+#[test]
+fn test_assignee_records_submission() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_submit_work(&1, &s.hash(1)),
+        Err(Ok(Error::AssignmentPending))
+    );
+
+    s.escrow.accept_assignment(&1);
+    s.escrow.submit_work(&1, &s.hash(1));
+    assert_eq!(s.env.auths()[0].0, s.contributor);
+
+    s.escrow.submit_work(&1, &s.hash(2));
+    let submission = s.escrow.get_submission(&1).unwrap();
+    assert_eq!(submission.contributor, s.contributor);
+    assert_eq!(submission.submission_hash, s.hash(2));
+    assert_eq!(submission.submitted_at, s.env.ledger().timestamp());
+
+    assert_eq!(
+        s.escrow.try_submit_work(&2, &s.hash(1)),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_release_can_require_submission() {
+    let s = Setup::new();
+    s.escrow.accept_assignment(&1);
+    s.escrow.set_submission_required(&1, &true);
+    assert!(s.escrow.is_submission_required(&1));
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::SubmissionRequired))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::SubmissionRequired))
+    );
+
+    s.escrow.submit_work(&1, &s.hash(7));
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(
+        s.escrow.try_submit_work(&1, &s.hash(8)),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
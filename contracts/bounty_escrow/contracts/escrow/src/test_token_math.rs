@@ -138,7 +138,7 @@ fn split_invariant_prime_amount() {
 
 #[test]
 fn split_invariant_large_amount() {
-    let amount = 1_000_000_000_0000000_i128; // 1 billion XLM in stroops
+    let amount = 10_000_000_000_000_000_i128; // 1 billion XLM in stroops
     let (fee, net) = token_math::split_amount(amount, 250);
     assert_eq!(fee + net, amount);
 }
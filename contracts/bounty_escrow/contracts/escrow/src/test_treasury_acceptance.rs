@@ -0,0 +1,76 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, TreasuryError};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    treasury: Address,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let base = TestSetup::new();
+        let treasury = Address::generate(&base.env);
+        base.escrow
+            .update_fee_config(&None, &Some(1_000), &None, &Some(true));
+        Self { base, treasury }
+    }
+
+    fn release(&self, bounty_id: u64) {
+        let contributor = Address::generate(&self.env);
+        self.lock(bounty_id, 1_000);
+        self.escrow.release_funds(&bounty_id, &contributor);
+    }
+}
+
+#[test]
+fn test_fees_route_to_treasury_only_after_acceptance() {
+    let s = Setup::new();
+    s.escrow.set_treasury_address(&s.treasury);
+    assert_eq!(s.escrow.get_pending_treasury(), Some(s.treasury.clone()));
+    assert_eq!(s.escrow.get_fee_config().fee_recipient, s.admin);
+
+    s.release(1);
+    s.escrow.withdraw_fees(&100);
+    assert_eq!(s.token.balance(&s.admin), 100);
+    assert_eq!(s.token.balance(&s.treasury), 0);
+
+    s.escrow.accept_treasury_role();
+    assert_eq!(s.env.auths()[0].0, s.treasury);
+    assert_eq!(s.escrow.get_pending_treasury(), None);
+    assert_eq!(s.escrow.get_fee_config().fee_recipient, s.treasury);
+
+    s.release(2);
+    s.escrow.withdraw_fees(&100);
+    assert_eq!(s.token.balance(&s.treasury), 100);
+}
+
+#[test]
+fn test_proposal_can_be_withdrawn() {
+    let s = Setup::new();
+    assert_eq!(
+        s.escrow.try_accept_treasury_role(),
+        Err(Err(TreasuryError::TreasuryNotProposed.into()))
+    );
+
+    s.escrow
+        .update_fee_config(&None, &None, &Some(s.treasury.clone()), &None);
+    // Re-proposing the current recipient cancels the pending hand-over.
+    s.escrow.set_treasury_address(&s.admin);
+    assert_eq!(s.escrow.get_pending_treasury(), None);
+    assert_eq!(
+        s.escrow.try_accept_treasury_role(),
+        Err(Err(TreasuryError::TreasuryNotProposed.into()))
+    );
+    assert_eq!(s.escrow.get_fee_config().fee_recipient, s.admin);
+}
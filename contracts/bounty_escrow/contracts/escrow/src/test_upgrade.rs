@@ -0,0 +1,35 @@
+#![cfg(test)]
+
+use crate::{test_setup::TestSetup, BountyEscrowContract, BountyEscrowContractClient, Error};
+use soroban_sdk::{BytesN, Env};
+
+#[test]
+fn test_versions_after_init() {
+    let s = TestSetup::new();
+
+    assert_eq!(s.escrow.get_version(), 1);
+    assert_eq!(s.escrow.get_schema_version(), 5);
+}
+
+#[test]
+fn test_upgrade_requires_init() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let escrow =
+        BountyEscrowContractClient::new(&env, &env.register_contract(None, BountyEscrowContract));
+    let hash = BytesN::from_array(&env, &[7; 32]);
+
+    assert_eq!(escrow.try_upgrade(&hash), Err(Ok(Error::NotInitialized)));
+}
+
+#[test]
+fn test_direct_upgrade_blocked_while_timelocked() {
+    let s = TestSetup::new();
+    s.escrow.set_timelock_delay(&86_400);
+    let hash = BytesN::from_array(&s.env, &[7; 32]);
+
+    assert_eq!(
+        s.escrow.try_upgrade(&hash),
+        Err(Ok(Error::TimelockRequired))
+    );
+}
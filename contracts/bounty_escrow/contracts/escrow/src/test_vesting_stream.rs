@@ -0,0 +1,105 @@
+#![cfg(test)]
+
+use crate::{
+    test_setup::TestSetup, CancellationError, Error, EscrowStatus, RefundMode, StreamError,
+};
+use soroban_sdk::{testutils::Ledger, Env};
+
+const DURATION: u64 = 1_000;
+
+fn setup<'a>() -> TestSetup<'a> {
+    let env = Env::default();
+    env.ledger().set_timestamp(10_000);
+    let s = TestSetup::with_env(env);
+    s.escrow.lock_funds(&s.depositor, &1, &1_000, &100_000);
+    s
+}
+
+#[test]
+fn test_stream_vests_linearly() {
+    let s = setup();
+    s.escrow.release_stream(&1, &s.contributor, &DURATION);
+    let stream = s.escrow.get_vesting_stream(&1).unwrap();
+    assert_eq!(stream.total, 1_000);
+    assert_eq!(s.escrow.get_withdrawable(&1), 0);
+    assert_eq!(
+        s.escrow.try_withdraw_vested(&1),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    s.advance_time(250);
+    assert_eq!(s.escrow.withdraw_vested(&1), 250);
+    assert_eq!(s.token.balance(&s.contributor), 250);
+    let info = s.escrow.get_escrow_info(&1);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert_eq!(info.remaining_amount, 750);
+
+    s.advance_time(500);
+    assert_eq!(s.escrow.get_withdrawable(&1), 500);
+    assert_eq!(s.escrow.withdraw_vested(&1), 500);
+
+    // Past the end everything left is vested and the escrow settles.
+    s.advance_time(DURATION);
+    assert_eq!(s.escrow.withdraw_vested(&1), 250);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.escrow.get_escrow_info(&1).status, EscrowStatus::Released);
+    assert_eq!(s.escrow.get_vesting_stream(&1), None);
+    assert_eq!(
+        s.escrow.try_withdraw_vested(&1),
+        Err(Err(StreamError::StreamNotFound.into()))
+    );
+}
+
+#[test]
+fn test_stream_blocks_other_payouts() {
+    let s = setup();
+    s.escrow.release_stream(&1, &s.contributor, &DURATION);
+
+    assert_eq!(
+        s.escrow.try_release_funds(&1, &s.contributor),
+        Err(Ok(Error::StreamActive))
+    );
+    assert_eq!(
+        s.escrow.try_partial_release(&1, &s.contributor, &100),
+        Err(Ok(Error::StreamActive))
+    );
+    assert_eq!(
+        s.escrow.try_release_stream(&1, &s.contributor, &DURATION),
+        Err(Ok(Error::StreamActive))
+    );
+    assert_eq!(
+        s.escrow.try_increase_escrow(&1, &100),
+        Err(Ok(Error::StreamActive))
+    );
+    assert_eq!(
+        s.escrow.try_cancel_escrow(&1),
+        Err(Err(CancellationError::CancellationNotAllowed.into()))
+    );
+
+    s.escrow
+        .approve_refund(&1, &500, &s.depositor, &RefundMode::Partial);
+    assert_eq!(s.escrow.try_refund(&1), Err(Ok(Error::StreamActive)));
+}
+
+#[test]
+fn test_stream_validation() {
+    let s = setup();
+    assert_eq!(
+        s.escrow.try_release_stream(&1, &s.contributor, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        s.escrow.try_release_stream(&2, &s.contributor, &DURATION),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert_eq!(
+        s.escrow.try_get_withdrawable(&1),
+        Err(Ok(StreamError::StreamNotFound))
+    );
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(
+        s.escrow.try_release_stream(&1, &s.contributor, &DURATION),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use crate::yield_strategy::YieldError;
+use crate::{test_setup::TestSetup, YieldBeneficiary};
+use core::ops::Deref;
+use soroban_sdk::{contract, contractimpl, contracttype, testutils::Ledger, token, Address, Env};
+
+#[contracttype]
+enum PoolKey {
+    Token,
+    Balance(Address),
+    WithdrawalFee,
+}
+
+/// Lending pool that pays whatever interest the test credits with `accrue`.
+#[contract]
+pub struct MockPool;
+
+#[contractimpl]
+impl MockPool {
+    pub fn init(env: Env, token: Address) {
+        env.storage().instance().set(&PoolKey::Token, &token);
+    }
+
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = env.storage().instance().get(&PoolKey::Token).unwrap();
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        Self::credit(&env, &from, amount);
+    }
+
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        to.require_auth();
+        Self::credit(&env, &to, -amount);
+        let fee: i128 = env
+            .storage()
+            .instance()
+            .get(&PoolKey::WithdrawalFee)
+            .unwrap_or(0);
+        let token: Address = env.storage().instance().get(&PoolKey::Token).unwrap();
+        token::Client::new(&env, &token).transfer(
+            &env.current_contract_address(),
+            &to,
+            &(amount - fee),
+        );
+    }
+
+    pub fn balance(env: Env, owner: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&PoolKey::Balance(owner))
+            .unwrap_or(0)
+    }
+
+    /// Credit `amount` of interest to `owner`; the tokens must already have
+    /// been sent to the pool.
+    pub fn accrue(env: Env, owner: Address, amount: i128) {
+        Self::credit(&env, &owner, amount);
+    }
+
+    /// Keep `fee` out of every withdrawal.
+    pub fn set_withdrawal_fee(env: Env, fee: i128) {
+        env.storage().instance().set(&PoolKey::WithdrawalFee, &fee);
+    }
+
+    fn credit(env: &Env, owner: &Address, amount: i128) {
+        let key = PoolKey::Balance(owner.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+    }
+}
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    pool: MockPoolClient<'a>,
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.base
+    }
+}
+
+impl<'a> Setup<'a> {
+    fn new(beneficiary: YieldBeneficiary) -> Self {
+        let base = TestSetup::new();
+        let pool = MockPoolClient::new(&base.env, &base.env.register_contract(None, MockPool));
+        pool.init(&base.token.address);
+        base.escrow
+            .set_yield_strategy(&Some(pool.address.clone()), &beneficiary);
+        Self { base, pool }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) {
+        self.lock_for(bounty_id, amount, None);
+    }
+
+    fn lock_for(&self, bounty_id: u64, amount: i128, beneficiary: Option<YieldBeneficiary>) {
+        self.escrow.lock_funds_with_yield(
+            &self.depositor,
+            &bounty_id,
+            &amount,
+            &self.deadline(),
+            &beneficiary,
+        );
+    }
+
+    /// Pay `amount` of interest on the escrow's pool position.
+    fn accrue(&self, amount: i128) {
+        self.token_admin.mint(&self.pool.address, &amount);
+        self.pool.accrue(&self.escrow.address, &amount);
+    }
+}
+
+#[test]
+fn test_locked_funds_are_invested_without_tripping_invariant() {
+    let s = Setup::new(YieldBeneficiary::Treasury);
+    s.lock(1, 1_000);
+
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(s.pool.balance(&s.escrow.address), 1_000);
+    let position = s.escrow.get_yield_position(&1).unwrap();
+    assert_eq!(position.principal, 1_000);
+    assert_eq!(position.pool, s.pool.address);
+
+    // Invested principal still counts as held: nothing is rescuable and a
+    // later operation does not see a shortfall.
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 0);
+    s.lock(2, 500);
+    assert!(!s.escrow.is_paused());
+}
+
+#[test]
+fn test_release_withdraws_and_pays_interest_to_treasury() {
+    let s = Setup::new(YieldBeneficiary::Treasury);
+    s.lock(1, 1_000);
+    s.accrue(50);
+
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    assert_eq!(s.token.balance(&s.contributor), 400);
+    assert_eq!(s.token.balance(&s.admin), 50);
+    assert_eq!(s.escrow.get_yield_position(&1), None);
+    assert_eq!(s.pool.balance(&s.escrow.address), 0);
+
+    // The rest stays in the contract and pays out normally.
+    s.escrow.partial_release(&1, &s.contributor, &600);
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert!(!s.escrow.is_paused());
+}
+
+#[test]
+fn test_interest_is_what_the_pool_actually_returned() {
+    let s = Setup::new(YieldBeneficiary::Treasury);
+    s.lock(1, 1_000);
+    s.accrue(50);
+    s.pool.set_withdrawal_fee(&30);
+
+    // The pool reports 1_050 but only sends 1_020: the treasury gets the
+    // 20 that actually came back on top of the principal.
+    s.escrow.partial_release(&1, &s.contributor, &400);
+    assert_eq!(s.token.balance(&s.admin), 20);
+    assert_eq!(s.token.balance(&s.escrow.address), 600);
+    assert!(!s.escrow.is_paused());
+}
+
+#[test]
+fn test_refund_pays_interest_to_depositor() {
+    let s = Setup::new(YieldBeneficiary::Depositor);
+    s.lock(1, 1_000);
+    s.lock(2, 3_000);
+    s.accrue(400);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    s.escrow.refund(&1);
+    // A quarter of the pool position belongs to escrow 1.
+    assert_eq!(s.token.balance(&s.depositor), 10_000 - 3_000 + 100);
+
+    s.escrow.refund(&2);
+    assert_eq!(s.token.balance(&s.depositor), 10_000 + 400);
+}
+
+#[test]
+fn test_yield_lock_requires_configured_pool() {
+    let s = Setup::new(YieldBeneficiary::Treasury);
+    s.escrow
+        .set_yield_strategy(&None, &YieldBeneficiary::Treasury);
+    let deadline = s.env.ledger().timestamp() + 1_000;
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_with_yield(&s.depositor, &1, &1_000, &deadline, &None),
+        Err(Err(YieldError::PoolNotSet.into()))
+    );
+}
+
+#[test]
+fn test_depositor_chooses_beneficiary_at_lock_time() {
+    let s = Setup::new(YieldBeneficiary::Treasury);
+    s.lock_for(1, 1_000, Some(YieldBeneficiary::Contributor));
+    s.lock(2, 1_000);
+    assert_eq!(
+        s.escrow.get_yield_position(&1).unwrap().beneficiary,
+        YieldBeneficiary::Contributor
+    );
+    assert_eq!(
+        s.escrow.get_yield_position(&2).unwrap().beneficiary,
+        YieldBeneficiary::Treasury
+    );
+    s.accrue(200);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 1_000 + 100);
+    s.escrow.release_funds(&2, &s.contributor);
+    assert_eq!(s.token.balance(&s.contributor), 2_000 + 100);
+    assert_eq!(s.token.balance(&s.admin), 100);
+    assert_eq!(s.escrow.get_untracked_balance(&s.token.address), 0);
+    assert!(!s.escrow.is_paused());
+}
+
+#[test]
+fn test_contributor_interest_goes_to_depositor_on_refund() {
+    let s = Setup::new(YieldBeneficiary::Treasury);
+    s.lock_for(1, 1_000, Some(YieldBeneficiary::Contributor));
+    s.accrue(80);
+
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 1_001);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), 10_000 + 80);
+    assert_eq!(s.token.balance(&s.escrow.address), 0);
+}
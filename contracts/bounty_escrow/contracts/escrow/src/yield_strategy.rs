@@ -0,0 +1,237 @@
+//! Optional yield strategy for idle escrow funds.
+//!
+//! The admin whitelists a lending pool with `set_yield_strategy`. Escrows
+//! locked through `lock_funds_with_yield` have their funds deposited into the
+//! pool, and the whole position is withdrawn again the first time the
+//! escrow's remaining amount goes down (release, refund, cancel, ...), so
+//! every payout path sees the funds back in the contract. Interest earned on
+//! top of the principal is sent to the escrow's `YieldBeneficiary`, chosen at
+//! lock time. Interest owed to the contributor is held until the release
+//! that triggered the withdrawal pays them.
+//!
+//! Escrows share one pool position per pool and own it through shares, so
+//! interest is split pro rata to amount and time invested. Invested principal
+//! is excluded from the balance the contract must hold itself (see
+//! `tracked_balance`); a pool that returns less than the principal leaves a
+//! shortfall that trips the balance circuit breaker.
+//!
+//! Pools are reached through an adapter implementing `LendingPool`, e.g. a
+//! thin wrapper around a Blend pool's `submit`.
+
+use crate::escrow_entries::{self, Entry};
+use crate::{YieldBeneficiary, YieldPosition};
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contractclient, contracterror, contracttype, token, vec, Address, Env, IntoVal, Symbol,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum YieldError {
+    /// Returned when investing while no lending pool is configured
+    PoolNotSet = 88,
+}
+
+impl YieldError {
+    pub fn description(&self) -> &'static str {
+        match self {
+            YieldError::PoolNotSet => "no lending pool is configured",
+        }
+    }
+}
+
+/// Interface a lending pool adapter must implement. Amounts are in the
+/// escrow token.
+#[allow(dead_code)]
+#[contractclient(name = "LendingPoolClient")]
+pub trait LendingPool {
+    /// Pull `amount` from `from` (which authorizes the token transfer) and
+    /// credit it to `from`'s position.
+    fn deposit(env: Env, from: Address, amount: i128);
+    /// Send `amount` of `to`'s position, including interest, back to `to`.
+    fn withdraw(env: Env, to: Address, amount: i128);
+    /// Current value of `owner`'s position, including interest.
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum YieldKey {
+    /// Address of the whitelisted pool new deposits go to
+    Pool,
+    /// YieldBeneficiary used when the depositor does not choose one
+    Beneficiary,
+    /// pool -> i128 shares issued against the contract's position
+    TotalShares(Address),
+    /// i128 principal currently deposited across all pools
+    TotalPrincipal,
+    /// bounty_id -> YieldPosition
+    Position(u64),
+    /// bounty_id -> (principal, withdrawn) awaiting the contributor's payout
+    HeldInterest(u64),
+    /// i128 interest held in the contract for contributors
+    TotalHeldInterest,
+}
+
+pub fn pool(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&YieldKey::Pool)
+}
+
+pub fn beneficiary(env: &Env) -> YieldBeneficiary {
+    env.storage()
+        .instance()
+        .get(&YieldKey::Beneficiary)
+        .unwrap_or(YieldBeneficiary::Treasury)
+}
+
+pub fn configure(env: &Env, pool: Option<Address>, beneficiary: YieldBeneficiary) {
+    match pool {
+        Some(address) => env.storage().instance().set(&YieldKey::Pool, &address),
+        None => env.storage().instance().remove(&YieldKey::Pool),
+    }
+    env.storage()
+        .instance()
+        .set(&YieldKey::Beneficiary, &beneficiary);
+}
+
+pub fn position(env: &Env, bounty_id: u64) -> Option<YieldPosition> {
+    escrow_entries::get(env, bounty_id, Entry::YieldPosition)
+}
+
+/// Principal held by pools rather than by the contract itself.
+pub fn invested_principal(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::TotalPrincipal)
+        .unwrap_or(0)
+}
+
+fn total_shares(env: &Env, pool: &Address) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::TotalShares(pool.clone()))
+        .unwrap_or(0)
+}
+
+fn add_totals(env: &Env, pool: &Address, shares: i128, principal: i128) {
+    env.storage().instance().set(
+        &YieldKey::TotalShares(pool.clone()),
+        &(total_shares(env, pool) + shares),
+    );
+    env.storage().instance().set(
+        &YieldKey::TotalPrincipal,
+        &(invested_principal(env) + principal),
+    );
+}
+
+/// Interest held in the contract until contributors are paid.
+pub fn held_interest(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&YieldKey::TotalHeldInterest)
+        .unwrap_or(0)
+}
+
+/// Keep the interest of a settled position for the contributor the current
+/// release pays.
+pub fn hold_interest(env: &Env, bounty_id: u64, principal: i128, withdrawn: i128) {
+    escrow_entries::set(env, bounty_id, Entry::HeldInterest, &(principal, withdrawn));
+    env.storage().instance().set(
+        &YieldKey::TotalHeldInterest,
+        &(held_interest(env) + withdrawn - principal),
+    );
+}
+
+/// Remove and return the `(principal, withdrawn)` held for `bounty_id`.
+pub fn take_held_interest(env: &Env, bounty_id: u64) -> Option<(i128, i128)> {
+    let (principal, withdrawn): (i128, i128) =
+        escrow_entries::get(env, bounty_id, Entry::HeldInterest)?;
+    escrow_entries::remove(env, bounty_id, Entry::HeldInterest);
+    env.storage().instance().set(
+        &YieldKey::TotalHeldInterest,
+        &(held_interest(env) - (withdrawn - principal)),
+    );
+    Some((principal, withdrawn))
+}
+
+/// Deposit `amount` of `bounty_id`'s funds into `pool`, with interest going
+/// to `beneficiary`.
+pub fn invest(
+    env: &Env,
+    bounty_id: u64,
+    pool: &Address,
+    token: &Address,
+    amount: i128,
+    beneficiary: YieldBeneficiary,
+) {
+    let contract = env.current_contract_address();
+    let client = LendingPoolClient::new(env, pool);
+    let value = client.balance(&contract);
+    let total = total_shares(env, pool);
+    let shares = if total == 0 || value <= 0 {
+        amount
+    } else {
+        amount * total / value
+    };
+
+    // EFFECTS: record the position before the external calls (CEI)
+    escrow_entries::set(
+        env,
+        bounty_id,
+        Entry::YieldPosition,
+        &YieldPosition {
+            pool: pool.clone(),
+            shares,
+            principal: amount,
+            beneficiary,
+        },
+    );
+    add_totals(env, pool, shares, amount);
+
+    // INTERACTION: the pool pulls the tokens from the contract
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: token.clone(),
+                fn_name: Symbol::new(env, "transfer"),
+                args: (contract.clone(), pool.clone(), amount).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    client.deposit(&contract, &amount);
+}
+
+/// Withdraw `bounty_id`'s whole position back into the contract. Returns
+/// the position and the amount of `token` actually received, which can be
+/// less than the pool reported, or `None` if nothing is invested.
+pub fn divest(env: &Env, bounty_id: u64, token: &Address) -> Option<(YieldPosition, i128)> {
+    if invested_principal(env) == 0 {
+        return None;
+    }
+    let position = position(env, bounty_id)?;
+    let contract = env.current_contract_address();
+    let client = LendingPoolClient::new(env, &position.pool);
+    let total = total_shares(env, &position.pool);
+    let value = if total > 0 {
+        position.shares * client.balance(&contract) / total
+    } else {
+        0
+    };
+
+    // EFFECTS: drop the position before the external call (CEI)
+    escrow_entries::remove(env, bounty_id, Entry::YieldPosition);
+    add_totals(env, &position.pool, -position.shares, -position.principal);
+
+    // INTERACTION: the pool sends the funds back to the contract
+    if value <= 0 {
+        return Some((position, 0));
+    }
+    let token_client = token::Client::new(env, token);
+    let before = token_client.balance(&contract);
+    client.withdraw(&contract, &value);
+    let received = token_client.balance(&contract) - before;
+    Some((position, received))
+}
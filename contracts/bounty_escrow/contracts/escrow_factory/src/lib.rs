@@ -0,0 +1,232 @@
+//! # Escrow Factory
+//!
+//! Deploys one `BountyEscrowContract` instance per organization so each org
+//! gets isolated accounting (balances, fees, pause state, admin) instead of
+//! sharing one global escrow.
+//!
+//! The factory admin uploads the escrow WASM once and sets an
+//! `EscrowPreset` (token and fee schedule). `deploy_instance` then deploys
+//! a fresh instance for an organization, initializes it with the org's
+//! admin and the preset token, and applies the preset fees, all in one
+//! invocation. Deployed instances are tracked by org name and listed with
+//! `list_instances`.
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, Address,
+    BytesN, Env, Symbol, Vec,
+};
+
+/// Subset of the escrow interface the factory calls into.
+#[contractclient(name = "EscrowClient")]
+pub trait EscrowContract {
+    fn init(env: Env, admin: Address, token: Address);
+    fn update_fee_config(
+        env: Env,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        fee_recipient: Option<Address>,
+        fee_enabled: Option<bool>,
+    );
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// The organization already has an instance
+    InstanceExists = 3,
+    InstanceNotFound = 4,
+    /// Returned when a preset fee rate is negative or above the escrow's cap
+    InvalidFeeRate = 5,
+}
+
+/// Maximum fee rate the escrow accepts, in basis points.
+const MAX_FEE_RATE: i128 = 5_000;
+
+/// Configuration applied to every newly deployed instance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowPreset {
+    pub token: Address,
+    /// Lock fee in basis points; fees are enabled if either rate is non-zero.
+    pub lock_fee_rate: i128,
+    /// Release fee in basis points.
+    pub release_fee_rate: i128,
+    /// Treasury proposed on the new instance; fees go to the org admin
+    /// until it calls `accept_treasury_role` there.
+    pub fee_recipient: Option<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInstance {
+    pub org: Symbol,
+    pub address: Address,
+    pub admin: Address,
+    pub deployed_at: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// BytesN<32> hash of the uploaded escrow WASM
+    WasmHash,
+    /// EscrowPreset applied to new instances
+    Preset,
+    /// org -> EscrowInstance
+    Instance(Symbol),
+    /// Vec<Symbol> of orgs in deployment order
+    InstanceIndex,
+}
+
+#[contract]
+pub struct EscrowFactoryContract;
+
+#[contractimpl]
+impl EscrowFactoryContract {
+    /// Initialize with an admin, the escrow WASM to deploy and the preset
+    /// new instances get.
+    pub fn init(
+        env: Env,
+        admin: Address,
+        wasm_hash: BytesN<32>,
+        preset: EscrowPreset,
+    ) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        Self::validate_preset(&preset)?;
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+        env.storage().instance().set(&DataKey::Preset, &preset);
+        Ok(())
+    }
+
+    /// Deploy new instances from `wasm_hash` (admin only). Existing
+    /// instances keep their code; each upgrades itself.
+    pub fn set_wasm_hash(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        env.storage().instance().set(&DataKey::WasmHash, &wasm_hash);
+        Ok(())
+    }
+
+    /// Replace the preset for instances deployed from now on (admin only).
+    pub fn set_preset(env: Env, preset: EscrowPreset) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        Self::validate_preset(&preset)?;
+        env.storage().instance().set(&DataKey::Preset, &preset);
+        Ok(())
+    }
+
+    /// Deploy an escrow instance for `org`, administered by `org_admin`,
+    /// and return its address. Requires the org admin's authorization,
+    /// which also covers applying the preset fees on the new instance.
+    pub fn deploy_instance(env: Env, org: Symbol, org_admin: Address) -> Result<Address, Error> {
+        let wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::WasmHash)
+            .ok_or(Error::NotInitialized)?;
+        let preset: EscrowPreset = env.storage().instance().get(&DataKey::Preset).unwrap();
+        org_admin.require_auth();
+
+        let key = DataKey::Instance(org.clone());
+        if env.storage().persistent().has(&key) {
+            return Err(Error::InstanceExists);
+        }
+        let mut index: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::InstanceIndex)
+            .unwrap_or(Vec::new(&env));
+
+        // One salt per deployment keeps instance addresses deterministic.
+        let mut salt = [0u8; 32];
+        salt[28..].copy_from_slice(&index.len().to_be_bytes());
+        let address = env
+            .deployer()
+            .with_current_contract(BytesN::from_array(&env, &salt))
+            .deploy(wasm_hash);
+
+        let escrow = EscrowClient::new(&env, &address);
+        escrow.init(&org_admin, &preset.token);
+        if preset.lock_fee_rate > 0 || preset.release_fee_rate > 0 {
+            escrow.update_fee_config(
+                &Some(preset.lock_fee_rate),
+                &Some(preset.release_fee_rate),
+                &preset.fee_recipient,
+                &Some(true),
+            );
+        }
+
+        let instance = EscrowInstance {
+            org: org.clone(),
+            address: address.clone(),
+            admin: org_admin,
+            deployed_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&key, &instance);
+        index.push_back(org.clone());
+        env.storage()
+            .persistent()
+            .set(&DataKey::InstanceIndex, &index);
+
+        env.events()
+            .publish((symbol_short!("inst_new"), org), instance);
+        Ok(address)
+    }
+
+    /// View: the instance deployed for `org`.
+    pub fn get_instance(env: Env, org: Symbol) -> Result<EscrowInstance, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Instance(org))
+            .ok_or(Error::InstanceNotFound)
+    }
+
+    /// View: all deployed instances in deployment order.
+    pub fn list_instances(env: Env) -> Vec<EscrowInstance> {
+        let index: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::InstanceIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut result = Vec::new(&env);
+        for org in index.iter() {
+            if let Some(instance) = env.storage().persistent().get(&DataKey::Instance(org)) {
+                result.push_back(instance);
+            }
+        }
+        result
+    }
+
+    /// View: the preset new instances get.
+    pub fn get_preset(env: Env) -> Option<EscrowPreset> {
+        env.storage().instance().get(&DataKey::Preset)
+    }
+
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn validate_preset(preset: &EscrowPreset) -> Result<(), Error> {
+        for rate in [preset.lock_fee_rate, preset.release_fee_rate] {
+            if !(0..=MAX_FEE_RATE).contains(&rate) {
+                return Err(Error::InvalidFeeRate);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,108 @@
+#![cfg(test)]
+
+use crate::{Error, EscrowFactoryContract, EscrowFactoryContractClient, EscrowPreset};
+use soroban_sdk::{symbol_short, testutils::Address as _, token, Address, Env};
+
+mod escrow {
+    soroban_sdk::contractimport!(file = "../../target/wasm32v1-none/release/bounty_escrow.wasm");
+}
+
+struct Setup<'a> {
+    env: Env,
+    token: token::Client<'a>,
+    factory: EscrowFactoryContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_address);
+
+        let wasm_hash = env.deployer().upload_contract_wasm(escrow::WASM);
+        let factory_id = env.register_contract(None, EscrowFactoryContract);
+        let factory = EscrowFactoryContractClient::new(&env, &factory_id);
+        factory.init(
+            &admin,
+            &wasm_hash,
+            &EscrowPreset {
+                token: token_address,
+                lock_fee_rate: 0,
+                release_fee_rate: 250,
+                fee_recipient: None,
+            },
+        );
+
+        Self {
+            env,
+            token,
+            factory,
+        }
+    }
+}
+
+#[test]
+fn test_each_org_gets_an_isolated_instance() {
+    let s = Setup::new();
+    let acme_admin = Address::generate(&s.env);
+    let globex_admin = Address::generate(&s.env);
+    let acme = s
+        .factory
+        .deploy_instance(&symbol_short!("acme"), &acme_admin);
+    let globex = s
+        .factory
+        .deploy_instance(&symbol_short!("globex"), &globex_admin);
+    assert_ne!(acme, globex);
+
+    let instances = s.factory.list_instances();
+    assert_eq!(instances.len(), 2);
+    assert_eq!(instances.get(0).unwrap().address, acme);
+    assert_eq!(instances.get(1).unwrap().admin, globex_admin);
+    assert_eq!(
+        s.factory.get_instance(&symbol_short!("globex")).address,
+        globex
+    );
+
+    // Preset token and fees are applied, and balances are kept apart.
+    let acme_escrow = escrow::Client::new(&s.env, &acme);
+    let fees = acme_escrow.get_fee_config();
+    assert_eq!(fees.release_fee_rate, 250);
+    assert!(fees.fee_enabled);
+    let depositor = Address::generate(&s.env);
+    token::StellarAssetClient::new(&s.env, &s.token.address).mint(&depositor, &1_000);
+    acme_escrow.lock_funds(&depositor, &1, &1_000, &1_000);
+    assert_eq!(s.token.balance(&acme), 1_000);
+    assert_eq!(s.token.balance(&globex), 0);
+    assert!(escrow::Client::new(&s.env, &globex)
+        .try_get_escrow_info(&1)
+        .is_err());
+}
+
+#[test]
+fn test_deploy_validation() {
+    let s = Setup::new();
+    let org_admin = Address::generate(&s.env);
+    s.factory
+        .deploy_instance(&symbol_short!("acme"), &org_admin);
+    assert_eq!(
+        s.factory
+            .try_deploy_instance(&symbol_short!("acme"), &org_admin),
+        Err(Ok(Error::InstanceExists))
+    );
+    assert_eq!(
+        s.factory.try_get_instance(&symbol_short!("initech")),
+        Err(Ok(Error::InstanceNotFound))
+    );
+
+    let mut preset = s.factory.get_preset().unwrap();
+    preset.release_fee_rate = 5_001;
+    assert_eq!(
+        s.factory.try_set_preset(&preset),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+}
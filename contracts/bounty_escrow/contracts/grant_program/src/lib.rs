@@ -0,0 +1,485 @@
+//! # Grant Program
+//!
+//! Companion contract that runs a grants program on top of the bounty
+//! escrow. It holds a token budget, opens funding rounds carved out of that
+//! budget, accepts applications (identified by a proposal hash, the proposal
+//! itself lives off-chain) and, when the admin approves one, locks the grant
+//! in the escrow under a bounty id. From there the escrow handles payout,
+//! disputes and refunds as for any bounty; refunded grants return to this
+//! contract's budget because it is the escrow depositor.
+//!
+//! ## Budget accounting
+//! The budget is this contract's token balance. Opening a round reserves its
+//! budget; approvals draw from the round's reservation and closing a round
+//! frees whatever was not granted. `get_available_budget` is the balance
+//! minus all reservations.
+#![no_std]
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, vec,
+    Address, BytesN, Env, IntoVal, Symbol, Vec,
+};
+
+/// Subset of the escrow interface the grant program calls into.
+#[contractclient(name = "EscrowClient")]
+pub trait EscrowContract {
+    fn lock_funds(env: Env, depositor: Address, bounty_id: u64, amount: i128, deadline: u64);
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when an amount is zero or negative
+    InvalidAmount = 3,
+    /// Returned when a round or grant exceeds the budget available to it
+    InsufficientBudget = 4,
+    RoundNotFound = 5,
+    /// Returned when the round is closed or its application window has ended
+    RoundClosed = 6,
+    ApplicationNotFound = 7,
+    /// Returned when approving or rejecting an application already decided
+    ApplicationNotPending = 8,
+    /// Returned when a round's application window is already over
+    InvalidDeadline = 9,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundStatus {
+    Open,
+    Closed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Round {
+    pub round_id: u64,
+    pub budget: i128,
+    /// Amount granted so far.
+    pub allocated: i128,
+    /// Applications are accepted until this timestamp.
+    pub applications_close_at: u64,
+    pub status: RoundStatus,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ApplicationStatus {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Application {
+    pub application_id: u64,
+    pub round_id: u64,
+    pub applicant: Address,
+    /// Hash of the off-chain proposal.
+    pub proposal_hash: BytesN<32>,
+    pub requested: i128,
+    pub status: ApplicationStatus,
+    /// Amount granted on approval. Zero otherwise.
+    pub granted: i128,
+    /// Escrow bounty the grant is locked under. Zero until approved.
+    pub bounty_id: u64,
+    pub submitted_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundOpened {
+    pub round_id: u64,
+    pub budget: i128,
+    pub applications_close_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundClosed {
+    pub round_id: u64,
+    pub allocated: i128,
+    pub released_budget: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationSubmitted {
+    pub application_id: u64,
+    pub round_id: u64,
+    pub applicant: Address,
+    pub proposal_hash: BytesN<32>,
+    pub requested: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ApplicationDecided {
+    pub application_id: u64,
+    pub status: ApplicationStatus,
+    pub granted: i128,
+    pub bounty_id: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// Token the budget is held in; must match the escrow token
+    Token,
+    /// Address of the bounty escrow contract grants are locked in
+    Escrow,
+    /// i128 sum of unallocated budget across open rounds
+    Reserved,
+    NextRoundId,
+    NextApplicationId,
+    /// round_id -> Round
+    Round(u64),
+    /// application_id -> Application
+    Application(u64),
+    /// round_id -> Vec<u64> application ids in submission order
+    RoundApplications(u64),
+}
+
+#[contract]
+pub struct GrantProgramContract;
+
+#[contractimpl]
+impl GrantProgramContract {
+    /// Initialize with an admin, the budget token and the escrow contract
+    /// grants are locked in.
+    pub fn init(env: Env, admin: Address, token: Address, escrow: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Escrow, &escrow);
+        Ok(())
+    }
+
+    /// Add `amount` to the program budget. Anyone can fund the program.
+    pub fn fund(env: Env, from: Address, amount: i128) -> Result<(), Error> {
+        let token = Self::token(&env)?;
+        from.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+        env.events()
+            .publish((symbol_short!("g_fund"),), (from, amount));
+        Ok(())
+    }
+
+    /// Open a round reserving `budget` from the available budget and
+    /// accepting applications until `applications_close_at` (admin only).
+    /// Returns the new round id.
+    pub fn open_round(env: Env, budget: i128, applications_close_at: u64) -> Result<u64, Error> {
+        Self::require_admin(&env)?;
+        if budget <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if applications_close_at <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
+        }
+        if budget > Self::get_available_budget(env.clone())? {
+            return Err(Error::InsufficientBudget);
+        }
+
+        let round_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextRoundId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextRoundId, &(round_id + 1));
+        let round = Round {
+            round_id,
+            budget,
+            allocated: 0,
+            applications_close_at,
+            status: RoundStatus::Open,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Round(round_id), &round);
+        Self::add_reserved(&env, budget);
+
+        env.events().publish(
+            (symbol_short!("g_round"), round_id),
+            RoundOpened {
+                round_id,
+                budget,
+                applications_close_at,
+            },
+        );
+        Ok(round_id)
+    }
+
+    /// Close a round, returning its unallocated budget to the program
+    /// (admin only). Pending applications can no longer be approved.
+    pub fn close_round(env: Env, round_id: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        let mut round = Self::get_round(env.clone(), round_id)?;
+        if round.status != RoundStatus::Open {
+            return Err(Error::RoundClosed);
+        }
+        round.status = RoundStatus::Closed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Round(round_id), &round);
+        let released_budget = round.budget - round.allocated;
+        Self::add_reserved(&env, -released_budget);
+
+        env.events().publish(
+            (symbol_short!("g_close"), round_id),
+            RoundClosed {
+                round_id,
+                allocated: round.allocated,
+                released_budget,
+            },
+        );
+        Ok(())
+    }
+
+    /// Submit an application for `requested` tokens to an open round.
+    /// Returns the new application id.
+    pub fn apply(
+        env: Env,
+        applicant: Address,
+        round_id: u64,
+        proposal_hash: BytesN<32>,
+        requested: i128,
+    ) -> Result<u64, Error> {
+        applicant.require_auth();
+        let round = Self::get_round(env.clone(), round_id)?;
+        let now = env.ledger().timestamp();
+        if round.status != RoundStatus::Open || now >= round.applications_close_at {
+            return Err(Error::RoundClosed);
+        }
+        if requested <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let application_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextApplicationId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextApplicationId, &(application_id + 1));
+        let application = Application {
+            application_id,
+            round_id,
+            applicant: applicant.clone(),
+            proposal_hash: proposal_hash.clone(),
+            requested,
+            status: ApplicationStatus::Pending,
+            granted: 0,
+            bounty_id: 0,
+            submitted_at: now,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Application(application_id), &application);
+
+        let index_key = DataKey::RoundApplications(round_id);
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or(Vec::new(&env));
+        index.push_back(application_id);
+        env.storage().persistent().set(&index_key, &index);
+
+        env.events().publish(
+            (symbol_short!("g_apply"), round_id),
+            ApplicationSubmitted {
+                application_id,
+                round_id,
+                applicant,
+                proposal_hash,
+                requested,
+            },
+        );
+        Ok(application_id)
+    }
+
+    /// Approve an application, granting `amount` from its round's budget
+    /// and locking it in the escrow as `bounty_id` with `deadline` (admin
+    /// only). The grant is paid to the applicant through the escrow's
+    /// normal release flow.
+    pub fn approve_application(
+        env: Env,
+        application_id: u64,
+        amount: i128,
+        bounty_id: u64,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        let mut application = Self::get_application(env.clone(), application_id)?;
+        if application.status != ApplicationStatus::Pending {
+            return Err(Error::ApplicationNotPending);
+        }
+        let mut round = Self::get_round(env.clone(), application.round_id)?;
+        if round.status != RoundStatus::Open {
+            return Err(Error::RoundClosed);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > round.budget - round.allocated {
+            return Err(Error::InsufficientBudget);
+        }
+
+        // EFFECTS: record the grant before calling into the escrow
+        round.allocated += amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Round(round.round_id), &round);
+        Self::add_reserved(&env, -amount);
+        application.status = ApplicationStatus::Approved;
+        application.granted = amount;
+        application.bounty_id = bounty_id;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Application(application_id), &application);
+
+        // INTERACTION: lock the grant in the escrow. The escrow pulls the
+        // tokens from this contract, which is a nested call and needs
+        // explicit authorization.
+        let token = Self::token(&env)?;
+        let escrow: Address = env.storage().instance().get(&DataKey::Escrow).unwrap();
+        let program = env.current_contract_address();
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token,
+                    fn_name: Symbol::new(&env, "transfer"),
+                    args: (program.clone(), escrow.clone(), amount).into_val(&env),
+                },
+                sub_invocations: Vec::new(&env),
+            }),
+        ]);
+        EscrowClient::new(&env, &escrow).lock_funds(&program, &bounty_id, &amount, &deadline);
+
+        Self::emit_decided(&env, &application);
+        Ok(())
+    }
+
+    /// Reject a pending application (admin only).
+    pub fn reject_application(env: Env, application_id: u64) -> Result<(), Error> {
+        Self::require_admin(&env)?;
+        let mut application = Self::get_application(env.clone(), application_id)?;
+        if application.status != ApplicationStatus::Pending {
+            return Err(Error::ApplicationNotPending);
+        }
+        application.status = ApplicationStatus::Rejected;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Application(application_id), &application);
+
+        Self::emit_decided(&env, &application);
+        Ok(())
+    }
+
+    /// View: budget not reserved by any open round.
+    pub fn get_available_budget(env: Env) -> Result<i128, Error> {
+        let token = Self::token(&env)?;
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let reserved: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reserved)
+            .unwrap_or(0);
+        Ok(balance - reserved)
+    }
+
+    /// View: a funding round.
+    pub fn get_round(env: Env, round_id: u64) -> Result<Round, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Round(round_id))
+            .ok_or(Error::RoundNotFound)
+    }
+
+    /// View: an application.
+    pub fn get_application(env: Env, application_id: u64) -> Result<Application, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Application(application_id))
+            .ok_or(Error::ApplicationNotFound)
+    }
+
+    /// View: applications submitted to `round_id`, paginated.
+    pub fn list_applications(env: Env, round_id: u64, offset: u32, limit: u32) -> Vec<Application> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::RoundApplications(round_id))
+            .unwrap_or(Vec::new(&env));
+        let mut result = Vec::new(&env);
+        let end = offset.saturating_add(limit).min(index.len());
+        for i in offset..end {
+            let application_id = index.get(i).unwrap();
+            if let Some(application) = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Application(application_id))
+            {
+                result.push_back(application);
+            }
+        }
+        result
+    }
+
+    fn require_admin(env: &Env) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        Ok(())
+    }
+
+    fn token(env: &Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)
+    }
+
+    fn add_reserved(env: &Env, delta: i128) {
+        let reserved: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reserved)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::Reserved, &(reserved + delta));
+    }
+
+    fn emit_decided(env: &Env, application: &Application) {
+        env.events().publish(
+            (symbol_short!("g_decide"), application.application_id),
+            ApplicationDecided {
+                application_id: application.application_id,
+                status: application.status.clone(),
+                granted: application.granted,
+                bounty_id: application.bounty_id,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,174 @@
+#![cfg(test)]
+
+use crate::{
+    ApplicationStatus, Error, GrantProgramContract, GrantProgramContractClient, RoundStatus,
+};
+use bounty_escrow::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token, Address, BytesN, Env,
+};
+
+struct Setup<'a> {
+    env: Env,
+    funder: Address,
+    applicant: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+    program: GrantProgramContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let funder = Address::generate(&env);
+        let applicant = Address::generate(&env);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_address);
+        token::StellarAssetClient::new(&env, &token_address).mint(&funder, &10_000);
+
+        let escrow_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &escrow_id);
+        escrow.init(&admin, &token_address);
+
+        let program_id = env.register_contract(None, GrantProgramContract);
+        let program = GrantProgramContractClient::new(&env, &program_id);
+        program.init(&admin, &token_address, &escrow_id);
+        program.fund(&funder, &10_000);
+
+        Self {
+            env,
+            funder,
+            applicant,
+            token,
+            escrow,
+            program,
+        }
+    }
+
+    fn closes_at(&self) -> u64 {
+        self.env.ledger().timestamp() + 1_000
+    }
+
+    fn apply(&self, round_id: u64, requested: i128) -> u64 {
+        let proposal = BytesN::from_array(&self.env, &[3u8; 32]);
+        self.program
+            .apply(&self.applicant, &round_id, &proposal, &requested)
+    }
+}
+
+#[test]
+fn test_approved_application_is_locked_in_escrow() {
+    let s = Setup::new();
+    let round_id = s.program.open_round(&5_000, &s.closes_at());
+    assert_eq!(s.program.get_available_budget(), 5_000);
+
+    let application_id = s.apply(round_id, 3_000);
+    let deadline = s.env.ledger().timestamp() + 10_000;
+    s.program
+        .approve_application(&application_id, &2_500, &100, &deadline);
+
+    let application = s.program.get_application(&application_id);
+    assert_eq!(application.status, ApplicationStatus::Approved);
+    assert_eq!(application.granted, 2_500);
+    assert_eq!(application.bounty_id, 100);
+    assert_eq!(s.program.get_round(&round_id).allocated, 2_500);
+
+    let escrow = s.escrow.get_escrow_info(&100);
+    assert_eq!(escrow.depositor, s.program.address);
+    assert_eq!(escrow.amount, 2_500);
+    assert_eq!(s.token.balance(&s.program.address), 7_500);
+
+    // The escrow pays the grantee through its normal release flow.
+    s.escrow.release_funds(&100, &s.applicant);
+    assert_eq!(
+        s.escrow.get_escrow_info(&100).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(s.token.balance(&s.applicant), 2_500);
+}
+
+#[test]
+fn test_round_budget_limits_grants() {
+    let s = Setup::new();
+    assert_eq!(
+        s.program.try_open_round(&20_000, &s.closes_at()),
+        Err(Ok(Error::InsufficientBudget))
+    );
+    let round_id = s.program.open_round(&4_000, &s.closes_at());
+    let first = s.apply(round_id, 3_000);
+    let second = s.apply(round_id, 3_000);
+    let deadline = s.env.ledger().timestamp() + 10_000;
+
+    s.program.approve_application(&first, &3_000, &1, &deadline);
+    assert_eq!(
+        s.program
+            .try_approve_application(&second, &3_000, &2, &deadline),
+        Err(Ok(Error::InsufficientBudget))
+    );
+    assert_eq!(
+        s.program
+            .try_approve_application(&first, &500, &2, &deadline),
+        Err(Ok(Error::ApplicationNotPending))
+    );
+
+    s.program.reject_application(&second);
+    assert_eq!(
+        s.program.get_application(&second).status,
+        ApplicationStatus::Rejected
+    );
+    assert_eq!(s.program.list_applications(&round_id, &0, &10).len(), 2);
+}
+
+#[test]
+fn test_closing_round_frees_unallocated_budget() {
+    let s = Setup::new();
+    let round_id = s.program.open_round(&6_000, &s.closes_at());
+    let application_id = s.apply(round_id, 1_000);
+    let deadline = s.env.ledger().timestamp() + 10_000;
+    s.program
+        .approve_application(&application_id, &1_000, &1, &deadline);
+    assert_eq!(s.program.get_available_budget(), 4_000);
+
+    s.program.close_round(&round_id);
+    assert_eq!(s.program.get_round(&round_id).status, RoundStatus::Closed);
+    assert_eq!(s.program.get_available_budget(), 9_000);
+    assert_eq!(
+        s.program.try_close_round(&round_id),
+        Err(Ok(Error::RoundClosed))
+    );
+
+    // A refunded grant returns to the program budget.
+    s.env.ledger().set_timestamp(deadline + 1);
+    s.escrow.refund(&1);
+    assert_eq!(s.program.get_available_budget(), 10_000);
+    assert_eq!(s.token.balance(&s.funder), 0);
+}
+
+#[test]
+fn test_applications_only_while_round_open() {
+    let s = Setup::new();
+    let proposal = BytesN::from_array(&s.env, &[3u8; 32]);
+    assert_eq!(
+        s.program.try_apply(&s.applicant, &9, &proposal, &100),
+        Err(Ok(Error::RoundNotFound))
+    );
+    let round_id = s.program.open_round(&1_000, &s.closes_at());
+    assert_eq!(
+        s.program.try_apply(&s.applicant, &round_id, &proposal, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    s.env.ledger().set_timestamp(s.closes_at());
+    assert_eq!(
+        s.program
+            .try_apply(&s.applicant, &round_id, &proposal, &100),
+        Err(Ok(Error::RoundClosed))
+    );
+}
@@ -0,0 +1,195 @@
+//! # Payment Splitter
+//!
+//! Splits every payment it receives in one token between a fixed set of
+//! payees, proportionally to their shares. Use its address as the recipient
+//! of an escrow release (`release_funds(bounty_id, splitter)`) to pay a team
+//! bounty out automatically.
+//!
+//! Funds are never pushed: each payee (or anyone on their behalf) calls
+//! `release(payee)` to transfer what is currently owed. The amount owed is
+//! `total_received * shares / total_shares - already_released`, where
+//! `total_received` is the current balance plus everything released so far,
+//! so payments received at any time are split the same way. Rounding dust
+//! stays in the contract and is paid out as later payments accumulate.
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Vec,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when payees is empty or its length differs from shares
+    InvalidPayees = 3,
+    /// Returned when a payee is given zero shares
+    InvalidShares = 4,
+    DuplicatePayee = 5,
+    NotPayee = 6,
+    /// Returned when the payee is owed nothing
+    NothingDue = 7,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentReleased {
+    pub payee: Address,
+    pub amount: i128,
+    pub total_released: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Token,
+    /// Vec<Address> of payees in registration order
+    Payees,
+    TotalShares,
+    TotalReleased,
+    /// payee Address -> u32 shares
+    Shares(Address),
+    /// payee Address -> i128 released so far
+    Released(Address),
+}
+
+#[contract]
+pub struct PaymentSplitterContract;
+
+#[contractimpl]
+impl PaymentSplitterContract {
+    /// Initialize with the token to split and `shares[i]` for `payees[i]`.
+    /// The payee set is fixed afterwards.
+    pub fn init(
+        env: Env,
+        token: Address,
+        payees: Vec<Address>,
+        shares: Vec<u32>,
+    ) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Token) {
+            return Err(Error::AlreadyInitialized);
+        }
+        if payees.is_empty() || payees.len() != shares.len() {
+            return Err(Error::InvalidPayees);
+        }
+
+        let mut total_shares: u32 = 0;
+        for (i, payee) in payees.iter().enumerate() {
+            let share = shares.get(i as u32).unwrap();
+            if share == 0 {
+                return Err(Error::InvalidShares);
+            }
+            let key = DataKey::Shares(payee);
+            if env.storage().persistent().has(&key) {
+                return Err(Error::DuplicatePayee);
+            }
+            env.storage().persistent().set(&key, &share);
+            total_shares = total_shares
+                .checked_add(share)
+                .ok_or(Error::InvalidShares)?;
+        }
+
+        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage().instance().set(&DataKey::Payees, &payees);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalShares, &total_shares);
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalReleased, &0i128);
+        Ok(())
+    }
+
+    /// Transfer everything currently owed to `payee`. Callable by anyone;
+    /// funds only ever go to the payee. Returns the amount transferred.
+    pub fn release(env: Env, payee: Address) -> Result<i128, Error> {
+        let amount = Self::get_releasable(env.clone(), payee.clone())?;
+        if amount == 0 {
+            return Err(Error::NothingDue);
+        }
+
+        // EFFECTS: account for the payment before the transfer
+        let released = Self::get_released(env.clone(), payee.clone()) + amount;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Released(payee.clone()), &released);
+        let total_released = Self::get_total_released(env.clone()) + amount;
+        env.storage()
+            .instance()
+            .set(&DataKey::TotalReleased, &total_released);
+
+        // INTERACTION: external token transfer is last
+        let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &payee, &amount);
+
+        env.events().publish(
+            (symbol_short!("split_rel"), payee.clone()),
+            PaymentReleased {
+                payee,
+                amount,
+                total_released,
+            },
+        );
+        Ok(amount)
+    }
+
+    /// View: amount `payee` would receive from `release` right now.
+    pub fn get_releasable(env: Env, payee: Address) -> Result<i128, Error> {
+        let token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        let shares = Self::get_shares(env.clone(), payee.clone());
+        if shares == 0 {
+            return Err(Error::NotPayee);
+        }
+        let total_shares: u32 = env.storage().instance().get(&DataKey::TotalShares).unwrap();
+
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        let total_received = balance + Self::get_total_released(env.clone());
+        let entitled = total_received * shares as i128 / total_shares as i128;
+        Ok(entitled - Self::get_released(env, payee))
+    }
+
+    /// View: shares held by `payee`. Zero for non-payees.
+    pub fn get_shares(env: Env, payee: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Shares(payee))
+            .unwrap_or(0)
+    }
+
+    /// View: amount already released to `payee`.
+    pub fn get_released(env: Env, payee: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Released(payee))
+            .unwrap_or(0)
+    }
+
+    /// View: amount released to all payees.
+    pub fn get_total_released(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalReleased)
+            .unwrap_or(0)
+    }
+
+    /// View: payees in registration order.
+    pub fn get_payees(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Payees)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// View: the token being split.
+    pub fn get_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Token)
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,160 @@
+#![cfg(test)]
+
+use crate::{Error, PaymentSplitterContract, PaymentSplitterContractClient};
+use bounty_escrow::{BountyEscrowContract, BountyEscrowContractClient};
+use soroban_sdk::{testutils::Address as _, token, vec, Address, Env};
+
+struct Setup<'a> {
+    env: Env,
+    depositor: Address,
+    alice: Address,
+    bob: Address,
+    carol: Address,
+    token: token::Client<'a>,
+    escrow: BountyEscrowContractClient<'a>,
+    splitter: PaymentSplitterContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let carol = Address::generate(&env);
+
+        let token_address = env
+            .register_stellar_asset_contract_v2(admin.clone())
+            .address();
+        let token = token::Client::new(&env, &token_address);
+        token::StellarAssetClient::new(&env, &token_address).mint(&depositor, &10_000);
+
+        let escrow_id = env.register_contract(None, BountyEscrowContract);
+        let escrow = BountyEscrowContractClient::new(&env, &escrow_id);
+        escrow.init(&admin, &token_address);
+
+        let splitter_id = env.register_contract(None, PaymentSplitterContract);
+        let splitter = PaymentSplitterContractClient::new(&env, &splitter_id);
+        splitter.init(
+            &token_address,
+            &vec![&env, alice.clone(), bob.clone(), carol.clone()],
+            &vec![&env, 50, 30, 20],
+        );
+
+        Self {
+            env,
+            depositor,
+            alice,
+            bob,
+            carol,
+            token,
+            escrow,
+            splitter,
+        }
+    }
+
+    fn pay_bounty(&self, bounty_id: u64, amount: i128) {
+        let deadline = self.env.ledger().timestamp() + 1_000;
+        self.escrow
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+        self.escrow
+            .release_funds(&bounty_id, &self.splitter.address);
+    }
+}
+
+#[test]
+fn test_escrow_release_is_split_between_payees() {
+    let s = Setup::new();
+    s.pay_bounty(1, 1_000);
+    assert_eq!(s.token.balance(&s.splitter.address), 1_000);
+
+    assert_eq!(s.splitter.get_releasable(&s.bob), 300);
+    assert_eq!(s.splitter.release(&s.alice), 500);
+    assert_eq!(s.splitter.release(&s.bob), 300);
+    assert_eq!(s.splitter.release(&s.carol), 200);
+
+    assert_eq!(s.token.balance(&s.alice), 500);
+    assert_eq!(s.token.balance(&s.bob), 300);
+    assert_eq!(s.token.balance(&s.carol), 200);
+    assert_eq!(s.splitter.get_total_released(), 1_000);
+    assert_eq!(s.token.balance(&s.splitter.address), 0);
+}
+
+#[test]
+fn test_later_payments_are_split_the_same_way() {
+    let s = Setup::new();
+    s.pay_bounty(1, 1_000);
+    s.splitter.release(&s.alice);
+
+    // Bob and Carol have not pulled yet; a second bounty adds to their dues.
+    s.pay_bounty(2, 500);
+    assert_eq!(s.splitter.release(&s.alice), 250);
+    assert_eq!(s.splitter.release(&s.bob), 450);
+    assert_eq!(s.splitter.release(&s.carol), 300);
+    assert_eq!(s.splitter.get_released(&s.alice), 750);
+    assert_eq!(s.splitter.try_release(&s.alice), Err(Ok(Error::NothingDue)));
+}
+
+#[test]
+fn test_rounding_dust_is_paid_out_later() {
+    let s = Setup::new();
+    s.pay_bounty(1, 7);
+    assert_eq!(s.splitter.release(&s.alice), 3);
+    assert_eq!(s.splitter.release(&s.bob), 2);
+    assert_eq!(s.splitter.release(&s.carol), 1);
+    assert_eq!(s.token.balance(&s.splitter.address), 1);
+
+    s.pay_bounty(2, 3);
+    assert_eq!(s.splitter.release(&s.alice), 2);
+    assert_eq!(s.splitter.release(&s.bob), 1);
+    assert_eq!(s.splitter.release(&s.carol), 1);
+    assert_eq!(s.token.balance(&s.splitter.address), 0);
+}
+
+#[test]
+fn test_init_and_payee_validation() {
+    let s = Setup::new();
+    let env = &s.env;
+    let stranger = Address::generate(env);
+    assert_eq!(s.splitter.try_release(&stranger), Err(Ok(Error::NotPayee)));
+    assert_eq!(
+        s.splitter.try_init(
+            &s.token.address,
+            &vec![env, stranger.clone()],
+            &vec![env, 1]
+        ),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+
+    let fresh = PaymentSplitterContractClient::new(
+        env,
+        &env.register_contract(None, PaymentSplitterContract),
+    );
+    assert_eq!(
+        fresh.try_init(&s.token.address, &vec![env, stranger.clone()], &vec![env]),
+        Err(Ok(Error::InvalidPayees))
+    );
+    assert_eq!(
+        fresh.try_init(
+            &s.token.address,
+            &vec![env, stranger.clone()],
+            &vec![env, 0]
+        ),
+        Err(Ok(Error::InvalidShares))
+    );
+    assert_eq!(
+        fresh.try_init(
+            &s.token.address,
+            &vec![env, stranger.clone(), stranger.clone()],
+            &vec![env, 1, 1]
+        ),
+        Err(Ok(Error::DuplicatePayee))
+    );
+    assert_eq!(
+        fresh.try_get_releasable(&stranger),
+        Err(Ok(Error::NotInitialized))
+    );
+}
@@ -0,0 +1,205 @@
+//! # Reputation
+//!
+//! Companion contract to the bounty escrow that keeps per-address statistics
+//! (bounties completed, total earned, refunds received, disputes lost) so
+//! front-ends and other contracts can query a contributor's track record.
+//!
+//! Stats are only written by reporters the admin has registered, normally
+//! one or more escrow contracts configured with `set_reputation_contract`.
+//! Each record call requires the reporter's authorization, which a contract
+//! grants implicitly when it invokes this contract directly.
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when the caller is not a registered reporter
+    UnauthorizedReporter = 3,
+    /// Returned when a reported amount is zero or negative
+    InvalidAmount = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContributorStats {
+    /// Bounties fully paid out to this address.
+    pub bounties_completed: u32,
+    /// Sum of net payouts received, across full and partial releases.
+    pub total_earned: i128,
+    /// Number of refunds received as a depositor.
+    pub refunds_received: u32,
+    /// Sum of refunded amounts received as a depositor.
+    pub total_refunded: i128,
+    /// Disputes resolved in the other party's favour.
+    pub disputes_lost: u32,
+    pub last_updated: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationUpdated {
+    pub address: Address,
+    pub bounty_id: u64,
+    pub reporter: Address,
+    pub stats: ContributorStats,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    /// reporter Address -> bool
+    Reporter(Address),
+    /// Address -> ContributorStats
+    Stats(Address),
+}
+
+#[contract]
+pub struct ReputationContract;
+
+#[contractimpl]
+impl ReputationContract {
+    /// Initialize with the admin that manages reporters.
+    pub fn init(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    /// Allow or revoke `reporter` (typically an escrow contract) to record
+    /// stats (admin only).
+    pub fn set_reporter(env: Env, reporter: Address, allowed: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let key = DataKey::Reporter(reporter);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        Ok(())
+    }
+
+    /// View: whether `reporter` may record stats.
+    pub fn is_reporter(env: Env, reporter: Address) -> bool {
+        env.storage().persistent().has(&DataKey::Reporter(reporter))
+    }
+
+    /// Record a payout of `amount` to `contributor` for `bounty_id`.
+    /// `completed` is set on the payout that fully releases the bounty.
+    pub fn record_release(
+        env: Env,
+        reporter: Address,
+        contributor: Address,
+        bounty_id: u64,
+        amount: i128,
+        completed: bool,
+    ) -> Result<(), Error> {
+        Self::check_reporter(&env, &reporter)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::update(&env, &reporter, &contributor, bounty_id, |stats| {
+            stats.total_earned = stats.total_earned.saturating_add(amount);
+            if completed {
+                stats.bounties_completed = stats.bounties_completed.saturating_add(1);
+            }
+        });
+        Ok(())
+    }
+
+    /// Record a refund of `amount` to `recipient` for `bounty_id`.
+    pub fn record_refund(
+        env: Env,
+        reporter: Address,
+        recipient: Address,
+        bounty_id: u64,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::check_reporter(&env, &reporter)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Self::update(&env, &reporter, &recipient, bounty_id, |stats| {
+            stats.refunds_received = stats.refunds_received.saturating_add(1);
+            stats.total_refunded = stats.total_refunded.saturating_add(amount);
+        });
+        Ok(())
+    }
+
+    /// Record that `party` lost the dispute on `bounty_id`.
+    pub fn record_dispute_lost(
+        env: Env,
+        reporter: Address,
+        party: Address,
+        bounty_id: u64,
+    ) -> Result<(), Error> {
+        Self::check_reporter(&env, &reporter)?;
+        Self::update(&env, &reporter, &party, bounty_id, |stats| {
+            stats.disputes_lost = stats.disputes_lost.saturating_add(1);
+        });
+        Ok(())
+    }
+
+    /// View: stats for `address`. All zero when nothing was recorded.
+    pub fn get_stats(env: Env, address: Address) -> ContributorStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Stats(address))
+            .unwrap_or_default()
+    }
+
+    fn check_reporter(env: &Env, reporter: &Address) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        reporter.require_auth();
+        if !env
+            .storage()
+            .persistent()
+            .has(&DataKey::Reporter(reporter.clone()))
+        {
+            return Err(Error::UnauthorizedReporter);
+        }
+        Ok(())
+    }
+
+    fn update(
+        env: &Env,
+        reporter: &Address,
+        address: &Address,
+        bounty_id: u64,
+        apply: impl FnOnce(&mut ContributorStats),
+    ) {
+        let key = DataKey::Stats(address.clone());
+        let mut stats: ContributorStats = env.storage().persistent().get(&key).unwrap_or_default();
+        apply(&mut stats);
+        stats.last_updated = env.ledger().timestamp();
+        env.storage().persistent().set(&key, &stats);
+
+        env.events().publish(
+            (symbol_short!("rep_upd"), address.clone()),
+            ReputationUpdated {
+                address: address.clone(),
+                bounty_id,
+                reporter: reporter.clone(),
+                stats,
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod test;
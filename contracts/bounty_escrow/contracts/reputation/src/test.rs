@@ -0,0 +1,119 @@
+#![cfg(test)]
+
+use crate::{ContributorStats, Error, ReputationContract, ReputationContractClient};
+use core::ops::Deref;
+use escrow_testutils::TestSetup;
+use soroban_sdk::{testutils::Address as _, Address, BytesN};
+
+struct Setup<'a> {
+    base: TestSetup<'a>,
+    reputation: ReputationContractClient<'a>,
+}
+
+impl<'a> Setup<'a> {
+    fn new() -> Self {
+        let s = TestSetup::new();
+        let reputation_id = s.env.register_contract(None, ReputationContract);
+        let reputation = ReputationContractClient::new(&s.env, &reputation_id);
+        reputation.init(&s.admin);
+        reputation.set_reporter(&s.escrow.address, &true);
+        s.escrow.set_reputation_contract(&Some(reputation_id));
+
+        Self {
+            base: s,
+            reputation,
+        }
+    }
+}
+
+impl<'a> Deref for Setup<'a> {
+    type Target = TestSetup<'a>;
+
+    fn deref(&self) -> &TestSetup<'a> {
+        &self.base
+    }
+}
+
+#[test]
+fn test_release_updates_contributor_stats() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.lock(2, 600);
+
+    s.escrow.release_funds(&1, &s.contributor);
+    s.escrow.partial_release(&2, &s.contributor, &200);
+
+    let stats = s.reputation.get_stats(&s.contributor);
+    assert_eq!(stats.bounties_completed, 1);
+    assert_eq!(stats.total_earned, 1_200);
+
+    // The final partial payout completes the second bounty.
+    s.escrow.partial_release(&2, &s.contributor, &400);
+    let stats = s.reputation.get_stats(&s.contributor);
+    assert_eq!(stats.bounties_completed, 2);
+    assert_eq!(stats.total_earned, 1_600);
+    assert_eq!(stats.disputes_lost, 0);
+}
+
+#[test]
+fn test_refund_updates_depositor_stats() {
+    let s = Setup::new();
+    s.lock(1, 1_000);
+    s.lock(2, 500);
+
+    s.escrow.cancel_escrow(&2);
+    s.advance_past_deadline(1);
+    s.escrow.refund(&1);
+
+    let stats = s.reputation.get_stats(&s.depositor);
+    assert_eq!(stats.refunds_received, 2);
+    assert_eq!(stats.total_refunded, 1_500);
+    assert_eq!(stats.bounties_completed, 0);
+    assert_eq!(s.token.balance(&s.depositor), 10_000);
+}
+
+#[test]
+fn test_resolved_dispute_counts_against_losing_party() {
+    let s = Setup::new();
+    let arbiter = Address::generate(&s.env);
+    let reason = BytesN::from_array(&s.env, &[9u8; 32]);
+    s.escrow.set_arbiter(&arbiter);
+    s.lock(1, 1_000);
+    s.escrow.assign_contributor(&1, &s.contributor, &500);
+    s.escrow.accept_assignment(&1);
+
+    s.escrow
+        .open_dispute(&s.depositor, &1, &s.contributor, &reason);
+    s.escrow.resolve_dispute(&1, &2_000);
+
+    let contributor = s.reputation.get_stats(&s.contributor);
+    assert_eq!(contributor.disputes_lost, 1);
+    assert_eq!(contributor.total_earned, 200);
+    assert_eq!(contributor.bounties_completed, 1);
+
+    let depositor = s.reputation.get_stats(&s.depositor);
+    assert_eq!(depositor.disputes_lost, 0);
+    assert_eq!(depositor.total_refunded, 800);
+}
+
+#[test]
+fn test_unregistered_reporter_is_rejected_without_blocking_payouts() {
+    let s = Setup::new();
+    let stranger = Address::generate(&s.env);
+    assert_eq!(
+        s.reputation
+            .try_record_release(&stranger, &s.contributor, &1, &100, &true),
+        Err(Ok(Error::UnauthorizedReporter))
+    );
+
+    // Revoking the escrow makes its reports fail, but releases still succeed.
+    s.reputation.set_reporter(&s.escrow.address, &false);
+    s.lock(1, 1_000);
+    s.escrow.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_000);
+    assert_eq!(
+        s.reputation.get_stats(&s.contributor),
+        ContributorStats::default()
+    );
+}
@@ -0,0 +1,72 @@
+//! Token amounts and times as operators read and type them.
+
+use anyhow::{bail, Context, Result};
+
+/// Decimals of Stellar assets, and of XLM fees.
+pub const STELLAR_DECIMALS: u32 = 7;
+
+/// `"12.5"` with `decimals` 7 is 125_000_000 base units.
+pub fn parse_amount(amount: &str, decimals: u32) -> Result<i128> {
+    let (whole, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        bail!("invalid amount `{amount}`");
+    }
+    if fraction.len() > decimals as usize {
+        bail!("`{amount}` has more than {decimals} decimals");
+    }
+    let scale = 10i128.pow(decimals);
+    let whole: i128 = if whole.is_empty() { 0 } else { whole.parse()? };
+    let fraction: i128 = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<i128>()? * 10i128.pow(decimals - fraction.len() as u32)
+    };
+    whole
+        .checked_mul(scale)
+        .and_then(|whole| whole.checked_add(fraction))
+        .filter(|amount| *amount > 0)
+        .with_context(|| format!("amount `{amount}` is out of range"))
+}
+
+/// 125_000_000 base units with `decimals` 7 is `"12.5000000"`.
+pub fn amount(units: i128, decimals: u32) -> String {
+    if decimals == 0 {
+        return units.to_string();
+    }
+    let scale = 10u128.pow(decimals);
+    let sign = if units < 0 { "-" } else { "" };
+    let units = units.unsigned_abs();
+    format!(
+        "{sign}{}.{:0width$}",
+        units / scale,
+        units % scale,
+        width = decimals as usize
+    )
+}
+
+/// Coarse distance between two unix timestamps, e.g. `"in 2d 4h"` or
+/// `"3h 10m ago"`.
+pub fn relative(timestamp: u64, now: u64) -> String {
+    let (seconds, future) = if timestamp >= now {
+        (timestamp - now, true)
+    } else {
+        (now - timestamp, false)
+    };
+    let (days, hours, minutes) = (seconds / 86_400, seconds / 3_600 % 24, seconds / 60 % 60);
+    let span = match (days, hours, minutes) {
+        (0, 0, 0) => return "now".to_string(),
+        (0, 0, minutes) => format!("{minutes}m"),
+        (0, hours, minutes) => format!("{hours}h {minutes}m"),
+        (days, hours, _) => format!("{days}d {hours}h"),
+    };
+    if future {
+        format!("in {span}")
+    } else {
+        format!("{span} ago")
+    }
+}
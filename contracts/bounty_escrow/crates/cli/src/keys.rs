@@ -0,0 +1,122 @@
+//! Signing keys: a secret (`S...`) from `GRAINLIFY_SECRET_KEY`, or a named
+//! key in the keystore, one file per key holding its secret.
+
+use anyhow::{bail, Context, Result};
+use grainlify_client::Signer;
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// `GRAINLIFY_KEYSTORE`, or `~/.config/grainlify/keys`.
+pub fn keystore_dir() -> Result<PathBuf> {
+    if let Ok(dir) = env::var("GRAINLIFY_KEYSTORE") {
+        return Ok(dir.into());
+    }
+    let home = env::var("HOME").context("neither GRAINLIFY_KEYSTORE nor HOME is set")?;
+    Ok(Path::new(&home).join(".config/grainlify/keys"))
+}
+
+/// The signer for a command: the named keystore key if one is given,
+/// otherwise `secret`.
+pub fn signer(dir: &Path, name: Option<&str>, secret: Option<&str>) -> Result<Signer> {
+    match (name, secret) {
+        (Some(name), _) => load(dir, name),
+        (None, Some(secret)) => {
+            Signer::from_secret(secret.trim()).context("GRAINLIFY_SECRET_KEY is not a valid secret")
+        }
+        (None, None) => bail!("no signing key: pass --key <NAME> or set GRAINLIFY_SECRET_KEY"),
+    }
+}
+
+pub fn load(dir: &Path, name: &str) -> Result<Signer> {
+    let path = key_path(dir, name)?;
+    check_permissions(&path)?;
+    let secret =
+        fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    Signer::from_secret(secret.trim())
+        .with_context(|| format!("{} does not hold a valid secret", path.display()))
+}
+
+/// Store `secret` as `name`, readable by the owner only.
+pub fn add(dir: &Path, name: &str, secret: &str) -> Result<Signer> {
+    let signer = Signer::from_secret(secret.trim()).context("not a valid secret")?;
+    let path = key_path(dir, name)?;
+    if path.exists() {
+        bail!("key `{name}` already exists");
+    }
+    fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    write_private(&path, secret.trim())?;
+    Ok(signer)
+}
+
+/// Names of the stored keys with their public keys, sorted by name.
+pub fn list(dir: &Path) -> Result<Vec<(String, String)>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut keys = Vec::new();
+    for entry in fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        let public_key = match load(dir, &name) {
+            Ok(signer) => signer.public_key(),
+            Err(error) => format!("unreadable: {error:#}"),
+        };
+        keys.push((name, public_key));
+    }
+    keys.sort();
+    Ok(keys)
+}
+
+fn key_path(dir: &Path, name: &str) -> Result<PathBuf> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        bail!("invalid key name `{name}`: use letters, digits, `-` and `_`");
+    }
+    Ok(dir.join(name))
+}
+
+#[cfg(unix)]
+fn write_private(path: &Path, contents: &str) -> Result<()> {
+    use std::{io::Write, os::unix::fs::OpenOptionsExt};
+
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(path)
+        .with_context(|| format!("creating {}", path.display()))?;
+    writeln!(file, "{contents}")?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn write_private(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, format!("{contents}\n")).with_context(|| format!("writing {}", path.display()))
+}
+
+/// Like ssh, refuse keys that other users can read.
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(path)
+        .with_context(|| format!("no key at {}", path.display()))?
+        .permissions()
+        .mode();
+    if mode & 0o077 != 0 {
+        bail!(
+            "{} is accessible by other users; run `chmod 600` on it",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Result<()> {
+    Ok(())
+}
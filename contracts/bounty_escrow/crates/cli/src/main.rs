@@ -0,0 +1,350 @@
+//! # Grainlify CLI
+//!
+//! Operate a bounty escrow contract from a terminal: lock, release and
+//! refund escrows, manage rescues and read contract statistics, without a
+//! front-end.
+//!
+//! Connection settings come from flags or `GRAINLIFY_RPC_URL`,
+//! `GRAINLIFY_NETWORK_PASSPHRASE` and `GRAINLIFY_CONTRACT_ID`. Commands that
+//! send transactions sign with the keystore key named by `--key` or
+//! `GRAINLIFY_KEY`, or else with the secret in `GRAINLIFY_SECRET_KEY`.
+//! Amounts are in whole tokens, e.g. `12.5`, scaled by `--decimals`.
+
+mod format;
+mod keys;
+
+use anyhow::{Context, Result};
+use clap::{ArgGroup, Parser, Subcommand};
+use format::STELLAR_DECIMALS;
+use grainlify_client::{Client, Error, Signer, Submitted};
+use std::{
+    env,
+    io::{self, BufRead},
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const TESTNET_PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+#[derive(Parser)]
+#[command(
+    name = "grainlify-cli",
+    version,
+    about = "Operate a Grainlify bounty escrow"
+)]
+struct Cli {
+    /// Soroban RPC endpoint
+    #[arg(long, env = "GRAINLIFY_RPC_URL", global = true)]
+    rpc_url: Option<String>,
+    #[arg(long, env = "GRAINLIFY_NETWORK_PASSPHRASE", global = true, default_value = TESTNET_PASSPHRASE)]
+    network_passphrase: String,
+    /// Escrow contract (`C...`)
+    #[arg(long, env = "GRAINLIFY_CONTRACT_ID", global = true)]
+    contract_id: Option<String>,
+    /// Keystore key to sign with
+    #[arg(long, env = "GRAINLIFY_KEY", global = true)]
+    key: Option<String>,
+    /// Decimals of the escrow token
+    #[arg(long, env = "GRAINLIFY_TOKEN_DECIMALS", global = true, default_value_t = STELLAR_DECIMALS)]
+    decimals: u32,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Lock funds from the signer into a new escrow
+    #[command(group(ArgGroup::new("expiry").required(true).args(["deadline", "days"])))]
+    Lock {
+        bounty_id: u64,
+        amount: String,
+        /// Refund deadline as a unix timestamp
+        #[arg(long)]
+        deadline: Option<u64>,
+        /// Refund deadline in days from now
+        #[arg(long)]
+        days: Option<u64>,
+    },
+    /// Release an escrow to a contributor (admin)
+    Release {
+        bounty_id: u64,
+        /// Contributor address (`G...`)
+        contributor: String,
+    },
+    /// Refund an escrow to its depositor
+    Refund { bounty_id: u64 },
+    /// Move tokens out of the contract to the treasury (admin)
+    Rescue {
+        #[command(subcommand)]
+        command: RescueCommand,
+    },
+    /// Contract totals, or a single escrow
+    Stats { bounty_id: Option<u64> },
+    /// Manage the keystore
+    Keys {
+        #[command(subcommand)]
+        command: KeysCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum RescueCommand {
+    /// Announce a timelocked rescue of escrow tokens
+    Request { amount: String },
+    /// Carry out the pending rescue once its delay has passed
+    Execute,
+    /// Withdraw the pending rescue
+    Cancel,
+    /// Show the pending rescue
+    Status,
+    /// Sweep a token's balance not owed to any escrow
+    Untracked {
+        /// Token contract (`C...`)
+        token: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeysCommand {
+    /// Store a secret read from stdin under a name
+    Add { name: String },
+    /// List stored keys and their addresses
+    List,
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", describe(&cli, &error).await);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: &Cli) -> Result<()> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    match &cli.command {
+        Command::Lock {
+            bounty_id,
+            amount,
+            deadline,
+            days,
+        } => {
+            let amount = format::parse_amount(amount, cli.decimals)?;
+            let deadline = match (deadline, days) {
+                (Some(deadline), _) => *deadline,
+                (None, days) => now + days.unwrap_or_default() * 86_400,
+            };
+            let submitted = cli
+                .client()?
+                .lock_funds(&cli.signer()?, *bounty_id, amount, deadline)
+                .await?;
+            println!(
+                "Locked {} in escrow #{bounty_id}, refundable after {deadline} ({})",
+                format::amount(amount, cli.decimals),
+                format::relative(deadline, now)
+            );
+            print_submitted(&submitted);
+        }
+        Command::Release {
+            bounty_id,
+            contributor,
+        } => {
+            let client = cli.client()?;
+            let escrow = client.get_escrow_info(*bounty_id).await?;
+            let submitted = client
+                .release_funds(&cli.signer()?, *bounty_id, contributor)
+                .await?;
+            println!(
+                "Released {} from escrow #{bounty_id} to {contributor}",
+                format::amount(escrow.remaining_amount, cli.decimals)
+            );
+            print_submitted(&submitted);
+        }
+        Command::Refund { bounty_id } => {
+            let client = cli.client()?;
+            let escrow = client.get_escrow_info(*bounty_id).await?;
+            let submitted = client.refund(&cli.signer()?, *bounty_id).await?;
+            println!(
+                "Refunded {} from escrow #{bounty_id}",
+                format::amount(escrow.remaining_amount, cli.decimals)
+            );
+            print_submitted(&submitted);
+        }
+        Command::Rescue { command } => rescue(cli, command, now).await?,
+        Command::Stats { bounty_id } => stats(cli, *bounty_id, now).await?,
+        Command::Keys { command } => {
+            let dir = keys::keystore_dir()?;
+            match command {
+                KeysCommand::Add { name } => {
+                    let mut secret = String::new();
+                    io::stdin().lock().read_line(&mut secret)?;
+                    let signer = keys::add(&dir, name, &secret)?;
+                    println!("Stored `{name}` ({})", signer.public_key());
+                }
+                KeysCommand::List => {
+                    for (name, public_key) in keys::list(&dir)? {
+                        println!("{name:<16} {public_key}");
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn rescue(cli: &Cli, command: &RescueCommand, now: u64) -> Result<()> {
+    let client = cli.client()?;
+    match command {
+        RescueCommand::Request { amount } => {
+            let amount = format::parse_amount(amount, cli.decimals)?;
+            let submitted = client.request_rescue(&cli.signer()?, amount).await?;
+            println!(
+                "Requested a rescue of {}",
+                format::amount(amount, cli.decimals)
+            );
+            print_submitted(&submitted);
+            if let Some(request) = client.get_rescue_request().await? {
+                println!(
+                    "  executable  {} ({})",
+                    request.executable_at,
+                    format::relative(request.executable_at, now)
+                );
+            }
+        }
+        RescueCommand::Execute => {
+            let request = client
+                .get_rescue_request()
+                .await?
+                .context("no rescue is pending")?;
+            let submitted = client.execute_rescue(&cli.signer()?).await?;
+            println!(
+                "Rescued {} to {}",
+                format::amount(request.amount, cli.decimals),
+                request.recipient
+            );
+            print_submitted(&submitted);
+        }
+        RescueCommand::Cancel => {
+            let submitted = client.cancel_rescue(&cli.signer()?).await?;
+            println!("Cancelled the pending rescue");
+            print_submitted(&submitted);
+        }
+        RescueCommand::Status => match client.get_rescue_request().await? {
+            Some(request) => {
+                println!(
+                    "Rescue of {} pending",
+                    format::amount(request.amount, cli.decimals)
+                );
+                println!("  recipient   {}", request.recipient);
+                println!("  requested   by {}", request.requested_by);
+                println!(
+                    "  executable  {} ({})",
+                    request.executable_at,
+                    format::relative(request.executable_at, now)
+                );
+            }
+            None => println!("No rescue pending"),
+        },
+        RescueCommand::Untracked { token } => {
+            let submitted = client
+                .rescue_untracked_tokens(&cli.signer()?, token)
+                .await?;
+            let amount = grainlify_client::scval::to_i128(&submitted.result)?;
+            // Other tokens may have other decimals; show base units too.
+            println!(
+                "Rescued {} ({amount} base units) of {token}",
+                format::amount(amount, cli.decimals)
+            );
+            print_submitted(&submitted);
+        }
+    }
+    Ok(())
+}
+
+async fn stats(cli: &Cli, bounty_id: Option<u64>, now: u64) -> Result<()> {
+    let client = cli.client()?;
+    let amount = |units| format::amount(units, cli.decimals);
+    if let Some(bounty_id) = bounty_id {
+        let escrow = client.get_escrow_info(bounty_id).await?;
+        println!("Escrow #{bounty_id}  {}", escrow.status);
+        println!("  depositor  {}", escrow.depositor);
+        println!("  amount     {}", amount(escrow.amount));
+        println!("  remaining  {}", amount(escrow.remaining_amount));
+        println!(
+            "  deadline   {} ({})",
+            escrow.deadline,
+            format::relative(escrow.deadline, now)
+        );
+        return Ok(());
+    }
+
+    let stats = client.get_contract_stats().await?;
+    let balance = client.get_balance().await?;
+    println!(
+        "Escrows         {} locked, {} released, {} refunded, {} partially refunded, {} cancelled",
+        stats.count_locked,
+        stats.count_released,
+        stats.count_refunded,
+        stats.count_partially_refunded,
+        stats.count_cancelled
+    );
+    println!("Value locked    {}", amount(stats.total_value_locked));
+    println!("Released        {}", amount(stats.total_released));
+    println!("Refunded        {}", amount(stats.total_refunded));
+    println!("Fees collected  {}", amount(stats.total_fees_collected));
+    println!("Balance         {}", amount(balance));
+    Ok(())
+}
+
+impl Cli {
+    fn client(&self) -> Result<Client> {
+        let rpc_url = self
+            .rpc_url
+            .as_deref()
+            .context("no RPC endpoint: pass --rpc-url or set GRAINLIFY_RPC_URL")?;
+        let contract_id = self
+            .contract_id
+            .as_deref()
+            .context("no contract: pass --contract-id or set GRAINLIFY_CONTRACT_ID")?;
+        Ok(Client::new(rpc_url, &self.network_passphrase, contract_id)?)
+    }
+
+    fn signer(&self) -> Result<Signer> {
+        let secret = env::var("GRAINLIFY_SECRET_KEY").ok();
+        keys::signer(
+            &keys::keystore_dir()?,
+            self.key.as_deref(),
+            secret.as_deref(),
+        )
+    }
+}
+
+fn print_submitted(submitted: &Submitted) {
+    println!("  transaction {}", submitted.hash);
+    if let Some(ledger) = submitted.ledger {
+        println!("  ledger      {ledger}");
+    }
+    println!(
+        "  fee         {} XLM",
+        format::amount(submitted.fee.total() as i128, STELLAR_DECIMALS)
+    );
+}
+
+/// The error chain, with the contract's own description of contract errors.
+async fn describe(cli: &Cli, error: &anyhow::Error) -> String {
+    if let Some(Error::Contract(code)) = error.downcast_ref::<Error>() {
+        if let Ok(client) = cli.client() {
+            if let Ok(description) = client.get_error_description(*code).await {
+                return format!("{error:#}: {description}");
+            }
+        }
+    }
+    format!("{error:#}")
+}
+
+#[cfg(test)]
+mod test;
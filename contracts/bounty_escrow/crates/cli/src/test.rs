@@ -0,0 +1,71 @@
+#![cfg(test)]
+
+use crate::{format, keys};
+use std::{fs, path::PathBuf};
+
+const SECRET: &str = "SAAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQCAIBAEAQC5MY";
+
+fn keystore(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("grainlify-cli-{name}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn test_amounts() {
+    assert_eq!(format::parse_amount("12.5", 7).unwrap(), 125_000_000);
+    assert_eq!(format::parse_amount("3", 7).unwrap(), 30_000_000);
+    assert_eq!(format::parse_amount(".0000001", 7).unwrap(), 1);
+    assert_eq!(format::parse_amount("42", 0).unwrap(), 42);
+    for invalid in ["", ".", "0", "-1", "1.2.3", "1e5", "0.00000001"] {
+        assert!(format::parse_amount(invalid, 7).is_err(), "{invalid}");
+    }
+
+    assert_eq!(format::amount(125_000_000, 7), "12.5000000");
+    assert_eq!(format::amount(-1, 7), "-0.0000001");
+    assert_eq!(format::amount(42, 0), "42");
+}
+
+#[test]
+fn test_relative_times() {
+    assert_eq!(format::relative(1_000, 1_000), "now");
+    assert_eq!(format::relative(1_000 + 90, 1_000), "in 1m");
+    assert_eq!(
+        format::relative(1_000 + 2 * 86_400 + 4 * 3_600, 1_000),
+        "in 2d 4h"
+    );
+    assert_eq!(
+        format::relative(1_000, 1_000 + 3 * 3_600 + 600),
+        "3h 10m ago"
+    );
+}
+
+#[test]
+fn test_keystore() {
+    let dir = keystore("keys");
+    let signer = keys::add(&dir, "admin", &format!("{SECRET}\n")).unwrap();
+    assert!(keys::add(&dir, "admin", SECRET).is_err());
+    assert!(keys::add(&dir, "../admin", SECRET).is_err());
+    assert!(keys::add(&dir, "bad", "not-a-secret").is_err());
+
+    let loaded = keys::signer(&dir, Some("admin"), None).unwrap();
+    assert_eq!(loaded.public_key(), signer.public_key());
+    assert_eq!(
+        keys::list(&dir).unwrap(),
+        vec![("admin".to_string(), signer.public_key())]
+    );
+
+    let from_env = keys::signer(&dir, None, Some(SECRET)).unwrap();
+    assert_eq!(from_env.public_key(), signer.public_key());
+    // A named key takes precedence over the secret from the environment.
+    assert!(keys::signer(&dir, Some("missing"), Some(SECRET)).is_err());
+    assert!(keys::signer(&dir, None, None).is_err());
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(dir.join("admin"), fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(keys::load(&dir, "admin").is_err());
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
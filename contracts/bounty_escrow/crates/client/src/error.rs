@@ -0,0 +1,65 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("rpc request failed: {0}")]
+    Transport(#[from] reqwest::Error),
+    #[error("rpc error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("invalid xdr: {0}")]
+    Xdr(#[from] stellar_xdr::curr::Error),
+    #[error("invalid key or address: {0}")]
+    Strkey(String),
+    /// The contract returned its `Error` with this code
+    #[error("contract error #{0}")]
+    Contract(u32),
+    #[error("simulation failed: {0}")]
+    Simulation(String),
+    /// Simulation needs entries restored from the archive first
+    #[error("ledger entries are archived and must be restored")]
+    Archived,
+    /// Authorization by an account other than the signer is required
+    #[error("the call needs authorization from another account")]
+    ForeignAuth,
+    #[error("account {0} not found")]
+    AccountNotFound(String),
+    /// The node is busy; the transaction was not accepted
+    #[error("rpc asked to try again later")]
+    TryAgainLater,
+    /// The transaction raced another one from the same account
+    #[error("bad sequence number")]
+    BadSequence,
+    #[error("transaction {hash} failed: {reason}")]
+    Failed { hash: String, reason: String },
+    /// Not included within the polling timeout; it may still land
+    #[error("transaction {0} not confirmed in time")]
+    Timeout(String),
+    #[error("unexpected value: {0}")]
+    Decode(String),
+}
+
+impl Error {
+    /// Whether building and submitting the call again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Error::Transport(_) | Error::TryAgainLater | Error::BadSequence
+        )
+    }
+
+    /// Parse the contract error code out of a host error message such as
+    /// `HostError: Error(Contract, #16)`.
+    pub(crate) fn from_simulation(message: String) -> Self {
+        let code = message
+            .split("Error(Contract, #")
+            .nth(1)
+            .and_then(|rest| rest.split(')').next())
+            .and_then(|code| code.parse().ok());
+        match code {
+            Some(code) => Error::Contract(code),
+            None => Error::Simulation(message),
+        }
+    }
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
@@ -0,0 +1,515 @@
+//! # Grainlify Client
+//!
+//! Typed access to a bounty escrow contract over Soroban RPC, so services
+//! don't build XDR by hand for every call.
+//!
+//! Every write goes through the same steps: fetch the signer's sequence
+//! number, build the invocation, simulate it for resources, fees and
+//! authorization, sign, submit and poll until it lands. Transport errors,
+//! busy nodes and sequence races are retried with exponential backoff per
+//! `RetryPolicy`. Views are simulated only and never submitted.
+//!
+//! The signer is the transaction source and must be the address the call
+//! authorizes (the depositor for `lock_funds`, the admin for
+//! `release_funds`); calls needing another party's authorization fail with
+//! `Error::ForeignAuth`.
+
+pub mod error;
+pub mod rpc;
+pub mod scval;
+pub mod tx;
+
+pub use error::{Error, Result};
+pub use tx::{Call, Signer};
+
+use rpc::RpcClient;
+use std::{future::Future, str::FromStr, time::Duration};
+use stellar_xdr::curr::{
+    AccountId, Hash, Limits, PublicKey, ReadXdr, ScAddress, ScVal, SorobanAuthorizationEntry,
+    SorobanTransactionData, TransactionEnvelope, TransactionResult, TransactionResultResult,
+    TransactionV1Envelope, Uint256,
+};
+
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// Attempts per call, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each one after
+    pub initial_backoff: Duration,
+    /// How often `getTransaction` is polled after submission
+    pub poll_interval: Duration,
+    /// How long to wait for a submitted transaction before giving up
+    pub confirm_timeout: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_secs(1),
+            poll_interval: Duration::from_secs(1),
+            confirm_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// Outcome of simulating a call.
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    pub result: ScVal,
+    pub transaction_data: SorobanTransactionData,
+    pub auth: Vec<SorobanAuthorizationEntry>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FeeEstimate {
+    /// Fee offered for inclusion in a ledger, in stroops
+    pub inclusion_fee: u32,
+    /// Fee for the call's CPU, memory, ledger I/O and rent, in stroops
+    pub resource_fee: i64,
+}
+
+impl FeeEstimate {
+    pub fn total(&self) -> i64 {
+        self.inclusion_fee as i64 + self.resource_fee
+    }
+}
+
+/// A call that made it into a ledger.
+#[derive(Clone, Debug)]
+pub struct Submitted {
+    pub hash: String,
+    pub ledger: Option<u32>,
+    /// Return value, as simulated before submission
+    pub result: ScVal,
+    pub fee: FeeEstimate,
+}
+
+/// The contract's `Escrow`, without its refund history and metadata.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowInfo {
+    pub depositor: String,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    /// `EscrowStatus` variant, e.g. `"Locked"`
+    pub status: String,
+    pub deadline: u64,
+}
+
+impl EscrowInfo {
+    pub fn from_scval(value: &ScVal) -> Result<Self> {
+        Ok(Self {
+            depositor: scval::to_address(scval::field(value, "depositor")?)?,
+            amount: scval::to_i128(scval::field(value, "amount")?)?,
+            remaining_amount: scval::to_i128(scval::field(value, "remaining_amount")?)?,
+            status: scval::to_variant(scval::field(value, "status")?)?,
+            deadline: scval::to_u64(scval::field(value, "deadline")?)?,
+        })
+    }
+}
+
+/// The contract's `ContractStats`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ContractStats {
+    pub count_locked: u32,
+    pub count_released: u32,
+    pub count_refunded: u32,
+    pub count_partially_refunded: u32,
+    pub count_cancelled: u32,
+    pub total_value_locked: i128,
+    pub total_released: i128,
+    pub total_refunded: i128,
+    pub total_fees_collected: i128,
+}
+
+impl ContractStats {
+    pub fn from_scval(value: &ScVal) -> Result<Self> {
+        let count = |name| scval::to_u32(scval::field(value, name)?);
+        let total = |name| scval::to_i128(scval::field(value, name)?);
+        Ok(Self {
+            count_locked: count("count_locked")?,
+            count_released: count("count_released")?,
+            count_refunded: count("count_refunded")?,
+            count_partially_refunded: count("count_partially_refunded")?,
+            count_cancelled: count("count_cancelled")?,
+            total_value_locked: total("total_value_locked")?,
+            total_released: total("total_released")?,
+            total_refunded: total("total_refunded")?,
+            total_fees_collected: total("total_fees_collected")?,
+        })
+    }
+}
+
+/// The contract's pending `RescueRequest`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RescueRequest {
+    pub amount: i128,
+    pub recipient: String,
+    pub requested_by: String,
+    pub requested_at: u64,
+    pub executable_at: u64,
+}
+
+impl RescueRequest {
+    pub fn from_scval(value: &ScVal) -> Result<Self> {
+        Ok(Self {
+            amount: scval::to_i128(scval::field(value, "amount")?)?,
+            recipient: scval::to_address(scval::field(value, "recipient")?)?,
+            requested_by: scval::to_address(scval::field(value, "requested_by")?)?,
+            requested_at: scval::to_u64(scval::field(value, "requested_at")?)?,
+            executable_at: scval::to_u64(scval::field(value, "executable_at")?)?,
+        })
+    }
+}
+
+/// The contract's `EscrowTtl`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct EscrowTtl {
+    /// 0 if the record has not been extended since TTLs were tracked
+    pub live_until_ledger: u32,
+    pub ledgers_remaining: u32,
+}
+
+impl EscrowTtl {
+    pub fn from_scval(value: &ScVal) -> Result<Self> {
+        Ok(Self {
+            live_until_ledger: scval::to_u32(scval::field(value, "live_until_ledger")?)?,
+            ledgers_remaining: scval::to_u32(scval::field(value, "ledgers_remaining")?)?,
+        })
+    }
+}
+
+pub struct Client {
+    rpc: RpcClient,
+    contract: ScAddress,
+    network_id: Hash,
+    retry: RetryPolicy,
+}
+
+impl Client {
+    /// Client for the escrow `contract_id` (`C...`) on the network with
+    /// `network_passphrase`.
+    pub fn new(rpc_url: &str, network_passphrase: &str, contract_id: &str) -> Result<Self> {
+        let contract = ScAddress::from_str(contract_id)
+            .ok()
+            .filter(|address| matches!(address, ScAddress::Contract(_)))
+            .ok_or_else(|| Error::Strkey(contract_id.to_string()))?;
+        Ok(Self {
+            rpc: RpcClient::new(rpc_url),
+            contract,
+            network_id: tx::network_id(network_passphrase),
+            retry: RetryPolicy::default(),
+        })
+    }
+
+    pub fn with_retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    pub fn rpc(&self) -> &RpcClient {
+        &self.rpc
+    }
+
+    pub fn contract_id(&self) -> String {
+        self.contract.to_string()
+    }
+
+    /// Simulate `call` as sent by `source`.
+    pub async fn simulate(&self, source: &AccountId, call: &Call) -> Result<Simulation> {
+        self.with_retries(|| async {
+            let tx = tx::build(source, 0, &self.contract, call)?;
+            self.simulate_tx(TransactionEnvelope::Tx(TransactionV1Envelope {
+                tx,
+                signatures: Default::default(),
+            }))
+            .await
+        })
+        .await
+    }
+
+    /// Fees `call` would cost if sent by `source` now.
+    pub async fn estimate_fee(&self, source: &AccountId, call: &Call) -> Result<FeeEstimate> {
+        let simulation = self.simulate(source, call).await?;
+        Ok(FeeEstimate {
+            inclusion_fee: tx::BASE_FEE,
+            resource_fee: simulation.transaction_data.resource_fee,
+        })
+    }
+
+    /// Simulate a read-only `call` and return its result.
+    pub async fn view(&self, call: &Call) -> Result<ScVal> {
+        // Views need no real source account.
+        let source = AccountId(PublicKey::PublicKeyTypeEd25519(Uint256([0; 32])));
+        Ok(self.simulate(&source, call).await?.result)
+    }
+
+    /// Sign and submit `call`, retrying per the client's `RetryPolicy`.
+    pub async fn invoke(&self, signer: &Signer, call: &Call) -> Result<Submitted> {
+        self.with_retries(|| self.invoke_once(signer, call)).await
+    }
+
+    /// Lock `amount` from the signer into a new escrow.
+    pub async fn lock_funds(
+        &self,
+        depositor: &Signer,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<Submitted> {
+        let call = Call::new(
+            "lock_funds",
+            vec![
+                scval::address(&depositor.public_key())?,
+                scval::u64(bounty_id),
+                scval::i128(amount),
+                scval::u64(deadline),
+            ],
+        );
+        self.invoke(depositor, &call).await
+    }
+
+    /// Release an escrow to `contributor` (`G...`) as the admin.
+    pub async fn release_funds(
+        &self,
+        admin: &Signer,
+        bounty_id: u64,
+        contributor: &str,
+    ) -> Result<Submitted> {
+        let call = Call::new(
+            "release_funds",
+            vec![scval::u64(bounty_id), scval::address(contributor)?],
+        );
+        self.invoke(admin, &call).await
+    }
+
+    /// Refund an escrow to its depositor.
+    pub async fn refund(&self, signer: &Signer, bounty_id: u64) -> Result<Submitted> {
+        let call = Call::new("refund", vec![scval::u64(bounty_id)]);
+        self.invoke(signer, &call).await
+    }
+
+    /// Refund an escrow past its deadline and grace period to its
+    /// depositor. Any account may send it.
+    pub async fn claim_expired_refund(&self, signer: &Signer, bounty_id: u64) -> Result<Submitted> {
+        let call = Call::new("claim_expired_refund", vec![scval::u64(bounty_id)]);
+        self.invoke(signer, &call).await
+    }
+
+    /// Extend the records of `bounty_ids` (at most 20) to the maximum TTL.
+    /// Any account may send it; the number extended is in
+    /// `Submitted::result`.
+    pub async fn bump_all(&self, signer: &Signer, bounty_ids: &[u64]) -> Result<Submitted> {
+        let ids = bounty_ids.iter().copied().map(scval::u64).collect();
+        let call = Call::new("bump_all", vec![scval::vec(ids)?]);
+        self.invoke(signer, &call).await
+    }
+
+    /// Announce a rescue of `amount` escrow tokens to the treasury.
+    pub async fn request_rescue(&self, admin: &Signer, amount: i128) -> Result<Submitted> {
+        let call = Call::new("request_rescue", vec![scval::i128(amount)]);
+        self.invoke(admin, &call).await
+    }
+
+    /// Carry out the pending rescue once its delay has passed.
+    pub async fn execute_rescue(&self, admin: &Signer) -> Result<Submitted> {
+        self.invoke(admin, &Call::new("execute_rescue", Vec::new()))
+            .await
+    }
+
+    pub async fn cancel_rescue(&self, admin: &Signer) -> Result<Submitted> {
+        self.invoke(admin, &Call::new("cancel_rescue", Vec::new()))
+            .await
+    }
+
+    /// Sweep `token` (`C...`) not owed to any escrow to the treasury. The
+    /// rescued amount is in `Submitted::result`.
+    pub async fn rescue_untracked_tokens(&self, admin: &Signer, token: &str) -> Result<Submitted> {
+        let call = Call::new("rescue_untracked_tokens", vec![scval::address(token)?]);
+        self.invoke(admin, &call).await
+    }
+
+    pub async fn get_escrow_info(&self, bounty_id: u64) -> Result<EscrowInfo> {
+        let call = Call::new("get_escrow_info", vec![scval::u64(bounty_id)]);
+        EscrowInfo::from_scval(&self.view(&call).await?)
+    }
+
+    /// Token balance held by the contract.
+    pub async fn get_balance(&self) -> Result<i128> {
+        scval::to_i128(&self.view(&Call::new("get_balance", Vec::new())).await?)
+    }
+
+    /// Ids of escrows in `status` (an `EscrowStatus` variant such as
+    /// `"Locked"`), in creation order.
+    pub async fn get_escrow_ids_by_status(
+        &self,
+        status: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>> {
+        let call = Call::new(
+            "get_escrow_ids_by_status",
+            vec![
+                scval::variant(status)?,
+                scval::u32(offset),
+                scval::u32(limit),
+            ],
+        );
+        scval::to_vec(&self.view(&call).await?)?
+            .iter()
+            .map(scval::to_u64)
+            .collect()
+    }
+
+    pub async fn get_escrow_ttl(&self, bounty_id: u64) -> Result<EscrowTtl> {
+        let call = Call::new("get_escrow_ttl", vec![scval::u64(bounty_id)]);
+        EscrowTtl::from_scval(&self.view(&call).await?)
+    }
+
+    /// Seconds after its deadline before an escrow can be refunded.
+    pub async fn get_refund_grace_period(&self) -> Result<u64> {
+        scval::to_u64(
+            &self
+                .view(&Call::new("get_refund_grace_period", Vec::new()))
+                .await?,
+        )
+    }
+
+    pub async fn get_contract_stats(&self) -> Result<ContractStats> {
+        ContractStats::from_scval(
+            &self
+                .view(&Call::new("get_contract_stats", Vec::new()))
+                .await?,
+        )
+    }
+
+    pub async fn get_rescue_request(&self) -> Result<Option<RescueRequest>> {
+        let value = self
+            .view(&Call::new("get_rescue_request", Vec::new()))
+            .await?;
+        scval::to_option(&value)
+            .map(RescueRequest::from_scval)
+            .transpose()
+    }
+
+    /// The contract's description of its error `code`.
+    pub async fn get_error_description(&self, code: u32) -> Result<String> {
+        let call = Call::new("get_error_description", vec![scval::u32(code)]);
+        scval::to_string(&self.view(&call).await?)
+    }
+
+    async fn with_retries<T, F, Fut>(&self, mut attempt: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let mut backoff = self.retry.initial_backoff;
+        let mut attempts = 1;
+        loop {
+            match attempt().await {
+                Err(error) if error.is_retryable() && attempts < self.retry.max_attempts => {
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempts += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    async fn simulate_tx(&self, envelope: TransactionEnvelope) -> Result<Simulation> {
+        let response = self.rpc.simulate_transaction(&envelope).await?;
+        if let Some(error) = response.error {
+            return Err(Error::from_simulation(error));
+        }
+        if response.restore_preamble.is_some() {
+            return Err(Error::Archived);
+        }
+        let result = response
+            .results
+            .first()
+            .ok_or_else(|| Error::Simulation("no result".to_string()))?;
+        let data = response
+            .transaction_data
+            .ok_or_else(|| Error::Simulation("no transaction data".to_string()))?;
+        Ok(Simulation {
+            result: ScVal::from_xdr_base64(&result.xdr, Limits::none())?,
+            transaction_data: SorobanTransactionData::from_xdr_base64(data, Limits::none())?,
+            auth: result
+                .auth
+                .iter()
+                .map(|entry| SorobanAuthorizationEntry::from_xdr_base64(entry, Limits::none()))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    async fn invoke_once(&self, signer: &Signer, call: &Call) -> Result<Submitted> {
+        let source = signer.account_id();
+        let sequence = self.rpc.account_sequence(&source).await? + 1;
+        let tx = tx::build(&source, sequence, &self.contract, call)?;
+        let simulation = self
+            .simulate_tx(TransactionEnvelope::Tx(TransactionV1Envelope {
+                tx: tx.clone(),
+                signatures: Default::default(),
+            }))
+            .await?;
+        let fee = FeeEstimate {
+            inclusion_fee: tx::BASE_FEE,
+            resource_fee: simulation.transaction_data.resource_fee,
+        };
+        let tx = tx::assemble(tx, simulation.transaction_data, simulation.auth)?;
+        let hash = hex(&tx::transaction_hash(&self.network_id, &tx)?);
+        let envelope = signer.sign(&self.network_id, tx)?;
+
+        let sent = self.rpc.send_transaction(&envelope).await?;
+        match sent.status.as_str() {
+            "PENDING" | "DUPLICATE" => {}
+            "TRY_AGAIN_LATER" => return Err(Error::TryAgainLater),
+            _ => return Err(failure(hash, sent.error_result_xdr.as_deref())),
+        }
+
+        let deadline = tokio::time::Instant::now() + self.retry.confirm_timeout;
+        loop {
+            tokio::time::sleep(self.retry.poll_interval).await;
+            let status = self.rpc.get_transaction(&hash).await?;
+            match status.status.as_str() {
+                "SUCCESS" => {
+                    return Ok(Submitted {
+                        hash,
+                        ledger: status.ledger,
+                        result: simulation.result,
+                        fee,
+                    })
+                }
+                "FAILED" => return Err(failure(hash, status.result_xdr.as_deref())),
+                _ if tokio::time::Instant::now() >= deadline => return Err(Error::Timeout(hash)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Error for a rejected or failed transaction, from its `TransactionResult`.
+fn failure(hash: String, result_xdr: Option<&str>) -> Error {
+    let result = result_xdr
+        .and_then(|xdr| TransactionResult::from_xdr_base64(xdr, Limits::none()).ok())
+        .map(|result| result.result);
+    match result {
+        Some(TransactionResultResult::TxBadSeq) => Error::BadSequence,
+        Some(result) => Error::Failed {
+            hash,
+            reason: result.name().to_string(),
+        },
+        None => Error::Failed {
+            hash,
+            reason: "unknown".to_string(),
+        },
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,226 @@
+//! Soroban RPC transport: the JSON-RPC methods the client, indexer and
+//! tools use, with XDR left base64-encoded for the caller to decode.
+
+use crate::error::{Error, Result};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+use stellar_xdr::curr::{
+    AccountId, LedgerEntryData, LedgerKey, LedgerKeyAccount, Limits, ReadXdr, TransactionEnvelope,
+    WriteXdr,
+};
+
+/// Where a `getEvents` page starts.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Start {
+    Ledger(u32),
+    /// Paging token returned by the previous page
+    Cursor(String),
+}
+
+/// A contract event as returned by `getEvents`; topics and value are
+/// base64-encoded `ScVal` XDR.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcEvent {
+    pub id: String,
+    pub ledger: u32,
+    pub ledger_closed_at: String,
+    pub contract_id: String,
+    pub topic: Vec<String>,
+    pub value: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPage {
+    pub events: Vec<RpcEvent>,
+    pub latest_ledger: u32,
+    /// Resume point after this page, set even when it has no events
+    #[serde(default)]
+    pub cursor: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulateResponse {
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub transaction_data: Option<String>,
+    #[serde(default)]
+    pub min_resource_fee: Option<String>,
+    #[serde(default)]
+    pub results: Vec<SimulateResult>,
+    /// Present when archived entries must be restored first
+    #[serde(default)]
+    pub restore_preamble: Option<Value>,
+    pub latest_ledger: u32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SimulateResult {
+    #[serde(default)]
+    pub auth: Vec<String>,
+    pub xdr: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendResponse {
+    /// `PENDING`, `DUPLICATE`, `TRY_AGAIN_LATER` or `ERROR`
+    pub status: String,
+    pub hash: String,
+    #[serde(default)]
+    pub error_result_xdr: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionResponse {
+    /// `SUCCESS`, `FAILED` or `NOT_FOUND`
+    pub status: String,
+    #[serde(default)]
+    pub ledger: Option<u32>,
+    #[serde(default)]
+    pub result_xdr: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LatestLedger {
+    sequence: u32,
+}
+
+#[derive(Deserialize)]
+struct LedgerEntries {
+    #[serde(default)]
+    entries: Vec<LedgerEntryResult>,
+}
+
+#[derive(Deserialize)]
+struct LedgerEntryResult {
+    xdr: String,
+}
+
+#[derive(Deserialize)]
+struct Response<T> {
+    result: Option<T>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+#[derive(Clone)]
+pub struct RpcClient {
+    http: reqwest::Client,
+    url: String,
+}
+
+impl RpcClient {
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            url: url.into(),
+        }
+    }
+
+    pub async fn latest_ledger(&self) -> Result<u32> {
+        let ledger: LatestLedger = self.call("getLatestLedger", json!({})).await?;
+        Ok(ledger.sequence)
+    }
+
+    /// Up to `limit` events of `contract_id` from `start` on.
+    pub async fn get_events(
+        &self,
+        start: &Start,
+        contract_id: &str,
+        limit: usize,
+    ) -> Result<EventPage> {
+        let filters = json!([{ "type": "contract", "contractIds": [contract_id] }]);
+        let params = match start {
+            Start::Ledger(ledger) => json!({
+                "startLedger": ledger,
+                "filters": filters,
+                "pagination": { "limit": limit },
+            }),
+            Start::Cursor(cursor) => json!({
+                "filters": filters,
+                "pagination": { "cursor": cursor, "limit": limit },
+            }),
+        };
+        self.call("getEvents", params).await
+    }
+
+    /// Current sequence number of `account`.
+    pub async fn account_sequence(&self, account: &AccountId) -> Result<i64> {
+        let key = LedgerKey::Account(LedgerKeyAccount {
+            account_id: account.clone(),
+        });
+        let entries: LedgerEntries = self
+            .call(
+                "getLedgerEntries",
+                json!({ "keys": [key.to_xdr_base64(Limits::none())?] }),
+            )
+            .await?;
+        let entry = entries
+            .entries
+            .first()
+            .ok_or_else(|| Error::AccountNotFound(account.to_string()))?;
+        match LedgerEntryData::from_xdr_base64(&entry.xdr, Limits::none())? {
+            LedgerEntryData::Account(account) => Ok(account.seq_num.0),
+            _ => Err(Error::Decode("ledger entry is not an account".to_string())),
+        }
+    }
+
+    pub async fn simulate_transaction(
+        &self,
+        envelope: &TransactionEnvelope,
+    ) -> Result<SimulateResponse> {
+        let transaction = envelope.to_xdr_base64(Limits::none())?;
+        self.call("simulateTransaction", json!({ "transaction": transaction }))
+            .await
+    }
+
+    pub async fn send_transaction(&self, envelope: &TransactionEnvelope) -> Result<SendResponse> {
+        let transaction = envelope.to_xdr_base64(Limits::none())?;
+        self.call("sendTransaction", json!({ "transaction": transaction }))
+            .await
+    }
+
+    pub async fn get_transaction(&self, hash: &str) -> Result<TransactionResponse> {
+        self.call("getTransaction", json!({ "hash": hash })).await
+    }
+
+    async fn call<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+        let response: Response<T> = self
+            .http
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        match (response.result, response.error) {
+            (_, Some(error)) => Err(Error::Rpc {
+                code: error.code,
+                message: error.message,
+            }),
+            (Some(result), None) => Ok(result),
+            (None, None) => Err(Error::Rpc {
+                code: 0,
+                message: format!("{method}: empty response"),
+            }),
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Conversions between Rust values and the `ScVal`s of contract calls,
+//! events and `#[contracttype]` structs.
+
+use crate::error::{Error, Result};
+use std::str::FromStr;
+use stellar_xdr::curr::{Int128Parts, ScAddress, ScMap, ScSymbol, ScVal, ScVec, StringM, VecM};
+
+pub fn u32(value: u32) -> ScVal {
+    ScVal::U32(value)
+}
+
+pub fn u64(value: u64) -> ScVal {
+    ScVal::U64(value)
+}
+
+pub fn i128(value: i128) -> ScVal {
+    ScVal::I128(Int128Parts {
+        hi: (value >> 64) as i64,
+        lo: value as u64,
+    })
+}
+
+pub fn symbol(name: &str) -> Result<ScVal> {
+    let name = StringM::try_from(name).map_err(|_| Error::Decode(format!("bad symbol {name}")))?;
+    Ok(ScVal::Symbol(ScSymbol(name)))
+}
+
+/// Unit variant `name` of a `#[contracttype]` enum, e.g. `"Locked"`.
+pub fn variant(name: &str) -> Result<ScVal> {
+    let items: VecM<ScVal> = vec![symbol(name)?]
+        .try_into()
+        .map_err(|_| Error::Decode(format!("bad variant {name}")))?;
+    Ok(ScVal::Vec(Some(ScVec(items))))
+}
+
+pub fn vec(values: Vec<ScVal>) -> Result<ScVal> {
+    let items: VecM<ScVal> = values
+        .try_into()
+        .map_err(|_| Error::Decode("vector too long".to_string()))?;
+    Ok(ScVal::Vec(Some(ScVec(items))))
+}
+
+/// `G...` account or `C...` contract address.
+pub fn address(strkey: &str) -> Result<ScVal> {
+    let address = ScAddress::from_str(strkey).map_err(|_| Error::Strkey(strkey.to_string()))?;
+    Ok(ScVal::Address(address))
+}
+
+/// Field `name` of a `#[contracttype]` struct.
+pub fn field<'a>(value: &'a ScVal, name: &str) -> Result<&'a ScVal> {
+    let ScVal::Map(Some(ScMap(entries))) = value else {
+        return Err(Error::Decode("value is not a struct".to_string()));
+    };
+    entries
+        .iter()
+        .find(
+            |entry| matches!(&entry.key, ScVal::Symbol(key) if key.0.as_slice() == name.as_bytes()),
+        )
+        .map(|entry| &entry.val)
+        .ok_or_else(|| Error::Decode(format!("struct has no `{name}`")))
+}
+
+pub fn to_u64(value: &ScVal) -> Result<u64> {
+    match value {
+        ScVal::U64(value) => Ok(*value),
+        _ => Err(Error::Decode("expected a u64".to_string())),
+    }
+}
+
+pub fn to_u32(value: &ScVal) -> Result<u32> {
+    match value {
+        ScVal::U32(value) => Ok(*value),
+        _ => Err(Error::Decode("expected a u32".to_string())),
+    }
+}
+
+pub fn to_i128(value: &ScVal) -> Result<i128> {
+    match value {
+        ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+        _ => Err(Error::Decode("expected an i128".to_string())),
+    }
+}
+
+pub fn to_address(value: &ScVal) -> Result<String> {
+    match value {
+        ScVal::Address(address) => Ok(address.to_string()),
+        _ => Err(Error::Decode("expected an address".to_string())),
+    }
+}
+
+pub fn to_string(value: &ScVal) -> Result<String> {
+    match value {
+        ScVal::String(value) => Ok(value.to_utf8_string_lossy()),
+        _ => Err(Error::Decode("expected a string".to_string())),
+    }
+}
+
+/// `None` for a missing `Option` value, which the contract returns as void.
+pub fn to_option(value: &ScVal) -> Option<&ScVal> {
+    match value {
+        ScVal::Void => None,
+        value => Some(value),
+    }
+}
+
+pub fn to_vec(value: &ScVal) -> Result<&[ScVal]> {
+    match value {
+        ScVal::Vec(Some(items)) => Ok(items.as_slice()),
+        _ => Err(Error::Decode("expected a vector".to_string())),
+    }
+}
+
+/// Name of a unit variant of a `#[contracttype]` enum, e.g. `"Locked"`.
+pub fn to_variant(value: &ScVal) -> Result<String> {
+    match value {
+        ScVal::Vec(Some(items)) => match items.first() {
+            Some(ScVal::Symbol(name)) => Ok(name.0.to_utf8_string_lossy()),
+            _ => Err(Error::Decode("expected an enum variant".to_string())),
+        },
+        _ => Err(Error::Decode("expected an enum variant".to_string())),
+    }
+}
@@ -0,0 +1,225 @@
+#![cfg(test)]
+
+use crate::{scval, tx, Call, Client, Error, RetryPolicy, Signer};
+use axum::{extract::State, routing::post, Json, Router};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde_json::{json, Value};
+use std::{
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use stellar_xdr::curr::{
+    AccountEntry, AccountEntryExt, ExtensionPoint, LedgerEntryData, LedgerFootprint, Limits, ScVal,
+    SequenceNumber, SorobanAddressCredentials, SorobanAuthorizationEntry,
+    SorobanAuthorizedFunction, SorobanAuthorizedInvocation, SorobanCredentials, SorobanResources,
+    SorobanTransactionData, Thresholds, TransactionEnvelope, WriteXdr,
+};
+
+const CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+fn signer() -> Signer {
+    Signer::from_secret(&stellar_strkey::ed25519::PrivateKey([7; 32]).to_string()).unwrap()
+}
+
+fn transaction_data(resource_fee: i64) -> SorobanTransactionData {
+    SorobanTransactionData {
+        ext: ExtensionPoint::V0,
+        resources: SorobanResources {
+            footprint: LedgerFootprint {
+                read_only: Default::default(),
+                read_write: Default::default(),
+            },
+            instructions: 0,
+            read_bytes: 0,
+            write_bytes: 0,
+        },
+        resource_fee,
+    }
+}
+
+/// A JSON-RPC node answering with canned responses.
+#[derive(Default)]
+struct MockNode {
+    /// `sendTransaction` calls answered with `TRY_AGAIN_LATER` first
+    busy_sends: u32,
+    sends: AtomicU32,
+    simulation_error: Option<String>,
+}
+
+async fn handle(State(node): State<Arc<MockNode>>, Json(request): Json<Value>) -> Json<Value> {
+    let result = match request["method"].as_str().unwrap() {
+        "getLedgerEntries" => {
+            let account = LedgerEntryData::Account(AccountEntry {
+                account_id: signer().account_id(),
+                balance: 100_000_000,
+                seq_num: SequenceNumber(41),
+                num_sub_entries: 0,
+                inflation_dest: None,
+                flags: 0,
+                home_domain: Default::default(),
+                thresholds: Thresholds([1, 0, 0, 0]),
+                signers: Default::default(),
+                ext: AccountEntryExt::V0,
+            });
+            json!({ "entries": [{ "xdr": account.to_xdr_base64(Limits::none()).unwrap() }] })
+        }
+        "simulateTransaction" => match &node.simulation_error {
+            Some(error) => json!({ "error": error, "latestLedger": 1 }),
+            None => json!({
+                "transactionData": transaction_data(1_234).to_xdr_base64(Limits::none()).unwrap(),
+                "minResourceFee": "1234",
+                "results": [{
+                    "auth": [],
+                    "xdr": ScVal::Void.to_xdr_base64(Limits::none()).unwrap(),
+                }],
+                "latestLedger": 1,
+            }),
+        },
+        "sendTransaction" => {
+            let sends = node.sends.fetch_add(1, Ordering::SeqCst) + 1;
+            let status = if sends <= node.busy_sends {
+                "TRY_AGAIN_LATER"
+            } else {
+                "PENDING"
+            };
+            json!({ "status": status, "hash": "" })
+        }
+        "getTransaction" => json!({ "status": "SUCCESS", "ledger": 5 }),
+        method => panic!("unexpected {method}"),
+    };
+    Json(json!({ "jsonrpc": "2.0", "id": 1, "result": result }))
+}
+
+async fn mock_client(node: Arc<MockNode>) -> Client {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let app = Router::new().route("/", post(handle)).with_state(node);
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    Client::new(&url, PASSPHRASE, CONTRACT)
+        .unwrap()
+        .with_retry(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(1),
+            poll_interval: Duration::from_millis(1),
+            confirm_timeout: Duration::from_secs(1),
+        })
+}
+
+#[test]
+fn test_scval_conversions() {
+    for value in [0, -5, i128::MAX, i128::MIN] {
+        assert_eq!(scval::to_i128(&scval::i128(value)).unwrap(), value);
+    }
+    let account = signer().public_key();
+    assert_eq!(
+        scval::to_address(&scval::address(&account).unwrap()).unwrap(),
+        account
+    );
+    assert!(scval::address("not-an-address").is_err());
+    assert!(Client::new("http://localhost", PASSPHRASE, &account).is_err());
+}
+
+#[test]
+fn test_contract_errors_parsed_from_simulation() {
+    let error = Error::from_simulation("HostError: Error(Contract, #16)\n\nEvent log...".into());
+    assert!(matches!(error, Error::Contract(16)));
+    let error = Error::from_simulation("HostError: Error(Budget, ExceededLimit)".into());
+    assert!(matches!(error, Error::Simulation(_)));
+}
+
+#[test]
+fn test_signed_transaction_verifies() {
+    let signer = signer();
+    let network_id = tx::network_id(PASSPHRASE);
+    let contract = CONTRACT.parse().unwrap();
+    let call = Call::new("refund", vec![scval::u64(1)]);
+    let tx = tx::build(&signer.account_id(), 42, &contract, &call).unwrap();
+    let tx = tx::assemble(tx, transaction_data(500), Vec::new()).unwrap();
+    assert_eq!(tx.fee, tx::BASE_FEE + 500);
+
+    let hash = tx::transaction_hash(&network_id, &tx).unwrap();
+    let TransactionEnvelope::Tx(envelope) = signer.sign(&network_id, tx).unwrap() else {
+        unreachable!()
+    };
+    let public_key = match &signer.account_id().0 {
+        stellar_xdr::curr::PublicKey::PublicKeyTypeEd25519(key) => key.0,
+    };
+    let signature = Signature::from_slice(&envelope.signatures[0].signature.0).unwrap();
+    VerifyingKey::from_bytes(&public_key)
+        .unwrap()
+        .verify(&hash, &signature)
+        .unwrap();
+}
+
+#[test]
+fn test_assemble_refuses_foreign_auth() {
+    let signer = signer();
+    let contract: stellar_xdr::curr::ScAddress = CONTRACT.parse().unwrap();
+    let call = Call::new("refund", vec![scval::u64(1)]);
+    let tx = tx::build(&signer.account_id(), 42, &contract, &call).unwrap();
+    let foreign = SorobanAuthorizationEntry {
+        credentials: SorobanCredentials::Address(SorobanAddressCredentials {
+            address: contract.clone(),
+            nonce: 0,
+            signature_expiration_ledger: 0,
+            signature: ScVal::Void,
+        }),
+        root_invocation: SorobanAuthorizedInvocation {
+            function: SorobanAuthorizedFunction::ContractFn(
+                stellar_xdr::curr::InvokeContractArgs {
+                    contract_address: contract,
+                    function_name: "refund".try_into().unwrap(),
+                    args: Default::default(),
+                },
+            ),
+            sub_invocations: Default::default(),
+        },
+    };
+    assert!(matches!(
+        tx::assemble(tx, transaction_data(0), vec![foreign]),
+        Err(Error::ForeignAuth)
+    ));
+}
+
+#[tokio::test]
+async fn test_invoke_retries_busy_node() {
+    let node = Arc::new(MockNode {
+        busy_sends: 1,
+        ..MockNode::default()
+    });
+    let client = mock_client(node.clone()).await;
+    let submitted = client.lock_funds(&signer(), 1, 1_000, 2_000).await.unwrap();
+    assert_eq!(node.sends.load(Ordering::SeqCst), 2);
+    assert_eq!(submitted.ledger, Some(5));
+    assert_eq!(submitted.fee.resource_fee, 1_234);
+    assert_eq!(submitted.fee.total(), 1_334);
+
+    // Out of attempts: the last error is returned.
+    let node = Arc::new(MockNode {
+        busy_sends: 3,
+        ..MockNode::default()
+    });
+    let client = mock_client(node.clone()).await;
+    assert!(matches!(
+        client.refund(&signer(), 1).await,
+        Err(Error::TryAgainLater)
+    ));
+    assert_eq!(node.sends.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn test_view_reports_contract_error() {
+    let node = Arc::new(MockNode {
+        simulation_error: Some("HostError: Error(Contract, #4)".to_string()),
+        ..MockNode::default()
+    });
+    let client = mock_client(node).await;
+    assert!(matches!(
+        client.get_escrow_info(9).await,
+        Err(Error::Contract(4))
+    ));
+}
@@ -0,0 +1,155 @@
+//! Building, assembling and signing contract-call transactions.
+
+use crate::error::{Error, Result};
+use ed25519_dalek::{Signer as _, SigningKey};
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{
+    AccountId, DecoratedSignature, Hash, HostFunction, InvokeContractArgs, InvokeHostFunctionOp,
+    Limits, Memo, MuxedAccount, Operation, OperationBody, Preconditions, PublicKey, ScAddress,
+    ScSymbol, ScVal, SequenceNumber, Signature, SignatureHint, SorobanAuthorizationEntry,
+    SorobanCredentials, SorobanTransactionData, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction,
+    TransactionV1Envelope, Uint256, VecM, WriteXdr,
+};
+
+/// Inclusion fee offered on top of the resource fee, in stroops.
+pub const BASE_FEE: u32 = 100;
+
+/// A contract function and its arguments.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Call {
+    pub function: String,
+    pub args: Vec<ScVal>,
+}
+
+impl Call {
+    pub fn new(function: &str, args: Vec<ScVal>) -> Self {
+        Self {
+            function: function.to_string(),
+            args,
+        }
+    }
+}
+
+/// An ed25519 account key that sources and signs transactions.
+pub struct Signer {
+    key: SigningKey,
+}
+
+impl Signer {
+    /// Load an `S...` secret seed.
+    pub fn from_secret(secret: &str) -> Result<Self> {
+        let seed = stellar_strkey::ed25519::PrivateKey::from_string(secret)
+            .map_err(|_| Error::Strkey("invalid secret seed".to_string()))?;
+        Ok(Self {
+            key: SigningKey::from_bytes(&seed.0),
+        })
+    }
+
+    pub fn account_id(&self) -> AccountId {
+        AccountId(PublicKey::PublicKeyTypeEd25519(Uint256(
+            self.key.verifying_key().to_bytes(),
+        )))
+    }
+
+    /// The `G...` address of the account.
+    pub fn public_key(&self) -> String {
+        self.account_id().to_string()
+    }
+
+    /// Sign `tx` for the network with id `network_id`.
+    pub fn sign(&self, network_id: &Hash, tx: Transaction) -> Result<TransactionEnvelope> {
+        let hash = transaction_hash(network_id, &tx)?;
+        let public_key = self.key.verifying_key().to_bytes();
+        let signature = DecoratedSignature {
+            hint: SignatureHint(public_key[28..].try_into().unwrap()),
+            signature: Signature(self.key.sign(&hash).to_bytes().to_vec().try_into()?),
+        };
+        Ok(TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx,
+            signatures: vec![signature].try_into()?,
+        }))
+    }
+}
+
+/// Network id: the SHA-256 of the network passphrase.
+pub fn network_id(passphrase: &str) -> Hash {
+    Hash(Sha256::digest(passphrase.as_bytes()).into())
+}
+
+/// Hash of `tx` as submitted, also used to look it up with `getTransaction`.
+pub fn transaction_hash(network_id: &Hash, tx: &Transaction) -> Result<[u8; 32]> {
+    let payload = TransactionSignaturePayload {
+        network_id: network_id.clone(),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+    };
+    Ok(Sha256::digest(payload.to_xdr(Limits::none())?).into())
+}
+
+/// Transaction from `source` invoking `call` on `contract`, before
+/// simulation fills in its resources and authorization.
+pub fn build(
+    source: &AccountId,
+    sequence: i64,
+    contract: &ScAddress,
+    call: &Call,
+) -> Result<Transaction> {
+    let function_name = ScSymbol(
+        call.function
+            .as_str()
+            .try_into()
+            .map_err(|_| Error::Decode(format!("bad function name {}", call.function)))?,
+    );
+    let operation = Operation {
+        source_account: None,
+        body: OperationBody::InvokeHostFunction(InvokeHostFunctionOp {
+            host_function: HostFunction::InvokeContract(InvokeContractArgs {
+                contract_address: contract.clone(),
+                function_name,
+                args: call.args.clone().try_into()?,
+            }),
+            auth: VecM::default(),
+        }),
+    };
+    Ok(Transaction {
+        source_account: MuxedAccount::Ed25519(match &source.0 {
+            PublicKey::PublicKeyTypeEd25519(key) => key.clone(),
+        }),
+        fee: BASE_FEE,
+        seq_num: SequenceNumber(sequence),
+        cond: Preconditions::None,
+        memo: Memo::None,
+        operations: vec![operation].try_into()?,
+        ext: TransactionExt::V0,
+    })
+}
+
+/// Apply the simulated resources, resource fee and authorization to `tx`.
+/// Only the transaction's source account may authorize: entries for other
+/// addresses would need their own signatures.
+pub fn assemble(
+    mut tx: Transaction,
+    data: SorobanTransactionData,
+    auth: Vec<SorobanAuthorizationEntry>,
+) -> Result<Transaction> {
+    if auth
+        .iter()
+        .any(|entry| !matches!(entry.credentials, SorobanCredentials::SourceAccount))
+    {
+        return Err(Error::ForeignAuth);
+    }
+    let resource_fee = u32::try_from(data.resource_fee)
+        .map_err(|_| Error::Decode("resource fee out of range".to_string()))?;
+    tx.fee = BASE_FEE.saturating_add(resource_fee);
+    let mut operations = tx.operations.to_vec();
+    if let Some(Operation {
+        body: OperationBody::InvokeHostFunction(op),
+        ..
+    }) = operations.first_mut()
+    {
+        op.auth = auth.try_into()?;
+    }
+    tx.operations = operations.try_into()?;
+    tx.ext = TransactionExt::V1(data);
+    Ok(tx)
+}
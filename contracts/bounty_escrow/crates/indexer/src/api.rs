@@ -0,0 +1,142 @@
+//! Read-only JSON API over the store.
+//!
+//! | Route                          | Returns                                   |
+//! |--------------------------------|-------------------------------------------|
+//! | `GET /escrows`                 | escrows, `?status=&depositor=`            |
+//! | `GET /escrows/:id`             | one escrow                                |
+//! | `GET /escrows/:id/payouts`     | releases and refunds of the escrow        |
+//! | `GET /escrows/:id/events`      | indexed events of the escrow              |
+//! | `GET /payouts`                 | all payouts, `?recipient=`                |
+//! | `GET /stats`                   | counts per status and totals              |
+//! | `GET /gaps`                    | detected sequence gaps                    |
+//!
+//! Listings take `?limit=` (default 50, at most 500) and `?offset=`.
+
+use crate::{
+    model::{Escrow, EscrowStatus, Payout, SequenceGap},
+    store::{EventRecord, Filter, Stats, Store},
+};
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+
+const DEFAULT_LIMIT: i64 = 50;
+const MAX_LIMIT: i64 = 500;
+
+pub fn router(store: Store) -> Router {
+    Router::new()
+        .route("/escrows", get(list_escrows))
+        .route("/escrows/:id", get(get_escrow))
+        .route("/escrows/:id/payouts", get(escrow_payouts))
+        .route("/escrows/:id/events", get(escrow_events))
+        .route("/payouts", get(list_payouts))
+        .route("/stats", get(stats))
+        .route("/gaps", get(gaps))
+        .with_state(store)
+}
+
+pub enum ApiError {
+    BadRequest(String),
+    NotFound,
+    Internal(anyhow::Error),
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(error: anyhow::Error) -> Self {
+        ApiError::Internal(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match self {
+            ApiError::BadRequest(message) => (StatusCode::BAD_REQUEST, message).into_response(),
+            ApiError::NotFound => StatusCode::NOT_FOUND.into_response(),
+            ApiError::Internal(error) => {
+                tracing::error!("query failed: {error:#}");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        }
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ListParams {
+    pub status: Option<String>,
+    pub depositor: Option<String>,
+    pub recipient: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+impl ListParams {
+    fn filter(&self) -> Result<Filter, ApiError> {
+        let status = match &self.status {
+            Some(status) => Some(
+                EscrowStatus::parse(status)
+                    .ok_or_else(|| ApiError::BadRequest(format!("unknown status {status}")))?,
+            ),
+            None => None,
+        };
+        Ok(Filter {
+            status,
+            address: self.depositor.clone().or_else(|| self.recipient.clone()),
+            bounty_id: None,
+            limit: self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT),
+            offset: self.offset.unwrap_or(0).max(0),
+        })
+    }
+}
+
+async fn list_escrows(
+    State(store): State<Store>,
+    Query(params): Query<ListParams>,
+) -> ApiResult<Vec<Escrow>> {
+    Ok(Json(store.escrows(&params.filter()?).await?))
+}
+
+async fn get_escrow(State(store): State<Store>, Path(id): Path<u64>) -> ApiResult<Escrow> {
+    store.escrow(id).await?.map(Json).ok_or(ApiError::NotFound)
+}
+
+async fn escrow_payouts(
+    State(store): State<Store>,
+    Path(id): Path<u64>,
+    Query(params): Query<ListParams>,
+) -> ApiResult<Vec<Payout>> {
+    let filter = Filter {
+        bounty_id: Some(id),
+        address: None,
+        ..params.filter()?
+    };
+    Ok(Json(store.payouts(&filter).await?))
+}
+
+async fn escrow_events(
+    State(store): State<Store>,
+    Path(id): Path<u64>,
+) -> ApiResult<Vec<EventRecord>> {
+    Ok(Json(store.events(id).await?))
+}
+
+async fn list_payouts(
+    State(store): State<Store>,
+    Query(params): Query<ListParams>,
+) -> ApiResult<Vec<Payout>> {
+    Ok(Json(store.payouts(&params.filter()?).await?))
+}
+
+async fn stats(State(store): State<Store>) -> ApiResult<Stats> {
+    Ok(Json(store.stats().await?))
+}
+
+async fn gaps(State(store): State<Store>) -> ApiResult<Vec<SequenceGap>> {
+    Ok(Json(store.gaps().await?))
+}
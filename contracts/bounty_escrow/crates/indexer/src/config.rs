@@ -0,0 +1,56 @@
+use anyhow::{Context, Result};
+use std::{env, net::SocketAddr, time::Duration};
+
+/// Indexer settings, read from the environment.
+///
+/// | Variable                   | Default                          |
+/// |----------------------------|----------------------------------|
+/// | `GRAINLIFY_RPC_URL`        | required                         |
+/// | `GRAINLIFY_CONTRACT_ID`    | required (`C...` strkey)         |
+/// | `DATABASE_URL`             | `sqlite://grainlify-indexer.db`  |
+/// | `GRAINLIFY_START_LEDGER`   | latest ledger on first run       |
+/// | `GRAINLIFY_LISTEN_ADDR`    | `127.0.0.1:8088`                 |
+/// | `GRAINLIFY_POLL_SECS`      | `5`                              |
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub rpc_url: String,
+    pub contract_id: String,
+    /// `sqlite://...` or `postgres://...`
+    pub database_url: String,
+    /// Ledger to start from when the database has no cursor yet. RPC nodes
+    /// only keep recent ledgers, so older escrows need an archive node.
+    pub start_ledger: Option<u32>,
+    pub listen_addr: SocketAddr,
+    pub poll_interval: Duration,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self> {
+        let rpc_url = env::var("GRAINLIFY_RPC_URL").context("GRAINLIFY_RPC_URL is not set")?;
+        let contract_id =
+            env::var("GRAINLIFY_CONTRACT_ID").context("GRAINLIFY_CONTRACT_ID is not set")?;
+        let database_url = env::var("DATABASE_URL")
+            .unwrap_or_else(|_| "sqlite://grainlify-indexer.db?mode=rwc".to_string());
+        let start_ledger = match env::var("GRAINLIFY_START_LEDGER") {
+            Ok(value) => Some(value.parse().context("invalid GRAINLIFY_START_LEDGER")?),
+            Err(_) => None,
+        };
+        let listen_addr = env::var("GRAINLIFY_LISTEN_ADDR")
+            .unwrap_or_else(|_| "127.0.0.1:8088".to_string())
+            .parse()
+            .context("invalid GRAINLIFY_LISTEN_ADDR")?;
+        let poll_secs = match env::var("GRAINLIFY_POLL_SECS") {
+            Ok(value) => value.parse().context("invalid GRAINLIFY_POLL_SECS")?,
+            Err(_) => 5,
+        };
+
+        Ok(Self {
+            rpc_url,
+            contract_id,
+            database_url,
+            start_ledger,
+            listen_addr,
+            poll_interval: Duration::from_secs(poll_secs),
+        })
+    }
+}
@@ -0,0 +1,180 @@
+//! Decoding escrow events from their XDR topics and payloads.
+//!
+//! Per-escrow events carry `(name, bounty_id, schema_version, sequence)` as
+//! topics (see the contract's `events.rs`). Events published before schema
+//! v3 only carry `(name, bounty_id)`; those are recognised by name and have
+//! no sequence number. Contract-wide events are not part of the model and
+//! are skipped.
+
+use anyhow::{anyhow, bail, Result};
+use grainlify_client::{rpc::RpcEvent, scval};
+use stellar_xdr::curr::{Limits, ReadXdr, ScVal};
+
+/// Event layout version the indexer understands.
+pub const EVENT_SCHEMA_VERSION: u32 = 3;
+
+/// Names of per-escrow events that predate the versioned topic layout.
+const LEGACY_ESCROW_EVENTS: &[&str] = &[
+    "f_lock", "esc_incr", "esc_fund", "f_rel", "f_prel", "f_ref", "esc_cncl", "esc_move", "dl_ext",
+    "dsp_open", "dsp_res",
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowEvent {
+    pub id: String,
+    pub ledger: u32,
+    pub closed_at: String,
+    pub bounty_id: u64,
+    pub name: String,
+    /// Per-escrow sequence number; absent on events older than schema v3
+    pub sequence: Option<u64>,
+    pub kind: EventKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventKind {
+    Locked {
+        depositor: String,
+        amount: i64,
+        deadline: u64,
+    },
+    /// Top-up or funder contribution
+    Increased {
+        amount: i64,
+        remaining: i64,
+    },
+    Released {
+        recipient: String,
+        amount: i64,
+    },
+    PartiallyReleased {
+        recipient: String,
+        amount: i64,
+        remaining: i64,
+    },
+    Refunded {
+        recipient: String,
+        amount: i64,
+    },
+    Cancelled,
+    DeadlineExtended {
+        deadline: u64,
+    },
+    DisputeOpened,
+    DisputeResolved,
+    /// Moved to another id on the same instance
+    Reassigned {
+        new_bounty_id: u64,
+    },
+    MigratedOut {
+        target: String,
+        target_bounty_id: u64,
+    },
+    MigratedIn {
+        source: String,
+        depositor: String,
+        amount: i64,
+    },
+    /// Any other per-escrow event; kept in the event log only
+    Other,
+}
+
+/// Decode `event`, or `None` if it is not a per-escrow event.
+pub fn decode(event: &RpcEvent) -> Result<Option<EscrowEvent>> {
+    let topics = event
+        .topic
+        .iter()
+        .map(|topic| ScVal::from_xdr_base64(topic, Limits::none()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let Some(ScVal::Symbol(name)) = topics.first() else {
+        return Ok(None);
+    };
+    let name = name.0.to_utf8_string()?;
+
+    let (bounty_id, sequence) = match topics.as_slice() {
+        [_, ScVal::U64(bounty_id), ScVal::U32(version), ScVal::U64(sequence)] => {
+            if *version > EVENT_SCHEMA_VERSION {
+                bail!("event {} uses unknown schema v{version}", event.id);
+            }
+            (*bounty_id, Some(*sequence))
+        }
+        [_, ScVal::U64(bounty_id)] if LEGACY_ESCROW_EVENTS.contains(&name.as_str()) => {
+            (*bounty_id, None)
+        }
+        _ => return Ok(None),
+    };
+
+    let data = ScVal::from_xdr_base64(&event.value, Limits::none())?;
+    let kind = decode_kind(&name, &data)?;
+    Ok(Some(EscrowEvent {
+        id: event.id.clone(),
+        ledger: event.ledger,
+        closed_at: event.ledger_closed_at.clone(),
+        bounty_id,
+        name,
+        sequence,
+        kind,
+    }))
+}
+
+fn decode_kind(name: &str, data: &ScVal) -> Result<EventKind> {
+    let kind = match name {
+        "f_lock" => EventKind::Locked {
+            depositor: address(data, "depositor")?,
+            amount: amount(data, "amount")?,
+            deadline: u64_field(data, "deadline")?,
+        },
+        "esc_incr" | "esc_fund" => EventKind::Increased {
+            amount: amount(data, "new_amount")?,
+            remaining: amount(data, "remaining_amount")?,
+        },
+        "f_rel" => EventKind::Released {
+            recipient: address(data, "recipient")?,
+            amount: amount(data, "amount")?,
+        },
+        "f_prel" => EventKind::PartiallyReleased {
+            recipient: address(data, "recipient")?,
+            amount: amount(data, "amount")?,
+            remaining: amount(data, "remaining_amount")?,
+        },
+        "f_ref" => EventKind::Refunded {
+            recipient: address(data, "refund_to")?,
+            amount: amount(data, "amount")?,
+        },
+        "esc_cncl" => EventKind::Cancelled,
+        "dl_ext" => EventKind::DeadlineExtended {
+            deadline: u64_field(data, "new_deadline")?,
+        },
+        "dsp_open" => EventKind::DisputeOpened,
+        "dsp_res" => EventKind::DisputeResolved,
+        "esc_move" => EventKind::Reassigned {
+            new_bounty_id: u64_field(data, "new_bounty_id")?,
+        },
+        "esc_out" => EventKind::MigratedOut {
+            target: address(data, "target")?,
+            target_bounty_id: u64_field(data, "target_bounty_id")?,
+        },
+        "esc_in" => EventKind::MigratedIn {
+            source: address(data, "source")?,
+            depositor: address(data, "depositor")?,
+            amount: amount(data, "amount")?,
+        },
+        _ => EventKind::Other,
+    };
+    Ok(kind)
+}
+
+fn u64_field(data: &ScVal, name: &str) -> Result<u64> {
+    Ok(scval::to_u64(scval::field(data, name)?)?)
+}
+
+/// Token amounts are `i128` on chain but Stellar asset balances fit in an
+/// `i64`, which every database can store.
+fn amount(data: &ScVal, name: &str) -> Result<i64> {
+    let value = scval::to_i128(scval::field(data, name)?)?;
+    i64::try_from(value).map_err(|_| anyhow!("`{name}` is out of range"))
+}
+
+fn address(data: &ScVal, name: &str) -> Result<String> {
+    Ok(scval::to_address(scval::field(data, name)?)?)
+}
@@ -0,0 +1,53 @@
+//! # Grainlify Indexer
+//!
+//! Follows a bounty escrow contract's events through Soroban RPC and keeps
+//! a queryable model of its escrows and payouts in SQLite or Postgres, so
+//! dashboards don't need to replay events themselves.
+//!
+//! - `grainlify_client::rpc` pages through `getEvents` for the contract.
+//! - `events` decodes per-escrow events from their XDR topics and payload.
+//! - `model` applies them to escrows and derives payouts.
+//! - `store` persists the model and the resume position.
+//! - `api` serves the model as JSON.
+//!
+//! Per-escrow sequence numbers (event schema v3) are checked as events are
+//! applied; a gap means events were missed and is logged and listed under
+//! `/gaps`.
+
+pub mod api;
+pub mod config;
+pub mod events;
+pub mod model;
+pub mod store;
+pub mod sync;
+
+use anyhow::Result;
+use config::Config;
+use grainlify_client::rpc::RpcClient;
+use store::Store;
+use sync::Syncer;
+
+/// Sync events and serve the API until interrupted.
+pub async fn run(config: Config) -> Result<()> {
+    let store = Store::connect(&config.database_url).await?;
+    let syncer = Syncer {
+        rpc: RpcClient::new(&config.rpc_url),
+        store: store.clone(),
+        contract_id: config.contract_id.clone(),
+        start_ledger: config.start_ledger,
+        poll_interval: config.poll_interval,
+    };
+
+    let listener = tokio::net::TcpListener::bind(config.listen_addr).await?;
+    tracing::info!("serving on {}", config.listen_addr);
+    let server = axum::serve(listener, api::router(store));
+    tokio::select! {
+        result = server => result?,
+        _ = syncer.run() => {}
+        _ = tokio::signal::ctrl_c() => tracing::info!("shutting down"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
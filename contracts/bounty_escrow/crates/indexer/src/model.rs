@@ -0,0 +1,241 @@
+//! The escrow and payout model rebuilt from events.
+
+use crate::events::{EscrowEvent, EventKind};
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EscrowStatus {
+    Locked,
+    PartiallyReleased,
+    Released,
+    Refunded,
+    Cancelled,
+    /// Reassigned to another id on the same instance
+    Moved,
+    /// Migrated to another instance
+    MigratedOut,
+}
+
+impl EscrowStatus {
+    pub const ALL: [EscrowStatus; 7] = [
+        EscrowStatus::Locked,
+        EscrowStatus::PartiallyReleased,
+        EscrowStatus::Released,
+        EscrowStatus::Refunded,
+        EscrowStatus::Cancelled,
+        EscrowStatus::Moved,
+        EscrowStatus::MigratedOut,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EscrowStatus::Locked => "locked",
+            EscrowStatus::PartiallyReleased => "partially_released",
+            EscrowStatus::Released => "released",
+            EscrowStatus::Refunded => "refunded",
+            EscrowStatus::Cancelled => "cancelled",
+            EscrowStatus::Moved => "moved",
+            EscrowStatus::MigratedOut => "migrated_out",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|status| status.as_str() == value)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Escrow {
+    pub bounty_id: u64,
+    pub depositor: String,
+    pub amount: i64,
+    pub remaining: i64,
+    /// Unknown for escrows migrated in from another instance
+    pub deadline: Option<u64>,
+    pub status: EscrowStatus,
+    pub disputed: bool,
+    /// Sequence number of the latest applied event, if it had one
+    pub last_sequence: Option<u64>,
+    pub created_ledger: u32,
+    pub updated_ledger: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutKind {
+    Release,
+    Refund,
+}
+
+impl PayoutKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PayoutKind::Release => "release",
+            PayoutKind::Refund => "refund",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "release" => Some(PayoutKind::Release),
+            "refund" => Some(PayoutKind::Refund),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Payout {
+    pub event_id: String,
+    pub bounty_id: u64,
+    pub kind: PayoutKind,
+    pub recipient: String,
+    pub amount: i64,
+    pub ledger: u32,
+    pub closed_at: String,
+}
+
+/// A break in an escrow's event sequence: events in between were missed.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct SequenceGap {
+    pub bounty_id: u64,
+    pub expected: u64,
+    pub found: u64,
+    pub ledger: u32,
+}
+
+impl Escrow {
+    fn new(event: &EscrowEvent, depositor: &str, amount: i64, deadline: Option<u64>) -> Self {
+        Self {
+            bounty_id: event.bounty_id,
+            depositor: depositor.to_string(),
+            amount,
+            remaining: amount,
+            deadline,
+            status: EscrowStatus::Locked,
+            disputed: false,
+            last_sequence: None,
+            created_ledger: event.ledger,
+            updated_ledger: event.ledger,
+        }
+    }
+
+    /// The gap `event` reveals, if its sequence number doesn't follow the
+    /// last one applied.
+    pub fn gap(&self, event: &EscrowEvent) -> Option<SequenceGap> {
+        let (Some(last), Some(found)) = (self.last_sequence, event.sequence) else {
+            return None;
+        };
+        (found != last + 1).then(|| SequenceGap {
+            bounty_id: self.bounty_id,
+            expected: last + 1,
+            found,
+            ledger: event.ledger,
+        })
+    }
+}
+
+/// Apply `event` to the escrow it belongs to, returning the escrows to
+/// store. Events of escrows created before the indexer's first ledger are
+/// not modelled and return nothing.
+pub fn apply(current: Option<Escrow>, event: &EscrowEvent) -> Vec<Escrow> {
+    let mut escrow = match (current, &event.kind) {
+        (
+            _,
+            EventKind::Locked {
+                depositor,
+                amount,
+                deadline,
+            },
+        ) => Escrow::new(event, depositor, *amount, Some(*deadline)),
+        (
+            None,
+            EventKind::MigratedIn {
+                depositor, amount, ..
+            },
+        ) => Escrow::new(event, depositor, *amount, None),
+        (Some(escrow), _) => escrow,
+        (None, _) => return Vec::new(),
+    };
+    escrow.last_sequence = event.sequence.or(escrow.last_sequence);
+    escrow.updated_ledger = event.ledger;
+
+    let mut updated = Vec::new();
+    match &event.kind {
+        EventKind::Increased { amount, remaining } => {
+            escrow.amount = *amount;
+            escrow.remaining = *remaining;
+        }
+        EventKind::Released { amount, .. } => {
+            // Full, split and milestone releases all report the gross share.
+            escrow.remaining = (escrow.remaining - amount).max(0);
+            escrow.status = if escrow.remaining == 0 {
+                EscrowStatus::Released
+            } else {
+                EscrowStatus::PartiallyReleased
+            };
+        }
+        EventKind::PartiallyReleased { remaining, .. } => {
+            escrow.remaining = *remaining;
+            escrow.status = if *remaining == 0 {
+                EscrowStatus::Released
+            } else {
+                EscrowStatus::PartiallyReleased
+            };
+        }
+        EventKind::Refunded { amount, .. } => {
+            // Partial refunds leave the escrow open.
+            escrow.remaining = (escrow.remaining - amount).max(0);
+            if escrow.remaining == 0 {
+                escrow.status = EscrowStatus::Refunded;
+            }
+        }
+        EventKind::Cancelled => {
+            escrow.remaining = 0;
+            escrow.status = EscrowStatus::Cancelled;
+        }
+        EventKind::DeadlineExtended { deadline } => escrow.deadline = Some(*deadline),
+        EventKind::DisputeOpened => escrow.disputed = true,
+        EventKind::DisputeResolved => escrow.disputed = false,
+        EventKind::Reassigned { new_bounty_id } => {
+            let mut moved = escrow.clone();
+            moved.bounty_id = *new_bounty_id;
+            moved.last_sequence = None;
+            moved.created_ledger = event.ledger;
+            updated.push(moved);
+            escrow.remaining = 0;
+            escrow.status = EscrowStatus::Moved;
+        }
+        EventKind::MigratedOut { .. } => {
+            escrow.remaining = 0;
+            escrow.status = EscrowStatus::MigratedOut;
+        }
+        EventKind::Locked { .. } | EventKind::MigratedIn { .. } | EventKind::Other => {}
+    }
+    updated.insert(0, escrow);
+    updated
+}
+
+/// The payout `event` records, if any.
+pub fn payout(event: &EscrowEvent) -> Option<Payout> {
+    let (kind, recipient, amount) = match &event.kind {
+        EventKind::Released { recipient, amount }
+        | EventKind::PartiallyReleased {
+            recipient, amount, ..
+        } => (PayoutKind::Release, recipient, *amount),
+        EventKind::Refunded { recipient, amount } => (PayoutKind::Refund, recipient, *amount),
+        _ => return None,
+    };
+    Some(Payout {
+        event_id: event.id.clone(),
+        bounty_id: event.bounty_id,
+        kind,
+        recipient: recipient.clone(),
+        amount,
+        ledger: event.ledger,
+        closed_at: event.closed_at.clone(),
+    })
+}
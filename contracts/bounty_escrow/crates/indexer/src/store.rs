@@ -0,0 +1,411 @@
+//! SQLite / Postgres storage for the model, through sqlx's `Any` driver.
+//!
+//! Each page of events is applied in one transaction together with the
+//! paging token to resume from, so a crash never applies an event twice or
+//! skips one. Events are also keyed by their RPC id, which makes replaying
+//! a range idempotent.
+
+use crate::{
+    events::EscrowEvent,
+    model::{self, Escrow, EscrowStatus, Payout, PayoutKind, SequenceGap},
+};
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use sqlx::{
+    any::{AnyPoolOptions, AnyRow},
+    AnyConnection, AnyPool, Row,
+};
+use std::collections::BTreeMap;
+
+const SCHEMA: &[&str] = &[
+    "CREATE TABLE IF NOT EXISTS escrows (
+        bounty_id BIGINT PRIMARY KEY,
+        depositor TEXT NOT NULL,
+        amount BIGINT NOT NULL,
+        remaining BIGINT NOT NULL,
+        deadline BIGINT,
+        status TEXT NOT NULL,
+        disputed BIGINT NOT NULL,
+        last_sequence BIGINT,
+        created_ledger BIGINT NOT NULL,
+        updated_ledger BIGINT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS escrows_status ON escrows (status)",
+    "CREATE INDEX IF NOT EXISTS escrows_depositor ON escrows (depositor)",
+    "CREATE TABLE IF NOT EXISTS payouts (
+        event_id TEXT PRIMARY KEY,
+        bounty_id BIGINT NOT NULL,
+        kind TEXT NOT NULL,
+        recipient TEXT NOT NULL,
+        amount BIGINT NOT NULL,
+        ledger BIGINT NOT NULL,
+        closed_at TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS payouts_bounty ON payouts (bounty_id)",
+    "CREATE INDEX IF NOT EXISTS payouts_recipient ON payouts (recipient)",
+    "CREATE TABLE IF NOT EXISTS events (
+        id TEXT PRIMARY KEY,
+        bounty_id BIGINT NOT NULL,
+        name TEXT NOT NULL,
+        sequence BIGINT,
+        ledger BIGINT NOT NULL,
+        closed_at TEXT NOT NULL
+    )",
+    "CREATE INDEX IF NOT EXISTS events_bounty ON events (bounty_id)",
+    "CREATE TABLE IF NOT EXISTS sequence_gaps (
+        bounty_id BIGINT NOT NULL,
+        expected BIGINT NOT NULL,
+        found BIGINT NOT NULL,
+        ledger BIGINT NOT NULL
+    )",
+    "CREATE TABLE IF NOT EXISTS sync_state (
+        id BIGINT PRIMARY KEY,
+        paging_token TEXT NOT NULL,
+        ledger BIGINT NOT NULL
+    )",
+];
+
+const ESCROW_COLUMNS: &str = "bounty_id, depositor, amount, remaining, deadline, status, \
+    disputed, last_sequence, created_ledger, updated_ledger";
+
+/// Filters for escrow and payout listings; unset fields match everything.
+#[derive(Clone, Debug, Default)]
+pub struct Filter {
+    pub status: Option<EscrowStatus>,
+    pub address: Option<String>,
+    pub bounty_id: Option<u64>,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct EventRecord {
+    pub id: String,
+    pub name: String,
+    pub sequence: Option<u64>,
+    pub ledger: u32,
+    pub closed_at: String,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct Stats {
+    pub escrows: BTreeMap<String, i64>,
+    /// Remaining balance of open escrows
+    pub value_locked: i64,
+    pub total_released: i64,
+    pub total_refunded: i64,
+    /// Latest ledger the indexer has caught up to
+    pub ledger: Option<u32>,
+}
+
+#[derive(Clone)]
+pub struct Store {
+    pool: AnyPool,
+}
+
+impl Store {
+    pub async fn connect(url: &str) -> Result<Self> {
+        sqlx::any::install_default_drivers();
+        let pool = AnyPoolOptions::new()
+            .max_connections(5)
+            .connect(url)
+            .await?;
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await?;
+        }
+        Ok(Self { pool })
+    }
+
+    /// Paging token and ledger the last applied page ended at.
+    pub async fn cursor(&self) -> Result<Option<(String, u32)>> {
+        let row = sqlx::query("SELECT paging_token, ledger FROM sync_state WHERE id = 1")
+            .fetch_optional(&self.pool)
+            .await?;
+        row.map(|row| Ok((row.try_get("paging_token")?, ledger(&row, "ledger")?)))
+            .transpose()
+    }
+
+    /// Apply a page of events and save the position after it. Returns the
+    /// sequence gaps the page revealed.
+    pub async fn apply(
+        &self,
+        events: &[EscrowEvent],
+        cursor: Option<&str>,
+        ledger: u32,
+    ) -> Result<Vec<SequenceGap>> {
+        let mut tx = self.pool.begin().await?;
+        let mut gaps = Vec::new();
+        for event in events {
+            let inserted = sqlx::query(
+                "INSERT INTO events (id, bounty_id, name, sequence, ledger, closed_at)
+                 VALUES ($1, $2, $3, $4, $5, $6) ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(&event.id)
+            .bind(event.bounty_id as i64)
+            .bind(&event.name)
+            .bind(event.sequence.map(|sequence| sequence as i64))
+            .bind(event.ledger as i64)
+            .bind(&event.closed_at)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+            if inserted == 0 {
+                continue;
+            }
+
+            let current = fetch_escrow(&mut tx, event.bounty_id).await?;
+            if let Some(gap) = current.as_ref().and_then(|escrow| escrow.gap(event)) {
+                sqlx::query(
+                    "INSERT INTO sequence_gaps (bounty_id, expected, found, ledger)
+                     VALUES ($1, $2, $3, $4)",
+                )
+                .bind(gap.bounty_id as i64)
+                .bind(gap.expected as i64)
+                .bind(gap.found as i64)
+                .bind(gap.ledger as i64)
+                .execute(&mut *tx)
+                .await?;
+                gaps.push(gap);
+            }
+            for escrow in model::apply(current, event) {
+                save_escrow(&mut tx, &escrow).await?;
+            }
+            if let Some(payout) = model::payout(event) {
+                save_payout(&mut tx, &payout).await?;
+            }
+        }
+
+        if let Some(cursor) = cursor {
+            sqlx::query(
+                "INSERT INTO sync_state (id, paging_token, ledger) VALUES (1, $1, $2)
+                 ON CONFLICT (id) DO UPDATE
+                 SET paging_token = excluded.paging_token, ledger = excluded.ledger",
+            )
+            .bind(cursor)
+            .bind(ledger as i64)
+            .execute(&mut *tx)
+            .await?;
+        }
+        tx.commit().await?;
+        Ok(gaps)
+    }
+
+    pub async fn escrow(&self, bounty_id: u64) -> Result<Option<Escrow>> {
+        let mut conn = self.pool.acquire().await?;
+        fetch_escrow(&mut conn, bounty_id).await
+    }
+
+    /// Escrows matching `filter`, `address` being the depositor.
+    pub async fn escrows(&self, filter: &Filter) -> Result<Vec<Escrow>> {
+        let query = format!(
+            "SELECT {ESCROW_COLUMNS} FROM escrows
+             WHERE ($1 IS NULL OR status = $1) AND ($2 IS NULL OR depositor = $2)
+             ORDER BY bounty_id LIMIT $3 OFFSET $4"
+        );
+        sqlx::query(&query)
+            .bind(filter.status.map(EscrowStatus::as_str))
+            .bind(filter.address.as_deref())
+            .bind(filter.limit)
+            .bind(filter.offset)
+            .fetch_all(&self.pool)
+            .await?
+            .iter()
+            .map(escrow_from_row)
+            .collect()
+    }
+
+    /// Payouts matching `filter`, `address` being the recipient, in ledger
+    /// order.
+    pub async fn payouts(&self, filter: &Filter) -> Result<Vec<Payout>> {
+        sqlx::query(
+            "SELECT event_id, bounty_id, kind, recipient, amount, ledger, closed_at FROM payouts
+             WHERE ($1 IS NULL OR bounty_id = $1) AND ($2 IS NULL OR recipient = $2)
+             ORDER BY ledger, event_id LIMIT $3 OFFSET $4",
+        )
+        .bind(filter.bounty_id.map(|bounty_id| bounty_id as i64))
+        .bind(filter.address.as_deref())
+        .bind(filter.limit)
+        .bind(filter.offset)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            let kind: String = row.try_get("kind")?;
+            Ok(Payout {
+                event_id: row.try_get("event_id")?,
+                bounty_id: row.try_get::<i64, _>("bounty_id")? as u64,
+                kind: PayoutKind::parse(&kind).ok_or_else(|| anyhow!("bad payout kind {kind}"))?,
+                recipient: row.try_get("recipient")?,
+                amount: row.try_get("amount")?,
+                ledger: ledger(row, "ledger")?,
+                closed_at: row.try_get("closed_at")?,
+            })
+        })
+        .collect()
+    }
+
+    /// Indexed events of `bounty_id`, oldest first.
+    pub async fn events(&self, bounty_id: u64) -> Result<Vec<EventRecord>> {
+        sqlx::query(
+            "SELECT id, name, sequence, ledger, closed_at FROM events
+             WHERE bounty_id = $1 ORDER BY ledger, id",
+        )
+        .bind(bounty_id as i64)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(EventRecord {
+                id: row.try_get("id")?,
+                name: row.try_get("name")?,
+                sequence: row
+                    .try_get::<Option<i64>, _>("sequence")?
+                    .map(|sequence| sequence as u64),
+                ledger: ledger(row, "ledger")?,
+                closed_at: row.try_get("closed_at")?,
+            })
+        })
+        .collect()
+    }
+
+    pub async fn gaps(&self) -> Result<Vec<SequenceGap>> {
+        sqlx::query(
+            "SELECT bounty_id, expected, found, ledger FROM sequence_gaps
+             ORDER BY ledger, bounty_id",
+        )
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| {
+            Ok(SequenceGap {
+                bounty_id: row.try_get::<i64, _>("bounty_id")? as u64,
+                expected: row.try_get::<i64, _>("expected")? as u64,
+                found: row.try_get::<i64, _>("found")? as u64,
+                ledger: ledger(row, "ledger")?,
+            })
+        })
+        .collect()
+    }
+
+    pub async fn stats(&self) -> Result<Stats> {
+        let mut stats = Stats::default();
+        let rows = sqlx::query(
+            "SELECT status, COUNT(*) AS escrows,
+                    CAST(COALESCE(SUM(remaining), 0) AS BIGINT) AS remaining
+             FROM escrows GROUP BY status",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        for row in rows {
+            let status: String = row.try_get("status")?;
+            if matches!(
+                EscrowStatus::parse(&status),
+                Some(EscrowStatus::Locked | EscrowStatus::PartiallyReleased)
+            ) {
+                stats.value_locked += row.try_get::<i64, _>("remaining")?;
+            }
+            stats.escrows.insert(status, row.try_get("escrows")?);
+        }
+
+        let rows = sqlx::query(
+            "SELECT kind, CAST(COALESCE(SUM(amount), 0) AS BIGINT) AS total
+             FROM payouts GROUP BY kind",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        for row in rows {
+            let total: i64 = row.try_get("total")?;
+            match PayoutKind::parse(&row.try_get::<String, _>("kind")?) {
+                Some(PayoutKind::Release) => stats.total_released = total,
+                Some(PayoutKind::Refund) => stats.total_refunded = total,
+                None => {}
+            }
+        }
+
+        stats.ledger = self.cursor().await?.map(|(_, ledger)| ledger);
+        Ok(stats)
+    }
+}
+
+async fn fetch_escrow(conn: &mut AnyConnection, bounty_id: u64) -> Result<Option<Escrow>> {
+    let query = format!("SELECT {ESCROW_COLUMNS} FROM escrows WHERE bounty_id = $1");
+    sqlx::query(&query)
+        .bind(bounty_id as i64)
+        .fetch_optional(&mut *conn)
+        .await?
+        .as_ref()
+        .map(escrow_from_row)
+        .transpose()
+}
+
+async fn save_escrow(conn: &mut AnyConnection, escrow: &Escrow) -> Result<()> {
+    let query = format!(
+        "INSERT INTO escrows ({ESCROW_COLUMNS})
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+         ON CONFLICT (bounty_id) DO UPDATE SET
+             depositor = excluded.depositor,
+             amount = excluded.amount,
+             remaining = excluded.remaining,
+             deadline = excluded.deadline,
+             status = excluded.status,
+             disputed = excluded.disputed,
+             last_sequence = excluded.last_sequence,
+             created_ledger = excluded.created_ledger,
+             updated_ledger = excluded.updated_ledger"
+    );
+    sqlx::query(&query)
+        .bind(escrow.bounty_id as i64)
+        .bind(&escrow.depositor)
+        .bind(escrow.amount)
+        .bind(escrow.remaining)
+        .bind(escrow.deadline.map(|deadline| deadline as i64))
+        .bind(escrow.status.as_str())
+        .bind(escrow.disputed as i64)
+        .bind(escrow.last_sequence.map(|sequence| sequence as i64))
+        .bind(escrow.created_ledger as i64)
+        .bind(escrow.updated_ledger as i64)
+        .execute(&mut *conn)
+        .await?;
+    Ok(())
+}
+
+async fn save_payout(conn: &mut AnyConnection, payout: &Payout) -> Result<()> {
+    sqlx::query(
+        "INSERT INTO payouts (event_id, bounty_id, kind, recipient, amount, ledger, closed_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7) ON CONFLICT (event_id) DO NOTHING",
+    )
+    .bind(&payout.event_id)
+    .bind(payout.bounty_id as i64)
+    .bind(payout.kind.as_str())
+    .bind(&payout.recipient)
+    .bind(payout.amount)
+    .bind(payout.ledger as i64)
+    .bind(&payout.closed_at)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+fn escrow_from_row(row: &AnyRow) -> Result<Escrow> {
+    let status: String = row.try_get("status")?;
+    Ok(Escrow {
+        bounty_id: row.try_get::<i64, _>("bounty_id")? as u64,
+        depositor: row.try_get("depositor")?,
+        amount: row.try_get("amount")?,
+        remaining: row.try_get("remaining")?,
+        deadline: row
+            .try_get::<Option<i64>, _>("deadline")?
+            .map(|deadline| deadline as u64),
+        status: EscrowStatus::parse(&status).ok_or_else(|| anyhow!("bad status {status}"))?,
+        disputed: row.try_get::<i64, _>("disputed")? != 0,
+        last_sequence: row
+            .try_get::<Option<i64>, _>("last_sequence")?
+            .map(|sequence| sequence as u64),
+        created_ledger: ledger(row, "created_ledger")?,
+        updated_ledger: ledger(row, "updated_ledger")?,
+    })
+}
+
+fn ledger(row: &AnyRow, column: &str) -> Result<u32> {
+    Ok(u32::try_from(row.try_get::<i64, _>(column)?)?)
+}
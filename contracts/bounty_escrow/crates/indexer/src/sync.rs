@@ -0,0 +1,84 @@
+//! Follows the contract's events and applies them to the store.
+
+use crate::{events, store::Store};
+use anyhow::Result;
+use grainlify_client::rpc::{RpcClient, Start};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+/// Events requested per `getEvents` call.
+const PAGE_LIMIT: usize = 200;
+
+pub struct Syncer {
+    pub rpc: RpcClient,
+    pub store: Store,
+    pub contract_id: String,
+    pub start_ledger: Option<u32>,
+    pub poll_interval: Duration,
+}
+
+impl Syncer {
+    /// Poll for new events forever. RPC and database errors are logged and
+    /// retried after the poll interval.
+    pub async fn run(&self) {
+        loop {
+            match self.sync_page().await {
+                Ok(full) if full => continue,
+                Ok(_) => {}
+                Err(error) => warn!("sync failed: {error:#}"),
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Fetch and apply one page of events. Returns whether the page was
+    /// full, in which case more events are likely waiting.
+    pub async fn sync_page(&self) -> Result<bool> {
+        let start = match self.store.cursor().await? {
+            Some((cursor, _)) => Start::Cursor(cursor),
+            None => match self.start_ledger {
+                Some(ledger) => Start::Ledger(ledger),
+                None => Start::Ledger(self.rpc.latest_ledger().await?),
+            },
+        };
+        let page = self
+            .rpc
+            .get_events(&start, &self.contract_id, PAGE_LIMIT)
+            .await?;
+
+        let mut decoded = Vec::with_capacity(page.events.len());
+        for event in &page.events {
+            match events::decode(event) {
+                Ok(Some(event)) => decoded.push(event),
+                Ok(None) => debug!("skipping contract-wide event {}", event.id),
+                Err(error) => warn!("skipping undecodable event {}: {error:#}", event.id),
+            }
+        }
+
+        let cursor = page
+            .cursor
+            .clone()
+            .or_else(|| page.events.last().map(|event| event.id.clone()));
+        let ledger = page
+            .events
+            .last()
+            .map_or(page.latest_ledger, |event| event.ledger);
+        let gaps = self
+            .store
+            .apply(&decoded, cursor.as_deref(), ledger)
+            .await?;
+        for gap in gaps {
+            warn!(
+                "escrow {} skipped events {}..{} before ledger {}",
+                gap.bounty_id, gap.expected, gap.found, gap.ledger
+            );
+        }
+        if !decoded.is_empty() {
+            info!(
+                "applied {} escrow events up to ledger {ledger}",
+                decoded.len()
+            );
+        }
+        Ok(page.events.len() == PAGE_LIMIT)
+    }
+}
@@ -0,0 +1,222 @@
+#![cfg(test)]
+
+use crate::{
+    events::{self, EventKind},
+    model::EscrowStatus,
+    store::{Filter, Store},
+};
+use grainlify_client::rpc::RpcEvent;
+use stellar_xdr::curr::{
+    AccountId, Int128Parts, Limits, PublicKey, ScAddress, ScMap, ScMapEntry, ScSymbol, ScVal,
+    Uint256, WriteXdr,
+};
+
+fn symbol(name: &str) -> ScVal {
+    ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+}
+
+fn account(seed: u8) -> ScVal {
+    ScVal::Address(ScAddress::Account(AccountId(
+        PublicKey::PublicKeyTypeEd25519(Uint256([seed; 32])),
+    )))
+}
+
+fn i128(value: i64) -> ScVal {
+    ScVal::I128(Int128Parts {
+        hi: if value < 0 { -1 } else { 0 },
+        lo: value as u64,
+    })
+}
+
+fn payload(fields: &[(&str, ScVal)]) -> ScVal {
+    let entries: Vec<ScMapEntry> = fields
+        .iter()
+        .map(|(key, val)| ScMapEntry {
+            key: symbol(key),
+            val: val.clone(),
+        })
+        .collect();
+    ScVal::Map(Some(ScMap(entries.try_into().unwrap())))
+}
+
+fn rpc_event(id: &str, ledger: u32, topics: &[ScVal], data: ScVal) -> RpcEvent {
+    RpcEvent {
+        id: id.to_string(),
+        ledger,
+        ledger_closed_at: "2026-01-01T00:00:00Z".to_string(),
+        contract_id: "CCONTRACT".to_string(),
+        topic: topics
+            .iter()
+            .map(|topic| topic.to_xdr_base64(Limits::none()).unwrap())
+            .collect(),
+        value: data.to_xdr_base64(Limits::none()).unwrap(),
+    }
+}
+
+/// `(name, bounty_id, 3, sequence)` topics of a per-escrow event.
+fn escrow_topics(name: &str, bounty_id: u64, sequence: u64) -> Vec<ScVal> {
+    vec![
+        symbol(name),
+        ScVal::U64(bounty_id),
+        ScVal::U32(3),
+        ScVal::U64(sequence),
+    ]
+}
+
+fn lock(id: &str, bounty_id: u64, sequence: u64, amount: i64) -> RpcEvent {
+    rpc_event(
+        id,
+        10,
+        &escrow_topics("f_lock", bounty_id, sequence),
+        payload(&[
+            ("amount", i128(amount)),
+            ("bounty_id", ScVal::U64(bounty_id)),
+            ("deadline", ScVal::U64(1_000)),
+            ("depositor", account(1)),
+            ("version", ScVal::U32(2)),
+        ]),
+    )
+}
+
+fn release(id: &str, name: &str, bounty_id: u64, sequence: u64, amount: i64) -> RpcEvent {
+    rpc_event(
+        id,
+        20,
+        &escrow_topics(name, bounty_id, sequence),
+        payload(&[
+            ("amount", i128(amount)),
+            ("bounty_id", ScVal::U64(bounty_id)),
+            ("recipient", account(2)),
+            ("remaining_amount", i128(1_000 - amount)),
+            ("timestamp", ScVal::U64(500)),
+            ("version", ScVal::U32(2)),
+        ]),
+    )
+}
+
+async fn store(name: &str) -> Store {
+    let path = std::env::temp_dir().join(format!(
+        "grainlify-indexer-{name}-{}.db",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&path);
+    Store::connect(&format!("sqlite://{}?mode=rwc", path.display()))
+        .await
+        .unwrap()
+}
+
+fn filter() -> Filter {
+    Filter {
+        limit: 50,
+        ..Filter::default()
+    }
+}
+
+#[test]
+fn test_decode_topic_layouts() {
+    let event = events::decode(&lock("1", 7, 1, 1_000)).unwrap().unwrap();
+    assert_eq!(event.bounty_id, 7);
+    assert_eq!(event.sequence, Some(1));
+    assert_eq!(
+        event.kind,
+        EventKind::Locked {
+            depositor: account_strkey(1),
+            amount: 1_000,
+            deadline: 1_000,
+        }
+    );
+
+    // Events published before schema v3 carry no version or sequence.
+    let legacy = rpc_event(
+        "2",
+        10,
+        &[symbol("f_ref"), ScVal::U64(7)],
+        payload(&[("amount", i128(40)), ("refund_to", account(1))]),
+    );
+    let event = events::decode(&legacy).unwrap().unwrap();
+    assert_eq!(event.sequence, None);
+    assert!(matches!(event.kind, EventKind::Refunded { amount: 40, .. }));
+
+    // Contract-wide events are not part of the model.
+    let init = rpc_event("3", 10, &[symbol("init"), ScVal::U32(3)], ScVal::Void);
+    assert_eq!(events::decode(&init).unwrap(), None);
+
+    let unknown = rpc_event("4", 10, &escrow_topics("asg_acc", 7, 2), ScVal::Void);
+    assert_eq!(
+        events::decode(&unknown).unwrap().unwrap().kind,
+        EventKind::Other
+    );
+}
+
+fn account_strkey(seed: u8) -> String {
+    match account(seed) {
+        ScVal::Address(address) => address.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::test]
+async fn test_store_builds_escrows_and_payouts() {
+    let store = store("payouts").await;
+    let page: Vec<_> = [
+        lock("1", 7, 1, 1_000),
+        release("2", "f_prel", 7, 2, 300),
+        release("3", "f_rel", 7, 3, 700),
+        lock("4", 8, 1, 500),
+    ]
+    .iter()
+    .map(|event| events::decode(event).unwrap().unwrap())
+    .collect();
+    store.apply(&page, Some("4"), 20).await.unwrap();
+    // Replaying a page changes nothing.
+    store.apply(&page, Some("4"), 20).await.unwrap();
+
+    let escrow = store.escrow(7).await.unwrap().unwrap();
+    assert_eq!(escrow.status, EscrowStatus::Released);
+    assert_eq!(escrow.remaining, 0);
+    assert_eq!(escrow.last_sequence, Some(3));
+
+    let payouts = store
+        .payouts(&Filter {
+            bounty_id: Some(7),
+            ..filter()
+        })
+        .await
+        .unwrap();
+    assert_eq!(payouts.len(), 2);
+    assert_eq!(payouts[1].amount, 700);
+    assert_eq!(store.events(7).await.unwrap().len(), 3);
+
+    let locked = store
+        .escrows(&Filter {
+            status: Some(EscrowStatus::Locked),
+            ..filter()
+        })
+        .await
+        .unwrap();
+    assert_eq!(locked.len(), 1);
+    assert_eq!(locked[0].bounty_id, 8);
+
+    let stats = store.stats().await.unwrap();
+    assert_eq!(stats.value_locked, 500);
+    assert_eq!(stats.total_released, 1_000);
+    assert_eq!(stats.ledger, Some(20));
+    assert_eq!(store.cursor().await.unwrap().unwrap().0, "4");
+}
+
+#[tokio::test]
+async fn test_store_records_sequence_gaps() {
+    let store = store("gaps").await;
+    let page: Vec<_> = [lock("1", 7, 1, 1_000), release("2", "f_prel", 7, 4, 300)]
+        .iter()
+        .map(|event| events::decode(event).unwrap().unwrap())
+        .collect();
+    let gaps = store.apply(&page, Some("2"), 20).await.unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!((gaps[0].expected, gaps[0].found), (2, 4));
+    assert_eq!(store.gaps().await.unwrap(), gaps);
+    assert_eq!(
+        store.escrow(7).await.unwrap().unwrap().status,
+        EscrowStatus::PartiallyReleased
+    );
+}
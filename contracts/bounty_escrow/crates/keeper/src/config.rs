@@ -0,0 +1,59 @@
+use anyhow::{Context, Result};
+use std::{env, net::SocketAddr, time::Duration};
+
+/// Keeper settings, read from the environment.
+///
+/// | Variable                       | Default                              |
+/// |--------------------------------|--------------------------------------|
+/// | `GRAINLIFY_RPC_URL`            | required                             |
+/// | `GRAINLIFY_NETWORK_PASSPHRASE` | `Test SDF Network ; September 2015`  |
+/// | `GRAINLIFY_CONTRACT_ID`        | required (`C...` strkey)             |
+/// | `GRAINLIFY_SECRET_KEY`         | required (`S...`, pays the fees)     |
+/// | `GRAINLIFY_POLL_SECS`          | `300`                                |
+/// | `GRAINLIFY_TTL_THRESHOLD`      | `120960` (about a week of ledgers)   |
+/// | `GRAINLIFY_METRICS_ADDR`       | `127.0.0.1:9090`                     |
+#[derive(Clone)]
+pub struct Config {
+    pub rpc_url: String,
+    pub network_passphrase: String,
+    pub contract_id: String,
+    pub secret_key: String,
+    pub poll_interval: Duration,
+    /// Open escrows with fewer ledgers of TTL left are extended
+    pub ttl_threshold: u32,
+    pub metrics_addr: SocketAddr,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self> {
+        let rpc_url = env::var("GRAINLIFY_RPC_URL").context("GRAINLIFY_RPC_URL is not set")?;
+        let network_passphrase = env::var("GRAINLIFY_NETWORK_PASSPHRASE")
+            .unwrap_or_else(|_| "Test SDF Network ; September 2015".to_string());
+        let contract_id =
+            env::var("GRAINLIFY_CONTRACT_ID").context("GRAINLIFY_CONTRACT_ID is not set")?;
+        let secret_key =
+            env::var("GRAINLIFY_SECRET_KEY").context("GRAINLIFY_SECRET_KEY is not set")?;
+        let poll_secs = match env::var("GRAINLIFY_POLL_SECS") {
+            Ok(value) => value.parse().context("invalid GRAINLIFY_POLL_SECS")?,
+            Err(_) => 300,
+        };
+        let ttl_threshold = match env::var("GRAINLIFY_TTL_THRESHOLD") {
+            Ok(value) => value.parse().context("invalid GRAINLIFY_TTL_THRESHOLD")?,
+            Err(_) => 120_960,
+        };
+        let metrics_addr = env::var("GRAINLIFY_METRICS_ADDR")
+            .unwrap_or_else(|_| "127.0.0.1:9090".to_string())
+            .parse()
+            .context("invalid GRAINLIFY_METRICS_ADDR")?;
+
+        Ok(Self {
+            rpc_url,
+            network_passphrase,
+            contract_id,
+            secret_key,
+            poll_interval: Duration::from_secs(poll_secs),
+            ttl_threshold,
+            metrics_addr,
+        })
+    }
+}
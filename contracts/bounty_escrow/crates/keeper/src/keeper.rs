@@ -0,0 +1,166 @@
+use crate::metrics::Metrics;
+use anyhow::Result;
+use grainlify_client::{Client, Error, Signer};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Ids fetched per `get_escrow_ids_by_status` call.
+const PAGE_LIMIT: u32 = 50;
+/// The contract's `MAX_BATCH_SIZE`, the most ids `bump_all` takes.
+const BUMP_BATCH: usize = 20;
+
+/// What one pass over the open escrows did.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Report {
+    /// Escrows still open after the pass
+    pub open: usize,
+    pub refunded: Vec<u64>,
+    pub bumped: Vec<u64>,
+    /// Escrows whose records are archived and must be restored by hand
+    pub archived: Vec<u64>,
+    /// Calls that failed for reasons other than the escrow not being due
+    pub failures: usize,
+    /// Fees paid, in stroops
+    pub fees: i64,
+}
+
+pub struct Keeper {
+    pub client: Client,
+    pub signer: Signer,
+    /// Open escrows with fewer ledgers of TTL left are extended
+    pub ttl_threshold: u32,
+    pub metrics: Arc<Metrics>,
+}
+
+impl Keeper {
+    /// Run a pass every `poll_interval`, forever.
+    pub async fn run(&self, poll_interval: Duration) {
+        loop {
+            match self.tick().await {
+                Ok(report) => {
+                    tracing::info!(
+                        open = report.open,
+                        refunded = report.refunded.len(),
+                        bumped = report.bumped.len(),
+                        archived = report.archived.len(),
+                        failures = report.failures,
+                        "pass done"
+                    );
+                    self.metrics.record(&report, now());
+                }
+                Err(error) => {
+                    tracing::warn!("pass failed: {error:#}");
+                    self.metrics.record_error();
+                }
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Refund every `Locked` escrow past its deadline and grace period,
+    /// then extend the records of open escrows close to archival.
+    pub async fn tick(&self) -> Result<Report> {
+        let now = now();
+        let grace_period = self.client.get_refund_grace_period().await?;
+        let mut report = Report::default();
+        let mut open = Vec::new();
+
+        for bounty_id in self.ids("Locked").await? {
+            let escrow = match self.client.get_escrow_info(bounty_id).await {
+                Ok(escrow) => escrow,
+                Err(error) => {
+                    self.fail(&mut report, bounty_id, error);
+                    continue;
+                }
+            };
+            if escrow.deadline.saturating_add(grace_period) > now {
+                open.push(bounty_id);
+                continue;
+            }
+            match self
+                .client
+                .claim_expired_refund(&self.signer, bounty_id)
+                .await
+            {
+                Ok(submitted) => {
+                    tracing::info!(bounty_id, hash = %submitted.hash, "refunded expired escrow");
+                    report.fees += submitted.fee.total();
+                    report.refunded.push(bounty_id);
+                }
+                // Not refundable yet, e.g. disputed or with a pending claim;
+                // simulation caught it before anything was sent.
+                Err(Error::Contract(code)) => {
+                    tracing::debug!(bounty_id, code, "expired escrow not refundable");
+                    open.push(bounty_id);
+                }
+                Err(error) => {
+                    self.fail(&mut report, bounty_id, error);
+                    open.push(bounty_id);
+                }
+            }
+        }
+        open.extend(self.ids("PartiallyRefunded").await?);
+
+        let mut due = Vec::new();
+        for &bounty_id in &open {
+            match self.client.get_escrow_ttl(bounty_id).await {
+                Ok(ttl) if ttl.ledgers_remaining < self.ttl_threshold => due.push(bounty_id),
+                Ok(_) => {}
+                Err(error) => self.fail(&mut report, bounty_id, error),
+            }
+        }
+        for batch in due.chunks(BUMP_BATCH) {
+            match self.client.bump_all(&self.signer, batch).await {
+                Ok(submitted) => {
+                    tracing::info!(count = batch.len(), hash = %submitted.hash, "extended escrows");
+                    report.fees += submitted.fee.total();
+                    report.bumped.extend_from_slice(batch);
+                }
+                Err(error) => {
+                    tracing::warn!("extending {batch:?} failed: {error}");
+                    report.failures += batch.len();
+                }
+            }
+        }
+
+        report.open = open.len();
+        Ok(report)
+    }
+
+    /// All escrows in `status`, page by page.
+    async fn ids(&self, status: &str) -> Result<Vec<u64>> {
+        let mut ids = Vec::new();
+        loop {
+            let page = self
+                .client
+                .get_escrow_ids_by_status(status, ids.len() as u32, PAGE_LIMIT)
+                .await?;
+            let done = page.len() < PAGE_LIMIT as usize;
+            ids.extend(page);
+            if done {
+                return Ok(ids);
+            }
+        }
+    }
+
+    fn fail(&self, report: &mut Report, bounty_id: u64, error: Error) {
+        match error {
+            Error::Archived => {
+                tracing::error!(bounty_id, "escrow is archived and must be restored");
+                report.archived.push(bounty_id);
+            }
+            error => {
+                tracing::warn!(bounty_id, "{error}");
+                report.failures += 1;
+            }
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
@@ -0,0 +1,54 @@
+//! # Grainlify Keeper
+//!
+//! Looks after a bounty escrow contract so programs don't lose funds to
+//! archival or forget expired escrows. Each pass:
+//!
+//! - refunds `Locked` escrows past their deadline and grace period with
+//!   `claim_expired_refund`, which any account may send;
+//! - extends the records of open escrows whose TTL is below the threshold
+//!   with `bump_all`;
+//! - reports what it did as Prometheus metrics at `/metrics`.
+//!
+//! Every call is simulated before it is sent, so escrows that are not due
+//! (disputed, with a pending claim, ...) cost nothing. Escrows already
+//! archived are logged and counted but need a manual restore.
+
+pub mod config;
+pub mod keeper;
+pub mod metrics;
+
+use anyhow::Result;
+use config::Config;
+use grainlify_client::{Client, Signer};
+use keeper::Keeper;
+use metrics::Metrics;
+use std::sync::Arc;
+
+/// Run passes and serve metrics until interrupted.
+pub async fn run(config: Config) -> Result<()> {
+    let metrics = Arc::new(Metrics::default());
+    let keeper = Keeper {
+        client: Client::new(
+            &config.rpc_url,
+            &config.network_passphrase,
+            &config.contract_id,
+        )?,
+        signer: Signer::from_secret(&config.secret_key)?,
+        ttl_threshold: config.ttl_threshold,
+        metrics: metrics.clone(),
+    };
+    tracing::info!("keeping escrows as {}", keeper.signer.public_key());
+
+    let listener = tokio::net::TcpListener::bind(config.metrics_addr).await?;
+    tracing::info!("serving metrics on {}", config.metrics_addr);
+    let server = axum::serve(listener, metrics::router(metrics));
+    tokio::select! {
+        result = server => result?,
+        _ = keeper.run(config.poll_interval) => {}
+        _ = tokio::signal::ctrl_c() => tracing::info!("shutting down"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
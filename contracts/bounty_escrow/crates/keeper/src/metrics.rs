@@ -0,0 +1,122 @@
+//! Keeper metrics in the Prometheus text format, served at `/metrics`.
+
+use crate::keeper::Report;
+use axum::{extract::State, routing::get, Router};
+use std::{
+    fmt::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+#[derive(Default)]
+pub struct Metrics {
+    passes: AtomicU64,
+    pass_errors: AtomicU64,
+    refunds: AtomicU64,
+    ttl_bumps: AtomicU64,
+    failures: AtomicU64,
+    fees: AtomicU64,
+    open_escrows: AtomicU64,
+    archived_escrows: AtomicU64,
+    last_pass: AtomicU64,
+}
+
+impl Metrics {
+    pub fn record(&self, report: &Report, now: u64) {
+        self.passes.fetch_add(1, Ordering::Relaxed);
+        self.refunds
+            .fetch_add(report.refunded.len() as u64, Ordering::Relaxed);
+        self.ttl_bumps
+            .fetch_add(report.bumped.len() as u64, Ordering::Relaxed);
+        self.failures
+            .fetch_add(report.failures as u64, Ordering::Relaxed);
+        self.fees
+            .fetch_add(report.fees.max(0) as u64, Ordering::Relaxed);
+        self.open_escrows
+            .store(report.open as u64, Ordering::Relaxed);
+        self.archived_escrows
+            .store(report.archived.len() as u64, Ordering::Relaxed);
+        self.last_pass.store(now, Ordering::Relaxed);
+    }
+
+    pub fn record_error(&self) {
+        self.pass_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn render(&self) -> String {
+        let metrics = [
+            ("passes_total", "counter", "Completed passes", &self.passes),
+            (
+                "pass_errors_total",
+                "counter",
+                "Passes aborted by an error",
+                &self.pass_errors,
+            ),
+            (
+                "refunds_total",
+                "counter",
+                "Expired escrows refunded",
+                &self.refunds,
+            ),
+            (
+                "ttl_bumps_total",
+                "counter",
+                "Escrow records extended",
+                &self.ttl_bumps,
+            ),
+            (
+                "failures_total",
+                "counter",
+                "Failed escrow calls",
+                &self.failures,
+            ),
+            (
+                "fees_stroops_total",
+                "counter",
+                "Fees paid in stroops",
+                &self.fees,
+            ),
+            (
+                "open_escrows",
+                "gauge",
+                "Open escrows after the last pass",
+                &self.open_escrows,
+            ),
+            (
+                "archived_escrows",
+                "gauge",
+                "Archived escrows found in the last pass",
+                &self.archived_escrows,
+            ),
+            (
+                "last_pass_timestamp_seconds",
+                "gauge",
+                "Unix time of the last completed pass",
+                &self.last_pass,
+            ),
+        ];
+        let mut out = String::new();
+        for (name, kind, help, value) in metrics {
+            let _ = writeln!(out, "# HELP grainlify_keeper_{name} {help}");
+            let _ = writeln!(out, "# TYPE grainlify_keeper_{name} {kind}");
+            let _ = writeln!(
+                out,
+                "grainlify_keeper_{name} {}",
+                value.load(Ordering::Relaxed)
+            );
+        }
+        out
+    }
+}
+
+pub fn router(metrics: Arc<Metrics>) -> Router {
+    Router::new()
+        .route("/metrics", get(render))
+        .with_state(metrics)
+}
+
+async fn render(State(metrics): State<Arc<Metrics>>) -> String {
+    metrics.render()
+}
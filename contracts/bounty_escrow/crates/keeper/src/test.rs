@@ -0,0 +1,265 @@
+#![cfg(test)]
+
+use crate::{keeper::Keeper, metrics::Metrics};
+use axum::{extract::State, routing::post, Json, Router};
+use grainlify_client::{scval, Client, RetryPolicy, Signer};
+use serde_json::{json, Value};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+use stellar_xdr::curr::{
+    AccountEntry, AccountEntryExt, ExtensionPoint, HostFunction, LedgerEntryData, LedgerFootprint,
+    Limits, Operation, OperationBody, ReadXdr, ScMap, ScMapEntry, ScVal, SequenceNumber,
+    SorobanResources, SorobanTransactionData, Thresholds, TransactionEnvelope, WriteXdr,
+};
+
+const CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+const FUTURE: u64 = 1 << 40;
+
+fn signer() -> Signer {
+    Signer::from_secret(&stellar_strkey::ed25519::PrivateKey([7; 32]).to_string()).unwrap()
+}
+
+/// An open escrow on the mock contract.
+struct MockEscrow {
+    bounty_id: u64,
+    status: &'static str,
+    deadline: u64,
+    ledgers_remaining: u32,
+    /// Contract error `claim_expired_refund` fails with
+    refund_error: Option<u32>,
+}
+
+#[derive(Default)]
+struct MockNode {
+    escrows: Vec<MockEscrow>,
+    /// Functions of the transactions sent, in order
+    sent: Mutex<Vec<String>>,
+    /// Function of the last simulated transaction
+    last_call: Mutex<String>,
+}
+
+fn map(fields: Vec<(&str, ScVal)>) -> ScVal {
+    let entries: Vec<ScMapEntry> = fields
+        .into_iter()
+        .map(|(key, val)| ScMapEntry {
+            key: scval::symbol(key).unwrap(),
+            val,
+        })
+        .collect();
+    ScVal::Map(Some(ScMap(entries.try_into().unwrap())))
+}
+
+impl MockNode {
+    fn escrow(&self, args: &[ScVal]) -> &MockEscrow {
+        let bounty_id = scval::to_u64(&args[0]).unwrap();
+        self.escrows
+            .iter()
+            .find(|escrow| escrow.bounty_id == bounty_id)
+            .unwrap()
+    }
+
+    /// Result of calling `function`, or the contract error it fails with.
+    fn call(&self, function: &str, args: &[ScVal]) -> Result<ScVal, u32> {
+        Ok(match function {
+            "get_refund_grace_period" => scval::u64(0),
+            "get_escrow_ids_by_status" => {
+                let status = scval::to_variant(&args[0]).unwrap();
+                let ids = self
+                    .escrows
+                    .iter()
+                    .filter(|escrow| escrow.status == status)
+                    .map(|escrow| scval::u64(escrow.bounty_id))
+                    .collect();
+                scval::vec(ids).unwrap()
+            }
+            "get_escrow_info" => {
+                let escrow = self.escrow(args);
+                map(vec![
+                    ("depositor", scval::address(&signer().public_key()).unwrap()),
+                    ("amount", scval::i128(1_000)),
+                    ("remaining_amount", scval::i128(1_000)),
+                    ("status", scval::variant(escrow.status).unwrap()),
+                    ("deadline", scval::u64(escrow.deadline)),
+                ])
+            }
+            "get_escrow_ttl" => map(vec![
+                ("live_until_ledger", scval::u32(0)),
+                (
+                    "ledgers_remaining",
+                    scval::u32(self.escrow(args).ledgers_remaining),
+                ),
+            ]),
+            "claim_expired_refund" => match self.escrow(args).refund_error {
+                Some(code) => return Err(code),
+                None => ScVal::Void,
+            },
+            "bump_all" => scval::u32(scval::to_vec(&args[0]).unwrap().len() as u32),
+            function => panic!("unexpected call {function}"),
+        })
+    }
+}
+
+fn invocation(transaction: &str) -> (String, Vec<ScVal>) {
+    let TransactionEnvelope::Tx(envelope) =
+        TransactionEnvelope::from_xdr_base64(transaction, Limits::none()).unwrap()
+    else {
+        unreachable!()
+    };
+    let Operation {
+        body: OperationBody::InvokeHostFunction(op),
+        ..
+    } = &envelope.tx.operations[0]
+    else {
+        unreachable!()
+    };
+    let HostFunction::InvokeContract(invoke) = &op.host_function else {
+        unreachable!()
+    };
+    (
+        invoke.function_name.0.to_utf8_string_lossy(),
+        invoke.args.to_vec(),
+    )
+}
+
+async fn handle(State(node): State<Arc<MockNode>>, Json(request): Json<Value>) -> Json<Value> {
+    let result = match request["method"].as_str().unwrap() {
+        "getLedgerEntries" => {
+            let account = LedgerEntryData::Account(AccountEntry {
+                account_id: signer().account_id(),
+                balance: 100_000_000,
+                seq_num: SequenceNumber(41),
+                num_sub_entries: 0,
+                inflation_dest: None,
+                flags: 0,
+                home_domain: Default::default(),
+                thresholds: Thresholds([1, 0, 0, 0]),
+                signers: Default::default(),
+                ext: AccountEntryExt::V0,
+            });
+            json!({ "entries": [{ "xdr": account.to_xdr_base64(Limits::none()).unwrap() }] })
+        }
+        "simulateTransaction" => {
+            let (function, args) = invocation(request["params"]["transaction"].as_str().unwrap());
+            *node.last_call.lock().unwrap() = function.clone();
+            match node.call(&function, &args) {
+                Ok(result) => json!({
+                    "transactionData": SorobanTransactionData {
+                        ext: ExtensionPoint::V0,
+                        resources: SorobanResources {
+                            footprint: LedgerFootprint {
+                                read_only: Default::default(),
+                                read_write: Default::default(),
+                            },
+                            instructions: 0,
+                            read_bytes: 0,
+                            write_bytes: 0,
+                        },
+                        resource_fee: 1_000,
+                    }
+                    .to_xdr_base64(Limits::none())
+                    .unwrap(),
+                    "results": [{ "auth": [], "xdr": result.to_xdr_base64(Limits::none()).unwrap() }],
+                    "latestLedger": 1,
+                }),
+                Err(code) => json!({
+                    "error": format!("HostError: Error(Contract, #{code})"),
+                    "latestLedger": 1,
+                }),
+            }
+        }
+        "sendTransaction" => {
+            let (function, _) = invocation(request["params"]["transaction"].as_str().unwrap());
+            node.sent.lock().unwrap().push(function);
+            json!({ "status": "PENDING", "hash": "" })
+        }
+        "getTransaction" => json!({ "status": "SUCCESS", "ledger": 5 }),
+        method => panic!("unexpected {method}"),
+    };
+    Json(json!({ "jsonrpc": "2.0", "id": 1, "result": result }))
+}
+
+async fn keeper(node: Arc<MockNode>) -> Keeper {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let app = Router::new().route("/", post(handle)).with_state(node);
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    let client = Client::new(&url, PASSPHRASE, CONTRACT)
+        .unwrap()
+        .with_retry(RetryPolicy {
+            max_attempts: 1,
+            initial_backoff: Duration::from_millis(1),
+            poll_interval: Duration::from_millis(1),
+            confirm_timeout: Duration::from_secs(1),
+        });
+    Keeper {
+        client,
+        signer: signer(),
+        ttl_threshold: 1_000,
+        metrics: Arc::new(Metrics::default()),
+    }
+}
+
+#[tokio::test]
+async fn test_pass_refunds_expired_and_bumps_ttls() {
+    let escrow = |bounty_id, status, deadline, ledgers_remaining, refund_error| MockEscrow {
+        bounty_id,
+        status,
+        deadline,
+        ledgers_remaining,
+        refund_error,
+    };
+    let node = Arc::new(MockNode {
+        escrows: vec![
+            escrow(1, "Locked", 0, 5_000, None),
+            escrow(2, "Locked", FUTURE, 10, None),
+            escrow(3, "PartiallyRefunded", FUTURE, 5_000, None),
+            // Expired but disputed: stays open and is still looked after.
+            escrow(4, "Locked", 0, 10, Some(34)),
+            escrow(5, "Released", 0, 0, None),
+        ],
+        ..MockNode::default()
+    });
+    let keeper = keeper(node.clone()).await;
+
+    let report = keeper.tick().await.unwrap();
+    assert_eq!(report.refunded, vec![1]);
+    assert_eq!(report.bumped, vec![2, 4]);
+    assert_eq!(report.open, 3);
+    assert_eq!(report.failures, 0);
+    assert_eq!(report.fees, 2 * 1_100);
+    assert_eq!(
+        *node.sent.lock().unwrap(),
+        vec!["claim_expired_refund", "bump_all"]
+    );
+
+    keeper.metrics.record(&report, 1_700_000_000);
+    let metrics = keeper.metrics.render();
+    assert!(metrics.contains("grainlify_keeper_refunds_total 1\n"));
+    assert!(metrics.contains("grainlify_keeper_ttl_bumps_total 2\n"));
+    assert!(metrics.contains("grainlify_keeper_open_escrows 3\n"));
+    assert!(metrics.contains("# TYPE grainlify_keeper_passes_total counter\n"));
+}
+
+#[tokio::test]
+async fn test_nothing_due_sends_nothing() {
+    let node = Arc::new(MockNode {
+        escrows: vec![MockEscrow {
+            bounty_id: 1,
+            status: "Locked",
+            deadline: FUTURE,
+            ledgers_remaining: 5_000,
+            refund_error: None,
+        }],
+        ..MockNode::default()
+    });
+    let keeper = keeper(node.clone()).await;
+
+    let report = keeper.tick().await.unwrap();
+    assert_eq!(report.open, 1);
+    assert!(report.refunded.is_empty() && report.bumped.is_empty());
+    assert!(node.sent.lock().unwrap().is_empty());
+    assert_eq!(*node.last_call.lock().unwrap(), "get_escrow_ttl");
+}
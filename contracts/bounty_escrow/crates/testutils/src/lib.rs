@@ -0,0 +1,110 @@
+//! # Escrow Test Utilities
+//!
+//! The `Setup` boilerplate every test module of a contract built on the
+//! bounty escrow used to copy: an initialized escrow with a Stellar asset
+//! token and a funded depositor, plus builders for escrows in each status
+//! and helpers to move the ledger clock.
+//!
+//! ```ignore
+//! let s = TestSetup::new();
+//! s.released(1, 500);
+//! s.lock(2, 1_000);
+//! s.advance_past_deadline(2);
+//! s.escrow.refund(&2);
+//! ```
+//!
+//! A contract's test `Setup` can wrap a `TestSetup` and deref to it, so its
+//! tests keep writing `s.escrow` and `s.env`.
+//!
+//! The fixture itself, with `new`, `with_env`, `deadline`, `lock` and
+//! `advance_time`, comes from `bounty_escrow::escrow_test_setup!`, which the
+//! unit tests inside `bounty-escrow` expand too; this crate adds the rest.
+
+use bounty_escrow::RefundMode;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address,
+};
+
+bounty_escrow::escrow_test_setup!();
+
+/// Seconds per ledger on the network, used by `advance_ledgers`.
+pub const LEDGER_SECONDS: u64 = 5;
+
+impl TestSetup<'_> {
+    /// A fresh address, e.g. for a second contributor.
+    pub fn address(&self) -> Address {
+        Address::generate(&self.env)
+    }
+
+    pub fn mint(&self, to: &Address, amount: i128) {
+        self.token_admin.mint(to, &amount);
+    }
+
+    // ========================================================================
+    // Escrow builders
+    // ========================================================================
+
+    /// A `Locked` escrow of which `paid` has been released to the
+    /// contributor.
+    pub fn partially_released(&self, bounty_id: u64, amount: i128, paid: i128) {
+        self.lock(bounty_id, amount);
+        self.escrow
+            .partial_release(&bounty_id, &self.contributor, &paid);
+    }
+
+    /// A `Released` escrow, paid in full to the contributor.
+    pub fn released(&self, bounty_id: u64, amount: i128) {
+        self.lock(bounty_id, amount);
+        self.escrow.release_funds(&bounty_id, &self.contributor);
+    }
+
+    /// A `Refunded` escrow, returned early to the depositor with the
+    /// admin's approval so the clock doesn't move.
+    pub fn refunded(&self, bounty_id: u64, amount: i128) {
+        self.lock(bounty_id, amount);
+        self.escrow
+            .approve_refund(&bounty_id, &amount, &self.depositor, &RefundMode::Full);
+        self.escrow.refund(&bounty_id);
+    }
+
+    /// A `PartiallyRefunded` escrow of which `refunded` went back to the
+    /// depositor.
+    pub fn partially_refunded(&self, bounty_id: u64, amount: i128, refunded: i128) {
+        self.lock(bounty_id, amount);
+        self.escrow.approve_refund(
+            &bounty_id,
+            &refunded,
+            &self.depositor,
+            &RefundMode::Partial,
+        );
+        self.escrow.refund(&bounty_id);
+    }
+
+    /// A `Cancelled` escrow, withdrawn by the depositor.
+    pub fn cancelled(&self, bounty_id: u64, amount: i128) {
+        self.lock(bounty_id, amount);
+        self.escrow.cancel_escrow(&bounty_id);
+    }
+
+    // ========================================================================
+    // Time travel
+    // ========================================================================
+
+    /// Close `ledgers` ledgers, moving the clock `LEDGER_SECONDS` per ledger.
+    pub fn advance_ledgers(&self, ledgers: u32) {
+        self.env.ledger().with_mut(|ledger| {
+            ledger.sequence_number += ledgers;
+            ledger.timestamp += u64::from(ledgers) * LEDGER_SECONDS;
+        });
+    }
+
+    /// Move the clock just past the deadline of `bounty_id`.
+    pub fn advance_past_deadline(&self, bounty_id: u64) {
+        let deadline = self.escrow.get_escrow_info(&bounty_id).deadline;
+        self.env.ledger().set_timestamp(deadline + 1);
+    }
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,49 @@
+#![cfg(test)]
+
+use crate::{TestSetup, DEPOSITOR_BALANCE, LEDGER_SECONDS};
+use bounty_escrow::EscrowStatus;
+
+#[test]
+fn test_builders_reach_each_status() {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    s.partially_released(2, 1_000, 300);
+    s.released(3, 1_000);
+    s.refunded(4, 1_000);
+    s.partially_refunded(5, 1_000, 400);
+    s.cancelled(6, 1_000);
+
+    let status = |bounty_id| s.escrow.get_escrow_info(&bounty_id).status;
+    assert_eq!(status(1), EscrowStatus::Locked);
+    assert_eq!(status(2), EscrowStatus::Locked);
+    assert_eq!(s.escrow.get_escrow_info(&2).remaining_amount, 700);
+    assert_eq!(status(3), EscrowStatus::Released);
+    assert_eq!(status(4), EscrowStatus::Refunded);
+    assert_eq!(status(5), EscrowStatus::PartiallyRefunded);
+    assert_eq!(status(6), EscrowStatus::Cancelled);
+
+    assert_eq!(s.token.balance(&s.contributor), 1_300);
+    assert_eq!(
+        s.token.balance(&s.depositor),
+        DEPOSITOR_BALANCE - 6_000 + 2_400
+    );
+}
+
+#[test]
+fn test_time_travel() {
+    let s = TestSetup::new();
+    s.lock(1, 1_000);
+    let start = s.env.ledger().timestamp();
+
+    s.advance_time(60);
+    assert_eq!(s.env.ledger().timestamp(), start + 60);
+
+    let sequence = s.env.ledger().sequence();
+    s.advance_ledgers(10);
+    assert_eq!(s.env.ledger().sequence(), sequence + 10);
+    assert_eq!(s.env.ledger().timestamp(), start + 60 + 10 * LEDGER_SECONDS);
+
+    s.advance_past_deadline(1);
+    s.escrow.refund(&1);
+    assert_eq!(s.token.balance(&s.depositor), DEPOSITOR_BALANCE);
+}
@@ -0,0 +1,107 @@
+//! Follows the contract's events and turns them into webhooks.
+
+use crate::{delivery::Deliverer, payload::Webhook};
+use anyhow::{Context, Result};
+use grainlify_client::rpc::{RpcClient, Start};
+use grainlify_indexer::events;
+use std::{fs, io, path::PathBuf, time::Duration};
+use tracing::{debug, info, warn};
+
+/// Events requested per `getEvents` call.
+const PAGE_LIMIT: usize = 100;
+
+pub struct Bridge {
+    pub rpc: RpcClient,
+    pub deliverer: Deliverer,
+    pub contract_id: String,
+    pub cursor_file: PathBuf,
+    pub start_ledger: Option<u32>,
+    pub poll_interval: Duration,
+}
+
+impl Bridge {
+    /// Poll for new events forever. Errors are logged and retried after the
+    /// poll interval.
+    pub async fn run(&self) {
+        loop {
+            match self.sync_page().await {
+                Ok(full) if full => continue,
+                Ok(_) => {}
+                Err(error) => warn!("sync failed: {error:#}"),
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Fetch one page of events and deliver their webhooks, then save the
+    /// resume position. A crash in between redelivers the page, so
+    /// receivers should deduplicate on the webhook id. Returns whether the
+    /// page was full, in which case more events are likely waiting.
+    pub async fn sync_page(&self) -> Result<bool> {
+        let start = match self.cursor()? {
+            Some(cursor) => Start::Cursor(cursor),
+            None => match self.start_ledger {
+                Some(ledger) => Start::Ledger(ledger),
+                None => Start::Ledger(self.rpc.latest_ledger().await?),
+            },
+        };
+        let page = self
+            .rpc
+            .get_events(&start, &self.contract_id, PAGE_LIMIT)
+            .await?;
+
+        let mut delivered = 0;
+        for event in &page.events {
+            let event = match events::decode(event) {
+                Ok(Some(event)) => event,
+                Ok(None) => continue,
+                Err(error) => {
+                    warn!("skipping undecodable event {}: {error:#}", event.id);
+                    continue;
+                }
+            };
+            let Some(webhook) = Webhook::from_event(&self.contract_id, &event) else {
+                debug!(
+                    "no webhook for {} of escrow {}",
+                    event.name, event.bounty_id
+                );
+                continue;
+            };
+            // Receivers that stay down past the retries miss the webhook
+            // rather than hold up everyone else.
+            if self.deliverer.deliver(&webhook).await?.is_empty() {
+                delivered += 1;
+            }
+        }
+
+        let cursor = page
+            .cursor
+            .clone()
+            .or_else(|| page.events.last().map(|event| event.id.clone()));
+        if let Some(cursor) = cursor {
+            self.save_cursor(&cursor)?;
+        }
+        if delivered > 0 {
+            info!("delivered {delivered} webhooks");
+        }
+        Ok(page.events.len() == PAGE_LIMIT)
+    }
+
+    fn cursor(&self) -> Result<Option<String>> {
+        match fs::read_to_string(&self.cursor_file) {
+            Ok(cursor) => Ok(Some(cursor.trim().to_string()).filter(|c| !c.is_empty())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(error) => {
+                Err(error).with_context(|| format!("reading {}", self.cursor_file.display()))
+            }
+        }
+    }
+
+    /// Replace the cursor file atomically, so a crash leaves the old one.
+    fn save_cursor(&self, cursor: &str) -> Result<()> {
+        let temporary = self.cursor_file.with_extension("tmp");
+        fs::write(&temporary, cursor)
+            .and_then(|()| fs::rename(&temporary, &self.cursor_file))
+            .with_context(|| format!("writing {}", self.cursor_file.display()))
+    }
+}
@@ -0,0 +1,67 @@
+use anyhow::{bail, Context, Result};
+use std::{env, path::PathBuf, time::Duration};
+
+/// Bridge settings, read from the environment.
+///
+/// | Variable                   | Default                          |
+/// |----------------------------|----------------------------------|
+/// | `GRAINLIFY_RPC_URL`        | required                         |
+/// | `GRAINLIFY_CONTRACT_ID`    | required (`C...` strkey)         |
+/// | `GRAINLIFY_WEBHOOK_URLS`   | required, comma-separated        |
+/// | `GRAINLIFY_WEBHOOK_SECRET` | required, shared with receivers  |
+/// | `GRAINLIFY_CURSOR_FILE`    | `grainlify-webhooks.cursor`      |
+/// | `GRAINLIFY_START_LEDGER`   | latest ledger on first run       |
+/// | `GRAINLIFY_POLL_SECS`      | `5`                              |
+#[derive(Clone)]
+pub struct Config {
+    pub rpc_url: String,
+    pub contract_id: String,
+    pub webhook_urls: Vec<String>,
+    /// Key of the HMAC in `X-Grainlify-Signature`
+    pub webhook_secret: String,
+    /// Where the resume position is kept between runs
+    pub cursor_file: PathBuf,
+    pub start_ledger: Option<u32>,
+    pub poll_interval: Duration,
+}
+
+impl Config {
+    pub fn from_env() -> Result<Self> {
+        let rpc_url = env::var("GRAINLIFY_RPC_URL").context("GRAINLIFY_RPC_URL is not set")?;
+        let contract_id =
+            env::var("GRAINLIFY_CONTRACT_ID").context("GRAINLIFY_CONTRACT_ID is not set")?;
+        let webhook_urls: Vec<String> = env::var("GRAINLIFY_WEBHOOK_URLS")
+            .context("GRAINLIFY_WEBHOOK_URLS is not set")?
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(String::from)
+            .collect();
+        if webhook_urls.is_empty() {
+            bail!("GRAINLIFY_WEBHOOK_URLS lists no URL");
+        }
+        let webhook_secret =
+            env::var("GRAINLIFY_WEBHOOK_SECRET").context("GRAINLIFY_WEBHOOK_SECRET is not set")?;
+        let cursor_file = env::var("GRAINLIFY_CURSOR_FILE")
+            .unwrap_or_else(|_| "grainlify-webhooks.cursor".to_string())
+            .into();
+        let start_ledger = match env::var("GRAINLIFY_START_LEDGER") {
+            Ok(value) => Some(value.parse().context("invalid GRAINLIFY_START_LEDGER")?),
+            Err(_) => None,
+        };
+        let poll_secs = match env::var("GRAINLIFY_POLL_SECS") {
+            Ok(value) => value.parse().context("invalid GRAINLIFY_POLL_SECS")?,
+            Err(_) => 5,
+        };
+
+        Ok(Self {
+            rpc_url,
+            contract_id,
+            webhook_urls,
+            webhook_secret,
+            cursor_file,
+            start_ledger,
+            poll_interval: Duration::from_secs(poll_secs),
+        })
+    }
+}
@@ -0,0 +1,89 @@
+//! Posting signed webhooks, with retries.
+
+use crate::{payload::Webhook, signature};
+use anyhow::Result;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub struct Deliverer {
+    http: reqwest::Client,
+    urls: Vec<String>,
+    secret: Vec<u8>,
+    /// Attempts per URL, including the first
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for each one after
+    pub initial_backoff: Duration,
+}
+
+impl Deliverer {
+    pub fn new(urls: Vec<String>, secret: &str) -> Result<Self> {
+        Ok(Self {
+            http: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .build()?,
+            urls,
+            secret: secret.as_bytes().to_vec(),
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+        })
+    }
+
+    /// Post `webhook` to every URL. Returns the URLs that did not accept it
+    /// within the allowed attempts.
+    pub async fn deliver(&self, webhook: &Webhook) -> Result<Vec<&str>> {
+        let body = serde_json::to_vec(webhook)?;
+        let mut failed = Vec::new();
+        for url in &self.urls {
+            let mut backoff = self.initial_backoff;
+            let mut attempts = 1;
+            loop {
+                match self.post(url, webhook, &body).await {
+                    Ok(()) => break,
+                    Err(Failure::Retryable(error)) if attempts < self.max_attempts => {
+                        tracing::debug!("{} to {url} failed, retrying: {error}", webhook.id);
+                        tokio::time::sleep(backoff).await;
+                        backoff *= 2;
+                        attempts += 1;
+                    }
+                    Err(Failure::Retryable(error) | Failure::Rejected(error)) => {
+                        tracing::warn!("giving up on {} to {url}: {error}", webhook.id);
+                        failed.push(url.as_str());
+                        break;
+                    }
+                }
+            }
+        }
+        Ok(failed)
+    }
+
+    /// One attempt, signed with the current time.
+    async fn post(&self, url: &str, webhook: &Webhook, body: &[u8]) -> Result<(), Failure> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        let response = self
+            .http
+            .post(url)
+            .header("Content-Type", "application/json")
+            .header("X-Grainlify-Event", webhook.kind)
+            .header("X-Grainlify-Delivery", &webhook.id)
+            .header(signature::HEADER, signature::sign(&self.secret, now, body))
+            .body(body.to_vec())
+            .send()
+            .await
+            .map_err(|error| Failure::Retryable(error.to_string()))?;
+
+        let status = response.status();
+        match status.as_u16() {
+            200..=299 => Ok(()),
+            408 | 429 | 500..=599 => Err(Failure::Retryable(status.to_string())),
+            _ => Err(Failure::Rejected(status.to_string())),
+        }
+    }
+}
+
+enum Failure {
+    /// Timeouts, connection errors, throttling and server errors
+    Retryable(String),
+    /// The receiver refused the webhook; sending it again won't help
+    Rejected(String),
+}
@@ -0,0 +1,52 @@
+//! # Grainlify Webhooks
+//!
+//! Follows a bounty escrow contract's events and POSTs a signed webhook to
+//! every configured URL when an escrow is locked, released, refunded or
+//! disputed, so integrations such as a GitHub bot can comment on issues
+//! when bounties are funded or paid.
+//!
+//! - `bridge` pages through `getEvents` and decodes events with
+//!   `grainlify_indexer::events`.
+//! - `payload` builds the JSON body of each webhook.
+//! - `signature` signs bodies with HMAC-SHA256 and verifies them.
+//! - `delivery` posts webhooks, retrying timeouts and server errors.
+//!
+//! Delivery is at least once: the resume position is saved after each page,
+//! so receivers should deduplicate on the webhook `id` (also sent as
+//! `X-Grainlify-Delivery`).
+
+pub mod bridge;
+pub mod config;
+pub mod delivery;
+pub mod payload;
+pub mod signature;
+
+use anyhow::Result;
+use bridge::Bridge;
+use config::Config;
+use delivery::Deliverer;
+use grainlify_client::rpc::RpcClient;
+
+/// Deliver webhooks until interrupted.
+pub async fn run(config: Config) -> Result<()> {
+    let bridge = Bridge {
+        rpc: RpcClient::new(&config.rpc_url),
+        deliverer: Deliverer::new(config.webhook_urls.clone(), &config.webhook_secret)?,
+        contract_id: config.contract_id.clone(),
+        cursor_file: config.cursor_file.clone(),
+        start_ledger: config.start_ledger,
+        poll_interval: config.poll_interval,
+    };
+    tracing::info!(
+        "delivering webhooks to {} receivers",
+        config.webhook_urls.len()
+    );
+    tokio::select! {
+        _ = bridge.run() => {}
+        _ = tokio::signal::ctrl_c() => tracing::info!("shutting down"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,77 @@
+//! The JSON body of a webhook.
+
+use grainlify_indexer::events::{EscrowEvent, EventKind};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Webhook {
+    /// Id of the contract event; stable across redeliveries
+    pub id: String,
+    /// e.g. `escrow.locked`
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub contract_id: String,
+    pub bounty_id: u64,
+    pub ledger: u32,
+    pub closed_at: String,
+    /// Per-escrow sequence number; absent on events older than schema v3
+    pub sequence: Option<u64>,
+    /// Amounts are decimal strings of base units, as they may not fit in a
+    /// JavaScript number.
+    pub data: Value,
+}
+
+impl Webhook {
+    /// The webhook for `event`, or `None` if receivers are not notified of
+    /// its kind.
+    pub fn from_event(contract_id: &str, event: &EscrowEvent) -> Option<Self> {
+        let (kind, data) = match &event.kind {
+            EventKind::Locked {
+                depositor,
+                amount,
+                deadline,
+            } => (
+                "escrow.locked",
+                json!({
+                    "depositor": depositor,
+                    "amount": amount.to_string(),
+                    "deadline": deadline,
+                }),
+            ),
+            EventKind::Released { recipient, amount } => (
+                "escrow.released",
+                json!({ "recipient": recipient, "amount": amount.to_string() }),
+            ),
+            EventKind::PartiallyReleased {
+                recipient,
+                amount,
+                remaining,
+            } => (
+                "escrow.partially_released",
+                json!({
+                    "recipient": recipient,
+                    "amount": amount.to_string(),
+                    "remaining": remaining.to_string(),
+                }),
+            ),
+            EventKind::Refunded { recipient, amount } => (
+                "escrow.refunded",
+                json!({ "recipient": recipient, "amount": amount.to_string() }),
+            ),
+            EventKind::DisputeOpened => ("escrow.dispute_opened", json!({})),
+            EventKind::DisputeResolved => ("escrow.dispute_resolved", json!({})),
+            _ => return None,
+        };
+        Some(Self {
+            id: event.id.clone(),
+            kind,
+            contract_id: contract_id.to_string(),
+            bounty_id: event.bounty_id,
+            ledger: event.ledger,
+            closed_at: event.closed_at.clone(),
+            sequence: event.sequence,
+            data,
+        })
+    }
+}
@@ -0,0 +1,154 @@
+#![cfg(test)]
+
+use crate::{delivery::Deliverer, payload::Webhook, signature};
+use axum::{body::Bytes, extract::State, http::HeaderMap, http::StatusCode, routing::post, Router};
+use grainlify_indexer::events::{EscrowEvent, EventKind};
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+const SECRET: &str = "whsec_test";
+const CONTRACT: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+
+fn event(kind: EventKind) -> EscrowEvent {
+    EscrowEvent {
+        id: "0000000042-0000000001".to_string(),
+        ledger: 42,
+        closed_at: "2026-01-01T00:00:00Z".to_string(),
+        bounty_id: 7,
+        name: "f_lock".to_string(),
+        sequence: Some(1),
+        kind,
+    }
+}
+
+fn locked() -> Webhook {
+    let kind = EventKind::Locked {
+        depositor: "GDEPOSITOR".to_string(),
+        amount: 1_000,
+        deadline: 2_000,
+    };
+    Webhook::from_event(CONTRACT, &event(kind)).unwrap()
+}
+
+/// A receiver answering with `statuses` in turn, then 200.
+#[derive(Default)]
+struct Receiver {
+    statuses: Mutex<Vec<u16>>,
+    received: Mutex<Vec<(HeaderMap, Bytes)>>,
+}
+
+async fn receive(
+    State(receiver): State<Arc<Receiver>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    receiver.received.lock().unwrap().push((headers, body));
+    let mut statuses = receiver.statuses.lock().unwrap();
+    let status = if statuses.is_empty() {
+        200
+    } else {
+        statuses.remove(0)
+    };
+    StatusCode::from_u16(status).unwrap()
+}
+
+async fn receiver(statuses: Vec<u16>) -> (Arc<Receiver>, String) {
+    let receiver = Arc::new(Receiver {
+        statuses: Mutex::new(statuses),
+        ..Receiver::default()
+    });
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hook", listener.local_addr().unwrap());
+    let app = Router::new()
+        .route("/hook", post(receive))
+        .with_state(receiver.clone());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    (receiver, url)
+}
+
+fn deliverer(urls: Vec<String>) -> Deliverer {
+    let mut deliverer = Deliverer::new(urls, SECRET).unwrap();
+    deliverer.initial_backoff = Duration::from_millis(1);
+    deliverer.max_attempts = 3;
+    deliverer
+}
+
+#[test]
+fn test_signatures() {
+    let body = br#"{"id":"1"}"#;
+    let header = signature::sign(SECRET.as_bytes(), 1_000, body);
+    assert!(header.starts_with("t=1000,v1="));
+    assert!(signature::verify(
+        SECRET.as_bytes(),
+        &header,
+        body,
+        1_100,
+        300
+    ));
+
+    assert!(!signature::verify(b"other", &header, body, 1_100, 300));
+    assert!(!signature::verify(
+        SECRET.as_bytes(),
+        &header,
+        b"{}",
+        1_100,
+        300
+    ));
+    // Replayed too late.
+    assert!(!signature::verify(
+        SECRET.as_bytes(),
+        &header,
+        body,
+        2_000,
+        300
+    ));
+    assert!(!signature::verify(
+        SECRET.as_bytes(),
+        "v1=00",
+        body,
+        1_000,
+        300
+    ));
+}
+
+#[test]
+fn test_payloads() {
+    let webhook = serde_json::to_value(locked()).unwrap();
+    assert_eq!(webhook["type"], "escrow.locked");
+    assert_eq!(webhook["bounty_id"], 7);
+    assert_eq!(webhook["sequence"], 1);
+    assert_eq!(webhook["data"]["amount"], "1000");
+    assert_eq!(webhook["data"]["deadline"], 2_000);
+
+    let dispute = Webhook::from_event(CONTRACT, &event(EventKind::DisputeOpened)).unwrap();
+    assert_eq!(dispute.kind, "escrow.dispute_opened");
+    let extended = EventKind::DeadlineExtended { deadline: 3_000 };
+    assert_eq!(Webhook::from_event(CONTRACT, &event(extended)), None);
+}
+
+#[tokio::test]
+async fn test_delivery_is_signed_and_retried() {
+    let (flaky, flaky_url) = receiver(vec![503, 500]).await;
+    let (refusing, refusing_url) = receiver(vec![410]).await;
+    let deliverer = deliverer(vec![flaky_url, refusing_url.clone()]);
+
+    let failed = deliverer.deliver(&locked()).await.unwrap();
+    assert_eq!(failed, vec![refusing_url.as_str()]);
+    // Client errors are not retried.
+    assert_eq!(refusing.received.lock().unwrap().len(), 1);
+
+    let received = flaky.received.lock().unwrap();
+    assert_eq!(received.len(), 3);
+    let (headers, body) = received.last().unwrap();
+    assert_eq!(headers["x-grainlify-event"], "escrow.locked");
+    assert_eq!(headers["x-grainlify-delivery"], "0000000042-0000000001");
+    let header = headers["x-grainlify-signature"].to_str().unwrap();
+    let now = header[2..header.find(',').unwrap()].parse().unwrap();
+    assert!(signature::verify(SECRET.as_bytes(), header, body, now, 0));
+    assert_eq!(
+        serde_json::from_slice::<serde_json::Value>(body).unwrap()["id"],
+        "0000000042-0000000001"
+    );
+}